@@ -58,87 +58,917 @@ pub async fn get_ship_count() -> Result<i64, String> {
         .map_err(|e| e.to_string())
 }
 
+// ============================================================================
+// Customer Operations (Ship Owners / Managers)
+// ============================================================================
+
+/// Get all active customers with their contacts
+pub async fn get_all_customers() -> Result<Vec<Customer>, String> {
+    services::customer_service::get_all()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a single customer by ID
+pub async fn get_customer_by_id(id: i32) -> Result<Option<Customer>, String> {
+    services::customer_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Search customers by legal name or tax number
+pub async fn search_customers(query: String) -> Result<Vec<Customer>, String> {
+    services::customer_service::search(&query)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a new customer with contacts
+pub async fn create_customer(customer: CreateCustomerRequest) -> Result<Customer, String> {
+    services::customer_service::create(customer)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update an existing customer
+pub async fn update_customer(id: i32, customer: UpdateCustomerRequest) -> Result<Customer, String> {
+    services::customer_service::update(id, customer)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Deactivate a customer
+pub async fn delete_customer(id: i32) -> Result<bool, String> {
+    services::customer_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Add a contact person to a customer
+pub async fn add_customer_contact(customer_id: i32, contact: CreateCustomerContactRequest) -> Result<Customer, String> {
+    services::customer_service::add_contact(customer_id, contact)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Remove a customer contact
+pub async fn delete_customer_contact(contact_id: i32) -> Result<bool, String> {
+    services::customer_service::delete_contact(contact_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get ships owned / managed by a customer
+pub async fn get_customer_ships(customer_id: i32) -> Result<Vec<Ship>, String> {
+    services::customer_service::get_ships(customer_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Current credit exposure of a customer
+pub async fn get_customer_credit_status(customer_id: i32) -> Result<CreditCheck, String> {
+    services::customer_service::get_credit_status(customer_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Check whether an order would push its customer over the credit limit
+pub async fn check_order_credit_limit(order_id: i32) -> Result<Option<CreditCheck>, String> {
+    services::customer_service::check_order_credit(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Price Agreement Operations (Customer Contract Pricing)
+// ============================================================================
+
+/// Get price agreements, optionally for a single customer
+pub async fn get_price_agreements(customer_id: Option<i32>) -> Result<Vec<PriceAgreement>, String> {
+    services::price_agreement_service::get_all(customer_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a price agreement with its lines
+pub async fn get_price_agreement_by_id(id: i32) -> Result<Option<PriceAgreement>, String> {
+    services::price_agreement_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a price agreement with item / IMPA group lines
+pub async fn create_price_agreement(agreement: CreatePriceAgreementRequest) -> Result<PriceAgreement, String> {
+    services::price_agreement_service::create(agreement)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update a price agreement (name, validity, notes, active flag)
+pub async fn update_price_agreement(id: i32, agreement: UpdatePriceAgreementRequest) -> Result<PriceAgreement, String> {
+    services::price_agreement_service::update(id, agreement)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a price agreement
+pub async fn delete_price_agreement(id: i32) -> Result<bool, String> {
+    services::price_agreement_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Add a price line to an agreement
+pub async fn add_price_agreement_line(agreement_id: i32, line: CreatePriceAgreementLineRequest) -> Result<PriceAgreement, String> {
+    services::price_agreement_service::add_line(agreement_id, line)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Remove a price line from an agreement
+pub async fn delete_price_agreement_line(line_id: i32) -> Result<bool, String> {
+    services::price_agreement_service::delete_line(line_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Contract selling price for an IMPA item on an order (None = no contract)
+pub async fn get_contract_price(order_id: i32, impa_code: String, buying_price: f64) -> Result<Option<ContractPrice>, String> {
+    services::price_agreement_service::get_contract_price(order_id, &impa_code, buying_price)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Order lines priced outside the customer's contract
+pub async fn get_off_contract_lines(customer_id: Option<i32>) -> Result<Vec<OffContractLine>, String> {
+    services::price_agreement_service::get_off_contract_lines(customer_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Pricing Operations (Selling Price Engine)
+// ============================================================================
+
+/// Get all pricing rules, most specific first
+pub async fn get_pricing_rules() -> Result<Vec<PricingRule>, String> {
+    services::pricing_service::get_rules()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a markup / margin floor rule
+pub async fn create_pricing_rule(rule: CreatePricingRuleRequest) -> Result<PricingRule, String> {
+    services::pricing_service::create_rule(rule)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update a pricing rule
+pub async fn update_pricing_rule(id: i32, rule: UpdatePricingRuleRequest) -> Result<PricingRule, String> {
+    services::pricing_service::update_rule(id, rule)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a pricing rule
+pub async fn delete_pricing_rule(id: i32) -> Result<bool, String> {
+    services::pricing_service::delete_rule(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get selling price rounding per currency
+pub async fn get_currency_roundings() -> Result<Vec<CurrencyRounding>, String> {
+    services::pricing_service::get_roundings()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Set the selling price rounding increment of a currency
+pub async fn set_currency_rounding(currency: String, increment: f64) -> Result<CurrencyRounding, String> {
+    services::pricing_service::set_rounding(&currency, increment)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Propose a selling price for an item on an order, with the rule that fired
+pub async fn propose_selling_price(order_id: i32, impa_code: Option<String>, buying_price: f64) -> Result<PriceProposal, String> {
+    services::pricing_service::propose_selling_price(order_id, impa_code.as_deref(), buying_price)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Re-price all lines of an order with the pricing engine
+pub async fn reprice_order(order_id: i32) -> Result<RepriceResult, String> {
+    services::pricing_service::reprice_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 // ============================================================================
 // Order Operations
 // ============================================================================
 
-/// Get all orders with optional status filter
-pub async fn get_all_orders(status_filter: Option<OrderStatus>) -> Result<Vec<Order>, String> {
-    services::order_service::get_all(status_filter)
+/// Get all orders with optional status filter
+pub async fn get_all_orders(status_filter: Option<OrderStatus>) -> Result<Vec<Order>, String> {
+    services::order_service::get_all(status_filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a single order with all items
+pub async fn get_order_with_items(id: i32) -> Result<Option<OrderWithItems>, String> {
+    services::order_service::get_with_items(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a new order (with the customer's credit check)
+pub async fn create_order(order: CreateOrderRequest) -> Result<OrderWithItems, String> {
+    services::order_service::create(order)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update an existing order
+pub async fn update_order(id: i32, order: UpdateOrderRequest) -> Result<Order, String> {
+    services::order_service::update(id, order)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update order status (state machine enforced; Delivered needs a signed delivery note or a waiver)
+pub async fn update_order_status(id: i32, new_status: OrderStatus) -> Result<Order, String> {
+    services::order_service::update_status(id, new_status)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get orders by ship visit ID
+pub async fn get_orders_by_ship_visit(ship_visit_id: i32) -> Result<Vec<Order>, String> {
+    services::order_service::get_by_ship_visit(ship_visit_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete an order (cascade deletes order items)
+pub async fn delete_order(id: i32) -> Result<bool, String> {
+    services::order_service::delete_order(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Copy an order's items into a new order for another ship visit, optionally at today's prices
+pub async fn clone_order(order_id: i32, target_ship_visit_id: i32, refresh_prices: bool) -> Result<OrderWithItems, String> {
+    services::order_service::clone_order(order_id, target_ship_visit_id, refresh_prices)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Move the given items into a new order numbered after the original (linked for audit)
+pub async fn split_order(order_id: i32, item_ids: Vec<i32>) -> Result<OrderWithItems, String> {
+    services::order_service::split_order(order_id, item_ids)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Merge orders for the same visit into the first one; the others are cancelled and linked
+pub async fn merge_orders(order_ids: Vec<i32>) -> Result<OrderWithItems, String> {
+    services::order_service::merge_orders(order_ids)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the splits and merges an order took part in
+pub async fn get_order_links(order_id: i32) -> Result<Vec<OrderLink>, String> {
+    services::order_service::get_links(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Order Item Operations  
+// ============================================================================
+
+/// Get all items for an order
+pub async fn get_order_items(order_id: i32) -> Result<Vec<OrderItem>, String> {
+    services::order_item_service::get_by_order_id(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Add item to an order; returns the order with the customer's credit check
+pub async fn add_order_item(item: CreateOrderItemRequest) -> Result<OrderWithItems, String> {
+    services::order_item_service::create(item)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update order item (prices, quantity)
+pub async fn update_order_item(id: i32, item: UpdateOrderItemRequest) -> Result<OrderItem, String> {
+    services::order_item_service::update(id, item)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete order item
+pub async fn delete_order_item(id: i32) -> Result<bool, String> {
+    services::order_item_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Order Charge Operations (Launch Boat, Delivery, Port Fees, Discounts)
+// ============================================================================
+
+/// Get the charges of an order
+pub async fn get_order_charges(order_id: i32) -> Result<Vec<OrderCharge>, String> {
+    services::order_charge_service::get_by_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Add a charge or discount to an order
+pub async fn add_order_charge(charge: CreateOrderChargeRequest) -> Result<OrderCharge, String> {
+    services::order_charge_service::create(charge)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update an order charge
+pub async fn update_order_charge(id: i32, charge: UpdateOrderChargeRequest) -> Result<OrderCharge, String> {
+    services::order_charge_service::update(id, charge)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Remove a charge from an order
+pub async fn delete_order_charge(id: i32) -> Result<bool, String> {
+    services::order_charge_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Landed Cost Operations (Freight, Customs, Handling on Inbound Goods)
+// ============================================================================
+
+/// Get landed costs recorded against an order
+pub async fn get_landed_costs(order_id: i32) -> Result<Vec<LandedCost>, String> {
+    services::landed_cost_service::get_by_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Record a landed cost and allocate it by value, weight or quantity
+pub async fn create_landed_cost(landed_cost: CreateLandedCostRequest) -> Result<LandedCost, String> {
+    services::landed_cost_service::create(landed_cost)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a landed cost and its allocations
+pub async fn delete_landed_cost(id: i32) -> Result<bool, String> {
+    services::landed_cost_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Order Fulfilment Operations (Partial Deliveries, Back-Orders)
+// ============================================================================
+
+/// Get the delivery events of an order
+pub async fn get_order_deliveries(order_id: i32) -> Result<Vec<ItemDelivery>, String> {
+    services::fulfilment_service::get_deliveries(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Record a (partial) delivery of order lines; the order status follows the fulfilment
+pub async fn record_delivery(delivery: RecordDeliveryRequest) -> Result<OrderWithItems, String> {
+    services::fulfilment_service::record_delivery(delivery)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a delivery event
+pub async fn delete_order_delivery(id: i32) -> Result<bool, String> {
+    services::fulfilment_service::delete_delivery(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Close an order line short, dropping its undelivered remainder
+pub async fn close_order_item(order_item_id: i32, reason: Option<String>) -> Result<OrderItem, String> {
+    services::fulfilment_service::close_item(order_item_id, reason)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the lines of an order that still have quantities to deliver
+pub async fn get_back_ordered_items(order_id: i32) -> Result<Vec<OrderItem>, String> {
+    services::fulfilment_service::get_back_ordered_items(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Move back-ordered quantities to a follow-up order for a later visit of the same ship
+pub async fn roll_over_backorders(order_id: i32, ship_visit_id: i32) -> Result<Order, String> {
+    services::fulfilment_service::roll_over_backorders(order_id, ship_visit_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the back-order follow-up orders of an order
+pub async fn get_follow_up_orders(order_id: i32) -> Result<Vec<Order>, String> {
+    services::fulfilment_service::get_follow_up_orders(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Delivery Note Operations (Signed Proof of Delivery)
+// ============================================================================
+
+/// Get the delivery notes of an order
+pub async fn get_delivery_notes(order_id: i32) -> Result<Vec<DeliveryNote>, String> {
+    services::delivery_note_service::get_by_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a delivery note by ID
+pub async fn get_delivery_note(id: i32) -> Result<Option<DeliveryNote>, String> {
+    services::delivery_note_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Generate a numbered delivery note from delivered order items
+pub async fn create_delivery_note(note: CreateDeliveryNoteRequest) -> Result<DeliveryNote, String> {
+    services::delivery_note_service::create(note)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Attach the captured signature or the ship stamp photo to a delivery note
+pub async fn add_delivery_note_attachment(
+    delivery_note_id: i32,
+    kind: DeliveryNoteAttachmentKind,
+    file_name: String,
+    mime_type: String,
+    data: Vec<u8>,
+) -> Result<DeliveryNoteAttachment, String> {
+    services::delivery_note_service::add_attachment(delivery_note_id, kind, file_name, mime_type, data)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the image bytes of a delivery note attachment
+pub async fn get_delivery_note_attachment_data(attachment_id: i32) -> Result<Vec<u8>, String> {
+    services::delivery_note_service::get_attachment_data(attachment_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Complete a delivery note with the receiver's name and rank (requires a signature)
+pub async fn complete_delivery_note(id: i32, completion: CompleteDeliveryNoteRequest) -> Result<DeliveryNote, String> {
+    services::delivery_note_service::complete(id, completion)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete an unsigned delivery note
+pub async fn delete_delivery_note(id: i32) -> Result<bool, String> {
+    services::delivery_note_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Allow an order to be marked delivered without a signed delivery note
+pub async fn waive_delivery_note(order_id: i32, reason: String) -> Result<Order, String> {
+    services::delivery_note_service::waive(order_id, reason)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Compliance Operations (Dangerous Goods, Prescription Medicines)
+// ============================================================================
+
+/// Check an order for missing safety data sheets and pharmacist sign-offs
+pub async fn get_order_compliance(order_id: i32) -> Result<OrderCompliance, String> {
+    services::compliance_service::check_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Sign off the prescription-only lines of an order
+pub async fn sign_off_prescription_items(order_id: i32, sign_off: PharmacistSignOffRequest) -> Result<OrderCompliance, String> {
+    services::compliance_service::sign_off_prescriptions(order_id, sign_off)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Put the safety data sheet (PDF or image) of a supply item on file
+pub async fn set_supply_item_sds(
+    supply_item_id: i32,
+    file_name: String,
+    mime_type: String,
+    data: Vec<u8>,
+) -> Result<SupplyItem, String> {
+    services::compliance_service::set_sds(supply_item_id, file_name, mime_type, data)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Dangerous goods declaration of a delivery note
+pub async fn get_dangerous_goods_summary(delivery_note_id: i32) -> Result<DangerousGoodsSummary, String> {
+    services::compliance_service::dangerous_goods_summary(delivery_note_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Return Operations (Goods Sent Back by Ships, Credit Notes)
+// ============================================================================
+
+/// Get the returns recorded against an order
+pub async fn get_order_returns(order_id: i32) -> Result<Vec<OrderReturn>, String> {
+    services::return_service::get_by_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a return by ID
+pub async fn get_order_return(id: i32) -> Result<Option<OrderReturn>, String> {
+    services::return_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Record returned quantities of delivered lines (Return stock movements, optional credit note)
+pub async fn create_order_return(order_return: CreateReturnRequest) -> Result<OrderReturn, String> {
+    services::return_service::create(order_return)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Issue the credit note of a return recorded without one
+pub async fn issue_return_credit_note(return_id: i32) -> Result<CreditNote, String> {
+    services::return_service::issue_credit_note(return_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the credit notes of an order
+pub async fn get_order_credit_notes(order_id: i32) -> Result<Vec<CreditNote>, String> {
+    services::credit_note_service::get_by_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the credit notes issued against an invoice
+pub async fn get_invoice_credit_notes(invoice_id: i32) -> Result<Vec<CreditNote>, String> {
+    services::credit_note_service::get_by_invoice(invoice_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Requisition Template Operations (Recurring Provision Lists)
+// ============================================================================
+
+/// Get all requisition templates
+pub async fn get_all_requisition_templates() -> Result<Vec<RequisitionTemplate>, String> {
+    services::requisition_template_service::get_all()
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Get a single order with all items
-pub async fn get_order_with_items(id: i32) -> Result<Option<OrderWithItems>, String> {
-    services::order_service::get_with_items(id)
+/// Get the templates usable for a ship (its own and those of its ship type)
+pub async fn get_requisition_templates_for_ship(ship_id: i32) -> Result<Vec<RequisitionTemplate>, String> {
+    services::requisition_template_service::get_for_ship(ship_id)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Create a new order
-pub async fn create_order(order: CreateOrderRequest) -> Result<Order, String> {
-    services::order_service::create(order)
+/// Get a requisition template by ID
+pub async fn get_requisition_template(id: i32) -> Result<Option<RequisitionTemplate>, String> {
+    services::requisition_template_service::get_by_id(id)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Update an existing order
-pub async fn update_order(id: i32, order: UpdateOrderRequest) -> Result<Order, String> {
-    services::order_service::update(id, order)
+/// Create a requisition template for a ship or a ship type
+pub async fn create_requisition_template(template: CreateRequisitionTemplateRequest) -> Result<RequisitionTemplate, String> {
+    services::requisition_template_service::create(template)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Update order status (state machine enforced)
-pub async fn update_order_status(id: i32, new_status: OrderStatus) -> Result<Order, String> {
-    services::order_service::update_status(id, new_status)
+/// Save an order's lines as a template for its ship (or the ship's type)
+pub async fn create_requisition_template_from_order(
+    order_id: i32,
+    name: String,
+    for_ship_type: bool,
+    base_headcount: Option<f64>,
+) -> Result<RequisitionTemplate, String> {
+    services::requisition_template_service::create_from_order(order_id, name, for_ship_type, base_headcount)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Get orders by ship visit ID
-pub async fn get_orders_by_ship_visit(ship_visit_id: i32) -> Result<Vec<Order>, String> {
-    services::order_service::get_by_ship_visit(ship_visit_id)
+/// Update a requisition template
+pub async fn update_requisition_template(id: i32, template: UpdateRequisitionTemplateRequest) -> Result<RequisitionTemplate, String> {
+    services::requisition_template_service::update(id, template)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Delete an order (cascade deletes order items)
-pub async fn delete_order(id: i32) -> Result<bool, String> {
-    services::order_service::delete_order(id)
+/// Delete a requisition template
+pub async fn delete_requisition_template(id: i32) -> Result<bool, String> {
+    services::requisition_template_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a new order from a template, scaling quantities by headcount or factor
+pub async fn instantiate_requisition_template(request: InstantiateTemplateRequest) -> Result<OrderWithItems, String> {
+    services::requisition_template_service::instantiate(request)
         .await
         .map_err(|e| e.to_string())
 }
 
 // ============================================================================
-// Order Item Operations  
+// Requisition Import Operations (CSV/XLSX Files from Ships)
 // ============================================================================
 
-/// Get all items for an order
-pub async fn get_order_items(order_id: i32) -> Result<Vec<OrderItem>, String> {
-    services::order_item_service::get_by_order_id(order_id)
+/// Read a requisition file and match its lines to supply items (nothing is saved)
+pub async fn preview_requisition_import(request: RequisitionImportRequest) -> Result<RequisitionImportPreview, String> {
+    services::requisition_import_service::preview(request)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Add item to an order
-pub async fn add_order_item(item: CreateOrderItemRequest) -> Result<OrderItem, String> {
-    services::order_item_service::create(item)
+/// Add the confirmed preview lines to the order as items (all or nothing)
+pub async fn commit_requisition_import(order_id: i32, lines: Vec<RequisitionImportLine>) -> Result<OrderWithItems, String> {
+    services::requisition_import_service::commit(order_id, lines)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Update order item (prices, quantity)
-pub async fn update_order_item(id: i32, item: UpdateOrderItemRequest) -> Result<OrderItem, String> {
-    services::order_item_service::update(id, item)
+// ============================================================================
+// Supplier Price List Operations (Monthly CSV/XLSX Price Lists)
+// ============================================================================
+
+/// Diff a supplier price list against the supplier's items (nothing is saved)
+pub async fn preview_supplier_price_list(request: PriceListImportRequest) -> Result<PriceListPreview, String> {
+    services::price_list_service::preview(&request)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Delete order item
-pub async fn delete_order_item(id: i32) -> Result<bool, String> {
-    services::order_item_service::delete(id)
+/// Apply a supplier price list: update prices, create new items, discontinue missing ones (all or nothing)
+pub async fn import_supplier_price_list(request: PriceListImportRequest) -> Result<PriceListImportResult, String> {
+    services::price_list_service::import(request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Global Search Operations
+// ============================================================================
+
+/// Full-text search over supply items, suppliers, ships and orders (all scopes when empty)
+pub async fn search(query: String, scopes: Vec<SearchScope>, limit: i32) -> Result<Vec<SearchHit>, String> {
+    services::search_service::search(&query, &scopes, limit)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Attachment Operations (Product Photos, Signed Documents)
+// ============================================================================
+
+/// Attach a file to a supply item, order, delivery note, supplier or ship
+pub async fn add_attachment(request: CreateAttachmentRequest) -> Result<Attachment, String> {
+    services::attachment_service::add(request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get all attachments of a record
+pub async fn get_attachments(entity_type: AttachmentEntity, entity_id: i32) -> Result<Vec<Attachment>, String> {
+    services::attachment_service::get_for_entity(entity_type, entity_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get attachment metadata by ID
+pub async fn get_attachment(id: i32) -> Result<Option<Attachment>, String> {
+    services::attachment_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the file contents of an attachment
+pub async fn get_attachment_data(id: i32) -> Result<Vec<u8>, String> {
+    services::attachment_service::get_data(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the PNG thumbnail of an image attachment
+pub async fn get_attachment_thumbnail(id: i32) -> Result<Vec<u8>, String> {
+    services::attachment_service::get_thumbnail(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Change the caption of an attachment
+pub async fn set_attachment_caption(id: i32, caption: Option<String>) -> Result<Attachment, String> {
+    services::attachment_service::set_caption(id, caption)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete an attachment
+pub async fn delete_attachment(id: i32) -> Result<bool, String> {
+    services::attachment_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Remove stored files that are no longer attached to anything
+pub async fn prune_attachment_files() -> Result<i32, String> {
+    services::attachment_service::prune_files()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Export Operations (XLSX/CSV by output path extension)
+// ============================================================================
+
+/// Export an order with its items and totals
+pub async fn export_order(order_id: i32, output_path: String) -> Result<ExportResult, String> {
+    services::export_service::export_order(order_id, &output_path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Export the order list with per-order totals
+pub async fn export_orders(filter: OrderExportFilter, output_path: String) -> Result<ExportResult, String> {
+    services::export_service::export_orders(filter, &output_path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Export stock levels with all stock movements
+pub async fn export_stock(output_path: String) -> Result<ExportResult, String> {
+    services::export_service::export_stock(&output_path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Export the bonded stock reconciliation with the period's declared movements
+pub async fn export_bonded_reconciliation(period_start: String, period_end: String, output_path: String) -> Result<ExportResult, String> {
+    services::export_service::export_bonded_reconciliation(&period_start, &period_end, &output_path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Export the profit report
+pub async fn export_profit_report(output_path: String) -> Result<ExportResult, String> {
+    services::export_service::export_profit_report(&output_path)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Document Operations (Company Letterhead and Order PDFs)
+// ============================================================================
+
+/// Get the company profile printed on documents
+pub async fn get_company_profile() -> Result<CompanyProfile, String> {
+    services::company_service::get_profile()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Save the company profile printed on documents
+pub async fn update_company_profile(profile: UpdateCompanyProfileRequest) -> Result<CompanyProfile, String> {
+    services::company_service::update_profile(profile)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Render a proforma, quotation, invoice or delivery note PDF for an order
+pub async fn render_order_pdf(request: PdfRenderRequest) -> Result<PdfRenderResult, String> {
+    services::pdf_service::render_to_file(request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// IMPA Catalog Operations (Code Validation and Marine Stores Guide Reference)
+// ============================================================================
+
+/// Validate an IMPA code; returns it without spaces
+pub async fn validate_impa_code(code: String) -> Result<String, String> {
+    services::impa_service::validate(&code)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Look up an IMPA code in the reference catalog (for auto-filling items)
+pub async fn lookup_impa_code(code: String) -> Result<Option<ImpaCatalogEntry>, String> {
+    services::impa_service::lookup(&code)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Search the IMPA catalog by code prefix or description
+pub async fn search_impa_catalog(query: String, section: Option<String>, limit: i32) -> Result<Vec<ImpaCatalogEntry>, String> {
+    services::impa_service::search(&query, section.as_deref(), limit)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the IMPA catalog sections
+pub async fn get_impa_sections() -> Result<Vec<ImpaSection>, String> {
+    services::impa_service::get_sections()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Set the supply item category auto-filled for an IMPA section
+pub async fn set_impa_section_category(section: String, category_id: Option<i32>) -> Result<ImpaSection, String> {
+    services::impa_service::set_section_category(&section, category_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Load an IMPA Marine Stores Guide-style CSV/XLSX file into the reference catalog
+pub async fn import_impa_catalog(request: ImpaCatalogImportRequest) -> Result<ImpaCatalogImportResult, String> {
+    services::impa_service::import_catalog(request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Supply items whose IMPA code is malformed or not in the reference catalog
+pub async fn get_impa_code_issues() -> Result<Vec<ImpaCodeIssue>, String> {
+    services::impa_service::get_code_issues()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Category Operations (Product Category Tree)
+// ============================================================================
+
+/// Get the category tree as a depth-first list (use `depth` to indent)
+pub async fn get_all_categories() -> Result<Vec<Category>, String> {
+    services::category_service::get_all()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a category by ID
+pub async fn get_category_by_id(id: i32) -> Result<Option<Category>, String> {
+    services::category_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a category (top-level when `parent_id` is empty)
+pub async fn create_category(request: CreateCategoryRequest) -> Result<Category, String> {
+    services::category_service::create(request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update a category's code, names, order or active flag
+pub async fn update_category(id: i32, request: UpdateCategoryRequest) -> Result<Category, String> {
+    services::category_service::update(id, request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Move a category under another parent (None = top level)
+pub async fn move_category(id: i32, parent_id: Option<i32>) -> Result<Category, String> {
+    services::category_service::move_category(id, parent_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete an unused category without subcategories
+pub async fn delete_category(id: i32) -> Result<bool, String> {
+    services::category_service::delete(id)
         .await
         .map_err(|e| e.to_string())
 }
@@ -154,9 +984,16 @@ pub async fn calculate_order_totals(order_id: i32) -> Result<OrderTotals, String
         .map_err(|e| e.to_string())
 }
 
-/// Calculate profit for a single item
-pub fn calculate_item_profit(buying_price: f64, selling_price: f64, quantity: f64) -> ItemProfit {
-    services::calculation_service::calculate_item_profit(buying_price, selling_price, quantity)
+/// Calculate profit for a single item (flagged when below `min_margin_percent`)
+pub fn calculate_item_profit(buying_price: f64, selling_price: f64, quantity: f64, landed_cost_per_unit: f64, min_margin_percent: Option<f64>) -> ItemProfit {
+    services::calculation_service::calculate_item_profit(buying_price, selling_price, quantity, landed_cost_per_unit, min_margin_percent)
+}
+
+/// Profit of an order line including landed cost, checked against the margin floor of the pricing rules
+pub async fn calculate_order_item_profit(order_item_id: i32) -> Result<ItemProfit, String> {
+    services::calculation_service::calculate_order_item_profit(order_item_id)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 // ============================================================================
@@ -205,9 +1042,9 @@ pub async fn search_suppliers(query: String) -> Result<Vec<Supplier>, String> {
         .map_err(|e| e.to_string())
 }
 
-/// Get suppliers by category
-pub async fn get_suppliers_by_category(category: String) -> Result<Vec<Supplier>, String> {
-    services::supplier_service::get_by_category(&category)
+/// Get suppliers in a category or any of its subcategories
+pub async fn get_suppliers_by_category(category_id: i32) -> Result<Vec<Supplier>, String> {
+    services::supplier_service::get_by_category(category_id)
         .await
         .map_err(|e| e.to_string())
 }
@@ -230,58 +1067,142 @@ pub async fn get_all_supply_items() -> Result<Vec<SupplyItem>, String> {
         .map_err(|e| e.to_string())
 }
 
-/// Get a single supply item by ID
-pub async fn get_supply_item_by_id(id: i32) -> Result<Option<SupplyItem>, String> {
-    services::supply_item_service::get_by_id(id)
+/// Get a single supply item by ID
+pub async fn get_supply_item_by_id(id: i32) -> Result<Option<SupplyItem>, String> {
+    services::supply_item_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get supply items by supplier
+pub async fn get_supply_items_by_supplier(supplier_id: i32) -> Result<Vec<SupplyItem>, String> {
+    services::supply_item_service::get_by_supplier(supplier_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get supply items in a category or any of its subcategories
+pub async fn get_supply_items_by_category(category_id: i32) -> Result<Vec<SupplyItem>, String> {
+    services::supply_item_service::get_by_category(category_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create a new supply item
+pub async fn create_supply_item(item: CreateSupplyItemRequest) -> Result<SupplyItem, String> {
+    services::supply_item_service::create(item)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update an existing supply item
+pub async fn update_supply_item(id: i32, item: UpdateSupplyItemRequest) -> Result<SupplyItem, String> {
+    services::supply_item_service::update(id, item)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a supply item
+pub async fn delete_supply_item(id: i32) -> Result<bool, String> {
+    services::supply_item_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Search supply items by name, IMPA code, or description
+pub async fn search_supply_items(query: String) -> Result<Vec<SupplyItem>, String> {
+    services::supply_item_service::search(&query)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get total supply item count
+pub async fn get_supply_item_count() -> Result<i64, String> {
+    services::supply_item_service::count()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Price changes of a supply item, newest first
+pub async fn get_price_history(supply_item_id: i32) -> Result<Vec<PriceChange>, String> {
+    services::price_history_service::get_history(supply_item_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Supply items whose price rose more than `min_rise_percent` between two dates (YYYY-MM-DD)
+pub async fn get_price_rise_report(
+    start_date: String,
+    end_date: String,
+    min_rise_percent: f64,
+    supplier_id: Option<i32>,
+) -> Result<Vec<PriceRiseItem>, String> {
+    services::price_history_service::get_price_rises(&start_date, &end_date, min_rise_percent, supplier_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Equivalent Product Operations (Alternatives Across Suppliers)
+// ============================================================================
+
+/// Get all equivalence groups with their supply items
+pub async fn get_all_equivalence_groups() -> Result<Vec<EquivalenceGroup>, String> {
+    services::equivalence_service::get_all_groups()
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Get supply items by supplier
-pub async fn get_supply_items_by_supplier(supplier_id: i32) -> Result<Vec<SupplyItem>, String> {
-    services::supply_item_service::get_by_supplier(supplier_id)
+/// Get an equivalence group by ID
+pub async fn get_equivalence_group(id: i32) -> Result<Option<EquivalenceGroup>, String> {
+    services::equivalence_service::get_group(id)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Get supply items by category
-pub async fn get_supply_items_by_category(category: String) -> Result<Vec<SupplyItem>, String> {
-    services::supply_item_service::get_by_category(&category)
+/// Link supply items as interchangeable
+pub async fn create_equivalence_group(request: CreateEquivalenceGroupRequest) -> Result<EquivalenceGroup, String> {
+    services::equivalence_service::create_group(request)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Create a new supply item
-pub async fn create_supply_item(item: CreateSupplyItemRequest) -> Result<SupplyItem, String> {
-    services::supply_item_service::create(item)
+/// Add a supply item to an equivalence group
+pub async fn add_to_equivalence_group(group_id: i32, supply_item_id: i32) -> Result<EquivalenceGroup, String> {
+    services::equivalence_service::add_to_group(group_id, supply_item_id)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Update an existing supply item
-pub async fn update_supply_item(id: i32, item: UpdateSupplyItemRequest) -> Result<SupplyItem, String> {
-    services::supply_item_service::update(id, item)
+/// Take a supply item out of its equivalence group
+pub async fn remove_from_equivalence_group(supply_item_id: i32) -> Result<bool, String> {
+    services::equivalence_service::remove_from_group(supply_item_id)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Delete a supply item
-pub async fn delete_supply_item(id: i32) -> Result<bool, String> {
-    services::supply_item_service::delete(id)
+/// Delete an equivalence group (supply items are kept)
+pub async fn delete_equivalence_group(id: i32) -> Result<bool, String> {
+    services::equivalence_service::delete_group(id)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Search supply items by name, IMPA code, or description
-pub async fn search_supply_items(query: String) -> Result<Vec<SupplyItem>, String> {
-    services::supply_item_service::search(&query)
+/// Equivalent supply items of other suppliers with price, availability, stock and lead time
+pub async fn get_alternatives(supply_item_id: i32) -> Result<Vec<ProductAlternative>, String> {
+    services::equivalence_service::get_alternatives(supply_item_id)
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Get total supply item count
-pub async fn get_supply_item_count() -> Result<i64, String> {
-    services::supply_item_service::count()
+/// Suggest the cheapest in-stock equivalent for an order line (by supply item or IMPA code)
+pub async fn suggest_order_item_source(
+    order_id: i32,
+    supply_item_id: Option<i32>,
+    impa_code: Option<String>,
+    quantity: f64,
+) -> Result<Option<ProductAlternative>, String> {
+    services::equivalence_service::suggest_for_order(order_id, supply_item_id, impa_code.as_deref(), quantity)
         .await
         .map_err(|e| e.to_string())
 }
@@ -360,6 +1281,19 @@ pub async fn create_stock_movement(movement: CreateStockMovementRequest) -> Resu
         .map_err(|e| e.to_string())
 }
 
+/// Issue stock for an order line, converting the line's unit to the stock unit.
+/// Bonded stock needs the customs declaration number.
+pub async fn issue_stock_to_order(
+    order_item_id: i32,
+    stock_id: Option<i32>,
+    quantity: Option<f64>,
+    customs_declaration_number: Option<String>,
+) -> Result<StockMovement, String> {
+    services::stock_service::issue_to_order(order_item_id, stock_id, quantity, customs_declaration_number)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 /// Get stock with all movements
 pub async fn get_stock_with_movements(id: i32) -> Result<Option<StockWithMovements>, String> {
     services::stock_service::get_with_movements(id)
@@ -374,6 +1308,77 @@ pub async fn get_stock_summary() -> Result<StockSummary, String> {
         .map_err(|e| e.to_string())
 }
 
+// ============================================================================
+// Bonded Store Operations (Duty-Free Goods for Transit Ships)
+// ============================================================================
+
+/// Whether bonded goods may be supplied for an order (foreign-flag ship at an international port)
+pub async fn get_bonded_eligibility(order_id: i32) -> Result<BondedEligibility, String> {
+    services::bonded_service::get_eligibility(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Bonded stock reconciliation for customs over a period (YYYY-MM-DD, inclusive)
+pub async fn get_bonded_reconciliation(period_start: String, period_end: String) -> Result<BondedReconciliation, String> {
+    services::bonded_service::reconciliation(&period_start, &period_end)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Unit of Measure Operations (Conversions and Pack Sizes)
+// ============================================================================
+
+/// Get all units of measure with their aliases
+pub async fn get_all_units() -> Result<Vec<UnitOfMeasure>, String> {
+    services::unit_service::get_all()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Add a unit of measure
+pub async fn create_unit(request: CreateUnitRequest) -> Result<UnitOfMeasure, String> {
+    services::unit_service::create(request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Update a unit of measure (names, factor, aliases, active flag)
+pub async fn update_unit(id: i32, request: UpdateUnitRequest) -> Result<UnitOfMeasure, String> {
+    services::unit_service::update(id, request)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the pack sizes of a supply item
+pub async fn get_pack_sizes(supply_item_id: i32) -> Result<Vec<PackSize>, String> {
+    services::unit_service::get_pack_sizes(supply_item_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Set how much one pack of a supply item holds, e.g. 1 KOLI = 24 ADET
+pub async fn set_pack_size(supply_item_id: i32, pack_unit: String, quantity: f64, content_unit: String) -> Result<PackSize, String> {
+    services::unit_service::set_pack_size(supply_item_id, &pack_unit, quantity, &content_unit)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a pack size
+pub async fn delete_pack_size(id: i32) -> Result<bool, String> {
+    services::unit_service::delete_pack_size(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Convert a quantity between units (with a supply item's pack sizes when given)
+pub async fn convert_quantity(quantity: f64, from_unit: String, to_unit: String, supply_item_id: Option<i32>) -> Result<f64, String> {
+    services::unit_service::convert_quantity(quantity, &from_unit, &to_unit, supply_item_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 // ============================================================================
 // Port Operations
 // ============================================================================
@@ -537,6 +1542,203 @@ pub async fn get_top_profitable_orders(limit: i32) -> Result<Vec<OrderProfitInfo
         .map_err(|e| e.to_string())
 }
 
+// ============================================================================
+// Receivables Operations (Invoices, Payments, Aging)
+// ============================================================================
+
+/// Get all invoices with optional status filter
+pub async fn get_all_invoices(status_filter: Option<InvoiceStatus>) -> Result<Vec<Invoice>, String> {
+    services::invoice_service::get_all(status_filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a single invoice by ID
+pub async fn get_invoice_by_id(id: i32) -> Result<Option<Invoice>, String> {
+    services::invoice_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get invoices for an order
+pub async fn get_invoices_by_order(order_id: i32) -> Result<Vec<Invoice>, String> {
+    services::invoice_service::get_by_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get invoices billed to a customer (ship owner)
+pub async fn get_invoices_by_customer(customer_id: i32) -> Result<Vec<Invoice>, String> {
+    services::invoice_service::get_by_customer(customer_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Create an invoice for an order (billed to the ship owner)
+pub async fn create_invoice(invoice: CreateInvoiceRequest) -> Result<Invoice, String> {
+    services::invoice_service::create(invoice)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Cancel an invoice (only if no payments are applied)
+pub async fn cancel_invoice(id: i32) -> Result<Invoice, String> {
+    services::invoice_service::cancel(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get all payments
+pub async fn get_all_payments() -> Result<Vec<Payment>, String> {
+    services::payment_service::get_all()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get payments received from a customer
+pub async fn get_payments_by_customer(customer_id: i32) -> Result<Vec<Payment>, String> {
+    services::payment_service::get_by_customer(customer_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a single payment with its allocations
+pub async fn get_payment_by_id(id: i32) -> Result<Option<Payment>, String> {
+    services::payment_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Record a payment and apply it to invoices (partial, multi-currency, overpayment credit)
+pub async fn record_payment(payment: CreatePaymentRequest) -> Result<Payment, String> {
+    services::payment_service::record_payment(payment)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Apply the unallocated credit of a payment to invoices
+pub async fn allocate_payment(payment_id: i32, allocations: Vec<PaymentAllocationRequest>) -> Result<Payment, String> {
+    services::payment_service::allocate_payment(payment_id, allocations)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a payment (re-opens the invoices it settled)
+pub async fn delete_payment(id: i32) -> Result<bool, String> {
+    services::payment_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a customer's account balance per currency
+pub async fn get_customer_balances(customer_id: i32) -> Result<Vec<CustomerBalance>, String> {
+    services::payment_service::get_customer_balances(customer_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Receivables aging (0-30 / 31-60 / 61-90 / 90+ days past due) per owner
+pub async fn get_receivables_aging(as_of: String) -> Result<Vec<ReceivablesAgingRow>, String> {
+    services::payment_service::get_receivables_aging(&as_of)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ============================================================================
+// Payables Operations (Supplier Invoices, 3-Way Match, Supplier Payments)
+// ============================================================================
+
+/// Get all supplier invoices with optional status filter
+pub async fn get_all_supplier_invoices(status_filter: Option<InvoiceStatus>) -> Result<Vec<SupplierInvoice>, String> {
+    services::supplier_invoice_service::get_all(status_filter)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get a single supplier invoice with its lines
+pub async fn get_supplier_invoice_by_id(id: i32) -> Result<Option<SupplierInvoice>, String> {
+    services::supplier_invoice_service::get_by_id(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get invoices received from a supplier
+pub async fn get_supplier_invoices_by_supplier(supplier_id: i32) -> Result<Vec<SupplierInvoice>, String> {
+    services::supplier_invoice_service::get_by_supplier(supplier_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get supplier invoices billed against an order
+pub async fn get_supplier_invoices_by_order(order_id: i32) -> Result<Vec<SupplierInvoice>, String> {
+    services::supplier_invoice_service::get_by_order(order_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Record a supplier invoice with its lines
+pub async fn create_supplier_invoice(invoice: CreateSupplierInvoiceRequest) -> Result<SupplierInvoice, String> {
+    services::supplier_invoice_service::create(invoice)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Cancel a supplier invoice (only if nothing has been paid)
+pub async fn cancel_supplier_invoice(id: i32) -> Result<SupplierInvoice, String> {
+    services::supplier_invoice_service::cancel(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// 3-way match of a supplier invoice against the order and goods receipts
+pub async fn match_supplier_invoice(id: i32) -> Result<SupplierInvoiceMatch, String> {
+    services::supplier_invoice_service::match_invoice(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Approve a supplier invoice for payment (updates order item costs to billed prices)
+pub async fn approve_supplier_invoice(id: i32, accept_variances: bool) -> Result<SupplierInvoice, String> {
+    services::supplier_invoice_service::approve(id, accept_variances)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get payments made to a supplier
+pub async fn get_supplier_payments_by_supplier(supplier_id: i32) -> Result<Vec<SupplierPayment>, String> {
+    services::supplier_payment_service::get_by_supplier(supplier_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get payments made against a supplier invoice
+pub async fn get_supplier_payments_by_invoice(supplier_invoice_id: i32) -> Result<Vec<SupplierPayment>, String> {
+    services::supplier_payment_service::get_by_invoice(supplier_invoice_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Pay (part of) an approved supplier invoice
+pub async fn record_supplier_payment(payment: CreateSupplierPaymentRequest) -> Result<SupplierPayment, String> {
+    services::supplier_payment_service::record_payment(payment)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a supplier payment
+pub async fn delete_supplier_payment(id: i32) -> Result<bool, String> {
+    services::supplier_payment_service::delete(id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Open supplier invoices due within the next `days` days (overdue included)
+pub async fn get_payables_due(days: i32) -> Result<Vec<PayableDue>, String> {
+    services::supplier_payment_service::get_payables_due(days)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 // ============================================================================
 // Database Initialization
 // ============================================================================
@@ -582,25 +1784,86 @@ pub async fn load_seed_data() -> Result<String, String> {
 
     // Clear existing data first (in correct order due to FK constraints)
     let clear_queries = vec![
+        "DELETE FROM credit_notes",
+        "DELETE FROM order_return_lines",
+        "DELETE FROM order_returns",
+        "DELETE FROM supplier_payments",
+        "DELETE FROM supplier_invoice_lines",
+        "DELETE FROM supplier_invoices",
+        "DELETE FROM payment_allocations",
+        "DELETE FROM payments",
+        "DELETE FROM invoices",
         "DELETE FROM stock_movements",
         "DELETE FROM stock",
+        "DELETE FROM landed_cost_allocations",
+        "DELETE FROM landed_costs",
+        "DELETE FROM order_charges",
+        "DELETE FROM order_item_deliveries",
+        "DELETE FROM attachments",
+        "DELETE FROM delivery_notes",
+        "DELETE FROM pharmacist_signoffs",
         "DELETE FROM order_items",
+        "DELETE FROM order_links",
         "DELETE FROM orders",
         "DELETE FROM ship_visits",
+        "DELETE FROM requisition_template_lines",
+        "DELETE FROM requisition_templates",
+        "DELETE FROM equivalence_group_items",
+        "DELETE FROM pack_sizes",
+        "DELETE FROM equivalence_groups",
+        "DELETE FROM supply_item_prices",
         "DELETE FROM supply_items",
         "DELETE FROM suppliers",
         "DELETE FROM ships",
+        "DELETE FROM pricing_rules",
+        "DELETE FROM currency_rounding",
+        "DELETE FROM company_profile",
+        "DELETE FROM impa_catalog",
+        "DELETE FROM impa_sections",
+        "DELETE FROM categories",
+        "DELETE FROM price_agreement_lines",
+        "DELETE FROM price_agreements",
+        "DELETE FROM customer_contacts",
+        "DELETE FROM customers",
         "DELETE FROM ports",
         // Reset autoincrement counters
         "DELETE FROM sqlite_sequence WHERE name='ports'",
         "DELETE FROM sqlite_sequence WHERE name='ships'",
+        "DELETE FROM sqlite_sequence WHERE name='customers'",
+        "DELETE FROM sqlite_sequence WHERE name='customer_contacts'",
+        "DELETE FROM sqlite_sequence WHERE name='price_agreements'",
+        "DELETE FROM sqlite_sequence WHERE name='price_agreement_lines'",
+        "DELETE FROM sqlite_sequence WHERE name='pricing_rules'",
+        "DELETE FROM sqlite_sequence WHERE name='categories'",
         "DELETE FROM sqlite_sequence WHERE name='suppliers'",
         "DELETE FROM sqlite_sequence WHERE name='supply_items'",
+        "DELETE FROM sqlite_sequence WHERE name='supply_item_prices'",
+        "DELETE FROM sqlite_sequence WHERE name='equivalence_groups'",
+        "DELETE FROM sqlite_sequence WHERE name='pack_sizes'",
         "DELETE FROM sqlite_sequence WHERE name='stock'",
         "DELETE FROM sqlite_sequence WHERE name='stock_movements'",
         "DELETE FROM sqlite_sequence WHERE name='ship_visits'",
+        "DELETE FROM sqlite_sequence WHERE name='requisition_templates'",
+        "DELETE FROM sqlite_sequence WHERE name='requisition_template_lines'",
         "DELETE FROM sqlite_sequence WHERE name='orders'",
         "DELETE FROM sqlite_sequence WHERE name='order_items'",
+        "DELETE FROM sqlite_sequence WHERE name='pharmacist_signoffs'",
+        "DELETE FROM sqlite_sequence WHERE name='order_links'",
+        "DELETE FROM sqlite_sequence WHERE name='order_charges'",
+        "DELETE FROM sqlite_sequence WHERE name='order_item_deliveries'",
+        "DELETE FROM sqlite_sequence WHERE name='delivery_notes'",
+        "DELETE FROM sqlite_sequence WHERE name='attachments'",
+        "DELETE FROM sqlite_sequence WHERE name='landed_costs'",
+        "DELETE FROM sqlite_sequence WHERE name='landed_cost_allocations'",
+        "DELETE FROM sqlite_sequence WHERE name='invoices'",
+        "DELETE FROM sqlite_sequence WHERE name='order_returns'",
+        "DELETE FROM sqlite_sequence WHERE name='order_return_lines'",
+        "DELETE FROM sqlite_sequence WHERE name='credit_notes'",
+        "DELETE FROM sqlite_sequence WHERE name='payments'",
+        "DELETE FROM sqlite_sequence WHERE name='payment_allocations'",
+        "DELETE FROM sqlite_sequence WHERE name='supplier_invoices'",
+        "DELETE FROM sqlite_sequence WHERE name='supplier_invoice_lines'",
+        "DELETE FROM sqlite_sequence WHERE name='supplier_payments'",
     ];
     
     for query in clear_queries {
@@ -616,82 +1879,226 @@ pub async fn load_seed_data() -> Result<String, String> {
 
     // === PORTS (Limanlar) ===
     conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
-        INSERT INTO ports (name, country, city, timezone, latitude, longitude, notes, is_active) VALUES
-        ('Egeport - Kuşadası', 'Türkiye', 'Kuşadası', 'Europe/Istanbul', 37.8579, 27.2609, 'Ana operasyon limanı - Cruise ve yük gemileri', 1),
-        ('Alsancak Limanı', 'Türkiye', 'İzmir', 'Europe/Istanbul', 38.4437, 27.1428, 'İzmir ana konteyner limanı', 1),
-        ('Çeşme Limanı', 'Türkiye', 'Çeşme', 'Europe/Istanbul', 38.3235, 26.3025, 'Feribot ve yolcu gemileri', 1),
-        ('Bodrum Cruise Port', 'Türkiye', 'Bodrum', 'Europe/Istanbul', 37.0344, 27.4305, 'Cruise ve yat limanı', 1),
-        ('Pire Limanı', 'Yunanistan', 'Atina', 'Europe/Athens', 37.9475, 23.6417, 'Yunanistan ana limanı', 1)
+        INSERT INTO ports (name, country, city, timezone, latitude, longitude, notes, is_international, is_active) VALUES
+        ('Egeport - Kuşadası', 'Türkiye', 'Kuşadası', 'Europe/Istanbul', 37.8579, 27.2609, 'Ana operasyon limanı - Cruise ve yük gemileri', 1, 1),
+        ('Alsancak Limanı', 'Türkiye', 'İzmir', 'Europe/Istanbul', 38.4437, 27.1428, 'İzmir ana konteyner limanı', 1, 1),
+        ('Çeşme Limanı', 'Türkiye', 'Çeşme', 'Europe/Istanbul', 38.3235, 26.3025, 'Feribot ve yolcu gemileri', 1, 1),
+        ('Bodrum Cruise Port', 'Türkiye', 'Bodrum', 'Europe/Istanbul', 37.0344, 27.4305, 'Cruise ve yat limanı', 1, 1),
+        ('Pire Limanı', 'Yunanistan', 'Atina', 'Europe/Athens', 37.9475, 23.6417, 'Yunanistan ana limanı', 1, 1)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === CUSTOMERS (Armatör / İşletmeci Firmalar) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO customers (legal_name, tax_number, tax_office, billing_address, country, payment_terms_days, credit_limit, default_currency, is_active) VALUES
+        ('MSC Cruises', 'CHE-105.946.473', NULL, 'Chemin Rieu 12-14, 1208 Geneva', 'İsviçre', 45, 2000000.0, 'TRY', 1),
+        ('Costa Crociere', 'IT00269000105', NULL, 'Piazza Piccapietra 48, 16121 Genova', 'İtalya', 45, 1500000.0, 'TRY', 1),
+        ('TUI Cruises', 'DE264936532', NULL, 'Anckelmannsplatz 1, 20537 Hamburg', 'Almanya', 30, 750000.0, 'TRY', 1),
+        ('Marella Cruises', 'GB243304009', NULL, 'Wigmore House, Wigmore Lane, Luton', 'İngiltere', 30, NULL, 'TRY', 1),
+        ('Viking Ocean Cruises', 'CHE-114.391.128', NULL, 'Schweizerhalle, 4133 Pratteln', 'İsviçre', 30, 250000.0, 'TRY', 1),
+        ('Seabourn Cruise Line', '98-0357772', NULL, '450 Third Avenue West, Seattle', 'ABD', 30, 50000.0, 'USD', 1),
+        ('Norwegian Cruise Line', '98-0691007', NULL, '7665 Corporate Center Drive, Miami', 'ABD', 30, NULL, 'TRY', 1),
+        ('Celebrity Cruises', '98-0081645', NULL, '1050 Caribbean Way, Miami', 'ABD', 30, NULL, 'TRY', 1),
+        ('Ege Denizcilik A.Ş.', '3250412874', 'Kordon', 'Atatürk Cad. No:158, Alsancak, İzmir', 'Türkiye', 15, 250000.0, 'TRY', 1),
+        ('Arkas Holding', '0730017562', 'Hasan Tahsin', 'Liman Cad. No:38, Alsancak, İzmir', 'Türkiye', 30, NULL, 'TRY', 1)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO customer_contacts (customer_id, name, role, email, phone, is_primary) VALUES
+        (1, 'Giulia Romano', 'Purchasing', 'purchasing@msccruises.com', '+41 22 703 8888', 1),
+        (1, 'Marco Bianchi', 'Accounting', 'ap@msccruises.com', '+41 22 703 8800', 0),
+        (2, 'Luca Ferrari', 'Hotel Supply', 'supply@costa.it', '+39 010 548 3111', 1),
+        (9, 'Kemal Arslan', 'Superintendent', 'kemal@egedenizcilik.com.tr', '+90 232 463 1122', 1)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === PRICE AGREEMENTS (Yıllık Fiyat Anlaşmaları) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO price_agreements (customer_id, name, currency, valid_from, valid_to, notes) VALUES
+        (1, 'MSC 2026 Kumanya Kontratı', 'TRY', '2026-01-01', '2026-12-31', 'Yıllık provizyon anlaşması')
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO price_agreement_lines (agreement_id, impa_code, impa_group, description, fixed_price, markup_percent) VALUES
+        (1, '370101', NULL, 'Dana antrikot sabit fiyat', 245.00, NULL),
+        (1, NULL, '37', 'Kumanya grubu', NULL, 30.0)
     "#.to_string())).await.map_err(|e| e.to_string())?;
 
     // === SHIPS (Gemiler) ===
     conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
-        INSERT INTO ships (name, imo_number, flag, ship_type, gross_tonnage, owner, is_active) VALUES
-        ('MSC FANTASIA', '9359791', 'Panama', 'Cruise', 137936.0, 'MSC Cruises', 1),
-        ('COSTA SMERALDA', '9785648', 'İtalya', 'Cruise', 185010.0, 'Costa Crociere', 1),
-        ('MEIN SCHIFF 5', '9753208', 'Malta', 'Cruise', 99526.0, 'TUI Cruises', 1),
-        ('MARELLA EXPLORER', '9573398', 'Malta', 'Cruise', 76522.0, 'Marella Cruises', 1),
-        ('VIKING STAR', '9650418', 'Norveç', 'Cruise', 47842.0, 'Viking Ocean Cruises', 1),
-        ('SEABOURN ENCORE', '9713258', 'Bahama', 'Cruise', 40350.0, 'Seabourn Cruise Line', 1),
-        ('NORWEGIAN JADE', '9304057', 'Bahama', 'Cruise', 93558.0, 'Norwegian Cruise Line', 1),
-        ('CELEBRITY INFINITY', '9189421', 'Malta', 'Cruise', 90940.0, 'Celebrity Cruises', 1),
-        ('AEGEAN GLORY', '8912345', 'Türkiye', 'Cargo', 15420.0, 'Ege Denizcilik A.Ş.', 1),
-        ('IZMIR EXPRESS', '9012456', 'Türkiye', 'Container', 22850.0, 'Arkas Holding', 1)
+        INSERT INTO ships (name, imo_number, flag, ship_type, gross_tonnage, owner, owner_id, is_active) VALUES
+        ('MSC FANTASIA', '9359791', 'Panama', 'Cruise', 137936.0, 'MSC Cruises', 1, 1),
+        ('COSTA SMERALDA', '9785648', 'İtalya', 'Cruise', 185010.0, 'Costa Crociere', 2, 1),
+        ('MEIN SCHIFF 5', '9753208', 'Malta', 'Cruise', 99526.0, 'TUI Cruises', 3, 1),
+        ('MARELLA EXPLORER', '9573398', 'Malta', 'Cruise', 76522.0, 'Marella Cruises', 4, 1),
+        ('VIKING STAR', '9650418', 'Norveç', 'Cruise', 47842.0, 'Viking Ocean Cruises', 5, 1),
+        ('SEABOURN ENCORE', '9713258', 'Bahama', 'Cruise', 40350.0, 'Seabourn Cruise Line', 6, 1),
+        ('NORWEGIAN JADE', '9304057', 'Bahama', 'Cruise', 93558.0, 'Norwegian Cruise Line', 7, 1),
+        ('CELEBRITY INFINITY', '9189421', 'Malta', 'Cruise', 90940.0, 'Celebrity Cruises', 8, 1),
+        ('AEGEAN GLORY', '8912345', 'Türkiye', 'Cargo', 15420.0, 'Ege Denizcilik A.Ş.', 9, 1),
+        ('IZMIR EXPRESS', '9012456', 'Türkiye', 'Container', 22850.0, 'Arkas Holding', 10, 1)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === CATEGORIES (Ürün Kategorileri) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO categories (id, parent_id, code, name_tr, name_en, sort_order) VALUES
+        (1, NULL, 'PROVISIONS', 'Kumanya', 'Provisions', 1),
+        (2, NULL, 'TECHNICAL', 'Teknik Malzeme', 'Technical Stores', 2),
+        (3, NULL, 'DECK_STORES', 'Güverte Malzemeleri', 'Deck Stores', 3),
+        (4, NULL, 'SAFETY', 'Emniyet Ekipmanı', 'Safety Equipment', 4),
+        (5, 1, 'MEAT', 'Et ve Tavuk', 'Meat & Poultry', 1),
+        (6, 1, 'SEAFOOD', 'Deniz Ürünleri', 'Seafood', 2),
+        (7, 1, 'DAIRY', 'Süt Ürünleri', 'Dairy', 3),
+        (8, 7, 'CHEESE', 'Peynir', 'Cheese', 1),
+        (9, 1, 'FRESH_PRODUCE', 'Sebze ve Meyve', 'Fresh Produce', 4),
+        (10, 1, 'DRY_GOODS', 'Kuru Gıda', 'Dry Goods', 5),
+        (11, 2, 'LUBRICANTS', 'Yağlar', 'Lubricants', 1),
+        (12, 2, 'FILTERS', 'Filtreler', 'Filters', 2),
+        (13, 3, 'ROPES', 'Halat ve Teller', 'Ropes & Wires', 1),
+        (14, 3, 'PAINT', 'Boyalar', 'Paints', 2),
+        (15, NULL, 'BONDED_STORES', 'Gümrüksüz Mallar', 'Bonded Stores', 5)
     "#.to_string())).await.map_err(|e| e.to_string())?;
 
     // === SUPPLIERS (Tedarikçiler) ===
     conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
-        INSERT INTO suppliers (name, contact_person, email, phone, address, country, category, is_active) VALUES
-        ('Ege Kumanya Ltd.', 'Mehmet Yılmaz', 'mehmet@egekumanya.com', '+90 256 612 3456', 'Kuşadası Sanayi Sitesi No:45', 'Türkiye', 'PROVISIONS', 1),
-        ('Deniz Gıda A.Ş.', 'Ayşe Kaya', 'ayse@denizgida.com.tr', '+90 232 445 6789', 'Alsancak Liman Cad. No:12', 'Türkiye', 'PROVISIONS', 1),
-        ('Kaptan Teknik', 'Ali Demir', 'ali@kaptanteknik.com', '+90 256 614 2233', 'Kuşadası Marina Karşısı', 'Türkiye', 'TECHNICAL', 1),
-        ('Maritim Supplies', 'Hasan Öztürk', 'hasan@maritimsupplies.com', '+90 232 421 5566', 'İzmir Atatürk OSB', 'Türkiye', 'DECK_STORES', 1),
-        ('Akdeniz Et Ürünleri', 'Fatma Çelik', 'fatma@akdenizet.com', '+90 256 618 9900', 'Söke Organize Sanayi', 'Türkiye', 'PROVISIONS', 1),
-        ('Blue Ocean Trading', 'Dimitris Papadopoulos', 'dimitris@blueocean.gr', '+30 210 455 7788', 'Piraeus Port Area', 'Yunanistan', 'PROVISIONS', 1),
-        ('Aegean Fresh Produce', 'Maria Konstantinou', 'maria@aegeanfresh.gr', '+30 210 322 4455', 'Athens Central Market', 'Yunanistan', 'PROVISIONS', 1),
-        ('İzmir Safety Equipment', 'Kemal Arslan', 'kemal@izmirsafety.com', '+90 232 458 1122', 'Kemalpaşa OSB', 'Türkiye', 'SAFETY', 1)
+        INSERT INTO suppliers (name, contact_person, email, phone, address, country, category, lead_time_days, is_active) VALUES
+        ('Ege Kumanya Ltd.', 'Mehmet Yılmaz', 'mehmet@egekumanya.com', '+90 256 612 3456', 'Kuşadası Sanayi Sitesi No:45', 'Türkiye', 'PROVISIONS', 1, 1),
+        ('Deniz Gıda A.Ş.', 'Ayşe Kaya', 'ayse@denizgida.com.tr', '+90 232 445 6789', 'Alsancak Liman Cad. No:12', 'Türkiye', 'PROVISIONS', 2, 1),
+        ('Kaptan Teknik', 'Ali Demir', 'ali@kaptanteknik.com', '+90 256 614 2233', 'Kuşadası Marina Karşısı', 'Türkiye', 'TECHNICAL', 3, 1),
+        ('Maritim Supplies', 'Hasan Öztürk', 'hasan@maritimsupplies.com', '+90 232 421 5566', 'İzmir Atatürk OSB', 'Türkiye', 'DECK_STORES', 5, 1),
+        ('Akdeniz Et Ürünleri', 'Fatma Çelik', 'fatma@akdenizet.com', '+90 256 618 9900', 'Söke Organize Sanayi', 'Türkiye', 'PROVISIONS', 1, 1),
+        ('Blue Ocean Trading', 'Dimitris Papadopoulos', 'dimitris@blueocean.gr', '+30 210 455 7788', 'Piraeus Port Area', 'Yunanistan', 'PROVISIONS', 7, 1),
+        ('Aegean Fresh Produce', 'Maria Konstantinou', 'maria@aegeanfresh.gr', '+30 210 322 4455', 'Athens Central Market', 'Yunanistan', 'PROVISIONS', 4, 1),
+        ('İzmir Safety Equipment', 'Kemal Arslan', 'kemal@izmirsafety.com', '+90 232 458 1122', 'Kemalpaşa OSB', 'Türkiye', 'SAFETY', NULL, 1)
     "#.to_string())).await.map_err(|e| e.to_string())?;
 
     // === SUPPLY_ITEMS (Ürün Kataloğu) ===
     conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
         INSERT INTO supply_items (supplier_id, impa_code, name, description, category, unit, unit_price, currency, minimum_order_quantity, is_available) VALUES
         -- Gıda Ürünleri (Ege Kumanya)
-        (1, '370101', 'Dana Antrikot (Dondurulmuş)', 'Premium kalite dana antrikot, 10kg paket', 'PROVISIONS', 'KG', 185.50, 'TRY', 50, 1),
-        (1, '370201', 'Tavuk But (Dondurulmuş)', 'Bütün tavuk but, 15kg koli', 'PROVISIONS', 'KG', 78.90, 'TRY', 100, 1),
-        (1, '370301', 'Somon Fileto', 'Norveç somonu, vakumlu paket', 'PROVISIONS', 'KG', 420.00, 'TRY', 30, 1),
-        (1, '371001', 'Zeytinyağı (Riviera)', 'Ege bölgesi zeytinyağı, 5L bidon', 'PROVISIONS', 'LT', 380.00, 'TRY', 20, 1),
-        (1, '371101', 'Pirinç Baldo', 'Yerli baldo pirinç, 25kg çuval', 'PROVISIONS', 'KG', 62.50, 'TRY', 100, 1),
+        (1, '370101', 'Dana Antrikot (Dondurulmuş)', 'Premium kalite dana antrikot, 10kg paket', 'MEAT', 'KG', 185.50, 'TRY', 50, 1),
+        (1, '370201', 'Tavuk But (Dondurulmuş)', 'Bütün tavuk but, 15kg koli', 'MEAT', 'KG', 78.90, 'TRY', 100, 1),
+        (1, '370301', 'Somon Fileto', 'Norveç somonu, vakumlu paket', 'SEAFOOD', 'KG', 420.00, 'TRY', 30, 1),
+        (1, '371001', 'Zeytinyağı (Riviera)', 'Ege bölgesi zeytinyağı, 5L bidon', 'DRY_GOODS', 'LT', 380.00, 'TRY', 20, 1),
+        (1, '371101', 'Pirinç Baldo', 'Yerli baldo pirinç, 25kg çuval', 'DRY_GOODS', 'KG', 62.50, 'TRY', 100, 1),
         
         -- Gıda Ürünleri (Deniz Gıda)
-        (2, '370501', 'Karides (Jumbo)', 'Temizlenmiş jumbo karides, IQF', 'PROVISIONS', 'KG', 580.00, 'TRY', 25, 1),
-        (2, '371201', 'Taze Sebze Paketi', 'Mevsim sebzeleri karışık', 'PROVISIONS', 'KG', 45.00, 'TRY', 200, 1),
-        (2, '371301', 'Taze Meyve Paketi', 'Karışık mevsim meyveleri', 'PROVISIONS', 'KG', 65.00, 'TRY', 150, 1),
-        (2, '371401', 'Süt (UHT)', 'Uzun ömürlü süt, 1L', 'PROVISIONS', 'ADET', 28.50, 'TRY', 500, 1),
-        (2, '371501', 'Tereyağı', 'Blok tereyağı, 5kg', 'PROVISIONS', 'KG', 320.00, 'TRY', 30, 1),
+        (2, '370501', 'Karides (Jumbo)', 'Temizlenmiş jumbo karides, IQF', 'SEAFOOD', 'KG', 580.00, 'TRY', 25, 1),
+        (2, '371201', 'Taze Sebze Paketi', 'Mevsim sebzeleri karışık', 'FRESH_PRODUCE', 'KG', 45.00, 'TRY', 200, 1),
+        (2, '371301', 'Taze Meyve Paketi', 'Karışık mevsim meyveleri', 'FRESH_PRODUCE', 'KG', 65.00, 'TRY', 150, 1),
+        (2, '371401', 'Süt (UHT)', 'Uzun ömürlü süt, 1L', 'DAIRY', 'ADET', 28.50, 'TRY', 500, 1),
+        (2, '371501', 'Tereyağı', 'Blok tereyağı, 5kg', 'DAIRY', 'KG', 320.00, 'TRY', 30, 1),
         
         -- Teknik Malzemeler (Kaptan Teknik)
-        (3, '450101', 'Motor Yağı 15W40', 'Deniz motoru yağı, 20L bidon', 'TECHNICAL', 'ADET', 2850.00, 'TRY', 10, 1),
-        (3, '450201', 'Hidrolik Yağı', 'ISO VG 46, 20L bidon', 'TECHNICAL', 'ADET', 1950.00, 'TRY', 10, 1),
-        (3, '450301', 'Gres Yağı', 'Çok amaçlı gres, 18kg kova', 'TECHNICAL', 'ADET', 1450.00, 'TRY', 5, 1),
-        (3, '450401', 'Filtre Seti (Ana Motor)', 'Yağ+yakıt+hava filtresi seti', 'TECHNICAL', 'SET', 4500.00, 'TRY', 2, 1),
+        (3, '450101', 'Motor Yağı 15W40', 'Deniz motoru yağı, 20L bidon', 'LUBRICANTS', 'ADET', 2850.00, 'TRY', 10, 1),
+        (3, '450201', 'Hidrolik Yağı', 'ISO VG 46, 20L bidon', 'LUBRICANTS', 'ADET', 1950.00, 'TRY', 10, 1),
+        (3, '450301', 'Gres Yağı', 'Çok amaçlı gres, 18kg kova', 'LUBRICANTS', 'ADET', 1450.00, 'TRY', 5, 1),
+        (3, '450401', 'Filtre Seti (Ana Motor)', 'Yağ+yakıt+hava filtresi seti', 'FILTERS', 'SET', 4500.00, 'TRY', 2, 1),
         
         -- Güverte Malzemeleri (Maritim Supplies)
-        (4, '470101', 'Manila Halat 24mm', '220m rulo, IMO onaylı', 'DECK_STORES', 'RULO', 8500.00, 'TRY', 2, 1),
-        (4, '470201', 'Çelik Halat 16mm', '6x36 IWRC, 200m', 'DECK_STORES', 'RULO', 12500.00, 'TRY', 1, 1),
-        (4, '470301', 'Koruyucu Boya (Antifouling)', '20L kova, kırmızı', 'DECK_STORES', 'ADET', 5800.00, 'TRY', 5, 1),
-        (4, '470401', 'Deck Boya (Kaymaz)', '20L kova, gri', 'DECK_STORES', 'ADET', 3200.00, 'TRY', 10, 1),
+        (4, '470101', 'Manila Halat 24mm', '220m rulo, IMO onaylı', 'ROPES', 'RULO', 8500.00, 'TRY', 2, 1),
+        (4, '470201', 'Çelik Halat 16mm', '6x36 IWRC, 200m', 'ROPES', 'RULO', 12500.00, 'TRY', 1, 1),
+        (4, '470301', 'Koruyucu Boya (Antifouling)', '20L kova, kırmızı', 'PAINT', 'ADET', 5800.00, 'TRY', 5, 1),
+        (4, '470401', 'Deck Boya (Kaymaz)', '20L kova, gri', 'PAINT', 'ADET', 3200.00, 'TRY', 10, 1),
         
         -- Et Ürünleri (Akdeniz Et)
-        (5, '370601', 'Kuzu Pirzola', 'Yerli kuzu, taze', 'PROVISIONS', 'KG', 420.00, 'TRY', 30, 1),
-        (5, '370701', 'Dana Kıyma', 'Taze çekilmiş, yağsız', 'PROVISIONS', 'KG', 195.00, 'TRY', 50, 1),
-        (5, '370801', 'Sucuk (Kangal)', 'Geleneksel Ege sucuğu', 'PROVISIONS', 'KG', 280.00, 'TRY', 20, 1),
+        (5, '370601', 'Kuzu Pirzola', 'Yerli kuzu, taze', 'MEAT', 'KG', 420.00, 'TRY', 30, 1),
+        (5, '370701', 'Dana Kıyma', 'Taze çekilmiş, yağsız', 'MEAT', 'KG', 195.00, 'TRY', 50, 1),
+        (5, '370801', 'Sucuk (Kangal)', 'Geleneksel Ege sucuğu', 'MEAT', 'KG', 280.00, 'TRY', 20, 1),
         
         -- Güvenlik Ekipmanları
         (8, '480101', 'Can Yeleği (SOLAS)', 'IMO onaylı, yetişkin', 'SAFETY', 'ADET', 850.00, 'TRY', 50, 1),
         (8, '480201', 'Yangın Söndürücü 6kg', 'ABC tozlu, IMO onaylı', 'SAFETY', 'ADET', 1200.00, 'TRY', 20, 1),
-        (8, '480301', 'İlk Yardım Seti', 'Gemi tipi, büyük boy', 'SAFETY', 'ADET', 2800.00, 'TRY', 5, 1)
+        (8, '480301', 'İlk Yardım Seti', 'Gemi tipi, büyük boy', 'SAFETY', 'ADET', 2800.00, 'TRY', 5, 1),
+
+        -- Peynir (Deniz Gıda)
+        (2, '371601', 'Kaşar Peyniri', 'Tam yağlı kaşar, 2kg blok', 'CHEESE', 'KG', 240.00, 'TRY', 20, 1),
+
+        -- Başka tedarikçilerden muadiller
+        (5, '370101', 'Dana Antrikot', 'Yerli dana antrikot, vakumlu', 'MEAT', 'KG', 179.00, 'TRY', 40, 1),
+        (6, '370301', 'Norwegian Salmon Fillet', 'Fresh, skin on, 5kg box', 'SEAFOOD', 'KG', 11.50, 'EUR', 20, 1),
+        (5, NULL, 'Piliç But (Taze)', 'Kemikli piliç but, 10kg kasa', 'MEAT', 'KG', 74.50, 'TRY', 50, 1),
+
+        -- Gümrüksüz mallar (Blue Ocean Trading)
+        (6, NULL, 'Viski 12 Yıllık', '1L şişe, transit gemilere gümrüksüz', 'BONDED_STORES', 'ADET', 24.00, 'EUR', 12, 1),
+        (6, NULL, 'Sigara (Karton)', '10 paket x 20 adet, transit gemilere gümrüksüz', 'BONDED_STORES', 'KOLI', 32.00, 'EUR', 10, 1)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === PRICING RULES (Fiyatlandırma Kuralları) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO pricing_rules (name, category, supplier_id, customer_id, markup_percent, min_margin_percent, notes) VALUES
+        ('Genel Kâr Oranı', NULL, NULL, NULL, 20.0, 10.0, 'Varsayılan kural'),
+        ('Kumanya', 'PROVISIONS', NULL, NULL, 25.0, 12.0, NULL),
+        ('Teknik Malzeme', 'TECHNICAL', NULL, NULL, 15.0, 8.0, NULL),
+        ('Emniyet Ekipmanı', 'SAFETY', NULL, NULL, 18.0, NULL, NULL),
+        ('Akdeniz Et - Et Ürünleri', NULL, 5, NULL, 22.0, NULL, 'Tedarikçi iskontosu yansıtılıyor'),
+        ('Seabourn Premium', NULL, NULL, 6, 35.0, 20.0, 'Lüks segment')
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO currency_rounding (currency, increment) VALUES
+        ('TRY', 0.5),
+        ('USD', 0.05),
+        ('EUR', 0.05)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === COMPANY PROFILE (Antet) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO company_profile (id, name, address, phone, email, website, tax_office, tax_number, bank_name, iban, swift) VALUES
+        (1, 'Ege Gemi İkmal Ltd. Şti.', 'Türkmen Mah. Liman Cad. No:24, 09400 Kuşadası / Aydın', '+90 256 614 2200', 'info@egegemiikmal.com.tr', 'www.egegemiikmal.com.tr', 'Kuşadası', '3250987614', 'Türkiye İş Bankası Kuşadası Şubesi', 'TR33 0006 4000 0011 2345 6789 01', 'ISBKTRIS')
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === IMPA CATALOG (Referans Katalog) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO impa_sections (code, name, category) VALUES
+        ('37', 'Kumanya / Provisions', 'PROVISIONS'),
+        ('45', 'Yağlar ve Filtreler / Lubricants & Filters', 'TECHNICAL'),
+        ('47', 'Güverte Malzemeleri / Deck Stores', 'DECK_STORES'),
+        ('48', 'Emniyet Ekipmanı / Safety Equipment', 'SAFETY')
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO impa_catalog (code, description, unit, section) VALUES
+        ('370101', 'Beef, striploin, frozen', 'KG', '37'),
+        ('370201', 'Chicken, legs, frozen', 'KG', '37'),
+        ('370301', 'Salmon, fillet', 'KG', '37'),
+        ('370501', 'Shrimps, jumbo, IQF', 'KG', '37'),
+        ('370601', 'Lamb, chops', 'KG', '37'),
+        ('370701', 'Beef, minced', 'KG', '37'),
+        ('371001', 'Olive oil, extra virgin', 'LT', '37'),
+        ('371101', 'Rice, baldo', 'KG', '37'),
+        ('371401', 'Milk, UHT, 1 ltr', 'ADET', '37'),
+        ('371501', 'Butter, block', 'KG', '37'),
+        ('371601', 'Cheese, kashar', 'KG', '37'),
+        ('450101', 'Engine oil, SAE 15W40', 'ADET', '45'),
+        ('450201', 'Hydraulic oil, ISO VG 46', 'ADET', '45'),
+        ('450301', 'Grease, multipurpose', 'ADET', '45'),
+        ('470101', 'Rope, manila, 24mm', 'RULO', '47'),
+        ('470201', 'Wire rope, 16mm, 6x36 IWRC', 'RULO', '47'),
+        ('470301', 'Paint, antifouling', 'ADET', '47'),
+        ('480101', 'Lifejacket, adult, SOLAS', 'ADET', '48'),
+        ('480201', 'Fire extinguisher, dry powder 6kg', 'ADET', '48'),
+        ('480301', 'First aid kit, ship type', 'ADET', '48')
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // Suppliers, items, rules and IMPA sections above use category codes
+    services::category_service::link_legacy_categories(&conn).await.map_err(|e| e.to_string())?;
+
+    // === PRICE HISTORY (Fiyat Geçmişi) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO supply_item_prices (supply_item_id, effective_date, old_price, old_currency, new_price, currency, source, reference) VALUES
+        (1, '2026-01-05', NULL, NULL, 168.00, 'TRY', 'MANUAL', NULL),
+        (1, '2026-04-01', 168.00, 'TRY', 176.50, 'TRY', 'PRICE_LIST', 'ege_kumanya_2026_04.xlsx'),
+        (1, '2026-07-01', 176.50, 'TRY', 185.50, 'TRY', 'PRICE_LIST', 'ege_kumanya_2026_07.xlsx'),
+        (3, '2026-01-05', NULL, NULL, 395.00, 'TRY', 'MANUAL', NULL),
+        (3, '2026-06-15', 395.00, 'TRY', 420.00, 'TRY', 'MANUAL', NULL),
+        (10, '2026-01-10', NULL, NULL, 260.00, 'TRY', 'MANUAL', NULL),
+        (10, '2026-05-02', 260.00, 'TRY', 320.00, 'TRY', 'MANUAL', NULL),
+        (11, '2026-02-01', NULL, NULL, 2990.00, 'TRY', 'MANUAL', NULL),
+        (11, '2026-08-20', 2990.00, 'TRY', 2850.00, 'TRY', 'MANUAL', NULL)
     "#.to_string())).await.map_err(|e| e.to_string())?;
 
+    // Remaining items start with their current price
+    crate::database::record_missing_prices(&conn).await.map_err(|e| e.to_string())?;
+
     // === STOCK (Depo Stokları) ===
     conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
         INSERT INTO stock (supply_item_id, quantity, unit, warehouse_location, minimum_quantity) VALUES
@@ -707,7 +2114,65 @@ pub async fn load_seed_data() -> Result<String, String> {
         (12, 40.0, 'ADET', 'Teknik Depo C1', 10.0),
         (15, 10.0, 'RULO', 'Güverte Deposu D1', 3.0),
         (22, 100.0, 'ADET', 'Güvenlik Deposu E1', 30.0),
-        (23, 50.0, 'ADET', 'Güvenlik Deposu E1', 15.0)
+        (23, 50.0, 'ADET', 'Güvenlik Deposu E1', 15.0),
+        (26, 120.0, 'KG', 'Soğuk Depo A2', 40.0),
+        (28, 60.0, 'KG', 'Soğuk Depo A2', 20.0)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === PACK SIZES (Ambalaj İçerikleri) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO pack_sizes (supply_item_id, pack_unit, quantity, content_unit) VALUES
+        (4, 'BIDON', 5.0, 'LT'),
+        (9, 'KOLI', 12.0, 'ADET'),
+        (11, 'ADET', 20.0, 'LT'),
+        (15, 'RULO', 220.0, 'M'),
+        (22, 'KOLI', 10.0, 'ADET')
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === REGULATED ITEMS (Tehlikeli Maddeler, Reçeteli İlaçlar) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        UPDATE supply_items SET un_number = 'UN1263', hazard_class = '3', sds_required = 1 WHERE id IN (17, 18)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        UPDATE supply_items SET un_number = 'UN1044', hazard_class = '2.2', sds_required = 1 WHERE id = 23
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        UPDATE supply_items SET sds_required = 1 WHERE id = 11
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        UPDATE supply_items SET prescription_only = 1 WHERE id = 24
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === BONDED STORES (Antrepo - Gümrüksüz Mallar) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        UPDATE supply_items SET is_bonded = 1 WHERE id IN (29, 30)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO stock (supply_item_id, quantity, unit, warehouse_location, warehouse_type, minimum_quantity) VALUES
+        (29, 48.0, 'ADET', 'Antrepo G1', 'BONDED', 12.0),
+        (30, 20.0, 'KOLI', 'Antrepo G1', 'BONDED', 5.0)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO stock_movements (stock_id, movement_type, quantity, unit, reference_type, reference_id, reference_info, customs_declaration_number, created_at)
+        SELECT s.id, 'IN', s.quantity, s.unit, 'supplier', 6, 'Tedarikçi: Blue Ocean Trading', '26350300AN000412', '2026-09-14 10:30:00'
+        FROM stock s WHERE s.warehouse_type = 'BONDED'
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // === EQUIVALENCE GROUPS (Muadil Ürünler) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO equivalence_groups (name, notes) VALUES
+        ('Tavuk But', 'Dondurulmuş ve taze piliç but birbirinin yerine verilebilir')
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO equivalence_group_items (group_id, supply_item_id) VALUES
+        (1, 2),
+        (1, 28)
     "#.to_string())).await.map_err(|e| e.to_string())?;
 
     // === SHIP_VISITS (Gemi Ziyaretleri - Yaklaşan) ===
@@ -781,7 +2246,35 @@ pub async fn load_seed_data() -> Result<String, String> {
         (7, 'Kuzu Pirzola', '370601', 'New Zealand lamb', 25.0, 'KG', 14.00, 21.00, 'USD', 'VIA_WAREHOUSE', 'Import')
     "#.to_string())).await.map_err(|e| e.to_string())?;
 
-    Ok("Demo verileri başarıyla yüklendi! 🚢\n\n• 5 Liman (Egeport, Alsancak, Çeşme, Bodrum, Pire)\n• 10 Gemi (Cruise ve Kargo)\n• 8 Tedarikçi\n• 24 Ürün\n• 10 Gemi Ziyareti\n• 7 Sipariş\n• 26 Sipariş Kalemi".to_string())
+    // === ORDER_CHARGES (Sipariş Masrafları) ===
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, r#"
+        INSERT INTO order_charges (order_id, charge_type, description, billing, markup_percent, cost_amount, revenue_amount, notes) VALUES
+        (1, 'LAUNCH_BOAT', 'Demirdeki gemiye bot ile teslimat', 'MARKUP', 20.0, 6500.00, 7800.00, NULL),
+        (1, 'PORT_FEE', 'Egeport liman giriş ücreti', 'PASS_THROUGH', NULL, 1200.00, 1200.00, NULL),
+        (2, 'TRUCK_DELIVERY', 'Soğutmalı kamyon', 'PASS_THROUGH', NULL, 3500.00, 3500.00, NULL),
+        (2, 'AGENCY_COMMISSION', 'Ege Marine Agency komisyonu', 'ABSORBED', NULL, 2500.00, 0.0, NULL),
+        (3, 'AFTER_HOURS', 'Gece teslimatı ek ücreti', 'MARKUP', 25.0, 2000.00, 2500.00, 'Acil teslimat'),
+        (5, 'DISCOUNT', 'Sadakat indirimi', 'PASS_THROUGH', NULL, 0.0, -5000.00, NULL)
+    "#.to_string())).await.map_err(|e| e.to_string())?;
+
+    // Orders inherit customer and payment terms from their ship
+    crate::database::link_customers(&conn).await.map_err(|e| e.to_string())?;
+    // Demo suppliers, items and rules carry the category as text
+    services::category_service::link_legacy_categories(&conn).await.map_err(|e| e.to_string())?;
+
+    // === LANDED COSTS (İthalat Navlunu) ===
+    services::landed_cost_service::create(CreateLandedCostRequest {
+        order_id: 7,
+        stock_movement_id: None,
+        cost_type: LandedCostType::Freight,
+        description: Some("Soğuk zincir hava kargo".to_string()),
+        amount: 120.0,
+        allocation_method: AllocationMethod::Weight,
+        reference: Some("AWB 235-11840021".to_string()),
+        weights: Vec::new(),
+    }).await.map_err(|e| e.to_string())?;
+
+    Ok("Demo verileri başarıyla yüklendi! 🚢\n\n• 5 Liman (Egeport, Alsancak, Çeşme, Bodrum, Pire)\n• 10 Müşteri (Armatör)\n• 10 Gemi (Cruise ve Kargo)\n• 8 Tedarikçi\n• 24 Ürün\n• 10 Gemi Ziyareti\n• 7 Sipariş\n• 26 Sipariş Kalemi".to_string())
 }
 
 // ============================================================================
@@ -2,7 +2,7 @@
 //! 
 //! Handles PostgreSQL (remote) and SQLite (local cache) connections.
 
-use sea_orm::{Database, DatabaseConnection, DbErr, ConnectionTrait, Statement, DatabaseBackend};
+use sea_orm::{Database, DatabaseConnection, DbErr, ConnectionTrait, Statement, DatabaseBackend, Value};
 use std::sync::OnceLock;
 use tokio::sync::RwLock;
 use std::path::PathBuf;
@@ -18,6 +18,11 @@ pub fn get_default_db_path() -> PathBuf {
     path
 }
 
+/// Get the directory of the attachment file store, next to the default database
+pub fn get_attachments_dir() -> PathBuf {
+    get_default_db_path().with_file_name("attachments")
+}
+
 /// Initialize SQLite database with default path
 pub async fn init_sqlite() -> Result<(), anyhow::Error> {
     let db_path = get_default_db_path();
@@ -305,40 +310,1053 @@ async fn create_tables(conn: &DatabaseConnection) -> Result<(), DbErr> {
         "CREATE INDEX IF NOT EXISTS idx_ship_visits_status ON ship_visits(status)".to_string()
     )).await?;
 
-    // =========================================================================
-    // MIGRATIONS - Add columns to existing tables
-    // =========================================================================
-    
-    // Add ship_visit_id to orders table if it doesn't exist
-    // SQLite doesn't support IF NOT EXISTS for ADD COLUMN, so we check first
-    let columns_result = conn.query_all(Statement::from_string(
+    // Invoices table (customer receivables)
+    conn.execute(Statement::from_string(
         DatabaseBackend::Sqlite,
-        "PRAGMA table_info(orders)".to_string()
+        r#"
+        CREATE TABLE IF NOT EXISTS invoices (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            invoice_number TEXT UNIQUE NOT NULL,
+            order_id INTEGER NOT NULL,
+            customer_name TEXT NOT NULL,
+            amount REAL NOT NULL,
+            currency TEXT NOT NULL DEFAULT 'USD',
+            issue_date TEXT NOT NULL,
+            due_date TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'OPEN',
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_id) REFERENCES orders(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Payments table (money received from ship owners)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS payments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            customer_name TEXT NOT NULL,
+            amount REAL NOT NULL,
+            currency TEXT NOT NULL DEFAULT 'USD',
+            payment_date TEXT NOT NULL,
+            method TEXT NOT NULL DEFAULT 'BANK_TRANSFER',
+            reference TEXT,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    // Payment allocations table (which invoice a payment settles, and how much)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS payment_allocations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            payment_id INTEGER NOT NULL,
+            invoice_id INTEGER NOT NULL,
+            amount REAL NOT NULL,
+            exchange_rate REAL NOT NULL DEFAULT 1.0,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (payment_id) REFERENCES payments(id) ON DELETE CASCADE,
+            FOREIGN KEY (invoice_id) REFERENCES invoices(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Receivables indexes
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_invoices_order_id ON invoices(order_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_invoices_customer_name ON invoices(customer_name)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_payments_customer_name ON payments(customer_name)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_payment_allocations_invoice_id ON payment_allocations(invoice_id)".to_string()
+    )).await?;
+
+    // Supplier invoices table (payables)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS supplier_invoices (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            supplier_id INTEGER NOT NULL,
+            invoice_number TEXT NOT NULL,
+            order_id INTEGER,
+            currency TEXT NOT NULL DEFAULT 'USD',
+            issue_date TEXT NOT NULL,
+            due_date TEXT NOT NULL,
+            status TEXT NOT NULL DEFAULT 'OPEN',
+            approved_at TEXT,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            UNIQUE (supplier_id, invoice_number),
+            FOREIGN KEY (supplier_id) REFERENCES suppliers(id),
+            FOREIGN KEY (order_id) REFERENCES orders(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Supplier invoice lines table (what the supplier actually billed)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS supplier_invoice_lines (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            supplier_invoice_id INTEGER NOT NULL,
+            order_item_id INTEGER,
+            supply_item_id INTEGER,
+            description TEXT NOT NULL,
+            quantity REAL NOT NULL,
+            unit_price REAL NOT NULL,
+            FOREIGN KEY (supplier_invoice_id) REFERENCES supplier_invoices(id) ON DELETE CASCADE,
+            FOREIGN KEY (order_item_id) REFERENCES order_items(id),
+            FOREIGN KEY (supply_item_id) REFERENCES supply_items(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Supplier payments table (money paid to suppliers)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS supplier_payments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            supplier_invoice_id INTEGER NOT NULL,
+            amount REAL NOT NULL,
+            payment_date TEXT NOT NULL,
+            method TEXT NOT NULL DEFAULT 'BANK_TRANSFER',
+            reference TEXT,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (supplier_invoice_id) REFERENCES supplier_invoices(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Payables indexes
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_supplier_invoices_supplier_id ON supplier_invoices(supplier_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_supplier_invoices_due_date ON supplier_invoices(due_date)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_supplier_invoice_lines_invoice_id ON supplier_invoice_lines(supplier_invoice_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_supplier_payments_invoice_id ON supplier_payments(supplier_invoice_id)".to_string()
+    )).await?;
+
+    // Customers table (ship owners / managers)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS customers (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            legal_name TEXT NOT NULL,
+            tax_number TEXT,
+            tax_office TEXT,
+            billing_address TEXT,
+            country TEXT,
+            payment_terms_days INTEGER NOT NULL DEFAULT 30,
+            credit_limit REAL,
+            default_currency TEXT NOT NULL DEFAULT 'USD',
+            notes TEXT,
+            is_active INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    // Customer contacts table
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS customer_contacts (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            customer_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            role TEXT,
+            email TEXT,
+            phone TEXT,
+            is_primary INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (customer_id) REFERENCES customers(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_customer_contacts_customer_id ON customer_contacts(customer_id)".to_string()
+    )).await?;
+
+    // Price agreements table (customer contracts)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS price_agreements (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            customer_id INTEGER NOT NULL,
+            name TEXT NOT NULL,
+            currency TEXT NOT NULL,
+            valid_from TEXT NOT NULL,
+            valid_to TEXT NOT NULL,
+            notes TEXT,
+            is_active INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (customer_id) REFERENCES customers(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Price agreement lines (item- or IMPA group-level prices / markups)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS price_agreement_lines (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            agreement_id INTEGER NOT NULL,
+            impa_code TEXT,
+            impa_group TEXT,
+            description TEXT,
+            fixed_price REAL,
+            markup_percent REAL,
+            FOREIGN KEY (agreement_id) REFERENCES price_agreements(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_price_agreements_customer_id ON price_agreements(customer_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_price_agreement_lines_agreement_id ON price_agreement_lines(agreement_id)".to_string()
+    )).await?;
+
+    // Order charges (launch boat, delivery, port fees, discounts...)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS order_charges (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            order_id INTEGER NOT NULL,
+            charge_type TEXT NOT NULL,
+            description TEXT,
+            billing TEXT NOT NULL DEFAULT 'PASS_THROUGH',
+            markup_percent REAL,
+            cost_amount REAL NOT NULL DEFAULT 0,
+            revenue_amount REAL NOT NULL DEFAULT 0,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_order_charges_order_id ON order_charges(order_id)".to_string()
+    )).await?;
+
+    // Landed costs (freight, customs, handling on inbound goods)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS landed_costs (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            order_id INTEGER NOT NULL,
+            stock_movement_id INTEGER,
+            cost_type TEXT NOT NULL,
+            description TEXT,
+            amount REAL NOT NULL,
+            allocation_method TEXT NOT NULL,
+            reference TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE,
+            FOREIGN KEY (stock_movement_id) REFERENCES stock_movements(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Landed cost allocated to each order item (stock_id set for warehouse items)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS landed_cost_allocations (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            landed_cost_id INTEGER NOT NULL,
+            order_item_id INTEGER NOT NULL,
+            stock_id INTEGER,
+            basis REAL NOT NULL,
+            amount REAL NOT NULL,
+            FOREIGN KEY (landed_cost_id) REFERENCES landed_costs(id) ON DELETE CASCADE,
+            FOREIGN KEY (order_item_id) REFERENCES order_items(id) ON DELETE CASCADE,
+            FOREIGN KEY (stock_id) REFERENCES stock(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_landed_costs_order_id ON landed_costs(order_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_landed_cost_allocations_order_item_id ON landed_cost_allocations(order_item_id)".to_string()
+    )).await?;
+
+    // Deliveries per order line (partial deliveries)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS order_item_deliveries (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            order_item_id INTEGER NOT NULL,
+            quantity REAL NOT NULL,
+            delivered_at TEXT NOT NULL,
+            ship_visit_id INTEGER,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_item_id) REFERENCES order_items(id) ON DELETE CASCADE,
+            FOREIGN KEY (ship_visit_id) REFERENCES ship_visits(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_order_item_deliveries_order_item_id ON order_item_deliveries(order_item_id)".to_string()
+    )).await?;
+
+    // Delivery notes (signed proof of delivery on board)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS delivery_notes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            note_number TEXT NOT NULL UNIQUE,
+            order_id INTEGER NOT NULL,
+            ship_visit_id INTEGER,
+            status TEXT NOT NULL DEFAULT 'DRAFT',
+            receiver_name TEXT,
+            receiver_rank TEXT,
+            signed_at TEXT,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_id) REFERENCES orders(id),
+            FOREIGN KEY (ship_visit_id) REFERENCES ship_visits(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_delivery_notes_order_id ON delivery_notes(order_id)".to_string()
+    )).await?;
+
+    // Attachments (product photos, signed documents); files live in the attachment store
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS attachments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            entity_type TEXT NOT NULL,
+            entity_id INTEGER NOT NULL,
+            role TEXT,
+            file_name TEXT NOT NULL,
+            mime_type TEXT NOT NULL,
+            content_hash TEXT NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            width INTEGER,
+            height INTEGER,
+            has_thumbnail INTEGER NOT NULL DEFAULT 0,
+            caption TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_attachments_entity ON attachments(entity_type, entity_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE UNIQUE INDEX IF NOT EXISTS idx_attachments_role ON attachments(entity_type, entity_id, role) WHERE role IS NOT NULL".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_attachments_content_hash ON attachments(content_hash)".to_string()
+    )).await?;
+
+    // Returns from ships and the credit notes issued for them
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS order_returns (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            return_number TEXT NOT NULL UNIQUE,
+            order_id INTEGER NOT NULL,
+            return_date TEXT NOT NULL,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_id) REFERENCES orders(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS order_return_lines (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            return_id INTEGER NOT NULL,
+            order_item_id INTEGER NOT NULL,
+            quantity REAL NOT NULL,
+            reason TEXT NOT NULL,
+            restocked INTEGER NOT NULL DEFAULT 0,
+            stock_movement_id INTEGER,
+            credit_amount REAL NOT NULL,
+            cost_amount REAL NOT NULL DEFAULT 0,
+            FOREIGN KEY (return_id) REFERENCES order_returns(id) ON DELETE CASCADE,
+            FOREIGN KEY (order_item_id) REFERENCES order_items(id),
+            FOREIGN KEY (stock_movement_id) REFERENCES stock_movements(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_order_return_lines_order_item_id ON order_return_lines(order_item_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS credit_notes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            credit_note_number TEXT NOT NULL UNIQUE,
+            invoice_id INTEGER NOT NULL,
+            order_id INTEGER NOT NULL,
+            return_id INTEGER,
+            customer_name TEXT NOT NULL,
+            amount REAL NOT NULL,
+            currency TEXT NOT NULL,
+            issue_date TEXT NOT NULL,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (invoice_id) REFERENCES invoices(id),
+            FOREIGN KEY (order_id) REFERENCES orders(id),
+            FOREIGN KEY (return_id) REFERENCES order_returns(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_credit_notes_invoice_id ON credit_notes(invoice_id)".to_string()
+    )).await?;
+
+    // Requisition templates (recurring provision lists per ship or ship type)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS requisition_templates (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            ship_id INTEGER,
+            ship_type TEXT,
+            base_headcount REAL,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (ship_id) REFERENCES ships(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS requisition_template_lines (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            template_id INTEGER NOT NULL,
+            product_name TEXT NOT NULL,
+            impa_code TEXT,
+            description TEXT,
+            quantity REAL NOT NULL,
+            unit TEXT NOT NULL,
+            notes TEXT,
+            FOREIGN KEY (template_id) REFERENCES requisition_templates(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    // Order splits and merges (audit trail between the orders involved)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS order_links (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            link_type TEXT NOT NULL,
+            source_order_id INTEGER NOT NULL,
+            target_order_id INTEGER NOT NULL,
+            order_item_ids TEXT NOT NULL DEFAULT '',
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (source_order_id) REFERENCES orders(id) ON DELETE CASCADE,
+            FOREIGN KEY (target_order_id) REFERENCES orders(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_order_links_source ON order_links(source_order_id)".to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_order_links_target ON order_links(target_order_id)".to_string()
+    )).await?;
+
+    // Company letterhead (single row, printed on every document)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS company_profile (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            name TEXT NOT NULL,
+            address TEXT,
+            phone TEXT,
+            email TEXT,
+            website TEXT,
+            tax_office TEXT,
+            tax_number TEXT,
+            bank_name TEXT,
+            iban TEXT,
+            swift TEXT,
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    // Product category tree (Kumanya > Süt Ürünleri > Peynir)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS categories (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            parent_id INTEGER,
+            code TEXT UNIQUE NOT NULL,
+            name_tr TEXT NOT NULL,
+            name_en TEXT,
+            sort_order INTEGER NOT NULL DEFAULT 0,
+            is_active INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (parent_id) REFERENCES categories(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_categories_parent_id ON categories(parent_id)".to_string()
+    )).await?;
+
+    // IMPA Marine Stores Guide reference (sections and codes)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS impa_sections (
+            code TEXT PRIMARY KEY,
+            name TEXT,
+            category TEXT
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS impa_catalog (
+            code TEXT PRIMARY KEY,
+            description TEXT NOT NULL,
+            unit TEXT,
+            section TEXT NOT NULL,
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_impa_catalog_section ON impa_catalog(section)".to_string()
+    )).await?;
+
+    // Supply item price history (every unit_price change)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS supply_item_prices (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            supply_item_id INTEGER NOT NULL,
+            effective_date TEXT NOT NULL,
+            old_price REAL,
+            old_currency TEXT,
+            new_price REAL NOT NULL,
+            currency TEXT NOT NULL,
+            source TEXT NOT NULL DEFAULT 'MANUAL',
+            reference TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (supply_item_id) REFERENCES supply_items(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_supply_item_prices_item ON supply_item_prices(supply_item_id, effective_date)".to_string()
+    )).await?;
+
+    // Equivalence groups (supply items linked by hand as interchangeable)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS equivalence_groups (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            notes TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS equivalence_group_items (
+            group_id INTEGER NOT NULL,
+            supply_item_id INTEGER NOT NULL UNIQUE,
+            FOREIGN KEY (group_id) REFERENCES equivalence_groups(id) ON DELETE CASCADE,
+            FOREIGN KEY (supply_item_id) REFERENCES supply_items(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_equivalence_group_items_group ON equivalence_group_items(group_id)".to_string()
+    )).await?;
+
+    // Units of measure (codes, conversion factors and accepted spellings)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS units (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            code TEXT NOT NULL UNIQUE,
+            name_tr TEXT NOT NULL,
+            name_en TEXT,
+            dimension TEXT NOT NULL,
+            factor REAL NOT NULL DEFAULT 1,
+            is_active INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS unit_aliases (
+            alias TEXT PRIMARY KEY,
+            unit_id INTEGER NOT NULL,
+            FOREIGN KEY (unit_id) REFERENCES units(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    // Pack sizes per supply item (1 KOLI = 24 ADET)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS pack_sizes (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            supply_item_id INTEGER NOT NULL,
+            pack_unit TEXT NOT NULL,
+            quantity REAL NOT NULL,
+            content_unit TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            UNIQUE (supply_item_id, pack_unit),
+            FOREIGN KEY (supply_item_id) REFERENCES supply_items(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    // Pharmacist sign-offs of prescription-only order lines
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS pharmacist_signoffs (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            order_item_id INTEGER NOT NULL UNIQUE,
+            pharmacist_name TEXT NOT NULL,
+            license_number TEXT NOT NULL,
+            signed_quantity REAL NOT NULL,
+            notes TEXT,
+            signed_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (order_item_id) REFERENCES order_items(id) ON DELETE CASCADE
+        )
+        "#.to_string()
+    )).await?;
+
+    // Pricing rules (markup / margin floor per category, supplier, customer)
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS pricing_rules (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            category TEXT,
+            supplier_id INTEGER,
+            customer_id INTEGER,
+            markup_percent REAL,
+            min_margin_percent REAL,
+            notes TEXT,
+            is_active INTEGER NOT NULL DEFAULT 1,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (supplier_id) REFERENCES suppliers(id),
+            FOREIGN KEY (customer_id) REFERENCES customers(id)
+        )
+        "#.to_string()
+    )).await?;
+
+    // Selling price rounding per currency
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS currency_rounding (
+            currency TEXT PRIMARY KEY,
+            increment REAL NOT NULL
+        )
+        "#.to_string()
+    )).await?;
+
+    // =========================================================================
+    // MIGRATIONS - Add columns to existing tables
+    // =========================================================================
+    
+    // Add ship_visit_id to orders table if it doesn't exist
+    // SQLite doesn't support IF NOT EXISTS for ADD COLUMN, so we check first
+    let columns_result = conn.query_all(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "PRAGMA table_info(orders)".to_string()
+    )).await?;
+    
+    let has_ship_visit_id = columns_result.iter().any(|row| {
+        use sea_orm::QueryResult;
+        row.try_get::<String>("", "name")
+            .map(|name| name == "ship_visit_id")
+            .unwrap_or(false)
+    });
+    
+    if !has_ship_visit_id {
+        tracing::info!("Adding ship_visit_id column to orders table...");
+        conn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            "ALTER TABLE orders ADD COLUMN ship_visit_id INTEGER REFERENCES ship_visits(id)".to_string()
+        )).await?;
+        
+        // Create index for the new column
+        conn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            "CREATE INDEX IF NOT EXISTS idx_orders_ship_visit_id ON orders(ship_visit_id)".to_string()
+        )).await?;
+        tracing::info!("Migration complete: ship_visit_id added to orders");
+    }
+
+    // One-off data migrations, recorded so they run once per database
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        CREATE TABLE IF NOT EXISTS schema_migrations (
+            name TEXT PRIMARY KEY,
+            applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#.to_string()
+    )).await?;
+
+    // Customer links (ships.owner was free text before customers existed)
+    add_column_if_missing(conn, "ships", "owner_id", "INTEGER REFERENCES customers(id)").await?;
+    add_column_if_missing(conn, "orders", "customer_id", "INTEGER REFERENCES customers(id)").await?;
+    add_column_if_missing(conn, "orders", "payment_terms_days", "INTEGER").await?;
+    add_column_if_missing(conn, "invoices", "customer_id", "INTEGER REFERENCES customers(id)").await?;
+    add_column_if_missing(conn, "payments", "customer_id", "INTEGER REFERENCES customers(id)").await?;
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_payments_customer_id ON payments(customer_id)".to_string()
+    )).await?;
+    if !migration_applied(conn, "link_customers").await? {
+        link_customers(conn).await?;
+        mark_migration_applied(conn, "link_customers").await?;
+    }
+
+    // Partial deliveries and back-orders
+    add_column_if_missing(conn, "order_items", "rolled_over_quantity", "REAL NOT NULL DEFAULT 0").await?;
+    add_column_if_missing(conn, "order_items", "closed_quantity", "REAL NOT NULL DEFAULT 0").await?;
+    add_column_if_missing(conn, "order_items", "closed_at", "TEXT").await?;
+    add_column_if_missing(conn, "order_items", "close_reason", "TEXT").await?;
+    add_column_if_missing(conn, "order_items", "backorder_of_item_id", "INTEGER REFERENCES order_items(id)").await?;
+    add_column_if_missing(conn, "orders", "parent_order_id", "INTEGER REFERENCES orders(id)").await?;
+
+    // Orders delivered before per-line tracking count as delivered in full
+    if !migration_applied(conn, "backfill_deliveries").await? {
+        conn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO order_item_deliveries (order_item_id, quantity, delivered_at, ship_visit_id)
+            SELECT oi.id, oi.quantity, COALESCE(oi.ship_delivery_date, o.updated_at), o.ship_visit_id
+            FROM order_items oi
+            JOIN orders o ON oi.order_id = o.id
+            WHERE o.status IN ('DELIVERED', 'INVOICED') AND oi.quantity > 0
+              AND NOT EXISTS (SELECT 1 FROM order_item_deliveries d WHERE d.order_item_id = oi.id)
+            "#.to_string()
+        )).await?;
+        mark_migration_applied(conn, "backfill_deliveries").await?;
+    }
+
+    // Delivery notes: deliveries are signed for on a note, or the order is waived
+    add_column_if_missing(conn, "order_item_deliveries", "delivery_note_id", "INTEGER REFERENCES delivery_notes(id)").await?;
+    add_column_if_missing(conn, "orders", "delivery_note_waiver", "TEXT").await?;
+
+    // Orders delivered before delivery notes existed keep their status
+    if !migration_applied(conn, "waive_legacy_deliveries").await? {
+        conn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            r#"
+            UPDATE orders SET delivery_note_waiver = 'Delivered before delivery notes were recorded'
+            WHERE status IN ('DELIVERED', 'INVOICED') AND delivery_note_waiver IS NULL
+              AND NOT EXISTS (SELECT 1 FROM delivery_notes dn WHERE dn.order_id = orders.id)
+            "#.to_string()
+        )).await?;
+        mark_migration_applied(conn, "waive_legacy_deliveries").await?;
+    }
+
+    // Categories were free text before the category tree existed
+    add_column_if_missing(conn, "suppliers", "category_id", "INTEGER REFERENCES categories(id)").await?;
+    add_column_if_missing(conn, "supply_items", "category_id", "INTEGER REFERENCES categories(id)").await?;
+    add_column_if_missing(conn, "pricing_rules", "category_id", "INTEGER REFERENCES categories(id)").await?;
+    add_column_if_missing(conn, "impa_sections", "category_id", "INTEGER REFERENCES categories(id)").await?;
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "CREATE INDEX IF NOT EXISTS idx_supply_items_category_id ON supply_items(category_id)".to_string()
+    )).await?;
+    if !migration_applied(conn, "link_legacy_categories").await? {
+        crate::services::category_service::link_legacy_categories(conn).await?;
+        mark_migration_applied(conn, "link_legacy_categories").await?;
+    }
+
+    // Supplier article numbers (matched by price list imports)
+    add_column_if_missing(conn, "supply_items", "supplier_sku", "TEXT").await?;
+
+    // Items priced before price history existed start with their current price
+    record_missing_prices(conn).await?;
+
+    // Supplier lead times (shown when comparing alternatives)
+    add_column_if_missing(conn, "suppliers", "lead_time_days", "INTEGER").await?;
+
+    // Stock movements entered in another unit than the stock keep what was entered
+    add_column_if_missing(conn, "stock_movements", "entered_quantity", "REAL").await?;
+    add_column_if_missing(conn, "stock_movements", "entered_unit", "TEXT").await?;
+    crate::services::unit_service::register_default_units(conn).await?;
+
+    // Dangerous goods and prescription-only medicines
+    add_column_if_missing(conn, "supply_items", "un_number", "TEXT").await?;
+    add_column_if_missing(conn, "supply_items", "hazard_class", "TEXT").await?;
+    add_column_if_missing(conn, "supply_items", "sds_required", "INTEGER NOT NULL DEFAULT 0").await?;
+    add_column_if_missing(conn, "supply_items", "prescription_only", "INTEGER NOT NULL DEFAULT 0").await?;
+
+    // Bonded (duty-free) stores held under customs supervision
+    add_column_if_missing(conn, "supply_items", "is_bonded", "INTEGER NOT NULL DEFAULT 0").await?;
+    add_column_if_missing(conn, "stock", "warehouse_type", "TEXT NOT NULL DEFAULT 'GENERAL'").await?;
+    add_column_if_missing(conn, "stock_movements", "customs_declaration_number", "TEXT").await?;
+    add_column_if_missing(conn, "ports", "is_international", "INTEGER NOT NULL DEFAULT 0").await?;
+
+    // Goods receipts name the order line they fill (matched against supplier invoices)
+    add_column_if_missing(conn, "stock_movements", "order_item_id", "INTEGER REFERENCES order_items(id)").await?;
+
+    // Full-text search index over catalog, suppliers, ships and orders
+    crate::services::search_service::build_index(conn).await?;
+
+    // Attachments of deleted records; signature BLOBs of older databases go to the file store
+    crate::services::attachment_service::install_triggers(conn).await?;
+    crate::services::attachment_service::migrate_delivery_note_attachments(conn).await?;
+
+    tracing::info!("SQLite tables created successfully");
+    Ok(())
+}
+
+/// Add a column to a table unless it already exists
+async fn add_column_if_missing(conn: &DatabaseConnection, table: &str, column: &str, definition: &str) -> Result<(), DbErr> {
+    use sea_orm::QueryResult;
+
+    let columns = conn.query_all(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        format!("PRAGMA table_info({})", table)
+    )).await?;
+
+    let exists = columns.iter().any(|row: &QueryResult| {
+        row.try_get::<String>("", "name")
+            .map(|name| name == column)
+            .unwrap_or(false)
+    });
+
+    if !exists {
+        tracing::info!("Adding {} column to {} table...", column, table);
+        conn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, definition)
+        )).await?;
+    }
+    Ok(())
+}
+
+/// Whether a one-off data migration has already run on this database
+pub(crate) async fn migration_applied<C: ConnectionTrait>(conn: &C, name: &str) -> Result<bool, DbErr> {
+    let row = conn.query_one(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT 1 FROM schema_migrations WHERE name = ?",
+        vec![Value::String(Some(Box::new(name.to_string())))]
+    )).await?;
+    Ok(row.is_some())
+}
+
+/// Record a one-off data migration as applied
+pub(crate) async fn mark_migration_applied<C: ConnectionTrait>(conn: &C, name: &str) -> Result<(), DbErr> {
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT OR REPLACE INTO schema_migrations (name, applied_at) VALUES (?, datetime('now'))",
+        vec![Value::String(Some(Box::new(name.to_string())))]
+    )).await?;
+    Ok(())
+}
+
+/// Create customer accounts for free-text ship owners and link ships, orders,
+/// invoices and payments to them. Owner names are matched case-insensitively, so
+/// "MSC Cruises" and "msc cruises " end up on the same account.
+pub(crate) async fn link_customers(conn: &DatabaseConnection) -> Result<(), DbErr> {
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO customers (legal_name)
+        SELECT MIN(TRIM(s.owner)) FROM ships s
+        WHERE s.owner_id IS NULL AND TRIM(COALESCE(s.owner, '')) != ''
+          AND NOT EXISTS (SELECT 1 FROM customers c WHERE c.legal_name = TRIM(s.owner) COLLATE NOCASE)
+        GROUP BY TRIM(s.owner) COLLATE NOCASE
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE ships SET
+            owner_id = (SELECT MIN(c.id) FROM customers c WHERE c.legal_name = TRIM(ships.owner) COLLATE NOCASE)
+        WHERE owner_id IS NULL AND TRIM(COALESCE(owner, '')) != ''
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE ships SET owner = (SELECT c.legal_name FROM customers c WHERE c.id = ships.owner_id)
+        WHERE owner_id IS NOT NULL
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE orders SET
+            customer_id = (SELECT s.owner_id FROM ships s WHERE s.id = orders.ship_id),
+            payment_terms_days = COALESCE(payment_terms_days, (
+                SELECT c.payment_terms_days FROM ships s JOIN customers c ON s.owner_id = c.id
+                WHERE s.id = orders.ship_id))
+        WHERE customer_id IS NULL
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE invoices SET
+            customer_id = (SELECT MIN(c.id) FROM customers c WHERE c.legal_name = invoices.customer_name COLLATE NOCASE)
+        WHERE customer_id IS NULL
+        "#.to_string()
+    )).await?;
+
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE payments SET
+            customer_id = (SELECT MIN(c.id) FROM customers c WHERE c.legal_name = payments.customer_name COLLATE NOCASE)
+        WHERE customer_id IS NULL
+        "#.to_string()
+    )).await?;
+
+    Ok(())
+}
+
+/// Give supply items without price history an opening entry with their
+/// current price, effective from their last update.
+pub(crate) async fn record_missing_prices(conn: &DatabaseConnection) -> Result<(), DbErr> {
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO supply_item_prices (supply_item_id, effective_date, new_price, currency, source)
+        SELECT si.id, date(si.updated_at), si.unit_price, si.currency, 'MANUAL'
+        FROM supply_items si
+        WHERE NOT EXISTS (SELECT 1 FROM supply_item_prices p WHERE p.supply_item_id = si.id)
+        "#.to_string()
     )).await?;
-    
-    let has_ship_visit_id = columns_result.iter().any(|row| {
-        use sea_orm::QueryResult;
-        row.try_get::<String>("", "name")
-            .map(|name| name == "ship_visit_id")
-            .unwrap_or(false)
-    });
-    
-    if !has_ship_visit_id {
-        tracing::info!("Adding ship_visit_id column to orders table...");
-        conn.execute(Statement::from_string(
-            DatabaseBackend::Sqlite,
-            "ALTER TABLE orders ADD COLUMN ship_visit_id INTEGER REFERENCES ship_visits(id)".to_string()
-        )).await?;
-        
-        // Create index for the new column
-        conn.execute(Statement::from_string(
-            DatabaseBackend::Sqlite,
-            "CREATE INDEX IF NOT EXISTS idx_orders_ship_visit_id ON orders(ship_visit_id)".to_string()
-        )).await?;
-        tracing::info!("Migration complete: ship_visit_id added to orders");
-    }
 
-    tracing::info!("SQLite tables created successfully");
     Ok(())
 }
 
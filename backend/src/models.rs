@@ -77,7 +77,10 @@ pub struct Ship {
     pub flag: String,
     pub ship_type: Option<String>,
     pub gross_tonnage: Option<f64>,
+    /// Owner name as shown on documents (legal name of the linked customer)
     pub owner: Option<String>,
+    /// Customer account that owns / manages the ship
+    pub owner_id: Option<i32>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -90,6 +93,7 @@ pub struct CreateShipRequest {
     pub ship_type: Option<String>,
     pub gross_tonnage: Option<f64>,
     pub owner: Option<String>,
+    pub owner_id: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +104,113 @@ pub struct UpdateShipRequest {
     pub ship_type: Option<String>,
     pub gross_tonnage: Option<f64>,
     pub owner: Option<String>,
+    pub owner_id: Option<i32>,
+}
+
+// ============================================================================
+// Customer Models (Ship Owners / Managers)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Customer {
+    pub id: i32,
+    pub legal_name: String,
+    pub tax_number: Option<String>,
+    pub tax_office: Option<String>,
+    pub billing_address: Option<String>,
+    pub country: Option<String>,
+    /// Days from invoice date until payment is due
+    pub payment_terms_days: i32,
+    /// Maximum open exposure in the default currency (None = no limit)
+    pub credit_limit: Option<f64>,
+    pub default_currency: String,
+    pub notes: Option<String>,
+    pub is_active: bool,
+    pub contacts: Vec<CustomerContact>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerContact {
+    pub id: i32,
+    pub customer_id: i32,
+    pub name: String,
+    /// e.g. "Purchasing", "Superintendent", "Accounting"
+    pub role: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub is_primary: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCustomerContactRequest {
+    pub name: String,
+    pub role: Option<String>,
+    pub email: Option<String>,
+    pub phone: Option<String>,
+    pub is_primary: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCustomerRequest {
+    pub legal_name: String,
+    pub tax_number: Option<String>,
+    pub tax_office: Option<String>,
+    pub billing_address: Option<String>,
+    pub country: Option<String>,
+    /// Defaults to 30 days
+    pub payment_terms_days: Option<i32>,
+    pub credit_limit: Option<f64>,
+    /// Defaults to USD
+    pub default_currency: Option<String>,
+    pub notes: Option<String>,
+    pub contacts: Vec<CreateCustomerContactRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCustomerRequest {
+    pub legal_name: Option<String>,
+    pub tax_number: Option<String>,
+    pub tax_office: Option<String>,
+    pub billing_address: Option<String>,
+    pub country: Option<String>,
+    pub payment_terms_days: Option<i32>,
+    pub credit_limit: Option<f64>,
+    /// Remove the credit limit (`credit_limit` is ignored)
+    pub clear_credit_limit: bool,
+    pub default_currency: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Credit exposure of a customer, optionally including an order under review
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditCheck {
+    pub customer_id: i32,
+    pub customer_name: String,
+    pub credit_limit: Option<f64>,
+    /// Customer's default currency; the limit and the amounts below are in it
+    pub currency: String,
+    /// Unpaid balance of open invoices
+    pub open_receivables: f64,
+    /// Revenue of active orders that are not invoiced yet
+    pub uninvoiced_orders: f64,
+    /// Revenue of the order being checked (0 when it is in another currency)
+    pub order_amount: f64,
+    pub projected_exposure: f64,
+    pub exceeds_limit: bool,
+    /// Exposure in other currencies, not counted against the limit
+    pub other_currencies: Vec<CurrencyExposure>,
+    pub warning: Option<String>,
+}
+
+/// Credit exposure of a customer in one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyExposure {
+    pub currency: String,
+    pub open_receivables: f64,
+    /// Including the order under review when it is in this currency
+    pub uninvoiced_orders: f64,
 }
 
 // ============================================================================
@@ -133,10 +244,19 @@ pub struct Order {
     /// Optional link to a ship visit - when the order will be delivered
     pub ship_visit_id: Option<i32>,
     pub ship_visit_info: Option<String>,
+    /// Customer the order is billed to (owner of the ship at order time)
+    pub customer_id: Option<i32>,
+    pub customer_name: Option<String>,
+    /// Payment terms inherited from the customer when the order was created
+    pub payment_terms_days: Option<i32>,
     pub status: OrderStatus,
     pub delivery_port: Option<String>,
     pub notes: Option<String>,
     pub currency: String,
+    /// Original order when this is a back-order follow-up
+    pub parent_order_id: Option<i32>,
+    /// Reason the order may be marked delivered without a signed delivery note
+    pub delivery_note_waiver: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -146,6 +266,8 @@ pub struct OrderWithItems {
     pub order: Order,
     pub items: Vec<OrderItem>,
     pub totals: OrderTotals,
+    /// Credit position of the order's customer (warning set when over the limit)
+    pub credit_check: Option<CreditCheck>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -155,6 +277,7 @@ pub struct CreateOrderRequest {
     pub ship_visit_id: Option<i32>,
     pub delivery_port: Option<String>,
     pub notes: Option<String>,
+    /// Leave empty to use the customer's default currency
     pub currency: String,
 }
 
@@ -167,6 +290,38 @@ pub struct UpdateOrderRequest {
     pub currency: Option<String>,
 }
 
+/// How two orders are related after a split or a merge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderLinkType {
+    /// Items were moved out of the source into a new target order
+    Split,
+    /// The source order was merged into the target and cancelled
+    Merge,
+}
+
+impl OrderLinkType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            OrderLinkType::Split => "Bölündü",
+            OrderLinkType::Merge => "Birleştirildi",
+        }
+    }
+}
+
+/// Audit record of a split or merge between two orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderLink {
+    pub id: i32,
+    pub link_type: OrderLinkType,
+    pub source_order_id: i32,
+    pub source_order_number: Option<String>,
+    pub target_order_id: i32,
+    pub target_order_number: Option<String>,
+    /// Order items moved from source to target
+    pub order_item_ids: Vec<i32>,
+    pub created_at: String,
+}
+
 // ============================================================================
 // Order Item Models (Critical for profit calculation)
 // ============================================================================
@@ -192,6 +347,81 @@ pub struct OrderItem {
     /// When delivered to ship
     pub ship_delivery_date: Option<String>,
     pub notes: Option<String>,
+    /// Sum of the recorded deliveries
+    pub delivered_quantity: f64,
+    /// Still to be delivered on this order (0 once the line is closed)
+    pub back_ordered_quantity: f64,
+    /// Moved to a follow-up order (no longer part of `quantity`)
+    pub rolled_over_quantity: f64,
+    /// Dropped when the line was closed short (no longer part of `quantity`)
+    pub closed_quantity: f64,
+    pub closed_at: Option<String>,
+    pub close_reason: Option<String>,
+    pub fulfilment: FulfilmentStatus,
+    /// Line of the original order this back-order line comes from
+    pub backorder_of_item_id: Option<i32>,
+    /// Sent back by the ship after delivery (still part of `quantity`)
+    pub returned_quantity: f64,
+}
+
+/// Delivery state of an order line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FulfilmentStatus {
+    Pending,
+    PartiallyDelivered,
+    Delivered,
+    /// Delivered part done, remainder moved to a follow-up order
+    RolledOver,
+    /// Closed short; the remainder will not be delivered
+    Closed,
+}
+
+impl FulfilmentStatus {
+    /// Nothing left to deliver on this order
+    pub fn is_done(&self) -> bool {
+        matches!(self, FulfilmentStatus::Delivered | FulfilmentStatus::RolledOver | FulfilmentStatus::Closed)
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            FulfilmentStatus::Pending => "Bekliyor",
+            FulfilmentStatus::PartiallyDelivered => "Kısmi Teslim",
+            FulfilmentStatus::Delivered => "Teslim Edildi",
+            FulfilmentStatus::RolledOver => "Sonraki Siparişe Aktarıldı",
+            FulfilmentStatus::Closed => "Kapatıldı",
+        }
+    }
+}
+
+/// Delivery event of an order line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemDelivery {
+    pub id: i32,
+    pub order_item_id: i32,
+    pub product_name: Option<String>,
+    pub quantity: f64,
+    pub delivered_at: String,
+    pub ship_visit_id: Option<i32>,
+    pub notes: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryLine {
+    pub order_item_id: i32,
+    pub quantity: f64,
+}
+
+/// Goods handed over to the ship for some lines of an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordDeliveryRequest {
+    pub order_id: i32,
+    /// Defaults to now
+    pub delivered_at: Option<String>,
+    /// Defaults to the order's ship visit
+    pub ship_visit_id: Option<i32>,
+    pub notes: Option<String>,
+    pub lines: Vec<DeliveryLine>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -203,6 +433,7 @@ pub struct CreateOrderItemRequest {
     pub quantity: f64,
     pub unit: String,
     pub buying_price: f64,
+    /// 0 = let the pricing engine propose the price (price agreement, then pricing rules)
     pub selling_price: f64,
     pub currency: String,
     pub delivery_type: DeliveryType,
@@ -226,6 +457,96 @@ pub struct UpdateOrderItemRequest {
     pub notes: Option<String>,
 }
 
+/// Non-item charge on an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChargeType {
+    LaunchBoat,
+    TruckDelivery,
+    AfterHours,
+    PortFee,
+    AgencyCommission,
+    Discount,
+    Other,
+}
+
+impl ChargeType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ChargeType::LaunchBoat => "Bot Kiralama",
+            ChargeType::TruckDelivery => "Kamyon Nakliye",
+            ChargeType::AfterHours => "Mesai Dışı Ek Ücret",
+            ChargeType::PortFee => "Liman Giriş Ücreti",
+            ChargeType::AgencyCommission => "Acente Komisyonu",
+            ChargeType::Discount => "İndirim",
+            ChargeType::Other => "Diğer",
+        }
+    }
+}
+
+/// How a charge is billed to the customer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChargeBilling {
+    /// Billed at cost
+    PassThrough,
+    /// Billed at cost plus `markup_percent`
+    Markup,
+    /// Our cost only, not billed (e.g. agency commission we pay)
+    Absorbed,
+}
+
+impl ChargeBilling {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ChargeBilling::PassThrough => "Maliyetine Yansıtılır",
+            ChargeBilling::Markup => "Kâr Eklenerek Yansıtılır",
+            ChargeBilling::Absorbed => "Yansıtılmaz",
+        }
+    }
+}
+
+/// Charge line of an order, in the order's currency.
+///
+/// A discount has no cost and a negative revenue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderCharge {
+    pub id: i32,
+    pub order_id: i32,
+    pub charge_type: ChargeType,
+    pub description: Option<String>,
+    pub billing: ChargeBilling,
+    pub markup_percent: Option<f64>,
+    /// What the charge costs us
+    pub cost_amount: f64,
+    /// What the customer is billed (negative for discounts)
+    pub revenue_amount: f64,
+    pub currency: String,
+    pub notes: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateOrderChargeRequest {
+    pub order_id: i32,
+    pub charge_type: ChargeType,
+    pub description: Option<String>,
+    /// Cost of the charge; for a discount, the discount given
+    pub amount: f64,
+    /// Ignored for discounts
+    pub billing: ChargeBilling,
+    /// Required when billing is Markup
+    pub markup_percent: Option<f64>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateOrderChargeRequest {
+    pub description: Option<String>,
+    pub amount: Option<f64>,
+    pub billing: Option<ChargeBilling>,
+    pub markup_percent: Option<f64>,
+    pub notes: Option<String>,
+}
+
 // ============================================================================
 // Financial Calculation Models
 // ============================================================================
@@ -233,22 +554,41 @@ pub struct UpdateOrderItemRequest {
 /// Profit calculation for a single item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemProfit {
-    /// Total cost: buying_price * quantity
+    /// Total cost: (buying_price + landed cost per unit) * quantity
     pub total_cost: f64,
     /// Total revenue: selling_price * quantity
     pub total_revenue: f64,
-    /// Gross profit: (selling_price - buying_price) * quantity
+    /// Gross profit: total_revenue - total_cost
     pub gross_profit: f64,
-    /// Margin percentage: ((selling_price - buying_price) / selling_price) * 100
+    /// Margin percentage: ((selling_price - unit cost) / selling_price) * 100
     pub margin_percent: Option<f64>,
+    /// Landed cost (freight, customs, handling) allocated to the line, included in `total_cost`
+    pub landed_cost: f64,
+    /// Minimum margin the pricing rules require for this item, in percent
+    pub min_margin_percent: Option<f64>,
+    /// True when the margin is below `min_margin_percent`
+    pub below_margin_floor: bool,
 }
 
 /// Order totals calculated in Rust
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderTotals {
     pub item_count: i32,
+    pub charge_count: i32,
+    /// Items and charges
     pub total_cost: f64,
+    /// Items and charges (discounts reduce revenue)
     pub total_revenue: f64,
+    /// Part of `total_cost` coming from order charges
+    pub charges_cost: f64,
+    /// Part of `total_revenue` coming from order charges
+    pub charges_revenue: f64,
+    /// Part of `total_cost` coming from landed costs allocated to the items
+    pub landed_cost: f64,
+    /// Revenue given back for returned goods, already deducted from `total_revenue`
+    pub returns_revenue: f64,
+    /// Cost of returned goods put back into stock, already deducted from `total_cost`
+    pub returns_cost: f64,
     pub gross_profit: f64,
     pub margin_percent: Option<f64>,
     pub currency: String,
@@ -278,6 +618,49 @@ pub struct OrderProfitInfo {
     pub currency: String,
 }
 
+// ============================================================================
+// Category Models (Product Category Tree)
+// ============================================================================
+
+/// Node of the product category tree, e.g. Kumanya > Süt Ürünleri > Peynir
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Category {
+    pub id: i32,
+    pub parent_id: Option<i32>,
+    /// Unique code, e.g. "PROVISIONS" or "DAIRY"
+    pub code: String,
+    pub name_tr: String,
+    pub name_en: Option<String>,
+    /// 0 for top-level categories
+    pub depth: i32,
+    /// Turkish names from the root, e.g. "Kumanya > Süt Ürünleri"
+    pub path: String,
+    pub sort_order: i32,
+    pub is_active: bool,
+    /// Supply items filed directly under this category
+    pub item_count: i32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateCategoryRequest {
+    pub parent_id: Option<i32>,
+    pub code: String,
+    pub name_tr: String,
+    pub name_en: Option<String>,
+    pub sort_order: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCategoryRequest {
+    pub code: Option<String>,
+    pub name_tr: Option<String>,
+    pub name_en: Option<String>,
+    pub sort_order: Option<i32>,
+    pub is_active: Option<bool>,
+}
+
 // ============================================================================
 // Supplier Models
 // ============================================================================
@@ -291,7 +674,12 @@ pub struct Supplier {
     pub phone: Option<String>,
     pub address: Option<String>,
     pub country: Option<String>,
+    /// Category code, e.g. "PROVISIONS"
     pub category: String,
+    pub category_id: Option<i32>,
+    pub category_name: Option<String>,
+    /// Usual days from order to delivery at our warehouse
+    pub lead_time_days: Option<i32>,
     pub is_active: bool,
     pub created_at: String,
     pub updated_at: String,
@@ -305,7 +693,8 @@ pub struct CreateSupplierRequest {
     pub phone: Option<String>,
     pub address: Option<String>,
     pub country: Option<String>,
-    pub category: String,
+    pub category_id: i32,
+    pub lead_time_days: Option<i32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -316,7 +705,8 @@ pub struct UpdateSupplierRequest {
     pub phone: Option<String>,
     pub address: Option<String>,
     pub country: Option<String>,
-    pub category: Option<String>,
+    pub category_id: Option<i32>,
+    pub lead_time_days: Option<i32>,
 }
 
 // ============================================================================
@@ -329,14 +719,31 @@ pub struct SupplyItem {
     pub supplier_id: i32,
     pub supplier_name: Option<String>,
     pub impa_code: Option<String>,
+    /// The supplier's own article number
+    pub supplier_sku: Option<String>,
     pub name: String,
     pub description: Option<String>,
+    /// Category code, e.g. "DAIRY"
     pub category: String,
+    pub category_id: Option<i32>,
+    pub category_name: Option<String>,
     pub unit: String,
     pub unit_price: f64,
     pub currency: String,
     pub minimum_order_quantity: Option<i32>,
     pub is_available: bool,
+    /// UN number of a dangerous good, e.g. "UN1263"
+    pub un_number: Option<String>,
+    /// IMDG hazard class, e.g. "3" or "2.2"
+    pub hazard_class: Option<String>,
+    /// A safety data sheet must be on file before the item goes on board
+    pub sds_required: bool,
+    /// Medicine that needs a pharmacist's sign-off on every order
+    pub prescription_only: bool,
+    /// Excise goods (alcohol, tobacco) that may be kept duty-suspended in a bonded warehouse
+    pub is_bonded: bool,
+    /// The safety data sheet, fetched with `get_attachment_data`
+    pub sds_attachment_id: Option<i32>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -345,27 +752,237 @@ pub struct SupplyItem {
 pub struct CreateSupplyItemRequest {
     pub supplier_id: i32,
     pub impa_code: Option<String>,
+    pub supplier_sku: Option<String>,
     pub name: String,
     pub description: Option<String>,
-    pub category: String,
+    /// Filled in from the IMPA section when not given
+    pub category_id: Option<i32>,
     pub unit: String,
     pub unit_price: f64,
     pub currency: String,
     pub minimum_order_quantity: Option<i32>,
+    pub un_number: Option<String>,
+    pub hazard_class: Option<String>,
+    pub sds_required: bool,
+    pub prescription_only: bool,
+    pub is_bonded: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateSupplyItemRequest {
     pub supplier_id: Option<i32>,
     pub impa_code: Option<String>,
+    pub supplier_sku: Option<String>,
     pub name: Option<String>,
     pub description: Option<String>,
-    pub category: Option<String>,
+    pub category_id: Option<i32>,
     pub unit: Option<String>,
     pub unit_price: Option<f64>,
     pub currency: Option<String>,
     pub minimum_order_quantity: Option<i32>,
     pub is_available: Option<bool>,
+    /// Date a price change takes effect (YYYY-MM-DD), defaults to today
+    pub price_effective_date: Option<String>,
+    /// Blank clears the UN number
+    pub un_number: Option<String>,
+    /// Blank clears the hazard class
+    pub hazard_class: Option<String>,
+    pub sds_required: Option<bool>,
+    pub prescription_only: Option<bool>,
+    pub is_bonded: Option<bool>,
+}
+
+// ============================================================================
+// Equivalent Product Models (Same Item Across Suppliers)
+// ============================================================================
+
+/// Supply items linked by hand as interchangeable (items sharing an IMPA code
+/// are equivalent without a group)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquivalenceGroup {
+    pub id: i32,
+    pub name: String,
+    pub notes: Option<String>,
+    pub members: Vec<SupplyItem>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateEquivalenceGroupRequest {
+    pub name: String,
+    pub notes: Option<String>,
+    pub supply_item_ids: Vec<i32>,
+}
+
+/// Why a supply item counts as an alternative
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlternativeLink {
+    SameImpa,
+    Manual,
+}
+
+impl AlternativeLink {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AlternativeLink::SameImpa => "Aynı IMPA Kodu",
+            AlternativeLink::Manual => "Manuel Eşleştirme",
+        }
+    }
+}
+
+/// An equivalent supply item of another (or the same) supplier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProductAlternative {
+    pub supply_item_id: i32,
+    pub name: String,
+    pub impa_code: Option<String>,
+    pub supplier_sku: Option<String>,
+    pub supplier_id: i32,
+    pub supplier_name: Option<String>,
+    pub unit: String,
+    pub unit_price: f64,
+    pub currency: String,
+    pub is_available: bool,
+    /// Quantity in our warehouse
+    pub stock_quantity: f64,
+    pub lead_time_days: Option<i32>,
+    pub linked_by: AlternativeLink,
+}
+
+// ============================================================================
+// Supply Item Price History Models
+// ============================================================================
+
+/// Where a supply item price change came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceChangeSource {
+    /// Edited by hand on the supply item
+    Manual,
+    /// Supplier price list import
+    PriceListImport,
+    /// Awarded quotation (RFQ)
+    RfqAward,
+}
+
+impl PriceChangeSource {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PriceChangeSource::Manual => "Elle Giriş",
+            PriceChangeSource::PriceListImport => "Fiyat Listesi",
+            PriceChangeSource::RfqAward => "Teklif (RFQ)",
+        }
+    }
+}
+
+/// One supply item price change; the first entry of an item has no old price
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceChange {
+    pub id: i32,
+    pub supply_item_id: i32,
+    /// YYYY-MM-DD
+    pub effective_date: String,
+    pub old_price: Option<f64>,
+    pub old_currency: Option<String>,
+    pub new_price: f64,
+    pub currency: String,
+    /// Percent change, when old and new price share a currency
+    pub change_percent: Option<f64>,
+    pub source: PriceChangeSource,
+    /// Price list file name, RFQ number, ...
+    pub reference: Option<String>,
+    pub created_at: String,
+}
+
+/// Supply item whose price rose over a period
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceRiseItem {
+    pub supply_item_id: i32,
+    pub supply_item_name: String,
+    pub impa_code: Option<String>,
+    pub supplier_id: i32,
+    pub supplier_name: Option<String>,
+    pub currency: String,
+    /// Price in effect at the start of the period
+    pub start_price: f64,
+    /// Price in effect at the end of the period
+    pub end_price: f64,
+    pub change_percent: f64,
+    /// Price changes inside the period
+    pub change_count: i32,
+    pub last_change_date: String,
+}
+
+// ============================================================================
+// Unit of Measure Models
+// ============================================================================
+
+/// What a unit measures; units convert freely only within a dimension
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnitDimension {
+    Mass,
+    Volume,
+    Length,
+    Count,
+    /// Box, crate, roll... - converts only through an item's pack size
+    Pack,
+}
+
+impl UnitDimension {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            UnitDimension::Mass => "Ağırlık",
+            UnitDimension::Volume => "Hacim",
+            UnitDimension::Length => "Uzunluk",
+            UnitDimension::Count => "Adet",
+            UnitDimension::Pack => "Ambalaj",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnitOfMeasure {
+    pub id: i32,
+    /// e.g. "KG"
+    pub code: String,
+    pub name_tr: String,
+    pub name_en: Option<String>,
+    pub dimension: UnitDimension,
+    /// Size in the dimension's base unit (KG, LT, M, ADET); 1 for the base unit and packs
+    pub factor: f64,
+    /// Other spellings accepted for this unit, e.g. "PCS" for "ADET"
+    pub aliases: Vec<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUnitRequest {
+    pub code: String,
+    pub name_tr: String,
+    pub name_en: Option<String>,
+    pub dimension: UnitDimension,
+    pub factor: f64,
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateUnitRequest {
+    pub name_tr: Option<String>,
+    pub name_en: Option<String>,
+    pub factor: Option<f64>,
+    /// Replaces the alias list when given
+    pub aliases: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
+
+/// Content of one pack of a supply item, e.g. 1 KOLI = 24 ADET
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackSize {
+    pub id: i32,
+    pub supply_item_id: i32,
+    pub pack_unit: String,
+    pub quantity: f64,
+    pub content_unit: String,
 }
 
 // ============================================================================
@@ -396,6 +1013,24 @@ impl StockMovementType {
     }
 }
 
+/// Kind of warehouse a stock record is kept in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WarehouseType {
+    /// Duty-paid goods
+    General,
+    /// Customs bonded warehouse: duty-suspended goods for foreign ships
+    Bonded,
+}
+
+impl WarehouseType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WarehouseType::General => "Genel Depo",
+            WarehouseType::Bonded => "Antrepo",
+        }
+    }
+}
+
 /// Current stock level for a product
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Stock {
@@ -405,6 +1040,7 @@ pub struct Stock {
     pub quantity: f64,
     pub unit: String,
     pub warehouse_location: Option<String>,
+    pub warehouse_type: WarehouseType,
     pub minimum_quantity: f64,
     pub last_updated: String,
 }
@@ -421,7 +1057,14 @@ pub struct StockMovement {
     pub reference_type: Option<String>,  // "order", "supplier", "adjustment"
     pub reference_id: Option<i32>,        // order_id, supplier_id, etc.
     pub reference_info: Option<String>,   // "Sipariş #ORD-2026-001" veya "Tedarikçi: ABC Ltd."
+    /// Order line a goods receipt fills
+    pub order_item_id: Option<i32>,
     pub notes: Option<String>,
+    /// Quantity and unit as entered, when they differ from the stock unit
+    pub entered_quantity: Option<f64>,
+    pub entered_unit: Option<String>,
+    /// Customs declaration the movement was made under (required in a bonded warehouse)
+    pub customs_declaration_number: Option<String>,
     pub created_at: String,
 }
 
@@ -431,6 +1074,8 @@ pub struct CreateStockRequest {
     pub quantity: f64,
     pub unit: String,
     pub warehouse_location: Option<String>,
+    /// Bonded stock starts empty; goods come in with a declared In movement
+    pub warehouse_type: WarehouseType,
     pub minimum_quantity: f64,
 }
 
@@ -446,10 +1091,15 @@ pub struct CreateStockMovementRequest {
     pub stock_id: i32,
     pub movement_type: StockMovementType,
     pub quantity: f64,
+    /// Unit of `quantity`; None = the stock unit. Converted to the stock unit.
+    pub unit: Option<String>,
     pub reference_type: Option<String>,
     pub reference_id: Option<i32>,
     pub reference_info: Option<String>,
+    /// Order line a goods receipt fills; needs the order as reference
+    pub order_item_id: Option<i32>,
     pub notes: Option<String>,
+    pub customs_declaration_number: Option<String>,
 }
 
 /// Stock with movement history
@@ -465,7 +1115,10 @@ pub struct StockSummary {
     pub total_items: i32,
     pub low_stock_count: i32,
     pub out_of_stock_count: i32,
+    /// Catalog price plus allocated landed cost
     pub total_value: f64,
+    /// Part of `total_value` coming from landed costs
+    pub landed_cost_value: f64,
     pub currency: String,
 }
 
@@ -483,6 +1136,8 @@ pub struct Port {
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     pub notes: Option<String>,
+    /// Open to international traffic under customs control (bonded goods can be delivered)
+    pub is_international: bool,
     pub is_active: bool,
     pub created_at: String,
     pub updated_at: String,
@@ -497,6 +1152,7 @@ pub struct CreatePortRequest {
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     pub notes: Option<String>,
+    pub is_international: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -508,6 +1164,7 @@ pub struct UpdatePortRequest {
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     pub notes: Option<String>,
+    pub is_international: Option<bool>,
     pub is_active: Option<bool>,
 }
 
@@ -639,3 +1296,1551 @@ pub struct CalendarData {
     pub ports: Vec<Port>,                 // For resource view grouping
 }
 
+// ============================================================================
+// Receivables Models (Invoices, Payments, Aging)
+// ============================================================================
+
+/// Invoice status - derived from how much of the invoice has been paid
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InvoiceStatus {
+    Open,
+    PartiallyPaid,
+    Paid,
+    Cancelled,
+}
+
+impl InvoiceStatus {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            InvoiceStatus::Open => "Açık",
+            InvoiceStatus::PartiallyPaid => "Kısmi Ödendi",
+            InvoiceStatus::Paid => "Ödendi",
+            InvoiceStatus::Cancelled => "İptal",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Invoice {
+    pub id: i32,
+    pub invoice_number: String,
+    pub order_id: i32,
+    pub order_number: Option<String>,
+    /// Customer account the invoice is billed to
+    pub customer_id: Option<i32>,
+    /// Ship owner / manager the invoice is billed to
+    pub customer_name: String,
+    pub amount: f64,
+    /// Sum of payment allocations, in invoice currency
+    pub paid_amount: f64,
+    /// Sum of credit notes issued against the invoice
+    pub credited_amount: f64,
+    /// amount - paid_amount - credited_amount
+    pub balance: f64,
+    pub currency: String,
+    pub issue_date: String,       // YYYY-MM-DD
+    pub due_date: String,         // YYYY-MM-DD
+    pub status: InvoiceStatus,
+    pub notes: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateInvoiceRequest {
+    pub order_id: i32,
+    /// Defaults to today
+    pub issue_date: Option<String>,
+    /// Defaults to issue date + the order's payment terms
+    pub due_date: Option<String>,
+    /// Defaults to the order's total revenue
+    pub amount: Option<f64>,
+    pub notes: Option<String>,
+}
+
+/// How a payment was received
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PaymentMethod {
+    BankTransfer,
+    Cash,
+    CreditCard,
+    Cheque,
+}
+
+impl PaymentMethod {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PaymentMethod::BankTransfer => "Havale/EFT",
+            PaymentMethod::Cash => "Nakit",
+            PaymentMethod::CreditCard => "Kredi Kartı",
+            PaymentMethod::Cheque => "Çek",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Payment {
+    pub id: i32,
+    /// Customer account the payment came from
+    pub customer_id: Option<i32>,
+    pub customer_name: String,
+    pub amount: f64,
+    pub currency: String,
+    /// Part of the payment already applied to invoices, in payment currency
+    pub allocated_amount: f64,
+    /// Overpayment kept as credit on the customer account
+    pub unallocated_amount: f64,
+    pub payment_date: String,     // YYYY-MM-DD
+    pub method: PaymentMethod,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+    pub allocations: Vec<PaymentAllocation>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentAllocation {
+    pub id: i32,
+    pub payment_id: i32,
+    pub invoice_id: i32,
+    pub invoice_number: Option<String>,
+    /// Amount taken from the payment, in payment currency
+    pub amount: f64,
+    /// Payment currency -> invoice currency
+    pub exchange_rate: f64,
+    /// amount * exchange_rate, in invoice currency
+    pub invoice_amount: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentAllocationRequest {
+    pub invoice_id: i32,
+    /// Amount in payment currency
+    pub amount: f64,
+    /// Required when payment and invoice currencies differ
+    pub exchange_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePaymentRequest {
+    pub customer_id: i32,
+    pub amount: f64,
+    pub currency: String,
+    pub payment_date: String,
+    pub method: PaymentMethod,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+    /// Explicit allocations. If empty, the payment is applied to the
+    /// customer's oldest open invoices in the same currency.
+    pub allocations: Vec<PaymentAllocationRequest>,
+}
+
+/// Customer account balance in one currency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomerBalance {
+    pub customer_id: i32,
+    pub customer_name: String,
+    pub currency: String,
+    pub total_invoiced: f64,
+    pub total_paid: f64,
+    /// Credit notes issued against the invoices
+    pub total_credited: f64,
+    pub open_balance: f64,
+    /// Unallocated payments (overpayment credit)
+    pub credit: f64,
+}
+
+/// Receivables aging row - open balances per owner, bucketed by days past due
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceivablesAgingRow {
+    /// None for invoices and payments not linked to a customer account
+    pub customer_id: Option<i32>,
+    pub customer_name: String,
+    pub currency: String,
+    pub days_0_30: f64,
+    pub days_31_60: f64,
+    pub days_61_90: f64,
+    pub days_over_90: f64,
+    pub total: f64,
+    /// Unallocated payments as of the report date
+    pub credit: f64,
+}
+
+// ============================================================================
+// Payables Models (Supplier Invoices, 3-Way Match, Supplier Payments)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierInvoice {
+    pub id: i32,
+    pub supplier_id: i32,
+    pub supplier_name: Option<String>,
+    /// The supplier's own invoice number
+    pub invoice_number: String,
+    /// Order the goods were bought for (acts as the purchase order)
+    pub order_id: Option<i32>,
+    pub order_number: Option<String>,
+    /// Sum of line totals
+    pub amount: f64,
+    pub paid_amount: f64,
+    pub balance: f64,
+    pub currency: String,
+    pub issue_date: String,       // YYYY-MM-DD
+    pub due_date: String,         // YYYY-MM-DD
+    pub status: InvoiceStatus,
+    /// Set once the invoice passed matching and may be paid
+    pub approved_at: Option<String>,
+    pub notes: Option<String>,
+    pub lines: Vec<SupplierInvoiceLine>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierInvoiceLine {
+    pub id: i32,
+    pub supplier_invoice_id: i32,
+    /// Purchase order line being billed
+    pub order_item_id: Option<i32>,
+    /// Catalog item (links the line to its stock receipts)
+    pub supply_item_id: Option<i32>,
+    pub description: String,
+    pub quantity: f64,
+    pub unit_price: f64,
+    pub line_total: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSupplierInvoiceLineRequest {
+    pub order_item_id: Option<i32>,
+    pub supply_item_id: Option<i32>,
+    pub description: String,
+    pub quantity: f64,
+    pub unit_price: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSupplierInvoiceRequest {
+    pub supplier_id: i32,
+    pub invoice_number: String,
+    pub order_id: Option<i32>,
+    pub currency: String,
+    pub issue_date: String,
+    pub due_date: String,
+    pub notes: Option<String>,
+    pub lines: Vec<CreateSupplierInvoiceLineRequest>,
+}
+
+/// Result of matching one invoice line against the order and goods receipts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchStatus {
+    /// Ordered, received and billed quantities and prices agree
+    Matched,
+    /// Billed price differs from the order's buying price
+    PriceVariance,
+    /// Billed quantity differs from ordered or received quantity
+    QuantityVariance,
+    /// No goods receipt (stock IN) recorded for the line yet
+    NotReceived,
+    /// Line is not linked to a purchase order line
+    Unmatched,
+    /// Order line is not in the invoicing supplier's catalog
+    WrongSupplier,
+}
+
+impl MatchStatus {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            MatchStatus::Matched => "Eşleşti",
+            MatchStatus::PriceVariance => "Fiyat Farkı",
+            MatchStatus::QuantityVariance => "Miktar Farkı",
+            MatchStatus::NotReceived => "Teslim Alınmadı",
+            MatchStatus::Unmatched => "Eşleşmedi",
+            MatchStatus::WrongSupplier => "Başka Tedarikçi",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierInvoiceLineMatch {
+    pub line_id: i32,
+    pub description: String,
+    pub order_item_id: Option<i32>,
+    pub ordered_quantity: Option<f64>,
+    /// Estimated cost on the order item (OrderItem.buying_price)
+    pub ordered_price: Option<f64>,
+    /// Sum of stock IN movements for the order line; None for direct-to-ship lines
+    pub received_quantity: Option<f64>,
+    pub invoiced_quantity: f64,
+    pub invoiced_price: f64,
+    /// (invoiced_price - ordered_price) * invoiced_quantity
+    pub price_variance: f64,
+    pub status: MatchStatus,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierInvoiceMatch {
+    pub supplier_invoice_id: i32,
+    pub lines: Vec<SupplierInvoiceLineMatch>,
+    pub is_fully_matched: bool,
+    pub total_price_variance: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SupplierPayment {
+    pub id: i32,
+    pub supplier_invoice_id: i32,
+    pub invoice_number: Option<String>,
+    pub supplier_id: i32,
+    pub supplier_name: Option<String>,
+    pub amount: f64,
+    pub currency: String,
+    pub payment_date: String,     // YYYY-MM-DD
+    pub method: PaymentMethod,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateSupplierPaymentRequest {
+    pub supplier_invoice_id: i32,
+    /// Amount in invoice currency
+    pub amount: f64,
+    pub payment_date: String,
+    pub method: PaymentMethod,
+    pub reference: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Open supplier invoice falling due within the requested window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayableDue {
+    pub supplier_invoice_id: i32,
+    pub supplier_id: i32,
+    pub supplier_name: Option<String>,
+    pub invoice_number: String,
+    pub currency: String,
+    pub due_date: String,
+    pub balance: f64,
+    /// Negative when overdue
+    pub days_until_due: i64,
+}
+
+// ============================================================================
+// Price Agreement Models (Customer Contract Pricing)
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceAgreement {
+    pub id: i32,
+    pub customer_id: i32,
+    pub customer_name: Option<String>,
+    pub name: String,
+    pub currency: String,
+    pub valid_from: String,       // YYYY-MM-DD
+    pub valid_to: String,         // YYYY-MM-DD (inclusive)
+    pub notes: Option<String>,
+    pub is_active: bool,
+    pub lines: Vec<PriceAgreementLine>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Contract price for one IMPA item or a whole IMPA group.
+///
+/// Exactly one of `impa_code` / `impa_group` and one of `fixed_price` /
+/// `markup_percent` is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceAgreementLine {
+    pub id: i32,
+    pub agreement_id: i32,
+    /// Full IMPA code (item-level price)
+    pub impa_code: Option<String>,
+    /// IMPA code prefix, e.g. "37" or "3701" (category-level price)
+    pub impa_group: Option<String>,
+    pub description: Option<String>,
+    /// Fixed selling price per unit
+    pub fixed_price: Option<f64>,
+    /// Markup on the buying price, in percent
+    pub markup_percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePriceAgreementLineRequest {
+    pub impa_code: Option<String>,
+    pub impa_group: Option<String>,
+    pub description: Option<String>,
+    pub fixed_price: Option<f64>,
+    pub markup_percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePriceAgreementRequest {
+    pub customer_id: i32,
+    pub name: String,
+    pub currency: String,
+    pub valid_from: String,
+    pub valid_to: String,
+    pub notes: Option<String>,
+    pub lines: Vec<CreatePriceAgreementLineRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdatePriceAgreementRequest {
+    pub name: Option<String>,
+    pub valid_from: Option<String>,
+    pub valid_to: Option<String>,
+    pub notes: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Selling price resolved from a customer's agreement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractPrice {
+    pub agreement_id: i32,
+    pub agreement_name: String,
+    pub line_id: i32,
+    pub selling_price: f64,
+    /// True when the price comes from an IMPA group markup/price rather than the item itself
+    pub is_group_price: bool,
+}
+
+/// Order line whose selling price differs from the customer's contract
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OffContractLine {
+    pub order_id: i32,
+    pub order_number: String,
+    pub order_item_id: i32,
+    pub customer_id: i32,
+    pub customer_name: String,
+    pub product_name: String,
+    pub impa_code: String,
+    pub quantity: f64,
+    pub currency: String,
+    pub selling_price: f64,
+    pub contract_price: f64,
+    /// (selling_price - contract_price) * quantity
+    pub revenue_difference: f64,
+    pub agreement_id: i32,
+    pub agreement_name: String,
+}
+
+// ============================================================================
+// Pricing Rule Models (Selling Price Engine)
+// ============================================================================
+
+/// Markup / margin floor rule.
+///
+/// A rule without category, supplier and customer is the default rule. When
+/// several rules match an item the most specific one wins: customer before
+/// supplier before category.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PricingRule {
+    pub id: i32,
+    pub name: String,
+    /// Supply item category; the rule also covers its subcategories
+    pub category_id: Option<i32>,
+    /// Category code, e.g. "PROVISIONS"
+    pub category: Option<String>,
+    pub supplier_id: Option<i32>,
+    pub supplier_name: Option<String>,
+    pub customer_id: Option<i32>,
+    pub customer_name: Option<String>,
+    /// Markup on the buying price, in percent
+    pub markup_percent: Option<f64>,
+    /// Minimum margin on the selling price, in percent
+    pub min_margin_percent: Option<f64>,
+    pub notes: Option<String>,
+    pub is_active: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePricingRuleRequest {
+    pub name: String,
+    pub category_id: Option<i32>,
+    pub supplier_id: Option<i32>,
+    pub customer_id: Option<i32>,
+    pub markup_percent: Option<f64>,
+    pub min_margin_percent: Option<f64>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdatePricingRuleRequest {
+    pub name: Option<String>,
+    pub markup_percent: Option<f64>,
+    pub min_margin_percent: Option<f64>,
+    pub notes: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Selling prices in a currency are rounded up to a multiple of `increment`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CurrencyRounding {
+    pub currency: String,
+    pub increment: f64,
+}
+
+/// Where a proposed selling price comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceSource {
+    /// Customer price agreement
+    Contract,
+    CustomerRule,
+    SupplierRule,
+    CategoryRule,
+    DefaultRule,
+    /// Only a margin floor matched; the price is the floor price
+    MarginFloor,
+    /// No agreement or rule covers the item
+    NoRule,
+}
+
+impl PriceSource {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PriceSource::Contract => "Fiyat Anlaşması",
+            PriceSource::CustomerRule => "Müşteri Kuralı",
+            PriceSource::SupplierRule => "Tedarikçi Kuralı",
+            PriceSource::CategoryRule => "Kategori Kuralı",
+            PriceSource::DefaultRule => "Varsayılan Kural",
+            PriceSource::MarginFloor => "Minimum Marj",
+            PriceSource::NoRule => "Kural Yok",
+        }
+    }
+}
+
+/// Selling price proposed by the pricing engine, with the reasoning behind it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceProposal {
+    pub buying_price: f64,
+    /// 0 when `source` is NoRule
+    pub selling_price: f64,
+    pub currency: String,
+    pub source: PriceSource,
+    /// Rule whose markup was applied
+    pub rule_id: Option<i32>,
+    /// Agreement the contract price comes from
+    pub agreement_id: Option<i32>,
+    pub markup_percent: Option<f64>,
+    pub min_margin_percent: Option<f64>,
+    /// Margin of the proposed price, in percent
+    pub margin_percent: Option<f64>,
+    /// True when the markup price was raised to the margin floor
+    pub floor_applied: bool,
+    /// True when a contract price is below the margin floor (contract prices are not raised)
+    pub below_margin_floor: bool,
+    pub rounding_increment: f64,
+    pub explanation: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepricedItem {
+    pub order_item_id: i32,
+    pub product_name: String,
+    pub impa_code: Option<String>,
+    pub old_selling_price: f64,
+    pub new_selling_price: f64,
+    pub proposal: PriceProposal,
+}
+
+/// Outcome of repricing all lines of an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepriceResult {
+    pub order_id: i32,
+    pub updated_count: i32,
+    pub unchanged_count: i32,
+    /// Lines no agreement or rule covers (left as they are)
+    pub skipped_count: i32,
+    pub items: Vec<RepricedItem>,
+}
+
+// ============================================================================
+// Landed Cost Models (Freight / Customs / Handling on inbound goods)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LandedCostType {
+    Freight,
+    Customs,
+    Handling,
+    Insurance,
+    Other,
+}
+
+impl LandedCostType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            LandedCostType::Freight => "Navlun",
+            LandedCostType::Customs => "Gümrük",
+            LandedCostType::Handling => "Elleçleme",
+            LandedCostType::Insurance => "Sigorta",
+            LandedCostType::Other => "Diğer",
+        }
+    }
+}
+
+/// Basis used to spread a landed cost over order items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AllocationMethod {
+    /// buying_price * quantity
+    Value,
+    /// Line weight in kg
+    Weight,
+    Quantity,
+}
+
+impl AllocationMethod {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AllocationMethod::Value => "Değere Göre",
+            AllocationMethod::Weight => "Ağırlığa Göre",
+            AllocationMethod::Quantity => "Miktara Göre",
+        }
+    }
+}
+
+/// Landed cost recorded against an order (purchase order) or one of its goods receipts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandedCost {
+    pub id: i32,
+    pub order_id: i32,
+    pub order_number: Option<String>,
+    /// Goods receipt (stock IN movement) the cost belongs to; None = whole order
+    pub stock_movement_id: Option<i32>,
+    pub cost_type: LandedCostType,
+    pub description: Option<String>,
+    pub amount: f64,
+    pub currency: String,
+    pub allocation_method: AllocationMethod,
+    /// Freight bill / customs declaration number
+    pub reference: Option<String>,
+    pub allocations: Vec<LandedCostAllocation>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LandedCostAllocation {
+    pub id: i32,
+    pub landed_cost_id: i32,
+    pub order_item_id: i32,
+    pub product_name: Option<String>,
+    /// Value, weight or quantity of the line
+    pub basis: f64,
+    pub amount: f64,
+    /// amount / line quantity
+    pub amount_per_unit: f64,
+}
+
+/// Weight of an order line, for allocation by weight
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemWeight {
+    pub order_item_id: i32,
+    pub weight_kg: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateLandedCostRequest {
+    pub order_id: i32,
+    pub stock_movement_id: Option<i32>,
+    pub cost_type: LandedCostType,
+    pub description: Option<String>,
+    /// In the order's currency
+    pub amount: f64,
+    pub allocation_method: AllocationMethod,
+    pub reference: Option<String>,
+    /// Line weights for allocation by weight; lines sold in KG default to their quantity
+    pub weights: Vec<ItemWeight>,
+}
+
+// ============================================================================
+// Delivery Note Models (Signed Proof of Delivery)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryNoteStatus {
+    /// Generated, waiting for the receiver's signature
+    Draft,
+    /// Signed on board; counts as proof of delivery
+    Completed,
+}
+
+impl DeliveryNoteStatus {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DeliveryNoteStatus::Draft => "Taslak",
+            DeliveryNoteStatus::Completed => "İmzalandı",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeliveryNoteAttachmentKind {
+    /// Receiver's captured signature
+    Signature,
+    /// Photo of the ship stamp
+    ShipStamp,
+}
+
+impl DeliveryNoteAttachmentKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            DeliveryNoteAttachmentKind::Signature => "İmza",
+            DeliveryNoteAttachmentKind::ShipStamp => "Gemi Kaşesi",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryNote {
+    pub id: i32,
+    /// DN-2026-0001
+    pub note_number: String,
+    pub order_id: i32,
+    pub order_number: Option<String>,
+    pub ship_name: Option<String>,
+    pub ship_visit_id: Option<i32>,
+    pub status: DeliveryNoteStatus,
+    /// Chief steward / officer who received the goods
+    pub receiver_name: Option<String>,
+    pub receiver_rank: Option<String>,
+    /// When the receiver signed
+    pub signed_at: Option<String>,
+    pub notes: Option<String>,
+    pub lines: Vec<DeliveryNoteLine>,
+    pub attachments: Vec<DeliveryNoteAttachment>,
+    pub created_at: String,
+}
+
+/// Delivered quantity of an order line on a delivery note
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryNoteLine {
+    pub order_item_id: i32,
+    pub product_name: String,
+    pub impa_code: Option<String>,
+    pub unit: String,
+    pub quantity: f64,
+}
+
+/// Attachment metadata; the image itself is fetched with `get_delivery_note_attachment_data`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeliveryNoteAttachment {
+    pub id: i32,
+    pub delivery_note_id: i32,
+    pub kind: DeliveryNoteAttachmentKind,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size_bytes: i64,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDeliveryNoteRequest {
+    pub order_id: i32,
+    /// Delivery events to put on the note; None = every delivery not yet on a note
+    pub delivery_ids: Option<Vec<i32>>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompleteDeliveryNoteRequest {
+    pub receiver_name: String,
+    pub receiver_rank: String,
+    /// Defaults to now
+    pub signed_at: Option<String>,
+}
+
+// ============================================================================
+// Return Models (Goods Sent Back by Ships, Credit Notes)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReturnReason {
+    Damaged,
+    WrongItem,
+    Surplus,
+    Other,
+}
+
+impl ReturnReason {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ReturnReason::Damaged => "Hasarlı",
+            ReturnReason::WrongItem => "Yanlış Ürün",
+            ReturnReason::Surplus => "Fazla",
+            ReturnReason::Other => "Diğer",
+        }
+    }
+}
+
+/// Goods returned by a ship against a delivered order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderReturn {
+    pub id: i32,
+    /// RET-2026-0001
+    pub return_number: String,
+    pub order_id: i32,
+    pub order_number: Option<String>,
+    pub return_date: String,      // YYYY-MM-DD
+    pub notes: Option<String>,
+    /// Revenue given back (sum of the line credit amounts)
+    pub credit_amount: f64,
+    pub currency: String,
+    pub credit_note_id: Option<i32>,
+    pub credit_note_number: Option<String>,
+    pub lines: Vec<OrderReturnLine>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderReturnLine {
+    pub id: i32,
+    pub order_item_id: i32,
+    pub product_name: Option<String>,
+    pub quantity: f64,
+    pub unit: Option<String>,
+    pub reason: ReturnReason,
+    /// Put back into warehouse stock with a Return movement
+    pub restocked: bool,
+    pub stock_movement_id: Option<i32>,
+    /// quantity * selling_price
+    pub credit_amount: f64,
+    /// quantity * buying_price when restocked, 0 when written off
+    pub cost_amount: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReturnLineRequest {
+    pub order_item_id: i32,
+    pub quantity: f64,
+    pub reason: ReturnReason,
+    /// Put the goods back into stock (needs a stock record for the item's IMPA code)
+    pub restock: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReturnRequest {
+    pub order_id: i32,
+    /// Defaults to today
+    pub return_date: Option<String>,
+    pub notes: Option<String>,
+    pub lines: Vec<ReturnLineRequest>,
+    /// Issue a credit note against the order's invoice
+    pub issue_credit_note: bool,
+    /// Customs declaration for goods restocked into the bonded warehouse
+    pub customs_declaration_number: Option<String>,
+}
+
+/// Credit note reducing the balance of a customer invoice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreditNote {
+    pub id: i32,
+    /// CN-2026-0001
+    pub credit_note_number: String,
+    pub invoice_id: i32,
+    pub invoice_number: Option<String>,
+    pub order_id: i32,
+    pub return_id: Option<i32>,
+    pub customer_name: String,
+    pub amount: f64,
+    pub currency: String,
+    pub issue_date: String,       // YYYY-MM-DD
+    pub notes: Option<String>,
+    pub created_at: String,
+}
+
+// ============================================================================
+// Requisition Template Models (Recurring Provision Lists)
+// ============================================================================
+
+/// Saved provisions list for a ship or for every ship of a type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequisitionTemplate {
+    pub id: i32,
+    pub name: String,
+    /// Set for a ship's own template
+    pub ship_id: Option<i32>,
+    pub ship_name: Option<String>,
+    /// Set for a template shared by a ship type (e.g. "Cruise")
+    pub ship_type: Option<String>,
+    /// Persons on board (crew + passengers) the quantities are meant for
+    pub base_headcount: Option<f64>,
+    pub notes: Option<String>,
+    pub lines: Vec<RequisitionTemplateLine>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequisitionTemplateLine {
+    pub id: i32,
+    pub template_id: i32,
+    pub product_name: String,
+    pub impa_code: Option<String>,
+    pub description: Option<String>,
+    pub quantity: f64,
+    pub unit: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequisitionTemplateLineRequest {
+    pub product_name: String,
+    pub impa_code: Option<String>,
+    pub description: Option<String>,
+    pub quantity: f64,
+    pub unit: String,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRequisitionTemplateRequest {
+    pub name: String,
+    /// Exactly one of ship_id / ship_type
+    pub ship_id: Option<i32>,
+    pub ship_type: Option<String>,
+    pub base_headcount: Option<f64>,
+    pub notes: Option<String>,
+    pub lines: Vec<RequisitionTemplateLineRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRequisitionTemplateRequest {
+    pub name: Option<String>,
+    pub base_headcount: Option<f64>,
+    pub notes: Option<String>,
+    /// Replaces all lines when set
+    pub lines: Option<Vec<RequisitionTemplateLineRequest>>,
+}
+
+/// Create an order from a template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstantiateTemplateRequest {
+    pub template_id: i32,
+    pub ship_id: i32,
+    pub ship_visit_id: Option<i32>,
+    /// Persons on board this call; quantities scale by headcount / base_headcount
+    pub headcount: Option<f64>,
+    /// Explicit quantity factor (takes precedence over headcount)
+    pub scale_factor: Option<f64>,
+    /// Leave empty to use the customer's default currency
+    pub currency: String,
+}
+
+// ============================================================================
+// Requisition Import Models (CSV/XLSX Files from Ships)
+// ============================================================================
+
+/// Which columns of the file hold which field.
+///
+/// A column is given by its header text (case-insensitive) or by its letter
+/// ("A", "B", ... "AA"); letters are required when the file has no header row.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequisitionColumnMapping {
+    pub impa_code: Option<String>,
+    pub description: String,
+    pub quantity: String,
+    pub unit: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Requisition file to preview for an order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequisitionImportRequest {
+    pub order_id: i32,
+    /// Path of a .csv, .xlsx or .xls file
+    pub file_path: String,
+    pub mapping: RequisitionColumnMapping,
+    /// 1-based row of the column headers (0 = no header row); data starts below it
+    pub header_row: i32,
+    /// Worksheet to read (default: the first sheet)
+    pub sheet_name: Option<String>,
+    /// CSV delimiter (default: detected from the first line, ';' or ',')
+    pub delimiter: Option<String>,
+}
+
+/// How a requisition line was matched to a supply item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RequisitionMatchKind {
+    /// Same IMPA code
+    Impa,
+    /// Similar product name
+    FuzzyName,
+    Unmatched,
+}
+
+impl RequisitionMatchKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            RequisitionMatchKind::Impa => "IMPA Eşleşmesi",
+            RequisitionMatchKind::FuzzyName => "Benzer İsim",
+            RequisitionMatchKind::Unmatched => "Eşleşmedi",
+        }
+    }
+}
+
+/// One line of a requisition file with its proposed supply item.
+/// Operators may change `supply_item_id`, `quantity` or `include` before committing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequisitionImportLine {
+    /// Row number in the file (1-based)
+    pub row_number: i32,
+    pub impa_code: Option<String>,
+    pub description: String,
+    pub quantity: f64,
+    pub unit: String,
+    pub notes: Option<String>,
+    pub match_kind: RequisitionMatchKind,
+    pub supply_item_id: Option<i32>,
+    pub supply_item_name: Option<String>,
+    /// 1.0 for IMPA matches, name similarity (0-1) for fuzzy matches
+    pub match_score: f64,
+    /// Whether the line becomes an order item on commit
+    pub include: bool,
+    /// Why the row could not be read (such lines are never imported)
+    pub error: Option<String>,
+}
+
+/// Result of reading a requisition file, before anything is written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequisitionImportPreview {
+    pub order_id: i32,
+    pub order_number: String,
+    pub lines: Vec<RequisitionImportLine>,
+    pub impa_matched: i32,
+    pub fuzzy_matched: i32,
+    pub unmatched: i32,
+    pub errors: i32,
+}
+
+// ============================================================================
+// Supplier Price List Import Models
+// ============================================================================
+
+/// Which columns of a supplier price list hold which field (header text or column letter)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListColumnMapping {
+    pub supplier_sku: Option<String>,
+    pub impa_code: Option<String>,
+    pub name: String,
+    pub unit: Option<String>,
+    pub price: String,
+    /// Defaults to the request currency when not mapped
+    pub currency: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListImportRequest {
+    pub supplier_id: i32,
+    /// Path of a .csv, .xlsx or .xls file
+    pub file_path: String,
+    pub mapping: PriceListColumnMapping,
+    /// 1-based row of the column headers (0 = no header row)
+    pub header_row: i32,
+    pub sheet_name: Option<String>,
+    pub delimiter: Option<String>,
+    /// Currency of rows without a currency column, e.g. "TRY"
+    pub currency: String,
+    /// Category of new items whose IMPA section has none
+    pub default_category_id: Option<i32>,
+    /// Date the prices take effect (YYYY-MM-DD), defaults to today
+    pub effective_date: Option<String>,
+    /// Mark the supplier's items missing from the list as unavailable
+    pub discontinue_missing: bool,
+}
+
+/// How a price list row was matched to one of the supplier's items
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceListMatchKind {
+    SupplierSku,
+    Impa,
+    Name,
+}
+
+/// What importing a price list row does to the catalog
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceListChangeKind {
+    New,
+    PriceUp,
+    PriceDown,
+    /// Price in a different currency than before
+    CurrencyChanged,
+    Unchanged,
+    /// The supplier's item is not on the list
+    Discontinued,
+}
+
+impl PriceListChangeKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PriceListChangeKind::New => "Yeni Ürün",
+            PriceListChangeKind::PriceUp => "Fiyat Artışı",
+            PriceListChangeKind::PriceDown => "Fiyat Düşüşü",
+            PriceListChangeKind::CurrencyChanged => "Para Birimi Değişti",
+            PriceListChangeKind::Unchanged => "Değişmedi",
+            PriceListChangeKind::Discontinued => "Listeden Çıktı",
+        }
+    }
+}
+
+/// One line of the price list diff
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListChange {
+    /// Row number in the file (1-based), None for discontinued items
+    pub row_number: Option<i32>,
+    pub kind: PriceListChangeKind,
+    pub matched_by: Option<PriceListMatchKind>,
+    pub supply_item_id: Option<i32>,
+    pub supplier_sku: Option<String>,
+    pub impa_code: Option<String>,
+    pub name: String,
+    pub unit: Option<String>,
+    pub old_price: Option<f64>,
+    pub old_currency: Option<String>,
+    pub new_price: Option<f64>,
+    pub currency: Option<String>,
+    pub change_percent: Option<f64>,
+    /// Why the row cannot be imported
+    pub error: Option<String>,
+}
+
+/// Diff of a price list against the supplier's catalog, before anything is written
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListPreview {
+    pub supplier_id: i32,
+    pub supplier_name: String,
+    pub lines: Vec<PriceListChange>,
+    pub new_items: i32,
+    pub price_up: i32,
+    pub price_down: i32,
+    pub currency_changed: i32,
+    pub unchanged: i32,
+    pub discontinued: i32,
+    pub errors: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceListImportResult {
+    pub created: i32,
+    pub updated: i32,
+    pub unchanged: i32,
+    pub discontinued: i32,
+}
+
+// ============================================================================
+// Global Search Models
+// ============================================================================
+
+/// What a global search looks through
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SearchScope {
+    SupplyItems,
+    Suppliers,
+    Ships,
+    Orders,
+}
+
+impl SearchScope {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            SearchScope::SupplyItems => "Ürünler",
+            SearchScope::Suppliers => "Tedarikçiler",
+            SearchScope::Ships => "Gemiler",
+            SearchScope::Orders => "Siparişler",
+        }
+    }
+}
+
+/// One search result; `scope` tells which kind of record `entity_id` is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub scope: SearchScope,
+    pub entity_id: i32,
+    pub title: String,
+    pub subtitle: Option<String>,
+    /// Text around the match, matched words wrapped in <b></b>
+    pub snippet: String,
+    /// Higher is a better match
+    pub score: f64,
+}
+
+// ============================================================================
+// Attachment Models (Product Photos, Signed Documents)
+// ============================================================================
+
+/// Kind of record a file is attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AttachmentEntity {
+    SupplyItem,
+    Order,
+    DeliveryNote,
+    Supplier,
+    Ship,
+}
+
+impl AttachmentEntity {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AttachmentEntity::SupplyItem => "Ürün",
+            AttachmentEntity::Order => "Sipariş",
+            AttachmentEntity::DeliveryNote => "Teslim Belgesi",
+            AttachmentEntity::Supplier => "Tedarikçi",
+            AttachmentEntity::Ship => "Gemi",
+        }
+    }
+}
+
+/// Attachment metadata; the file is fetched with `get_attachment_data`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: i32,
+    pub entity_type: AttachmentEntity,
+    pub entity_id: i32,
+    /// Slot the file fills on its record (e.g. SIGNATURE on a delivery note), None for free attachments
+    pub role: Option<String>,
+    pub file_name: String,
+    pub mime_type: String,
+    /// SHA-256 of the content; identical files are stored once
+    pub content_hash: String,
+    pub size_bytes: i64,
+    /// Pixel size, for images
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub has_thumbnail: bool,
+    pub caption: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAttachmentRequest {
+    pub entity_type: AttachmentEntity,
+    pub entity_id: i32,
+    pub file_name: String,
+    pub mime_type: String,
+    pub data: Vec<u8>,
+    pub caption: Option<String>,
+}
+
+// ============================================================================
+// Compliance Models (Dangerous Goods, Prescription Medicines)
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComplianceIssueKind {
+    /// Dangerous good or SDS-required item without a safety data sheet on file
+    MissingSds,
+    /// Prescription-only medicine not signed off by a pharmacist
+    MissingPharmacistSignOff,
+}
+
+impl ComplianceIssueKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ComplianceIssueKind::MissingSds => "Güvenlik Bilgi Formu Eksik",
+            ComplianceIssueKind::MissingPharmacistSignOff => "Eczacı Onayı Eksik",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComplianceIssue {
+    pub order_item_id: i32,
+    pub product_name: String,
+    pub kind: ComplianceIssueKind,
+    pub detail: String,
+}
+
+/// Documents an order needs before it can be Prepared or sent on its way
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderCompliance {
+    pub order_id: i32,
+    pub order_number: String,
+    /// Lines that are dangerous goods, need an SDS or are prescription-only
+    pub regulated_lines: i32,
+    pub issues: Vec<ComplianceIssue>,
+    pub is_compliant: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PharmacistSignOffRequest {
+    pub pharmacist_name: String,
+    /// Pharmacist's chamber registration number
+    pub license_number: String,
+    pub notes: Option<String>,
+}
+
+/// A dangerous good handed over on a delivery note
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DangerousGoodsLine {
+    pub order_item_id: i32,
+    pub product_name: String,
+    pub impa_code: Option<String>,
+    pub un_number: String,
+    pub hazard_class: String,
+    pub quantity: f64,
+    pub unit: String,
+    pub sds_on_file: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DangerousGoodsClassTotal {
+    pub hazard_class: String,
+    /// IMDG class name, e.g. "Yanıcı Sıvılar"
+    pub description: String,
+    pub line_count: i32,
+    pub un_numbers: Vec<String>,
+}
+
+/// Dangerous goods declaration of one delivery
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DangerousGoodsSummary {
+    pub delivery_note_id: i32,
+    pub note_number: String,
+    pub order_number: Option<String>,
+    pub ship_name: Option<String>,
+    pub lines: Vec<DangerousGoodsLine>,
+    pub classes: Vec<DangerousGoodsClassTotal>,
+}
+
+// ============================================================================
+// Bonded Store Models (Duty-Free Goods for Foreign Ships)
+// ============================================================================
+
+/// Whether an order's ship may receive bonded goods
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondedEligibility {
+    pub order_id: i32,
+    pub ship_name: Option<String>,
+    pub ship_flag: Option<String>,
+    pub port_name: Option<String>,
+    pub port_country: Option<String>,
+    pub is_international_port: bool,
+    pub is_eligible: bool,
+    /// Why the ship is not eligible
+    pub reason: Option<String>,
+}
+
+/// Bonded stock of one item over a reconciliation period, in the stock unit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondedReconciliationLine {
+    pub stock_id: i32,
+    pub supply_item_id: i32,
+    pub supply_item_name: Option<String>,
+    pub unit: String,
+    pub warehouse_location: Option<String>,
+    pub opening_quantity: f64,
+    pub received_quantity: f64,
+    pub returned_quantity: f64,
+    pub issued_quantity: f64,
+    /// Net change from count corrections
+    pub adjusted_quantity: f64,
+    pub closing_quantity: f64,
+    /// Balance the movement log gives today
+    pub expected_quantity: f64,
+    /// Quantity on the stock record today
+    pub recorded_quantity: f64,
+    /// Recorded minus expected; non-zero means stock changed outside the movement log
+    pub difference: f64,
+}
+
+/// Periodic bonded stock reconciliation for customs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BondedReconciliation {
+    /// YYYY-MM-DD, inclusive
+    pub period_start: String,
+    pub period_end: String,
+    pub lines: Vec<BondedReconciliationLine>,
+    /// Bonded movements in the period with their customs declarations
+    pub movements: Vec<StockMovement>,
+}
+
+// ============================================================================
+// Export Models (XLSX/CSV Files for Operations and Finance)
+// ============================================================================
+
+/// Filters for exporting the order list (all optional)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderExportFilter {
+    pub status: Option<OrderStatus>,
+    pub ship_id: Option<i32>,
+    pub customer_id: Option<i32>,
+    /// Orders created on or after this date (YYYY-MM-DD)
+    pub date_from: Option<String>,
+    /// Orders created on or before this date (YYYY-MM-DD)
+    pub date_to: Option<String>,
+}
+
+/// Files written by an export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportResult {
+    /// The output path, plus one file per extra table for CSV exports
+    pub files: Vec<String>,
+    /// Data rows written (all tables)
+    pub row_count: i32,
+}
+
+// ============================================================================
+// Document Models (Company Letterhead and Order PDFs)
+// ============================================================================
+
+/// Our own company, printed as the letterhead of every document
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyProfile {
+    pub name: String,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub tax_office: Option<String>,
+    pub tax_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub iban: Option<String>,
+    pub swift: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCompanyProfileRequest {
+    pub name: String,
+    pub address: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub website: Option<String>,
+    pub tax_office: Option<String>,
+    pub tax_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub iban: Option<String>,
+    pub swift: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PdfDocumentKind {
+    Proforma,
+    Quotation,
+    Invoice,
+    DeliveryNote,
+}
+
+impl PdfDocumentKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PdfDocumentKind::Proforma => "Proforma Fatura",
+            PdfDocumentKind::Quotation => "Fiyat Teklifi",
+            PdfDocumentKind::Invoice => "Fatura",
+            PdfDocumentKind::DeliveryNote => "Teslim Fişi",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfRenderRequest {
+    pub order_id: i32,
+    pub kind: PdfDocumentKind,
+    /// Invoice or delivery note to print (defaults to the order's latest one)
+    pub document_id: Option<i32>,
+    /// VAT added on top of the net amount (0 = exempt)
+    pub tax_rate_percent: f64,
+    /// Where the PDF is written
+    pub output_path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PdfRenderResult {
+    pub file_path: String,
+    pub page_count: i32,
+}
+
+// ============================================================================
+// IMPA Catalog Models (Marine Stores Guide Reference)
+// ============================================================================
+
+/// Reference entry of the IMPA Marine Stores Guide
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpaCatalogEntry {
+    /// Six digits, no spaces ("370101")
+    pub code: String,
+    pub description: String,
+    pub unit: Option<String>,
+    /// First two digits of the code
+    pub section: String,
+    pub section_name: Option<String>,
+    /// Supply item category used when auto-filling items of this section
+    pub category_id: Option<i32>,
+    /// Category code, e.g. "PROVISIONS"
+    pub category: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpaSection {
+    pub code: String,
+    pub name: Option<String>,
+    pub category_id: Option<i32>,
+    pub category: Option<String>,
+    pub entry_count: i32,
+}
+
+/// Catalog file columns, each a header name or a column letter ("A")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpaColumnMapping {
+    pub code: String,
+    pub description: String,
+    pub unit: Option<String>,
+    /// Section code or "37 Nautical Equipment"; defaults to the code's first two digits
+    pub section: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpaCatalogImportRequest {
+    /// CSV or XLSX file
+    pub file_path: String,
+    pub mapping: ImpaColumnMapping,
+    /// 1-based row holding the column headers (0 = no header row)
+    pub header_row: i32,
+    /// Sheet to read (defaults to the first sheet)
+    pub sheet_name: Option<String>,
+    /// CSV delimiter (detected when None)
+    pub delimiter: Option<String>,
+    /// Clear the catalog before loading
+    pub replace_existing: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpaCatalogImportResult {
+    pub inserted: i32,
+    pub updated: i32,
+    /// Section header rows ("37 NAUTICAL EQUIPMENT") taken as section names
+    pub sections: i32,
+    /// Rows skipped with the reason ("Row 12: ...")
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImpaCodeProblem {
+    /// Not six digits or an unknown section
+    Invalid,
+    /// Well-formed but missing from the reference catalog
+    NotInCatalog,
+}
+
+impl ImpaCodeProblem {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ImpaCodeProblem::Invalid => "Geçersiz Kod",
+            ImpaCodeProblem::NotInCatalog => "Katalogda Yok",
+        }
+    }
+}
+
+/// Supply item whose IMPA code does not match the reference catalog
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpaCodeIssue {
+    pub supply_item_id: i32,
+    pub supply_item_name: String,
+    pub supplier_name: Option<String>,
+    pub impa_code: String,
+    pub problem: ImpaCodeProblem,
+    pub message: String,
+}
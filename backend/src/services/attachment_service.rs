@@ -0,0 +1,518 @@
+//! Attachment Service - Files attached to supply items, orders, delivery notes, suppliers and ships
+//!
+//! File contents are content-addressed: each distinct file is stored once,
+//! named by its SHA-256, under `attachments/` in the SSMS data directory next
+//! to the local database. The `attachments` table records which file is
+//! attached to which record. Images also get a PNG thumbnail in
+//! `attachments/thumbs/` when they are stored.
+
+use crate::models::{Attachment, AttachmentEntity, CreateAttachmentRequest};
+use crate::database;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
+
+/// Largest accepted attachment
+const MAX_ATTACHMENT_BYTES: usize = 20 * 1024 * 1024;
+
+/// Longest side of a thumbnail, in pixels
+const THUMBNAIL_SIZE: u32 = 256;
+
+/// Stored name, table and label of every entity that takes attachments
+const ENTITIES: [(AttachmentEntity, &str, &str, &str); 5] = [
+    (AttachmentEntity::SupplyItem, "SUPPLY_ITEM", "supply_items", "Supply item"),
+    (AttachmentEntity::Order, "ORDER", "orders", "Order"),
+    (AttachmentEntity::DeliveryNote, "DELIVERY_NOTE", "delivery_notes", "Delivery note"),
+    (AttachmentEntity::Supplier, "SUPPLIER", "suppliers", "Supplier"),
+    (AttachmentEntity::Ship, "SHIP", "ships", "Ship"),
+];
+
+fn entity_info(entity: AttachmentEntity) -> (&'static str, &'static str, &'static str) {
+    ENTITIES.iter()
+        .find(|(e, ..)| *e == entity)
+        .map(|(_, name, table, label)| (*name, *table, *label))
+        .unwrap_or(("SUPPLY_ITEM", "supply_items", "Supply item"))
+}
+
+pub(crate) fn entity_to_str(entity: AttachmentEntity) -> &'static str {
+    entity_info(entity).0
+}
+
+fn entity_from_str(entity: &str) -> AttachmentEntity {
+    ENTITIES.iter()
+        .find(|(_, name, ..)| *name == entity)
+        .map(|(e, ..)| *e)
+        .unwrap_or(AttachmentEntity::SupplyItem)
+}
+
+#[derive(Debug, FromQueryResult)]
+struct AttachmentRow {
+    id: i32,
+    entity_type: String,
+    entity_id: i32,
+    role: Option<String>,
+    file_name: String,
+    mime_type: String,
+    content_hash: String,
+    size_bytes: i64,
+    width: Option<i32>,
+    height: Option<i32>,
+    has_thumbnail: i32,
+    caption: Option<String>,
+    created_at: String,
+}
+
+impl From<AttachmentRow> for Attachment {
+    fn from(row: AttachmentRow) -> Self {
+        Attachment {
+            id: row.id,
+            entity_type: entity_from_str(&row.entity_type),
+            entity_id: row.entity_id,
+            role: row.role,
+            file_name: row.file_name,
+            mime_type: row.mime_type,
+            content_hash: row.content_hash,
+            size_bytes: row.size_bytes,
+            width: row.width,
+            height: row.height,
+            has_thumbnail: row.has_thumbnail != 0,
+            caption: row.caption,
+            created_at: row.created_at,
+        }
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    id, entity_type, entity_id, role, file_name, mime_type, content_hash, size_bytes,
+    width, height, has_thumbnail, caption, created_at
+"#;
+
+// ============================================================================
+// File store
+// ============================================================================
+
+/// A file as written to the store
+struct StoredFile {
+    hash: String,
+    size: i64,
+    width: Option<i32>,
+    height: Option<i32>,
+    has_thumbnail: bool,
+}
+
+fn file_path(hash: &str) -> PathBuf {
+    let mut path = database::get_attachments_dir();
+    path.push(&hash[..2]);
+    path.push(hash);
+    path
+}
+
+fn thumbnail_path(hash: &str) -> PathBuf {
+    let mut path = database::get_attachments_dir();
+    path.push("thumbs");
+    path.push(format!("{}.png", hash));
+    path
+}
+
+/// Write through a temporary file so a crash never leaves a truncated file under its hash
+fn write_atomic(path: &PathBuf, data: &[u8]) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, data)?;
+    std::fs::rename(&tmp, path)
+}
+
+/// Thumbnail of an image file; returns the full image size
+fn write_thumbnail(hash: &str, data: &[u8]) -> Result<(u32, u32)> {
+    let image = image::load_from_memory(data)
+        .map_err(|e| anyhow::anyhow!("Image could not be read: {}", e))?;
+    let mut png = std::io::Cursor::new(Vec::new());
+    image.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        .write_to(&mut png, image::ImageFormat::Png)
+        .map_err(|e| anyhow::anyhow!("Thumbnail could not be written: {}", e))?;
+    write_atomic(&thumbnail_path(hash), png.get_ref())?;
+    Ok((image.width(), image.height()))
+}
+
+/// Put a file in the store; an identical file already there is reused.
+/// Images the store cannot decode are kept without a thumbnail.
+fn store_file(mime_type: &str, data: &[u8]) -> Result<StoredFile> {
+    let hash = hex::encode(Sha256::digest(data));
+    let path = file_path(&hash);
+    if !path.exists() {
+        write_atomic(&path, data)?;
+    }
+
+    let size = if mime_type.starts_with("image/") { write_thumbnail(&hash, data).ok() } else { None };
+    Ok(StoredFile {
+        hash,
+        size: data.len() as i64,
+        width: size.map(|(w, _)| w as i32),
+        height: size.map(|(_, h)| h as i32),
+        has_thumbnail: size.is_some(),
+    })
+}
+
+/// Remove a file from the store once no attachment refers to it
+pub(crate) async fn release<C: ConnectionTrait>(conn: &C, hash: &str) -> Result<()> {
+    #[derive(Debug, FromQueryResult)]
+    struct CountRow {
+        count: i64,
+    }
+
+    let row = CountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT COUNT(*) as count FROM attachments WHERE content_hash = ?",
+        vec![Value::String(Some(Box::new(hash.to_string())))]
+    ))
+    .one(conn)
+    .await?;
+
+    if row.map(|r| r.count).unwrap_or(0) == 0 {
+        for path in [file_path(hash), thumbnail_path(hash)] {
+            match std::fs::remove_file(&path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Attachments
+// ============================================================================
+
+fn validate(req: &CreateAttachmentRequest) -> Result<()> {
+    if req.file_name.trim().is_empty() {
+        anyhow::bail!("File name is required");
+    }
+    if req.mime_type.trim().is_empty() {
+        anyhow::bail!("MIME type is required");
+    }
+    if req.data.is_empty() {
+        anyhow::bail!("File {} is empty", req.file_name);
+    }
+    if req.data.len() > MAX_ATTACHMENT_BYTES {
+        anyhow::bail!("File {} is larger than {} MB", req.file_name, MAX_ATTACHMENT_BYTES / (1024 * 1024));
+    }
+    Ok(())
+}
+
+async fn check_entity<C: ConnectionTrait>(conn: &C, entity: AttachmentEntity, entity_id: i32) -> Result<()> {
+    let (_, table, label) = entity_info(entity);
+    let row = conn.query_one(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT id FROM {} WHERE id = ?", table),
+        vec![Value::Int(Some(entity_id))]
+    )).await?;
+    if row.is_none() {
+        anyhow::bail!("{} {} not found", label, entity_id);
+    }
+    Ok(())
+}
+
+/// Store the file and attach it; `role` names the slot it fills on its record
+pub(crate) async fn insert<C: ConnectionTrait>(conn: &C, req: &CreateAttachmentRequest, role: Option<&str>) -> Result<i32> {
+    validate(req)?;
+    let stored = store_file(&req.mime_type, &req.data)?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO attachments (entity_type, entity_id, role, file_name, mime_type, content_hash, size_bytes,
+            width, height, has_thumbnail, caption)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(entity_to_str(req.entity_type).to_string()))),
+            Value::Int(Some(req.entity_id)),
+            Value::String(role.map(|r| Box::new(r.to_string()))),
+            Value::String(Some(Box::new(req.file_name.trim().to_string()))),
+            Value::String(Some(Box::new(req.mime_type.trim().to_string()))),
+            Value::String(Some(Box::new(stored.hash))),
+            Value::BigInt(Some(stored.size)),
+            Value::Int(stored.width),
+            Value::Int(stored.height),
+            Value::Int(Some(stored.has_thumbnail as i32)),
+            Value::String(req.caption.as_ref().map(|c| c.trim()).filter(|c| !c.is_empty()).map(|c| Box::new(c.to_string()))),
+        ]
+    )).await?;
+
+    Ok(result.last_insert_id() as i32)
+}
+
+/// Store the file in the `role` slot of its record, replacing the file there
+pub(crate) async fn replace_role(req: CreateAttachmentRequest, role: &str) -> Result<i32> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let replaced = get_for_entity(req.entity_type, req.entity_id).await?
+        .into_iter()
+        .find(|a| a.role.as_deref() == Some(role));
+
+    let txn = conn.begin().await?;
+
+    if let Some(old) = &replaced {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "DELETE FROM attachments WHERE id = ?",
+            vec![Value::Int(Some(old.id))]
+        )).await?;
+    }
+    let id = insert(&txn, &req, Some(role)).await?;
+
+    txn.commit().await?;
+
+    if let Some(old) = replaced {
+        release(&conn, &old.content_hash).await?;
+    }
+    Ok(id)
+}
+
+/// Attach a file to a supply item, order, delivery note, supplier or ship
+pub async fn add(req: CreateAttachmentRequest) -> Result<Attachment> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    check_entity(&conn, req.entity_type, req.entity_id).await?;
+    let id = insert(&conn, &req, None).await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created attachment"))
+}
+
+/// Get attachment metadata by ID
+pub async fn get_by_id(id: i32) -> Result<Option<Attachment>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row = AttachmentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} FROM attachments WHERE id = ?", SELECT_FIELDS),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    Ok(row.map(Attachment::from))
+}
+
+/// All attachments of a record, oldest first
+pub async fn get_for_entity(entity: AttachmentEntity, entity_id: i32) -> Result<Vec<Attachment>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows = AttachmentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} FROM attachments WHERE entity_type = ? AND entity_id = ? ORDER BY id", SELECT_FIELDS),
+        vec![
+            Value::String(Some(Box::new(entity_to_str(entity).to_string()))),
+            Value::Int(Some(entity_id)),
+        ]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(Attachment::from).collect())
+}
+
+async fn get_existing(id: i32) -> Result<Attachment> {
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Attachment not found"))
+}
+
+/// File contents of an attachment
+pub async fn get_data(id: i32) -> Result<Vec<u8>> {
+    let attachment = get_existing(id).await?;
+    std::fs::read(file_path(&attachment.content_hash))
+        .map_err(|e| anyhow::anyhow!("File of attachment {} could not be read: {}", attachment.file_name, e))
+}
+
+/// PNG thumbnail of an image attachment, made again if it went missing
+pub async fn get_thumbnail(id: i32) -> Result<Vec<u8>> {
+    let attachment = get_existing(id).await?;
+    if !attachment.has_thumbnail {
+        anyhow::bail!("Attachment {} has no thumbnail", attachment.file_name);
+    }
+
+    let path = thumbnail_path(&attachment.content_hash);
+    if !path.exists() {
+        write_thumbnail(&attachment.content_hash, &get_data(id).await?)?;
+    }
+    Ok(std::fs::read(path)?)
+}
+
+/// Change the caption of an attachment
+pub async fn set_caption(id: i32, caption: Option<String>) -> Result<Attachment> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    get_existing(id).await?;
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE attachments SET caption = ? WHERE id = ?",
+        vec![
+            Value::String(caption.as_deref().map(str::trim).filter(|c| !c.is_empty()).map(|c| Box::new(c.to_string()))),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    get_existing(id).await
+}
+
+/// Delete an attachment; its file goes when nothing else refers to it.
+/// Files filling a slot (delivery note signature, ship stamp, safety data
+/// sheet) are replaced on their record instead.
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let Some(attachment) = get_by_id(id).await? else {
+        return Ok(false);
+    };
+    if let Some(role) = &attachment.role {
+        anyhow::bail!(
+            "Attachment {} is the {} of its {} and cannot be deleted on its own",
+            attachment.file_name, role, entity_info(attachment.entity_type).2.to_lowercase()
+        );
+    }
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM attachments WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    release(&conn, &attachment.content_hash).await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Remove stored files no attachment refers to any more, e.g. after their
+/// record was deleted. Returns the number of files removed.
+pub async fn prune_files() -> Result<i32> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct HashRow {
+        content_hash: String,
+    }
+
+    let referenced: std::collections::HashSet<String> = HashRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT DISTINCT content_hash FROM attachments".to_string()
+    ))
+    .all(&conn)
+    .await?
+    .into_iter()
+    .map(|r| r.content_hash)
+    .collect();
+
+    let root = database::get_attachments_dir();
+    let Ok(dirs) = std::fs::read_dir(&root) else {
+        return Ok(0);
+    };
+
+    let mut removed = 0;
+    for dir in dirs.flatten().filter(|d| d.path().is_dir()) {
+        for file in std::fs::read_dir(dir.path())?.flatten() {
+            let name = file.file_name().to_string_lossy().to_string();
+            let hash = name.strip_suffix(".png").filter(|_| dir.file_name() == "thumbs").unwrap_or(&name);
+            if !referenced.contains(hash) {
+                std::fs::remove_file(file.path())?;
+                removed += 1;
+            }
+        }
+    }
+    Ok(removed)
+}
+
+// ============================================================================
+// Schema
+// ============================================================================
+
+/// Triggers dropping the attachments of deleted records; their files are left for `prune_files`
+pub(crate) async fn install_triggers(conn: &DatabaseConnection) -> Result<(), DbErr> {
+    for (_, name, table, _) in &ENTITIES {
+        let trigger = format!("attachments_{}_delete", table);
+        conn.execute(Statement::from_string(DatabaseBackend::Sqlite, format!("DROP TRIGGER IF EXISTS {}", trigger))).await?;
+        conn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            format!(
+                "CREATE TRIGGER {} AFTER DELETE ON {} BEGIN DELETE FROM attachments WHERE entity_type = '{}' AND entity_id = OLD.id; END",
+                trigger, table, name
+            )
+        )).await?;
+    }
+    Ok(())
+}
+
+/// Move signature and stamp images kept as BLOBs in the former
+/// `delivery_note_attachments` table into the file store
+pub(crate) async fn migrate_delivery_note_attachments(conn: &DatabaseConnection) -> Result<(), DbErr> {
+    let legacy = conn.query_one(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'delivery_note_attachments'".to_string()
+    )).await?;
+    if legacy.is_none() {
+        return Ok(());
+    }
+
+    #[derive(Debug, FromQueryResult)]
+    struct LegacyRow {
+        delivery_note_id: i32,
+        kind: String,
+        file_name: String,
+        mime_type: String,
+        data: Vec<u8>,
+        created_at: String,
+    }
+
+    let rows = LegacyRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT delivery_note_id, kind, file_name, mime_type, data, created_at FROM delivery_note_attachments ORDER BY id".to_string()
+    ))
+    .all(conn)
+    .await?;
+    tracing::info!("Moving {} delivery note attachments to the file store...", rows.len());
+
+    let txn = conn.begin().await?;
+    for row in rows {
+        let stored = store_file(&row.mime_type, &row.data)
+            .map_err(|e| DbErr::Custom(format!("Attachment {} could not be stored: {}", row.file_name, e)))?;
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO attachments (entity_type, entity_id, role, file_name, mime_type, content_hash, size_bytes,
+                width, height, has_thumbnail, created_at)
+            VALUES ('DELIVERY_NOTE', ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            vec![
+                Value::Int(Some(row.delivery_note_id)),
+                Value::String(Some(Box::new(row.kind))),
+                Value::String(Some(Box::new(row.file_name))),
+                Value::String(Some(Box::new(row.mime_type))),
+                Value::String(Some(Box::new(stored.hash))),
+                Value::BigInt(Some(stored.size)),
+                Value::Int(stored.width),
+                Value::Int(stored.height),
+                Value::Int(Some(stored.has_thumbnail as i32)),
+                Value::String(Some(Box::new(row.created_at))),
+            ]
+        )).await?;
+    }
+    txn.execute(Statement::from_string(DatabaseBackend::Sqlite, "DROP TABLE delivery_note_attachments".to_string())).await?;
+    txn.commit().await?;
+    Ok(())
+}
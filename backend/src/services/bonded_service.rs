@@ -0,0 +1,283 @@
+//! Bonded Service - Duty-free stores for ships in international transit
+//!
+//! Alcohol, tobacco and similar goods may be kept duty-suspended in a bonded
+//! warehouse (antrepo) and supplied to foreign-flag ships calling at an
+//! international port, under customs supervision. Bonded supply items are
+//! stocked only in the bonded warehouse, every bonded stock movement carries
+//! a customs declaration number, and the movement log is reconciled against
+//! the stock records for periodic customs reporting.
+
+use crate::models::{
+    BondedEligibility, BondedReconciliation, BondedReconciliationLine, StockMovement, StockMovementType, WarehouseType,
+};
+use crate::database;
+use crate::services::{stock_service, invoice_service::parse_date, requisition_import_service::normalize_name};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, FromQueryResult)]
+struct FlagRow {
+    is_bonded: i32,
+}
+
+/// Whether the supply item is a bonded (duty-free) item
+pub(crate) async fn is_bonded_item<C: ConnectionTrait>(conn: &C, supply_item_id: i32) -> Result<bool> {
+    let row: Option<FlagRow> = FlagRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT is_bonded FROM supply_items WHERE id = ?",
+        vec![Value::Int(Some(supply_item_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    row.map(|r| r.is_bonded == 1)
+        .ok_or_else(|| anyhow::anyhow!("Supply item not found"))
+}
+
+/// The bonded flag of an item decides its warehouse, so it cannot change once the item is stocked
+pub(crate) async fn ensure_not_stocked<C: ConnectionTrait>(conn: &C, supply_item_id: i32) -> Result<()> {
+    let stocked = conn.query_one(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT 1 FROM stock WHERE supply_item_id = ?",
+        vec![Value::Int(Some(supply_item_id))]
+    )).await?;
+
+    if stocked.is_some() {
+        anyhow::bail!("The item has a stock record; its bonded flag cannot change");
+    }
+    Ok(())
+}
+
+#[derive(Debug, FromQueryResult)]
+struct EligibilityRow {
+    order_id: i32,
+    ship_name: Option<String>,
+    ship_flag: Option<String>,
+    port_name: Option<String>,
+    port_country: Option<String>,
+    is_international: Option<i32>,
+}
+
+/// ISO 3166 codes with the Turkish and English names used for flags and port countries
+const COUNTRIES: &[(&str, &[&str])] = &[
+    ("TR", &["Türkiye", "Turkey", "Turkiye"]),
+    ("GR", &["Yunanistan", "Greece"]),
+    ("CY", &["Kıbrıs", "Cyprus"]),
+    ("MT", &["Malta"]),
+    ("IT", &["İtalya", "Italy"]),
+    ("PA", &["Panama"]),
+    ("LR", &["Liberya", "Liberia"]),
+    ("MH", &["Marshall Adaları", "Marshall Islands"]),
+    ("BS", &["Bahama", "Bahamalar", "Bahamas"]),
+    ("BB", &["Barbados"]),
+    ("BZ", &["Belize"]),
+    ("BM", &["Bermuda"]),
+    ("AG", &["Antigua ve Barbuda", "Antigua and Barbuda"]),
+    ("VC", &["Saint Vincent ve Grenadinler", "Saint Vincent and the Grenadines"]),
+    ("KM", &["Komorlar", "Comoros"]),
+    ("TG", &["Togo"]),
+    ("SL", &["Sierra Leone"]),
+    ("MD", &["Moldova"]),
+    ("NO", &["Norveç", "Norway"]),
+    ("DK", &["Danimarka", "Denmark"]),
+    ("SE", &["İsveç", "Sweden"]),
+    ("FI", &["Finlandiya", "Finland"]),
+    ("DE", &["Almanya", "Germany"]),
+    ("NL", &["Hollanda", "Netherlands"]),
+    ("BE", &["Belçika", "Belgium"]),
+    ("FR", &["Fransa", "France"]),
+    ("ES", &["İspanya", "Spain"]),
+    ("PT", &["Portekiz", "Portugal"]),
+    ("GB", &["Birleşik Krallık", "İngiltere", "United Kingdom"]),
+    ("IE", &["İrlanda", "Ireland"]),
+    ("HR", &["Hırvatistan", "Croatia"]),
+    ("BG", &["Bulgaristan", "Bulgaria"]),
+    ("RO", &["Romanya", "Romania"]),
+    ("UA", &["Ukrayna", "Ukraine"]),
+    ("RU", &["Rusya", "Russia"]),
+    ("GE", &["Gürcistan", "Georgia"]),
+    ("EG", &["Mısır", "Egypt"]),
+    ("IL", &["İsrail", "Israel"]),
+    ("LB", &["Lübnan", "Lebanon"]),
+    ("US", &["ABD", "Amerika Birleşik Devletleri", "United States"]),
+    ("SG", &["Singapur", "Singapore"]),
+    ("HK", &["Hong Kong"]),
+    ("CN", &["Çin", "China"]),
+    ("JP", &["Japonya", "Japan"]),
+    ("KR", &["Güney Kore", "South Korea"]),
+    ("IN", &["Hindistan", "India"]),
+];
+
+/// ISO country code of a flag or port country given either as a code or a name
+fn country_code(value: &str) -> Option<&'static str> {
+    let value = value.trim();
+    let name = normalize_name(value);
+    COUNTRIES.iter()
+        .find(|(code, names)| {
+            code.eq_ignore_ascii_case(value) || names.iter().any(|n| normalize_name(n) == name)
+        })
+        .map(|(code, _)| *code)
+}
+
+async fn eligibility<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<BondedEligibility> {
+    let row: EligibilityRow = EligibilityRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT o.id as order_id, sh.name as ship_name, sh.flag as ship_flag,
+               p.name as port_name, p.country as port_country, p.is_international
+        FROM orders o
+        LEFT JOIN ships sh ON o.ship_id = sh.id
+        LEFT JOIN ship_visits sv ON o.ship_visit_id = sv.id
+        LEFT JOIN ports p ON sv.port_id = p.id
+        WHERE o.id = ?
+        "#,
+        vec![Value::Int(Some(order_id))]
+    ))
+    .one(conn)
+    .await?
+    .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    let is_international_port = row.is_international == Some(1);
+    let reason = match (&row.ship_flag, &row.port_name, &row.port_country) {
+        (None, _, _) => Some("The ship's flag is not known".to_string()),
+        (_, None, _) | (_, _, None) => Some("The order is not linked to a port visit".to_string()),
+        (Some(_), Some(port), Some(_)) if !is_international_port => {
+            Some(format!("{} is not an international port", port))
+        }
+        (Some(flag), Some(_), Some(country)) => match (country_code(flag), country_code(country)) {
+            (None, _) => Some(format!("The ship's flag {} is not a known country", flag)),
+            (_, None) => Some(format!("The port country {} is not a known country", country)),
+            (Some(flag_code), Some(country_code)) if flag_code == country_code => {
+                Some(format!("The ship flies the flag of the port country ({})", flag))
+            }
+            _ => None,
+        },
+    };
+
+    Ok(BondedEligibility {
+        order_id: row.order_id,
+        ship_name: row.ship_name,
+        ship_flag: row.ship_flag,
+        port_name: row.port_name,
+        port_country: row.port_country,
+        is_international_port,
+        is_eligible: reason.is_none(),
+        reason,
+    })
+}
+
+/// Whether bonded goods may be supplied for an order: a foreign-flag ship
+/// visiting an international port
+pub async fn get_eligibility(order_id: i32) -> Result<BondedEligibility> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    eligibility(&conn, order_id).await
+}
+
+/// Refuse bonded goods for an order whose ship is not eligible
+pub(crate) async fn ensure_eligible<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<()> {
+    let eligibility = eligibility(conn, order_id).await?;
+    if let Some(reason) = eligibility.reason {
+        anyhow::bail!(
+            "Bonded goods cannot be supplied to {}: {}",
+            eligibility.ship_name.as_deref().unwrap_or("this ship"),
+            reason
+        );
+    }
+    Ok(())
+}
+
+/// Stock level after a movement, following the rules of `stock_service`
+fn apply(quantity: f64, movement: &StockMovement) -> f64 {
+    match movement.movement_type {
+        StockMovementType::In | StockMovementType::Return => quantity + movement.quantity,
+        StockMovementType::Out => (quantity - movement.quantity).max(0.0),
+        StockMovementType::Adjustment => movement.quantity,
+    }
+}
+
+/// Bonded stock reconciliation for customs over a period (YYYY-MM-DD, inclusive).
+///
+/// Bonded stock starts empty, so replaying the movement log gives the opening
+/// and closing balance of every item and the balance expected today. A
+/// difference against the stock record means stock changed outside the log.
+pub async fn reconciliation(period_start: &str, period_end: &str) -> Result<BondedReconciliation> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let start = parse_date(period_start)?.format("%Y-%m-%d").to_string();
+    let end = parse_date(period_end)?.format("%Y-%m-%d").to_string();
+    if start > end {
+        anyhow::bail!("The reconciliation period ends before it starts");
+    }
+
+    let stock: Vec<_> = stock_service::get_all().await?
+        .into_iter()
+        .filter(|s| s.warehouse_type == WarehouseType::Bonded)
+        .collect();
+    let movements = stock_service::bonded_movements(&conn).await?;
+
+    let mut by_stock: HashMap<i32, Vec<&StockMovement>> = HashMap::new();
+    for movement in &movements {
+        by_stock.entry(movement.stock_id).or_default().push(movement);
+    }
+
+    let lines = stock.iter().map(|s| {
+        let mut line = BondedReconciliationLine {
+            stock_id: s.id,
+            supply_item_id: s.supply_item_id,
+            supply_item_name: s.supply_item_name.clone(),
+            unit: s.unit.clone(),
+            warehouse_location: s.warehouse_location.clone(),
+            opening_quantity: 0.0,
+            received_quantity: 0.0,
+            returned_quantity: 0.0,
+            issued_quantity: 0.0,
+            adjusted_quantity: 0.0,
+            closing_quantity: 0.0,
+            expected_quantity: 0.0,
+            recorded_quantity: s.quantity,
+            difference: 0.0,
+        };
+
+        let mut balance = 0.0;
+        for movement in by_stock.get(&s.id).map(Vec::as_slice).unwrap_or_default() {
+            let date = movement.created_at.get(..10).unwrap_or(&movement.created_at);
+            let before = balance;
+            balance = apply(balance, movement);
+            if date < start.as_str() {
+                line.opening_quantity = balance;
+            } else if date <= end.as_str() {
+                match movement.movement_type {
+                    StockMovementType::In => line.received_quantity += balance - before,
+                    StockMovementType::Return => line.returned_quantity += balance - before,
+                    StockMovementType::Out => line.issued_quantity += before - balance,
+                    StockMovementType::Adjustment => line.adjusted_quantity += balance - before,
+                }
+            }
+        }
+        line.closing_quantity = line.opening_quantity + line.received_quantity + line.returned_quantity
+            - line.issued_quantity + line.adjusted_quantity;
+        line.expected_quantity = balance;
+        line.difference = line.recorded_quantity - balance;
+        line
+    }).collect();
+
+    let movements = movements.into_iter()
+        .filter(|m| {
+            let date = m.created_at.get(..10).unwrap_or(&m.created_at);
+            date >= start.as_str() && date <= end.as_str()
+        })
+        .collect();
+
+    Ok(BondedReconciliation {
+        period_start: start,
+        period_end: end,
+        lines,
+        movements,
+    })
+}
@@ -2,33 +2,74 @@
 
 use crate::models::{ItemProfit, OrderTotals, ProfitSummary, OrderProfitInfo};
 use crate::database;
+use crate::services::{order_service, order_item_service, order_charge_service, landed_cost_service, pricing_service, return_service};
 use anyhow::Result;
 use sea_orm::{Statement, DatabaseBackend, FromQueryResult};
 
 /// Calculate profit for a single item
 /// 
 /// Formulas:
-/// - Gross Profit = (Selling Price - Buying Price) × Quantity
-/// - Margin (%) = ((Selling Price - Buying Price) / Selling Price) × 100
-pub fn calculate_item_profit(buying_price: f64, selling_price: f64, quantity: f64) -> ItemProfit {
-    let total_cost = buying_price * quantity;
+/// - Unit Cost = Buying Price + Landed Cost per Unit
+/// - Gross Profit = (Selling Price - Unit Cost) × Quantity
+/// - Margin (%) = ((Selling Price - Unit Cost) / Selling Price) × 100
+///
+/// The item is flagged when its margin is below `min_margin_percent`.
+pub fn calculate_item_profit(
+    buying_price: f64,
+    selling_price: f64,
+    quantity: f64,
+    landed_cost_per_unit: f64,
+    min_margin_percent: Option<f64>,
+) -> ItemProfit {
+    let unit_cost = buying_price + landed_cost_per_unit;
+    let total_cost = unit_cost * quantity;
     let total_revenue = selling_price * quantity;
-    let gross_profit = (selling_price - buying_price) * quantity;
+    let gross_profit = (selling_price - unit_cost) * quantity;
     
     let margin_percent = if selling_price > 0.0 {
-        Some(((selling_price - buying_price) / selling_price) * 100.0)
+        Some(((selling_price - unit_cost) / selling_price) * 100.0)
     } else {
         None
     };
 
+    let below_margin_floor = match (min_margin_percent, margin_percent) {
+        (Some(floor), Some(margin)) => margin < floor - 1e-9,
+        (Some(_), None) => unit_cost > 0.0,
+        (None, _) => false,
+    };
+
     ItemProfit {
         total_cost,
         total_revenue,
         gross_profit,
         margin_percent,
+        landed_cost: landed_cost_per_unit * quantity,
+        min_margin_percent,
+        below_margin_floor,
     }
 }
 
+/// Profit of an order line including its landed cost, checked against the margin floor of the pricing rules
+pub async fn calculate_order_item_profit(order_item_id: i32) -> Result<ItemProfit> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let item = order_item_service::get_by_id(order_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order item not found"))?;
+    let order = order_service::get_by_id(item.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    let min_margin_percent = pricing_service::find_margin_floor(
+        &conn, &order, item.impa_code.as_deref(), item.buying_price
+    ).await?;
+
+    let landed_cost = landed_cost_service::allocated_to_item(&conn, item.id).await?;
+    let landed_cost_per_unit = if item.quantity > 0.0 { landed_cost / item.quantity } else { 0.0 };
+
+    Ok(calculate_item_profit(item.buying_price, item.selling_price, item.quantity, landed_cost_per_unit, min_margin_percent))
+}
+
 #[derive(Debug, FromQueryResult)]
 struct OrderItemRow {
     buying_price: f64,
@@ -37,7 +78,7 @@ struct OrderItemRow {
     currency: String,
 }
 
-/// Calculate totals for an entire order
+/// Calculate totals for an entire order (item lines, order charges and landed costs, net of returns)
 pub async fn calculate_order_totals(order_id: i32) -> Result<OrderTotals> {
     let conn = database::get_connection()
         .await
@@ -55,34 +96,33 @@ pub async fn calculate_order_totals(order_id: i32) -> Result<OrderTotals> {
     .all(&conn)
     .await?;
 
-    if items.is_empty() {
-        // Get order currency if no items
-        #[derive(Debug, FromQueryResult)]
-        struct OrderCurrency {
-            currency: String,
+    let (charge_count, charges_cost, charges_revenue) = order_charge_service::totals_for_order(&conn, order_id).await?;
+    let landed_cost = landed_cost_service::allocated_to_order(&conn, order_id).await?;
+    let (returns_revenue, returns_cost) = return_service::totals_for_order(&conn, order_id).await?;
+
+    let currency = match items.first() {
+        Some(item) => item.currency.clone(),
+        None => {
+            // Get order currency if no items
+            #[derive(Debug, FromQueryResult)]
+            struct OrderCurrency {
+                currency: String,
+            }
+
+            let order_currency: Option<OrderCurrency> = OrderCurrency::find_by_statement(Statement::from_string(
+                DatabaseBackend::Sqlite,
+                format!("SELECT currency FROM orders WHERE id = {}", order_id)
+            ))
+            .one(&conn)
+            .await?;
+
+            order_currency.map(|c| c.currency).unwrap_or_else(|| "USD".to_string())
         }
-        
-        let order_currency: Option<OrderCurrency> = OrderCurrency::find_by_statement(Statement::from_string(
-            DatabaseBackend::Sqlite,
-            format!("SELECT currency FROM orders WHERE id = {}", order_id)
-        ))
-        .one(&conn)
-        .await?;
-
-        return Ok(OrderTotals {
-            item_count: 0,
-            total_cost: 0.0,
-            total_revenue: 0.0,
-            gross_profit: 0.0,
-            margin_percent: None,
-            currency: order_currency.map(|c| c.currency).unwrap_or_else(|| "USD".to_string()),
-        });
-    }
+    };
 
     // Calculate totals
-    let mut total_cost = 0.0;
-    let mut total_revenue = 0.0;
-    let currency = items.first().map(|i| i.currency.clone()).unwrap_or_else(|| "USD".to_string());
+    let mut total_cost = charges_cost + landed_cost - returns_cost;
+    let mut total_revenue = charges_revenue - returns_revenue;
 
     for item in &items {
         total_cost += item.buying_price * item.quantity;
@@ -98,14 +138,41 @@ pub async fn calculate_order_totals(order_id: i32) -> Result<OrderTotals> {
 
     Ok(OrderTotals {
         item_count: items.len() as i32,
+        charge_count,
         total_cost,
         total_revenue,
+        charges_cost,
+        charges_revenue,
+        landed_cost,
+        returns_revenue,
+        returns_cost,
         gross_profit,
         margin_percent,
         currency,
     })
 }
 
+/// Per-order revenue and cost of item lines, charges and landed costs net of returns, for non-cancelled orders
+const ORDER_PROFIT_SQL: &str = r#"
+    SELECT
+        o.id as order_id,
+        o.order_number,
+        s.name as ship_name,
+        o.currency,
+        COALESCE((SELECT SUM(oi.selling_price * oi.quantity) FROM order_items oi WHERE oi.order_id = o.id), 0.0)
+          + COALESCE((SELECT SUM(oc.revenue_amount) FROM order_charges oc WHERE oc.order_id = o.id), 0.0)
+          - COALESCE((SELECT SUM(rl.credit_amount) FROM order_return_lines rl JOIN order_returns r ON rl.return_id = r.id WHERE r.order_id = o.id), 0.0) as total_revenue,
+        COALESCE((SELECT SUM(oi.buying_price * oi.quantity) FROM order_items oi WHERE oi.order_id = o.id), 0.0)
+          + COALESCE((SELECT SUM(oc.cost_amount) FROM order_charges oc WHERE oc.order_id = o.id), 0.0)
+          + COALESCE((SELECT SUM(a.amount) FROM landed_cost_allocations a JOIN order_items oi ON a.order_item_id = oi.id WHERE oi.order_id = o.id), 0.0)
+          - COALESCE((SELECT SUM(rl.cost_amount) FROM order_return_lines rl JOIN order_returns r ON rl.return_id = r.id WHERE r.order_id = o.id), 0.0) as total_cost,
+        (EXISTS (SELECT 1 FROM order_items oi WHERE oi.order_id = o.id)
+          OR EXISTS (SELECT 1 FROM order_charges oc WHERE oc.order_id = o.id)) as has_lines
+    FROM orders o
+    LEFT JOIN ships s ON o.ship_id = s.id
+    WHERE o.status != 'CANCELLED'
+"#;
+
 /// Get profit summary for all orders (for dashboard)
 pub async fn get_profit_summary() -> Result<ProfitSummary> {
     let conn = database::get_connection()
@@ -121,15 +188,14 @@ pub async fn get_profit_summary() -> Result<ProfitSummary> {
 
     let summary: Option<SummaryRow> = SummaryRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
-        r#"
+        format!(r#"
             SELECT 
-                COUNT(DISTINCT oi.order_id) as total_orders,
-                CAST(COALESCE(SUM(oi.selling_price * oi.quantity), 0.0) AS REAL) as total_revenue,
-                CAST(COALESCE(SUM(oi.buying_price * oi.quantity), 0.0) AS REAL) as total_cost
-            FROM order_items oi
-            INNER JOIN orders o ON oi.order_id = o.id
-            WHERE o.status != 'CANCELLED'
-        "#.to_string()
+                COUNT(*) as total_orders,
+                CAST(COALESCE(SUM(p.total_revenue), 0.0) AS REAL) as total_revenue,
+                CAST(COALESCE(SUM(p.total_cost), 0.0) AS REAL) as total_cost
+            FROM ({}) p
+            WHERE p.has_lines
+        "#, ORDER_PROFIT_SQL)
     ))
     .one(&conn)
     .await?;
@@ -184,26 +250,18 @@ pub async fn get_top_profitable_orders(limit: i32) -> Result<Vec<OrderProfitInfo
     let rows: Vec<ProfitRow> = ProfitRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
         format!(r#"
-            SELECT 
-                o.id as order_id,
-                o.order_number,
-                s.name as ship_name,
-                CAST(COALESCE(SUM(oi.selling_price * oi.quantity), 0.0) AS REAL) as total_revenue,
-                CAST(COALESCE(SUM(oi.buying_price * oi.quantity), 0.0) AS REAL) as total_cost,
-                o.currency
-            FROM orders o
-            LEFT JOIN ships s ON o.ship_id = s.id
-            LEFT JOIN order_items oi ON o.id = oi.order_id
-            WHERE o.status != 'CANCELLED'
-            GROUP BY o.id
-            HAVING total_revenue > 0
-            ORDER BY (total_revenue - total_cost) DESC
+            SELECT p.order_id, p.order_number, p.ship_name,
+                CAST(p.total_revenue AS REAL) as total_revenue,
+                CAST(p.total_cost AS REAL) as total_cost,
+                p.currency
+            FROM ({}) p
+            WHERE p.total_revenue > 0
+            ORDER BY (p.total_revenue - p.total_cost) DESC
             LIMIT {}
-        "#, limit)
+        "#, ORDER_PROFIT_SQL, limit)
     ))
     .all(&conn)
     .await?;
-
     Ok(rows.into_iter().map(|r| {
         let total_revenue = r.total_revenue.unwrap_or(0.0);
         let total_cost = r.total_cost.unwrap_or(0.0);
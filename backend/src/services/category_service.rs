@@ -0,0 +1,450 @@
+//! Category Service - Product category tree (Kumanya > Süt Ürünleri > Peynir)
+//!
+//! Suppliers, supply items, pricing rules and IMPA sections reference a
+//! category by id. Their old free-text `category` column is kept filled with
+//! the category code for older readers.
+
+use crate::models::{Category, CreateCategoryRequest, UpdateCategoryRequest};
+use crate::database;
+use crate::services::requisition_import_service::normalize_name;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, Statement, DatabaseBackend, FromQueryResult, Value};
+use std::collections::HashMap;
+
+/// Ids of a category and all of its subcategories (bind the category id)
+pub(crate) const SUBTREE_SQL: &str = r#"
+    WITH RECURSIVE subtree(id) AS (
+        SELECT ?
+        UNION
+        SELECT c.id FROM categories c JOIN subtree t ON c.parent_id = t.id
+    )
+    SELECT id FROM subtree
+"#;
+
+#[derive(Debug, FromQueryResult)]
+struct CategoryRow {
+    id: i32,
+    parent_id: Option<i32>,
+    code: String,
+    name_tr: String,
+    name_en: Option<String>,
+    sort_order: i32,
+    is_active: i32,
+    item_count: i32,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CountRow {
+    count: i32,
+}
+
+/// "Süt Ürünleri" -> "SUT_URUNLERI"
+fn code_from(text: &str) -> String {
+    normalize_name(text).to_uppercase().replace(' ', "_")
+}
+
+fn clean(value: Option<String>) -> Option<String> {
+    value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+/// Flatten the rows depth-first, siblings by sort order then name
+fn build_tree(rows: Vec<CategoryRow>) -> Vec<Category> {
+    let ids: Vec<i32> = rows.iter().map(|r| r.id).collect();
+    let mut children: HashMap<Option<i32>, Vec<CategoryRow>> = HashMap::new();
+    for row in rows {
+        let parent = row.parent_id.filter(|p| ids.contains(p));
+        children.entry(parent).or_default().push(row);
+    }
+    for siblings in children.values_mut() {
+        siblings.sort_by(|a, b| (a.sort_order, &a.name_tr).cmp(&(b.sort_order, &b.name_tr)));
+    }
+
+    fn visit(
+        parent: Option<i32>,
+        depth: i32,
+        path: &str,
+        children: &mut HashMap<Option<i32>, Vec<CategoryRow>>,
+        out: &mut Vec<Category>,
+    ) {
+        for row in children.remove(&parent).unwrap_or_default() {
+            let path = if path.is_empty() { row.name_tr.clone() } else { format!("{} > {}", path, row.name_tr) };
+            let id = row.id;
+            out.push(Category {
+                id: row.id,
+                parent_id: row.parent_id,
+                code: row.code,
+                name_tr: row.name_tr,
+                name_en: row.name_en,
+                depth,
+                path: path.clone(),
+                sort_order: row.sort_order,
+                is_active: row.is_active == 1,
+                item_count: row.item_count,
+                created_at: row.created_at,
+                updated_at: row.updated_at,
+            });
+            visit(Some(id), depth + 1, &path, children, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    visit(None, 0, "", &mut children, &mut out);
+    out
+}
+
+async fn load<C: ConnectionTrait>(conn: &C) -> Result<Vec<Category>> {
+    let rows: Vec<CategoryRow> = CategoryRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT c.id, c.parent_id, c.code, c.name_tr, c.name_en, c.sort_order, c.is_active,
+               (SELECT COUNT(*) FROM supply_items si WHERE si.category_id = c.id) as item_count,
+               c.created_at, c.updated_at
+        FROM categories c
+        "#.to_string()
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(build_tree(rows))
+}
+
+async fn subtree_ids<C: ConnectionTrait>(conn: &C, id: i32) -> Result<Vec<i32>> {
+    #[derive(Debug, FromQueryResult)]
+    struct IdRow {
+        id: i32,
+    }
+
+    let rows: Vec<IdRow> = IdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        SUBTREE_SQL,
+        vec![Value::Int(Some(id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.id).collect())
+}
+
+async fn code_taken<C: ConnectionTrait>(conn: &C, code: &str, except_id: Option<i32>) -> Result<bool> {
+    let row: Option<CountRow> = CountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT COUNT(*) as count FROM categories WHERE code = ? AND id != ?",
+        vec![
+            Value::String(Some(Box::new(code.to_string()))),
+            Value::Int(Some(except_id.unwrap_or(0))),
+        ]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.count > 0).unwrap_or(false))
+}
+
+async fn count<C: ConnectionTrait>(conn: &C, id: i32, sql: &str) -> Result<i32> {
+    let row: Option<CountRow> = CountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        sql,
+        vec![Value::Int(Some(id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.count).unwrap_or(0))
+}
+
+/// Code of an active category, for the legacy `category` text columns
+pub(crate) async fn active_code<C: ConnectionTrait>(conn: &C, id: i32) -> Result<String> {
+    #[derive(Debug, FromQueryResult)]
+    struct CodeRow {
+        code: String,
+        is_active: i32,
+    }
+
+    let row: Option<CodeRow> = CodeRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT code, is_active FROM categories WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    ))
+    .one(conn)
+    .await?;
+
+    match row {
+        Some(row) if row.is_active == 1 => Ok(row.code),
+        Some(row) => anyhow::bail!("Category {} is inactive", row.code),
+        None => anyhow::bail!("Category not found"),
+    }
+}
+
+/// Get the whole tree, depth-first
+pub async fn get_all() -> Result<Vec<Category>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    load(&conn).await
+}
+
+/// Get a category by ID
+pub async fn get_by_id(id: i32) -> Result<Option<Category>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    Ok(load(&conn).await?.into_iter().find(|c| c.id == id))
+}
+
+/// Create a category; a blank code is derived from the English or Turkish name
+pub async fn create(req: CreateCategoryRequest) -> Result<Category> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let name_tr = req.name_tr.trim().to_string();
+    if name_tr.is_empty() {
+        anyhow::bail!("Category name is required");
+    }
+    let name_en = clean(req.name_en);
+    let code = match clean(Some(req.code)) {
+        Some(code) => code_from(&code),
+        None => code_from(name_en.as_deref().unwrap_or(&name_tr)),
+    };
+    if code.is_empty() {
+        anyhow::bail!("Category code is required");
+    }
+    if code_taken(&conn, &code, None).await? {
+        anyhow::bail!("Category code {} already exists", code);
+    }
+    if let Some(parent_id) = req.parent_id {
+        get_by_id(parent_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Parent category not found"))?;
+    }
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT INTO categories (parent_id, code, name_tr, name_en, sort_order) VALUES (?, ?, ?, ?, ?)",
+        vec![
+            Value::Int(req.parent_id),
+            Value::String(Some(Box::new(code))),
+            Value::String(Some(Box::new(name_tr))),
+            Value::String(name_en.map(Box::new)),
+            Value::Int(Some(req.sort_order.unwrap_or(0))),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created category"))
+}
+
+/// Update a category's code, names, order or active flag
+pub async fn update(id: i32, req: UpdateCategoryRequest) -> Result<Category> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let existing = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Category not found"))?;
+
+    let code = match clean(req.code) {
+        Some(code) => code_from(&code),
+        None => existing.code.clone(),
+    };
+    if code.is_empty() {
+        anyhow::bail!("Category code is required");
+    }
+    if code != existing.code && code_taken(&conn, &code, Some(id)).await? {
+        anyhow::bail!("Category code {} already exists", code);
+    }
+    let name_tr = clean(req.name_tr).unwrap_or(existing.name_tr);
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE categories SET code = ?, name_tr = ?, name_en = ?, sort_order = ?, is_active = ?, updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::String(Some(Box::new(code.clone()))),
+            Value::String(Some(Box::new(name_tr))),
+            Value::String(clean(req.name_en).or(existing.name_en).map(Box::new)),
+            Value::Int(Some(req.sort_order.unwrap_or(existing.sort_order))),
+            Value::Int(Some(req.is_active.unwrap_or(existing.is_active) as i32)),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    if code != existing.code {
+        for table in ["suppliers", "supply_items", "pricing_rules", "impa_sections"] {
+            conn.execute(Statement::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                format!("UPDATE {} SET category = ? WHERE category_id = ?", table),
+                vec![Value::String(Some(Box::new(code.clone()))), Value::Int(Some(id))]
+            )).await?;
+        }
+    }
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve updated category"))
+}
+
+/// Move a category (with its subcategories) under another parent, or to the top level
+pub async fn move_category(id: i32, parent_id: Option<i32>) -> Result<Category> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Category not found"))?;
+    if let Some(parent_id) = parent_id {
+        get_by_id(parent_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Parent category not found"))?;
+        if subtree_ids(&conn, id).await?.contains(&parent_id) {
+            anyhow::bail!("A category cannot be moved under itself or one of its subcategories");
+        }
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE categories SET parent_id = ?, updated_at = datetime('now') WHERE id = ?",
+        vec![Value::Int(parent_id), Value::Int(Some(id))]
+    )).await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve moved category"))
+}
+
+/// Delete a category that has no subcategories and is not in use
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    if count(&conn, id, "SELECT COUNT(*) as count FROM categories WHERE parent_id = ?").await? > 0 {
+        anyhow::bail!("Category has subcategories; move or delete them first");
+    }
+    let in_use = count(&conn, id, "SELECT COUNT(*) as count FROM supply_items WHERE category_id = ?").await?
+        + count(&conn, id, "SELECT COUNT(*) as count FROM suppliers WHERE category_id = ?").await?
+        + count(&conn, id, "SELECT COUNT(*) as count FROM pricing_rules WHERE category_id = ?").await?
+        + count(&conn, id, "SELECT COUNT(*) as count FROM impa_sections WHERE category_id = ?").await?;
+    if in_use > 0 {
+        anyhow::bail!("Category is used by suppliers, supply items, pricing rules or IMPA sections; deactivate it instead");
+    }
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM categories WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Map the free-text categories of suppliers, supply items, pricing rules and
+/// IMPA sections onto the tree.
+///
+/// Texts are matched on code and Turkish or English name, ignoring case,
+/// Turkish letters and underscores, so "Provisions", "provisions" and
+/// "Kumanya" all land on PROVISIONS. Unmatched texts become top-level
+/// categories. Rows that already have a category id are left alone.
+pub(crate) async fn link_legacy_categories(conn: &DatabaseConnection) -> Result<(), DbErr> {
+    #[derive(Debug, FromQueryResult)]
+    struct NameRow {
+        id: i32,
+        code: String,
+        name_tr: String,
+        name_en: Option<String>,
+    }
+
+    #[derive(Debug, FromQueryResult)]
+    struct TextRow {
+        category: String,
+    }
+
+    const TABLES: [&str; 4] = ["suppliers", "supply_items", "pricing_rules", "impa_sections"];
+
+    // Starter tree for databases that never had one
+    conn.execute(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO categories (code, name_tr, name_en, sort_order)
+        SELECT code, name_tr, name_en, sort_order FROM (
+            SELECT 'PROVISIONS' as code, 'Kumanya' as name_tr, 'Provisions' as name_en, 1 as sort_order
+            UNION ALL SELECT 'TECHNICAL', 'Teknik Malzeme', 'Technical Stores', 2
+            UNION ALL SELECT 'DECK_STORES', 'Güverte Malzemeleri', 'Deck Stores', 3
+            UNION ALL SELECT 'SAFETY', 'Emniyet Ekipmanı', 'Safety Equipment', 4
+        )
+        WHERE NOT EXISTS (SELECT 1 FROM categories)
+        "#.to_string()
+    )).await?;
+
+    let texts: Vec<TextRow> = TextRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        TABLES.iter()
+            .map(|t| format!("SELECT DISTINCT category FROM {} WHERE category_id IS NULL AND TRIM(COALESCE(category, '')) != ''", t))
+            .collect::<Vec<_>>()
+            .join(" UNION ")
+    ))
+    .all(conn)
+    .await?;
+    if texts.is_empty() {
+        return Ok(());
+    }
+
+    let categories: Vec<NameRow> = NameRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT id, code, name_tr, name_en FROM categories ORDER BY parent_id IS NOT NULL, id".to_string()
+    ))
+    .all(conn)
+    .await?;
+
+    // Top-level categories win over subcategories with the same name
+    let mut by_name: HashMap<String, i32> = HashMap::new();
+    for c in categories.iter().rev() {
+        for name in [Some(&c.code), Some(&c.name_tr), c.name_en.as_ref()].into_iter().flatten() {
+            by_name.insert(normalize_name(name), c.id);
+        }
+    }
+
+    for text in texts {
+        let key = normalize_name(&text.category);
+        let id = match by_name.get(&key) {
+            Some(id) => *id,
+            None => {
+                let result = conn.execute(Statement::from_sql_and_values(
+                    DatabaseBackend::Sqlite,
+                    "INSERT INTO categories (code, name_tr) VALUES (?, ?)",
+                    vec![
+                        Value::String(Some(Box::new(code_from(&text.category)))),
+                        Value::String(Some(Box::new(text.category.trim().to_string()))),
+                    ]
+                )).await?;
+                let id = result.last_insert_id() as i32;
+                tracing::info!("Created category {} for legacy text '{}'", code_from(&text.category), text.category);
+                by_name.insert(key, id);
+                id
+            }
+        };
+
+        for table in TABLES {
+            conn.execute(Statement::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                format!(
+                    r#"
+                    UPDATE {0} SET category_id = ?, category = (SELECT code FROM categories WHERE id = ?)
+                    WHERE category_id IS NULL AND category = ?
+                    "#,
+                    table
+                ),
+                vec![
+                    Value::Int(Some(id)),
+                    Value::Int(Some(id)),
+                    Value::String(Some(Box::new(text.category.clone()))),
+                ]
+            )).await?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Company Service - Our own letterhead details (name, tax and bank info)
+
+use crate::models::{CompanyProfile, UpdateCompanyProfileRequest};
+use crate::database;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct CompanyProfileRow {
+    name: String,
+    address: Option<String>,
+    phone: Option<String>,
+    email: Option<String>,
+    website: Option<String>,
+    tax_office: Option<String>,
+    tax_number: Option<String>,
+    bank_name: Option<String>,
+    iban: Option<String>,
+    swift: Option<String>,
+    updated_at: String,
+}
+
+impl From<CompanyProfileRow> for CompanyProfile {
+    fn from(row: CompanyProfileRow) -> Self {
+        CompanyProfile {
+            name: row.name,
+            address: row.address,
+            phone: row.phone,
+            email: row.email,
+            website: row.website,
+            tax_office: row.tax_office,
+            tax_number: row.tax_number,
+            bank_name: row.bank_name,
+            iban: row.iban,
+            swift: row.swift,
+            updated_at: Some(row.updated_at),
+        }
+    }
+}
+
+fn clean(value: Option<String>) -> Option<String> {
+    value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty())
+}
+
+/// Get the company profile (empty until it is first saved)
+pub async fn get_profile() -> Result<CompanyProfile> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<CompanyProfileRow> = CompanyProfileRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT name, address, phone, email, website, tax_office, tax_number, bank_name, iban, swift, updated_at
+        FROM company_profile WHERE id = 1
+        "#.to_string()
+    ))
+    .one(&conn)
+    .await?;
+
+    Ok(row.map(CompanyProfile::from).unwrap_or(CompanyProfile {
+        name: String::new(),
+        address: None,
+        phone: None,
+        email: None,
+        website: None,
+        tax_office: None,
+        tax_number: None,
+        bank_name: None,
+        iban: None,
+        swift: None,
+        updated_at: None,
+    }))
+}
+
+/// Save the company profile
+pub async fn update_profile(req: UpdateCompanyProfileRequest) -> Result<CompanyProfile> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let name = req.name.trim().to_string();
+    if name.is_empty() {
+        anyhow::bail!("Company name is required");
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO company_profile (id, name, address, phone, email, website, tax_office, tax_number, bank_name, iban, swift)
+        VALUES (1, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ON CONFLICT(id) DO UPDATE SET
+            name = excluded.name, address = excluded.address, phone = excluded.phone,
+            email = excluded.email, website = excluded.website, tax_office = excluded.tax_office,
+            tax_number = excluded.tax_number, bank_name = excluded.bank_name, iban = excluded.iban,
+            swift = excluded.swift, updated_at = datetime('now')
+        "#,
+        vec![
+            Value::String(Some(Box::new(name))),
+            Value::String(clean(req.address).map(Box::new)),
+            Value::String(clean(req.phone).map(Box::new)),
+            Value::String(clean(req.email).map(Box::new)),
+            Value::String(clean(req.website).map(Box::new)),
+            Value::String(clean(req.tax_office).map(Box::new)),
+            Value::String(clean(req.tax_number).map(Box::new)),
+            Value::String(clean(req.bank_name).map(Box::new)),
+            Value::String(clean(req.iban).map(Box::new)),
+            Value::String(clean(req.swift).map(Box::new)),
+        ]
+    )).await?;
+
+    get_profile().await
+}
@@ -0,0 +1,367 @@
+//! Compliance Service - Dangerous goods and prescription medicines on orders
+//!
+//! Paints, chemicals and gas cylinders are shipped as dangerous goods under a
+//! UN number and IMDG hazard class and need a safety data sheet (SDS) on file.
+//! Prescription-only medicines need a pharmacist's sign-off on every order.
+//! Order lines have no catalog reference, so their regulatory attributes are
+//! taken from the supply items with the same IMPA code (or, without a code,
+//! the same name). An order cannot be Prepared or sent on its way while a
+//! required document is missing.
+
+use crate::models::{
+    Order, OrderStatus, SupplyItem, ComplianceIssue, ComplianceIssueKind, OrderCompliance, PharmacistSignOffRequest,
+    DangerousGoodsLine, DangerousGoodsClassTotal, DangerousGoodsSummary, AttachmentEntity, CreateAttachmentRequest,
+};
+use crate::database;
+use crate::services::{order_service, supply_item_service, delivery_note_service, attachment_service};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+/// Attachment role of a supply item's safety data sheet
+const SDS_ROLE: &str = "SDS";
+
+/// IMDG hazard classes and divisions with their names
+const HAZARD_CLASSES: [(&str, &str); 21] = [
+    ("1", "Patlayıcılar"),
+    ("1.1", "Patlayıcılar"),
+    ("1.2", "Patlayıcılar"),
+    ("1.3", "Patlayıcılar"),
+    ("1.4", "Patlayıcılar"),
+    ("1.5", "Patlayıcılar"),
+    ("1.6", "Patlayıcılar"),
+    ("2.1", "Yanıcı Gazlar"),
+    ("2.2", "Yanıcı Olmayan, Zehirli Olmayan Gazlar"),
+    ("2.3", "Zehirli Gazlar"),
+    ("3", "Yanıcı Sıvılar"),
+    ("4.1", "Yanıcı Katılar"),
+    ("4.2", "Kendiliğinden Yanmaya Yatkın Maddeler"),
+    ("4.3", "Su ile Temasında Yanıcı Gaz Çıkaran Maddeler"),
+    ("5.1", "Oksitleyici Maddeler"),
+    ("5.2", "Organik Peroksitler"),
+    ("6.1", "Zehirli Maddeler"),
+    ("6.2", "Bulaşıcı Maddeler"),
+    ("7", "Radyoaktif Maddeler"),
+    ("8", "Aşındırıcı Maddeler"),
+    ("9", "Çeşitli Tehlikeli Maddeler"),
+];
+
+/// Supply items `si` describing the order line `l`
+const CATALOG_MATCH: &str = r#"
+    (l.impa_code IS NOT NULL AND replace(si.impa_code, ' ', '') = replace(l.impa_code, ' ', ''))
+    OR (l.impa_code IS NULL AND lower(si.name) = lower(l.product_name))
+"#;
+
+const SDS_ON_FILE: &str = "EXISTS (SELECT 1 FROM attachments a WHERE a.entity_type = 'SUPPLY_ITEM' AND a.entity_id = si.id AND a.role = 'SDS')";
+
+/// Normalize a UN number to "UN1263" and check the IMDG hazard class.
+/// Dangerous goods need both; blank values count as none.
+pub(crate) fn check_dangerous_goods(un_number: Option<String>, hazard_class: Option<String>) -> Result<(Option<String>, Option<String>)> {
+    let un_number = match un_number.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        Some(value) => {
+            let digits: String = value.to_uppercase().trim_start_matches("UN").chars().filter(|c| !c.is_whitespace()).collect();
+            if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+                anyhow::bail!("Invalid UN number {}: expected four digits, e.g. UN1263", value);
+            }
+            Some(format!("UN{}", digits))
+        }
+        None => None,
+    };
+
+    let hazard_class = match hazard_class.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+        Some(value) => {
+            if !HAZARD_CLASSES.iter().any(|(class, _)| *class == value) {
+                anyhow::bail!("Unknown IMDG hazard class {}", value);
+            }
+            Some(value.to_string())
+        }
+        None => None,
+    };
+
+    if un_number.is_some() != hazard_class.is_some() {
+        anyhow::bail!("Dangerous goods need both a UN number and a hazard class");
+    }
+    Ok((un_number, hazard_class))
+}
+
+fn hazard_class_name(class: &str) -> &'static str {
+    HAZARD_CLASSES.iter()
+        .find(|(c, _)| *c == class)
+        .map(|(_, name)| *name)
+        .unwrap_or("")
+}
+
+#[derive(Debug, FromQueryResult)]
+struct RegulatedLineRow {
+    order_item_id: i32,
+    product_name: String,
+    quantity: f64,
+    unit: String,
+    un_number: Option<String>,
+    hazard_class: Option<String>,
+    needs_sds: i32,
+    sds_on_file: i32,
+    prescription_only: i32,
+    signed_quantity: Option<f64>,
+}
+
+/// Order lines that are dangerous goods, need an SDS or are prescription-only
+async fn regulated_lines<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<Vec<RegulatedLineRow>> {
+    Ok(RegulatedLineRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            r#"
+            SELECT l.id as order_item_id, l.product_name, l.quantity, l.unit,
+                   MAX(si.un_number) as un_number, MAX(si.hazard_class) as hazard_class,
+                   MAX(si.sds_required = 1 OR si.hazard_class IS NOT NULL) as needs_sds,
+                   MAX({}) as sds_on_file,
+                   MAX(si.prescription_only) as prescription_only,
+                   (SELECT ps.signed_quantity FROM pharmacist_signoffs ps WHERE ps.order_item_id = l.id) as signed_quantity
+            FROM order_items l
+            JOIN supply_items si ON {}
+            WHERE l.order_id = ?
+            GROUP BY l.id
+            HAVING MAX(si.sds_required = 1 OR si.hazard_class IS NOT NULL) = 1 OR MAX(si.prescription_only) = 1
+            ORDER BY l.id
+            "#,
+            SDS_ON_FILE, CATALOG_MATCH
+        ),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(conn)
+    .await?)
+}
+
+fn needs_sign_off(line: &RegulatedLineRow) -> bool {
+    line.prescription_only == 1 && line.signed_quantity.is_none_or(|q| q + 1e-9 < line.quantity)
+}
+
+async fn compliance<C: ConnectionTrait>(conn: &C, order: &Order) -> Result<OrderCompliance> {
+    let lines = regulated_lines(conn, order.id).await?;
+
+    let mut issues = Vec::new();
+    for line in &lines {
+        if line.needs_sds == 1 && line.sds_on_file == 0 {
+            let detail = match (&line.un_number, &line.hazard_class) {
+                (Some(un_number), Some(class)) => format!("{}, class {}: no safety data sheet on file", un_number, class),
+                _ => "No safety data sheet on file".to_string(),
+            };
+            issues.push(ComplianceIssue {
+                order_item_id: line.order_item_id,
+                product_name: line.product_name.clone(),
+                kind: ComplianceIssueKind::MissingSds,
+                detail,
+            });
+        }
+        if needs_sign_off(line) {
+            let detail = match line.signed_quantity {
+                Some(signed) => format!("Signed off for {} {}, ordered {} {}", signed, line.unit, line.quantity, line.unit),
+                None => "Not signed off by a pharmacist".to_string(),
+            };
+            issues.push(ComplianceIssue {
+                order_item_id: line.order_item_id,
+                product_name: line.product_name.clone(),
+                kind: ComplianceIssueKind::MissingPharmacistSignOff,
+                detail,
+            });
+        }
+    }
+
+    Ok(OrderCompliance {
+        order_id: order.id,
+        order_number: order.order_number.clone(),
+        regulated_lines: lines.len() as i32,
+        is_compliant: issues.is_empty(),
+        issues,
+    })
+}
+
+/// Refuse a status that puts the goods on their way while documents are missing
+pub(crate) async fn ensure_compliant<C: ConnectionTrait>(conn: &C, order: &Order, status: OrderStatus) -> Result<()> {
+    let result = compliance(conn, order).await?;
+    if !result.is_compliant {
+        let missing: Vec<String> = result.issues.iter()
+            .map(|i| format!("{} ({})", i.product_name, i.detail))
+            .collect();
+        anyhow::bail!(
+            "Order {} cannot be moved to {}: {}",
+            order.order_number, status.display_name(), missing.join("; ")
+        );
+    }
+    Ok(())
+}
+
+async fn get_order(order_id: i32) -> Result<Order> {
+    order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))
+}
+
+/// Documents missing on an order
+pub async fn check_order(order_id: i32) -> Result<OrderCompliance> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = get_order(order_id).await?;
+    compliance(&conn, &order).await
+}
+
+/// Record a pharmacist's sign-off for every prescription-only line of an
+/// order not yet signed off for its current quantity
+pub async fn sign_off_prescriptions(order_id: i32, req: PharmacistSignOffRequest) -> Result<OrderCompliance> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = get_order(order_id).await?;
+    if order.status == OrderStatus::Cancelled {
+        anyhow::bail!("Order {} is cancelled", order.order_number);
+    }
+    let pharmacist_name = req.pharmacist_name.trim();
+    let license_number = req.license_number.trim();
+    if pharmacist_name.is_empty() || license_number.is_empty() {
+        anyhow::bail!("Pharmacist name and license number are required");
+    }
+
+    let lines: Vec<RegulatedLineRow> = regulated_lines(&conn, order.id).await?
+        .into_iter()
+        .filter(needs_sign_off)
+        .collect();
+    if lines.is_empty() {
+        anyhow::bail!("Order {} has no prescription-only lines awaiting sign-off", order.order_number);
+    }
+
+    let txn = conn.begin().await?;
+    for line in &lines {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO pharmacist_signoffs (order_item_id, pharmacist_name, license_number, signed_quantity, notes)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(order_item_id) DO UPDATE SET
+                pharmacist_name = excluded.pharmacist_name, license_number = excluded.license_number,
+                signed_quantity = excluded.signed_quantity, notes = excluded.notes, signed_at = datetime('now')
+            "#,
+            vec![
+                Value::Int(Some(line.order_item_id)),
+                Value::String(Some(Box::new(pharmacist_name.to_string()))),
+                Value::String(Some(Box::new(license_number.to_string()))),
+                Value::Double(Some(line.quantity)),
+                Value::String(req.notes.clone().map(Box::new)),
+            ]
+        )).await?;
+    }
+    txn.commit().await?;
+
+    tracing::info!("Order {}: {} prescription lines signed off by {}", order.order_number, lines.len(), pharmacist_name);
+    compliance(&conn, &order).await
+}
+
+/// Put the safety data sheet (PDF or image) of a supply item on file, replacing the previous one
+pub async fn set_sds(supply_item_id: i32, file_name: String, mime_type: String, data: Vec<u8>) -> Result<SupplyItem> {
+    supply_item_service::get_by_id(supply_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supply item not found"))?;
+    if mime_type != "application/pdf" && !mime_type.starts_with("image/") {
+        anyhow::bail!("Safety data sheet must be a PDF or an image");
+    }
+
+    let request = CreateAttachmentRequest {
+        entity_type: AttachmentEntity::SupplyItem,
+        entity_id: supply_item_id,
+        file_name,
+        mime_type,
+        data,
+        caption: None,
+    };
+    attachment_service::replace_role(request, SDS_ROLE).await?;
+
+    supply_item_service::get_by_id(supply_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supply item not found"))
+}
+
+/// Dangerous goods handed over on a delivery note, with totals per hazard class
+pub async fn dangerous_goods_summary(delivery_note_id: i32) -> Result<DangerousGoodsSummary> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let note = delivery_note_service::get_by_id(delivery_note_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Delivery note not found"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct LineRow {
+        order_item_id: i32,
+        product_name: String,
+        impa_code: Option<String>,
+        quantity: f64,
+        unit: String,
+        un_number: String,
+        hazard_class: String,
+        sds_on_file: i32,
+    }
+
+    let rows = LineRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            r#"
+            WITH delivered AS (
+                SELECT oi.id, oi.product_name, oi.impa_code, oi.unit, CAST(SUM(d.quantity) AS REAL) as quantity
+                FROM order_item_deliveries d
+                JOIN order_items oi ON d.order_item_id = oi.id
+                WHERE d.delivery_note_id = ?
+                GROUP BY oi.id
+            )
+            SELECT l.id as order_item_id, l.product_name, l.impa_code, l.quantity, l.unit,
+                   MAX(si.un_number) as un_number, MAX(si.hazard_class) as hazard_class,
+                   MAX({}) as sds_on_file
+            FROM delivered l
+            JOIN supply_items si ON {}
+            GROUP BY l.id
+            HAVING MAX(si.hazard_class) IS NOT NULL
+            ORDER BY MAX(si.hazard_class), l.id
+            "#,
+            SDS_ON_FILE, CATALOG_MATCH
+        ),
+        vec![Value::Int(Some(delivery_note_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut classes: Vec<DangerousGoodsClassTotal> = Vec::new();
+    for row in &rows {
+        let index = match classes.iter().position(|c| c.hazard_class == row.hazard_class) {
+            Some(index) => index,
+            None => {
+                classes.push(DangerousGoodsClassTotal {
+                    hazard_class: row.hazard_class.clone(),
+                    description: hazard_class_name(&row.hazard_class).to_string(),
+                    line_count: 0,
+                    un_numbers: Vec::new(),
+                });
+                classes.len() - 1
+            }
+        };
+        let total = &mut classes[index];
+        total.line_count += 1;
+        if !total.un_numbers.contains(&row.un_number) {
+            total.un_numbers.push(row.un_number.clone());
+        }
+    }
+
+    Ok(DangerousGoodsSummary {
+        delivery_note_id: note.id,
+        note_number: note.note_number,
+        order_number: note.order_number,
+        ship_name: note.ship_name,
+        lines: rows.into_iter().map(|r| DangerousGoodsLine {
+            order_item_id: r.order_item_id,
+            product_name: r.product_name,
+            impa_code: r.impa_code,
+            un_number: r.un_number,
+            hazard_class: r.hazard_class,
+            quantity: r.quantity,
+            unit: r.unit,
+            sds_on_file: r.sds_on_file == 1,
+        }).collect(),
+        classes,
+    })
+}
@@ -0,0 +1,156 @@
+//! Credit Note Service - Credits against customer invoices (returned goods)
+
+use crate::models::{CreditNote, Invoice, InvoiceStatus};
+use crate::database;
+use crate::services::{invoice_service, numbering};
+use crate::services::invoice_service::AMOUNT_EPSILON;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct CreditNoteRow {
+    id: i32,
+    credit_note_number: String,
+    invoice_id: i32,
+    invoice_number: Option<String>,
+    order_id: i32,
+    return_id: Option<i32>,
+    customer_name: String,
+    amount: f64,
+    currency: String,
+    issue_date: String,
+    notes: Option<String>,
+    created_at: String,
+}
+
+impl From<CreditNoteRow> for CreditNote {
+    fn from(row: CreditNoteRow) -> Self {
+        CreditNote {
+            id: row.id,
+            credit_note_number: row.credit_note_number,
+            invoice_id: row.invoice_id,
+            invoice_number: row.invoice_number,
+            order_id: row.order_id,
+            return_id: row.return_id,
+            customer_name: row.customer_name,
+            amount: row.amount,
+            currency: row.currency,
+            issue_date: row.issue_date,
+            notes: row.notes,
+            created_at: row.created_at,
+        }
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    cn.id, cn.credit_note_number, cn.invoice_id, i.invoice_number, cn.order_id, cn.return_id, cn.customer_name,
+    cn.amount, cn.currency, cn.issue_date, cn.notes, cn.created_at
+"#;
+
+const FROM_JOIN: &str = "FROM credit_notes cn LEFT JOIN invoices i ON cn.invoice_id = i.id";
+
+/// Get the credit notes of an order
+pub async fn get_by_order(order_id: i32) -> Result<Vec<CreditNote>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<CreditNoteRow> = CreditNoteRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE cn.order_id = ? ORDER BY cn.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(CreditNote::from).collect())
+}
+
+/// Get the credit notes issued against an invoice
+pub async fn get_by_invoice(invoice_id: i32) -> Result<Vec<CreditNote>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<CreditNoteRow> = CreditNoteRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE cn.invoice_id = ? ORDER BY cn.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(invoice_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(CreditNote::from).collect())
+}
+
+/// Get a credit note by ID
+pub async fn get_by_id(id: i32) -> Result<Option<CreditNote>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<CreditNoteRow> = CreditNoteRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE cn.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    Ok(row.map(CreditNote::from))
+}
+
+/// Latest non-cancelled invoice of an order (the one returns are credited against)
+pub(crate) async fn invoice_to_credit(order_id: i32) -> Result<Option<Invoice>> {
+    Ok(invoice_service::get_by_order(order_id).await?
+        .into_iter()
+        .rfind(|i| i.status != InvoiceStatus::Cancelled))
+}
+
+/// Issue a credit note against an invoice of the order, as part of a larger
+/// transaction. The credit cannot exceed what is left of the invoice after
+/// earlier credit notes. Returns the credit note ID.
+pub(crate) async fn issue<C: ConnectionTrait>(
+    conn: &C,
+    invoice: &Invoice,
+    return_id: Option<i32>,
+    amount: f64,
+    issue_date: &str,
+    notes: Option<String>,
+) -> Result<i32> {
+    if amount <= AMOUNT_EPSILON {
+        anyhow::bail!("Credit note amount must be positive");
+    }
+
+    let creditable = invoice.amount - invoice.credited_amount;
+    if amount > creditable + AMOUNT_EPSILON {
+        anyhow::bail!(
+            "Credit of {:.2} {} exceeds the {:.2} {} still creditable on invoice {}",
+            amount, invoice.currency, creditable, invoice.currency, invoice.invoice_number
+        );
+    }
+
+    let credit_note_number = numbering::next_document_number(conn, "credit_notes", "credit_note_number", "CN").await?;
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO credit_notes (credit_note_number, invoice_id, order_id, return_id, customer_name, amount, currency, issue_date, notes)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(credit_note_number))),
+            Value::Int(Some(invoice.id)),
+            Value::Int(Some(invoice.order_id)),
+            Value::Int(return_id),
+            Value::String(Some(Box::new(invoice.customer_name.clone()))),
+            Value::Double(Some(amount)),
+            Value::String(Some(Box::new(invoice.currency.clone()))),
+            Value::String(Some(Box::new(issue_date.to_string()))),
+            Value::String(notes.map(Box::new)),
+        ]
+    )).await?;
+
+    invoice_service::refresh_status(conn, invoice.id).await?;
+
+    Ok(result.last_insert_id() as i32)
+}
@@ -0,0 +1,546 @@
+//! Customer Service - Ship owner / manager accounts, contacts and credit checks
+
+use crate::models::{
+    Customer, CustomerContact, CreateCustomerRequest, UpdateCustomerRequest,
+    CreateCustomerContactRequest, CreditCheck, CurrencyExposure, InvoiceStatus, Order, OrderStatus, Ship,
+};
+use crate::database;
+use crate::services::{calculation_service, invoice_service, order_service, ship_service};
+use crate::services::invoice_service::AMOUNT_EPSILON;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+/// Payment terms used when a customer is created without explicit terms
+const DEFAULT_PAYMENT_TERMS_DAYS: i32 = 30;
+
+#[derive(Debug, FromQueryResult)]
+struct CustomerRow {
+    id: i32,
+    legal_name: String,
+    tax_number: Option<String>,
+    tax_office: Option<String>,
+    billing_address: Option<String>,
+    country: Option<String>,
+    payment_terms_days: i32,
+    credit_limit: Option<f64>,
+    default_currency: String,
+    notes: Option<String>,
+    is_active: i32,
+    created_at: String,
+    updated_at: String,
+}
+
+impl CustomerRow {
+    fn into_customer(self, contacts: Vec<CustomerContact>) -> Customer {
+        Customer {
+            id: self.id,
+            legal_name: self.legal_name,
+            tax_number: self.tax_number,
+            tax_office: self.tax_office,
+            billing_address: self.billing_address,
+            country: self.country,
+            payment_terms_days: self.payment_terms_days,
+            credit_limit: self.credit_limit,
+            default_currency: self.default_currency,
+            notes: self.notes,
+            is_active: self.is_active == 1,
+            contacts,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct ContactRow {
+    id: i32,
+    customer_id: i32,
+    name: String,
+    role: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+    is_primary: i32,
+}
+
+impl From<ContactRow> for CustomerContact {
+    fn from(row: ContactRow) -> Self {
+        CustomerContact {
+            id: row.id,
+            customer_id: row.customer_id,
+            name: row.name,
+            role: row.role,
+            email: row.email,
+            phone: row.phone,
+            is_primary: row.is_primary == 1,
+        }
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    id, legal_name, tax_number, tax_office, billing_address, country, payment_terms_days,
+    credit_limit, default_currency, notes, is_active, created_at, updated_at
+"#;
+
+async fn get_contacts<C: ConnectionTrait>(conn: &C, customer_id: i32) -> Result<Vec<CustomerContact>> {
+    let rows: Vec<ContactRow> = ContactRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT id, customer_id, name, role, email, phone, is_primary
+        FROM customer_contacts
+        WHERE customer_id = ?
+        ORDER BY is_primary DESC, name
+        "#,
+        vec![Value::Int(Some(customer_id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(CustomerContact::from).collect())
+}
+
+async fn insert_contact<C: ConnectionTrait>(conn: &C, customer_id: i32, contact: CreateCustomerContactRequest) -> Result<i32> {
+    if contact.name.trim().is_empty() {
+        anyhow::bail!("Contact name is required");
+    }
+
+    // Only one primary contact per customer
+    if contact.is_primary {
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "UPDATE customer_contacts SET is_primary = 0 WHERE customer_id = ?",
+            vec![Value::Int(Some(customer_id))]
+        )).await?;
+    }
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT INTO customer_contacts (customer_id, name, role, email, phone, is_primary) VALUES (?, ?, ?, ?, ?, ?)",
+        vec![
+            Value::Int(Some(customer_id)),
+            Value::String(Some(Box::new(contact.name.trim().to_string()))),
+            Value::String(contact.role.map(Box::new)),
+            Value::String(contact.email.map(Box::new)),
+            Value::String(contact.phone.map(Box::new)),
+            Value::Int(Some(contact.is_primary as i32)),
+        ]
+    )).await?;
+
+    Ok(result.last_insert_id() as i32)
+}
+
+/// Get all active customers
+pub async fn get_all() -> Result<Vec<Customer>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<CustomerRow> = CustomerRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} FROM customers WHERE is_active = 1 ORDER BY legal_name", SELECT_FIELDS)
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut customers = Vec::with_capacity(rows.len());
+    for row in rows {
+        let contacts = get_contacts(&conn, row.id).await?;
+        customers.push(row.into_customer(contacts));
+    }
+    Ok(customers)
+}
+
+/// Get customer by ID (with contacts)
+pub async fn get_by_id(id: i32) -> Result<Option<Customer>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<CustomerRow> = CustomerRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} FROM customers WHERE id = ? AND is_active = 1", SELECT_FIELDS),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    match row {
+        Some(row) => {
+            let contacts = get_contacts(&conn, row.id).await?;
+            Ok(Some(row.into_customer(contacts)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Search customers by legal name or tax number
+pub async fn search(query: &str) -> Result<Vec<Customer>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let search_term = format!("%{}%", query);
+
+    let rows: Vec<CustomerRow> = CustomerRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            "SELECT {} FROM customers WHERE is_active = 1 AND (legal_name LIKE ? OR tax_number LIKE ?) ORDER BY legal_name",
+            SELECT_FIELDS
+        ),
+        vec![
+            Value::String(Some(Box::new(search_term.clone()))),
+            Value::String(Some(Box::new(search_term))),
+        ]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut customers = Vec::with_capacity(rows.len());
+    for row in rows {
+        let contacts = get_contacts(&conn, row.id).await?;
+        customers.push(row.into_customer(contacts));
+    }
+    Ok(customers)
+}
+
+/// Create a customer with its contacts
+pub async fn create(customer: CreateCustomerRequest) -> Result<Customer> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let legal_name = customer.legal_name.trim().to_string();
+    if legal_name.is_empty() {
+        anyhow::bail!("Customer legal name is required");
+    }
+    let payment_terms_days = customer.payment_terms_days.unwrap_or(DEFAULT_PAYMENT_TERMS_DAYS);
+    if payment_terms_days < 0 {
+        anyhow::bail!("Payment terms cannot be negative");
+    }
+    if customer.credit_limit.is_some_and(|limit| limit < 0.0) {
+        anyhow::bail!("Credit limit cannot be negative");
+    }
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO customers (legal_name, tax_number, tax_office, billing_address, country, payment_terms_days, credit_limit, default_currency, notes)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(legal_name))),
+            Value::String(customer.tax_number.map(Box::new)),
+            Value::String(customer.tax_office.map(Box::new)),
+            Value::String(customer.billing_address.map(Box::new)),
+            Value::String(customer.country.map(Box::new)),
+            Value::Int(Some(payment_terms_days)),
+            Value::Double(customer.credit_limit),
+            Value::String(Some(Box::new(customer.default_currency.unwrap_or_else(|| "USD".to_string())))),
+            Value::String(customer.notes.map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    for contact in customer.contacts {
+        insert_contact(&txn, id, contact).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created customer"))
+}
+
+/// Update a customer. Renaming also updates the name on linked ships, invoices, payments and credit notes.
+pub async fn update(id: i32, customer: UpdateCustomerRequest) -> Result<Customer> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let existing = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+
+    let legal_name = customer.legal_name
+        .map(|n| n.trim().to_string())
+        .unwrap_or(existing.legal_name);
+    if legal_name.is_empty() {
+        anyhow::bail!("Customer legal name is required");
+    }
+    let payment_terms_days = customer.payment_terms_days.unwrap_or(existing.payment_terms_days);
+    if payment_terms_days < 0 {
+        anyhow::bail!("Payment terms cannot be negative");
+    }
+    let credit_limit = if customer.clear_credit_limit {
+        None
+    } else {
+        customer.credit_limit.or(existing.credit_limit)
+    };
+    if credit_limit.is_some_and(|limit| limit < 0.0) {
+        anyhow::bail!("Credit limit cannot be negative");
+    }
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE customers SET legal_name = ?, tax_number = ?, tax_office = ?, billing_address = ?, country = ?,
+            payment_terms_days = ?, credit_limit = ?, default_currency = ?, notes = ?, updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::String(Some(Box::new(legal_name.clone()))),
+            Value::String(customer.tax_number.or(existing.tax_number).map(Box::new)),
+            Value::String(customer.tax_office.or(existing.tax_office).map(Box::new)),
+            Value::String(customer.billing_address.or(existing.billing_address).map(Box::new)),
+            Value::String(customer.country.or(existing.country).map(Box::new)),
+            Value::Int(Some(payment_terms_days)),
+            Value::Double(credit_limit),
+            Value::String(Some(Box::new(customer.default_currency.unwrap_or(existing.default_currency)))),
+            Value::String(customer.notes.or(existing.notes).map(Box::new)),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    // Ships, invoices and payments keep the name next to the link; credit notes take it from their invoice
+    for sql in [
+        "UPDATE ships SET owner = ?1, updated_at = datetime('now') WHERE owner_id = ?2",
+        "UPDATE invoices SET customer_name = ?1 WHERE customer_id = ?2",
+        "UPDATE payments SET customer_name = ?1 WHERE customer_id = ?2",
+        "UPDATE credit_notes SET customer_name = ?1 WHERE invoice_id IN (SELECT id FROM invoices WHERE customer_id = ?2)",
+    ] {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            sql,
+            vec![
+                Value::String(Some(Box::new(legal_name.clone()))),
+                Value::Int(Some(id)),
+            ]
+        )).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve updated customer"))
+}
+
+/// Deactivate a customer (soft delete - invoices and orders keep their link)
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE customers SET is_active = 0, updated_at = datetime('now') WHERE id = ? AND is_active = 1",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Add a contact person to a customer
+pub async fn add_contact(customer_id: i32, contact: CreateCustomerContactRequest) -> Result<Customer> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    get_by_id(customer_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+
+    let txn = conn.begin().await?;
+    insert_contact(&txn, customer_id, contact).await?;
+    txn.commit().await?;
+
+    get_by_id(customer_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))
+}
+
+/// Remove a contact person
+pub async fn delete_contact(contact_id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM customer_contacts WHERE id = ?",
+        vec![Value::Int(Some(contact_id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Get the ships owned / managed by a customer
+pub async fn get_ships(customer_id: i32) -> Result<Vec<Ship>> {
+    let ships = ship_service::get_all().await?;
+    Ok(ships.into_iter().filter(|s| s.owner_id == Some(customer_id)).collect())
+}
+
+/// Credit exposure of a customer, with the order under review on top.
+///
+/// Exposure is open invoice balances plus the revenue of active orders that
+/// are not invoiced yet. Amounts in the customer's default currency count
+/// against the limit; there are no exchange rates to convert the others, so
+/// they are reported per currency. `order` is the order under review with
+/// its revenue in its own currency; it is kept out of the uninvoiced orders
+/// so it is not counted twice.
+async fn check_credit(customer: &Customer, order: Option<(&Order, f64)>) -> Result<CreditCheck> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct CurrencyAmountRow {
+        currency: String,
+        amount: f64,
+    }
+
+    let receivables: Vec<CurrencyAmountRow> = CurrencyAmountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT i.currency, CAST(COALESCE(SUM(i.amount - COALESCE(
+            (SELECT SUM(pa.amount * pa.exchange_rate) FROM payment_allocations pa WHERE pa.invoice_id = i.id), 0.0)
+            - COALESCE((SELECT SUM(cn.amount) FROM credit_notes cn WHERE cn.invoice_id = i.id), 0.0)), 0.0) AS REAL) as amount
+        FROM invoices i
+        WHERE i.customer_id = ? AND i.status IN ('OPEN', 'PARTIALLY_PAID')
+        GROUP BY i.currency
+        "#,
+        vec![Value::Int(Some(customer.id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let uninvoiced: Vec<CurrencyAmountRow> = CurrencyAmountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT o.currency, CAST(COALESCE(SUM(
+                   COALESCE((SELECT SUM(oi.selling_price * oi.quantity) FROM order_items oi WHERE oi.order_id = o.id), 0.0)
+                 + COALESCE((SELECT SUM(oc.revenue_amount) FROM order_charges oc WHERE oc.order_id = o.id), 0.0)
+                 - COALESCE((SELECT SUM(rl.credit_amount) FROM order_return_lines rl
+                             JOIN order_returns r ON rl.return_id = r.id WHERE r.order_id = o.id), 0.0)
+               ), 0.0) AS REAL) as amount
+        FROM orders o
+        WHERE o.customer_id = ? AND o.id != ?
+          AND o.status NOT IN ('INVOICED', 'CANCELLED')
+          AND NOT EXISTS (SELECT 1 FROM invoices i WHERE i.order_id = o.id AND i.status != 'CANCELLED')
+        GROUP BY o.currency
+        "#,
+        vec![
+            Value::Int(Some(customer.id)),
+            Value::Int(Some(order.map(|(o, _)| o.id).unwrap_or(0))),
+        ]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut open_receivables = 0.0;
+    let mut uninvoiced_orders = 0.0;
+    let mut order_amount = 0.0;
+    let mut other_currencies: Vec<CurrencyExposure> = Vec::new();
+    let mut add = |currency: &str, receivable: f64, uninvoiced: f64| {
+        if currency == customer.default_currency {
+            open_receivables += receivable;
+            uninvoiced_orders += uninvoiced;
+        } else if let Some(exposure) = other_currencies.iter_mut().find(|e| e.currency == currency) {
+            exposure.open_receivables += receivable;
+            exposure.uninvoiced_orders += uninvoiced;
+        } else {
+            other_currencies.push(CurrencyExposure {
+                currency: currency.to_string(),
+                open_receivables: receivable,
+                uninvoiced_orders: uninvoiced,
+            });
+        }
+    };
+    for row in &receivables {
+        add(&row.currency, row.amount, 0.0);
+    }
+    for row in &uninvoiced {
+        add(&row.currency, 0.0, row.amount);
+    }
+    match order {
+        Some((order, amount)) if order.currency == customer.default_currency => order_amount = amount,
+        Some((order, amount)) if amount > AMOUNT_EPSILON => add(&order.currency, 0.0, amount),
+        _ => {}
+    }
+    other_currencies.retain(|e| (e.open_receivables + e.uninvoiced_orders).abs() > AMOUNT_EPSILON);
+    other_currencies.sort_by(|a, b| a.currency.cmp(&b.currency));
+
+    let projected_exposure = open_receivables + uninvoiced_orders + order_amount;
+    let exceeds_limit = customer.credit_limit
+        .is_some_and(|limit| projected_exposure - limit > AMOUNT_EPSILON);
+
+    let mut warnings = Vec::new();
+    if exceeds_limit {
+        warnings.push(format!(
+            "{} would exceed its credit limit: exposure {:.2} {} / limit {:.2} {}",
+            customer.legal_name,
+            projected_exposure,
+            customer.default_currency,
+            customer.credit_limit.unwrap_or(0.0),
+            customer.default_currency
+        ));
+    }
+    if customer.credit_limit.is_some() && !other_currencies.is_empty() {
+        let amounts: Vec<String> = other_currencies.iter()
+            .map(|e| format!("{:.2} {}", e.open_receivables + e.uninvoiced_orders, e.currency))
+            .collect();
+        warnings.push(format!(
+            "{} exposure is not counted against the {} limit",
+            amounts.join(", "),
+            customer.default_currency
+        ));
+    }
+    let warning = (!warnings.is_empty()).then(|| warnings.join("; "));
+
+    Ok(CreditCheck {
+        customer_id: customer.id,
+        customer_name: customer.legal_name.clone(),
+        credit_limit: customer.credit_limit,
+        currency: customer.default_currency.clone(),
+        open_receivables,
+        uninvoiced_orders,
+        order_amount,
+        projected_exposure,
+        exceeds_limit,
+        other_currencies,
+        warning,
+    })
+}
+
+/// Current credit exposure of a customer
+pub async fn get_credit_status(customer_id: i32) -> Result<CreditCheck> {
+    let customer = get_by_id(customer_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+    check_credit(&customer, None).await
+}
+
+/// Credit check for an order. Returns None when the order has no customer.
+pub async fn check_order_credit(order_id: i32) -> Result<Option<CreditCheck>> {
+    let order = order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    let Some(customer_id) = order.customer_id else {
+        return Ok(None);
+    };
+    let Some(customer) = get_by_id(customer_id).await? else {
+        return Ok(None);
+    };
+
+    // Invoiced orders are already part of open receivables
+    let invoiced = order.status == OrderStatus::Invoiced
+        || invoice_service::get_by_order(order_id).await?
+            .iter()
+            .any(|i| i.status != InvoiceStatus::Cancelled);
+
+    let order_amount = if order.status != OrderStatus::Cancelled && !invoiced {
+        calculation_service::calculate_order_totals(order_id).await?.total_revenue
+    } else {
+        0.0
+    };
+
+    check_credit(&customer, Some((&order, order_amount))).await.map(Some)
+}
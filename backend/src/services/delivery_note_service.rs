@@ -0,0 +1,507 @@
+//! Delivery Note Service - Signed proof of delivery for goods handed over on board
+
+use crate::models::{
+    DeliveryNote, DeliveryNoteLine, DeliveryNoteAttachment, DeliveryNoteStatus, DeliveryNoteAttachmentKind,
+    CreateDeliveryNoteRequest, CompleteDeliveryNoteRequest, Order, OrderStatus, AttachmentEntity, CreateAttachmentRequest,
+};
+use crate::database;
+use crate::services::{order_service, fulfilment_service, numbering, attachment_service};
+use crate::services::invoice_service::parse_date;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct DeliveryNoteRow {
+    id: i32,
+    note_number: String,
+    order_id: i32,
+    order_number: Option<String>,
+    ship_name: Option<String>,
+    ship_visit_id: Option<i32>,
+    status: String,
+    receiver_name: Option<String>,
+    receiver_rank: Option<String>,
+    signed_at: Option<String>,
+    notes: Option<String>,
+    created_at: String,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct LineRow {
+    order_item_id: i32,
+    product_name: String,
+    impa_code: Option<String>,
+    unit: String,
+    quantity: f64,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct AttachmentRow {
+    id: i32,
+    delivery_note_id: i32,
+    kind: String,
+    file_name: String,
+    mime_type: String,
+    size_bytes: i64,
+    created_at: String,
+}
+
+impl From<AttachmentRow> for DeliveryNoteAttachment {
+    fn from(row: AttachmentRow) -> Self {
+        DeliveryNoteAttachment {
+            id: row.id,
+            delivery_note_id: row.delivery_note_id,
+            kind: kind_from_str(&row.kind),
+            file_name: row.file_name,
+            mime_type: row.mime_type,
+            size_bytes: row.size_bytes,
+            created_at: row.created_at,
+        }
+    }
+}
+
+fn status_from_str(status: &str) -> DeliveryNoteStatus {
+    match status {
+        "COMPLETED" => DeliveryNoteStatus::Completed,
+        _ => DeliveryNoteStatus::Draft,
+    }
+}
+
+fn kind_from_str(kind: &str) -> DeliveryNoteAttachmentKind {
+    match kind {
+        "SHIP_STAMP" => DeliveryNoteAttachmentKind::ShipStamp,
+        _ => DeliveryNoteAttachmentKind::Signature,
+    }
+}
+
+fn kind_to_str(kind: DeliveryNoteAttachmentKind) -> &'static str {
+    match kind {
+        DeliveryNoteAttachmentKind::Signature => "SIGNATURE",
+        DeliveryNoteAttachmentKind::ShipStamp => "SHIP_STAMP",
+    }
+}
+
+/// Largest accepted signature / stamp image
+const MAX_ATTACHMENT_BYTES: usize = 5 * 1024 * 1024;
+
+const SELECT_FIELDS: &str = r#"
+    dn.id, dn.note_number, dn.order_id, o.order_number, s.name as ship_name, dn.ship_visit_id, dn.status,
+    dn.receiver_name, dn.receiver_rank, dn.signed_at, dn.notes, dn.created_at
+"#;
+
+const FROM_JOIN: &str = r#"
+    FROM delivery_notes dn
+    JOIN orders o ON dn.order_id = o.id
+    LEFT JOIN ships s ON o.ship_id = s.id
+"#;
+
+async fn load_note<C: ConnectionTrait>(conn: &C, row: DeliveryNoteRow) -> Result<DeliveryNote> {
+    let lines: Vec<LineRow> = LineRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT oi.id as order_item_id, oi.product_name, oi.impa_code, oi.unit,
+               CAST(SUM(d.quantity) AS REAL) as quantity
+        FROM order_item_deliveries d
+        JOIN order_items oi ON d.order_item_id = oi.id
+        WHERE d.delivery_note_id = ?
+        GROUP BY oi.id
+        ORDER BY oi.id
+        "#,
+        vec![Value::Int(Some(row.id))]
+    ))
+    .all(conn)
+    .await?;
+
+    let attachments: Vec<AttachmentRow> = AttachmentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT id, entity_id as delivery_note_id, role as kind, file_name, mime_type, size_bytes, created_at
+        FROM attachments
+        WHERE entity_type = 'DELIVERY_NOTE' AND entity_id = ? AND role IS NOT NULL
+        ORDER BY id
+        "#,
+        vec![Value::Int(Some(row.id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(DeliveryNote {
+        id: row.id,
+        note_number: row.note_number,
+        order_id: row.order_id,
+        order_number: row.order_number,
+        ship_name: row.ship_name,
+        ship_visit_id: row.ship_visit_id,
+        status: status_from_str(&row.status),
+        receiver_name: row.receiver_name,
+        receiver_rank: row.receiver_rank,
+        signed_at: row.signed_at,
+        notes: row.notes,
+        lines: lines.into_iter().map(|l| DeliveryNoteLine {
+            order_item_id: l.order_item_id,
+            product_name: l.product_name,
+            impa_code: l.impa_code,
+            unit: l.unit,
+            quantity: l.quantity,
+        }).collect(),
+        attachments: attachments.into_iter().map(DeliveryNoteAttachment::from).collect(),
+        created_at: row.created_at,
+    })
+}
+
+/// Get all delivery notes of an order
+pub async fn get_by_order(order_id: i32) -> Result<Vec<DeliveryNote>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<DeliveryNoteRow> = DeliveryNoteRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE dn.order_id = ? ORDER BY dn.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut notes = Vec::with_capacity(rows.len());
+    for row in rows {
+        notes.push(load_note(&conn, row).await?);
+    }
+    Ok(notes)
+}
+
+/// Get a delivery note by ID
+pub async fn get_by_id(id: i32) -> Result<Option<DeliveryNote>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<DeliveryNoteRow> = DeliveryNoteRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE dn.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    match row {
+        Some(row) => Ok(Some(load_note(&conn, row).await?)),
+        None => Ok(None),
+    }
+}
+
+async fn get_draft(id: i32) -> Result<DeliveryNote> {
+    let note = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Delivery note not found"))?;
+    if note.status != DeliveryNoteStatus::Draft {
+        anyhow::bail!("Delivery note {} is already signed", note.note_number);
+    }
+    Ok(note)
+}
+
+async fn get_order(order_id: i32) -> Result<Order> {
+    order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))
+}
+
+/// Generate a delivery note from delivered order items.
+///
+/// The note lists the delivered quantities of the chosen delivery events
+/// (by default every delivery of the order not yet on a note).
+pub async fn create(req: CreateDeliveryNoteRequest) -> Result<DeliveryNote> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = get_order(req.order_id).await?;
+    if order.status == OrderStatus::Cancelled {
+        anyhow::bail!("Order {} is cancelled", order.order_number);
+    }
+
+    #[derive(Debug, FromQueryResult)]
+    struct OpenDeliveryRow {
+        id: i32,
+        ship_visit_id: Option<i32>,
+    }
+
+    let open: Vec<OpenDeliveryRow> = OpenDeliveryRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT d.id, d.ship_visit_id FROM order_item_deliveries d
+        JOIN order_items oi ON d.order_item_id = oi.id
+        WHERE oi.order_id = ? AND d.delivery_note_id IS NULL
+        ORDER BY d.id
+        "#,
+        vec![Value::Int(Some(order.id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let selected: Vec<&OpenDeliveryRow> = match &req.delivery_ids {
+        Some(ids) => {
+            for id in ids {
+                if !open.iter().any(|d| d.id == *id) {
+                    anyhow::bail!("Delivery {} is not an unsigned delivery of order {}", id, order.order_number);
+                }
+            }
+            open.iter().filter(|d| ids.contains(&d.id)).collect()
+        }
+        None => open.iter().collect(),
+    };
+    if selected.is_empty() {
+        anyhow::bail!("Order {} has no deliveries waiting for a delivery note", order.order_number);
+    }
+    let ship_visit_id = selected.iter().find_map(|d| d.ship_visit_id).or(order.ship_visit_id);
+
+    let txn = conn.begin().await?;
+
+    let note_number = numbering::next_document_number(&txn, "delivery_notes", "note_number", "DN").await?;
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO delivery_notes (note_number, order_id, ship_visit_id, status, notes)
+        VALUES (?, ?, ?, 'DRAFT', ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(note_number))),
+            Value::Int(Some(order.id)),
+            Value::Int(ship_visit_id),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+    let note_id = result.last_insert_id() as i32;
+
+    for delivery in selected {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "UPDATE order_item_deliveries SET delivery_note_id = ? WHERE id = ?",
+            vec![Value::Int(Some(note_id)), Value::Int(Some(delivery.id))]
+        )).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(note_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created delivery note"))
+}
+
+/// Attach the receiver's signature or the ship stamp photo (replaces an earlier one of the same kind)
+pub async fn add_attachment(
+    delivery_note_id: i32,
+    kind: DeliveryNoteAttachmentKind,
+    file_name: String,
+    mime_type: String,
+    data: Vec<u8>,
+) -> Result<DeliveryNoteAttachment> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    get_draft(delivery_note_id).await?;
+
+    if !mime_type.starts_with("image/") {
+        anyhow::bail!("{} must be an image", kind.display_name());
+    }
+    if data.is_empty() {
+        anyhow::bail!("{} image is empty", kind.display_name());
+    }
+    if data.len() > MAX_ATTACHMENT_BYTES {
+        anyhow::bail!("{} image is larger than {} MB", kind.display_name(), MAX_ATTACHMENT_BYTES / (1024 * 1024));
+    }
+
+    let request = CreateAttachmentRequest {
+        entity_type: AttachmentEntity::DeliveryNote,
+        entity_id: delivery_note_id,
+        file_name,
+        mime_type,
+        data,
+        caption: None,
+    };
+    let attachment_id = attachment_service::replace_role(request, kind_to_str(kind)).await?;
+
+    let row: Option<AttachmentRow> = AttachmentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT id, entity_id as delivery_note_id, role as kind, file_name, mime_type, size_bytes, created_at
+        FROM attachments WHERE id = ?
+        "#,
+        vec![Value::Int(Some(attachment_id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    row.map(DeliveryNoteAttachment::from)
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve delivery note attachment"))
+}
+
+/// Image bytes of a delivery note attachment
+pub async fn get_attachment_data(attachment_id: i32) -> Result<Vec<u8>> {
+    attachment_service::get_data(attachment_id).await
+}
+
+/// Record the receiver's signature: the note becomes proof of delivery
+pub async fn complete(id: i32, req: CompleteDeliveryNoteRequest) -> Result<DeliveryNote> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let note = get_draft(id).await?;
+
+    let receiver_name = req.receiver_name.trim();
+    let receiver_rank = req.receiver_rank.trim();
+    if receiver_name.is_empty() || receiver_rank.is_empty() {
+        anyhow::bail!("Receiver name and rank are required");
+    }
+    if note.lines.is_empty() {
+        anyhow::bail!("Delivery note {} has no delivered lines", note.note_number);
+    }
+    if !note.attachments.iter().any(|a| a.kind == DeliveryNoteAttachmentKind::Signature) {
+        anyhow::bail!("Delivery note {} has no captured signature", note.note_number);
+    }
+    let signed_at = match req.signed_at {
+        Some(value) => {
+            parse_date(&value)?;
+            value
+        }
+        None => chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    };
+
+    let order = get_order(note.order_id).await?;
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE delivery_notes SET status = 'COMPLETED', receiver_name = ?, receiver_rank = ?, signed_at = ?,
+            updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::String(Some(Box::new(receiver_name.to_string()))),
+            Value::String(Some(Box::new(receiver_rank.to_string()))),
+            Value::String(Some(Box::new(signed_at))),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    fulfilment_service::derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Delivery note not found after update"))
+}
+
+/// Delete an unsigned delivery note; its deliveries can go on a new note
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    get_draft(id).await?;
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE order_item_deliveries SET delivery_note_id = NULL WHERE delivery_note_id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM delivery_notes WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    txn.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Allow an order to be delivered without a signed delivery note
+pub async fn waive(order_id: i32, reason: String) -> Result<Order> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = get_order(order_id).await?;
+    if matches!(order.status, OrderStatus::Cancelled | OrderStatus::Invoiced) {
+        anyhow::bail!("Order {} is {}", order.order_number, order.status.display_name());
+    }
+    let reason = reason.trim();
+    if reason.is_empty() {
+        anyhow::bail!("A reason is required to waive the delivery note");
+    }
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE orders SET delivery_note_waiver = ?, updated_at = datetime('now') WHERE id = ?",
+        vec![
+            Value::String(Some(Box::new(reason.to_string()))),
+            Value::Int(Some(order.id)),
+        ]
+    )).await?;
+
+    fulfilment_service::derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    tracing::info!("Delivery note waived for order {}: {}", order.order_number, reason);
+
+    get_order(order_id).await
+}
+
+/// Whether the deliveries of an order are signed for.
+///
+/// True when the order has a waiver, or when every delivery is on a completed
+/// delivery note (an order with nothing delivered needs no note).
+pub(crate) async fn has_proof_of_delivery<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<bool> {
+    #[derive(Debug, FromQueryResult)]
+    struct ProofRow {
+        waived: i32,
+        unsigned_deliveries: i32,
+    }
+
+    let row: Option<ProofRow> = ProofRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT
+            (CASE WHEN o.delivery_note_waiver IS NOT NULL THEN 1 ELSE 0 END) as waived,
+            (SELECT COUNT(*) FROM order_item_deliveries d
+             JOIN order_items oi ON d.order_item_id = oi.id
+             LEFT JOIN delivery_notes dn ON d.delivery_note_id = dn.id
+             WHERE oi.order_id = o.id AND (dn.id IS NULL OR dn.status <> 'COMPLETED')) as unsigned_deliveries
+        FROM orders o
+        WHERE o.id = ?
+        "#,
+        vec![Value::Int(Some(order_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.waived == 1 || r.unsigned_deliveries == 0).unwrap_or(false))
+}
+
+/// Whether any delivery of an order line is on a completed (signed) delivery note
+pub(crate) async fn has_signed_delivery<C: ConnectionTrait>(conn: &C, order_item_id: i32) -> Result<bool> {
+    #[derive(Debug, FromQueryResult)]
+    struct CountRow {
+        count: i32,
+    }
+
+    let row: Option<CountRow> = CountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT COUNT(*) as count FROM order_item_deliveries d
+        JOIN delivery_notes dn ON d.delivery_note_id = dn.id
+        WHERE d.order_item_id = ? AND dn.status = 'COMPLETED'
+        "#,
+        vec![Value::Int(Some(order_item_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.is_some_and(|r| r.count > 0))
+}
@@ -0,0 +1,366 @@
+//! Equivalence Service - Interchangeable supply items across suppliers
+//!
+//! Supply items with the same IMPA code are equivalent; equivalence groups link
+//! items by hand (no IMPA code, or different codes for the same product). The
+//! two links chain: the group of an item also brings in the items sharing the
+//! IMPA codes of its members.
+
+use crate::models::{
+    EquivalenceGroup, CreateEquivalenceGroupRequest, ProductAlternative, AlternativeLink, SupplyItem,
+};
+use crate::database;
+use crate::services::{supply_item_service, order_service};
+use crate::services::price_agreement_service::normalize_impa;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct GroupRow {
+    id: i32,
+    name: String,
+    notes: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct AlternativeRow {
+    supply_item_id: i32,
+    name: String,
+    impa_code: Option<String>,
+    supplier_sku: Option<String>,
+    supplier_id: i32,
+    supplier_name: Option<String>,
+    unit: String,
+    unit_price: f64,
+    currency: String,
+    is_available: i32,
+    stock_quantity: f64,
+    lead_time_days: Option<i32>,
+}
+
+/// Supply items linked to the seed items of `{seed}`, by IMPA code or group, transitively
+const LINKED_SQL: &str = r#"
+    WITH RECURSIVE linked(id) AS (
+        {seed}
+        UNION
+        SELECT si.id FROM linked l
+        JOIN supply_items a ON a.id = l.id
+        JOIN supply_items si ON replace(si.impa_code, ' ', '') = replace(a.impa_code, ' ', '')
+        WHERE TRIM(COALESCE(a.impa_code, '')) != ''
+        UNION
+        SELECT m2.supply_item_id FROM linked l
+        JOIN equivalence_group_items m1 ON m1.supply_item_id = l.id
+        JOIN equivalence_group_items m2 ON m2.group_id = m1.group_id
+    )
+    SELECT si.id as supply_item_id, si.name, si.impa_code, si.supplier_sku, si.supplier_id, s.name as supplier_name,
+           si.unit, si.unit_price, si.currency, si.is_available,
+           CAST(COALESCE((SELECT SUM(st.quantity) FROM stock st WHERE st.supply_item_id = si.id), 0) AS REAL) as stock_quantity,
+           s.lead_time_days
+    FROM linked l
+    JOIN supply_items si ON si.id = l.id
+    JOIN suppliers s ON si.supplier_id = s.id
+    WHERE s.is_active = 1
+    ORDER BY si.is_available DESC, si.currency, si.unit_price, si.id
+"#;
+
+async fn linked_items<C: ConnectionTrait>(conn: &C, seed: &str, value: Value, impa_code: Option<&str>) -> Result<Vec<ProductAlternative>> {
+    let rows: Vec<AlternativeRow> = AlternativeRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        LINKED_SQL.replace("{seed}", seed),
+        vec![value]
+    ))
+    .all(conn)
+    .await?;
+
+    let impa_code = impa_code.map(normalize_impa).filter(|c| !c.is_empty());
+    Ok(rows.into_iter().map(|row| {
+        let same_impa = impa_code.is_some() && row.impa_code.as_deref().map(normalize_impa) == impa_code;
+        ProductAlternative {
+            supply_item_id: row.supply_item_id,
+            name: row.name,
+            impa_code: row.impa_code,
+            supplier_sku: row.supplier_sku,
+            supplier_id: row.supplier_id,
+            supplier_name: row.supplier_name,
+            unit: row.unit,
+            unit_price: row.unit_price,
+            currency: row.currency,
+            is_available: row.is_available == 1,
+            stock_quantity: row.stock_quantity,
+            lead_time_days: row.lead_time_days,
+            linked_by: if same_impa { AlternativeLink::SameImpa } else { AlternativeLink::Manual },
+        }
+    }).collect())
+}
+
+async fn load_group<C: ConnectionTrait>(conn: &C, id: i32) -> Result<Option<EquivalenceGroup>> {
+    let row: Option<GroupRow> = GroupRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT id, name, notes, created_at, updated_at FROM equivalence_groups WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    ))
+    .one(conn)
+    .await?;
+
+    let Some(row) = row else { return Ok(None) };
+
+    #[derive(Debug, FromQueryResult)]
+    struct MemberRow {
+        supply_item_id: i32,
+    }
+
+    let member_rows: Vec<MemberRow> = MemberRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT supply_item_id FROM equivalence_group_items WHERE group_id = ? ORDER BY supply_item_id",
+        vec![Value::Int(Some(id))]
+    ))
+    .all(conn)
+    .await?;
+
+    let mut members: Vec<SupplyItem> = Vec::with_capacity(member_rows.len());
+    for member in member_rows {
+        if let Some(item) = supply_item_service::get_by_id(member.supply_item_id).await? {
+            members.push(item);
+        }
+    }
+
+    Ok(Some(EquivalenceGroup {
+        id: row.id,
+        name: row.name,
+        notes: row.notes,
+        members,
+        created_at: row.created_at,
+        updated_at: row.updated_at,
+    }))
+}
+
+/// Group a supply item belongs to, if any
+async fn group_of<C: ConnectionTrait>(conn: &C, supply_item_id: i32) -> Result<Option<i32>> {
+    #[derive(Debug, FromQueryResult)]
+    struct GroupIdRow {
+        group_id: i32,
+    }
+
+    let row: Option<GroupIdRow> = GroupIdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT group_id FROM equivalence_group_items WHERE supply_item_id = ?",
+        vec![Value::Int(Some(supply_item_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.group_id))
+}
+
+/// Check that a supply item exists and is in no other group
+async fn check_member<C: ConnectionTrait>(conn: &C, supply_item_id: i32, group_id: Option<i32>) -> Result<()> {
+    let item = supply_item_service::get_by_id(supply_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supply item {} not found", supply_item_id))?;
+    match group_of(conn, supply_item_id).await? {
+        Some(existing) if Some(existing) != group_id => {
+            anyhow::bail!("{} is already in equivalence group {}", item.name, existing)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Get all equivalence groups with their members
+pub async fn get_all_groups() -> Result<Vec<EquivalenceGroup>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<GroupRow> = GroupRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT id, name, notes, created_at, updated_at FROM equivalence_groups ORDER BY name".to_string()
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut groups = Vec::with_capacity(rows.len());
+    for row in rows {
+        if let Some(group) = load_group(&conn, row.id).await? {
+            groups.push(group);
+        }
+    }
+    Ok(groups)
+}
+
+/// Get an equivalence group by ID
+pub async fn get_group(id: i32) -> Result<Option<EquivalenceGroup>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    load_group(&conn, id).await
+}
+
+/// Link at least two supply items as interchangeable
+pub async fn create_group(req: CreateEquivalenceGroupRequest) -> Result<EquivalenceGroup> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let name = req.name.trim().to_string();
+    if name.is_empty() {
+        anyhow::bail!("Group name is required");
+    }
+    let mut supply_item_ids = req.supply_item_ids.clone();
+    supply_item_ids.sort_unstable();
+    supply_item_ids.dedup();
+    if supply_item_ids.len() < 2 {
+        anyhow::bail!("An equivalence group needs at least two supply items");
+    }
+    for &supply_item_id in &supply_item_ids {
+        check_member(&conn, supply_item_id, None).await?;
+    }
+
+    let txn = conn.begin().await?;
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT INTO equivalence_groups (name, notes) VALUES (?, ?)",
+        vec![
+            Value::String(Some(Box::new(name))),
+            Value::String(req.notes.filter(|n| !n.trim().is_empty()).map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    for supply_item_id in supply_item_ids {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "INSERT INTO equivalence_group_items (group_id, supply_item_id) VALUES (?, ?)",
+            vec![Value::Int(Some(id)), Value::Int(Some(supply_item_id))]
+        )).await?;
+    }
+    txn.commit().await?;
+
+    load_group(&conn, id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created equivalence group"))
+}
+
+/// Add a supply item to an equivalence group
+pub async fn add_to_group(group_id: i32, supply_item_id: i32) -> Result<EquivalenceGroup> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    load_group(&conn, group_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Equivalence group not found"))?;
+    check_member(&conn, supply_item_id, Some(group_id)).await?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT OR IGNORE INTO equivalence_group_items (group_id, supply_item_id) VALUES (?, ?)",
+        vec![Value::Int(Some(group_id)), Value::Int(Some(supply_item_id))]
+    )).await?;
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE equivalence_groups SET updated_at = datetime('now') WHERE id = ?",
+        vec![Value::Int(Some(group_id))]
+    )).await?;
+
+    load_group(&conn, group_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve equivalence group"))
+}
+
+/// Take a supply item out of its equivalence group
+pub async fn remove_from_group(supply_item_id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM equivalence_group_items WHERE supply_item_id = ?",
+        vec![Value::Int(Some(supply_item_id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete an equivalence group (the supply items stay)
+pub async fn delete_group(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let txn = conn.begin().await?;
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM equivalence_group_items WHERE group_id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM equivalence_groups WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+    txn.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Equivalent supply items of all active suppliers, with price, availability,
+/// warehouse stock and supplier lead time. Available items first, then by
+/// currency and price. The item itself is not included.
+pub async fn get_alternatives(supply_item_id: i32) -> Result<Vec<ProductAlternative>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let item = supply_item_service::get_by_id(supply_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supply item not found"))?;
+
+    let alternatives = linked_items(&conn, "SELECT ?", Value::Int(Some(item.id)), item.impa_code.as_deref()).await?;
+    Ok(alternatives.into_iter().filter(|a| a.supply_item_id != item.id).collect())
+}
+
+/// Cheapest available supply item with enough warehouse stock for an order
+/// line, among the equivalents of a supply item or of an IMPA code.
+///
+/// Only items in the order's currency are compared, and when starting from a
+/// supply item only those sold in the same unit. Ties go to the shorter lead
+/// time. None when no equivalent is in stock.
+pub async fn suggest_for_order(
+    order_id: i32,
+    supply_item_id: Option<i32>,
+    impa_code: Option<&str>,
+    quantity: f64,
+) -> Result<Option<ProductAlternative>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    let (candidates, unit) = match (supply_item_id, impa_code.map(str::trim).filter(|c| !c.is_empty())) {
+        (Some(id), _) => {
+            let item = supply_item_service::get_by_id(id).await?
+                .ok_or_else(|| anyhow::anyhow!("Supply item not found"))?;
+            let candidates = linked_items(&conn, "SELECT ?", Value::Int(Some(item.id)), item.impa_code.as_deref()).await?;
+            (candidates, Some(item.unit))
+        }
+        (None, Some(code)) => {
+            let code = normalize_impa(code);
+            let candidates = linked_items(
+                &conn,
+                "SELECT id FROM supply_items WHERE replace(impa_code, ' ', '') = ?",
+                Value::String(Some(Box::new(code.clone()))),
+                Some(&code),
+            ).await?;
+            (candidates, None)
+        }
+        (None, None) => anyhow::bail!("A supply item or IMPA code is required"),
+    };
+
+    let needed = quantity.max(0.0);
+    Ok(candidates.into_iter()
+        .filter(|a| a.is_available && a.currency == order.currency)
+        .filter(|a| unit.as_deref().is_none_or(|u| a.unit.eq_ignore_ascii_case(u)))
+        .filter(|a| a.stock_quantity > 0.0 && a.stock_quantity >= needed)
+        .min_by(|a, b| a.unit_price.total_cmp(&b.unit_price)
+            .then(a.lead_time_days.unwrap_or(i32::MAX).cmp(&b.lead_time_days.unwrap_or(i32::MAX)))))
+}
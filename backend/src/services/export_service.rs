@@ -0,0 +1,655 @@
+//! Export Service - XLSX and CSV files for orders, stock, bonded stock and profit reports
+//!
+//! Every export is built as a set of tables and then written either as an
+//! XLSX workbook (one sheet per table, formatted, with SUM totals) or as CSV
+//! (one file per table). The format follows the output path's extension.
+//! Files only depend on the data: the workbook creation date is taken from the
+//! newest exported record, so every platform produces identical bytes.
+
+use crate::models::{ExportResult, OrderExportFilter, Order, OrderTotals};
+use crate::services::{order_service, calculation_service, stock_service, bonded_service};
+use anyhow::Result;
+use rust_xlsxwriter::{DocProperties, ExcelDateTime, Format, FormatBorder, Formula, Workbook, Worksheet};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone)]
+enum Cell {
+    Text(String),
+    Number(f64),
+    Money(f64),
+    Percent(f64),
+    Empty,
+}
+
+impl Cell {
+    fn text(value: impl Into<String>) -> Cell {
+        Cell::Text(value.into())
+    }
+
+    fn optional(value: Option<impl Into<String>>) -> Cell {
+        value.map(|v| Cell::Text(v.into())).unwrap_or(Cell::Empty)
+    }
+
+    fn csv_value(&self) -> String {
+        match self {
+            Cell::Text(s) => s.clone(),
+            Cell::Number(n) => format!("{}", (n * 1000.0).round() / 1000.0),
+            Cell::Money(n) | Cell::Percent(n) => format!("{:.2}", n),
+            Cell::Empty => String::new(),
+        }
+    }
+
+    fn display_len(&self) -> usize {
+        match self {
+            Cell::Text(s) => s.chars().count(),
+            Cell::Empty => 0,
+            other => other.csv_value().len() + 2,
+        }
+    }
+}
+
+struct Column {
+    title: &'static str,
+    /// Add a SUM row under the column
+    total: bool,
+}
+
+fn column(title: &'static str) -> Column {
+    Column { title, total: false }
+}
+
+fn summed(title: &'static str) -> Column {
+    Column { title, total: true }
+}
+
+struct Table {
+    /// Sheet name, also the CSV file suffix for extra tables
+    name: &'static str,
+    title: String,
+    /// Key/value lines above the table (order header, report period...)
+    info: Vec<(&'static str, Cell)>,
+    columns: Vec<Column>,
+    rows: Vec<Vec<Cell>>,
+    /// Key/value lines below the table (order totals...)
+    summary: Vec<(&'static str, Cell)>,
+}
+
+impl Table {
+    fn has_totals(&self) -> bool {
+        !self.rows.is_empty() && self.columns.iter().any(|c| c.total)
+    }
+
+    fn column_total(&self, index: usize) -> f64 {
+        self.rows.iter()
+            .map(|row| match row.get(index) {
+                Some(Cell::Number(n)) | Some(Cell::Money(n)) => *n,
+                _ => 0.0,
+            })
+            .sum()
+    }
+}
+
+enum ExportFormat {
+    Xlsx,
+    Csv,
+}
+
+fn export_format(path: &Path) -> Result<ExportFormat> {
+    match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+        Some("xlsx") => Ok(ExportFormat::Xlsx),
+        Some("csv") => Ok(ExportFormat::Csv),
+        _ => anyhow::bail!("Export path must end in .xlsx or .csv: {}", path.display()),
+    }
+}
+
+/// Write the tables to `output_path`; `stamp` (newest record timestamp) becomes the workbook date
+fn write_tables(output_path: &str, tables: &[Table], stamp: &str) -> Result<ExportResult> {
+    let path = Path::new(output_path);
+    let format = export_format(path)?;
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let files = match format {
+        ExportFormat::Xlsx => {
+            write_xlsx(path, tables, stamp)?;
+            vec![output_path.to_string()]
+        }
+        ExportFormat::Csv => write_csv(path, tables)?,
+    };
+
+    Ok(ExportResult {
+        files,
+        row_count: tables.iter().map(|t| t.rows.len() as i32).sum(),
+    })
+}
+
+/// One CSV line (quoted as needed); empty lines separate the blocks of a table
+fn csv_line<I, T>(content: &mut Vec<u8>, fields: I) -> Result<()>
+where
+    I: IntoIterator<Item = T>,
+    T: AsRef<[u8]>,
+{
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(fields)?;
+    content.extend(writer.into_inner().map_err(|e| anyhow::anyhow!("CSV write failed: {}", e))?);
+    Ok(())
+}
+
+fn write_csv(path: &Path, tables: &[Table]) -> Result<Vec<String>> {
+    let mut files = Vec::with_capacity(tables.len());
+
+    for (index, table) in tables.iter().enumerate() {
+        // Extra tables go next to the main file: stock.csv, stock-hareketler.csv
+        let file: PathBuf = if index == 0 {
+            path.to_path_buf()
+        } else {
+            let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("export");
+            path.with_file_name(format!("{}-{}.csv", stem, table.name.to_lowercase()))
+        };
+
+        // UTF-8 BOM so Excel shows Turkish characters correctly
+        let mut content = vec![0xEF, 0xBB, 0xBF];
+
+        for (key, value) in &table.info {
+            csv_line(&mut content, [key.to_string(), value.csv_value()])?;
+        }
+        if !table.info.is_empty() {
+            content.push(b'\n');
+        }
+
+        csv_line(&mut content, table.columns.iter().map(|c| c.title))?;
+        for row in &table.rows {
+            csv_line(&mut content, row.iter().map(Cell::csv_value))?;
+        }
+        if table.has_totals() {
+            csv_line(&mut content, table.columns.iter().enumerate().map(|(i, c)| {
+                if i == 0 {
+                    "Toplam".to_string()
+                } else if c.total {
+                    format!("{:.2}", table.column_total(i))
+                } else {
+                    String::new()
+                }
+            }))?;
+        }
+
+        if !table.summary.is_empty() {
+            content.push(b'\n');
+            for (key, value) in &table.summary {
+                csv_line(&mut content, [key.to_string(), value.csv_value()])?;
+            }
+        }
+
+        std::fs::write(&file, content)?;
+        files.push(file.display().to_string());
+    }
+
+    Ok(files)
+}
+
+struct Formats {
+    title: Format,
+    bold: Format,
+    header: Format,
+    number: Format,
+    money: Format,
+    percent: Format,
+    total_label: Format,
+    total_money: Format,
+}
+
+impl Formats {
+    fn new() -> Formats {
+        Formats {
+            title: Format::new().set_bold().set_font_size(14),
+            bold: Format::new().set_bold(),
+            header: Format::new().set_bold().set_background_color(0xD9E1F2).set_border_bottom(FormatBorder::Thin),
+            number: Format::new().set_num_format("#,##0.###"),
+            money: Format::new().set_num_format("#,##0.00"),
+            percent: Format::new().set_num_format("0.00\"%\""),
+            total_label: Format::new().set_bold().set_border_top(FormatBorder::Thin),
+            total_money: Format::new().set_bold().set_num_format("#,##0.00").set_border_top(FormatBorder::Thin),
+        }
+    }
+}
+
+fn write_cell(sheet: &mut Worksheet, row: u32, col: u16, cell: &Cell, formats: &Formats) -> Result<()> {
+    match cell {
+        Cell::Text(s) => { sheet.write_string(row, col, s)?; }
+        Cell::Number(n) => { sheet.write_number_with_format(row, col, *n, &formats.number)?; }
+        Cell::Money(n) => { sheet.write_number_with_format(row, col, *n, &formats.money)?; }
+        Cell::Percent(n) => { sheet.write_number_with_format(row, col, *n, &formats.percent)?; }
+        Cell::Empty => {}
+    }
+    Ok(())
+}
+
+/// Column letters for a 0-based index (0 = A)
+fn column_letters(mut index: usize) -> String {
+    let mut letters = String::new();
+    loop {
+        letters.insert(0, (b'A' + (index % 26) as u8) as char);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters
+}
+
+fn write_xlsx(path: &Path, tables: &[Table], stamp: &str) -> Result<()> {
+    let mut workbook = Workbook::new();
+    let created = ExcelDateTime::parse_from_str(stamp.get(..19).unwrap_or(stamp))
+        .or_else(|_| ExcelDateTime::from_ymd(2000, 1, 1))?;
+    workbook.set_properties(&DocProperties::new()
+        .set_title(tables.first().map(|t| t.title.as_str()).unwrap_or_default())
+        .set_creation_datetime(&created));
+
+    let formats = Formats::new();
+
+    for table in tables {
+        let sheet = workbook.add_worksheet();
+        sheet.set_name(table.name)?;
+
+        sheet.write_string_with_format(0, 0, &table.title, &formats.title)?;
+        let mut row: u32 = 2;
+        for (key, value) in &table.info {
+            sheet.write_string_with_format(row, 0, *key, &formats.bold)?;
+            write_cell(sheet, row, 1, value, &formats)?;
+            row += 1;
+        }
+        if !table.info.is_empty() {
+            row += 1;
+        }
+
+        let header_row = row;
+        for (col, column) in table.columns.iter().enumerate() {
+            sheet.write_string_with_format(header_row, col as u16, column.title, &formats.header)?;
+        }
+        row += 1;
+
+        let first_data_row = row;
+        for cells in &table.rows {
+            for (col, cell) in cells.iter().enumerate() {
+                write_cell(sheet, row, col as u16, cell, &formats)?;
+            }
+            row += 1;
+        }
+        let last_column = table.columns.len().saturating_sub(1) as u16;
+        sheet.set_freeze_panes(header_row + 1, 0)?;
+        if !table.rows.is_empty() {
+            sheet.autofilter(header_row, 0, row - 1, last_column)?;
+        }
+
+        if table.has_totals() {
+            sheet.write_string_with_format(row, 0, "Toplam", &formats.total_label)?;
+            for (col, column) in table.columns.iter().enumerate().skip(1) {
+                if column.total {
+                    let letters = column_letters(col);
+                    let formula = Formula::new(format!("=SUM({}{}:{}{})", letters, first_data_row + 1, letters, row))
+                        .set_result(format!("{}", table.column_total(col)));
+                    sheet.write_formula_with_format(row, col as u16, formula, &formats.total_money)?;
+                } else {
+                    sheet.write_blank(row, col as u16, &formats.total_label)?;
+                }
+            }
+            row += 1;
+        }
+
+        if !table.summary.is_empty() {
+            row += 1;
+            for (key, value) in &table.summary {
+                sheet.write_string_with_format(row, 0, *key, &formats.bold)?;
+                write_cell(sheet, row, 1, value, &formats)?;
+                row += 1;
+            }
+        }
+
+        // Fit columns to their contents (the key/value blocks share the first two columns)
+        for (col, column) in table.columns.iter().enumerate() {
+            let mut width = column.title.chars().count();
+            for cells in &table.rows {
+                width = width.max(cells.get(col).map(Cell::display_len).unwrap_or(0));
+            }
+            if col < 2 {
+                for (key, value) in table.info.iter().chain(table.summary.iter()) {
+                    width = width.max(if col == 0 { key.chars().count() } else { value.display_len() });
+                }
+            }
+            sheet.set_column_width(col as u16, (width.clamp(8, 50) + 2) as f64)?;
+        }
+    }
+
+    workbook.save(path)?;
+    Ok(())
+}
+
+/// Newest of the given timestamps ("" when there are none)
+fn newest<'a>(stamps: impl Iterator<Item = &'a str>) -> String {
+    stamps.max().unwrap_or_default().to_string()
+}
+
+fn totals_summary(totals: &OrderTotals) -> Vec<(&'static str, Cell)> {
+    vec![
+        ("Kalem Sayısı", Cell::Number(totals.item_count as f64)),
+        ("Ek Masraf Maliyeti", Cell::Money(totals.charges_cost)),
+        ("Ek Masraf Geliri", Cell::Money(totals.charges_revenue)),
+        ("Varış Maliyeti", Cell::Money(totals.landed_cost)),
+        ("İade Geliri", Cell::Money(totals.returns_revenue)),
+        ("İade Maliyeti", Cell::Money(totals.returns_cost)),
+        ("Toplam Maliyet", Cell::Money(totals.total_cost)),
+        ("Toplam Gelir", Cell::Money(totals.total_revenue)),
+        ("Brüt Kâr", Cell::Money(totals.gross_profit)),
+        ("Kâr Marjı %", totals.margin_percent.map(Cell::Percent).unwrap_or(Cell::Empty)),
+        ("Para Birimi", Cell::text(totals.currency.clone())),
+    ]
+}
+
+/// Export an order with its items and totals
+pub async fn export_order(order_id: i32, output_path: &str) -> Result<ExportResult> {
+    let order = order_service::get_with_items(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    let rows = order.items.iter().map(|item| vec![
+        Cell::optional(item.impa_code.clone()),
+        Cell::text(item.product_name.clone()),
+        Cell::optional(item.description.clone()),
+        Cell::Number(item.quantity),
+        Cell::text(item.unit.clone()),
+        Cell::Money(item.buying_price),
+        Cell::Money(item.selling_price),
+        Cell::Money(item.buying_price * item.quantity),
+        Cell::Money(item.selling_price * item.quantity),
+        Cell::Money((item.selling_price - item.buying_price) * item.quantity),
+        Cell::text(item.delivery_type.display_name()),
+        Cell::Number(item.delivered_quantity),
+    ]).collect();
+
+    let o = &order.order;
+    let table = Table {
+        name: "Siparis",
+        title: format!("Sipariş {}", o.order_number),
+        info: vec![
+            ("Sipariş No", Cell::text(o.order_number.clone())),
+            ("Gemi", Cell::optional(o.ship_name.clone())),
+            ("Müşteri", Cell::optional(o.customer_name.clone())),
+            ("Durum", Cell::text(o.status.display_name())),
+            ("Teslim Limanı", Cell::optional(o.delivery_port.clone())),
+            ("Sefer", Cell::optional(o.ship_visit_info.clone())),
+            ("Para Birimi", Cell::text(o.currency.clone())),
+            ("Tarih", Cell::text(o.created_at.clone())),
+        ],
+        columns: vec![
+            column("IMPA"),
+            column("Ürün"),
+            column("Açıklama"),
+            column("Miktar"),
+            column("Birim"),
+            column("Alış Fiyatı"),
+            column("Satış Fiyatı"),
+            summed("Alış Toplamı"),
+            summed("Satış Toplamı"),
+            summed("Kâr"),
+            column("Teslim Şekli"),
+            column("Teslim Edilen"),
+        ],
+        rows,
+        summary: totals_summary(&order.totals),
+    };
+
+    write_tables(output_path, &[table], &o.updated_at)
+}
+
+fn matches_filter(order: &Order, filter: &OrderExportFilter) -> bool {
+    let day = order.created_at.get(..10).unwrap_or(&order.created_at);
+    filter.ship_id.is_none_or(|id| order.ship_id == id)
+        && filter.customer_id.is_none_or(|id| order.customer_id == Some(id))
+        && filter.date_from.as_deref().is_none_or(|from| day >= from)
+        && filter.date_to.as_deref().is_none_or(|to| day <= to)
+}
+
+/// Export the order list with per-order totals
+pub async fn export_orders(filter: OrderExportFilter, output_path: &str) -> Result<ExportResult> {
+    let orders: Vec<Order> = order_service::get_all(filter.status).await?
+        .into_iter()
+        .filter(|o| matches_filter(o, &filter))
+        .collect();
+
+    let mut rows = Vec::with_capacity(orders.len());
+    for order in orders.iter().rev() {
+        let totals = calculation_service::calculate_order_totals(order.id).await?;
+        rows.push(vec![
+            Cell::text(order.order_number.clone()),
+            Cell::text(order.created_at.get(..10).unwrap_or(&order.created_at)),
+            Cell::optional(order.ship_name.clone()),
+            Cell::optional(order.customer_name.clone()),
+            Cell::text(order.status.display_name()),
+            Cell::optional(order.delivery_port.clone()),
+            Cell::text(order.currency.clone()),
+            Cell::Number(totals.item_count as f64),
+            Cell::Money(totals.total_cost),
+            Cell::Money(totals.total_revenue),
+            Cell::Money(totals.gross_profit),
+            totals.margin_percent.map(Cell::Percent).unwrap_or(Cell::Empty),
+        ]);
+    }
+
+    // Amounts are only summed when every order is in the same currency
+    let single_currency = orders.windows(2).all(|w| w[0].currency == w[1].currency);
+    let money = |title| if single_currency { summed(title) } else { column(title) };
+
+    let describe = |value: Option<String>| Cell::text(value.unwrap_or_else(|| "Tümü".to_string()));
+    let table = Table {
+        name: "Siparisler",
+        title: "Sipariş Listesi".to_string(),
+        info: vec![
+            ("Durum", describe(filter.status.map(|s| s.display_name().to_string()))),
+            ("Gemi", describe(filter.ship_id.and_then(|id| orders.iter().find(|o| o.ship_id == id)).and_then(|o| o.ship_name.clone()))),
+            ("Müşteri", describe(filter.customer_id.and_then(|id| orders.iter().find(|o| o.customer_id == Some(id))).and_then(|o| o.customer_name.clone()))),
+            ("Başlangıç", describe(filter.date_from.clone())),
+            ("Bitiş", describe(filter.date_to.clone())),
+        ],
+        columns: vec![
+            column("Sipariş No"),
+            column("Tarih"),
+            column("Gemi"),
+            column("Müşteri"),
+            column("Durum"),
+            column("Teslim Limanı"),
+            column("Para Birimi"),
+            column("Kalem"),
+            money("Toplam Maliyet"),
+            money("Toplam Gelir"),
+            money("Brüt Kâr"),
+            column("Kâr Marjı %"),
+        ],
+        rows,
+        summary: Vec::new(),
+    };
+
+    let stamp = newest(orders.iter().map(|o| o.updated_at.as_str()));
+    write_tables(output_path, &[table], &stamp)
+}
+
+/// Export stock levels and all stock movements
+pub async fn export_stock(output_path: &str) -> Result<ExportResult> {
+    let stock = stock_service::get_all().await?;
+
+    let mut movements = Vec::new();
+    for item in &stock {
+        movements.extend(stock_service::get_movements(item.id).await?);
+    }
+    movements.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+
+    let stock_table = Table {
+        name: "Stok",
+        title: "Stok Listesi".to_string(),
+        info: Vec::new(),
+        columns: vec![
+            column("Ürün"),
+            column("Miktar"),
+            column("Birim"),
+            column("Depo Yeri"),
+            column("Depo Türü"),
+            column("Min. Miktar"),
+            column("Durum"),
+            column("Son Güncelleme"),
+        ],
+        rows: stock.iter().map(|s| vec![
+            Cell::optional(s.supply_item_name.clone()),
+            Cell::Number(s.quantity),
+            Cell::text(s.unit.clone()),
+            Cell::optional(s.warehouse_location.clone()),
+            Cell::text(s.warehouse_type.display_name()),
+            Cell::Number(s.minimum_quantity),
+            Cell::text(if s.quantity < s.minimum_quantity { "Düşük Stok" } else { "Yeterli" }),
+            Cell::text(s.last_updated.clone()),
+        ]).collect(),
+        summary: Vec::new(),
+    };
+
+    let movement_table = Table {
+        name: "Hareketler",
+        title: "Stok Hareketleri".to_string(),
+        info: Vec::new(),
+        columns: vec![
+            column("Tarih"),
+            column("Ürün"),
+            column("Hareket"),
+            column("Miktar"),
+            column("Birim"),
+            column("Referans"),
+            column("Beyanname No"),
+            column("Not"),
+        ],
+        rows: movements.iter().map(|m| vec![
+            Cell::text(m.created_at.clone()),
+            Cell::optional(m.supply_item_name.clone()),
+            Cell::text(m.movement_type.display_name()),
+            Cell::Number(m.quantity),
+            Cell::text(m.unit.clone()),
+            Cell::optional(m.reference_info.clone()),
+            Cell::optional(m.customs_declaration_number.clone()),
+            Cell::optional(m.notes.clone()),
+        ]).collect(),
+        summary: Vec::new(),
+    };
+
+    let stamp = newest(stock.iter().map(|s| s.last_updated.as_str())
+        .chain(movements.iter().map(|m| m.created_at.as_str())));
+    write_tables(output_path, &[stock_table, movement_table], &stamp)
+}
+
+/// Export the bonded stock reconciliation of a period and its declared movements for customs
+pub async fn export_bonded_reconciliation(period_start: &str, period_end: &str, output_path: &str) -> Result<ExportResult> {
+    let report = bonded_service::reconciliation(period_start, period_end).await?;
+    let period = format!("{} - {}", report.period_start, report.period_end);
+
+    let reconciliation_table = Table {
+        name: "Mutabakat",
+        title: "Antrepo Stok Mutabakatı".to_string(),
+        info: vec![("Dönem", Cell::text(period.clone()))],
+        columns: vec![
+            column("Ürün"),
+            column("Birim"),
+            column("Depo Yeri"),
+            column("Dönem Başı"),
+            column("Giriş"),
+            column("İade"),
+            column("Çıkış"),
+            column("Sayım Farkı"),
+            column("Dönem Sonu"),
+            column("Beklenen"),
+            column("Kayıtlı"),
+            column("Fark"),
+        ],
+        rows: report.lines.iter().map(|l| vec![
+            Cell::optional(l.supply_item_name.clone()),
+            Cell::text(l.unit.clone()),
+            Cell::optional(l.warehouse_location.clone()),
+            Cell::Number(l.opening_quantity),
+            Cell::Number(l.received_quantity),
+            Cell::Number(l.returned_quantity),
+            Cell::Number(l.issued_quantity),
+            Cell::Number(l.adjusted_quantity),
+            Cell::Number(l.closing_quantity),
+            Cell::Number(l.expected_quantity),
+            Cell::Number(l.recorded_quantity),
+            Cell::Number(l.difference),
+        ]).collect(),
+        summary: Vec::new(),
+    };
+
+    let movement_table = Table {
+        name: "Hareketler",
+        title: "Antrepo Hareketleri".to_string(),
+        info: vec![("Dönem", Cell::text(period))],
+        columns: vec![
+            column("Tarih"),
+            column("Beyanname No"),
+            column("Ürün"),
+            column("Hareket"),
+            column("Miktar"),
+            column("Birim"),
+            column("Referans"),
+        ],
+        rows: report.movements.iter().map(|m| vec![
+            Cell::text(m.created_at.clone()),
+            Cell::optional(m.customs_declaration_number.clone()),
+            Cell::optional(m.supply_item_name.clone()),
+            Cell::text(m.movement_type.display_name()),
+            Cell::Number(m.quantity),
+            Cell::text(m.unit.clone()),
+            Cell::optional(m.reference_info.clone()),
+        ]).collect(),
+        summary: Vec::new(),
+    };
+
+    let stamp = newest(report.movements.iter().map(|m| m.created_at.as_str()));
+    write_tables(output_path, &[reconciliation_table, movement_table], &stamp)
+}
+
+/// Export the profit report (summary and per-order profit)
+pub async fn export_profit_report(output_path: &str) -> Result<ExportResult> {
+    let summary = calculation_service::get_profit_summary().await?;
+    let orders = calculation_service::get_top_profitable_orders(i32::MAX).await?;
+
+    let single_currency = orders.windows(2).all(|w| w[0].currency == w[1].currency);
+    let money = |title| if single_currency { summed(title) } else { column(title) };
+
+    let table = Table {
+        name: "Kar",
+        title: "Kâr Raporu".to_string(),
+        info: vec![
+            ("Sipariş Sayısı", Cell::Number(summary.total_orders as f64)),
+            ("Toplam Gelir", Cell::Money(summary.total_revenue)),
+            ("Toplam Maliyet", Cell::Money(summary.total_cost)),
+            ("Toplam Kâr", Cell::Money(summary.total_profit)),
+            ("Ortalama Marj %", summary.average_margin.map(Cell::Percent).unwrap_or(Cell::Empty)),
+            ("Para Birimi", Cell::text(summary.currency.clone())),
+        ],
+        columns: vec![
+            column("Sipariş No"),
+            column("Gemi"),
+            money("Gelir"),
+            money("Maliyet"),
+            money("Kâr"),
+            column("Kâr Marjı %"),
+            column("Para Birimi"),
+        ],
+        rows: orders.iter().map(|o| vec![
+            Cell::text(o.order_number.clone()),
+            Cell::text(o.ship_name.clone()),
+            Cell::Money(o.total_revenue),
+            Cell::Money(o.total_cost),
+            Cell::Money(o.profit),
+            Cell::Percent(o.margin_percent),
+            Cell::text(o.currency.clone()),
+        ]).collect(),
+        summary: Vec::new(),
+    };
+
+    // Aggregates have no record timestamp; the fixed fallback date keeps the file reproducible
+    write_tables(output_path, &[table], "")
+}
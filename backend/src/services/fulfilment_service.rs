@@ -0,0 +1,512 @@
+//! Fulfilment Service - Partial deliveries, closed lines and back-order follow-up orders
+
+use crate::models::{
+    ItemDelivery, RecordDeliveryRequest, Order, OrderItem, OrderStatus, OrderWithItems, VisitStatus,
+    FulfilmentStatus,
+};
+use crate::database;
+use crate::services::{order_service, order_item_service, ship_visit_service, delivery_note_service, compliance_service};
+use crate::services::invoice_service::parse_date;
+use crate::services::order_item_service::QUANTITY_EPSILON;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct DeliveryRow {
+    id: i32,
+    order_item_id: i32,
+    product_name: Option<String>,
+    quantity: f64,
+    delivered_at: String,
+    ship_visit_id: Option<i32>,
+    notes: Option<String>,
+    created_at: String,
+}
+
+impl From<DeliveryRow> for ItemDelivery {
+    fn from(row: DeliveryRow) -> Self {
+        ItemDelivery {
+            id: row.id,
+            order_item_id: row.order_item_id,
+            product_name: row.product_name,
+            quantity: row.quantity,
+            delivered_at: row.delivered_at,
+            ship_visit_id: row.ship_visit_id,
+            notes: row.notes,
+            created_at: row.created_at,
+        }
+    }
+}
+
+fn now() -> String {
+    chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Get the delivery events of an order, oldest first
+pub async fn get_deliveries(order_id: i32) -> Result<Vec<ItemDelivery>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<DeliveryRow> = DeliveryRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT d.id, d.order_item_id, oi.product_name, d.quantity, d.delivered_at, d.ship_visit_id, d.notes, d.created_at
+        FROM order_item_deliveries d
+        JOIN order_items oi ON d.order_item_id = oi.id
+        WHERE oi.order_id = ?
+        ORDER BY d.delivered_at, d.id
+        "#,
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(ItemDelivery::from).collect())
+}
+
+async fn insert_delivery<C: ConnectionTrait>(
+    conn: &C,
+    item: &OrderItem,
+    quantity: f64,
+    delivered_at: &str,
+    ship_visit_id: Option<i32>,
+    notes: Option<String>,
+) -> Result<()> {
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO order_item_deliveries (order_item_id, quantity, delivered_at, ship_visit_id, notes)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(item.id)),
+            Value::Double(Some(quantity)),
+            Value::String(Some(Box::new(delivered_at.to_string()))),
+            Value::Int(ship_visit_id),
+            Value::String(notes.map(Box::new)),
+        ]
+    )).await?;
+
+    // The line's ship delivery date is the date it was completed
+    if item.delivered_quantity + quantity + QUANTITY_EPSILON >= item.quantity {
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "UPDATE order_items SET ship_delivery_date = ?, updated_at = datetime('now') WHERE id = ?",
+            vec![
+                Value::String(Some(Box::new(delivered_at.get(..10).unwrap_or(delivered_at).to_string()))),
+                Value::Int(Some(item.id)),
+            ]
+        )).await?;
+    }
+
+    Ok(())
+}
+
+/// Status an order should have given the fulfilment of its lines (None = keep)
+fn derived_status(current: OrderStatus, items: &[OrderItem], proof_of_delivery: bool) -> Option<OrderStatus> {
+    if matches!(current, OrderStatus::Cancelled | OrderStatus::Invoiced) || items.is_empty() {
+        return None;
+    }
+
+    // Fully handed over, but only Delivered once signed for (or waived)
+    if items.iter().all(|i| i.fulfilment.is_done()) && proof_of_delivery {
+        return (current != OrderStatus::Delivered).then_some(OrderStatus::Delivered);
+    }
+
+    // Lines still open: a partly delivered order is under way
+    let any_delivered = items.iter().any(|i| i.delivered_quantity > 0.0);
+    let before_on_way = matches!(
+        current,
+        OrderStatus::New | OrderStatus::Quoted | OrderStatus::Agreed | OrderStatus::WaitingGoods | OrderStatus::Prepared
+    );
+    if current == OrderStatus::Delivered || (any_delivered && before_on_way) {
+        return Some(OrderStatus::OnWay);
+    }
+    None
+}
+
+/// Bring the order status in line with the fulfilment of its lines
+pub(crate) async fn derive_status<C: ConnectionTrait>(conn: &C, order: &Order) -> Result<()> {
+    let items = order_item_service::fetch_by_order(conn, order.id).await?;
+    let proof_of_delivery = delivery_note_service::has_proof_of_delivery(conn, order.id).await?;
+
+    if let Some(status) = derived_status(order.status, &items, proof_of_delivery) {
+        if status == OrderStatus::OnWay && order.status != OrderStatus::Delivered {
+            compliance_service::ensure_compliant(conn, order, status).await?;
+        }
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "UPDATE orders SET status = ?, updated_at = datetime('now') WHERE id = ?",
+            vec![
+                Value::String(Some(Box::new(order_service::status_to_str(status).to_string()))),
+                Value::Int(Some(order.id)),
+            ]
+        )).await?;
+        tracing::info!("Order {} status derived from fulfilment: {:?} -> {:?}", order.id, order.status, status);
+    }
+
+    Ok(())
+}
+
+/// Prepare an order for the manual Delivered status.
+///
+/// Orders without any recorded delivery are delivered in one piece: a delivery
+/// is recorded for every open line, which needs a delivery note waiver since
+/// nothing has been signed for. Otherwise all lines must already be fulfilled,
+/// closed or rolled over, and their deliveries signed for on completed notes.
+pub(crate) async fn complete_delivery<C: ConnectionTrait>(conn: &C, order: &Order) -> Result<()> {
+    let items = order_item_service::fetch_by_order(conn, order.id).await?;
+
+    if items.iter().all(|i| i.delivered_quantity <= 0.0) {
+        if order.delivery_note_waiver.is_none() && items.iter().any(|i| !i.fulfilment.is_done()) {
+            anyhow::bail!(
+                "Order {} has no signed delivery note; record the delivery on a delivery note or waive it with a reason",
+                order.order_number
+            );
+        }
+        let delivered_at = now();
+        for item in items.iter().filter(|i| !i.fulfilment.is_done()) {
+            insert_delivery(conn, item, item.back_ordered_quantity, &delivered_at, order.ship_visit_id, None).await?;
+        }
+        return Ok(());
+    }
+
+    let open: Vec<&str> = items.iter()
+        .filter(|i| !i.fulfilment.is_done())
+        .map(|i| i.product_name.as_str())
+        .collect();
+    if !open.is_empty() {
+        anyhow::bail!(
+            "Order {} still has undelivered lines ({}); record their delivery, close them or roll them over",
+            order.order_number, open.join(", ")
+        );
+    }
+    if !delivery_note_service::has_proof_of_delivery(conn, order.id).await? {
+        anyhow::bail!(
+            "Order {} has deliveries without a signed delivery note; complete the delivery note or waive it with a reason",
+            order.order_number
+        );
+    }
+    Ok(())
+}
+
+/// Record goods handed over to the ship for some lines of an order
+pub async fn record_delivery(req: RecordDeliveryRequest) -> Result<OrderWithItems> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(req.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    if matches!(order.status, OrderStatus::New | OrderStatus::Quoted | OrderStatus::Cancelled | OrderStatus::Invoiced) {
+        anyhow::bail!("Cannot record deliveries for an order in status {}", order.status.display_name());
+    }
+    if req.lines.is_empty() {
+        anyhow::bail!("Delivery has no lines");
+    }
+    let delivered_at = match req.delivered_at {
+        Some(value) => {
+            parse_date(&value)?;
+            value
+        }
+        None => now(),
+    };
+    let ship_visit_id = req.ship_visit_id.or(order.ship_visit_id);
+
+    let txn = conn.begin().await?;
+
+    let mut items = order_item_service::fetch_by_order(&txn, order.id).await?;
+    for line in &req.lines {
+        let item = items.iter_mut()
+            .find(|i| i.id == line.order_item_id)
+            .ok_or_else(|| anyhow::anyhow!("Order item {} is not on order {}", line.order_item_id, order.order_number))?;
+
+        if line.quantity <= 0.0 {
+            anyhow::bail!("Delivered quantity of {} must be positive", item.product_name);
+        }
+        if item.fulfilment.is_done() {
+            anyhow::bail!("{} has nothing left to deliver", item.product_name);
+        }
+        if line.quantity > item.back_ordered_quantity + QUANTITY_EPSILON {
+            anyhow::bail!(
+                "Only {} {} of {} is left to deliver",
+                item.back_ordered_quantity, item.unit, item.product_name
+            );
+        }
+
+        insert_delivery(&txn, item, line.quantity, &delivered_at, ship_visit_id, req.notes.clone()).await?;
+        item.delivered_quantity += line.quantity;
+        item.back_ordered_quantity -= line.quantity;
+    }
+
+    derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    order_service::get_with_items(order.id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))
+}
+
+/// Delete a delivery event (the order status is derived again)
+pub async fn delete_delivery(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct OrderIdRow {
+        order_id: i32,
+        note_status: Option<String>,
+    }
+
+    let Some(row) = OrderIdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT oi.order_id, dn.status as note_status FROM order_item_deliveries d
+        JOIN order_items oi ON d.order_item_id = oi.id
+        LEFT JOIN delivery_notes dn ON d.delivery_note_id = dn.id
+        WHERE d.id = ?
+        "#,
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await? else {
+        return Ok(false);
+    };
+
+    let order = order_service::get_by_id(row.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    if order.status == OrderStatus::Invoiced {
+        anyhow::bail!("Order {} is invoiced; its deliveries cannot be changed", order.order_number);
+    }
+    if row.note_status.as_deref() == Some("COMPLETED") {
+        anyhow::bail!("Delivery is on a signed delivery note and cannot be deleted");
+    }
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM order_item_deliveries WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Close a line short: the undelivered remainder is dropped from the order
+pub async fn close_item(order_item_id: i32, reason: Option<String>) -> Result<OrderItem> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let item = order_item_service::get_by_id(order_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order item not found"))?;
+    let order = order_service::get_by_id(item.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    if matches!(order.status, OrderStatus::Cancelled | OrderStatus::Invoiced) {
+        anyhow::bail!("Order {} is {}; its lines cannot be closed", order.order_number, order.status.display_name());
+    }
+    if item.fulfilment.is_done() {
+        anyhow::bail!("{} has nothing left to deliver", item.product_name);
+    }
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE order_items SET quantity = ?, closed_quantity = closed_quantity + ?, closed_at = ?, close_reason = ?,
+            updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::Double(Some(item.delivered_quantity)),
+            Value::Double(Some(item.back_ordered_quantity)),
+            Value::String(Some(Box::new(now()))),
+            Value::String(reason.map(Box::new)),
+            Value::Int(Some(item.id)),
+        ]
+    )).await?;
+
+    derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    order_item_service::get_by_id(order_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order item not found after update"))
+}
+
+/// Move all undelivered quantities of an order to a follow-up order for a
+/// later visit of the same ship.
+///
+/// The follow-up order is numbered after the original (ORD-…-B1, -B2…),
+/// keeps its customer, currency and prices, and starts as Agreed. The moved
+/// quantities leave the original lines, so each order bills only what it delivers.
+pub async fn roll_over_backorders(order_id: i32, ship_visit_id: i32) -> Result<Order> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    if matches!(order.status, OrderStatus::Cancelled | OrderStatus::Invoiced) {
+        anyhow::bail!("Order {} is {}; its back-orders cannot be rolled over", order.order_number, order.status.display_name());
+    }
+
+    let visit = ship_visit_service::get_by_id(ship_visit_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Ship visit not found"))?;
+    if visit.ship_id != order.ship_id {
+        anyhow::bail!("Ship visit {} is for another ship", ship_visit_id);
+    }
+    if matches!(visit.status, VisitStatus::Departed | VisitStatus::Cancelled) {
+        anyhow::bail!("Ship visit {} is {}", ship_visit_id, visit.status.display_name());
+    }
+    if let Some(current_visit_id) = order.ship_visit_id {
+        if current_visit_id == ship_visit_id {
+            anyhow::bail!("Back-orders must go to a later visit than the order's own");
+        }
+        if let Some(current_visit) = ship_visit_service::get_by_id(current_visit_id).await? {
+            if visit.eta <= current_visit.eta {
+                anyhow::bail!("Ship visit {} is not later than the order's visit", ship_visit_id);
+            }
+        }
+    }
+
+    let items = order_item_service::get_by_order_id(order_id).await?;
+    let open: Vec<&OrderItem> = items.iter()
+        .filter(|i| !i.fulfilment.is_done() && i.back_ordered_quantity > QUANTITY_EPSILON)
+        .collect();
+    if open.is_empty() {
+        anyhow::bail!("Order {} has no back-ordered quantities", order.order_number);
+    }
+
+    #[derive(Debug, FromQueryResult)]
+    struct CountRow {
+        count: i32,
+    }
+
+    let txn = conn.begin().await?;
+
+    let follow_ups = CountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT COUNT(*) as count FROM orders WHERE parent_order_id = ?",
+        vec![Value::Int(Some(order.id))]
+    ))
+    .one(&txn)
+    .await?
+    .map(|r| r.count)
+    .unwrap_or(0);
+    let order_number = format!("{}-B{}", order.order_number, follow_ups + 1);
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO orders (order_number, ship_id, ship_visit_id, customer_id, payment_terms_days, status, delivery_port, currency, notes, parent_order_id)
+        VALUES (?, ?, ?, ?, ?, 'AGREED', ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(order_number))),
+            Value::Int(Some(order.ship_id)),
+            Value::Int(Some(visit.id)),
+            Value::Int(order.customer_id),
+            Value::Int(order.payment_terms_days),
+            Value::String(visit.port_name.clone().or(order.delivery_port.clone()).map(Box::new)),
+            Value::String(Some(Box::new(order.currency.clone()))),
+            Value::String(Some(Box::new(format!("Back-order of {}", order.order_number)))),
+            Value::Int(Some(order.id)),
+        ]
+    )).await?;
+    let follow_up_id = result.last_insert_id() as i32;
+
+    for item in open {
+        let delivery_type = match item.delivery_type {
+            crate::models::DeliveryType::ViaWarehouse => "VIA_WAREHOUSE",
+            crate::models::DeliveryType::DirectToShip => "DIRECT_TO_SHIP",
+        };
+
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO order_items (order_id, product_name, impa_code, description, quantity, unit, buying_price, selling_price,
+                currency, delivery_type, warehouse_delivery_date, notes, backorder_of_item_id)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            vec![
+                Value::Int(Some(follow_up_id)),
+                Value::String(Some(Box::new(item.product_name.clone()))),
+                Value::String(item.impa_code.clone().map(Box::new)),
+                Value::String(item.description.clone().map(Box::new)),
+                Value::Double(Some(item.back_ordered_quantity)),
+                Value::String(Some(Box::new(item.unit.clone()))),
+                Value::Double(Some(item.buying_price)),
+                Value::Double(Some(item.selling_price)),
+                Value::String(Some(Box::new(item.currency.clone()))),
+                Value::String(Some(Box::new(delivery_type.to_string()))),
+                Value::String(item.warehouse_delivery_date.clone().map(Box::new)),
+                Value::String(item.notes.clone().map(Box::new)),
+                Value::Int(Some(item.id)),
+            ]
+        )).await?;
+
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            UPDATE order_items SET quantity = quantity - ?, rolled_over_quantity = rolled_over_quantity + ?,
+                updated_at = datetime('now')
+            WHERE id = ?
+            "#,
+            vec![
+                Value::Double(Some(item.back_ordered_quantity)),
+                Value::Double(Some(item.back_ordered_quantity)),
+                Value::Int(Some(item.id)),
+            ]
+        )).await?;
+    }
+
+    derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    order_service::get_by_id(follow_up_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve follow-up order"))
+}
+
+/// Back-order follow-up orders created from an order
+pub async fn get_follow_up_orders(order_id: i32) -> Result<Vec<Order>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct IdRow {
+        id: i32,
+    }
+
+    let rows: Vec<IdRow> = IdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT id FROM orders WHERE parent_order_id = ? ORDER BY id",
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut orders = Vec::with_capacity(rows.len());
+    for row in rows {
+        if let Some(order) = order_service::get_by_id(row.id).await? {
+            orders.push(order);
+        }
+    }
+    Ok(orders)
+}
+
+/// Lines of an order that still have quantities to deliver
+pub async fn get_back_ordered_items(order_id: i32) -> Result<Vec<OrderItem>> {
+    Ok(order_item_service::get_by_order_id(order_id).await?
+        .into_iter()
+        .filter(|i| matches!(i.fulfilment, FulfilmentStatus::Pending | FulfilmentStatus::PartiallyDelivered))
+        .collect())
+}
@@ -0,0 +1,472 @@
+//! IMPA Service - Code validation and the Marine Stores Guide reference catalog
+//!
+//! IMPA codes are six digits, the first two being the guide section
+//! ("37 01 01" is section 37). Codes are stored without spaces. Once a catalog
+//! has been imported, a code must also belong to one of its sections.
+
+use crate::models::{
+    ImpaCatalogEntry, ImpaSection, ImpaCatalogImportRequest, ImpaCatalogImportResult, ImpaCodeIssue, ImpaCodeProblem,
+};
+use crate::database;
+use crate::services::category_service;
+use crate::services::price_agreement_service::normalize_impa;
+use crate::services::requisition_import_service::{read_rows, resolve_column};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+use std::collections::{BTreeMap, HashSet};
+
+const DEFAULT_SEARCH_LIMIT: i32 = 50;
+
+#[derive(Debug, FromQueryResult)]
+struct EntryRow {
+    code: String,
+    description: String,
+    unit: Option<String>,
+    section: String,
+    section_name: Option<String>,
+    category_id: Option<i32>,
+    category: Option<String>,
+}
+
+impl From<EntryRow> for ImpaCatalogEntry {
+    fn from(row: EntryRow) -> Self {
+        ImpaCatalogEntry {
+            code: row.code,
+            description: row.description,
+            unit: row.unit,
+            section: row.section,
+            section_name: row.section_name,
+            category_id: row.category_id,
+            category: row.category,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct SectionRow {
+    code: String,
+    name: Option<String>,
+    category_id: Option<i32>,
+    category: Option<String>,
+    entry_count: i32,
+}
+
+impl From<SectionRow> for ImpaSection {
+    fn from(row: SectionRow) -> Self {
+        ImpaSection {
+            code: row.code,
+            name: row.name,
+            category_id: row.category_id,
+            category: row.category,
+            entry_count: row.entry_count,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CodeRow {
+    code: String,
+}
+
+const ENTRY_SELECT: &str = r#"
+    SELECT c.code, c.description, c.unit, c.section, s.name as section_name, s.category_id, cat.code as category
+    FROM impa_catalog c
+    LEFT JOIN impa_sections s ON s.code = c.section
+    LEFT JOIN categories cat ON s.category_id = cat.id
+"#;
+
+/// Normalize an IMPA code and check its structure.
+///
+/// With a non-empty `sections` set, the section prefix must be one of them.
+pub(crate) fn check_code(code: &str, sections: &HashSet<String>) -> Result<String> {
+    let normalized = normalize_impa(code);
+    if normalized.len() != 6 || !normalized.chars().all(|c| c.is_ascii_digit()) {
+        anyhow::bail!("IMPA code '{}' must be six digits", code.trim());
+    }
+    let section = &normalized[..2];
+    if !sections.is_empty() && !sections.contains(section) {
+        anyhow::bail!("IMPA code {} is in unknown section {}", normalized, section);
+    }
+    Ok(normalized)
+}
+
+/// Sections of the imported catalog (empty until a catalog is loaded)
+pub(crate) async fn known_sections<C: ConnectionTrait>(conn: &C) -> Result<HashSet<String>> {
+    let rows: Vec<CodeRow> = CodeRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT code FROM impa_sections".to_string()
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| r.code).collect())
+}
+
+/// Sections of the imported catalog, on a pooled connection
+pub(crate) async fn get_known_sections() -> Result<HashSet<String>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    known_sections(&conn).await
+}
+
+/// Validate an optional code from a request; a blank code becomes None
+pub(crate) async fn validate_optional<C: ConnectionTrait>(conn: &C, code: Option<String>) -> Result<Option<String>> {
+    match code.filter(|c| !c.trim().is_empty()) {
+        Some(code) => Ok(Some(check_code(&code, &known_sections(conn).await?)?)),
+        None => Ok(None),
+    }
+}
+
+/// Validate an IMPA code. Returns it without spaces.
+pub async fn validate(code: &str) -> Result<String> {
+    check_code(code, &get_known_sections().await?)
+}
+
+pub(crate) async fn find<C: ConnectionTrait>(conn: &C, code: &str) -> Result<Option<ImpaCatalogEntry>> {
+    let row: Option<EntryRow> = EntryRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("{} WHERE c.code = ?", ENTRY_SELECT),
+        vec![Value::String(Some(Box::new(normalize_impa(code))))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(ImpaCatalogEntry::from))
+}
+
+/// Reference entry of a code, for auto-filling item names, units and categories
+pub async fn lookup(code: &str) -> Result<Option<ImpaCatalogEntry>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    find(&conn, code).await
+}
+
+/// Search the catalog by code prefix or description, optionally within a section
+pub async fn search(query: &str, section: Option<&str>, limit: i32) -> Result<Vec<ImpaCatalogEntry>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let query = query.trim();
+    let limit = if limit > 0 { limit } else { DEFAULT_SEARCH_LIMIT };
+    let section = section.map(normalize_impa).filter(|s| !s.is_empty());
+
+    let rows: Vec<EntryRow> = EntryRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            "{} WHERE (c.code LIKE ? OR c.description LIKE ?) AND (? IS NULL OR c.section = ?) ORDER BY c.code LIMIT ?",
+            ENTRY_SELECT
+        ),
+        vec![
+            Value::String(Some(Box::new(format!("{}%", normalize_impa(query))))),
+            Value::String(Some(Box::new(format!("%{}%", query)))),
+            Value::String(section.clone().map(Box::new)),
+            Value::String(section.map(Box::new)),
+            Value::Int(Some(limit)),
+        ]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(ImpaCatalogEntry::from).collect())
+}
+
+async fn fetch_section<C: ConnectionTrait>(conn: &C, code: &str) -> Result<Option<ImpaSection>> {
+    let row: Option<SectionRow> = SectionRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT s.code, s.name, s.category_id, cat.code as category,
+               (SELECT COUNT(*) FROM impa_catalog c WHERE c.section = s.code) as entry_count
+        FROM impa_sections s
+        LEFT JOIN categories cat ON s.category_id = cat.id
+        WHERE s.code = ?
+        "#,
+        vec![Value::String(Some(Box::new(code.to_string())))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(ImpaSection::from))
+}
+
+/// Get the catalog sections with their entry counts
+pub async fn get_sections() -> Result<Vec<ImpaSection>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<SectionRow> = SectionRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT s.code, s.name, s.category_id, cat.code as category,
+               (SELECT COUNT(*) FROM impa_catalog c WHERE c.section = s.code) as entry_count
+        FROM impa_sections s
+        LEFT JOIN categories cat ON s.category_id = cat.id
+        ORDER BY s.code
+        "#.to_string()
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(ImpaSection::from).collect())
+}
+
+/// Set the supply item category filled in for items of a section (None clears it)
+pub async fn set_section_category(section: &str, category_id: Option<i32>) -> Result<ImpaSection> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let category = match category_id {
+        Some(category_id) => Some(category_service::active_code(&conn, category_id).await?),
+        None => None,
+    };
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE impa_sections SET category = ?, category_id = ? WHERE code = ?",
+        vec![
+            Value::String(category.map(Box::new)),
+            Value::Int(category_id),
+            Value::String(Some(Box::new(normalize_impa(section)))),
+        ]
+    )).await?;
+    if result.rows_affected() == 0 {
+        anyhow::bail!("IMPA section {} not found", section.trim());
+    }
+
+    fetch_section(&conn, &normalize_impa(section)).await?
+        .ok_or_else(|| anyhow::anyhow!("IMPA section {} not found", section.trim()))
+}
+
+/// Section code and name from a cell like "37", "37 01" or "37 NAUTICAL EQUIPMENT"
+fn parse_section(value: &str) -> (String, Option<String>) {
+    let value = value.trim();
+    let digits: String = value.chars().take_while(|c| c.is_ascii_digit() || *c == ' ').collect();
+    let code: String = normalize_impa(&digits).chars().take(2).collect();
+    let name = value[digits.len()..].trim().trim_start_matches(['-', '.', ':']).trim();
+    (code, Some(name.to_string()).filter(|n| !n.is_empty()))
+}
+
+/// Load an IMPA Marine Stores Guide-style file into the reference catalog.
+///
+/// Rows with a six digit code become entries; rows whose code is only the two
+/// section digits ("37" / "NAUTICAL EQUIPMENT") name the section. Existing codes
+/// are updated; with `replace_existing` the catalog is cleared first (section
+/// category settings are kept).
+pub async fn import_catalog(req: ImpaCatalogImportRequest) -> Result<ImpaCatalogImportResult> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows = read_rows(&req.file_path, req.sheet_name.as_deref(), req.delimiter.as_deref())?;
+    let header_row = req.header_row.max(0) as usize;
+    let headers = match header_row {
+        0 => None,
+        n => Some(rows.get(n - 1)
+            .ok_or_else(|| anyhow::anyhow!("The file has no row {} for the headers", n))?
+            .as_slice()),
+    };
+    let optional = |reference: &Option<String>| -> Result<Option<usize>> {
+        match reference.as_deref().map(str::trim) {
+            Some(r) if !r.is_empty() => Ok(Some(resolve_column(r, headers)?)),
+            _ => Ok(None),
+        }
+    };
+    let code_column = resolve_column(&req.mapping.code, headers)?;
+    let description_column = resolve_column(&req.mapping.description, headers)?;
+    let unit_column = optional(&req.mapping.unit)?;
+    let section_column = optional(&req.mapping.section)?;
+
+    let cell = |row: &[String], index: Option<usize>| -> Option<String> {
+        index.and_then(|i| row.get(i))
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+    };
+
+    let mut section_names: BTreeMap<String, Option<String>> = BTreeMap::new();
+    let mut entries: BTreeMap<String, (String, Option<String>)> = BTreeMap::new();
+    let mut errors = Vec::new();
+
+    for (index, row) in rows.iter().enumerate().skip(header_row) {
+        let row_number = index + 1;
+        let code = cell(row, Some(code_column));
+        let description = cell(row, Some(description_column));
+        let section = cell(row, section_column).map(|s| parse_section(&s));
+        let Some(code) = code else {
+            if description.is_some() {
+                errors.push(format!("Row {}: no IMPA code", row_number));
+            }
+            continue;
+        };
+
+        let normalized = normalize_impa(&code);
+        if normalized.len() == 2 && normalized.chars().all(|c| c.is_ascii_digit()) {
+            section_names.insert(normalized, description.or(section.and_then(|(_, name)| name)));
+            continue;
+        }
+
+        let code = match check_code(&code, &HashSet::new()) {
+            Ok(code) => code,
+            Err(e) => {
+                errors.push(format!("Row {}: {}", row_number, e));
+                continue;
+            }
+        };
+        let Some(description) = description else {
+            errors.push(format!("Row {}: IMPA code {} has no description", row_number, code));
+            continue;
+        };
+        if let Some((section_code, section_name)) = section {
+            if !section_code.is_empty() && section_code != code[..2] {
+                errors.push(format!("Row {}: IMPA code {} is not in section {}", row_number, code, section_code));
+                continue;
+            }
+            if section_name.is_some() {
+                section_names.entry(code[..2].to_string()).or_insert(section_name);
+            }
+        }
+        section_names.entry(code[..2].to_string()).or_insert(None);
+        entries.insert(code, (description, cell(row, unit_column)));
+    }
+
+    if entries.is_empty() {
+        anyhow::bail!("No IMPA codes found in {}", req.file_path);
+    }
+
+    let existing: HashSet<String> = if req.replace_existing {
+        HashSet::new()
+    } else {
+        CodeRow::find_by_statement(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            "SELECT code FROM impa_catalog".to_string()
+        ))
+        .all(&conn)
+        .await?
+        .into_iter()
+        .map(|r| r.code)
+        .collect()
+    };
+
+    let txn = conn.begin().await?;
+
+    if req.replace_existing {
+        txn.execute(Statement::from_string(DatabaseBackend::Sqlite, "DELETE FROM impa_catalog".to_string())).await?;
+        txn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            "DELETE FROM impa_sections WHERE category_id IS NULL".to_string()
+        )).await?;
+    }
+
+    let mut sections = 0;
+    for (code, name) in &section_names {
+        if name.is_some() {
+            sections += 1;
+        }
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO impa_sections (code, name) VALUES (?, ?)
+            ON CONFLICT(code) DO UPDATE SET name = COALESCE(excluded.name, impa_sections.name)
+            "#,
+            vec![
+                Value::String(Some(Box::new(code.clone()))),
+                Value::String(name.clone().map(Box::new)),
+            ]
+        )).await?;
+    }
+
+    let mut inserted = 0;
+    let mut updated = 0;
+    for (code, (description, unit)) in entries {
+        if existing.contains(&code) {
+            updated += 1;
+        } else {
+            inserted += 1;
+        }
+        let section = code[..2].to_string();
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO impa_catalog (code, description, unit, section) VALUES (?, ?, ?, ?)
+            ON CONFLICT(code) DO UPDATE SET
+                description = excluded.description, unit = excluded.unit,
+                section = excluded.section, updated_at = datetime('now')
+            "#,
+            vec![
+                Value::String(Some(Box::new(code))),
+                Value::String(Some(Box::new(description))),
+                Value::String(unit.map(Box::new)),
+                Value::String(Some(Box::new(section))),
+            ]
+        )).await?;
+    }
+
+    txn.commit().await?;
+
+    Ok(ImpaCatalogImportResult { inserted, updated, sections, errors })
+}
+
+/// Supply items whose IMPA code is malformed or missing from the reference catalog
+pub async fn get_code_issues() -> Result<Vec<ImpaCodeIssue>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct ItemRow {
+        id: i32,
+        name: String,
+        supplier_name: Option<String>,
+        impa_code: String,
+    }
+
+    let items: Vec<ItemRow> = ItemRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT si.id, si.name, s.name as supplier_name, si.impa_code
+        FROM supply_items si
+        LEFT JOIN suppliers s ON si.supplier_id = s.id
+        WHERE si.impa_code IS NOT NULL AND trim(si.impa_code) != ''
+        ORDER BY si.impa_code, si.name
+        "#.to_string()
+    ))
+    .all(&conn)
+    .await?;
+
+    let sections = known_sections(&conn).await?;
+    let catalog: HashSet<String> = CodeRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT code FROM impa_catalog".to_string()
+    ))
+    .all(&conn)
+    .await?
+    .into_iter()
+    .map(|r| r.code)
+    .collect();
+
+    let mut issues: Vec<ImpaCodeIssue> = items.into_iter().filter_map(|item| {
+        let (problem, message) = match check_code(&item.impa_code, &sections) {
+            Err(e) => (ImpaCodeProblem::Invalid, e.to_string()),
+            Ok(code) if !catalog.contains(&code) => (
+                ImpaCodeProblem::NotInCatalog,
+                format!("IMPA code {} is not in the reference catalog", code),
+            ),
+            Ok(_) => return None,
+        };
+        Some(ImpaCodeIssue {
+            supply_item_id: item.id,
+            supply_item_name: item.name,
+            supplier_name: item.supplier_name,
+            impa_code: item.impa_code,
+            problem,
+            message,
+        })
+    }).collect();
+    issues.sort_by_key(|i| i.problem != ImpaCodeProblem::Invalid);
+
+    Ok(issues)
+}
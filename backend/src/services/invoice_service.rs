@@ -0,0 +1,304 @@
+//! Invoice Service - Customer invoices (receivables side of an order)
+
+use crate::models::{Invoice, InvoiceStatus, CreateInvoiceRequest, OrderStatus};
+use crate::database;
+use crate::services::{calculation_service, customer_service, numbering, order_service, ship_service};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+
+/// Amounts below this are treated as zero (float rounding on currency values)
+pub(crate) const AMOUNT_EPSILON: f64 = 0.005;
+
+#[derive(Debug, FromQueryResult)]
+struct InvoiceRow {
+    id: i32,
+    invoice_number: String,
+    order_id: i32,
+    order_number: Option<String>,
+    customer_id: Option<i32>,
+    customer_name: String,
+    amount: f64,
+    paid_amount: f64,
+    credited_amount: f64,
+    currency: String,
+    issue_date: String,
+    due_date: String,
+    status: String,
+    notes: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<InvoiceRow> for Invoice {
+    fn from(row: InvoiceRow) -> Self {
+        Invoice {
+            id: row.id,
+            invoice_number: row.invoice_number,
+            order_id: row.order_id,
+            order_number: row.order_number,
+            customer_id: row.customer_id,
+            customer_name: row.customer_name,
+            amount: row.amount,
+            paid_amount: row.paid_amount,
+            credited_amount: row.credited_amount,
+            balance: row.amount - row.paid_amount - row.credited_amount,
+            currency: row.currency,
+            issue_date: row.issue_date,
+            due_date: row.due_date,
+            status: invoice_status_from_str(&row.status),
+            notes: row.notes,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Parse a date stored as `YYYY-MM-DD` or a full ISO 8601 timestamp
+pub(crate) fn parse_date(value: &str) -> Result<chrono::NaiveDate> {
+    let date_part = value.get(..10).unwrap_or(value);
+    chrono::NaiveDate::parse_from_str(date_part, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid date: {}", value))
+}
+
+pub(crate) fn invoice_status_from_str(status: &str) -> InvoiceStatus {
+    match status {
+        "PARTIALLY_PAID" => InvoiceStatus::PartiallyPaid,
+        "PAID" => InvoiceStatus::Paid,
+        "CANCELLED" => InvoiceStatus::Cancelled,
+        _ => InvoiceStatus::Open,
+    }
+}
+
+pub(crate) fn invoice_status_to_str(status: InvoiceStatus) -> &'static str {
+    match status {
+        InvoiceStatus::Open => "OPEN",
+        InvoiceStatus::PartiallyPaid => "PARTIALLY_PAID",
+        InvoiceStatus::Paid => "PAID",
+        InvoiceStatus::Cancelled => "CANCELLED",
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    i.id, i.invoice_number, i.order_id, o.order_number, i.customer_id, i.customer_name, i.amount,
+    CAST(COALESCE((SELECT SUM(pa.amount * pa.exchange_rate) FROM payment_allocations pa WHERE pa.invoice_id = i.id), 0.0) AS REAL) as paid_amount,
+    CAST(COALESCE((SELECT SUM(cn.amount) FROM credit_notes cn WHERE cn.invoice_id = i.id), 0.0) AS REAL) as credited_amount,
+    i.currency, i.issue_date, i.due_date, i.status, i.notes, i.created_at, i.updated_at
+"#;
+
+const FROM_JOIN: &str = "FROM invoices i LEFT JOIN orders o ON i.order_id = o.id";
+
+/// Get all invoices with optional status filter
+pub async fn get_all(status_filter: Option<InvoiceStatus>) -> Result<Vec<Invoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<InvoiceRow> = match status_filter {
+        Some(status) => InvoiceRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            format!("SELECT {} {} WHERE i.status = ? ORDER BY i.issue_date DESC, i.id DESC", SELECT_FIELDS, FROM_JOIN),
+            vec![Value::String(Some(Box::new(invoice_status_to_str(status).to_string())))]
+        ))
+        .all(&conn)
+        .await?,
+        None => InvoiceRow::find_by_statement(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            format!("SELECT {} {} ORDER BY i.issue_date DESC, i.id DESC", SELECT_FIELDS, FROM_JOIN)
+        ))
+        .all(&conn)
+        .await?,
+    };
+
+    Ok(rows.into_iter().map(Invoice::from).collect())
+}
+
+/// Get invoice by ID
+pub async fn get_by_id(id: i32) -> Result<Option<Invoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<InvoiceRow> = InvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE i.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    Ok(row.map(Invoice::from))
+}
+
+/// Get invoices for an order
+pub async fn get_by_order(order_id: i32) -> Result<Vec<Invoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<InvoiceRow> = InvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE i.order_id = ? ORDER BY i.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(Invoice::from).collect())
+}
+
+/// Get invoices billed to a customer (ship owner)
+pub async fn get_by_customer(customer_id: i32) -> Result<Vec<Invoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<InvoiceRow> = InvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE i.customer_id = ? ORDER BY i.due_date ASC, i.id ASC", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(customer_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(Invoice::from).collect())
+}
+
+/// Create an invoice for an order.
+///
+/// The invoice is billed to the order's customer account (or the ship owner's)
+/// and defaults to the order's total revenue. Without an explicit due date, the
+/// order's payment terms (or 30 days) apply. An order has at most one invoice
+/// that is not cancelled.
+pub async fn create(req: CreateInvoiceRequest) -> Result<Invoice> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(req.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    if order.status == OrderStatus::Cancelled {
+        anyhow::bail!("Cannot invoice a cancelled order");
+    }
+
+    if let Some(existing) = get_by_order(order.id).await?
+        .into_iter()
+        .find(|i| i.status != InvoiceStatus::Cancelled)
+    {
+        anyhow::bail!("Order {} is already invoiced on {}", order.order_number, existing.invoice_number);
+    }
+
+    let ship = ship_service::get_by_id(order.ship_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
+    let customer_id = order.customer_id.or(ship.owner_id)
+        .ok_or_else(|| anyhow::anyhow!("Ship {} is not linked to a customer account to invoice", ship.name))?;
+    let customer = customer_service::get_by_id(customer_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+
+    let amount = match req.amount {
+        Some(amount) => amount,
+        None => calculation_service::calculate_order_totals(order.id).await?.total_revenue,
+    };
+    if amount <= 0.0 {
+        anyhow::bail!("Invoice amount must be positive");
+    }
+
+    let issue_date = match req.issue_date {
+        Some(date) => parse_date(&date)?,
+        None => chrono::Utc::now().date_naive(),
+    };
+    let due_date = match req.due_date {
+        Some(date) => parse_date(&date)?,
+        None => issue_date + chrono::Duration::days(order.payment_terms_days.unwrap_or(30) as i64),
+    };
+    if due_date < issue_date {
+        anyhow::bail!("Due date cannot be before issue date");
+    }
+    let invoice_number = numbering::next_document_number(&conn, "invoices", "invoice_number", "INV").await?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO invoices (invoice_number, order_id, customer_id, customer_name, amount, currency, issue_date, due_date, status, notes)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, 'OPEN', ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(invoice_number))),
+            Value::Int(Some(order.id)),
+            Value::Int(Some(customer.id)),
+            Value::String(Some(Box::new(customer.legal_name))),
+            Value::Double(Some(amount)),
+            Value::String(Some(Box::new(order.currency))),
+            Value::String(Some(Box::new(issue_date.format("%Y-%m-%d").to_string()))),
+            Value::String(Some(Box::new(due_date.format("%Y-%m-%d").to_string()))),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+
+    get_by_id(result.last_insert_id() as i32).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created invoice"))
+}
+
+/// Cancel an invoice. Invoices with payments applied or credit notes issued
+/// against them cannot be cancelled.
+pub async fn cancel(id: i32) -> Result<Invoice> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let invoice = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Invoice not found"))?;
+
+    if invoice.paid_amount > AMOUNT_EPSILON {
+        anyhow::bail!("Invoice {} has payments applied and cannot be cancelled", invoice.invoice_number);
+    }
+    if invoice.credited_amount > AMOUNT_EPSILON {
+        anyhow::bail!("Invoice {} has credit notes issued against it and cannot be cancelled", invoice.invoice_number);
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE invoices SET status = 'CANCELLED', updated_at = datetime('now') WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Invoice not found after update"))
+}
+
+/// Recompute the stored status of an invoice from its allocations
+pub(crate) async fn refresh_status<C: ConnectionTrait>(conn: &C, id: i32) -> Result<()> {
+    let row: Option<InvoiceRow> = InvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE i.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(conn)
+    .await?;
+
+    let Some(row) = row else {
+        anyhow::bail!("Invoice not found");
+    };
+    if row.status == "CANCELLED" {
+        return Ok(());
+    }
+
+    let status = if row.amount - row.paid_amount - row.credited_amount <= AMOUNT_EPSILON {
+        InvoiceStatus::Paid
+    } else if row.paid_amount > AMOUNT_EPSILON {
+        InvoiceStatus::PartiallyPaid
+    } else {
+        InvoiceStatus::Open
+    };
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE invoices SET status = ?, updated_at = datetime('now') WHERE id = ?",
+        vec![
+            Value::String(Some(Box::new(invoice_status_to_str(status).to_string()))),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    Ok(())
+}
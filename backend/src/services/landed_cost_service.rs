@@ -0,0 +1,442 @@
+//! Landed Cost Service - Freight, customs and handling allocated onto order items
+
+use crate::models::{
+    LandedCost, LandedCostAllocation, CreateLandedCostRequest, LandedCostType, AllocationMethod,
+    DeliveryType, OrderItem,
+};
+use crate::database;
+use crate::services::{order_service, order_item_service, price_agreement_service};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct LandedCostRow {
+    id: i32,
+    order_id: i32,
+    order_number: Option<String>,
+    stock_movement_id: Option<i32>,
+    cost_type: String,
+    description: Option<String>,
+    amount: f64,
+    currency: String,
+    allocation_method: String,
+    reference: Option<String>,
+    created_at: String,
+}
+
+impl LandedCostRow {
+    fn into_landed_cost(self, allocations: Vec<LandedCostAllocation>) -> LandedCost {
+        LandedCost {
+            id: self.id,
+            order_id: self.order_id,
+            order_number: self.order_number,
+            stock_movement_id: self.stock_movement_id,
+            cost_type: cost_type_from_str(&self.cost_type),
+            description: self.description,
+            amount: self.amount,
+            currency: self.currency,
+            allocation_method: allocation_method_from_str(&self.allocation_method),
+            reference: self.reference,
+            allocations,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct AllocationRow {
+    id: i32,
+    landed_cost_id: i32,
+    order_item_id: i32,
+    product_name: Option<String>,
+    quantity: Option<f64>,
+    basis: f64,
+    amount: f64,
+}
+
+impl From<AllocationRow> for LandedCostAllocation {
+    fn from(row: AllocationRow) -> Self {
+        let amount_per_unit = match row.quantity {
+            Some(quantity) if quantity > 0.0 => row.amount / quantity,
+            _ => 0.0,
+        };
+        LandedCostAllocation {
+            id: row.id,
+            landed_cost_id: row.landed_cost_id,
+            order_item_id: row.order_item_id,
+            product_name: row.product_name,
+            basis: row.basis,
+            amount: row.amount,
+            amount_per_unit,
+        }
+    }
+}
+
+fn cost_type_from_str(cost_type: &str) -> LandedCostType {
+    match cost_type {
+        "FREIGHT" => LandedCostType::Freight,
+        "CUSTOMS" => LandedCostType::Customs,
+        "HANDLING" => LandedCostType::Handling,
+        "INSURANCE" => LandedCostType::Insurance,
+        _ => LandedCostType::Other,
+    }
+}
+
+fn cost_type_to_str(cost_type: LandedCostType) -> &'static str {
+    match cost_type {
+        LandedCostType::Freight => "FREIGHT",
+        LandedCostType::Customs => "CUSTOMS",
+        LandedCostType::Handling => "HANDLING",
+        LandedCostType::Insurance => "INSURANCE",
+        LandedCostType::Other => "OTHER",
+    }
+}
+
+fn allocation_method_from_str(method: &str) -> AllocationMethod {
+    match method {
+        "WEIGHT" => AllocationMethod::Weight,
+        "QUANTITY" => AllocationMethod::Quantity,
+        _ => AllocationMethod::Value,
+    }
+}
+
+fn allocation_method_to_str(method: AllocationMethod) -> &'static str {
+    match method {
+        AllocationMethod::Value => "VALUE",
+        AllocationMethod::Weight => "WEIGHT",
+        AllocationMethod::Quantity => "QUANTITY",
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    lc.id, lc.order_id, o.order_number, lc.stock_movement_id, lc.cost_type, lc.description,
+    lc.amount, o.currency, lc.allocation_method, lc.reference, lc.created_at
+"#;
+
+const FROM_JOIN: &str = "FROM landed_costs lc LEFT JOIN orders o ON lc.order_id = o.id";
+
+async fn get_allocations<C: ConnectionTrait>(conn: &C, landed_cost_id: i32) -> Result<Vec<LandedCostAllocation>> {
+    let rows: Vec<AllocationRow> = AllocationRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT a.id, a.landed_cost_id, a.order_item_id, oi.product_name, oi.quantity, a.basis, a.amount
+        FROM landed_cost_allocations a
+        LEFT JOIN order_items oi ON a.order_item_id = oi.id
+        WHERE a.landed_cost_id = ?
+        ORDER BY a.order_item_id
+        "#,
+        vec![Value::Int(Some(landed_cost_id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(LandedCostAllocation::from).collect())
+}
+
+/// Get landed costs recorded against an order
+pub async fn get_by_order(order_id: i32) -> Result<Vec<LandedCost>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<LandedCostRow> = LandedCostRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE lc.order_id = ? ORDER BY lc.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut costs = Vec::with_capacity(rows.len());
+    for row in rows {
+        let allocations = get_allocations(&conn, row.id).await?;
+        costs.push(row.into_landed_cost(allocations));
+    }
+    Ok(costs)
+}
+
+/// Get a landed cost with its allocations
+pub async fn get_by_id(id: i32) -> Result<Option<LandedCost>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<LandedCostRow> = LandedCostRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE lc.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    match row {
+        Some(row) => {
+            let allocations = get_allocations(&conn, row.id).await?;
+            Ok(Some(row.into_landed_cost(allocations)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Warehouse stock record holding the catalog item an order line was bought as
+async fn stock_for_item<C: ConnectionTrait>(conn: &C, item: &OrderItem) -> Result<Option<i32>> {
+    #[derive(Debug, FromQueryResult)]
+    struct StockIdRow {
+        id: i32,
+    }
+
+    if item.delivery_type == DeliveryType::DirectToShip {
+        return Ok(None);
+    }
+    let Some(impa_code) = item.impa_code.as_deref().map(price_agreement_service::normalize_impa) else {
+        return Ok(None);
+    };
+
+    let row: Option<StockIdRow> = StockIdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT s.id FROM stock s
+        JOIN supply_items si ON s.supply_item_id = si.id
+        WHERE replace(si.impa_code, ' ', '') = ?
+        ORDER BY (abs(si.unit_price - ?) < 0.005) DESC, s.id
+        LIMIT 1
+        "#,
+        vec![Value::String(Some(Box::new(impa_code))), Value::Double(Some(item.buying_price))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.id))
+}
+
+/// Order lines covered by a goods receipt, with the receipt's stock record
+async fn items_for_receipt<C: ConnectionTrait>(
+    conn: &C,
+    order_id: i32,
+    stock_movement_id: i32,
+    items: Vec<OrderItem>,
+) -> Result<Vec<(OrderItem, Option<i32>)>> {
+    #[derive(Debug, FromQueryResult)]
+    struct ReceiptRow {
+        stock_id: i32,
+        movement_type: String,
+        reference_type: Option<String>,
+        reference_id: Option<i32>,
+        impa_code: Option<String>,
+    }
+
+    let receipt: ReceiptRow = ReceiptRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT sm.stock_id, sm.movement_type, sm.reference_type, sm.reference_id, si.impa_code
+        FROM stock_movements sm
+        JOIN stock s ON sm.stock_id = s.id
+        JOIN supply_items si ON s.supply_item_id = si.id
+        WHERE sm.id = ?
+        "#,
+        vec![Value::Int(Some(stock_movement_id))]
+    ))
+    .one(conn)
+    .await?
+    .ok_or_else(|| anyhow::anyhow!("Goods receipt not found"))?;
+
+    if receipt.movement_type != "IN"
+        || receipt.reference_type.as_deref() != Some("order")
+        || receipt.reference_id != Some(order_id)
+    {
+        anyhow::bail!("Stock movement {} is not a goods receipt for this order", stock_movement_id);
+    }
+    let receipt_impa = receipt.impa_code.as_deref().map(price_agreement_service::normalize_impa);
+
+    let covered: Vec<(OrderItem, Option<i32>)> = items.into_iter()
+        .filter(|item| item.delivery_type == DeliveryType::ViaWarehouse)
+        .filter(|item| receipt_impa.is_some() && item.impa_code.as_deref().map(price_agreement_service::normalize_impa) == receipt_impa)
+        .map(|item| (item, Some(receipt.stock_id)))
+        .collect();
+
+    if covered.is_empty() {
+        anyhow::bail!("No order line matches the goods receipt");
+    }
+    Ok(covered)
+}
+
+/// Allocation basis of a line for the chosen method
+fn basis_for(item: &OrderItem, method: AllocationMethod, req: &CreateLandedCostRequest) -> Result<f64> {
+    match method {
+        AllocationMethod::Value => Ok(item.buying_price * item.quantity),
+        AllocationMethod::Quantity => Ok(item.quantity),
+        AllocationMethod::Weight => {
+            if let Some(weight) = req.weights.iter().find(|w| w.order_item_id == item.id) {
+                if weight.weight_kg < 0.0 {
+                    anyhow::bail!("Weight of {} cannot be negative", item.product_name);
+                }
+                Ok(weight.weight_kg)
+            } else if item.unit.eq_ignore_ascii_case("KG") {
+                Ok(item.quantity)
+            } else {
+                anyhow::bail!("Weight is required for {} ({})", item.product_name, item.unit)
+            }
+        }
+    }
+}
+
+/// Record a landed cost and allocate it onto the order's items.
+///
+/// With a goods receipt, only the lines received with it share the cost.
+/// Allocations are rounded to cents; the rounding difference goes to the
+/// line with the largest basis so the allocations add up to the amount.
+pub async fn create(req: CreateLandedCostRequest) -> Result<LandedCost> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    order_service::get_by_id(req.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    if req.amount <= 0.0 {
+        anyhow::bail!("Landed cost amount must be positive");
+    }
+
+    let items = order_item_service::get_by_order_id(req.order_id).await?;
+    let targets = match req.stock_movement_id {
+        Some(stock_movement_id) => items_for_receipt(&conn, req.order_id, stock_movement_id, items).await?,
+        None => {
+            let mut targets = Vec::with_capacity(items.len());
+            for item in items {
+                let stock_id = stock_for_item(&conn, &item).await?;
+                targets.push((item, stock_id));
+            }
+            targets
+        }
+    };
+    if targets.is_empty() {
+        anyhow::bail!("Order has no items to allocate the cost to");
+    }
+
+    let mut bases = Vec::with_capacity(targets.len());
+    for (item, _) in &targets {
+        bases.push(basis_for(item, req.allocation_method, &req)?);
+    }
+    let total_basis: f64 = bases.iter().sum();
+    if total_basis <= 0.0 {
+        anyhow::bail!("Cannot allocate: the {} of the items is zero", match req.allocation_method {
+            AllocationMethod::Value => "value",
+            AllocationMethod::Weight => "weight",
+            AllocationMethod::Quantity => "quantity",
+        });
+    }
+
+    let mut amounts: Vec<f64> = bases.iter()
+        .map(|basis| (req.amount * basis / total_basis * 100.0).round() / 100.0)
+        .collect();
+    let difference = req.amount - amounts.iter().sum::<f64>();
+    if let Some(largest) = bases.iter().enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(index, _)| index)
+    {
+        amounts[largest] = ((amounts[largest] + difference) * 100.0).round() / 100.0;
+    }
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO landed_costs (order_id, stock_movement_id, cost_type, description, amount, allocation_method, reference)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(req.order_id)),
+            Value::Int(req.stock_movement_id),
+            Value::String(Some(Box::new(cost_type_to_str(req.cost_type).to_string()))),
+            Value::String(req.description.clone().map(Box::new)),
+            Value::Double(Some(req.amount)),
+            Value::String(Some(Box::new(allocation_method_to_str(req.allocation_method).to_string()))),
+            Value::String(req.reference.clone().map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    for (((item, stock_id), basis), amount) in targets.iter().zip(&bases).zip(&amounts) {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO landed_cost_allocations (landed_cost_id, order_item_id, stock_id, basis, amount)
+            VALUES (?, ?, ?, ?, ?)
+            "#,
+            vec![
+                Value::Int(Some(id)),
+                Value::Int(Some(item.id)),
+                Value::Int(*stock_id),
+                Value::Double(Some(*basis)),
+                Value::Double(Some(*amount)),
+            ]
+        )).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created landed cost"))
+}
+
+/// Delete a landed cost and its allocations
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM landed_cost_allocations WHERE landed_cost_id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM landed_costs WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    txn.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[derive(Debug, FromQueryResult)]
+struct AmountRow {
+    amount: f64,
+}
+
+/// Total landed cost allocated to an order line
+pub(crate) async fn allocated_to_item<C: ConnectionTrait>(conn: &C, order_item_id: i32) -> Result<f64> {
+    let row: Option<AmountRow> = AmountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT CAST(COALESCE(SUM(amount), 0.0) AS REAL) as amount FROM landed_cost_allocations WHERE order_item_id = ?",
+        vec![Value::Int(Some(order_item_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.amount).unwrap_or(0.0))
+}
+
+/// Total landed cost allocated to the lines of an order
+pub(crate) async fn allocated_to_order<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<f64> {
+    let row: Option<AmountRow> = AmountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT CAST(COALESCE(SUM(a.amount), 0.0) AS REAL) as amount
+        FROM landed_cost_allocations a
+        JOIN order_items oi ON a.order_item_id = oi.id
+        WHERE oi.order_id = ?
+        "#,
+        vec![Value::Int(Some(order_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.amount).unwrap_or(0.0))
+}
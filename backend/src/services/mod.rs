@@ -1,11 +1,40 @@
 //! Services module - Business logic layer
 
 pub mod ship_service;
+pub mod customer_service;
+pub mod price_agreement_service;
+pub mod pricing_service;
 pub mod order_service;
 pub mod order_item_service;
+pub mod order_charge_service;
+pub mod landed_cost_service;
+pub mod fulfilment_service;
+pub mod delivery_note_service;
+pub mod compliance_service;
+pub mod return_service;
+pub mod requisition_template_service;
+pub mod requisition_import_service;
+pub mod export_service;
+pub mod search_service;
+pub mod attachment_service;
+pub mod company_service;
+pub mod pdf_service;
+pub mod impa_service;
+pub mod category_service;
 pub mod supplier_service;
 pub mod supply_item_service;
+pub mod equivalence_service;
+pub mod price_history_service;
+pub mod price_list_service;
+pub mod unit_service;
 pub mod stock_service;
+pub mod bonded_service;
 pub mod port_service;
 pub mod ship_visit_service;
 pub mod calculation_service;
+pub mod numbering;
+pub mod invoice_service;
+pub mod credit_note_service;
+pub mod payment_service;
+pub mod supplier_invoice_service;
+pub mod supplier_payment_service;
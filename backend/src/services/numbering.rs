@@ -0,0 +1,45 @@
+//! Document Numbering - Sequential, year-scoped document numbers (INV-2026-0001)
+
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct LastRow {
+    last: Option<i64>,
+}
+
+/// Get the next free document number for `prefix` in the current year.
+///
+/// Numbers look like `{prefix}-{YYYY}-{NNNN}`; the sequence restarts every year.
+/// The highest sequence is compared as a number, so 10000 follows 9999.
+pub async fn next_document_number<C: ConnectionTrait>(
+    conn: &C,
+    table: &str,
+    column: &str,
+    prefix: &str,
+) -> Result<String> {
+    let year_prefix = format!("{}-{}-", prefix, chrono::Utc::now().format("%Y"));
+    let next = last_sequence(conn, table, column, &year_prefix).await? + 1;
+
+    Ok(format!("{}{:04}", year_prefix, next))
+}
+
+/// Highest number following `prefix` in `column` of `table` (0 when there is none)
+pub(crate) async fn last_sequence<C: ConnectionTrait>(
+    conn: &C,
+    table: &str,
+    column: &str,
+    prefix: &str,
+) -> Result<i64> {
+    let row: Option<LastRow> = LastRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            "SELECT MAX(CAST(substr({column}, length(?1) + 1) AS INTEGER)) as last FROM {table} WHERE {column} LIKE ?1 || '%'"
+        ),
+        vec![Value::String(Some(Box::new(prefix.to_string())))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.and_then(|r| r.last).unwrap_or(0))
+}
@@ -0,0 +1,267 @@
+//! Order Charge Service - Non-item charges on orders (launch boat, delivery, fees, discounts)
+
+use crate::models::{OrderCharge, CreateOrderChargeRequest, UpdateOrderChargeRequest, ChargeType, ChargeBilling};
+use crate::database;
+use crate::services::order_service;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct ChargeRow {
+    id: i32,
+    order_id: i32,
+    charge_type: String,
+    description: Option<String>,
+    billing: String,
+    markup_percent: Option<f64>,
+    cost_amount: f64,
+    revenue_amount: f64,
+    currency: String,
+    notes: Option<String>,
+    created_at: String,
+}
+
+impl From<ChargeRow> for OrderCharge {
+    fn from(row: ChargeRow) -> Self {
+        OrderCharge {
+            id: row.id,
+            order_id: row.order_id,
+            charge_type: charge_type_from_str(&row.charge_type),
+            description: row.description,
+            billing: billing_from_str(&row.billing),
+            markup_percent: row.markup_percent,
+            cost_amount: row.cost_amount,
+            revenue_amount: row.revenue_amount,
+            currency: row.currency,
+            notes: row.notes,
+            created_at: row.created_at,
+        }
+    }
+}
+
+fn charge_type_from_str(charge_type: &str) -> ChargeType {
+    match charge_type {
+        "LAUNCH_BOAT" => ChargeType::LaunchBoat,
+        "TRUCK_DELIVERY" => ChargeType::TruckDelivery,
+        "AFTER_HOURS" => ChargeType::AfterHours,
+        "PORT_FEE" => ChargeType::PortFee,
+        "AGENCY_COMMISSION" => ChargeType::AgencyCommission,
+        "DISCOUNT" => ChargeType::Discount,
+        _ => ChargeType::Other,
+    }
+}
+
+fn charge_type_to_str(charge_type: ChargeType) -> &'static str {
+    match charge_type {
+        ChargeType::LaunchBoat => "LAUNCH_BOAT",
+        ChargeType::TruckDelivery => "TRUCK_DELIVERY",
+        ChargeType::AfterHours => "AFTER_HOURS",
+        ChargeType::PortFee => "PORT_FEE",
+        ChargeType::AgencyCommission => "AGENCY_COMMISSION",
+        ChargeType::Discount => "DISCOUNT",
+        ChargeType::Other => "OTHER",
+    }
+}
+
+fn billing_from_str(billing: &str) -> ChargeBilling {
+    match billing {
+        "MARKUP" => ChargeBilling::Markup,
+        "ABSORBED" => ChargeBilling::Absorbed,
+        _ => ChargeBilling::PassThrough,
+    }
+}
+
+fn billing_to_str(billing: ChargeBilling) -> &'static str {
+    match billing {
+        ChargeBilling::PassThrough => "PASS_THROUGH",
+        ChargeBilling::Markup => "MARKUP",
+        ChargeBilling::Absorbed => "ABSORBED",
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    oc.id, oc.order_id, oc.charge_type, oc.description, oc.billing, oc.markup_percent,
+    oc.cost_amount, oc.revenue_amount, o.currency, oc.notes, oc.created_at
+"#;
+
+const FROM_JOIN: &str = "FROM order_charges oc JOIN orders o ON oc.order_id = o.id";
+
+/// Cost and revenue of a charge: (billing, markup_percent, cost_amount, revenue_amount)
+fn price_charge(
+    charge_type: ChargeType,
+    amount: f64,
+    billing: ChargeBilling,
+    markup_percent: Option<f64>,
+) -> Result<(ChargeBilling, Option<f64>, f64, f64)> {
+    if amount < 0.0 {
+        anyhow::bail!("Charge amount cannot be negative");
+    }
+    if charge_type == ChargeType::Discount {
+        return Ok((ChargeBilling::PassThrough, None, 0.0, -amount));
+    }
+    match billing {
+        ChargeBilling::PassThrough => Ok((billing, None, amount, amount)),
+        ChargeBilling::Absorbed => Ok((billing, None, amount, 0.0)),
+        ChargeBilling::Markup => {
+            let markup = markup_percent
+                .ok_or_else(|| anyhow::anyhow!("Markup percentage is required for marked-up charges"))?;
+            if markup < 0.0 {
+                anyhow::bail!("Markup percentage cannot be negative");
+            }
+            let revenue = (amount * (1.0 + markup / 100.0) * 100.0).round() / 100.0;
+            Ok((billing, Some(markup), amount, revenue))
+        }
+    }
+}
+
+/// Get all charges of an order
+pub async fn get_by_order(order_id: i32) -> Result<Vec<OrderCharge>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<ChargeRow> = ChargeRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE oc.order_id = ? ORDER BY (oc.charge_type = 'DISCOUNT'), oc.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(OrderCharge::from).collect())
+}
+
+/// Get a charge by ID
+pub async fn get_by_id(id: i32) -> Result<Option<OrderCharge>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<ChargeRow> = ChargeRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE oc.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    Ok(row.map(OrderCharge::from))
+}
+
+/// Add a charge to an order
+pub async fn create(req: CreateOrderChargeRequest) -> Result<OrderCharge> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    order_service::get_by_id(req.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    let (billing, markup_percent, cost_amount, revenue_amount) =
+        price_charge(req.charge_type, req.amount, req.billing, req.markup_percent)?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO order_charges (order_id, charge_type, description, billing, markup_percent, cost_amount, revenue_amount, notes)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(req.order_id)),
+            Value::String(Some(Box::new(charge_type_to_str(req.charge_type).to_string()))),
+            Value::String(req.description.map(Box::new)),
+            Value::String(Some(Box::new(billing_to_str(billing).to_string()))),
+            Value::Double(markup_percent),
+            Value::Double(Some(cost_amount)),
+            Value::Double(Some(revenue_amount)),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+
+    get_by_id(result.last_insert_id() as i32).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created order charge"))
+}
+
+/// Update a charge (amount, billing, description, notes)
+pub async fn update(id: i32, req: UpdateOrderChargeRequest) -> Result<OrderCharge> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let existing = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order charge not found"))?;
+
+    let current_amount = if existing.charge_type == ChargeType::Discount {
+        -existing.revenue_amount
+    } else {
+        existing.cost_amount
+    };
+    let (billing, markup_percent, cost_amount, revenue_amount) = price_charge(
+        existing.charge_type,
+        req.amount.unwrap_or(current_amount),
+        req.billing.unwrap_or(existing.billing),
+        req.markup_percent.or(existing.markup_percent),
+    )?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE order_charges SET description = ?, billing = ?, markup_percent = ?, cost_amount = ?, revenue_amount = ?,
+            notes = ?, updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::String(req.description.or(existing.description).map(Box::new)),
+            Value::String(Some(Box::new(billing_to_str(billing).to_string()))),
+            Value::Double(markup_percent),
+            Value::Double(Some(cost_amount)),
+            Value::Double(Some(revenue_amount)),
+            Value::String(req.notes.or(existing.notes).map(Box::new)),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve updated order charge"))
+}
+
+/// Remove a charge from an order
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM order_charges WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Charge totals of an order: (count, cost, revenue)
+pub(crate) async fn totals_for_order<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<(i32, f64, f64)> {
+    #[derive(Debug, FromQueryResult)]
+    struct TotalsRow {
+        charge_count: i32,
+        cost: f64,
+        revenue: f64,
+    }
+
+    let row: Option<TotalsRow> = TotalsRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT COUNT(*) as charge_count,
+               CAST(COALESCE(SUM(cost_amount), 0.0) AS REAL) as cost,
+               CAST(COALESCE(SUM(revenue_amount), 0.0) AS REAL) as revenue
+        FROM order_charges
+        WHERE order_id = ?
+        "#,
+        vec![Value::Int(Some(order_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| (r.charge_count, r.cost, r.revenue)).unwrap_or((0, 0.0, 0.0)))
+}
@@ -1,9 +1,10 @@
 //! Order Item Service - CRUD operations for order items
 
-use crate::models::{OrderItem, CreateOrderItemRequest, UpdateOrderItemRequest, DeliveryType};
+use crate::models::{Order, OrderItem, OrderStatus, OrderWithItems, CreateOrderItemRequest, UpdateOrderItemRequest, DeliveryType, FulfilmentStatus, PriceSource};
 use crate::database;
+use crate::services::{order_service, pricing_service, impa_service, fulfilment_service, delivery_note_service};
 use anyhow::Result;
-use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult};
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait};
 
 #[derive(Debug, FromQueryResult)]
 struct OrderItemRow {
@@ -21,6 +22,13 @@ struct OrderItemRow {
     warehouse_delivery_date: Option<String>,
     ship_delivery_date: Option<String>,
     notes: Option<String>,
+    delivered_quantity: f64,
+    rolled_over_quantity: f64,
+    closed_quantity: f64,
+    closed_at: Option<String>,
+    close_reason: Option<String>,
+    backorder_of_item_id: Option<i32>,
+    returned_quantity: f64,
 }
 
 impl From<OrderItemRow> for OrderItem {
@@ -29,6 +37,22 @@ impl From<OrderItemRow> for OrderItem {
             "DIRECT_TO_SHIP" => DeliveryType::DirectToShip,
             _ => DeliveryType::ViaWarehouse,
         };
+
+        let outstanding = (row.quantity - row.delivered_quantity).max(0.0);
+        let fulfilment = if row.closed_at.is_some() {
+            FulfilmentStatus::Closed
+        } else if outstanding <= QUANTITY_EPSILON {
+            if row.rolled_over_quantity > 0.0 {
+                FulfilmentStatus::RolledOver
+            } else {
+                FulfilmentStatus::Delivered
+            }
+        } else if row.delivered_quantity > 0.0 {
+            FulfilmentStatus::PartiallyDelivered
+        } else {
+            FulfilmentStatus::Pending
+        };
+        let back_ordered_quantity = if fulfilment.is_done() { 0.0 } else { outstanding };
         
         OrderItem {
             id: row.id,
@@ -45,11 +69,29 @@ impl From<OrderItemRow> for OrderItem {
             warehouse_delivery_date: row.warehouse_delivery_date,
             ship_delivery_date: row.ship_delivery_date,
             notes: row.notes,
+            delivered_quantity: row.delivered_quantity,
+            back_ordered_quantity,
+            rolled_over_quantity: row.rolled_over_quantity,
+            closed_quantity: row.closed_quantity,
+            closed_at: row.closed_at,
+            close_reason: row.close_reason,
+            fulfilment,
+            backorder_of_item_id: row.backorder_of_item_id,
+            returned_quantity: row.returned_quantity,
         }
     }
 }
 
-const SELECT_FIELDS: &str = "id, order_id, product_name, impa_code, description, quantity, unit, buying_price, selling_price, currency, delivery_type, warehouse_delivery_date, ship_delivery_date, notes";
+const SELECT_FIELDS: &str = r#"
+    id, order_id, product_name, impa_code, description, quantity, unit, buying_price, selling_price, currency,
+    delivery_type, warehouse_delivery_date, ship_delivery_date, notes,
+    CAST(COALESCE((SELECT SUM(d.quantity) FROM order_item_deliveries d WHERE d.order_item_id = order_items.id), 0.0) AS REAL) as delivered_quantity,
+    rolled_over_quantity, closed_quantity, closed_at, close_reason, backorder_of_item_id,
+    CAST(COALESCE((SELECT SUM(rl.quantity) FROM order_return_lines rl WHERE rl.order_item_id = order_items.id), 0.0) AS REAL) as returned_quantity
+"#;
+
+/// Quantities closer than this are considered equal
+pub(crate) const QUANTITY_EPSILON: f64 = 0.0001;
 
 /// Get all items for an order
 pub async fn get_by_order_id(order_id: i32) -> Result<Vec<OrderItem>> {
@@ -57,12 +99,17 @@ pub async fn get_by_order_id(order_id: i32) -> Result<Vec<OrderItem>> {
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
+    fetch_by_order(&conn, order_id).await
+}
+
+/// Items of an order, read on the given connection or transaction
+pub(crate) async fn fetch_by_order<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<Vec<OrderItem>> {
     let sql = format!("SELECT {} FROM order_items WHERE order_id = ? ORDER BY id", SELECT_FIELDS);
     
     let rows: Vec<OrderItemRow> = OrderItemRow::find_by_statement(
         Statement::from_sql_and_values(DatabaseBackend::Sqlite, &sql, [order_id.into()])
     )
-    .all(&conn)
+    .all(conn)
     .await?;
 
     Ok(rows.into_iter().map(OrderItem::from).collect())
@@ -85,12 +132,59 @@ pub async fn get_by_id(id: i32) -> Result<Option<OrderItem>> {
     Ok(row.map(OrderItem::from))
 }
 
-/// Create a new order item
-pub async fn create(item: CreateOrderItemRequest) -> Result<OrderItem> {
+/// Create a new order item.
+///
+/// Without a selling price, the price is proposed by the pricing engine
+/// (customer price agreement first, then the markup rules). The IMPA code is
+/// validated; a blank product name or unit is filled in from the IMPA catalog.
+/// A new line reopens a delivered order. Returns the order with the customer's
+/// credit check, which warns when the line pushes the customer over its limit.
+pub async fn create(mut item: CreateOrderItemRequest) -> Result<OrderWithItems> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
+    let order = order_service::get_by_id(item.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    if matches!(order.status, OrderStatus::Cancelled | OrderStatus::Invoiced) {
+        anyhow::bail!("Order {} is {}; lines cannot be added", order.order_number, order.status.display_name());
+    }
+
+    item.impa_code = impa_service::validate_optional(&conn, item.impa_code).await?;
+    if let Some(reference) = match item.impa_code.as_deref() {
+        Some(code) => impa_service::find(&conn, code).await?,
+        None => None,
+    } {
+        if item.product_name.trim().is_empty() {
+            item.product_name = reference.description;
+        }
+        if item.unit.trim().is_empty() {
+            item.unit = reference.unit.unwrap_or_default();
+        }
+    }
+
+    let txn = conn.begin().await?;
+    insert(&txn, &order, item).await?;
+    fulfilment_service::derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    order_service::get_with_items(order.id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))
+}
+
+/// Insert an order line on the given connection or transaction. Returns the item ID.
+///
+/// A selling price of 0 is replaced by the pricing engine's proposal when a rule applies.
+pub(crate) async fn insert<C: ConnectionTrait>(conn: &C, order: &Order, mut item: CreateOrderItemRequest) -> Result<i32> {
+    if item.selling_price <= 0.0 {
+        let proposal = pricing_service::propose_for_order(
+            conn, order, item.impa_code.as_deref(), item.buying_price, &item.currency
+        ).await?;
+        if proposal.source != PriceSource::NoRule {
+            item.selling_price = proposal.selling_price;
+        }
+    }
+
     let delivery_type_str = match item.delivery_type {
         DeliveryType::ViaWarehouse => "VIA_WAREHOUSE",
         DeliveryType::DirectToShip => "DIRECT_TO_SHIP",
@@ -101,60 +195,42 @@ pub async fn create(item: CreateOrderItemRequest) -> Result<OrderItem> {
         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
     "#;
 
-    conn.execute(Statement::from_sql_and_values(
+    let result = conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
         sql,
         [
             item.order_id.into(),
-            item.product_name.clone().into(),
-            item.impa_code.clone().into(),
-            item.description.clone().into(),
+            item.product_name.into(),
+            item.impa_code.into(),
+            item.description.into(),
             item.quantity.into(),
-            item.unit.clone().into(),
+            item.unit.into(),
             item.buying_price.into(),
             item.selling_price.into(),
-            item.currency.clone().into(),
+            item.currency.into(),
             delivery_type_str.into(),
-            item.warehouse_delivery_date.clone().into(),
-            item.ship_delivery_date.clone().into(),
-            item.notes.clone().into(),
+            item.warehouse_delivery_date.into(),
+            item.ship_delivery_date.into(),
+            item.notes.into(),
         ],
     ))
     .await?;
 
-    // Get the last inserted ID
-    let id_row: Option<IdRow> = IdRow::find_by_statement(
-        Statement::from_string(DatabaseBackend::Sqlite, "SELECT last_insert_rowid() as id".to_string())
-    )
-    .one(&conn)
-    .await?;
-
-    let id = id_row.map(|r| r.id).unwrap_or(0);
-
-    Ok(OrderItem {
-        id,
-        order_id: item.order_id,
-        product_name: item.product_name,
-        impa_code: item.impa_code,
-        description: item.description,
-        quantity: item.quantity,
-        unit: item.unit,
-        buying_price: item.buying_price,
-        selling_price: item.selling_price,
-        currency: item.currency,
-        delivery_type: item.delivery_type,
-        warehouse_delivery_date: item.warehouse_delivery_date,
-        ship_delivery_date: item.ship_delivery_date,
-        notes: item.notes,
-    })
+    Ok(result.last_insert_id() as i32)
 }
 
-#[derive(Debug, FromQueryResult)]
-struct IdRow {
-    id: i32,
+/// Refuse changes to a line of an invoiced order or one signed for on a delivery note
+async fn check_changeable<C: ConnectionTrait>(conn: &C, order: &Order, item: &OrderItem) -> Result<()> {
+    if matches!(order.status, OrderStatus::Cancelled | OrderStatus::Invoiced) {
+        anyhow::bail!("Order {} is {}; its lines cannot be changed", order.order_number, order.status.display_name());
+    }
+    if delivery_note_service::has_signed_delivery(conn, item.id).await? {
+        anyhow::bail!("{} is signed for on a delivery note and cannot be changed", item.product_name);
+    }
+    Ok(())
 }
 
-/// Update an existing order item
+/// Update an existing order item (the order status is derived again)
 pub async fn update(id: i32, item: UpdateOrderItemRequest) -> Result<OrderItem> {
     let conn = database::get_connection()
         .await
@@ -163,6 +239,26 @@ pub async fn update(id: i32, item: UpdateOrderItemRequest) -> Result<OrderItem>
     // Get existing item first
     let existing = get_by_id(id).await?
         .ok_or_else(|| anyhow::anyhow!("Order item not found"))?;
+    let order = order_service::get_by_id(existing.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    check_changeable(&conn, &order, &existing).await?;
+
+    if let Some(quantity) = item.quantity {
+        if quantity + QUANTITY_EPSILON < existing.delivered_quantity {
+            anyhow::bail!(
+                "Quantity cannot be less than the {} {} already delivered",
+                existing.delivered_quantity, existing.unit
+            );
+        }
+        if existing.fulfilment == FulfilmentStatus::Closed && quantity > existing.quantity + QUANTITY_EPSILON {
+            anyhow::bail!("{} was closed short; add a new line for the extra quantity", existing.product_name);
+        }
+    }
+
+    let impa_code = match item.impa_code.clone() {
+        Some(code) => impa_service::validate_optional(&conn, Some(code)).await?,
+        None => existing.impa_code.clone(),
+    };
 
     let delivery_type = item.delivery_type.unwrap_or(existing.delivery_type);
     let delivery_type_str = match delivery_type {
@@ -179,12 +275,14 @@ pub async fn update(id: i32, item: UpdateOrderItemRequest) -> Result<OrderItem>
         WHERE id = ?
     "#;
 
-    conn.execute(Statement::from_sql_and_values(
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
         sql,
         [
             item.product_name.clone().unwrap_or(existing.product_name.clone()).into(),
-            item.impa_code.clone().or(existing.impa_code.clone()).into(),
+            impa_code.into(),
             item.description.clone().or(existing.description.clone()).into(),
             item.quantity.unwrap_or(existing.quantity).into(),
             item.unit.clone().unwrap_or(existing.unit.clone()).into(),
@@ -199,36 +297,45 @@ pub async fn update(id: i32, item: UpdateOrderItemRequest) -> Result<OrderItem>
     ))
     .await?;
 
-    Ok(OrderItem {
-        id,
-        order_id: existing.order_id,
-        product_name: item.product_name.unwrap_or(existing.product_name),
-        impa_code: item.impa_code.or(existing.impa_code),
-        description: item.description.or(existing.description),
-        quantity: item.quantity.unwrap_or(existing.quantity),
-        unit: item.unit.unwrap_or(existing.unit),
-        buying_price: item.buying_price.unwrap_or(existing.buying_price),
-        selling_price: item.selling_price.unwrap_or(existing.selling_price),
-        currency: existing.currency,
-        delivery_type,
-        warehouse_delivery_date: item.warehouse_delivery_date.or(existing.warehouse_delivery_date),
-        ship_delivery_date: item.ship_delivery_date.or(existing.ship_delivery_date),
-        notes: item.notes.or(existing.notes),
-    })
+    // A raised quantity reopens a delivered order
+    fulfilment_service::derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order item not found after update"))
 }
 
-/// Delete an order item
+/// Delete an order item with its unsigned deliveries (the order status is derived again)
 pub async fn delete(id: i32) -> Result<bool> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    let result = conn.execute(Statement::from_sql_and_values(
+    let Some(existing) = get_by_id(id).await? else {
+        return Ok(false);
+    };
+    let order = order_service::get_by_id(existing.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    check_changeable(&conn, &order, &existing).await?;
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM order_item_deliveries WHERE order_item_id = ?",
+        [id.into()],
+    ))
+    .await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
         "DELETE FROM order_items WHERE id = ?",
         [id.into()],
     ))
     .await?;
 
+    fulfilment_service::derive_status(&txn, &order).await?;
+    txn.commit().await?;
+
     Ok(result.rows_affected() > 0)
 }
@@ -1,10 +1,17 @@
 //! Order Service - CRUD operations and state machine for orders
 
-use crate::models::{Order, OrderWithItems, OrderTotals, OrderStatus, CreateOrderRequest, UpdateOrderRequest};
+use crate::models::{
+    Customer, Order, OrderWithItems, OrderTotals, OrderStatus, CreateOrderRequest, UpdateOrderRequest,
+    CreateOrderItemRequest, PriceSource, VisitStatus, OrderItem, OrderLink, OrderLinkType,
+};
 use crate::database;
-use crate::services::order_item_service;
+use crate::services::{
+    customer_service, order_item_service, order_charge_service, landed_cost_service, fulfilment_service,
+    return_service, ship_service, ship_visit_service, supply_item_service, pricing_service, compliance_service, numbering,
+};
+use crate::services::order_item_service::QUANTITY_EPSILON;
 use anyhow::Result;
-use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult};
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait};
 
 #[derive(Debug, FromQueryResult)]
 struct OrderRow {
@@ -14,10 +21,15 @@ struct OrderRow {
     ship_name: Option<String>,
     ship_visit_id: Option<i32>,
     ship_visit_info: Option<String>,
+    customer_id: Option<i32>,
+    customer_name: Option<String>,
+    payment_terms_days: Option<i32>,
     status: String,
     delivery_port: Option<String>,
     currency: String,
     notes: Option<String>,
+    parent_order_id: Option<i32>,
+    delivery_note_waiver: Option<String>,
     created_at: String,
     updated_at: String,
 }
@@ -44,26 +56,28 @@ impl From<OrderRow> for Order {
             ship_name: row.ship_name,
             ship_visit_id: row.ship_visit_id,
             ship_visit_info: row.ship_visit_info,
+            customer_id: row.customer_id,
+            customer_name: row.customer_name,
+            payment_terms_days: row.payment_terms_days,
             status,
             delivery_port: row.delivery_port,
             notes: row.notes,
             currency: row.currency,
+            parent_order_id: row.parent_order_id,
+            delivery_note_waiver: row.delivery_note_waiver,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
     }
 }
 
-#[derive(Debug, FromQueryResult)]
-struct IdRow {
-    id: i32,
-}
-
 const SELECT_FIELDS: &str = r#"
     o.id, o.order_number, o.ship_id, s.name as ship_name, 
     o.ship_visit_id, 
     CASE WHEN sv.id IS NOT NULL THEN p.name || ' (' || sv.eta || ' - ' || sv.etd || ')' ELSE NULL END as ship_visit_info,
-    o.status, o.delivery_port, o.currency, o.notes, o.created_at, o.updated_at
+    o.customer_id, c.legal_name as customer_name, o.payment_terms_days,
+    o.status, o.delivery_port, o.currency, o.notes, o.parent_order_id, o.delivery_note_waiver,
+    o.created_at, o.updated_at
 "#;
 
 pub async fn get_all(status_filter: Option<OrderStatus>) -> Result<Vec<Order>> {
@@ -76,6 +90,7 @@ pub async fn get_all(status_filter: Option<OrderStatus>) -> Result<Vec<Order>> {
         LEFT JOIN ships s ON o.ship_id = s.id 
         LEFT JOIN ship_visits sv ON o.ship_visit_id = sv.id
         LEFT JOIN ports p ON sv.port_id = p.id
+        LEFT JOIN customers c ON o.customer_id = c.id
     "#;
 
     let sql = if let Some(status) = status_filter {
@@ -115,12 +130,18 @@ pub async fn get_by_id(id: i32) -> Result<Option<Order>> {
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
+    fetch_by_id(&conn, id).await
+}
+
+/// Order by ID, read on the given connection or transaction
+pub(crate) async fn fetch_by_id<C: ConnectionTrait>(conn: &C, id: i32) -> Result<Option<Order>> {
     let sql = format!(
         r#"SELECT {} 
            FROM orders o 
            LEFT JOIN ships s ON o.ship_id = s.id 
            LEFT JOIN ship_visits sv ON o.ship_visit_id = sv.id
            LEFT JOIN ports p ON sv.port_id = p.id
+           LEFT JOIN customers c ON o.customer_id = c.id
            WHERE o.id = ?"#,
         SELECT_FIELDS
     );
@@ -128,7 +149,7 @@ pub async fn get_by_id(id: i32) -> Result<Option<Order>> {
     let row: Option<OrderRow> = OrderRow::find_by_statement(
         Statement::from_sql_and_values(DatabaseBackend::Sqlite, &sql, [id.into()])
     )
-    .one(&conn)
+    .one(conn)
     .await?;
 
     Ok(row.map(Order::from))
@@ -139,10 +160,16 @@ pub async fn get_with_items(id: i32) -> Result<Option<OrderWithItems>> {
     
     if let Some(order) = order {
         let items = order_item_service::get_by_order_id(id).await?;
+        let conn = database::get_connection()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+        let (charge_count, charges_cost, charges_revenue) = order_charge_service::totals_for_order(&conn, id).await?;
+        let landed_cost = landed_cost_service::allocated_to_order(&conn, id).await?;
+        let (returns_revenue, returns_cost) = return_service::totals_for_order(&conn, id).await?;
         
-        // Calculate totals
-        let mut total_cost = 0.0;
-        let mut total_revenue = 0.0;
+        // Calculate totals (item lines, order charges and landed costs, net of returns)
+        let mut total_cost = charges_cost + landed_cost - returns_cost;
+        let mut total_revenue = charges_revenue - returns_revenue;
         for item in &items {
             total_cost += item.buying_price * item.quantity;
             total_revenue += item.selling_price * item.quantity;
@@ -156,56 +183,473 @@ pub async fn get_with_items(id: i32) -> Result<Option<OrderWithItems>> {
         
         let totals = OrderTotals {
             item_count: items.len() as i32,
+            charge_count,
             total_cost,
             total_revenue,
+            charges_cost,
+            charges_revenue,
+            landed_cost,
+            returns_revenue,
+            returns_cost,
             gross_profit,
             margin_percent,
             currency: order.currency.clone(),
         };
         
-        Ok(Some(OrderWithItems { order, items, totals }))
+        let credit_check = customer_service::check_order_credit(id).await?;
+
+        Ok(Some(OrderWithItems { order, items, totals, credit_check }))
     } else {
         Ok(None)
     }
 }
 
-pub async fn create(order: CreateOrderRequest) -> Result<Order> {
+/// Customer account owning the ship, if the ship is linked to one
+pub(crate) async fn customer_for_ship(ship_id: i32) -> Result<Option<Customer>> {
+    let ship = ship_service::get_by_id(ship_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
+
+    match ship.owner_id {
+        Some(owner_id) => customer_service::get_by_id(owner_id).await,
+        None => Ok(None),
+    }
+}
+
+/// Create an order. The result carries the customer's credit check, with a
+/// warning when the customer is already over its credit limit.
+pub async fn create(order: CreateOrderRequest) -> Result<OrderWithItems> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    let order_number = format!("ORD-{}", chrono::Utc::now().format("%Y%m%d%H%M%S"));
+    let customer = customer_for_ship(order.ship_id).await?;
+    let id = insert(&conn, &order, customer.as_ref()).await?;
+
+    // Return full order with ship and visit info
+    get_with_items(id).await?.ok_or_else(|| anyhow::anyhow!("Failed to fetch created order"))
+}
+
+/// Order number for a new order (ORD-YYYYmmddHHMMSS, suffixed when several orders are created in the same second)
+async fn next_order_number<C: ConnectionTrait>(conn: &C) -> Result<String> {
+    let base = format!("ORD-{}", chrono::Utc::now().format("%Y%m%d%H%M%S"));
+
+    #[derive(Debug, FromQueryResult)]
+    struct CountRow {
+        count: i32,
+    }
+
+    let taken = CountRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT COUNT(*) as count FROM orders WHERE order_number = ? OR order_number LIKE ?",
+        [base.clone().into(), format!("{}-%", base).into()],
+    ))
+    .one(conn)
+    .await?
+    .map(|r| r.count)
+    .unwrap_or(0);
+
+    Ok(match taken {
+        0 => base,
+        n => format!("{}-{}", base, n + 1),
+    })
+}
+
+/// Insert a new order (status New) on the given connection or transaction.
+/// `customer` is the ship owner's account (see `customer_for_ship`). Returns the order ID.
+pub(crate) async fn insert<C: ConnectionTrait>(conn: &C, order: &CreateOrderRequest, customer: Option<&Customer>) -> Result<i32> {
+    let order_number = next_order_number(conn).await?;
+
+    // Orders inherit the terms of the ship owner's customer account
+    let currency = if order.currency.trim().is_empty() {
+        customer
+            .map(|c| c.default_currency.clone())
+            .unwrap_or_else(|| "USD".to_string())
+    } else {
+        order.currency.clone()
+    };
 
     let sql = r#"
-        INSERT INTO orders (order_number, ship_id, ship_visit_id, status, delivery_port, currency, notes)
-        VALUES (?, ?, ?, 'NEW', ?, ?, ?)
+        INSERT INTO orders (order_number, ship_id, ship_visit_id, customer_id, payment_terms_days, status, delivery_port, currency, notes)
+        VALUES (?, ?, ?, ?, ?, 'NEW', ?, ?, ?)
     "#;
 
-    conn.execute(Statement::from_sql_and_values(
+    let result = conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
         sql,
         [
-            order_number.clone().into(),
+            order_number.into(),
             order.ship_id.into(),
             order.ship_visit_id.into(),
+            customer.map(|c| c.id).into(),
+            customer.map(|c| c.payment_terms_days).into(),
             order.delivery_port.clone().into(),
-            order.currency.clone().into(),
+            currency.into(),
             order.notes.clone().into(),
         ],
     ))
     .await?;
 
-    // Get the last inserted ID and return full order with joins
-    let id_row: Option<IdRow> = IdRow::find_by_statement(
-        Statement::from_string(DatabaseBackend::Sqlite, "SELECT last_insert_rowid() as id".to_string())
-    )
-    .one(&conn)
+    Ok(result.last_insert_id() as i32)
+}
+
+/// Copy an order's lines into a new order for another ship visit.
+///
+/// Lines keep their originally ordered quantities (including parts rolled over
+/// or closed). With `refresh_prices`, buying prices come from the current
+/// supply item prices and selling prices from the pricing engine; lines without
+/// a current price or pricing rule keep their old prices.
+pub async fn clone_order(order_id: i32, target_ship_visit_id: i32, refresh_prices: bool) -> Result<OrderWithItems> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let source = get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    let visit = ship_visit_service::get_by_id(target_ship_visit_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Ship visit not found"))?;
+    if matches!(visit.status, VisitStatus::Departed | VisitStatus::Cancelled) {
+        anyhow::bail!("Ship visit {} is {}", visit.id, visit.status.display_name());
+    }
+    let items = order_item_service::get_by_order_id(order_id).await?;
+    let customer = customer_for_ship(visit.ship_id).await?;
+
+    let txn = conn.begin().await?;
+
+    let id = insert(&txn, &CreateOrderRequest {
+        ship_id: visit.ship_id,
+        ship_visit_id: Some(visit.id),
+        delivery_port: visit.port_name.clone(),
+        notes: Some(format!("Copy of {}", source.order_number)),
+        currency: source.currency.clone(),
+    }, customer.as_ref()).await?;
+    let order = fetch_by_id(&txn, id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to fetch created order"))?;
+
+    for item in items {
+        let mut buying_price = item.buying_price;
+        let mut selling_price = item.selling_price;
+        if refresh_prices {
+            if let Some(impa_code) = item.impa_code.as_deref() {
+                if let Some(price) = supply_item_service::current_buying_price(&txn, impa_code, &order.currency).await? {
+                    buying_price = price;
+                }
+            }
+            let proposal = pricing_service::propose_for_order(
+                &txn, &order, item.impa_code.as_deref(), buying_price, &order.currency
+            ).await?;
+            if proposal.source != PriceSource::NoRule {
+                selling_price = proposal.selling_price;
+            }
+        }
+
+        order_item_service::insert(&txn, &order, CreateOrderItemRequest {
+            order_id: order.id,
+            product_name: item.product_name,
+            impa_code: item.impa_code,
+            description: item.description,
+            quantity: item.quantity + item.rolled_over_quantity + item.closed_quantity,
+            unit: item.unit,
+            buying_price,
+            selling_price,
+            currency: order.currency.clone(),
+            delivery_type: item.delivery_type,
+            warehouse_delivery_date: None,
+            ship_delivery_date: None,
+            notes: item.notes,
+        }).await?;
+    }
+
+    txn.commit().await?;
+
+    tracing::info!("Order {} cloned to order {} for visit {}", source.order_number, order.order_number, visit.id);
+
+    get_with_items(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to fetch created order"))
+}
+
+/// Orders can only be split or merged before goods are prepared
+fn check_restructurable(order: &Order) -> Result<()> {
+    if !matches!(order.status, OrderStatus::New | OrderStatus::Quoted | OrderStatus::Agreed | OrderStatus::WaitingGoods) {
+        anyhow::bail!("Order {} is {}; only orders not yet prepared can be split or merged", order.order_number, order.status.display_name());
+    }
+    Ok(())
+}
+
+/// Lines with deliveries, roll-overs or closures stay on their order
+fn check_movable(order: &Order, item: &OrderItem) -> Result<()> {
+    if item.delivered_quantity > QUANTITY_EPSILON
+        || item.rolled_over_quantity > QUANTITY_EPSILON
+        || item.closed_quantity > QUANTITY_EPSILON
+    {
+        anyhow::bail!("Item {} of order {} has fulfilment history and cannot be moved", item.product_name, order.order_number);
+    }
+    Ok(())
+}
+
+fn status_rank(status: OrderStatus) -> i32 {
+    match status {
+        OrderStatus::New => 0,
+        OrderStatus::Quoted => 1,
+        OrderStatus::Agreed => 2,
+        OrderStatus::WaitingGoods => 3,
+        OrderStatus::Prepared => 4,
+        OrderStatus::OnWay => 5,
+        OrderStatus::Delivered => 6,
+        OrderStatus::Invoiced => 7,
+        OrderStatus::Cancelled => 8,
+    }
+}
+
+async fn insert_link<C: ConnectionTrait>(
+    conn: &C,
+    link_type: OrderLinkType,
+    source_order_id: i32,
+    target_order_id: i32,
+    item_ids: &[i32],
+) -> Result<()> {
+    let link_type_str = match link_type {
+        OrderLinkType::Split => "SPLIT",
+        OrderLinkType::Merge => "MERGE",
+    };
+    let item_ids = item_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT INTO order_links (link_type, source_order_id, target_order_id, order_item_ids) VALUES (?, ?, ?, ?)",
+        [link_type_str.into(), source_order_id.into(), target_order_id.into(), item_ids.into()],
+    ))
     .await?;
+    Ok(())
+}
 
-    let id = id_row.map(|r| r.id).unwrap_or(0);
-    
-    // Return full order with ship and visit info
-    get_by_id(id).await?.ok_or_else(|| anyhow::anyhow!("Failed to fetch created order"))
+async fn move_items<C: ConnectionTrait>(conn: &C, item_ids: &[i32], target_order_id: i32) -> Result<()> {
+    for item_id in item_ids {
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "UPDATE order_items SET order_id = ?, updated_at = datetime('now') WHERE id = ?",
+            [target_order_id.into(), (*item_id).into()],
+        ))
+        .await?;
+    }
+    Ok(())
+}
+
+/// Move some lines of an order into a new order, e.g. to deliver direct-to-ship
+/// lines on another day than the warehouse lines.
+///
+/// The lines keep their IDs (supplier invoice lines and landed cost allocations
+/// follow them). The new order is numbered after the original ({number}-S{n}),
+/// keeps its ship, visit, customer terms, currency and status, and both orders
+/// get a Split link for audit.
+pub async fn split_order(order_id: i32, item_ids: Vec<i32>) -> Result<OrderWithItems> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    check_restructurable(&order)?;
+
+    let mut item_ids = item_ids;
+    item_ids.sort_unstable();
+    item_ids.dedup();
+    if item_ids.is_empty() {
+        anyhow::bail!("Select the items to split off");
+    }
+
+    let items = order_item_service::get_by_order_id(order_id).await?;
+    for item_id in &item_ids {
+        let item = items.iter()
+            .find(|i| i.id == *item_id)
+            .ok_or_else(|| anyhow::anyhow!("Item {} is not on order {}", item_id, order.order_number))?;
+        check_movable(&order, item)?;
+    }
+    if item_ids.len() == items.len() {
+        anyhow::bail!("At least one item must stay on order {}", order.order_number);
+    }
+
+    let txn = conn.begin().await?;
+
+    // Numbered after the highest existing split, so numbers are never reused
+    let split_prefix = format!("{}-S", order.order_number);
+    let last_split = numbering::last_sequence(&txn, "orders", "order_number", &split_prefix).await?;
+    let order_number = format!("{}{}", split_prefix, last_split + 1);
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO orders (order_number, ship_id, ship_visit_id, customer_id, payment_terms_days, status, delivery_port, currency, notes)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        [
+            order_number.into(),
+            order.ship_id.into(),
+            order.ship_visit_id.into(),
+            order.customer_id.into(),
+            order.payment_terms_days.into(),
+            status_to_str(order.status).into(),
+            order.delivery_port.clone().into(),
+            order.currency.clone().into(),
+            format!("Split from {}", order.order_number).into(),
+        ],
+    ))
+    .await?;
+    let new_id = result.last_insert_id() as i32;
+
+    move_items(&txn, &item_ids, new_id).await?;
+    insert_link(&txn, OrderLinkType::Split, order.id, new_id, &item_ids).await?;
+
+    txn.commit().await?;
+
+    tracing::info!("Order {} split: {} items moved to order {}", order.order_number, item_ids.len(), new_id);
+
+    get_with_items(new_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to fetch split order"))
+}
+
+/// Merge orders for the same ship visit into the first (lowest ID) of them.
+///
+/// Lines, order charges, landed costs and supplier invoices move to the
+/// surviving order, which keeps its number and takes the least advanced status
+/// of the merged orders. The other orders are cancelled (their numbers are not
+/// reused) and each gets a Merge link to the surviving order for audit.
+pub async fn merge_orders(order_ids: Vec<i32>) -> Result<OrderWithItems> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let mut order_ids = order_ids;
+    order_ids.sort_unstable();
+    order_ids.dedup();
+    if order_ids.len() < 2 {
+        anyhow::bail!("Select at least two orders to merge");
+    }
+
+    let mut orders = Vec::with_capacity(order_ids.len());
+    for id in &order_ids {
+        let order = get_by_id(*id).await?
+            .ok_or_else(|| anyhow::anyhow!("Order {} not found", id))?;
+        check_restructurable(&order)?;
+        orders.push(order);
+    }
+
+    let target = &orders[0];
+    for order in &orders[1..] {
+        if order.ship_id != target.ship_id || order.ship_visit_id != target.ship_visit_id {
+            anyhow::bail!("Order {} is not for the same ship visit as {}", order.order_number, target.order_number);
+        }
+        if order.customer_id != target.customer_id {
+            anyhow::bail!("Order {} is billed to another customer than {}", order.order_number, target.order_number);
+        }
+        if order.currency != target.currency {
+            anyhow::bail!("Order {} is in {}, {} is in {}", order.order_number, order.currency, target.order_number, target.currency);
+        }
+    }
+
+    let mut moved = Vec::with_capacity(orders.len() - 1);
+    for order in &orders[1..] {
+        let items = order_item_service::get_by_order_id(order.id).await?;
+        for item in &items {
+            check_movable(order, item)?;
+        }
+        moved.push(items.iter().map(|i| i.id).collect::<Vec<_>>());
+    }
+
+    let status = orders.iter()
+        .map(|o| o.status)
+        .min_by_key(|s| status_rank(*s))
+        .unwrap_or(target.status);
+
+    let txn = conn.begin().await?;
+
+    for (order, item_ids) in orders[1..].iter().zip(moved.iter()) {
+        move_items(&txn, item_ids, target.id).await?;
+
+        for table in ["order_charges", "landed_costs", "supplier_invoices"] {
+            txn.execute(Statement::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                format!("UPDATE {} SET order_id = ? WHERE order_id = ?", table),
+                [target.id.into(), order.id.into()],
+            ))
+            .await?;
+        }
+
+        let note = match &order.notes {
+            Some(notes) if !notes.is_empty() => format!("{}\nMerged into {}", notes, target.order_number),
+            _ => format!("Merged into {}", target.order_number),
+        };
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "UPDATE orders SET status = 'CANCELLED', notes = ?, updated_at = datetime('now') WHERE id = ?",
+            [note.into(), order.id.into()],
+        ))
+        .await?;
+
+        insert_link(&txn, OrderLinkType::Merge, order.id, target.id, item_ids).await?;
+    }
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE orders SET status = ?, updated_at = datetime('now') WHERE id = ?",
+        [status_to_str(status).into(), target.id.into()],
+    ))
+    .await?;
+
+    txn.commit().await?;
+
+    tracing::info!("Orders {:?} merged into order {}", &order_ids[1..], target.order_number);
+
+    get_with_items(target.id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to fetch merged order"))
+}
+
+#[derive(Debug, FromQueryResult)]
+struct OrderLinkRow {
+    id: i32,
+    link_type: String,
+    source_order_id: i32,
+    source_order_number: Option<String>,
+    target_order_id: i32,
+    target_order_number: Option<String>,
+    order_item_ids: String,
+    created_at: String,
+}
+
+/// Splits and merges an order took part in, as source or target
+pub async fn get_links(order_id: i32) -> Result<Vec<OrderLink>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<OrderLinkRow> = OrderLinkRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT l.id, l.link_type, l.source_order_id, so.order_number as source_order_number,
+               l.target_order_id, tgt.order_number as target_order_number, l.order_item_ids, l.created_at
+        FROM order_links l
+        LEFT JOIN orders so ON l.source_order_id = so.id
+        LEFT JOIN orders tgt ON l.target_order_id = tgt.id
+        WHERE l.source_order_id = ? OR l.target_order_id = ?
+        ORDER BY l.id
+        "#,
+        [order_id.into(), order_id.into()],
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(|row| OrderLink {
+        id: row.id,
+        link_type: if row.link_type == "MERGE" { OrderLinkType::Merge } else { OrderLinkType::Split },
+        source_order_id: row.source_order_id,
+        source_order_number: row.source_order_number,
+        target_order_id: row.target_order_id,
+        target_order_number: row.target_order_number,
+        order_item_ids: row.order_item_ids
+            .split(',')
+            .filter_map(|id| id.trim().parse().ok())
+            .collect(),
+        created_at: row.created_at,
+    }).collect())
 }
 
 /// Update order (ship_visit_id, delivery_port, notes, currency)
@@ -223,8 +667,13 @@ pub async fn update(id: i32, request: UpdateOrderRequest) -> Result<Order> {
     let mut values: Vec<sea_orm::Value> = Vec::new();
 
     if let Some(ship_id) = request.ship_id {
+        let customer = customer_for_ship(ship_id).await?;
         updates.push("ship_id = ?");
         values.push(ship_id.into());
+        updates.push("customer_id = ?");
+        values.push(customer.as_ref().map(|c| c.id).into());
+        updates.push("payment_terms_days = ?");
+        values.push(customer.as_ref().map(|c| c.payment_terms_days).into());
     }
     if let Some(ship_visit_id) = request.ship_visit_id {
         updates.push("ship_visit_id = ?");
@@ -274,6 +723,7 @@ pub async fn get_by_ship_visit(ship_visit_id: i32) -> Result<Vec<Order>> {
            LEFT JOIN ships s ON o.ship_id = s.id 
            LEFT JOIN ship_visits sv ON o.ship_visit_id = sv.id
            LEFT JOIN ports p ON sv.port_id = p.id
+           LEFT JOIN customers c ON o.customer_id = c.id
            WHERE o.ship_visit_id = ?
            ORDER BY o.id DESC"#,
         SELECT_FIELDS
@@ -293,6 +743,20 @@ struct ShipNameRow {
     name: String,
 }
 
+pub(crate) fn status_to_str(status: OrderStatus) -> &'static str {
+    match status {
+        OrderStatus::New => "NEW",
+        OrderStatus::Quoted => "QUOTED",
+        OrderStatus::Agreed => "AGREED",
+        OrderStatus::WaitingGoods => "WAITING_GOODS",
+        OrderStatus::Prepared => "PREPARED",
+        OrderStatus::OnWay => "ON_WAY",
+        OrderStatus::Delivered => "DELIVERED",
+        OrderStatus::Invoiced => "INVOICED",
+        OrderStatus::Cancelled => "CANCELLED",
+    }
+}
+
 /// Update order status with state machine validation
 pub async fn update_status(id: i32, new_status: OrderStatus) -> Result<Order> {
     let conn = database::get_connection()
@@ -311,22 +775,22 @@ pub async fn update_status(id: i32, new_status: OrderStatus) -> Result<Order> {
         );
     }
 
-    let status_str = match new_status {
-        OrderStatus::New => "NEW",
-        OrderStatus::Quoted => "QUOTED",
-        OrderStatus::Agreed => "AGREED",
-        OrderStatus::WaitingGoods => "WAITING_GOODS",
-        OrderStatus::Prepared => "PREPARED",
-        OrderStatus::OnWay => "ON_WAY",
-        OrderStatus::Delivered => "DELIVERED",
-        OrderStatus::Invoiced => "INVOICED",
-        OrderStatus::Cancelled => "CANCELLED",
-    };
+    // Delivered is derived from the lines: a one-piece delivery is recorded for
+    // orders without deliveries, otherwise every line must be fulfilled or closed.
+    // Either way the deliveries must be on a signed delivery note, or waived
+    if new_status == OrderStatus::Delivered {
+        fulfilment_service::complete_delivery(&conn, &current_order).await?;
+    }
+
+    // Dangerous goods need their SDS and prescription medicines a pharmacist's sign-off
+    if matches!(new_status, OrderStatus::Prepared | OrderStatus::OnWay) {
+        compliance_service::ensure_compliant(&conn, &current_order, new_status).await?;
+    }
 
     conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
         "UPDATE orders SET status = ?, updated_at = datetime('now') WHERE id = ?",
-        [status_str.into(), id.into()],
+        [status_to_str(new_status).into(), id.into()],
     ))
     .await?;
 
@@ -336,27 +800,76 @@ pub async fn update_status(id: i32, new_status: OrderStatus) -> Result<Order> {
     get_by_id(id).await?.ok_or_else(|| anyhow::anyhow!("Order not found after update"))
 }
 
-/// Delete an order (with cascade - deletes order items first)
+/// Delete an order with its lines, deliveries, returns and costs.
+///
+/// Orders that were invoiced, billed by a supplier, split or merged cannot be
+/// deleted; cancel them instead.
 pub async fn delete_order(id: i32) -> Result<bool> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    // CASCADE DELETE: Delete order_items first (though they have ON DELETE CASCADE, let's be explicit)
-    conn.execute(Statement::from_sql_and_values(
+    let Some(order) = get_by_id(id).await? else {
+        return Ok(false);
+    };
+    if order.status == OrderStatus::Invoiced {
+        anyhow::bail!("Order {} is invoiced and cannot be deleted", order.order_number);
+    }
+
+    #[derive(Debug, FromQueryResult)]
+    struct DocumentCountRow {
+        invoices: i32,
+        supplier_invoices: i32,
+        links: i32,
+    }
+
+    let documents = DocumentCountRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "DELETE FROM order_items WHERE order_id = ?",
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM invoices WHERE order_id = ?1) as invoices,
+            (SELECT COUNT(*) FROM supplier_invoices WHERE order_id = ?1) as supplier_invoices,
+            (SELECT COUNT(*) FROM order_links WHERE source_order_id = ?1 OR target_order_id = ?1) as links
+        "#,
         [id.into()],
     ))
+    .one(&conn)
     .await?;
+    if let Some(documents) = documents {
+        if documents.invoices > 0 {
+            anyhow::bail!("Order {} has invoices and cannot be deleted; cancel it instead", order.order_number);
+        }
+        if documents.supplier_invoices > 0 {
+            anyhow::bail!("Order {} has supplier invoices and cannot be deleted; cancel it instead", order.order_number);
+        }
+        // Split and merge links are the audit trail between orders
+        if documents.links > 0 {
+            anyhow::bail!("Order {} was split or merged and cannot be deleted; cancel it instead", order.order_number);
+        }
+    }
 
-    // Delete the order itself
-    let result = conn.execute(Statement::from_sql_and_values(
+    let txn = conn.begin().await?;
+
+    for sql in [
+        "DELETE FROM order_return_lines WHERE return_id IN (SELECT id FROM order_returns WHERE order_id = ?)",
+        "DELETE FROM order_returns WHERE order_id = ?",
+        "DELETE FROM order_item_deliveries WHERE order_item_id IN (SELECT id FROM order_items WHERE order_id = ?)",
+        "DELETE FROM delivery_notes WHERE order_id = ?",
+        "DELETE FROM order_items WHERE order_id = ?",
+        "DELETE FROM order_charges WHERE order_id = ?",
+        "DELETE FROM landed_costs WHERE order_id = ?",
+    ] {
+        txn.execute(Statement::from_sql_and_values(DatabaseBackend::Sqlite, sql, [id.into()])).await?;
+    }
+
+    let result = txn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
         "DELETE FROM orders WHERE id = ?",
         [id.into()],
     ))
     .await?;
 
+    txn.commit().await?;
+
     Ok(result.rows_affected() > 0)
 }
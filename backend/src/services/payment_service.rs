@@ -0,0 +1,576 @@
+//! Payment Service - Customer payments, allocations to invoices and receivables aging
+
+use crate::models::{
+    Payment, PaymentAllocation, PaymentAllocationRequest, PaymentMethod, CreatePaymentRequest,
+    CustomerBalance, ReceivablesAgingRow,
+};
+use crate::database;
+use crate::services::customer_service;
+use crate::services::invoice_service::{self, parse_date, AMOUNT_EPSILON};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+use std::collections::BTreeMap;
+
+#[derive(Debug, FromQueryResult)]
+struct PaymentRow {
+    id: i32,
+    customer_id: Option<i32>,
+    customer_name: String,
+    amount: f64,
+    currency: String,
+    allocated_amount: f64,
+    payment_date: String,
+    method: String,
+    reference: Option<String>,
+    notes: Option<String>,
+    created_at: String,
+}
+
+impl PaymentRow {
+    fn into_payment(self, allocations: Vec<PaymentAllocation>) -> Payment {
+        Payment {
+            id: self.id,
+            customer_id: self.customer_id,
+            customer_name: self.customer_name,
+            amount: self.amount,
+            currency: self.currency,
+            allocated_amount: self.allocated_amount,
+            unallocated_amount: self.amount - self.allocated_amount,
+            payment_date: self.payment_date,
+            method: payment_method_from_str(&self.method),
+            reference: self.reference,
+            notes: self.notes,
+            allocations,
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct AllocationRow {
+    id: i32,
+    payment_id: i32,
+    invoice_id: i32,
+    invoice_number: Option<String>,
+    amount: f64,
+    exchange_rate: f64,
+}
+
+impl From<AllocationRow> for PaymentAllocation {
+    fn from(row: AllocationRow) -> Self {
+        PaymentAllocation {
+            id: row.id,
+            payment_id: row.payment_id,
+            invoice_id: row.invoice_id,
+            invoice_number: row.invoice_number,
+            amount: row.amount,
+            exchange_rate: row.exchange_rate,
+            invoice_amount: row.amount * row.exchange_rate,
+        }
+    }
+}
+
+/// Invoice fields needed to validate an allocation
+#[derive(Debug, FromQueryResult)]
+struct OpenInvoiceRow {
+    id: i32,
+    invoice_number: String,
+    customer_id: Option<i32>,
+    customer_name: String,
+    currency: String,
+    balance: f64,
+    status: String,
+}
+
+pub(crate) fn payment_method_from_str(method: &str) -> PaymentMethod {
+    match method {
+        "CASH" => PaymentMethod::Cash,
+        "CREDIT_CARD" => PaymentMethod::CreditCard,
+        "CHEQUE" => PaymentMethod::Cheque,
+        _ => PaymentMethod::BankTransfer,
+    }
+}
+
+pub(crate) fn payment_method_to_str(method: PaymentMethod) -> &'static str {
+    match method {
+        PaymentMethod::BankTransfer => "BANK_TRANSFER",
+        PaymentMethod::Cash => "CASH",
+        PaymentMethod::CreditCard => "CREDIT_CARD",
+        PaymentMethod::Cheque => "CHEQUE",
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    p.id, p.customer_id, p.customer_name, p.amount, p.currency,
+    CAST(COALESCE((SELECT SUM(pa.amount) FROM payment_allocations pa WHERE pa.payment_id = p.id), 0.0) AS REAL) as allocated_amount,
+    p.payment_date, p.method, p.reference, p.notes, p.created_at
+"#;
+
+const OPEN_INVOICE_SELECT: &str = r#"
+    SELECT i.id, i.invoice_number, i.customer_id, i.customer_name, i.currency, i.status,
+        CAST(i.amount - COALESCE((SELECT SUM(pa.amount * pa.exchange_rate) FROM payment_allocations pa WHERE pa.invoice_id = i.id), 0.0)
+            - COALESCE((SELECT SUM(cn.amount) FROM credit_notes cn WHERE cn.invoice_id = i.id), 0.0) AS REAL) as balance
+    FROM invoices i
+"#;
+
+async fn get_allocations<C: ConnectionTrait>(conn: &C, payment_id: i32) -> Result<Vec<PaymentAllocation>> {
+    let rows: Vec<AllocationRow> = AllocationRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT pa.id, pa.payment_id, pa.invoice_id, i.invoice_number, pa.amount, pa.exchange_rate
+        FROM payment_allocations pa
+        LEFT JOIN invoices i ON pa.invoice_id = i.id
+        WHERE pa.payment_id = ?
+        ORDER BY pa.id
+        "#,
+        vec![Value::Int(Some(payment_id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(PaymentAllocation::from).collect())
+}
+
+async fn get_payment<C: ConnectionTrait>(conn: &C, id: i32) -> Result<Option<Payment>> {
+    let row: Option<PaymentRow> = PaymentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} FROM payments p WHERE p.id = ?", SELECT_FIELDS),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(conn)
+    .await?;
+
+    match row {
+        Some(row) => {
+            let allocations = get_allocations(conn, row.id).await?;
+            Ok(Some(row.into_payment(allocations)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Get all payments, newest first
+pub async fn get_all() -> Result<Vec<Payment>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<PaymentRow> = PaymentRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} FROM payments p ORDER BY p.payment_date DESC, p.id DESC", SELECT_FIELDS)
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut payments = Vec::with_capacity(rows.len());
+    for row in rows {
+        let allocations = get_allocations(&conn, row.id).await?;
+        payments.push(row.into_payment(allocations));
+    }
+    Ok(payments)
+}
+
+/// Get payments received from a customer (ship owner)
+pub async fn get_by_customer(customer_id: i32) -> Result<Vec<Payment>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<PaymentRow> = PaymentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} FROM payments p WHERE p.customer_id = ? ORDER BY p.payment_date DESC, p.id DESC", SELECT_FIELDS),
+        vec![Value::Int(Some(customer_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut payments = Vec::with_capacity(rows.len());
+    for row in rows {
+        let allocations = get_allocations(&conn, row.id).await?;
+        payments.push(row.into_payment(allocations));
+    }
+    Ok(payments)
+}
+
+/// Get payment by ID (with allocations)
+pub async fn get_by_id(id: i32) -> Result<Option<Payment>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    get_payment(&conn, id).await
+}
+
+/// Apply part of a payment to invoices.
+///
+/// With no explicit allocations the remaining amount goes to the customer's
+/// oldest open invoices in the payment currency. Whatever is left stays on the
+/// payment as credit.
+async fn allocate<C: ConnectionTrait>(
+    conn: &C,
+    payment: &Payment,
+    allocations: Vec<PaymentAllocationRequest>,
+) -> Result<()> {
+    let mut remaining = payment.unallocated_amount;
+
+    let allocations = if allocations.is_empty() {
+        let open: Vec<OpenInvoiceRow> = OpenInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            format!(
+                "{} WHERE i.customer_id = ? AND i.currency = ? AND i.status IN ('OPEN', 'PARTIALLY_PAID') ORDER BY i.due_date ASC, i.id ASC",
+                OPEN_INVOICE_SELECT
+            ),
+            vec![
+                Value::Int(payment.customer_id),
+                Value::String(Some(Box::new(payment.currency.clone()))),
+            ]
+        ))
+        .all(conn)
+        .await?;
+
+        let mut auto = Vec::new();
+        for invoice in open {
+            if remaining <= AMOUNT_EPSILON {
+                break;
+            }
+            let amount = remaining.min(invoice.balance);
+            if amount > AMOUNT_EPSILON {
+                auto.push(PaymentAllocationRequest { invoice_id: invoice.id, amount, exchange_rate: None });
+                remaining -= amount;
+            }
+        }
+        remaining = payment.unallocated_amount;
+        auto
+    } else {
+        allocations
+    };
+
+    for allocation in allocations {
+        if allocation.amount <= 0.0 {
+            anyhow::bail!("Allocation amount must be positive");
+        }
+        if allocation.amount - remaining > AMOUNT_EPSILON {
+            anyhow::bail!("Allocations exceed the unallocated payment amount ({:.2} {})", remaining, payment.currency);
+        }
+
+        let invoice: OpenInvoiceRow = OpenInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            format!("{} WHERE i.id = ?", OPEN_INVOICE_SELECT),
+            vec![Value::Int(Some(allocation.invoice_id))]
+        ))
+        .one(conn)
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("Invoice {} not found", allocation.invoice_id))?;
+
+        if invoice.status == "CANCELLED" {
+            anyhow::bail!("Invoice {} is cancelled", invoice.invoice_number);
+        }
+        if invoice.customer_id.is_none() || invoice.customer_id != payment.customer_id {
+            anyhow::bail!("Invoice {} belongs to {}, not {}", invoice.invoice_number, invoice.customer_name, payment.customer_name);
+        }
+
+        let exchange_rate = if invoice.currency == payment.currency {
+            1.0
+        } else {
+            match allocation.exchange_rate {
+                Some(rate) if rate > 0.0 => rate,
+                _ => anyhow::bail!(
+                    "Exchange rate {} -> {} is required for invoice {}",
+                    payment.currency, invoice.currency, invoice.invoice_number
+                ),
+            }
+        };
+
+        if allocation.amount * exchange_rate - invoice.balance > AMOUNT_EPSILON {
+            anyhow::bail!(
+                "Allocation exceeds the open balance of invoice {} ({:.2} {})",
+                invoice.invoice_number, invoice.balance, invoice.currency
+            );
+        }
+
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "INSERT INTO payment_allocations (payment_id, invoice_id, amount, exchange_rate) VALUES (?, ?, ?, ?)",
+            vec![
+                Value::Int(Some(payment.id)),
+                Value::Int(Some(invoice.id)),
+                Value::Double(Some(allocation.amount)),
+                Value::Double(Some(exchange_rate)),
+            ]
+        )).await?;
+
+        invoice_service::refresh_status(conn, invoice.id).await?;
+        remaining -= allocation.amount;
+    }
+
+    Ok(())
+}
+
+/// Record a payment from a customer and apply it to invoices.
+///
+/// Supports partial payments (invoice stays PartiallyPaid), payments in another
+/// currency (via per-allocation exchange rate) and overpayments (the
+/// unallocated remainder is kept as customer credit).
+pub async fn record_payment(req: CreatePaymentRequest) -> Result<Payment> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let customer = customer_service::get_by_id(req.customer_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+    if req.amount <= 0.0 {
+        anyhow::bail!("Payment amount must be positive");
+    }
+    let payment_date = parse_date(&req.payment_date)?;
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO payments (customer_id, customer_name, amount, currency, payment_date, method, reference, notes)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(customer.id)),
+            Value::String(Some(Box::new(customer.legal_name))),
+            Value::Double(Some(req.amount)),
+            Value::String(Some(Box::new(req.currency))),
+            Value::String(Some(Box::new(payment_date.format("%Y-%m-%d").to_string()))),
+            Value::String(Some(Box::new(payment_method_to_str(req.method).to_string()))),
+            Value::String(req.reference.map(Box::new)),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+
+    let id = result.last_insert_id() as i32;
+    let payment = get_payment(&txn, id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created payment"))?;
+
+    allocate(&txn, &payment, req.allocations).await?;
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created payment"))
+}
+
+/// Apply the unallocated (credit) part of an existing payment to invoices
+pub async fn allocate_payment(payment_id: i32, allocations: Vec<PaymentAllocationRequest>) -> Result<Payment> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let txn = conn.begin().await?;
+
+    let payment = get_payment(&txn, payment_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Payment not found"))?;
+    if payment.unallocated_amount <= AMOUNT_EPSILON {
+        anyhow::bail!("Payment is fully allocated");
+    }
+
+    allocate(&txn, &payment, allocations).await?;
+    txn.commit().await?;
+
+    get_by_id(payment_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Payment not found after allocation"))
+}
+
+/// Delete a payment and its allocations (re-opens the invoices it settled)
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let txn = conn.begin().await?;
+
+    let invoice_ids: Vec<i32> = get_allocations(&txn, id).await?
+        .into_iter()
+        .map(|a| a.invoice_id)
+        .collect();
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM payment_allocations WHERE payment_id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM payments WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    for invoice_id in invoice_ids {
+        invoice_service::refresh_status(&txn, invoice_id).await?;
+    }
+
+    txn.commit().await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Get a customer's account balance, one row per currency
+pub async fn get_customer_balances(customer_id: i32) -> Result<Vec<CustomerBalance>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let customer = customer_service::get_by_id(customer_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct BalanceRow {
+        currency: String,
+        total_invoiced: f64,
+        total_paid: f64,
+        total_credited: f64,
+        credit: f64,
+    }
+
+    let rows: Vec<BalanceRow> = BalanceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT c.currency,
+            CAST(COALESCE((SELECT SUM(i.amount) FROM invoices i
+                WHERE i.customer_id = ?1 AND i.currency = c.currency AND i.status != 'CANCELLED'), 0.0) AS REAL) as total_invoiced,
+            CAST(COALESCE((SELECT SUM(pa.amount * pa.exchange_rate) FROM payment_allocations pa
+                JOIN invoices i ON pa.invoice_id = i.id
+                WHERE i.customer_id = ?1 AND i.currency = c.currency), 0.0) AS REAL) as total_paid,
+            CAST(COALESCE((SELECT SUM(cn.amount) FROM credit_notes cn
+                JOIN invoices i ON cn.invoice_id = i.id
+                WHERE i.customer_id = ?1 AND i.currency = c.currency AND i.status != 'CANCELLED'), 0.0) AS REAL) as total_credited,
+            CAST(COALESCE((SELECT SUM(p.amount - COALESCE((SELECT SUM(pa.amount) FROM payment_allocations pa WHERE pa.payment_id = p.id), 0.0))
+                FROM payments p WHERE p.customer_id = ?1 AND p.currency = c.currency), 0.0) AS REAL) as credit
+        FROM (
+            SELECT currency FROM invoices WHERE customer_id = ?1
+            UNION
+            SELECT currency FROM payments WHERE customer_id = ?1
+        ) c
+        ORDER BY c.currency
+        "#,
+        vec![Value::Int(Some(customer_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| CustomerBalance {
+        customer_id,
+        customer_name: customer.legal_name.clone(),
+        currency: r.currency,
+        total_invoiced: r.total_invoiced,
+        total_paid: r.total_paid,
+        total_credited: r.total_credited,
+        open_balance: r.total_invoiced - r.total_paid - r.total_credited,
+        credit: r.credit,
+    }).collect())
+}
+
+/// Receivables aging report as of a date.
+///
+/// Open balances per owner and currency, bucketed by days past the invoice due
+/// date (0–30 includes invoices not yet due). Only payments dated on or before
+/// `as_of` count towards the balance.
+pub async fn get_receivables_aging(as_of: &str) -> Result<Vec<ReceivablesAgingRow>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let as_of_date = parse_date(as_of)?;
+    let as_of_str = as_of_date.format("%Y-%m-%d").to_string();
+
+    #[derive(Debug, FromQueryResult)]
+    struct AgingInvoiceRow {
+        customer_id: Option<i32>,
+        customer_name: String,
+        currency: String,
+        due_date: String,
+        balance: f64,
+    }
+
+    let invoices: Vec<AgingInvoiceRow> = AgingInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT i.customer_id, COALESCE(c.legal_name, i.customer_name) as customer_name, i.currency, i.due_date,
+            CAST(i.amount - COALESCE((SELECT SUM(pa.amount * pa.exchange_rate) FROM payment_allocations pa
+                JOIN payments p ON pa.payment_id = p.id
+                WHERE pa.invoice_id = i.id AND p.payment_date <= ?1), 0.0)
+            - COALESCE((SELECT SUM(cn.amount) FROM credit_notes cn
+                WHERE cn.invoice_id = i.id AND cn.issue_date <= ?1), 0.0) AS REAL) as balance
+        FROM invoices i
+        LEFT JOIN customers c ON i.customer_id = c.id
+        WHERE i.status != 'CANCELLED' AND i.issue_date <= ?1
+        "#,
+        vec![Value::String(Some(Box::new(as_of_str.clone())))]
+    ))
+    .all(&conn)
+    .await?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct CreditRow {
+        customer_id: Option<i32>,
+        customer_name: String,
+        currency: String,
+        credit: f64,
+    }
+
+    let credits: Vec<CreditRow> = CreditRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT p.customer_id, COALESCE(c.legal_name, p.customer_name) as customer_name, p.currency,
+            CAST(SUM(p.amount - COALESCE((SELECT SUM(pa.amount) FROM payment_allocations pa
+                JOIN invoices i ON pa.invoice_id = i.id
+                WHERE pa.payment_id = p.id AND i.issue_date <= ?1), 0.0)) AS REAL) as credit
+        FROM payments p
+        LEFT JOIN customers c ON p.customer_id = c.id
+        WHERE p.payment_date <= ?1
+        GROUP BY p.customer_id, COALESCE(c.legal_name, p.customer_name), p.currency
+        "#,
+        vec![Value::String(Some(Box::new(as_of_str)))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let empty_row = |customer_id: Option<i32>, customer_name: &str, currency: &str| ReceivablesAgingRow {
+        customer_id,
+        customer_name: customer_name.to_string(),
+        currency: currency.to_string(),
+        days_0_30: 0.0,
+        days_31_60: 0.0,
+        days_61_90: 0.0,
+        days_over_90: 0.0,
+        total: 0.0,
+        credit: 0.0,
+    };
+
+    // Keyed by account; the name comes first so the report stays in name order
+    let mut report: BTreeMap<(String, Option<i32>, String), ReceivablesAgingRow> = BTreeMap::new();
+
+    for invoice in invoices {
+        if invoice.balance <= AMOUNT_EPSILON {
+            continue;
+        }
+        let days_overdue = (as_of_date - parse_date(&invoice.due_date)?).num_days().max(0);
+        let row = report
+            .entry((invoice.customer_name.clone(), invoice.customer_id, invoice.currency.clone()))
+            .or_insert_with(|| empty_row(invoice.customer_id, &invoice.customer_name, &invoice.currency));
+
+        match days_overdue {
+            0..=30 => row.days_0_30 += invoice.balance,
+            31..=60 => row.days_31_60 += invoice.balance,
+            61..=90 => row.days_61_90 += invoice.balance,
+            _ => row.days_over_90 += invoice.balance,
+        }
+        row.total += invoice.balance;
+    }
+
+    for credit in credits {
+        if credit.credit <= AMOUNT_EPSILON {
+            continue;
+        }
+        report
+            .entry((credit.customer_name.clone(), credit.customer_id, credit.currency.clone()))
+            .or_insert_with(|| empty_row(credit.customer_id, &credit.customer_name, &credit.currency))
+            .credit = credit.credit;
+    }
+
+    Ok(report.into_values().collect())
+}
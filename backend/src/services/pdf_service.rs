@@ -0,0 +1,1302 @@
+//! PDF Service - Branded proforma, quotation, invoice and delivery note PDFs
+//!
+//! Documents are laid out on A4 with the PDF standard Helvetica fonts (nothing
+//! to embed), re-encoded so the Turkish letters ğ, ı, İ and ş print. Labels are
+//! bilingual (Türkçe / English). A document is first gathered from the
+//! database into a [`PdfDocument`] and then rendered by [`render`], which only
+//! depends on that data: no timestamps or file IDs are written, so the same
+//! document always produces the same bytes and can be snapshot-tested.
+
+use crate::models::{
+    CompanyProfile, PdfDocumentKind, PdfRenderRequest, PdfRenderResult, OrderStatus, InvoiceStatus,
+};
+use crate::services::{
+    order_service, order_charge_service, ship_service, customer_service, ship_visit_service,
+    invoice_service, delivery_note_service, company_service,
+};
+use anyhow::Result;
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str, TextStr};
+use std::path::Path;
+
+// ============================================================================
+// Document data
+// ============================================================================
+
+/// One row of the item table
+#[derive(Debug, Clone)]
+pub struct PdfLine {
+    pub impa_code: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    pub quantity: f64,
+    pub unit: String,
+    /// None on delivery notes (printed without prices)
+    pub unit_price: Option<f64>,
+}
+
+/// Everything printed on a document
+#[derive(Debug, Clone)]
+pub struct PdfDocument {
+    pub kind: PdfDocumentKind,
+    pub company: CompanyProfile,
+    /// Invoice or delivery note number; the order number for proformas and quotations
+    pub number: String,
+    pub order_number: String,
+    /// YYYY-MM-DD (a time part is ignored)
+    pub date: String,
+    pub due_date: Option<String>,
+    pub payment_terms_days: Option<i32>,
+    pub currency: String,
+    /// Customer name first, then address and tax lines
+    pub bill_to: Vec<String>,
+    /// Ship and visit details (label, value)
+    pub vessel: Vec<(&'static str, String)>,
+    pub lines: Vec<PdfLine>,
+    /// Billed order charges (label, amount); discounts are negative
+    pub charges: Vec<(String, f64)>,
+    pub tax_rate_percent: f64,
+    /// Grand total the document must show (the invoice amount); computed when None
+    pub billed_total: Option<f64>,
+    /// Receiver and rank on delivery notes
+    pub receiver: Option<String>,
+    pub signed_at: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl PdfDocument {
+    fn is_priced(&self) -> bool {
+        self.kind != PdfDocumentKind::DeliveryNote
+    }
+}
+
+/// Money breakdown printed under the item table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfTotals {
+    pub subtotal: f64,
+    pub charges: f64,
+    /// Difference to a billed total that does not match the lines (invoices)
+    pub adjustment: f64,
+    pub net: f64,
+    pub tax: f64,
+    pub grand_total: f64,
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+/// Subtotal, charges, VAT and grand total of a document.
+///
+/// With a billed total (invoices), the grand total is that amount and the VAT
+/// is taken out of it; any gap to the lines shows up as an adjustment.
+pub fn totals(doc: &PdfDocument) -> PdfTotals {
+    let subtotal = round2(doc.lines.iter()
+        .map(|l| round2(l.quantity * l.unit_price.unwrap_or(0.0)))
+        .sum());
+    let charges = round2(doc.charges.iter().map(|(_, amount)| amount).sum());
+    let rate = doc.tax_rate_percent / 100.0;
+
+    match doc.billed_total {
+        Some(total) => {
+            let net = round2(total / (1.0 + rate));
+            PdfTotals {
+                subtotal,
+                charges,
+                adjustment: round2(net - subtotal - charges),
+                net,
+                tax: round2(total - net),
+                grand_total: total,
+            }
+        }
+        None => {
+            let net = round2(subtotal + charges);
+            let tax = round2(net * rate);
+            PdfTotals { subtotal, charges, adjustment: 0.0, net, tax, grand_total: round2(net + tax) }
+        }
+    }
+}
+
+// ============================================================================
+// Fonts and text encoding
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Font {
+    Regular,
+    Bold,
+}
+
+/// Helvetica advance widths (1/1000 em) for characters 32..=126
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Helvetica-Bold advance widths (1/1000 em) for characters 32..=126
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+impl Font {
+    fn resource(self) -> Name<'static> {
+        match self {
+            Font::Regular => Name(b"F1"),
+            Font::Bold => Name(b"F2"),
+        }
+    }
+
+    fn char_width(self, c: char) -> f32 {
+        let table = match self {
+            Font::Regular => &HELVETICA_WIDTHS,
+            Font::Bold => &HELVETICA_BOLD_WIDTHS,
+        };
+        let base = match c {
+            'Ç' => 'C', 'ç' => 'c', 'Ğ' => 'G', 'ğ' => 'g', 'Ş' => 'S', 'ş' => 's',
+            'İ' | 'Î' | 'Í' | 'Ì' | 'Ï' => 'I', 'ı' | 'î' | 'í' | 'ì' | 'ï' => 'i',
+            'Ö' | 'Ô' | 'Ó' | 'Ò' => 'O', 'ö' | 'ô' | 'ó' | 'ò' => 'o',
+            'Ü' | 'Û' | 'Ú' | 'Ù' => 'U', 'ü' | 'û' | 'ú' | 'ù' => 'u',
+            'Â' | 'Ä' | 'Á' | 'À' => 'A', 'â' | 'ä' | 'á' | 'à' => 'a',
+            'É' | 'È' | 'Ê' | 'Ë' => 'E', 'é' | 'è' | 'ê' | 'ë' => 'e',
+            'Ñ' => 'N', 'ñ' => 'n',
+            '…' | '—' => return 1.0,
+            other => other,
+        };
+        match base {
+            ' '..='~' => table[base as usize - 32] as f32 / 1000.0,
+            _ => 0.556,
+        }
+    }
+
+    fn text_width(self, text: &str, size: f32) -> f32 {
+        text.chars().map(|c| self.char_width(c)).sum::<f32>() * size
+    }
+}
+
+/// Encode text for the fonts' WinAnsi encoding with the Turkish letters
+/// mapped over Ð Ý Þ ð ý þ (same code points as Windows-1254)
+fn encode(text: &str) -> Vec<u8> {
+    text.chars().map(|c| match c {
+        'Ğ' => 0xD0,
+        'İ' => 0xDD,
+        'Ş' => 0xDE,
+        'ğ' => 0xF0,
+        'ı' => 0xFD,
+        'ş' => 0xFE,
+        'Ð' | 'Ý' | 'Þ' | 'ð' | 'ý' | 'þ' => b'?',
+        '€' => 0x80,
+        '…' => 0x85,
+        '‘' => 0x91,
+        '’' => 0x92,
+        '“' => 0x93,
+        '”' => 0x94,
+        '•' => 0x95,
+        '–' => 0x96,
+        '—' => 0x97,
+        '\t' | '\n' | '\r' => b' ',
+        ' '..='~' | '\u{A0}'..='\u{FF}' => c as u8,
+        _ => b'?',
+    }).collect()
+}
+
+/// Cut text to `width`, ending with an ellipsis when it does not fit
+fn fit(text: &str, font: Font, size: f32, width: f32) -> String {
+    if font.text_width(text, size) <= width {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let limit = width - font.text_width("…", size);
+    for c in text.chars() {
+        if font.text_width(&out, size) + font.char_width(c) * size > limit {
+            break;
+        }
+        out.push(c);
+    }
+    format!("{}…", out.trim_end())
+}
+
+/// Break text into lines no wider than `width` (long words are cut)
+fn wrap(text: &str, font: Font, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.lines() {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() { word.to_string() } else { format!("{} {}", line, word) };
+            if font.text_width(&candidate, size) <= width {
+                line = candidate;
+                continue;
+            }
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            if font.text_width(word, size) <= width {
+                line = word.to_string();
+            } else {
+                lines.push(fit(word, font, size, width));
+            }
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+    lines
+}
+
+// ============================================================================
+// Formatting (Turkish number and date style)
+// ============================================================================
+
+fn group_thousands(digits: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push('.');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// 1234.5 -> "1.234,50"
+fn format_amount(value: f64) -> String {
+    let cents = (value * 100.0).round() as i64;
+    let sign = if cents < 0 { "-" } else { "" };
+    let cents = cents.unsigned_abs();
+    format!("{}{},{:02}", sign, group_thousands(&(cents / 100).to_string()), cents % 100)
+}
+
+/// 12.5 -> "12,5" (up to three decimals)
+fn format_quantity(value: f64) -> String {
+    let text = format!("{:.3}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    let (whole, fraction) = text.split_once('.').unwrap_or((text, ""));
+    let (sign, whole) = whole.strip_prefix('-').map(|w| ("-", w)).unwrap_or(("", whole));
+    if fraction.is_empty() {
+        format!("{}{}", sign, group_thousands(whole))
+    } else {
+        format!("{}{},{}", sign, group_thousands(whole), fraction)
+    }
+}
+
+fn format_percent(value: f64) -> String {
+    format_quantity((value * 100.0).round() / 100.0)
+}
+
+/// "2026-05-12" -> "12.05.2026"; with `with_time`, "2026-05-12T08:30:00" -> "12.05.2026 08:30"
+fn format_date(value: &str, with_time: bool) -> String {
+    let value = value.trim();
+    let date = match (value.get(0..4), value.get(5..7), value.get(8..10)) {
+        (Some(y), Some(m), Some(d)) => format!("{}.{}.{}", d, m, y),
+        _ => return value.to_string(),
+    };
+    match value.get(11..16).filter(|_| with_time && value.len() > 10) {
+        Some(time) if time != "00:00" => format!("{} {}", date, time),
+        _ => date,
+    }
+}
+
+// ============================================================================
+// Amount in words
+// ============================================================================
+
+/// (Turkish major, Turkish minor, English major, English minor)
+fn currency_words(currency: &str) -> (String, String, String, String) {
+    let names = match currency {
+        "TRY" => ("Türk Lirası", "kuruş", "Turkish Lira", "kurus"),
+        "USD" => ("ABD Doları", "sent", "US Dollars", "cents"),
+        "EUR" => ("Avro", "sent", "Euros", "cents"),
+        "GBP" => ("İngiliz Sterlini", "peni", "Pounds Sterling", "pence"),
+        "CHF" => ("İsviçre Frangı", "santim", "Swiss Francs", "centimes"),
+        other => return (other.to_string(), "/100".to_string(), other.to_string(), "/100".to_string()),
+    };
+    (names.0.to_string(), names.1.to_string(), names.2.to_string(), names.3.to_string())
+}
+
+fn split_thousands(mut n: u64) -> Vec<u64> {
+    let mut groups = Vec::new();
+    while n > 0 {
+        groups.push(n % 1000);
+        n /= 1000;
+    }
+    groups
+}
+
+fn number_words_tr(n: u64) -> String {
+    const ONES: [&str; 10] = ["", "bir", "iki", "üç", "dört", "beş", "altı", "yedi", "sekiz", "dokuz"];
+    const TENS: [&str; 10] = ["", "on", "yirmi", "otuz", "kırk", "elli", "altmış", "yetmiş", "seksen", "doksan"];
+    const SCALES: [&str; 6] = ["", "bin", "milyon", "milyar", "trilyon", "katrilyon"];
+
+    if n == 0 {
+        return "sıfır".to_string();
+    }
+    let mut words: Vec<&str> = Vec::new();
+    for (scale, &group) in split_thousands(n).iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        // "bin", not "bir bin"
+        if !(scale == 1 && group == 1) {
+            let hundreds = (group / 100) as usize;
+            if hundreds > 1 {
+                words.push(ONES[hundreds]);
+            }
+            if hundreds > 0 {
+                words.push("yüz");
+            }
+            words.push(TENS[(group / 10 % 10) as usize]);
+            words.push(ONES[(group % 10) as usize]);
+        }
+        words.push(SCALES[scale]);
+    }
+    words.retain(|w| !w.is_empty());
+    words.join(" ")
+}
+
+fn number_words_en(n: u64) -> String {
+    const ONES: [&str; 20] = [
+        "", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+    ];
+    const TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+    const SCALES: [&str; 6] = ["", "thousand", "million", "billion", "trillion", "quadrillion"];
+
+    if n == 0 {
+        return "zero".to_string();
+    }
+    let mut words: Vec<String> = Vec::new();
+    for (scale, &group) in split_thousands(n).iter().enumerate().rev() {
+        if group == 0 {
+            continue;
+        }
+        let hundreds = (group / 100) as usize;
+        if hundreds > 0 {
+            words.push(format!("{} hundred", ONES[hundreds]));
+        }
+        let rest = (group % 100) as usize;
+        if rest >= 20 {
+            match rest % 10 {
+                0 => words.push(TENS[rest / 10].to_string()),
+                ones => words.push(format!("{}-{}", TENS[rest / 10], ONES[ones])),
+            }
+        } else if rest > 0 {
+            words.push(ONES[rest].to_string());
+        }
+        if scale > 0 {
+            words.push(SCALES[scale].to_string());
+        }
+    }
+    words.join(" ")
+}
+
+fn split_amount(amount: f64) -> (&'static str, u64, u64) {
+    let cents = (amount * 100.0).round() as i64;
+    let sign = if cents < 0 { "-" } else { "" };
+    let cents = cents.unsigned_abs();
+    (sign, cents / 100, cents % 100)
+}
+
+/// "Yalnız iki bin üç yüz Türk Lirası elli kuruş"
+pub fn amount_in_words_tr(amount: f64, currency: &str) -> String {
+    let (sign, major, minor) = split_amount(amount);
+    let (major_name, minor_name, _, _) = currency_words(currency);
+    let mut text = format!("Yalnız {}{} {}", if sign.is_empty() { "" } else { "eksi " }, number_words_tr(major), major_name);
+    if minor > 0 {
+        if minor_name.starts_with('/') {
+            text.push_str(&format!(" {}{}", minor, minor_name));
+        } else {
+            text.push_str(&format!(" {} {}", number_words_tr(minor), minor_name));
+        }
+    }
+    text
+}
+
+/// "Say two thousand three hundred Turkish Lira and fifty kurus only"
+pub fn amount_in_words_en(amount: f64, currency: &str) -> String {
+    let (sign, major, minor) = split_amount(amount);
+    let (_, _, major_name, minor_name) = currency_words(currency);
+    let mut text = format!("Say {}{} {}", if sign.is_empty() { "" } else { "minus " }, number_words_en(major), major_name);
+    if minor > 0 {
+        if minor_name.starts_with('/') {
+            text.push_str(&format!(" and {}{}", minor, minor_name));
+        } else {
+            text.push_str(&format!(" and {} {}", number_words_en(minor), minor_name));
+        }
+    }
+    text.push_str(" only");
+    text
+}
+
+// ============================================================================
+// Page layout
+// ============================================================================
+
+const PAGE_WIDTH: f32 = 595.28;
+const PAGE_HEIGHT: f32 = 841.89;
+const LEFT: f32 = 40.0;
+const RIGHT: f32 = PAGE_WIDTH - 40.0;
+const TOP: f32 = PAGE_HEIGHT - 40.0;
+/// Lowest baseline for body content (the footer sits below)
+const BOTTOM: f32 = 62.0;
+
+#[derive(Debug, Clone, Copy)]
+struct Style {
+    font: Font,
+    size: f32,
+    gray: f32,
+}
+
+const BODY: Style = Style { font: Font::Regular, size: 8.0, gray: 0.0 };
+const BODY_BOLD: Style = Style { font: Font::Bold, size: 8.0, gray: 0.0 };
+const SMALL: Style = Style { font: Font::Regular, size: 7.0, gray: 0.35 };
+const LABEL: Style = Style { font: Font::Bold, size: 6.5, gray: 0.4 };
+const HEADING: Style = Style { font: Font::Bold, size: 9.0, gray: 0.0 };
+const COMPANY: Style = Style { font: Font::Bold, size: 13.0, gray: 0.0 };
+const TITLE: Style = Style { font: Font::Bold, size: 15.0, gray: 0.0 };
+const SUBTITLE: Style = Style { font: Font::Bold, size: 10.0, gray: 0.4 };
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Right,
+}
+
+/// Pages drawn so far, the page being drawn on and the vertical position
+struct Canvas {
+    pages: Vec<Content>,
+    current: usize,
+    y: f32,
+}
+
+impl Canvas {
+    fn new() -> Self {
+        Canvas { pages: vec![Content::new()], current: 0, y: TOP }
+    }
+
+    fn add_page(&mut self) {
+        self.pages.push(Content::new());
+        self.current = self.pages.len() - 1;
+    }
+
+    fn page(&mut self) -> &mut Content {
+        &mut self.pages[self.current]
+    }
+
+    fn text(&mut self, x: f32, y: f32, style: Style, text: &str) {
+        let bytes = encode(text);
+        let content = self.page();
+        if style.gray > 0.0 {
+            content.set_fill_gray(style.gray);
+        }
+        content.begin_text();
+        content.set_font(style.font.resource(), style.size);
+        content.set_text_matrix([1.0, 0.0, 0.0, 1.0, x, y]);
+        content.show(Str(&bytes));
+        content.end_text();
+        if style.gray > 0.0 {
+            content.set_fill_gray(0.0);
+        }
+    }
+
+    /// Text ending at `x`
+    fn text_right(&mut self, x: f32, y: f32, style: Style, text: &str) {
+        let width = style.font.text_width(text, style.size);
+        self.text(x - width, y, style, text);
+    }
+
+    fn cell(&mut self, x: f32, width: f32, y: f32, style: Style, align: Align, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        let text = fit(text, style.font, style.size, width - 6.0);
+        match align {
+            Align::Left => self.text(x + 3.0, y, style, &text),
+            Align::Right => self.text_right(x + width - 3.0, y, style, &text),
+        }
+    }
+
+    fn line(&mut self, x1: f32, x2: f32, y: f32, width: f32, gray: f32) {
+        let content = self.page();
+        content.set_stroke_gray(gray);
+        content.set_line_width(width);
+        content.move_to(x1, y);
+        content.line_to(x2, y);
+        content.stroke();
+        content.set_stroke_gray(0.0);
+    }
+
+    fn fill(&mut self, x: f32, y: f32, width: f32, height: f32, gray: f32) {
+        let content = self.page();
+        content.set_fill_gray(gray);
+        content.rect(x, y, width, height);
+        content.fill_nonzero();
+        content.set_fill_gray(0.0);
+    }
+
+    fn frame(&mut self, x: f32, y: f32, width: f32, height: f32, gray: f32) {
+        let content = self.page();
+        content.set_stroke_gray(gray);
+        content.set_line_width(0.5);
+        content.rect(x, y, width, height);
+        content.stroke();
+        content.set_stroke_gray(0.0);
+    }
+}
+
+fn titles(kind: PdfDocumentKind) -> (&'static str, &'static str) {
+    match kind {
+        PdfDocumentKind::Proforma => ("PROFORMA FATURA", "PROFORMA INVOICE"),
+        PdfDocumentKind::Quotation => ("FİYAT TEKLİFİ", "QUOTATION"),
+        PdfDocumentKind::Invoice => ("FATURA", "INVOICE"),
+        PdfDocumentKind::DeliveryNote => ("TESLİM FİŞİ", "DELIVERY NOTE"),
+    }
+}
+
+fn meta_rows(doc: &PdfDocument) -> Vec<(&'static str, String)> {
+    let mut rows = Vec::new();
+    match doc.kind {
+        PdfDocumentKind::Invoice => rows.push(("Fatura No / Invoice No", doc.number.clone())),
+        PdfDocumentKind::DeliveryNote => rows.push(("Fiş No / Note No", doc.number.clone())),
+        PdfDocumentKind::Proforma | PdfDocumentKind::Quotation => {}
+    }
+    rows.push(("Sipariş No / Order No", doc.order_number.clone()));
+    rows.push(("Tarih / Date", format_date(&doc.date, false)));
+    if let Some(due) = &doc.due_date {
+        rows.push(("Vade / Due Date", format_date(due, false)));
+    } else if let Some(days) = doc.payment_terms_days.filter(|_| doc.is_priced()) {
+        rows.push(("Ödeme Vadesi / Payment Terms", format!("{} gün / days", days)));
+    }
+    if doc.is_priced() {
+        rows.push(("Para Birimi / Currency", doc.currency.clone()));
+    }
+    rows
+}
+
+/// Letterhead on the left, document title and numbers on the right
+fn draw_header(canvas: &mut Canvas, doc: &PdfDocument) {
+    let company = &doc.company;
+    let mut left_y = TOP - 12.0;
+    canvas.text(LEFT, left_y, COMPANY, &fit(&company.name, COMPANY.font, COMPANY.size, 290.0));
+    left_y -= 13.0;
+
+    let mut lines: Vec<String> = Vec::new();
+    if let Some(address) = &company.address {
+        lines.extend(wrap(address, BODY.font, SMALL.size, 290.0));
+    }
+    let contact: Vec<String> = [
+        company.phone.as_ref().map(|p| format!("Tel: {}", p)),
+        company.email.clone(),
+        company.website.clone(),
+    ].into_iter().flatten().collect();
+    if !contact.is_empty() {
+        lines.push(contact.join("  •  "));
+    }
+    if company.tax_office.is_some() || company.tax_number.is_some() {
+        lines.push(format!(
+            "V.D. / Tax Office: {}  •  V.N. / Tax No: {}",
+            company.tax_office.as_deref().unwrap_or("-"),
+            company.tax_number.as_deref().unwrap_or("-"),
+        ));
+    }
+    for line in lines {
+        canvas.text(LEFT, left_y, SMALL, &fit(&line, SMALL.font, SMALL.size, 290.0));
+        left_y -= 9.0;
+    }
+
+    let (title_tr, title_en) = titles(doc.kind);
+    let mut right_y = TOP - 12.0;
+    canvas.text_right(RIGHT, right_y, TITLE, title_tr);
+    right_y -= 13.0;
+    canvas.text_right(RIGHT, right_y, SUBTITLE, title_en);
+    right_y -= 15.0;
+    for (label, value) in meta_rows(doc) {
+        canvas.text(RIGHT - 200.0, right_y, LABEL, label);
+        canvas.text_right(RIGHT, right_y, BODY_BOLD, &fit(&value, BODY_BOLD.font, BODY_BOLD.size, 90.0));
+        right_y -= 10.5;
+    }
+
+    canvas.y = left_y.min(right_y) - 2.0;
+    canvas.line(LEFT, RIGHT, canvas.y, 1.0, 0.0);
+    canvas.y -= 12.0;
+}
+
+/// Short header on follow-up pages
+fn draw_continuation_header(canvas: &mut Canvas, doc: &PdfDocument) {
+    let (title_tr, title_en) = titles(doc.kind);
+    canvas.text(LEFT, TOP - 8.0, HEADING, &fit(&doc.company.name, HEADING.font, HEADING.size, 250.0));
+    canvas.text_right(RIGHT, TOP - 8.0, HEADING, &format!("{} / {}  {}", title_tr, title_en, doc.number));
+    canvas.line(LEFT, RIGHT, TOP - 14.0, 1.0, 0.0);
+    canvas.y = TOP - 28.0;
+}
+
+fn new_page(canvas: &mut Canvas, doc: &PdfDocument) {
+    canvas.add_page();
+    draw_continuation_header(canvas, doc);
+}
+
+/// Start a new page unless `height` still fits above the footer
+fn ensure_space(canvas: &mut Canvas, doc: &PdfDocument, height: f32) {
+    if canvas.y - height < BOTTOM {
+        new_page(canvas, doc);
+    }
+}
+
+/// Bill-to block and vessel block side by side
+fn draw_parties(canvas: &mut Canvas, doc: &PdfDocument) {
+    const GAP: f32 = 15.0;
+    let box_width = (RIGHT - LEFT - GAP) / 2.0;
+    let bill_to_label = match doc.kind {
+        PdfDocumentKind::DeliveryNote => "Alıcı / Consignee",
+        PdfDocumentKind::Quotation => "Sayın / To",
+        PdfDocumentKind::Proforma | PdfDocumentKind::Invoice => "Müşteri / Bill To",
+    };
+
+    let mut customer_lines: Vec<(Style, String)> = Vec::new();
+    for (i, line) in doc.bill_to.iter().enumerate() {
+        let style = if i == 0 { BODY_BOLD } else { BODY };
+        for wrapped in wrap(line, style.font, style.size, box_width - 12.0) {
+            customer_lines.push((style, wrapped));
+        }
+    }
+    let rows = customer_lines.len().max(doc.vessel.len()) as f32;
+    let height = 20.0 + rows * 10.5;
+    let top = canvas.y + 4.0;
+
+    canvas.frame(LEFT, top - height, box_width, height, 0.7);
+    canvas.text(LEFT + 6.0, top - 11.0, LABEL, bill_to_label);
+    let mut y = top - 23.0;
+    for (style, line) in &customer_lines {
+        canvas.text(LEFT + 6.0, y, *style, line);
+        y -= 10.5;
+    }
+
+    let x = LEFT + box_width + GAP;
+    canvas.frame(x, top - height, box_width, height, 0.7);
+    canvas.text(x + 6.0, top - 11.0, LABEL, "Gemi ve Liman / Vessel & Port");
+    let mut y = top - 23.0;
+    for (label, value) in &doc.vessel {
+        canvas.text(x + 6.0, y, SMALL, label);
+        canvas.text(x + 92.0, y, BODY, &fit(value, BODY.font, BODY.size, box_width - 98.0));
+        y -= 10.5;
+    }
+
+    canvas.y = top - height - 16.0;
+}
+
+struct Column {
+    title_tr: &'static str,
+    title_en: &'static str,
+    width: f32,
+    align: Align,
+}
+
+fn columns(priced: bool) -> Vec<Column> {
+    let col = |title_tr, title_en, width, align| Column { title_tr, title_en, width, align };
+    if priced {
+        vec![
+            col("Sıra", "No", 24.0, Align::Right),
+            col("IMPA", "IMPA", 48.0, Align::Left),
+            col("Ürün", "Description", 0.0, Align::Left),
+            col("Miktar", "Qty", 48.0, Align::Right),
+            col("Birim", "Unit", 38.0, Align::Left),
+            col("Birim Fiyat", "Unit Price", 66.0, Align::Right),
+            col("Tutar", "Amount", 74.0, Align::Right),
+        ]
+    } else {
+        vec![
+            col("Sıra", "No", 24.0, Align::Right),
+            col("IMPA", "IMPA", 56.0, Align::Left),
+            col("Ürün", "Description", 0.0, Align::Left),
+            col("Miktar", "Qty", 64.0, Align::Right),
+            col("Birim", "Unit", 48.0, Align::Left),
+        ]
+    }
+}
+
+/// Column widths with the description column taking the remaining space
+fn column_widths(columns: &[Column]) -> Vec<f32> {
+    let fixed: f32 = columns.iter().map(|c| c.width).sum();
+    columns.iter().map(|c| if c.width == 0.0 { RIGHT - LEFT - fixed } else { c.width }).collect()
+}
+
+fn draw_table_header(canvas: &mut Canvas, columns: &[Column], widths: &[f32]) {
+    let height = 20.0;
+    canvas.fill(LEFT, canvas.y - height, RIGHT - LEFT, height, 0.9);
+    let mut x = LEFT;
+    for (column, &width) in columns.iter().zip(widths) {
+        let tr = Style { size: 7.0, ..BODY_BOLD };
+        canvas.cell(x, width, canvas.y - 8.5, tr, column.align, column.title_tr);
+        canvas.cell(x, width, canvas.y - 16.5, SMALL, column.align, column.title_en);
+        x += width;
+    }
+    canvas.y -= height;
+}
+
+fn draw_items(canvas: &mut Canvas, doc: &PdfDocument) {
+    let columns = columns(doc.is_priced());
+    let widths = column_widths(&columns);
+    let description_index = 2;
+    let text_width = widths[description_index] - 6.0;
+
+    ensure_space(canvas, doc, 20.0 + 24.0);
+    draw_table_header(canvas, &columns, &widths);
+
+    if doc.lines.is_empty() {
+        canvas.text(LEFT + 3.0, canvas.y - 12.0, SMALL, "Kalem yok / No items");
+        canvas.y -= 18.0;
+        canvas.line(LEFT, RIGHT, canvas.y, 0.5, 0.6);
+        return;
+    }
+
+    for (i, line) in doc.lines.iter().enumerate() {
+        let name_lines = wrap(&line.name, BODY.font, BODY.size, text_width);
+        let description_lines = line.description.as_deref()
+            .map(|d| wrap(d, SMALL.font, SMALL.size, text_width))
+            .unwrap_or_default();
+        let height = 6.0 + name_lines.len().max(1) as f32 * 10.0 + description_lines.len() as f32 * 8.5;
+
+        if canvas.y - height < BOTTOM {
+            new_page(canvas, doc);
+            draw_table_header(canvas, &columns, &widths);
+        }
+
+        let baseline = canvas.y - 10.0;
+        let mut cells = vec![
+            (i + 1).to_string(),
+            line.impa_code.clone().unwrap_or_default(),
+            String::new(),
+            format_quantity(line.quantity),
+            line.unit.clone(),
+        ];
+        if let Some(price) = line.unit_price {
+            cells.push(format_amount(price));
+            cells.push(format_amount(round2(line.quantity * price)));
+        }
+
+        let mut x = LEFT;
+        for ((column, &width), value) in columns.iter().zip(&widths).zip(&cells) {
+            canvas.cell(x, width, baseline, BODY, column.align, value);
+            x += width;
+        }
+
+        let text_x = LEFT + widths[..description_index].iter().sum::<f32>() + 3.0;
+        let mut y = baseline;
+        for name in &name_lines {
+            canvas.text(text_x, y, BODY, name);
+            y -= 10.0;
+        }
+        for description in &description_lines {
+            canvas.text(text_x, y + 1.5, SMALL, description);
+            y -= 8.5;
+        }
+
+        canvas.y -= height;
+        canvas.line(LEFT, RIGHT, canvas.y, 0.4, 0.8);
+    }
+    canvas.line(LEFT, RIGHT, canvas.y, 0.8, 0.0);
+}
+
+fn draw_totals(canvas: &mut Canvas, doc: &PdfDocument) {
+    let totals = totals(doc);
+    let mut rows: Vec<(String, f64, bool)> = vec![("Ara Toplam / Subtotal".to_string(), totals.subtotal, false)];
+    for (label, amount) in &doc.charges {
+        rows.push((label.clone(), *amount, false));
+    }
+    if totals.adjustment.abs() >= 0.005 {
+        rows.push(("Düzeltme / Adjustment".to_string(), totals.adjustment, false));
+    }
+    rows.push(("Net Tutar / Net Amount".to_string(), totals.net, true));
+    rows.push((format!("KDV / VAT %{}", format_percent(doc.tax_rate_percent)), totals.tax, false));
+
+    let words_width = RIGHT - LEFT;
+    let words_tr = wrap(&amount_in_words_tr(totals.grand_total, &doc.currency), BODY.font, BODY.size, words_width - 12.0);
+    let words_en = wrap(&amount_in_words_en(totals.grand_total, &doc.currency), BODY.font, BODY.size, words_width - 12.0);
+    let words_height = 18.0 + (words_tr.len() + words_en.len()) as f32 * 10.0;
+    let height = 8.0 + rows.len() as f32 * 12.0 + 20.0 + 10.0 + words_height;
+    ensure_space(canvas, doc, height);
+
+    let label_x = RIGHT - 230.0;
+    let mut y = canvas.y - 13.0;
+    for (label, amount, strong) in rows {
+        let style = if strong { BODY_BOLD } else { BODY };
+        canvas.text(label_x, y, style, &fit(&label, style.font, style.size, 140.0));
+        canvas.text_right(RIGHT - 4.0, y, style, &format_amount(amount));
+        y -= 12.0;
+    }
+
+    canvas.fill(label_x - 6.0, y - 6.0, RIGHT - label_x + 6.0, 17.0, 0.15);
+    let total_style = Style { gray: 1.0, ..HEADING };
+    canvas.text(label_x, y, total_style, "Genel Toplam / Grand Total");
+    canvas.text_right(RIGHT - 4.0, y, total_style, &format!("{} {}", format_amount(totals.grand_total), doc.currency));
+    canvas.y = y - 18.0;
+
+    let top = canvas.y;
+    canvas.frame(LEFT, top - words_height + 4.0, words_width, words_height - 4.0, 0.7);
+    canvas.text(LEFT + 6.0, top - 7.0, LABEL, "Yazıyla / Amount in Words");
+    let mut y = top - 19.0;
+    for line in words_tr.iter().chain(&words_en) {
+        canvas.text(LEFT + 6.0, y, BODY, line);
+        y -= 10.0;
+    }
+    canvas.y = top - words_height - 8.0;
+}
+
+/// Titled block of free text lines
+fn draw_block(canvas: &mut Canvas, doc: &PdfDocument, title: &str, lines: &[String]) {
+    let wrapped: Vec<String> = lines.iter()
+        .flat_map(|l| wrap(l, BODY.font, BODY.size, RIGHT - LEFT))
+        .collect();
+    if wrapped.is_empty() {
+        return;
+    }
+    ensure_space(canvas, doc, 14.0 + wrapped.len() as f32 * 10.0);
+    canvas.text(LEFT, canvas.y - 7.0, LABEL, title);
+    canvas.y -= 18.0;
+    for line in &wrapped {
+        canvas.text(LEFT, canvas.y, BODY, line);
+        canvas.y -= 10.0;
+    }
+    canvas.y -= 6.0;
+}
+
+fn draw_bank(canvas: &mut Canvas, doc: &PdfDocument) {
+    let company = &doc.company;
+    let lines: Vec<String> = [
+        company.bank_name.clone(),
+        company.iban.as_ref().map(|iban| format!("IBAN: {}", iban)),
+        company.swift.as_ref().map(|swift| format!("SWIFT: {}", swift)),
+    ].into_iter().flatten().collect();
+    draw_block(canvas, doc, "Banka Bilgileri / Bank Details", &lines);
+}
+
+fn draw_signatures(canvas: &mut Canvas, doc: &PdfDocument) {
+    const GAP: f32 = 15.0;
+    let box_width = (RIGHT - LEFT - GAP) / 2.0;
+    let height = 82.0;
+    ensure_space(canvas, doc, height + 24.0);
+
+    canvas.text(LEFT, canvas.y - 8.0, SMALL,
+        "Yukarıdaki malzemeler eksiksiz ve hasarsız teslim alınmıştır. / The goods above were received complete and in good order.");
+    let top = canvas.y - 18.0;
+
+    let receiver = doc.receiver.clone().unwrap_or_default();
+    let signed = doc.signed_at.as_deref().map(|s| format_date(s, true)).unwrap_or_default();
+    let blocks = [
+        ("Teslim Eden / Delivered By", doc.company.name.clone(), String::new()),
+        ("Teslim Alan / Received By", receiver, signed),
+    ];
+    for (i, (label, name, date)) in blocks.iter().enumerate() {
+        let x = LEFT + i as f32 * (box_width + GAP);
+        canvas.frame(x, top - height, box_width, height, 0.7);
+        canvas.text(x + 6.0, top - 11.0, LABEL, label);
+        canvas.text(x + 6.0, top - 24.0, BODY_BOLD, &fit(name, BODY_BOLD.font, BODY_BOLD.size, box_width - 12.0));
+        if !date.is_empty() {
+            canvas.text(x + 6.0, top - 35.0, SMALL, &format!("Tarih / Date: {}", date));
+        }
+        canvas.line(x + 6.0, x + box_width - 6.0, top - height + 16.0, 0.5, 0.5);
+        canvas.text(x + 6.0, top - height + 6.0, SMALL, "İmza - Kaşe / Signature - Stamp");
+    }
+    canvas.y = top - height - 10.0;
+}
+
+fn draw_footers(canvas: &mut Canvas, doc: &PdfDocument) {
+    let count = canvas.pages.len();
+    let (title_tr, _) = titles(doc.kind);
+    let left = format!("{}  •  {} {}", doc.company.name, title_tr, doc.number);
+    for index in 0..count {
+        canvas.current = index;
+        canvas.line(LEFT, RIGHT, 42.0, 0.5, 0.6);
+        canvas.text(LEFT, 31.0, SMALL, &fit(&left, SMALL.font, SMALL.size, 400.0));
+        canvas.text_right(RIGHT, 31.0, SMALL, &format!("Sayfa / Page {}/{}", index + 1, count));
+    }
+}
+
+/// Lay out the document and write the PDF. Returns the bytes and the page count.
+pub fn render(doc: &PdfDocument) -> (Vec<u8>, i32) {
+    let mut canvas = Canvas::new();
+    draw_header(&mut canvas, doc);
+    draw_parties(&mut canvas, doc);
+    draw_items(&mut canvas, doc);
+
+    if doc.is_priced() {
+        draw_totals(&mut canvas, doc);
+        draw_bank(&mut canvas, doc);
+    }
+    if let Some(notes) = doc.notes.as_ref().filter(|n| !n.trim().is_empty()) {
+        draw_block(&mut canvas, doc, "Notlar / Notes", std::slice::from_ref(notes));
+    }
+    if doc.kind == PdfDocumentKind::DeliveryNote {
+        draw_signatures(&mut canvas, doc);
+    }
+    draw_footers(&mut canvas, doc);
+
+    let page_count = canvas.pages.len() as i32;
+    (write_pdf(doc, canvas.pages), page_count)
+}
+
+fn write_pdf(doc: &PdfDocument, pages: Vec<Content>) -> Vec<u8> {
+    let catalog_id = Ref::new(1);
+    let page_tree_id = Ref::new(2);
+    let regular_id = Ref::new(3);
+    let bold_id = Ref::new(4);
+    let info_id = Ref::new(5);
+    let page_ids: Vec<Ref> = (0..pages.len() as i32).map(|i| Ref::new(6 + i * 2)).collect();
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(catalog_id).pages(page_tree_id);
+    pdf.pages(page_tree_id).kids(page_ids.iter().copied()).count(pages.len() as i32);
+
+    for (content, &page_id) in pages.into_iter().zip(&page_ids) {
+        let content_id = Ref::new(page_id.get() + 1);
+        let mut page = pdf.page(page_id);
+        page.media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT));
+        page.parent(page_tree_id);
+        page.contents(content_id);
+        page.resources().fonts()
+            .pair(Font::Regular.resource(), regular_id)
+            .pair(Font::Bold.resource(), bold_id);
+        page.finish();
+        pdf.stream(content_id, &content.finish());
+    }
+
+    for (id, base_font) in [(regular_id, Name(b"Helvetica")), (bold_id, Name(b"Helvetica-Bold"))] {
+        let mut font = pdf.type1_font(id);
+        font.base_font(base_font);
+        let mut encoding = font.encoding_custom();
+        encoding.base_encoding(Name(b"WinAnsiEncoding"));
+        encoding.differences()
+            .consecutive(0xD0, [Name(b"Gbreve")])
+            .consecutive(0xDD, [Name(b"Idotaccent"), Name(b"Scedilla")])
+            .consecutive(0xF0, [Name(b"gbreve")])
+            .consecutive(0xFD, [Name(b"dotlessi"), Name(b"scedilla")]);
+    }
+
+    let (title_tr, title_en) = titles(doc.kind);
+    let title = format!("{} / {} {}", title_tr, title_en, doc.number);
+    pdf.document_info(info_id)
+        .title(TextStr(&title))
+        .author(TextStr(&doc.company.name))
+        .creator(TextStr("SSMS"))
+        .producer(TextStr("SSMS"));
+
+    pdf.finish()
+}
+
+// ============================================================================
+// Gathering documents from the database
+// ============================================================================
+
+fn date_part(value: &str) -> String {
+    value.get(..10).unwrap_or(value).to_string()
+}
+
+/// Collect everything a document prints for an order
+pub async fn build_document(request: &PdfRenderRequest) -> Result<PdfDocument> {
+    if !(0.0..=100.0).contains(&request.tax_rate_percent) {
+        anyhow::bail!("Tax rate must be between 0 and 100");
+    }
+
+    let data = order_service::get_with_items(request.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    let order = data.order;
+
+    let company = company_service::get_profile().await?;
+    if company.name.is_empty() {
+        anyhow::bail!("Company profile is not set up");
+    }
+
+    let ship = ship_service::get_by_id(order.ship_id).await?;
+    let customer = match order.customer_id {
+        Some(id) => customer_service::get_by_id(id).await?,
+        None => None,
+    };
+    let visit = match order.ship_visit_id {
+        Some(id) => ship_visit_service::get_by_id(id).await?,
+        None => None,
+    };
+
+    let mut vessel: Vec<(&'static str, String)> = Vec::new();
+    if let Some(ship) = &ship {
+        vessel.push(("Gemi / Vessel", ship.name.clone()));
+        vessel.push(("IMO No", ship.imo_number.clone()));
+        vessel.push(("Bayrak / Flag", ship.flag.clone()));
+    } else if let Some(name) = &order.ship_name {
+        vessel.push(("Gemi / Vessel", name.clone()));
+    }
+    let port = visit.as_ref().and_then(|v| v.port_name.clone()).or(order.delivery_port.clone());
+    if let Some(port) = port {
+        vessel.push(("Liman / Port", port));
+    }
+    if let Some(visit) = &visit {
+        vessel.push(("Varış / ETA", format_date(&visit.eta, true)));
+        vessel.push(("Kalkış / ETD", format_date(&visit.etd, true)));
+        if let Some(agent) = visit.agent_info.as_ref().filter(|a| !a.trim().is_empty()) {
+            vessel.push(("Acente / Agent", agent.clone()));
+        }
+    }
+
+    let customer_name = customer.as_ref().map(|c| c.legal_name.clone())
+        .or(order.customer_name.clone())
+        .or(ship.as_ref().and_then(|s| s.owner.clone()))
+        .unwrap_or_default();
+    let mut bill_to = vec![customer_name];
+    if let Some(customer) = &customer {
+        bill_to.extend(customer.billing_address.clone());
+        bill_to.extend(customer.country.clone());
+        if customer.tax_office.is_some() || customer.tax_number.is_some() {
+            bill_to.push(format!(
+                "V.D. / Tax Office: {}  •  V.N. / Tax No: {}",
+                customer.tax_office.as_deref().unwrap_or("-"),
+                customer.tax_number.as_deref().unwrap_or("-"),
+            ));
+        }
+    }
+
+    let mut doc = PdfDocument {
+        kind: request.kind,
+        company,
+        number: order.order_number.clone(),
+        order_number: order.order_number.clone(),
+        date: date_part(&order.updated_at),
+        due_date: None,
+        payment_terms_days: order.payment_terms_days,
+        currency: order.currency.clone(),
+        bill_to,
+        vessel,
+        lines: Vec::new(),
+        charges: Vec::new(),
+        tax_rate_percent: request.tax_rate_percent,
+        billed_total: None,
+        receiver: None,
+        signed_at: None,
+        notes: order.notes.clone(),
+    };
+
+    if request.kind == PdfDocumentKind::DeliveryNote {
+        let note = match request.document_id {
+            Some(id) => delivery_note_service::get_by_id(id).await?
+                .filter(|n| n.order_id == order.id)
+                .ok_or_else(|| anyhow::anyhow!("Delivery note not found for this order"))?,
+            None => delivery_note_service::get_by_order(order.id).await?
+                .into_iter()
+                .max_by_key(|n| n.id)
+                .ok_or_else(|| anyhow::anyhow!("Order has no delivery note to print"))?,
+        };
+        doc.number = note.note_number;
+        doc.date = date_part(note.signed_at.as_deref().unwrap_or(&note.created_at));
+        doc.lines = note.lines.into_iter().map(|line| {
+            let description = data.items.iter()
+                .find(|item| item.id == line.order_item_id)
+                .and_then(|item| item.description.clone());
+            PdfLine {
+                impa_code: line.impa_code,
+                name: line.product_name,
+                description,
+                quantity: line.quantity,
+                unit: line.unit,
+                unit_price: None,
+            }
+        }).collect();
+        doc.receiver = note.receiver_name.map(|name| match note.receiver_rank {
+            Some(rank) => format!("{} ({})", name, rank),
+            None => name,
+        });
+        doc.signed_at = note.signed_at;
+        doc.notes = note.notes.or(doc.notes);
+        return Ok(doc);
+    }
+
+    doc.lines = data.items.into_iter().map(|item| PdfLine {
+        impa_code: item.impa_code,
+        name: item.product_name,
+        description: item.description,
+        quantity: item.quantity,
+        unit: item.unit,
+        unit_price: Some(item.selling_price),
+    }).collect();
+    doc.charges = order_charge_service::get_by_order(order.id).await?
+        .into_iter()
+        .filter(|c| c.revenue_amount.abs() >= 0.005)
+        .map(|c| {
+            let label = match c.description.filter(|d| !d.trim().is_empty()) {
+                Some(description) => format!("{} - {}", c.charge_type.display_name(), description),
+                None => c.charge_type.display_name().to_string(),
+            };
+            (label, c.revenue_amount)
+        })
+        .collect();
+
+    if request.kind == PdfDocumentKind::Invoice {
+        let invoice = match request.document_id {
+            Some(id) => invoice_service::get_by_id(id).await?
+                .filter(|i| i.order_id == order.id)
+                .ok_or_else(|| anyhow::anyhow!("Invoice not found for this order"))?,
+            None => invoice_service::get_by_order(order.id).await?
+                .into_iter()
+                .filter(|i| i.status != InvoiceStatus::Cancelled)
+                .max_by_key(|i| i.id)
+                .ok_or_else(|| anyhow::anyhow!("Order has no invoice to print"))?,
+        };
+        if invoice.status == InvoiceStatus::Cancelled {
+            anyhow::bail!("Cannot print a cancelled invoice");
+        }
+        doc.number = invoice.invoice_number;
+        doc.date = invoice.issue_date;
+        doc.due_date = Some(invoice.due_date);
+        doc.currency = invoice.currency;
+        doc.billed_total = Some(invoice.amount);
+        doc.bill_to[0] = invoice.customer_name;
+        doc.notes = invoice.notes.or(doc.notes);
+    } else if order.status == OrderStatus::Cancelled {
+        anyhow::bail!("Cannot print a cancelled order");
+    }
+
+    Ok(doc)
+}
+
+/// Render an order document and write it to `output_path`
+pub async fn render_to_file(request: PdfRenderRequest) -> Result<PdfRenderResult> {
+    let path = Path::new(&request.output_path);
+    if !path.extension().and_then(|e| e.to_str()).is_some_and(|e| e.eq_ignore_ascii_case("pdf")) {
+        anyhow::bail!("Output path must end in .pdf: {}", path.display());
+    }
+
+    let doc = build_document(&request).await?;
+    let (bytes, page_count) = render(&doc);
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, bytes)?;
+
+    Ok(PdfRenderResult { file_path: request.output_path, page_count })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Set to rewrite the checked-in snapshots after an intended layout change
+    const UPDATE_SNAPSHOTS: &str = "UPDATE_SNAPSHOTS";
+
+    fn sample_document() -> PdfDocument {
+        let mut lines = vec![
+            PdfLine {
+                impa_code: Some("370301".to_string()),
+                name: "Somon Fileto".to_string(),
+                description: Some("Premium Norveç somonu, vakumlu".to_string()),
+                quantity: 40.0,
+                unit: "KG".to_string(),
+                unit_price: Some(18.0),
+            },
+            PdfLine {
+                impa_code: None,
+                name: "Şişe Su (İçme)".to_string(),
+                description: None,
+                quantity: 1250.5,
+                unit: "LT".to_string(),
+                unit_price: Some(0.35),
+            },
+        ];
+        // Enough rows to run onto a second page
+        lines.extend((1..=40).map(|i| PdfLine {
+            impa_code: Some(format!("{}", 390000 + i)),
+            name: format!("Kalem {}", i),
+            description: None,
+            quantity: i as f64,
+            unit: "ADET".to_string(),
+            unit_price: Some(12.5),
+        }));
+
+        PdfDocument {
+            kind: PdfDocumentKind::Proforma,
+            company: CompanyProfile {
+                name: "Ege Gemi Tedarik Ltd. Şti.".to_string(),
+                address: Some("Alsancak Mah. Liman Cad. No:1, İzmir".to_string()),
+                phone: Some("+90 232 000 00 00".to_string()),
+                email: Some("info@example.com".to_string()),
+                website: None,
+                tax_office: Some("Kordon".to_string()),
+                tax_number: Some("1234567890".to_string()),
+                bank_name: Some("Örnek Bankası".to_string()),
+                iban: Some("TR00 0000 0000 0000 0000 0000 00".to_string()),
+                swift: Some("EXAMTRIS".to_string()),
+                updated_at: None,
+            },
+            number: "ORD-2026-0007".to_string(),
+            order_number: "ORD-2026-0007".to_string(),
+            date: "2026-09-14 10:30:00".to_string(),
+            due_date: Some("2026-10-14".to_string()),
+            payment_terms_days: Some(30),
+            currency: "USD".to_string(),
+            bill_to: vec!["Seabourn Cruise Line".to_string(), "Seattle, WA".to_string()],
+            vessel: vec![("Gemi / Vessel", "SEABOURN ENCORE".to_string()), ("Liman / Port", "Egeport Kuşadası".to_string())],
+            lines,
+            charges: vec![("Liman giriş ücreti".to_string(), 120.0), ("İndirim".to_string(), -50.0)],
+            tax_rate_percent: 20.0,
+            billed_total: None,
+            receiver: None,
+            signed_at: None,
+            notes: Some("Teslimat gemi bordasında yapılacaktır.".to_string()),
+        }
+    }
+
+    #[test]
+    fn render_is_deterministic_and_matches_snapshot() {
+        let doc = sample_document();
+        let (first, pages) = render(&doc);
+        let (second, _) = render(&doc);
+        assert!(first == second, "rendering the same document twice gave different bytes");
+        assert_eq!(pages, 2);
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots/proforma.pdf");
+        if std::env::var_os(UPDATE_SNAPSHOTS).is_some() {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, &first).unwrap();
+        }
+        let snapshot = std::fs::read(&path)
+            .unwrap_or_else(|e| panic!("cannot read {}: {} (run with {}=1 to create it)", path.display(), e, UPDATE_SNAPSHOTS));
+        assert!(first == snapshot, "rendered PDF differs from {} (run with {}=1 if the change is intended)", path.display(), UPDATE_SNAPSHOTS);
+    }
+
+    #[test]
+    fn amount_in_words_turkish() {
+        let cases = [
+            (0.0, "TRY", "Yalnız sıfır Türk Lirası"),
+            (1000.0, "TRY", "Yalnız bin Türk Lirası"),
+            (1100.0, "TRY", "Yalnız bin yüz Türk Lirası"),
+            (101.0, "TRY", "Yalnız yüz bir Türk Lirası"),
+            (11000.0, "TRY", "Yalnız on bir bin Türk Lirası"),
+            (2300.50, "TRY", "Yalnız iki bin üç yüz Türk Lirası elli kuruş"),
+            (1_001_000.0, "USD", "Yalnız bir milyon bin ABD Doları"),
+            (-5.0, "EUR", "Yalnız eksi beş Avro"),
+            (12.34, "JPY", "Yalnız on iki JPY 34/100"),
+        ];
+        for (amount, currency, expected) in cases {
+            assert_eq!(amount_in_words_tr(amount, currency), expected, "{} {}", amount, currency);
+        }
+    }
+
+    #[test]
+    fn amount_in_words_english() {
+        let cases = [
+            (0.99, "GBP", "Say zero Pounds Sterling and ninety-nine pence only"),
+            (1100.0, "USD", "Say one thousand one hundred US Dollars only"),
+            (21.05, "EUR", "Say twenty-one Euros and five cents only"),
+            (2300.50, "TRY", "Say two thousand three hundred Turkish Lira and fifty kurus only"),
+            (1_000_015.0, "USD", "Say one million fifteen US Dollars only"),
+            (-5.0, "CHF", "Say minus five Swiss Francs only"),
+            (12.34, "JPY", "Say twelve JPY and 34/100 only"),
+        ];
+        for (amount, currency, expected) in cases {
+            assert_eq!(amount_in_words_en(amount, currency), expected, "{} {}", amount, currency);
+        }
+    }
+}
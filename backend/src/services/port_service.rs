@@ -15,6 +15,7 @@ struct PortRow {
     latitude: Option<f64>,
     longitude: Option<f64>,
     notes: Option<String>,
+    is_international: i32,
     is_active: i32,
     created_at: String,
     updated_at: String,
@@ -31,6 +32,7 @@ impl From<PortRow> for Port {
             latitude: row.latitude,
             longitude: row.longitude,
             notes: row.notes,
+            is_international: row.is_international == 1,
             is_active: row.is_active == 1,
             created_at: row.created_at,
             updated_at: row.updated_at,
@@ -47,7 +49,7 @@ pub async fn get_all() -> Result<Vec<Port>> {
         DatabaseBackend::Sqlite,
         r#"
         SELECT id, name, country, city, timezone, latitude, longitude, 
-               notes, is_active, created_at, updated_at
+               notes, is_international, is_active, created_at, updated_at
         FROM ports
         ORDER BY name ASC
         "#.to_string()
@@ -67,7 +69,7 @@ pub async fn get_active() -> Result<Vec<Port>> {
         DatabaseBackend::Sqlite,
         r#"
         SELECT id, name, country, city, timezone, latitude, longitude, 
-               notes, is_active, created_at, updated_at
+               notes, is_international, is_active, created_at, updated_at
         FROM ports
         WHERE is_active = 1
         ORDER BY name ASC
@@ -88,7 +90,7 @@ pub async fn get_by_id(id: i32) -> Result<Option<Port>> {
         DatabaseBackend::Sqlite,
         r#"
         SELECT id, name, country, city, timezone, latitude, longitude, 
-               notes, is_active, created_at, updated_at
+               notes, is_international, is_active, created_at, updated_at
         FROM ports
         WHERE id = ?
         "#,
@@ -108,8 +110,8 @@ pub async fn create(req: CreatePortRequest) -> Result<Port> {
     conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
         r#"
-        INSERT INTO ports (name, country, city, timezone, latitude, longitude, notes)
-        VALUES (?, ?, ?, ?, ?, ?, ?)
+        INSERT INTO ports (name, country, city, timezone, latitude, longitude, notes, is_international)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
         "#,
         vec![
             Value::String(Some(Box::new(req.name))),
@@ -119,13 +121,14 @@ pub async fn create(req: CreatePortRequest) -> Result<Port> {
             Value::Double(req.latitude),
             Value::Double(req.longitude),
             Value::String(req.notes.map(Box::new)),
+            Value::Int(Some(if req.is_international { 1 } else { 0 })),
         ]
     )).await?;
 
     // Get the created port
     let rows: Vec<PortRow> = PortRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, country, city, timezone, latitude, longitude, notes, is_active, created_at, updated_at FROM ports ORDER BY id DESC LIMIT 1".to_string()
+        "SELECT id, name, country, city, timezone, latitude, longitude, notes, is_international, is_active, created_at, updated_at FROM ports ORDER BY id DESC LIMIT 1".to_string()
     ))
     .all(&conn)
     .await?;
@@ -155,6 +158,7 @@ pub async fn update(id: i32, req: UpdatePortRequest) -> Result<Option<Port>> {
     let latitude = req.latitude.or(existing.latitude);
     let longitude = req.longitude.or(existing.longitude);
     let notes = req.notes.or(existing.notes);
+    let is_international = req.is_international.unwrap_or(existing.is_international);
     let is_active = req.is_active.unwrap_or(existing.is_active);
 
     conn.execute(Statement::from_sql_and_values(
@@ -162,7 +166,7 @@ pub async fn update(id: i32, req: UpdatePortRequest) -> Result<Option<Port>> {
         r#"
         UPDATE ports 
         SET name = ?, country = ?, city = ?, timezone = ?, 
-            latitude = ?, longitude = ?, notes = ?, is_active = ?,
+            latitude = ?, longitude = ?, notes = ?, is_international = ?, is_active = ?,
             updated_at = datetime('now')
         WHERE id = ?
         "#,
@@ -174,6 +178,7 @@ pub async fn update(id: i32, req: UpdatePortRequest) -> Result<Option<Port>> {
             Value::Double(latitude),
             Value::Double(longitude),
             Value::String(notes.map(Box::new)),
+            Value::Int(Some(if is_international { 1 } else { 0 })),
             Value::Int(Some(if is_active { 1 } else { 0 })),
             Value::Int(Some(id)),
         ]
@@ -222,7 +227,7 @@ pub async fn get_by_country(country: &str) -> Result<Vec<Port>> {
         DatabaseBackend::Sqlite,
         r#"
         SELECT id, name, country, city, timezone, latitude, longitude, 
-               notes, is_active, created_at, updated_at
+               notes, is_international, is_active, created_at, updated_at
         FROM ports
         WHERE country = ? AND is_active = 1
         ORDER BY name ASC
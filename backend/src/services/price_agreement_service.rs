@@ -0,0 +1,496 @@
+//! Price Agreement Service - Customer contracts with fixed prices or markups per IMPA item/group
+
+use crate::models::{
+    PriceAgreement, PriceAgreementLine, CreatePriceAgreementRequest, UpdatePriceAgreementRequest,
+    CreatePriceAgreementLineRequest, ContractPrice, OffContractLine, Order,
+};
+use crate::database;
+use crate::services::customer_service;
+use crate::services::invoice_service::{parse_date, AMOUNT_EPSILON};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct AgreementRow {
+    id: i32,
+    customer_id: i32,
+    customer_name: Option<String>,
+    name: String,
+    currency: String,
+    valid_from: String,
+    valid_to: String,
+    notes: Option<String>,
+    is_active: i32,
+    created_at: String,
+    updated_at: String,
+}
+
+impl AgreementRow {
+    fn into_agreement(self, lines: Vec<PriceAgreementLine>) -> PriceAgreement {
+        PriceAgreement {
+            id: self.id,
+            customer_id: self.customer_id,
+            customer_name: self.customer_name,
+            name: self.name,
+            currency: self.currency,
+            valid_from: self.valid_from,
+            valid_to: self.valid_to,
+            notes: self.notes,
+            is_active: self.is_active == 1,
+            lines,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct LineRow {
+    id: i32,
+    agreement_id: i32,
+    impa_code: Option<String>,
+    impa_group: Option<String>,
+    description: Option<String>,
+    fixed_price: Option<f64>,
+    markup_percent: Option<f64>,
+}
+
+impl From<LineRow> for PriceAgreementLine {
+    fn from(row: LineRow) -> Self {
+        PriceAgreementLine {
+            id: row.id,
+            agreement_id: row.agreement_id,
+            impa_code: row.impa_code,
+            impa_group: row.impa_group,
+            description: row.description,
+            fixed_price: row.fixed_price,
+            markup_percent: row.markup_percent,
+        }
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    pa.id, pa.customer_id, c.legal_name as customer_name, pa.name, pa.currency, pa.valid_from, pa.valid_to,
+    pa.notes, pa.is_active, pa.created_at, pa.updated_at
+"#;
+
+const FROM_JOIN: &str = "FROM price_agreements pa LEFT JOIN customers c ON pa.customer_id = c.id";
+
+/// IMPA codes are compared without whitespace ("37 01 01" == "370101")
+pub(crate) fn normalize_impa(code: &str) -> String {
+    code.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+async fn get_lines<C: ConnectionTrait>(conn: &C, agreement_id: i32) -> Result<Vec<PriceAgreementLine>> {
+    let rows: Vec<LineRow> = LineRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT id, agreement_id, impa_code, impa_group, description, fixed_price, markup_percent
+        FROM price_agreement_lines
+        WHERE agreement_id = ?
+        ORDER BY COALESCE(impa_code, impa_group), id
+        "#,
+        vec![Value::Int(Some(agreement_id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(PriceAgreementLine::from).collect())
+}
+
+async fn insert_line<C: ConnectionTrait>(conn: &C, agreement_id: i32, line: CreatePriceAgreementLineRequest) -> Result<()> {
+    let impa_code = line.impa_code.as_deref().map(normalize_impa).filter(|c| !c.is_empty());
+    let impa_group = line.impa_group.as_deref().map(normalize_impa).filter(|c| !c.is_empty());
+
+    if impa_code.is_some() == impa_group.is_some() {
+        anyhow::bail!("Agreement line needs either an IMPA code or an IMPA group");
+    }
+    match (line.fixed_price, line.markup_percent) {
+        (Some(price), None) if price >= 0.0 => {}
+        (None, Some(markup)) if markup > -100.0 => {}
+        _ => anyhow::bail!("Agreement line needs either a fixed price or a markup percentage"),
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO price_agreement_lines (agreement_id, impa_code, impa_group, description, fixed_price, markup_percent)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(agreement_id)),
+            Value::String(impa_code.map(Box::new)),
+            Value::String(impa_group.map(Box::new)),
+            Value::String(line.description.map(Box::new)),
+            Value::Double(line.fixed_price),
+            Value::Double(line.markup_percent),
+        ]
+    )).await?;
+
+    Ok(())
+}
+
+async fn rows_to_agreements<C: ConnectionTrait>(conn: &C, rows: Vec<AgreementRow>) -> Result<Vec<PriceAgreement>> {
+    let mut agreements = Vec::with_capacity(rows.len());
+    for row in rows {
+        let lines = get_lines(conn, row.id).await?;
+        agreements.push(row.into_agreement(lines));
+    }
+    Ok(agreements)
+}
+
+/// Get price agreements, optionally for a single customer
+pub async fn get_all(customer_id: Option<i32>) -> Result<Vec<PriceAgreement>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<AgreementRow> = match customer_id {
+        Some(customer_id) => AgreementRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            format!("SELECT {} {} WHERE pa.customer_id = ? ORDER BY pa.valid_from DESC, pa.id DESC", SELECT_FIELDS, FROM_JOIN),
+            vec![Value::Int(Some(customer_id))]
+        ))
+        .all(&conn)
+        .await?,
+        None => AgreementRow::find_by_statement(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            format!("SELECT {} {} ORDER BY c.legal_name, pa.valid_from DESC", SELECT_FIELDS, FROM_JOIN)
+        ))
+        .all(&conn)
+        .await?,
+    };
+
+    rows_to_agreements(&conn, rows).await
+}
+
+/// Get a price agreement with its lines
+pub async fn get_by_id(id: i32) -> Result<Option<PriceAgreement>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<AgreementRow> = AgreementRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE pa.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    match row {
+        Some(row) => {
+            let lines = get_lines(&conn, row.id).await?;
+            Ok(Some(row.into_agreement(lines)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Create a price agreement with its lines
+pub async fn create(req: CreatePriceAgreementRequest) -> Result<PriceAgreement> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    customer_service::get_by_id(req.customer_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+
+    if req.name.trim().is_empty() {
+        anyhow::bail!("Agreement name is required");
+    }
+    let valid_from = parse_date(&req.valid_from)?;
+    let valid_to = parse_date(&req.valid_to)?;
+    if valid_to < valid_from {
+        anyhow::bail!("Agreement end date cannot be before its start date");
+    }
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO price_agreements (customer_id, name, currency, valid_from, valid_to, notes)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(req.customer_id)),
+            Value::String(Some(Box::new(req.name.trim().to_string()))),
+            Value::String(Some(Box::new(req.currency))),
+            Value::String(Some(Box::new(valid_from.format("%Y-%m-%d").to_string()))),
+            Value::String(Some(Box::new(valid_to.format("%Y-%m-%d").to_string()))),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    for line in req.lines {
+        insert_line(&txn, id, line).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created price agreement"))
+}
+
+/// Update the header of a price agreement (name, validity, notes, active flag)
+pub async fn update(id: i32, req: UpdatePriceAgreementRequest) -> Result<PriceAgreement> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let existing = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Price agreement not found"))?;
+
+    let valid_from = parse_date(req.valid_from.as_deref().unwrap_or(&existing.valid_from))?;
+    let valid_to = parse_date(req.valid_to.as_deref().unwrap_or(&existing.valid_to))?;
+    if valid_to < valid_from {
+        anyhow::bail!("Agreement end date cannot be before its start date");
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE price_agreements SET name = ?, valid_from = ?, valid_to = ?, notes = ?, is_active = ?, updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::String(Some(Box::new(req.name.unwrap_or(existing.name)))),
+            Value::String(Some(Box::new(valid_from.format("%Y-%m-%d").to_string()))),
+            Value::String(Some(Box::new(valid_to.format("%Y-%m-%d").to_string()))),
+            Value::String(req.notes.or(existing.notes).map(Box::new)),
+            Value::Int(Some(req.is_active.unwrap_or(existing.is_active) as i32)),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve updated price agreement"))
+}
+
+/// Delete a price agreement and its lines
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM price_agreement_lines WHERE agreement_id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM price_agreements WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Add a price line to an agreement
+pub async fn add_line(agreement_id: i32, line: CreatePriceAgreementLineRequest) -> Result<PriceAgreement> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    get_by_id(agreement_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Price agreement not found"))?;
+
+    insert_line(&conn, agreement_id, line).await?;
+
+    get_by_id(agreement_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Price agreement not found"))
+}
+
+/// Remove a price line from an agreement
+pub async fn delete_line(line_id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM price_agreement_lines WHERE id = ?",
+        vec![Value::Int(Some(line_id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Resolve the contract selling price for an IMPA code.
+///
+/// Only active agreements of the customer in the given currency that are valid
+/// on `date` are considered. An item-level line beats a group line, and the
+/// longest matching IMPA group wins; among overlapping agreements the most
+/// recently started one applies.
+pub(crate) async fn find_contract_price<C: ConnectionTrait>(
+    conn: &C,
+    customer_id: i32,
+    currency: &str,
+    date: chrono::NaiveDate,
+    impa_code: &str,
+    buying_price: f64,
+) -> Result<Option<ContractPrice>> {
+    #[derive(Debug, FromQueryResult)]
+    struct MatchRow {
+        agreement_id: i32,
+        agreement_name: String,
+        line_id: i32,
+        impa_code: Option<String>,
+        fixed_price: Option<f64>,
+        markup_percent: Option<f64>,
+    }
+
+    let impa_code = normalize_impa(impa_code);
+    if impa_code.is_empty() {
+        return Ok(None);
+    }
+    let date = date.format("%Y-%m-%d").to_string();
+
+    let row: Option<MatchRow> = MatchRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT pa.id as agreement_id, pa.name as agreement_name, l.id as line_id,
+               l.impa_code, l.fixed_price, l.markup_percent
+        FROM price_agreement_lines l
+        JOIN price_agreements pa ON l.agreement_id = pa.id
+        WHERE pa.customer_id = ? AND pa.is_active = 1 AND pa.currency = ?
+          AND pa.valid_from <= ? AND pa.valid_to >= ?
+          AND (l.impa_code = ? OR (l.impa_group IS NOT NULL AND substr(?, 1, length(l.impa_group)) = l.impa_group))
+        ORDER BY (l.impa_code IS NOT NULL) DESC, length(l.impa_group) DESC, pa.valid_from DESC, l.id DESC
+        LIMIT 1
+        "#,
+        vec![
+            Value::Int(Some(customer_id)),
+            Value::String(Some(Box::new(currency.to_string()))),
+            Value::String(Some(Box::new(date.clone()))),
+            Value::String(Some(Box::new(date))),
+            Value::String(Some(Box::new(impa_code.clone()))),
+            Value::String(Some(Box::new(impa_code))),
+        ]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|row| {
+        let selling_price = match (row.fixed_price, row.markup_percent) {
+            (Some(price), _) => price,
+            (None, Some(markup)) => (buying_price * (1.0 + markup / 100.0) * 100.0).round() / 100.0,
+            (None, None) => buying_price,
+        };
+        ContractPrice {
+            agreement_id: row.agreement_id,
+            agreement_name: row.agreement_name,
+            line_id: row.line_id,
+            selling_price,
+            is_group_price: row.impa_code.is_none(),
+        }
+    }))
+}
+
+/// Contract price for an item on an order (uses the order's customer, currency and date)
+pub(crate) async fn find_contract_price_for_order<C: ConnectionTrait>(
+    conn: &C,
+    order: &Order,
+    impa_code: &str,
+    buying_price: f64,
+) -> Result<Option<ContractPrice>> {
+    let Some(customer_id) = order.customer_id else {
+        return Ok(None);
+    };
+    let order_date = parse_date(&order.created_at)?;
+    find_contract_price(conn, customer_id, &order.currency, order_date, impa_code, buying_price).await
+}
+
+/// Contract price the customer of an order pays for an IMPA item (None = no contract)
+pub async fn get_contract_price(order_id: i32, impa_code: &str, buying_price: f64) -> Result<Option<ContractPrice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = crate::services::order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    find_contract_price_for_order(&conn, &order, impa_code, buying_price).await
+}
+
+/// Order lines whose selling price differs from the contract in force when the
+/// order was created. Cancelled orders are skipped.
+pub async fn get_off_contract_lines(customer_id: Option<i32>) -> Result<Vec<OffContractLine>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct LineCandidateRow {
+        order_id: i32,
+        order_number: String,
+        order_created_at: String,
+        customer_id: i32,
+        customer_name: String,
+        order_item_id: i32,
+        product_name: String,
+        impa_code: String,
+        quantity: f64,
+        buying_price: f64,
+        selling_price: f64,
+        currency: String,
+    }
+
+    let rows: Vec<LineCandidateRow> = LineCandidateRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT o.id as order_id, o.order_number, o.created_at as order_created_at,
+               o.customer_id, c.legal_name as customer_name,
+               oi.id as order_item_id, oi.product_name, oi.impa_code, oi.quantity,
+               oi.buying_price, oi.selling_price, o.currency
+        FROM order_items oi
+        JOIN orders o ON oi.order_id = o.id
+        JOIN customers c ON o.customer_id = c.id
+        WHERE o.status != 'CANCELLED'
+          AND oi.impa_code IS NOT NULL
+          AND (? IS NULL OR o.customer_id = ?)
+          AND EXISTS (SELECT 1 FROM price_agreements pa WHERE pa.customer_id = o.customer_id AND pa.is_active = 1)
+        ORDER BY o.id, oi.id
+        "#,
+        vec![Value::Int(customer_id), Value::Int(customer_id)]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut lines = Vec::new();
+    for row in rows {
+        let order_date = parse_date(&row.order_created_at)?;
+        let Some(contract) = find_contract_price(
+            &conn, row.customer_id, &row.currency, order_date, &row.impa_code, row.buying_price
+        ).await? else {
+            continue;
+        };
+
+        if (row.selling_price - contract.selling_price).abs() <= AMOUNT_EPSILON {
+            continue;
+        }
+
+        lines.push(OffContractLine {
+            order_id: row.order_id,
+            order_number: row.order_number,
+            order_item_id: row.order_item_id,
+            customer_id: row.customer_id,
+            customer_name: row.customer_name,
+            product_name: row.product_name,
+            impa_code: row.impa_code,
+            quantity: row.quantity,
+            currency: row.currency,
+            selling_price: row.selling_price,
+            contract_price: contract.selling_price,
+            revenue_difference: (row.selling_price - contract.selling_price) * row.quantity,
+            agreement_id: contract.agreement_id,
+            agreement_name: contract.agreement_name,
+        });
+    }
+
+    Ok(lines)
+}
@@ -0,0 +1,240 @@
+//! Price History Service - Supply item price changes and price-rise analytics
+
+use crate::models::{PriceChange, PriceChangeSource, PriceRiseItem};
+use crate::database;
+use crate::services::invoice_service::parse_date;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct PriceChangeRow {
+    id: i32,
+    supply_item_id: i32,
+    effective_date: String,
+    old_price: Option<f64>,
+    old_currency: Option<String>,
+    new_price: f64,
+    currency: String,
+    source: String,
+    reference: Option<String>,
+    created_at: String,
+}
+
+impl From<PriceChangeRow> for PriceChange {
+    fn from(row: PriceChangeRow) -> Self {
+        let change_percent = match (row.old_price, &row.old_currency) {
+            (Some(old), Some(old_currency)) if old > 0.0 && *old_currency == row.currency => {
+                Some((row.new_price - old) / old * 100.0)
+            }
+            _ => None,
+        };
+        PriceChange {
+            id: row.id,
+            supply_item_id: row.supply_item_id,
+            effective_date: row.effective_date,
+            old_price: row.old_price,
+            old_currency: row.old_currency,
+            new_price: row.new_price,
+            currency: row.currency,
+            change_percent,
+            source: source_from_str(&row.source),
+            reference: row.reference,
+            created_at: row.created_at,
+        }
+    }
+}
+
+fn source_from_str(source: &str) -> PriceChangeSource {
+    match source {
+        "PRICE_LIST" => PriceChangeSource::PriceListImport,
+        "RFQ_AWARD" => PriceChangeSource::RfqAward,
+        _ => PriceChangeSource::Manual,
+    }
+}
+
+fn source_to_str(source: PriceChangeSource) -> &'static str {
+    match source {
+        PriceChangeSource::Manual => "MANUAL",
+        PriceChangeSource::PriceListImport => "PRICE_LIST",
+        PriceChangeSource::RfqAward => "RFQ_AWARD",
+    }
+}
+
+/// Today's date, or a given `YYYY-MM-DD` date after validation
+pub(crate) fn effective_date(value: Option<&str>) -> Result<String> {
+    match value.map(str::trim).filter(|v| !v.is_empty()) {
+        Some(value) => Ok(parse_date(value)?.format("%Y-%m-%d").to_string()),
+        None => Ok(chrono::Utc::now().format("%Y-%m-%d").to_string()),
+    }
+}
+
+/// Record a supply item price change.
+///
+/// `old` and `new` are price and currency; `old` is None for a new item.
+/// Nothing is recorded when neither price nor currency changed. A change
+/// cannot take effect before the item's last recorded change.
+pub(crate) async fn record<C: ConnectionTrait>(
+    conn: &C,
+    supply_item_id: i32,
+    old: Option<(f64, &str)>,
+    new: (f64, &str),
+    source: PriceChangeSource,
+    effective_date: &str,
+    reference: Option<&str>,
+) -> Result<()> {
+    let (new_price, currency) = new;
+    if let Some((old_price, old_currency)) = old {
+        if (old_price - new_price).abs() < 1e-9 && old_currency == currency {
+            return Ok(());
+        }
+
+        #[derive(Debug, FromQueryResult)]
+        struct LastRow {
+            effective_date: Option<String>,
+        }
+
+        let last: Option<LastRow> = LastRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "SELECT MAX(effective_date) as effective_date FROM supply_item_prices WHERE supply_item_id = ?",
+            vec![Value::Int(Some(supply_item_id))]
+        ))
+        .one(conn)
+        .await?;
+        if let Some(last_date) = last.and_then(|l| l.effective_date) {
+            if effective_date < last_date.as_str() {
+                anyhow::bail!("Price change cannot take effect before the last recorded change ({})", last_date);
+            }
+        }
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO supply_item_prices (supply_item_id, effective_date, old_price, old_currency, new_price, currency, source, reference)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(supply_item_id)),
+            Value::String(Some(Box::new(effective_date.to_string()))),
+            Value::Double(old.map(|(price, _)| price)),
+            Value::String(old.map(|(_, currency)| Box::new(currency.to_string()))),
+            Value::Double(Some(new_price)),
+            Value::String(Some(Box::new(currency.to_string()))),
+            Value::String(Some(Box::new(source_to_str(source).to_string()))),
+            Value::String(reference.map(|r| Box::new(r.to_string()))),
+        ]
+    )).await?;
+
+    Ok(())
+}
+
+/// Price changes of a supply item, newest first
+pub async fn get_history(supply_item_id: i32) -> Result<Vec<PriceChange>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<PriceChangeRow> = PriceChangeRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT id, supply_item_id, effective_date, old_price, old_currency, new_price, currency, source, reference, created_at
+        FROM supply_item_prices
+        WHERE supply_item_id = ?
+        ORDER BY effective_date DESC, id DESC
+        "#,
+        vec![Value::Int(Some(supply_item_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(PriceChange::from).collect())
+}
+
+/// Supply items whose price rose by more than `min_rise_percent` between two dates.
+///
+/// The start price is the price in effect on `start_date` (for items priced
+/// later, their first price); the end price is the one in effect on
+/// `end_date`. Items whose currency changed in between are left out.
+/// Largest rise first.
+pub async fn get_price_rises(
+    start_date: &str,
+    end_date: &str,
+    min_rise_percent: f64,
+    supplier_id: Option<i32>,
+) -> Result<Vec<PriceRiseItem>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let start_date = parse_date(start_date)?.format("%Y-%m-%d").to_string();
+    let end_date = parse_date(end_date)?.format("%Y-%m-%d").to_string();
+    if end_date < start_date {
+        anyhow::bail!("End date is before start date");
+    }
+
+    #[derive(Debug, FromQueryResult)]
+    struct HistoryRow {
+        supply_item_id: i32,
+        supply_item_name: String,
+        impa_code: Option<String>,
+        supplier_id: i32,
+        supplier_name: Option<String>,
+        effective_date: String,
+        old_price: Option<f64>,
+        new_price: f64,
+        currency: String,
+    }
+
+    let rows: Vec<HistoryRow> = HistoryRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT p.supply_item_id, si.name as supply_item_name, si.impa_code, si.supplier_id, s.name as supplier_name,
+               p.effective_date, p.old_price, p.new_price, p.currency
+        FROM supply_item_prices p
+        JOIN supply_items si ON p.supply_item_id = si.id
+        LEFT JOIN suppliers s ON si.supplier_id = s.id
+        WHERE p.effective_date <= ? AND (? IS NULL OR si.supplier_id = ?)
+        ORDER BY p.supply_item_id, p.effective_date, p.id
+        "#,
+        vec![
+            Value::String(Some(Box::new(end_date))),
+            Value::Int(supplier_id),
+            Value::Int(supplier_id),
+        ]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut items = Vec::new();
+    for group in rows.chunk_by(|a, b| a.supply_item_id == b.supply_item_id) {
+        let before: Vec<&HistoryRow> = group.iter().filter(|r| r.effective_date <= start_date).collect();
+        let during: Vec<&HistoryRow> = group.iter().filter(|r| r.effective_date > start_date).collect();
+        let (Some(first), Some(last)) = (before.last().or(during.first()), group.last()) else {
+            continue;
+        };
+        let start_price = first.new_price;
+        if first.currency != last.currency || start_price <= 0.0 || during.iter().any(|r| r.currency != first.currency) {
+            continue;
+        }
+        let change_percent = (last.new_price - start_price) / start_price * 100.0;
+        if change_percent <= min_rise_percent {
+            continue;
+        }
+        items.push(PriceRiseItem {
+            supply_item_id: last.supply_item_id,
+            supply_item_name: last.supply_item_name.clone(),
+            impa_code: last.impa_code.clone(),
+            supplier_id: last.supplier_id,
+            supplier_name: last.supplier_name.clone(),
+            currency: last.currency.clone(),
+            start_price,
+            end_price: last.new_price,
+            change_percent: (change_percent * 100.0).round() / 100.0,
+            change_count: during.iter().filter(|r| r.old_price.is_some()).count() as i32,
+            last_change_date: last.effective_date.clone(),
+        });
+    }
+
+    items.sort_by(|a, b| b.change_percent.total_cmp(&a.change_percent));
+    Ok(items)
+}
@@ -0,0 +1,443 @@
+//! Price List Service - Monthly supplier price lists from CSV/XLSX files
+//!
+//! `preview` diffs the file against the supplier's catalog without writing
+//! anything; `import` applies the same diff in one transaction: prices are
+//! updated, new items created, and (optionally) items missing from the list
+//! marked unavailable. Every price change goes into the price history.
+
+use crate::models::{
+    PriceListImportRequest, PriceListColumnMapping, PriceListPreview, PriceListChange, PriceListChangeKind,
+    PriceListMatchKind, PriceListImportResult, PriceChangeSource, SupplyItem, CreateSupplyItemRequest,
+};
+use crate::database;
+use crate::services::{supplier_service, supply_item_service, impa_service, category_service, price_history_service};
+use crate::services::price_agreement_service::normalize_impa;
+use crate::services::requisition_import_service::{read_rows, resolve_column, normalize_name};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Prices closer than this are the same price
+const PRICE_EPSILON: f64 = 0.005;
+
+struct ColumnIndexes {
+    supplier_sku: Option<usize>,
+    impa_code: Option<usize>,
+    name: usize,
+    unit: Option<usize>,
+    price: usize,
+    currency: Option<usize>,
+}
+
+fn resolve_mapping(mapping: &PriceListColumnMapping, headers: Option<&[String]>) -> Result<ColumnIndexes> {
+    let optional = |reference: &Option<String>| -> Result<Option<usize>> {
+        match reference.as_deref().map(str::trim) {
+            Some(r) if !r.is_empty() => Ok(Some(resolve_column(r, headers)?)),
+            _ => Ok(None),
+        }
+    };
+
+    Ok(ColumnIndexes {
+        supplier_sku: optional(&mapping.supplier_sku)?,
+        impa_code: optional(&mapping.impa_code)?,
+        name: resolve_column(&mapping.name, headers)?,
+        unit: optional(&mapping.unit)?,
+        price: resolve_column(&mapping.price, headers)?,
+        currency: optional(&mapping.currency)?,
+    })
+}
+
+/// Number as typed in a spreadsheet ("1250", "1.250,50", "1,250.50", "₺ 12,5").
+/// The separator that comes last is the decimal one.
+pub(crate) fn parse_number(value: &str) -> Option<f64> {
+    let value: String = value.chars().filter(|c| c.is_ascii_digit() || *c == '.' || *c == ',' || *c == '-').collect();
+    let normalized = match (value.rfind(','), value.rfind('.')) {
+        (Some(comma), Some(dot)) if comma > dot => value.replace('.', "").replace(',', "."),
+        (Some(_), Some(_)) => value.replace(',', ""),
+        (Some(_), None) => value.replace(',', "."),
+        _ => value,
+    };
+    normalized.parse::<f64>().ok().filter(|p| p.is_finite())
+}
+
+fn sku_key(sku: &str) -> String {
+    sku.trim().to_uppercase()
+}
+
+fn classify(old_price: f64, old_currency: &str, new_price: f64, currency: &str) -> PriceListChangeKind {
+    if old_currency != currency {
+        PriceListChangeKind::CurrencyChanged
+    } else if new_price > old_price + PRICE_EPSILON {
+        PriceListChangeKind::PriceUp
+    } else if new_price < old_price - PRICE_EPSILON {
+        PriceListChangeKind::PriceDown
+    } else {
+        PriceListChangeKind::Unchanged
+    }
+}
+
+/// All of a supplier's items, available or not
+async fn supplier_items(supplier_id: i32) -> Result<Vec<SupplyItem>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct IdRow {
+        id: i32,
+    }
+
+    let ids: Vec<IdRow> = IdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT id FROM supply_items WHERE supplier_id = ? ORDER BY id",
+        vec![Value::Int(Some(supplier_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut items = Vec::with_capacity(ids.len());
+    for row in ids {
+        if let Some(item) = supply_item_service::get_by_id(row.id).await? {
+            items.push(item);
+        }
+    }
+    Ok(items)
+}
+
+/// Read the price list and diff it against the supplier's catalog (nothing is written)
+pub async fn preview(req: &PriceListImportRequest) -> Result<PriceListPreview> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let supplier = supplier_service::get_by_id(req.supplier_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supplier not found"))?;
+    let default_currency = req.currency.trim().to_uppercase();
+    if default_currency.is_empty() && req.mapping.currency.is_none() {
+        anyhow::bail!("Currency is required");
+    }
+
+    let rows = read_rows(&req.file_path, req.sheet_name.as_deref(), req.delimiter.as_deref())?;
+    let header_row = req.header_row.max(0) as usize;
+    let headers = match header_row {
+        0 => None,
+        n => Some(rows.get(n - 1)
+            .ok_or_else(|| anyhow::anyhow!("The file has no row {} for the headers", n))?
+            .as_slice()),
+    };
+    let columns = resolve_mapping(&req.mapping, headers)?;
+    let sections = impa_service::get_known_sections().await?;
+
+    let items = supplier_items(req.supplier_id).await?;
+    let mut by_sku: HashMap<String, &SupplyItem> = HashMap::new();
+    let mut by_impa: HashMap<String, &SupplyItem> = HashMap::new();
+    let mut by_name: HashMap<String, &SupplyItem> = HashMap::new();
+    // Available items win when an older, unavailable item shares a key
+    for item in items.iter().filter(|i| !i.is_available).chain(items.iter().filter(|i| i.is_available)) {
+        if let Some(sku) = item.supplier_sku.as_deref().filter(|s| !s.trim().is_empty()) {
+            by_sku.insert(sku_key(sku), item);
+        }
+        if let Some(code) = item.impa_code.as_deref().filter(|c| !c.trim().is_empty()) {
+            by_impa.insert(normalize_impa(code), item);
+        }
+        by_name.insert(normalize_name(&item.name), item);
+    }
+
+    let cell = |row: &[String], index: Option<usize>| -> Option<String> {
+        index.and_then(|i| row.get(i))
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+    };
+
+    let mut lines: Vec<PriceListChange> = Vec::new();
+    let mut seen: HashMap<i32, i32> = HashMap::new();
+    // New rows by key, so a repeated new item is caught like a repeated catalog match
+    let mut new_by_sku: HashMap<String, i32> = HashMap::new();
+    let mut new_by_impa: HashMap<String, i32> = HashMap::new();
+    let mut new_by_name: HashMap<String, i32> = HashMap::new();
+    for (index, row) in rows.iter().enumerate().skip(header_row) {
+        let row_number = index as i32 + 1;
+        let supplier_sku = cell(row, columns.supplier_sku);
+        let impa_code = cell(row, columns.impa_code);
+        let name = cell(row, Some(columns.name));
+        let price_text = cell(row, Some(columns.price));
+        if supplier_sku.is_none() && impa_code.is_none() && name.is_none() && price_text.is_none() {
+            continue;
+        }
+        let currency = cell(row, columns.currency).map(|c| c.to_uppercase()).unwrap_or_else(|| default_currency.clone());
+        let new_price = price_text.as_deref().and_then(parse_number);
+
+        let impa_code = match impa_code {
+            Some(code) => match impa_service::check_code(&code, &sections) {
+                Ok(code) => Some(code),
+                Err(e) => {
+                    lines.push(PriceListChange {
+                        row_number: Some(row_number),
+                        kind: PriceListChangeKind::Unchanged,
+                        matched_by: None,
+                        supply_item_id: None,
+                        supplier_sku,
+                        impa_code: Some(code),
+                        name: name.unwrap_or_default(),
+                        unit: cell(row, columns.unit),
+                        old_price: None,
+                        old_currency: None,
+                        new_price,
+                        currency: Some(currency),
+                        change_percent: None,
+                        error: Some(e.to_string()),
+                    });
+                    continue;
+                }
+            },
+            None => None,
+        };
+
+        let matched = supplier_sku.as_deref().and_then(|s| by_sku.get(&sku_key(s)).map(|i| (*i, PriceListMatchKind::SupplierSku)))
+            .or_else(|| impa_code.as_deref().and_then(|c| by_impa.get(c).map(|i| (*i, PriceListMatchKind::Impa))))
+            .or_else(|| name.as_deref().and_then(|n| by_name.get(&normalize_name(n)).map(|i| (*i, PriceListMatchKind::Name))));
+
+        let mut error = match (&name, &price_text, new_price) {
+            (None, _, _) if matched.is_none() => Some("No product name".to_string()),
+            (_, None, _) => Some("No price".to_string()),
+            (_, Some(text), None) => Some(format!("Price '{}' is not a number", text)),
+            (_, _, Some(p)) if p < 0.0 => Some("Price cannot be negative".to_string()),
+            _ if currency.is_empty() => Some("No currency".to_string()),
+            _ => None,
+        };
+        if let Some((item, _)) = matched {
+            if let Some(first_row) = seen.insert(item.id, row_number) {
+                error = error.or(Some(format!("Same item as row {}", first_row)));
+            }
+        } else {
+            let sku = supplier_sku.as_deref().map(sku_key);
+            let name_key = name.as_deref().map(normalize_name);
+            let first_row = sku.as_ref().and_then(|k| new_by_sku.get(k))
+                .or_else(|| impa_code.as_ref().and_then(|k| new_by_impa.get(k)))
+                .or_else(|| name_key.as_ref().and_then(|k| new_by_name.get(k)))
+                .copied();
+            match first_row {
+                Some(first_row) => error = error.or(Some(format!("Same item as row {}", first_row))),
+                None => {
+                    if let Some(sku) = sku {
+                        new_by_sku.insert(sku, row_number);
+                    }
+                    if let Some(code) = &impa_code {
+                        new_by_impa.insert(code.clone(), row_number);
+                    }
+                    if let Some(name_key) = name_key {
+                        new_by_name.insert(name_key, row_number);
+                    }
+                }
+            }
+        }
+
+        let (kind, old_price, old_currency, change_percent) = match (matched, new_price) {
+            (Some((item, _)), Some(price)) => {
+                let kind = classify(item.unit_price, &item.currency, price, &currency);
+                let percent = (kind != PriceListChangeKind::CurrencyChanged && item.unit_price > 0.0)
+                    .then(|| ((price - item.unit_price) / item.unit_price * 10000.0).round() / 100.0);
+                (kind, Some(item.unit_price), Some(item.currency.clone()), percent)
+            }
+            (Some((item, _)), None) => (PriceListChangeKind::Unchanged, Some(item.unit_price), Some(item.currency.clone()), None),
+            (None, _) => (PriceListChangeKind::New, None, None, None),
+        };
+
+        lines.push(PriceListChange {
+            row_number: Some(row_number),
+            kind,
+            matched_by: matched.map(|(_, by)| by),
+            supply_item_id: matched.map(|(item, _)| item.id),
+            supplier_sku: supplier_sku.or_else(|| matched.and_then(|(item, _)| item.supplier_sku.clone())),
+            impa_code: impa_code.or_else(|| matched.and_then(|(item, _)| item.impa_code.clone())),
+            name: name.or_else(|| matched.map(|(item, _)| item.name.clone())).unwrap_or_default(),
+            unit: cell(row, columns.unit),
+            old_price,
+            old_currency,
+            new_price,
+            currency: Some(currency),
+            change_percent,
+            error,
+        });
+    }
+
+    // New items need a category: from their IMPA section or the request default
+    for line in lines.iter_mut().filter(|l| l.kind == PriceListChangeKind::New && l.error.is_none()) {
+        let section_category = match line.impa_code.as_deref() {
+            Some(code) => impa_service::find(&conn, code).await?.and_then(|e| e.category_id),
+            None => None,
+        };
+        if section_category.or(req.default_category_id).is_none() {
+            line.error = Some("No category for the new item; set a default category".to_string());
+        }
+    }
+
+    if req.discontinue_missing {
+        for item in items.iter().filter(|i| i.is_available && !seen.contains_key(&i.id)) {
+            lines.push(PriceListChange {
+                row_number: None,
+                kind: PriceListChangeKind::Discontinued,
+                matched_by: None,
+                supply_item_id: Some(item.id),
+                supplier_sku: item.supplier_sku.clone(),
+                impa_code: item.impa_code.clone(),
+                name: item.name.clone(),
+                unit: Some(item.unit.clone()),
+                old_price: Some(item.unit_price),
+                old_currency: Some(item.currency.clone()),
+                new_price: None,
+                currency: None,
+                change_percent: None,
+                error: None,
+            });
+        }
+    }
+
+    let count = |kind: PriceListChangeKind| {
+        lines.iter().filter(|l| l.error.is_none() && l.kind == kind).count() as i32
+    };
+    Ok(PriceListPreview {
+        supplier_id: supplier.id,
+        supplier_name: supplier.name,
+        new_items: count(PriceListChangeKind::New),
+        price_up: count(PriceListChangeKind::PriceUp),
+        price_down: count(PriceListChangeKind::PriceDown),
+        currency_changed: count(PriceListChangeKind::CurrencyChanged),
+        unchanged: count(PriceListChangeKind::Unchanged),
+        discontinued: count(PriceListChangeKind::Discontinued),
+        errors: lines.iter().filter(|l| l.error.is_some()).count() as i32,
+        lines,
+    })
+}
+
+/// Apply a price list, all or nothing. Rows with errors block the import.
+///
+/// Matched items get the new price and become available again; a SKU or IMPA
+/// code the item did not have yet is filled in.
+pub async fn import(req: PriceListImportRequest) -> Result<PriceListImportResult> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let preview = preview(&req).await?;
+    if preview.errors > 0 {
+        let first = preview.lines.iter().find(|l| l.error.is_some())
+            .map(|l| format!("row {}: {}", l.row_number.unwrap_or(0), l.error.clone().unwrap_or_default()))
+            .unwrap_or_default();
+        anyhow::bail!("Price list has {} rows with errors (first: {}); nothing was imported", preview.errors, first);
+    }
+
+    let effective_date = price_history_service::effective_date(req.effective_date.as_deref())?;
+    let reference = Path::new(&req.file_path)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string());
+
+    // Categories of new items are resolved before the transaction
+    let mut new_items = Vec::new();
+    for line in preview.lines.iter().filter(|l| l.kind == PriceListChangeKind::New) {
+        let section_category = match line.impa_code.as_deref() {
+            Some(code) => impa_service::find(&conn, code).await?,
+            None => None,
+        };
+        let category_id = section_category.as_ref().and_then(|e| e.category_id)
+            .or(req.default_category_id)
+            .ok_or_else(|| anyhow::anyhow!("No category for new item {}", line.name))?;
+        let category = category_service::active_code(&conn, category_id).await?;
+        let unit = line.unit.clone()
+            .or_else(|| section_category.and_then(|e| e.unit))
+            .unwrap_or_else(|| "ADET".to_string());
+        new_items.push((CreateSupplyItemRequest {
+            supplier_id: req.supplier_id,
+            impa_code: line.impa_code.clone(),
+            supplier_sku: line.supplier_sku.clone(),
+            name: line.name.clone(),
+            description: None,
+            category_id: Some(category_id),
+            unit,
+            unit_price: line.new_price.unwrap_or(0.0),
+            currency: line.currency.clone().unwrap_or_else(|| req.currency.trim().to_uppercase()),
+            minimum_order_quantity: None,
+            un_number: None,
+            hazard_class: None,
+            sds_required: false,
+            prescription_only: false,
+            is_bonded: false,
+        }, category));
+    }
+
+    let txn = conn.begin().await?;
+    let mut result = PriceListImportResult { created: 0, updated: 0, unchanged: 0, discontinued: 0 };
+
+    for (item, category) in &new_items {
+        supply_item_service::insert(&txn, item, category, PriceChangeSource::PriceListImport, &effective_date, reference.as_deref()).await?;
+        result.created += 1;
+    }
+
+    for line in &preview.lines {
+        let Some(supply_item_id) = line.supply_item_id else { continue };
+        match line.kind {
+            PriceListChangeKind::New => {}
+            PriceListChangeKind::Discontinued => {
+                txn.execute(Statement::from_sql_and_values(
+                    DatabaseBackend::Sqlite,
+                    "UPDATE supply_items SET is_available = 0, updated_at = datetime('now') WHERE id = ?",
+                    vec![Value::Int(Some(supply_item_id))]
+                )).await?;
+                result.discontinued += 1;
+            }
+            kind => {
+                let (Some(new_price), Some(currency)) = (line.new_price, line.currency.as_deref()) else { continue };
+                txn.execute(Statement::from_sql_and_values(
+                    DatabaseBackend::Sqlite,
+                    r#"
+                    UPDATE supply_items SET unit_price = ?, currency = ?, is_available = 1,
+                        supplier_sku = COALESCE(supplier_sku, ?), impa_code = COALESCE(impa_code, ?),
+                        updated_at = datetime('now')
+                    WHERE id = ?
+                    "#,
+                    vec![
+                        Value::Double(Some(new_price)),
+                        Value::String(Some(Box::new(currency.to_string()))),
+                        Value::String(line.supplier_sku.clone().map(Box::new)),
+                        Value::String(line.impa_code.clone().map(Box::new)),
+                        Value::Int(Some(supply_item_id)),
+                    ]
+                )).await?;
+                if kind == PriceListChangeKind::Unchanged {
+                    result.unchanged += 1;
+                    continue;
+                }
+                let old = line.old_price.zip(line.old_currency.as_deref());
+                price_history_service::record(
+                    &txn,
+                    supply_item_id,
+                    old,
+                    (new_price, currency),
+                    PriceChangeSource::PriceListImport,
+                    &effective_date,
+                    reference.as_deref(),
+                ).await?;
+                result.updated += 1;
+            }
+        }
+    }
+
+    txn.commit().await?;
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_number_takes_the_last_separator_as_decimal() {
+        assert_eq!(parse_number("1,200.5"), Some(1200.5));
+        assert_eq!(parse_number("1.200,5"), Some(1200.5));
+        assert_eq!(parse_number("12,5"), Some(12.5));
+        assert_eq!(parse_number("12.5"), Some(12.5));
+        assert_eq!(parse_number("₺ 1.250,50"), Some(1250.5));
+        assert_eq!(parse_number("abc"), None);
+    }
+}
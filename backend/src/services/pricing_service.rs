@@ -0,0 +1,608 @@
+//! Pricing Service - Rule-based selling price proposals (markups, margin floors, rounding)
+
+use crate::models::{
+    PricingRule, CreatePricingRuleRequest, UpdatePricingRuleRequest, CurrencyRounding,
+    PriceSource, PriceProposal, RepricedItem, RepriceResult, Order, OrderStatus,
+};
+use crate::database;
+use crate::services::{customer_service, supplier_service, order_service, order_item_service, price_agreement_service, category_service};
+use crate::services::invoice_service::AMOUNT_EPSILON;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+/// Rounding used for currencies without an entry in `currency_rounding`
+const DEFAULT_ROUNDING_INCREMENT: f64 = 0.01;
+
+#[derive(Debug, FromQueryResult)]
+struct RuleRow {
+    id: i32,
+    name: String,
+    category_id: Option<i32>,
+    category: Option<String>,
+    supplier_id: Option<i32>,
+    supplier_name: Option<String>,
+    customer_id: Option<i32>,
+    customer_name: Option<String>,
+    markup_percent: Option<f64>,
+    min_margin_percent: Option<f64>,
+    notes: Option<String>,
+    is_active: i32,
+    created_at: String,
+    updated_at: String,
+}
+
+impl From<RuleRow> for PricingRule {
+    fn from(row: RuleRow) -> Self {
+        PricingRule {
+            id: row.id,
+            name: row.name,
+            category_id: row.category_id,
+            category: row.category,
+            supplier_id: row.supplier_id,
+            supplier_name: row.supplier_name,
+            customer_id: row.customer_id,
+            customer_name: row.customer_name,
+            markup_percent: row.markup_percent,
+            min_margin_percent: row.min_margin_percent,
+            notes: row.notes,
+            is_active: row.is_active == 1,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    r.id, r.name, r.category_id, COALESCE(cat.code, r.category) as category, r.supplier_id, s.name as supplier_name, r.customer_id, c.legal_name as customer_name,
+    r.markup_percent, r.min_margin_percent, r.notes, r.is_active, r.created_at, r.updated_at
+"#;
+
+const FROM_JOIN: &str = r#"
+    FROM pricing_rules r
+    LEFT JOIN suppliers s ON r.supplier_id = s.id
+    LEFT JOIN customers c ON r.customer_id = c.id
+    LEFT JOIN categories cat ON r.category_id = cat.id
+"#;
+
+/// Most specific rules first: customer (4) > supplier (2) > category (1)
+const SPECIFICITY_ORDER: &str =
+    "((r.customer_id IS NOT NULL) * 4 + (r.supplier_id IS NOT NULL) * 2 + (r.category_id IS NOT NULL)) DESC";
+
+fn validate_percentages(markup_percent: Option<f64>, min_margin_percent: Option<f64>) -> Result<()> {
+    if markup_percent.is_none() && min_margin_percent.is_none() {
+        anyhow::bail!("Pricing rule needs a markup or a minimum margin");
+    }
+    if let Some(markup) = markup_percent {
+        if markup <= -100.0 {
+            anyhow::bail!("Markup must be greater than -100%");
+        }
+    }
+    if let Some(floor) = min_margin_percent {
+        if !(0.0..100.0).contains(&floor) {
+            anyhow::bail!("Minimum margin must be between 0% and 100%");
+        }
+    }
+    Ok(())
+}
+
+fn source_of(rule: &PricingRule) -> PriceSource {
+    if rule.customer_id.is_some() {
+        PriceSource::CustomerRule
+    } else if rule.supplier_id.is_some() {
+        PriceSource::SupplierRule
+    } else if rule.category_id.is_some() {
+        PriceSource::CategoryRule
+    } else {
+        PriceSource::DefaultRule
+    }
+}
+
+/// "rule 'X' (customer A, category B)" for explanations
+fn describe_rule(rule: &PricingRule) -> String {
+    let mut scope = Vec::new();
+    if let Some(customer_id) = rule.customer_id {
+        scope.push(format!("customer {}", rule.customer_name.clone().unwrap_or_else(|| format!("#{}", customer_id))));
+    }
+    if let Some(supplier_id) = rule.supplier_id {
+        scope.push(format!("supplier {}", rule.supplier_name.clone().unwrap_or_else(|| format!("#{}", supplier_id))));
+    }
+    if let Some(category) = &rule.category {
+        scope.push(format!("category {}", category));
+    }
+    if scope.is_empty() {
+        format!("rule '{}' (default)", rule.name)
+    } else {
+        format!("rule '{}' ({})", rule.name, scope.join(", "))
+    }
+}
+
+/// Round a price up to the next multiple of `increment` (cents precision)
+fn round_up(price: f64, increment: f64) -> f64 {
+    let rounded = (price / increment - 1e-9).ceil() * increment;
+    (rounded * 100.0).round() / 100.0
+}
+
+fn margin_of(buying_price: f64, selling_price: f64) -> Option<f64> {
+    if selling_price > 0.0 {
+        Some(((selling_price - buying_price) / selling_price) * 100.0)
+    } else {
+        None
+    }
+}
+
+/// Get all pricing rules, most specific first
+pub async fn get_rules() -> Result<Vec<PricingRule>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<RuleRow> = RuleRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} ORDER BY {}, r.id DESC", SELECT_FIELDS, FROM_JOIN, SPECIFICITY_ORDER)
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(PricingRule::from).collect())
+}
+
+/// Get a pricing rule by ID
+pub async fn get_rule_by_id(id: i32) -> Result<Option<PricingRule>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<RuleRow> = RuleRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE r.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    Ok(row.map(PricingRule::from))
+}
+
+/// Create a pricing rule
+pub async fn create_rule(req: CreatePricingRuleRequest) -> Result<PricingRule> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    if req.name.trim().is_empty() {
+        anyhow::bail!("Rule name is required");
+    }
+    validate_percentages(req.markup_percent, req.min_margin_percent)?;
+    if let Some(supplier_id) = req.supplier_id {
+        supplier_service::get_by_id(supplier_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Supplier not found"))?;
+    }
+    if let Some(customer_id) = req.customer_id {
+        customer_service::get_by_id(customer_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Customer not found"))?;
+    }
+    let category = match req.category_id {
+        Some(category_id) => Some(category_service::active_code(&conn, category_id).await?),
+        None => None,
+    };
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO pricing_rules (name, category, category_id, supplier_id, customer_id, markup_percent, min_margin_percent, notes)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(req.name.trim().to_string()))),
+            Value::String(category.map(Box::new)),
+            Value::Int(req.category_id),
+            Value::Int(req.supplier_id),
+            Value::Int(req.customer_id),
+            Value::Double(req.markup_percent),
+            Value::Double(req.min_margin_percent),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    get_rule_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created pricing rule"))
+}
+
+/// Update a pricing rule (the scope of a rule cannot change)
+pub async fn update_rule(id: i32, req: UpdatePricingRuleRequest) -> Result<PricingRule> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let existing = get_rule_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Pricing rule not found"))?;
+
+    let markup_percent = req.markup_percent.or(existing.markup_percent);
+    let min_margin_percent = req.min_margin_percent.or(existing.min_margin_percent);
+    validate_percentages(markup_percent, min_margin_percent)?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE pricing_rules SET name = ?, markup_percent = ?, min_margin_percent = ?, notes = ?, is_active = ?, updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::String(Some(Box::new(req.name.unwrap_or(existing.name)))),
+            Value::Double(markup_percent),
+            Value::Double(min_margin_percent),
+            Value::String(req.notes.or(existing.notes).map(Box::new)),
+            Value::Int(Some(req.is_active.unwrap_or(existing.is_active) as i32)),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    get_rule_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve updated pricing rule"))
+}
+
+/// Delete a pricing rule
+pub async fn delete_rule(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM pricing_rules WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Get the rounding configured per currency
+pub async fn get_roundings() -> Result<Vec<CurrencyRounding>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct RoundingRow {
+        currency: String,
+        increment: f64,
+    }
+
+    let rows: Vec<RoundingRow> = RoundingRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT currency, increment FROM currency_rounding ORDER BY currency".to_string()
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| CurrencyRounding { currency: r.currency, increment: r.increment }).collect())
+}
+
+/// Set the rounding increment of a currency (e.g. 0.5 TRY)
+pub async fn set_rounding(currency: &str, increment: f64) -> Result<CurrencyRounding> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let currency = currency.trim().to_uppercase();
+    if currency.is_empty() {
+        anyhow::bail!("Currency is required");
+    }
+    if increment < 0.01 {
+        anyhow::bail!("Rounding increment must be at least 0.01");
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO currency_rounding (currency, increment) VALUES (?, ?)
+        ON CONFLICT(currency) DO UPDATE SET increment = excluded.increment
+        "#,
+        vec![
+            Value::String(Some(Box::new(currency.clone()))),
+            Value::Double(Some(increment)),
+        ]
+    )).await?;
+
+    Ok(CurrencyRounding { currency, increment })
+}
+
+async fn rounding_increment<C: ConnectionTrait>(conn: &C, currency: &str) -> Result<f64> {
+    #[derive(Debug, FromQueryResult)]
+    struct IncrementRow {
+        increment: f64,
+    }
+
+    let row: Option<IncrementRow> = IncrementRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT increment FROM currency_rounding WHERE currency = ?",
+        vec![Value::String(Some(Box::new(currency.to_string())))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.increment).unwrap_or(DEFAULT_ROUNDING_INCREMENT))
+}
+
+/// Markup and margin floor rules that apply to an item, most specific first.
+///
+/// Category and supplier come from the catalog entry with the same IMPA code;
+/// the entry whose price equals the buying price is preferred. A category rule
+/// covers subcategories; among rules of the same kind the nearest category wins.
+async fn matching_rules<C: ConnectionTrait>(
+    conn: &C,
+    customer_id: Option<i32>,
+    impa_code: Option<&str>,
+    buying_price: f64,
+) -> Result<(Option<PricingRule>, Option<PricingRule>)> {
+    #[derive(Debug, FromQueryResult)]
+    struct CatalogRow {
+        supplier_id: i32,
+        category_id: Option<i32>,
+    }
+
+    let impa_code = impa_code.map(price_agreement_service::normalize_impa).filter(|c| !c.is_empty());
+    let catalog: Option<CatalogRow> = match impa_code {
+        Some(impa_code) => CatalogRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            SELECT supplier_id, category_id FROM supply_items
+            WHERE replace(impa_code, ' ', '') = ?
+            ORDER BY (abs(unit_price - ?) < 0.005) DESC, is_available DESC, id
+            LIMIT 1
+            "#,
+            vec![Value::String(Some(Box::new(impa_code))), Value::Double(Some(buying_price))]
+        ))
+        .one(conn)
+        .await?,
+        None => None,
+    };
+
+    let rows: Vec<RuleRow> = RuleRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            r#"
+            WITH RECURSIVE ancestors(id, distance) AS (
+                SELECT id, 0 FROM categories WHERE id = ?
+                UNION
+                SELECT c.parent_id, a.distance + 1 FROM categories c JOIN ancestors a ON c.id = a.id
+                WHERE c.parent_id IS NOT NULL
+            )
+            SELECT {} {}
+            LEFT JOIN ancestors a ON a.id = r.category_id
+            WHERE r.is_active = 1
+              AND (r.category_id IS NULL OR a.id IS NOT NULL)
+              AND (r.supplier_id IS NULL OR r.supplier_id = ?)
+              AND (r.customer_id IS NULL OR r.customer_id = ?)
+            ORDER BY {}, a.distance, r.id DESC
+            "#,
+            SELECT_FIELDS, FROM_JOIN, SPECIFICITY_ORDER
+        ),
+        vec![
+            Value::Int(catalog.as_ref().and_then(|c| c.category_id)),
+            Value::Int(catalog.as_ref().map(|c| c.supplier_id)),
+            Value::Int(customer_id),
+        ]
+    ))
+    .all(conn)
+    .await?;
+
+    let rules: Vec<PricingRule> = rows.into_iter().map(PricingRule::from).collect();
+    let markup_rule = rules.iter().find(|r| r.markup_percent.is_some()).cloned();
+    let floor_rule = rules.into_iter().find(|r| r.min_margin_percent.is_some());
+    Ok((markup_rule, floor_rule))
+}
+
+/// Minimum margin the rules require for an item on an order
+pub(crate) async fn find_margin_floor<C: ConnectionTrait>(
+    conn: &C,
+    order: &Order,
+    impa_code: Option<&str>,
+    buying_price: f64,
+) -> Result<Option<f64>> {
+    let (_, floor_rule) = matching_rules(conn, order.customer_id, impa_code, buying_price).await?;
+    Ok(floor_rule.and_then(|r| r.min_margin_percent))
+}
+
+/// Propose a selling price for an item on an order.
+///
+/// A customer price agreement wins over the rules. Otherwise the markup of the
+/// most specific rule is applied, raised to the margin floor when it falls
+/// short, and rounded up to the currency's rounding increment.
+pub(crate) async fn propose_for_order<C: ConnectionTrait>(
+    conn: &C,
+    order: &Order,
+    impa_code: Option<&str>,
+    buying_price: f64,
+    currency: &str,
+) -> Result<PriceProposal> {
+    let (markup_rule, floor_rule) = matching_rules(conn, order.customer_id, impa_code, buying_price).await?;
+    let min_margin_percent = floor_rule.as_ref().and_then(|r| r.min_margin_percent);
+    let increment = rounding_increment(conn, currency).await?;
+
+    let contract = match impa_code {
+        Some(impa_code) if currency == order.currency => {
+            price_agreement_service::find_contract_price_for_order(conn, order, impa_code, buying_price).await?
+        }
+        _ => None,
+    };
+
+    if let Some(contract) = contract {
+        let margin_percent = margin_of(buying_price, contract.selling_price);
+        let below_margin_floor = match (min_margin_percent, margin_percent) {
+            (Some(floor), Some(margin)) => margin < floor - 1e-9,
+            (Some(_), None) => buying_price > 0.0,
+            (None, _) => false,
+        };
+        let mut explanation = format!(
+            "Contract price from agreement '{}' ({} line)",
+            contract.agreement_name,
+            if contract.is_group_price { "IMPA group" } else { "item" }
+        );
+        if below_margin_floor {
+            if let (Some(rule), Some(floor)) = (&floor_rule, min_margin_percent) {
+                explanation.push_str(&format!("; below the {:.1}% margin floor of {}", floor, describe_rule(rule)));
+            }
+        }
+        return Ok(PriceProposal {
+            buying_price,
+            selling_price: contract.selling_price,
+            currency: currency.to_string(),
+            source: PriceSource::Contract,
+            rule_id: None,
+            agreement_id: Some(contract.agreement_id),
+            markup_percent: None,
+            min_margin_percent,
+            margin_percent,
+            floor_applied: false,
+            below_margin_floor,
+            rounding_increment: increment,
+            explanation,
+        });
+    }
+
+    let floor_price = min_margin_percent.map(|floor| buying_price / (1.0 - floor / 100.0));
+
+    let (source, rule_id, markup_percent, raw_price, floor_applied, mut explanation) = match (&markup_rule, floor_price) {
+        (Some(rule), _) => {
+            let markup = rule.markup_percent.unwrap_or(0.0);
+            let markup_price = buying_price * (1.0 + markup / 100.0);
+            let explanation = format!("Markup {:.1}% from {}", markup, describe_rule(rule));
+            match floor_price {
+                Some(floor_price) if markup_price < floor_price - 1e-9 => {
+                    (source_of(rule), Some(rule.id), Some(markup), floor_price, true, explanation)
+                }
+                _ => (source_of(rule), Some(rule.id), Some(markup), markup_price, false, explanation),
+            }
+        }
+        (None, Some(floor_price)) => (
+            PriceSource::MarginFloor,
+            floor_rule.as_ref().map(|r| r.id),
+            None,
+            floor_price,
+            false,
+            "No markup rule; priced at the margin floor".to_string(),
+        ),
+        (None, None) => {
+            return Ok(PriceProposal {
+                buying_price,
+                selling_price: 0.0,
+                currency: currency.to_string(),
+                source: PriceSource::NoRule,
+                rule_id: None,
+                agreement_id: None,
+                markup_percent: None,
+                min_margin_percent: None,
+                margin_percent: None,
+                floor_applied: false,
+                below_margin_floor: false,
+                rounding_increment: increment,
+                explanation: "No price agreement or pricing rule covers this item".to_string(),
+            });
+        }
+    };
+
+    if let (Some(rule), Some(floor)) = (&floor_rule, min_margin_percent) {
+        if floor_applied {
+            explanation.push_str(&format!("; raised to the {:.1}% margin floor of {}", floor, describe_rule(rule)));
+        } else if source == PriceSource::MarginFloor {
+            explanation.push_str(&format!(" ({:.1}% from {})", floor, describe_rule(rule)));
+        }
+    }
+
+    let selling_price = round_up(raw_price, increment);
+    if (selling_price - raw_price).abs() > 1e-9 {
+        explanation.push_str(&format!("; rounded up to {} {}", increment, currency));
+    }
+
+    Ok(PriceProposal {
+        buying_price,
+        selling_price,
+        currency: currency.to_string(),
+        source,
+        rule_id,
+        agreement_id: None,
+        markup_percent,
+        min_margin_percent,
+        margin_percent: margin_of(buying_price, selling_price),
+        floor_applied,
+        below_margin_floor: false,
+        rounding_increment: increment,
+        explanation,
+    })
+}
+
+/// Propose a selling price for an item on an order (in the order's currency)
+pub async fn propose_selling_price(order_id: i32, impa_code: Option<&str>, buying_price: f64) -> Result<PriceProposal> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    propose_for_order(&conn, &order, impa_code, buying_price, &order.currency).await
+}
+
+/// Re-price every line of an order with the pricing engine.
+///
+/// Lines no agreement or rule covers keep their price. Invoiced and cancelled
+/// orders cannot be repriced.
+pub async fn reprice_order(order_id: i32) -> Result<RepriceResult> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+
+    if matches!(order.status, OrderStatus::Invoiced | OrderStatus::Cancelled) {
+        anyhow::bail!("Order {} is {} and cannot be repriced", order.order_number, order.status.display_name());
+    }
+
+    let items = order_item_service::get_by_order_id(order_id).await?;
+
+    let txn = conn.begin().await?;
+
+    let mut result = RepriceResult {
+        order_id,
+        updated_count: 0,
+        unchanged_count: 0,
+        skipped_count: 0,
+        items: Vec::with_capacity(items.len()),
+    };
+
+    for item in items {
+        let proposal = propose_for_order(&txn, &order, item.impa_code.as_deref(), item.buying_price, &item.currency).await?;
+
+        let new_selling_price = if proposal.source == PriceSource::NoRule {
+            result.skipped_count += 1;
+            item.selling_price
+        } else if (proposal.selling_price - item.selling_price).abs() <= AMOUNT_EPSILON {
+            result.unchanged_count += 1;
+            item.selling_price
+        } else {
+            txn.execute(Statement::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                "UPDATE order_items SET selling_price = ?, updated_at = datetime('now') WHERE id = ?",
+                vec![Value::Double(Some(proposal.selling_price)), Value::Int(Some(item.id))]
+            )).await?;
+            result.updated_count += 1;
+            proposal.selling_price
+        };
+
+        result.items.push(RepricedItem {
+            order_item_id: item.id,
+            product_name: item.product_name,
+            impa_code: item.impa_code,
+            old_selling_price: item.selling_price,
+            new_selling_price,
+            proposal,
+        });
+    }
+
+    txn.commit().await?;
+
+    Ok(result)
+}
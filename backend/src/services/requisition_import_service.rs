@@ -0,0 +1,384 @@
+//! Requisition Import Service - Ship requisitions from CSV/XLSX files
+//!
+//! Importing is two steps: `preview` reads the file and proposes a supply item
+//! for every line without writing anything; `commit` turns the lines the
+//! operator confirmed into order items in one transaction.
+
+use crate::models::{
+    RequisitionImportRequest, RequisitionImportPreview, RequisitionImportLine, RequisitionMatchKind,
+    RequisitionColumnMapping, SupplyItem, OrderStatus, OrderWithItems, CreateOrderItemRequest, DeliveryType,
+};
+use crate::database;
+use crate::services::{order_service, order_item_service, supply_item_service, price_agreement_service, price_list_service, impa_service};
+use crate::services::order_item_service::QUANTITY_EPSILON;
+use anyhow::Result;
+use calamine::{open_workbook_auto, Reader};
+use sea_orm::TransactionTrait;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Minimum name similarity (Sørensen-Dice on normalized names) for a fuzzy match
+const FUZZY_MATCH_THRESHOLD: f64 = 0.6;
+
+/// Read the raw cells of a CSV or spreadsheet file (one Vec per row)
+pub(crate) fn read_rows(file_path: &str, sheet_name: Option<&str>, delimiter: Option<&str>) -> Result<Vec<Vec<String>>> {
+    let path = Path::new(file_path);
+    let extension = path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "csv" | "txt" => read_csv(path, delimiter),
+        "xlsx" | "xlsm" | "xls" | "ods" => read_workbook(path, sheet_name),
+        _ => anyhow::bail!("Unsupported file type: {}", file_path),
+    }
+}
+
+fn read_csv(path: &Path, delimiter: Option<&str>) -> Result<Vec<Vec<String>>> {
+    let content = std::fs::read(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read {}: {}", path.display(), e))?;
+    let content = String::from_utf8_lossy(&content);
+    let content = content.trim_start_matches('\u{feff}');
+
+    // Excel with a Turkish locale saves CSV with ';'
+    let delimiter = match delimiter.and_then(|d| d.bytes().next()) {
+        Some(d) => d,
+        None => {
+            let first_line = content.lines().next().unwrap_or_default();
+            if first_line.matches(';').count() > first_line.matches(',').count() { b';' } else { b',' }
+        }
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(content.as_bytes());
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        rows.push(record.iter().map(|c| c.trim().to_string()).collect());
+    }
+    Ok(rows)
+}
+
+fn read_workbook(path: &Path, sheet_name: Option<&str>) -> Result<Vec<Vec<String>>> {
+    let mut workbook = open_workbook_auto(path)
+        .map_err(|e| anyhow::anyhow!("Cannot open {}: {}", path.display(), e))?;
+
+    let sheet = match sheet_name {
+        Some(name) => name.to_string(),
+        None => workbook.sheet_names().first().cloned()
+            .ok_or_else(|| anyhow::anyhow!("Workbook has no sheets"))?,
+    };
+    let range = workbook.worksheet_range(&sheet)
+        .map_err(|e| anyhow::anyhow!("Cannot read sheet {}: {}", sheet, e))?;
+
+    // Rows are padded from A1 so column letters match what the operator sees
+    let (first_row, first_col) = range.start().unwrap_or((0, 0));
+    let mut rows = vec![Vec::new(); first_row as usize];
+    for row in range.rows() {
+        let mut cells = vec![String::new(); first_col as usize];
+        cells.extend(row.iter().map(|c| c.to_string().trim().to_string()));
+        rows.push(cells);
+    }
+    Ok(rows)
+}
+
+/// Column index from a letter reference ("A" = 0, "AA" = 26)
+fn column_from_letters(reference: &str) -> Option<usize> {
+    if reference.is_empty() || reference.len() > 3 || !reference.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let index = reference.to_ascii_uppercase()
+        .bytes()
+        .fold(0usize, |acc, b| acc * 26 + (b - b'A' + 1) as usize);
+    Some(index - 1)
+}
+
+/// Resolve a mapped column by header text first, then by letter
+pub(crate) fn resolve_column(reference: &str, headers: Option<&[String]>) -> Result<usize> {
+    let reference = reference.trim();
+    if let Some(headers) = headers {
+        if let Some(index) = headers.iter().position(|h| h.trim().eq_ignore_ascii_case(reference)) {
+            return Ok(index);
+        }
+    }
+    column_from_letters(reference)
+        .ok_or_else(|| anyhow::anyhow!("Column '{}' not found in the file", reference))
+}
+
+struct ColumnIndexes {
+    impa_code: Option<usize>,
+    description: usize,
+    quantity: usize,
+    unit: Option<usize>,
+    notes: Option<usize>,
+}
+
+fn resolve_mapping(mapping: &RequisitionColumnMapping, headers: Option<&[String]>) -> Result<ColumnIndexes> {
+    let optional = |reference: &Option<String>| -> Result<Option<usize>> {
+        match reference.as_deref().map(str::trim) {
+            Some(r) if !r.is_empty() => Ok(Some(resolve_column(r, headers)?)),
+            _ => Ok(None),
+        }
+    };
+
+    Ok(ColumnIndexes {
+        impa_code: optional(&mapping.impa_code)?,
+        description: resolve_column(&mapping.description, headers)?,
+        quantity: resolve_column(&mapping.quantity, headers)?,
+        unit: optional(&mapping.unit)?,
+        notes: optional(&mapping.notes)?,
+    })
+}
+
+/// Lowercase, fold Turkish letters and drop punctuation for name comparison
+pub(crate) fn normalize_name(name: &str) -> String {
+    let folded: String = name.chars()
+        .flat_map(|c| if c == 'İ' { 'i'.to_lowercase() } else { c.to_lowercase() })
+        .map(|c| match c {
+            'ç' => 'c',
+            'ğ' => 'g',
+            'ı' => 'i',
+            'ö' => 'o',
+            'ş' => 's',
+            'ü' => 'u',
+            'â' => 'a',
+            'î' => 'i',
+            'û' => 'u',
+            c if c.is_alphanumeric() => c,
+            _ => ' ',
+        })
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Best supply item for an IMPA code: available first, then in the order currency, then cheapest
+fn best_impa_match<'a>(items: &[&'a SupplyItem], currency: &str) -> Option<&'a SupplyItem> {
+    items.iter()
+        .copied()
+        .min_by(|a, b| {
+            (!a.is_available, a.currency != currency)
+                .cmp(&(!b.is_available, b.currency != currency))
+                .then(a.unit_price.total_cmp(&b.unit_price))
+        })
+}
+
+fn match_line(
+    line: &mut RequisitionImportLine,
+    by_impa: &HashMap<String, Vec<&SupplyItem>>,
+    names: &[(String, &SupplyItem)],
+    currency: &str,
+) {
+    if let Some(impa_code) = line.impa_code.as_deref() {
+        if let Some(item) = by_impa.get(&price_agreement_service::normalize_impa(impa_code))
+            .and_then(|items| best_impa_match(items, currency))
+        {
+            line.match_kind = RequisitionMatchKind::Impa;
+            line.supply_item_id = Some(item.id);
+            line.supply_item_name = Some(item.name.clone());
+            line.match_score = 1.0;
+            return;
+        }
+    }
+
+    let wanted = normalize_name(&line.description);
+    let best = names.iter()
+        .map(|(name, item)| (strsim::sorensen_dice(&wanted, name), *item))
+        .max_by(|a, b| a.0.total_cmp(&b.0).then(a.1.is_available.cmp(&b.1.is_available)));
+
+    if let Some((score, item)) = best.filter(|(score, _)| *score >= FUZZY_MATCH_THRESHOLD) {
+        line.match_kind = RequisitionMatchKind::FuzzyName;
+        line.supply_item_id = Some(item.id);
+        line.supply_item_name = Some(item.name.clone());
+        line.match_score = (score * 100.0).round() / 100.0;
+    }
+}
+
+/// Requisitions go into orders that are not yet packed for the ship
+fn check_importable(status: OrderStatus, order_number: &str) -> Result<()> {
+    if matches!(status, OrderStatus::Prepared | OrderStatus::OnWay | OrderStatus::Delivered | OrderStatus::Invoiced | OrderStatus::Cancelled) {
+        anyhow::bail!("Order {} is {}; requisitions cannot be imported into it", order_number, status.display_name());
+    }
+    Ok(())
+}
+
+/// Read a requisition file and match its lines to supply items (nothing is written)
+pub async fn preview(req: RequisitionImportRequest) -> Result<RequisitionImportPreview> {
+    let order = order_service::get_by_id(req.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    check_importable(order.status, &order.order_number)?;
+
+    let rows = read_rows(&req.file_path, req.sheet_name.as_deref(), req.delimiter.as_deref())?;
+    let header_row = req.header_row.max(0) as usize;
+    let headers = match header_row {
+        0 => None,
+        n => Some(rows.get(n - 1)
+            .ok_or_else(|| anyhow::anyhow!("The file has no row {} for the headers", n))?
+            .as_slice()),
+    };
+    let columns = resolve_mapping(&req.mapping, headers)?;
+
+    let sections = impa_service::get_known_sections().await?;
+    let supply_items = supply_item_service::get_all().await?;
+    let mut by_impa: HashMap<String, Vec<&SupplyItem>> = HashMap::new();
+    for item in &supply_items {
+        if let Some(impa_code) = item.impa_code.as_deref().filter(|c| !c.trim().is_empty()) {
+            by_impa.entry(price_agreement_service::normalize_impa(impa_code)).or_default().push(item);
+        }
+    }
+    let names: Vec<(String, &SupplyItem)> = supply_items.iter()
+        .map(|item| (normalize_name(&item.name), item))
+        .collect();
+
+    let cell = |row: &[String], index: Option<usize>| -> Option<String> {
+        index.and_then(|i| row.get(i))
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+    };
+
+    let mut lines = Vec::new();
+    for (index, row) in rows.iter().enumerate().skip(header_row) {
+        let impa_code = cell(row, columns.impa_code);
+        let description = cell(row, Some(columns.description));
+        let quantity_text = cell(row, Some(columns.quantity));
+        if impa_code.is_none() && description.is_none() && quantity_text.is_none() {
+            continue;
+        }
+
+        let quantity = quantity_text.as_deref().and_then(price_list_service::parse_number);
+        let impa_error = impa_code.as_deref().and_then(|code| impa_service::check_code(code, &sections).err());
+        let error = match (&description, &quantity_text, quantity) {
+            (None, _, _) if impa_code.is_none() => Some("No description or IMPA code".to_string()),
+            _ if impa_error.is_some() => impa_error.map(|e| e.to_string()),
+            (_, None, _) => Some("No quantity".to_string()),
+            (_, Some(text), None) => Some(format!("Quantity '{}' is not a number", text)),
+            (_, _, Some(q)) if q <= QUANTITY_EPSILON => Some("Quantity must be positive".to_string()),
+            _ => None,
+        };
+
+        let mut line = RequisitionImportLine {
+            row_number: index as i32 + 1,
+            description: description.or_else(|| impa_code.clone()).unwrap_or_default(),
+            impa_code,
+            quantity: quantity.unwrap_or(0.0),
+            unit: cell(row, columns.unit).unwrap_or_default(),
+            notes: cell(row, columns.notes),
+            match_kind: RequisitionMatchKind::Unmatched,
+            supply_item_id: None,
+            supply_item_name: None,
+            match_score: 0.0,
+            include: false,
+            error,
+        };
+        if line.error.is_none() {
+            match_line(&mut line, &by_impa, &names, &order.currency);
+            line.include = line.match_kind != RequisitionMatchKind::Unmatched;
+        }
+        lines.push(line);
+    }
+
+    let count = |kind: RequisitionMatchKind| {
+        lines.iter().filter(|l| l.error.is_none() && l.match_kind == kind).count() as i32
+    };
+    let impa_matched = count(RequisitionMatchKind::Impa);
+    let fuzzy_matched = count(RequisitionMatchKind::FuzzyName);
+    let unmatched = count(RequisitionMatchKind::Unmatched);
+    let errors = lines.iter().filter(|l| l.error.is_some()).count() as i32;
+
+    Ok(RequisitionImportPreview {
+        order_id: order.id,
+        order_number: order.order_number,
+        lines,
+        impa_matched,
+        fuzzy_matched,
+        unmatched,
+        errors,
+    })
+}
+
+/// Create order items from the confirmed preview lines, all or nothing.
+///
+/// Matched lines take the supply item's name and IMPA code (the ship's wording
+/// is kept as description); unmatched lines are imported as written. Buying
+/// prices come from the current supply items, selling prices from the pricing engine.
+/// IMPA codes are checked again, as the lines may have been edited since the preview.
+pub async fn commit(order_id: i32, lines: Vec<RequisitionImportLine>) -> Result<OrderWithItems> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    check_importable(order.status, &order.order_number)?;
+
+    let mut lines: Vec<RequisitionImportLine> = lines.into_iter()
+        .filter(|l| l.include && l.error.is_none())
+        .collect();
+    if lines.is_empty() {
+        anyhow::bail!("No requisition lines selected for import");
+    }
+
+    let mut matched = HashMap::new();
+    for line in &mut lines {
+        if line.quantity <= QUANTITY_EPSILON {
+            anyhow::bail!("Row {}: quantity must be positive", line.row_number);
+        }
+        line.impa_code = impa_service::validate_optional(&conn, line.impa_code.take()).await
+            .map_err(|e| anyhow::anyhow!("Row {}: {}", line.row_number, e))?;
+        if let Some(supply_item_id) = line.supply_item_id {
+            let item = supply_item_service::get_by_id(supply_item_id).await?
+                .ok_or_else(|| anyhow::anyhow!("Row {}: supply item {} not found", line.row_number, supply_item_id))?;
+            matched.insert(supply_item_id, item);
+        }
+    }
+
+    let txn = conn.begin().await?;
+
+    for line in lines {
+        let supply_item = line.supply_item_id.and_then(|id| matched.get(&id));
+
+        let impa_code = supply_item.and_then(|s| s.impa_code.clone()).or(line.impa_code);
+        let current_price = match impa_code.as_deref() {
+            Some(impa_code) => supply_item_service::current_buying_price(&txn, impa_code, &order.currency).await?,
+            None => None,
+        };
+        let buying_price = current_price
+            .or_else(|| supply_item.filter(|s| s.currency == order.currency).map(|s| s.unit_price))
+            .unwrap_or(0.0);
+
+        let (product_name, description) = match supply_item {
+            Some(item) => (item.name.clone(), Some(line.description)),
+            None => (line.description, None),
+        };
+        let unit = match (line.unit.trim(), supply_item) {
+            ("", Some(item)) => item.unit.clone(),
+            ("", None) => "ADET".to_string(),
+            (unit, _) => unit.to_string(),
+        };
+
+        order_item_service::insert(&txn, &order, CreateOrderItemRequest {
+            order_id: order.id,
+            product_name,
+            impa_code,
+            description,
+            quantity: line.quantity,
+            unit,
+            buying_price,
+            selling_price: 0.0,
+            currency: order.currency.clone(),
+            delivery_type: DeliveryType::ViaWarehouse,
+            warehouse_delivery_date: None,
+            ship_delivery_date: None,
+            notes: line.notes,
+        }).await?;
+    }
+
+    txn.commit().await?;
+
+    order_service::get_with_items(order.id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found after import"))
+}
@@ -0,0 +1,463 @@
+//! Requisition Template Service - Recurring provision lists per ship or ship type
+
+use crate::models::{
+    RequisitionTemplate, RequisitionTemplateLine, RequisitionTemplateLineRequest, CreateRequisitionTemplateRequest,
+    UpdateRequisitionTemplateRequest, InstantiateTemplateRequest, CreateOrderRequest, CreateOrderItemRequest,
+    OrderWithItems, DeliveryType,
+};
+use crate::database;
+use crate::services::{order_service, order_item_service, ship_service, ship_visit_service, supply_item_service};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct TemplateRow {
+    id: i32,
+    name: String,
+    ship_id: Option<i32>,
+    ship_name: Option<String>,
+    ship_type: Option<String>,
+    base_headcount: Option<f64>,
+    notes: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct LineRow {
+    id: i32,
+    template_id: i32,
+    product_name: String,
+    impa_code: Option<String>,
+    description: Option<String>,
+    quantity: f64,
+    unit: String,
+    notes: Option<String>,
+}
+
+impl From<LineRow> for RequisitionTemplateLine {
+    fn from(row: LineRow) -> Self {
+        RequisitionTemplateLine {
+            id: row.id,
+            template_id: row.template_id,
+            product_name: row.product_name,
+            impa_code: row.impa_code,
+            description: row.description,
+            quantity: row.quantity,
+            unit: row.unit,
+            notes: row.notes,
+        }
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    t.id, t.name, t.ship_id, s.name as ship_name, t.ship_type, t.base_headcount, t.notes, t.created_at, t.updated_at
+"#;
+
+const FROM_JOIN: &str = "FROM requisition_templates t LEFT JOIN ships s ON t.ship_id = s.id";
+
+async fn load_template<C: ConnectionTrait>(conn: &C, row: TemplateRow) -> Result<RequisitionTemplate> {
+    let lines: Vec<LineRow> = LineRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT id, template_id, product_name, impa_code, description, quantity, unit, notes
+        FROM requisition_template_lines
+        WHERE template_id = ?
+        ORDER BY id
+        "#,
+        vec![Value::Int(Some(row.id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(RequisitionTemplate {
+        id: row.id,
+        name: row.name,
+        ship_id: row.ship_id,
+        ship_name: row.ship_name,
+        ship_type: row.ship_type,
+        base_headcount: row.base_headcount,
+        notes: row.notes,
+        lines: lines.into_iter().map(RequisitionTemplateLine::from).collect(),
+        created_at: row.created_at,
+        updated_at: row.updated_at,
+    })
+}
+
+async fn load_all<C: ConnectionTrait>(conn: &C, rows: Vec<TemplateRow>) -> Result<Vec<RequisitionTemplate>> {
+    let mut templates = Vec::with_capacity(rows.len());
+    for row in rows {
+        templates.push(load_template(conn, row).await?);
+    }
+    Ok(templates)
+}
+
+/// Get all requisition templates
+pub async fn get_all() -> Result<Vec<RequisitionTemplate>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<TemplateRow> = TemplateRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} ORDER BY t.name", SELECT_FIELDS, FROM_JOIN)
+    ))
+    .all(&conn)
+    .await?;
+
+    load_all(&conn, rows).await
+}
+
+/// Templates usable for a ship: its own templates first, then those of its ship type
+pub async fn get_for_ship(ship_id: i32) -> Result<Vec<RequisitionTemplate>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let ship = ship_service::get_by_id(ship_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
+
+    let rows: Vec<TemplateRow> = TemplateRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            "SELECT {} {} WHERE t.ship_id = ? OR (t.ship_type IS NOT NULL AND lower(t.ship_type) = lower(?))
+             ORDER BY (t.ship_id IS NULL), t.name",
+            SELECT_FIELDS, FROM_JOIN
+        ),
+        vec![
+            Value::Int(Some(ship.id)),
+            Value::String(Some(Box::new(ship.ship_type.unwrap_or_default()))),
+        ]
+    ))
+    .all(&conn)
+    .await?;
+
+    load_all(&conn, rows).await
+}
+
+/// Get a template by ID
+pub async fn get_by_id(id: i32) -> Result<Option<RequisitionTemplate>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<TemplateRow> = TemplateRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE t.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    match row {
+        Some(row) => Ok(Some(load_template(&conn, row).await?)),
+        None => Ok(None),
+    }
+}
+
+fn validate_lines(lines: &[RequisitionTemplateLineRequest]) -> Result<()> {
+    if lines.is_empty() {
+        anyhow::bail!("Template has no lines");
+    }
+    for line in lines {
+        if line.product_name.trim().is_empty() {
+            anyhow::bail!("Template line needs a product name");
+        }
+        if line.quantity <= 0.0 {
+            anyhow::bail!("Quantity of {} must be positive", line.product_name);
+        }
+    }
+    Ok(())
+}
+
+fn validate_headcount(base_headcount: Option<f64>) -> Result<()> {
+    if base_headcount.is_some_and(|h| h <= 0.0) {
+        anyhow::bail!("Base headcount must be positive");
+    }
+    Ok(())
+}
+
+async fn insert_lines<C: ConnectionTrait>(conn: &C, template_id: i32, lines: Vec<RequisitionTemplateLineRequest>) -> Result<()> {
+    for line in lines {
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO requisition_template_lines (template_id, product_name, impa_code, description, quantity, unit, notes)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+            vec![
+                Value::Int(Some(template_id)),
+                Value::String(Some(Box::new(line.product_name.trim().to_string()))),
+                Value::String(line.impa_code.map(Box::new)),
+                Value::String(line.description.map(Box::new)),
+                Value::Double(Some(line.quantity)),
+                Value::String(Some(Box::new(line.unit))),
+                Value::String(line.notes.map(Box::new)),
+            ]
+        )).await?;
+    }
+    Ok(())
+}
+
+/// Save a requisition template for a ship or a ship type
+pub async fn create(req: CreateRequisitionTemplateRequest) -> Result<RequisitionTemplate> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    if req.name.trim().is_empty() {
+        anyhow::bail!("Template name is required");
+    }
+    let ship_type = req.ship_type.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+    match (req.ship_id, &ship_type) {
+        (Some(_), Some(_)) | (None, None) => anyhow::bail!("A template belongs to either a ship or a ship type"),
+        (Some(ship_id), None) => {
+            ship_service::get_by_id(ship_id).await?
+                .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
+        }
+        (None, Some(_)) => {}
+    }
+    validate_headcount(req.base_headcount)?;
+    validate_lines(&req.lines)?;
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO requisition_templates (name, ship_id, ship_type, base_headcount, notes)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::String(Some(Box::new(req.name.trim().to_string()))),
+            Value::Int(req.ship_id),
+            Value::String(ship_type.map(Box::new)),
+            Value::Double(req.base_headcount),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    insert_lines(&txn, id, req.lines).await?;
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created template"))
+}
+
+/// Save an order's lines as a template for its ship, or for the ship's type
+pub async fn create_from_order(
+    order_id: i32,
+    name: String,
+    for_ship_type: bool,
+    base_headcount: Option<f64>,
+) -> Result<RequisitionTemplate> {
+    let order = order_service::get_by_id(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    let ship = ship_service::get_by_id(order.ship_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
+
+    let (ship_id, ship_type) = if for_ship_type {
+        let ship_type = ship.ship_type
+            .filter(|t| !t.trim().is_empty())
+            .ok_or_else(|| anyhow::anyhow!("Ship {} has no ship type", ship.name))?;
+        (None, Some(ship_type))
+    } else {
+        (Some(ship.id), None)
+    };
+
+    let lines = order_item_service::get_by_order_id(order_id).await?
+        .into_iter()
+        .map(|item| RequisitionTemplateLineRequest {
+            quantity: item.quantity + item.rolled_over_quantity + item.closed_quantity,
+            product_name: item.product_name,
+            impa_code: item.impa_code,
+            description: item.description,
+            unit: item.unit,
+            notes: item.notes,
+        })
+        .collect();
+
+    create(CreateRequisitionTemplateRequest {
+        name,
+        ship_id,
+        ship_type,
+        base_headcount,
+        notes: Some(format!("From order {}", order.order_number)),
+        lines,
+    }).await
+}
+
+/// Update a template (name, base headcount, notes, lines)
+pub async fn update(id: i32, req: UpdateRequisitionTemplateRequest) -> Result<RequisitionTemplate> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let existing = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Requisition template not found"))?;
+
+    let name = req.name.unwrap_or(existing.name);
+    if name.trim().is_empty() {
+        anyhow::bail!("Template name is required");
+    }
+    let base_headcount = req.base_headcount.or(existing.base_headcount);
+    validate_headcount(base_headcount)?;
+    if let Some(lines) = &req.lines {
+        validate_lines(lines)?;
+    }
+
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        UPDATE requisition_templates SET name = ?, base_headcount = ?, notes = ?, updated_at = datetime('now')
+        WHERE id = ?
+        "#,
+        vec![
+            Value::String(Some(Box::new(name.trim().to_string()))),
+            Value::Double(base_headcount),
+            Value::String(req.notes.or(existing.notes).map(Box::new)),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    if let Some(lines) = req.lines {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "DELETE FROM requisition_template_lines WHERE template_id = ?",
+            vec![Value::Int(Some(id))]
+        )).await?;
+        insert_lines(&txn, id, lines).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Requisition template not found after update"))
+}
+
+/// Delete a template
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM requisition_template_lines WHERE template_id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM requisition_templates WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Scale a template quantity; whole-number quantities (cases, pieces) are rounded up to whole numbers
+fn scale_quantity(quantity: f64, factor: f64) -> f64 {
+    let scaled = quantity * factor;
+    if quantity.fract() == 0.0 {
+        (scaled - 1e-9).ceil()
+    } else {
+        (scaled * 100.0).round() / 100.0
+    }
+}
+
+/// Create a new order from a template.
+///
+/// Quantities are multiplied by `scale_factor`, or by headcount / base headcount
+/// (e.g. crew + passengers this call against the template's basis). Buying
+/// prices come from the current supply items; selling prices from the pricing engine.
+pub async fn instantiate(req: InstantiateTemplateRequest) -> Result<OrderWithItems> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let template = get_by_id(req.template_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Requisition template not found"))?;
+    let ship = ship_service::get_by_id(req.ship_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Ship not found"))?;
+
+    let applies = match (template.ship_id, &template.ship_type) {
+        (Some(ship_id), _) => ship_id == ship.id,
+        (None, Some(ship_type)) => ship.ship_type.as_deref().is_some_and(|t| t.eq_ignore_ascii_case(ship_type)),
+        (None, None) => false,
+    };
+    if !applies {
+        anyhow::bail!("Template {} is not for ship {}", template.name, ship.name);
+    }
+
+    let factor = match (req.scale_factor, req.headcount) {
+        (Some(factor), _) => factor,
+        (None, Some(headcount)) => {
+            let base = template.base_headcount
+                .ok_or_else(|| anyhow::anyhow!("Template {} has no base headcount to scale from", template.name))?;
+            headcount / base
+        }
+        (None, None) => 1.0,
+    };
+    if factor <= 0.0 || !factor.is_finite() {
+        anyhow::bail!("Scale factor must be positive");
+    }
+
+    let delivery_port = match req.ship_visit_id {
+        Some(visit_id) => {
+            let visit = ship_visit_service::get_by_id(visit_id).await?
+                .ok_or_else(|| anyhow::anyhow!("Ship visit not found"))?;
+            if visit.ship_id != ship.id {
+                anyhow::bail!("Ship visit {} is for another ship", visit_id);
+            }
+            visit.port_name
+        }
+        None => None,
+    };
+
+    let customer = order_service::customer_for_ship(ship.id).await?;
+
+    let txn = conn.begin().await?;
+
+    let order_id = order_service::insert(&txn, &CreateOrderRequest {
+        ship_id: ship.id,
+        ship_visit_id: req.ship_visit_id,
+        delivery_port,
+        notes: Some(format!("From template {} (x{:.2})", template.name, factor)),
+        currency: req.currency,
+    }, customer.as_ref()).await?;
+    let order = order_service::fetch_by_id(&txn, order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to fetch created order"))?;
+
+    for line in template.lines {
+        let buying_price = match line.impa_code.as_deref() {
+            Some(impa_code) => supply_item_service::current_buying_price(&txn, impa_code, &order.currency).await?
+                .unwrap_or(0.0),
+            None => 0.0,
+        };
+
+        order_item_service::insert(&txn, &order, CreateOrderItemRequest {
+            order_id,
+            product_name: line.product_name,
+            impa_code: line.impa_code,
+            description: line.description,
+            quantity: scale_quantity(line.quantity, factor),
+            unit: line.unit,
+            buying_price,
+            selling_price: 0.0,
+            currency: order.currency.clone(),
+            delivery_type: DeliveryType::ViaWarehouse,
+            warehouse_delivery_date: None,
+            ship_delivery_date: None,
+            notes: line.notes,
+        }).await?;
+    }
+
+    txn.commit().await?;
+
+    order_service::get_with_items(order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to fetch created order"))
+}
@@ -0,0 +1,350 @@
+//! Return Service - Goods sent back by ships after delivery
+
+use crate::models::{
+    OrderReturn, OrderReturnLine, CreateReturnRequest, ReturnReason, CreditNote, OrderStatus, StockMovementType,
+};
+use crate::database;
+use crate::services::{order_service, order_item_service, stock_service, credit_note_service, numbering};
+use crate::services::invoice_service::parse_date;
+use crate::services::order_item_service::QUANTITY_EPSILON;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, FromQueryResult)]
+struct ReturnRow {
+    id: i32,
+    return_number: String,
+    order_id: i32,
+    order_number: Option<String>,
+    return_date: String,
+    notes: Option<String>,
+    currency: String,
+    credit_note_id: Option<i32>,
+    credit_note_number: Option<String>,
+    created_at: String,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct LineRow {
+    id: i32,
+    order_item_id: i32,
+    product_name: Option<String>,
+    quantity: f64,
+    unit: Option<String>,
+    reason: String,
+    restocked: bool,
+    stock_movement_id: Option<i32>,
+    credit_amount: f64,
+    cost_amount: f64,
+}
+
+impl From<LineRow> for OrderReturnLine {
+    fn from(row: LineRow) -> Self {
+        OrderReturnLine {
+            id: row.id,
+            order_item_id: row.order_item_id,
+            product_name: row.product_name,
+            quantity: row.quantity,
+            unit: row.unit,
+            reason: reason_from_str(&row.reason),
+            restocked: row.restocked,
+            stock_movement_id: row.stock_movement_id,
+            credit_amount: row.credit_amount,
+            cost_amount: row.cost_amount,
+        }
+    }
+}
+
+fn reason_from_str(reason: &str) -> ReturnReason {
+    match reason {
+        "DAMAGED" => ReturnReason::Damaged,
+        "WRONG_ITEM" => ReturnReason::WrongItem,
+        "SURPLUS" => ReturnReason::Surplus,
+        _ => ReturnReason::Other,
+    }
+}
+
+fn reason_to_str(reason: ReturnReason) -> &'static str {
+    match reason {
+        ReturnReason::Damaged => "DAMAGED",
+        ReturnReason::WrongItem => "WRONG_ITEM",
+        ReturnReason::Surplus => "SURPLUS",
+        ReturnReason::Other => "OTHER",
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    r.id, r.return_number, r.order_id, o.order_number, r.return_date, r.notes, o.currency,
+    (SELECT cn.id FROM credit_notes cn WHERE cn.return_id = r.id ORDER BY cn.id LIMIT 1) as credit_note_id,
+    (SELECT cn.credit_note_number FROM credit_notes cn WHERE cn.return_id = r.id ORDER BY cn.id LIMIT 1) as credit_note_number,
+    r.created_at
+"#;
+
+const FROM_JOIN: &str = "FROM order_returns r JOIN orders o ON r.order_id = o.id";
+
+async fn load_return<C: ConnectionTrait>(conn: &C, row: ReturnRow) -> Result<OrderReturn> {
+    let lines: Vec<LineRow> = LineRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT rl.id, rl.order_item_id, oi.product_name, rl.quantity, oi.unit, rl.reason, rl.restocked,
+               rl.stock_movement_id, rl.credit_amount, rl.cost_amount
+        FROM order_return_lines rl
+        LEFT JOIN order_items oi ON rl.order_item_id = oi.id
+        WHERE rl.return_id = ?
+        ORDER BY rl.id
+        "#,
+        vec![Value::Int(Some(row.id))]
+    ))
+    .all(conn)
+    .await?;
+
+    let lines: Vec<OrderReturnLine> = lines.into_iter().map(OrderReturnLine::from).collect();
+
+    Ok(OrderReturn {
+        id: row.id,
+        return_number: row.return_number,
+        order_id: row.order_id,
+        order_number: row.order_number,
+        return_date: row.return_date,
+        notes: row.notes,
+        credit_amount: lines.iter().map(|l| l.credit_amount).sum(),
+        currency: row.currency,
+        credit_note_id: row.credit_note_id,
+        credit_note_number: row.credit_note_number,
+        lines,
+        created_at: row.created_at,
+    })
+}
+
+/// Get the returns recorded against an order
+pub async fn get_by_order(order_id: i32) -> Result<Vec<OrderReturn>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<ReturnRow> = ReturnRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE r.order_id = ? ORDER BY r.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut returns = Vec::with_capacity(rows.len());
+    for row in rows {
+        returns.push(load_return(&conn, row).await?);
+    }
+    Ok(returns)
+}
+
+/// Get a return by ID
+pub async fn get_by_id(id: i32) -> Result<Option<OrderReturn>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<ReturnRow> = ReturnRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE r.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    match row {
+        Some(row) => Ok(Some(load_return(&conn, row).await?)),
+        None => Ok(None),
+    }
+}
+
+/// Record goods returned by the ship.
+///
+/// Each line takes back part of the delivered quantity of an order line.
+/// Restocked lines post a Return stock movement referencing the order; the
+/// returned value is deducted from the order's revenue and, on request,
+/// credited against the order's invoice.
+pub async fn create(req: CreateReturnRequest) -> Result<OrderReturn> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order = order_service::get_by_id(req.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    if order.status == OrderStatus::Cancelled {
+        anyhow::bail!("Order {} is cancelled", order.order_number);
+    }
+    if req.lines.is_empty() {
+        anyhow::bail!("Return has no lines");
+    }
+
+    let return_date = match req.return_date {
+        Some(date) => parse_date(&date)?,
+        None => chrono::Utc::now().date_naive(),
+    }
+    .format("%Y-%m-%d")
+    .to_string();
+
+    let items = order_item_service::get_by_order_id(order.id).await?;
+    let mut requested: HashMap<i32, f64> = HashMap::new();
+    let mut stocks = Vec::with_capacity(req.lines.len());
+    for line in &req.lines {
+        let item = items.iter()
+            .find(|i| i.id == line.order_item_id)
+            .ok_or_else(|| anyhow::anyhow!("Order item {} is not on order {}", line.order_item_id, order.order_number))?;
+
+        if line.quantity <= 0.0 {
+            anyhow::bail!("Returned quantity of {} must be positive", item.product_name);
+        }
+        let total = requested.entry(item.id).or_insert(0.0);
+        *total += line.quantity;
+        let returnable = item.delivered_quantity - item.returned_quantity;
+        if *total > returnable + QUANTITY_EPSILON {
+            anyhow::bail!(
+                "Only {} {} of {} was delivered and not yet returned",
+                returnable.max(0.0), item.unit, item.product_name
+            );
+        }
+
+        let stock = if line.restock {
+            let stock = match item.impa_code.as_deref() {
+                Some(impa_code) => stock_service::find_by_impa(&conn, impa_code).await?,
+                None => None,
+            };
+            Some(stock.ok_or_else(|| anyhow::anyhow!("{} has no stock record to return the goods into", item.product_name))?)
+        } else {
+            None
+        };
+        stocks.push((item, stock));
+    }
+
+    let invoice = if req.issue_credit_note {
+        Some(credit_note_service::invoice_to_credit(order.id).await?
+            .ok_or_else(|| anyhow::anyhow!("Order {} has no invoice to credit", order.order_number))?)
+    } else {
+        None
+    };
+
+    let txn = conn.begin().await?;
+
+    let return_number = numbering::next_document_number(&txn, "order_returns", "return_number", "RET").await?;
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT INTO order_returns (return_number, order_id, return_date, notes) VALUES (?, ?, ?, ?)",
+        vec![
+            Value::String(Some(Box::new(return_number.clone()))),
+            Value::Int(Some(order.id)),
+            Value::String(Some(Box::new(return_date.clone()))),
+            Value::String(req.notes.clone().map(Box::new)),
+        ]
+    )).await?;
+    let return_id = result.last_insert_id() as i32;
+
+    let mut credit_total = 0.0;
+    for (line, (item, stock)) in req.lines.iter().zip(stocks) {
+        let stock_movement_id = match &stock {
+            Some(stock) => Some(stock_service::post_movement(
+                &txn,
+                stock,
+                StockMovementType::Return,
+                (line.quantity, &item.unit),
+                ("order", order.id, Some(format!("{} / {}", order.order_number, return_number))),
+                Some(line.reason.display_name().to_string()),
+                req.customs_declaration_number.clone(),
+            ).await?),
+            None => None,
+        };
+        let credit_amount = (line.quantity * item.selling_price * 100.0).round() / 100.0;
+        let cost_amount = if stock.is_some() {
+            (line.quantity * item.buying_price * 100.0).round() / 100.0
+        } else {
+            0.0
+        };
+        credit_total += credit_amount;
+
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO order_return_lines (return_id, order_item_id, quantity, reason, restocked, stock_movement_id, credit_amount, cost_amount)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            vec![
+                Value::Int(Some(return_id)),
+                Value::Int(Some(item.id)),
+                Value::Double(Some(line.quantity)),
+                Value::String(Some(Box::new(reason_to_str(line.reason).to_string()))),
+                Value::Int(Some(stock.is_some() as i32)),
+                Value::Int(stock_movement_id),
+                Value::Double(Some(credit_amount)),
+                Value::Double(Some(cost_amount)),
+            ]
+        )).await?;
+    }
+
+    if let Some(invoice) = &invoice {
+        credit_note_service::issue(
+            &txn, invoice, Some(return_id), credit_total, &return_date, Some(format!("Return {}", return_number)),
+        ).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(return_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created return"))
+}
+
+/// Issue the credit note of a return recorded without one (e.g. invoiced later)
+pub async fn issue_credit_note(return_id: i32) -> Result<CreditNote> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let order_return = get_by_id(return_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Return not found"))?;
+    if let Some(number) = &order_return.credit_note_number {
+        anyhow::bail!("Return {} is already credited by {}", order_return.return_number, number);
+    }
+    let invoice = credit_note_service::invoice_to_credit(order_return.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order {} has no invoice to credit", order_return.order_number.unwrap_or_default()))?;
+
+    let issue_date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+
+    let txn = conn.begin().await?;
+    let credit_note_id = credit_note_service::issue(
+        &txn,
+        &invoice,
+        Some(return_id),
+        order_return.credit_amount,
+        &issue_date,
+        Some(format!("Return {}", order_return.return_number)),
+    ).await?;
+    txn.commit().await?;
+
+    credit_note_service::get_by_id(credit_note_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created credit note"))
+}
+
+/// Returned value of an order: (revenue given back, cost of goods put back into stock)
+pub(crate) async fn totals_for_order<C: ConnectionTrait>(conn: &C, order_id: i32) -> Result<(f64, f64)> {
+    #[derive(Debug, FromQueryResult)]
+    struct TotalsRow {
+        revenue: f64,
+        cost: f64,
+    }
+
+    let row: Option<TotalsRow> = TotalsRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT CAST(COALESCE(SUM(rl.credit_amount), 0.0) AS REAL) as revenue,
+               CAST(COALESCE(SUM(rl.cost_amount), 0.0) AS REAL) as cost
+        FROM order_return_lines rl
+        JOIN order_returns r ON rl.return_id = r.id
+        WHERE r.order_id = ?
+        "#,
+        vec![Value::Int(Some(order_id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| (r.revenue, r.cost)).unwrap_or((0.0, 0.0)))
+}
@@ -0,0 +1,313 @@
+//! Search Service - Global full-text search over catalog, suppliers, ships and orders
+//!
+//! On SQLite the searchable text of every record lives in the FTS5 table
+//! `search_index` (rowid = record id * 10 + source kind), kept current by
+//! triggers on the source tables and the tables their text is taken from.
+//! The unicode61 tokenizer folds case and diacritics (ş/s, ğ/g, ü/u); the
+//! dotless ı has no diacritic and is folded before indexing. Query words are
+//! prefix matches, in any order. Search needs FTS5 and is SQLite-only, like
+//! the rest of the application.
+
+use crate::models::{SearchScope, SearchHit};
+use crate::database;
+use crate::services::requisition_import_service::normalize_name;
+use anyhow::Result;
+use std::collections::HashMap;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, Statement, DatabaseBackend, FromQueryResult, Value};
+
+/// Hits per scope when the caller gives no limit
+const DEFAULT_LIMIT: i32 = 50;
+
+/// Words of context shown in a snippet
+const SNIPPET_WORDS: usize = 12;
+
+/// A searchable table. Text columns are SQL expressions over the row `t`.
+struct Source {
+    scope: SearchScope,
+    kind: i32,
+    table: &'static str,
+    /// Rows that are searchable at all
+    filter: &'static str,
+    title: &'static str,
+    subtitle: &'static str,
+    /// Codes matched by prefix: IMPA, IMO, order number, SKU
+    codes: &'static str,
+    body: &'static str,
+    /// Other tables the text is taken from: (table, column of `t`, column of the other row)
+    dependents: &'static [(&'static str, &'static str, &'static str)],
+}
+
+const SOURCES: [Source; 4] = [
+    Source {
+        scope: SearchScope::SupplyItems,
+        kind: 1,
+        table: "supply_items",
+        filter: "1 = 1",
+        title: "t.name",
+        subtitle: "(SELECT s.name FROM suppliers s WHERE s.id = t.supplier_id)",
+        codes: "COALESCE(replace(t.impa_code, ' ', ''), '') || ' ' || COALESCE(t.supplier_sku, '') || ' ' || COALESCE(t.un_number, '')",
+        body: "COALESCE(t.description, '') || ' ' || COALESCE((SELECT c.name_tr FROM categories c WHERE c.id = t.category_id), t.category, '') || ' ' || COALESCE((SELECT s.name FROM suppliers s WHERE s.id = t.supplier_id), '')",
+        dependents: &[("suppliers", "supplier_id", "id"), ("categories", "category_id", "id")],
+    },
+    Source {
+        scope: SearchScope::Suppliers,
+        kind: 2,
+        table: "suppliers",
+        filter: "t.is_active = 1",
+        title: "t.name",
+        subtitle: "t.country",
+        codes: "''",
+        body: "COALESCE(t.contact_person, '') || ' ' || COALESCE(t.email, '') || ' ' || COALESCE(t.address, '') || ' ' || COALESCE(t.country, '') || ' ' || COALESCE((SELECT c.name_tr FROM categories c WHERE c.id = t.category_id), t.category, '')",
+        dependents: &[("categories", "category_id", "id")],
+    },
+    Source {
+        scope: SearchScope::Ships,
+        kind: 3,
+        table: "ships",
+        filter: "t.is_active = 1",
+        title: "t.name",
+        subtitle: "t.imo_number",
+        codes: "t.imo_number",
+        body: "COALESCE(t.flag, '') || ' ' || COALESCE(t.ship_type, '') || ' ' || COALESCE(t.owner, '') || ' ' || COALESCE(t.notes, '')",
+        dependents: &[],
+    },
+    Source {
+        scope: SearchScope::Orders,
+        kind: 4,
+        table: "orders",
+        filter: "1 = 1",
+        title: "t.order_number",
+        subtitle: "(SELECT s.name FROM ships s WHERE s.id = t.ship_id)",
+        codes: "t.order_number",
+        body: "COALESCE((SELECT s.name FROM ships s WHERE s.id = t.ship_id), '') || ' ' || COALESCE((SELECT c.legal_name FROM customers c WHERE c.id = t.customer_id), '') || ' ' || COALESCE(t.delivery_port, '') || ' ' || COALESCE(t.notes, '') || ' ' || COALESCE((SELECT group_concat(oi.product_name || ' ' || COALESCE(replace(oi.impa_code, ' ', ''), ''), ' ') FROM order_items oi WHERE oi.order_id = t.id), '')",
+        dependents: &[("ships", "ship_id", "id"), ("customers", "customer_id", "id"), ("order_items", "id", "order_id")],
+    },
+];
+
+#[derive(Debug, FromQueryResult)]
+struct HitRow {
+    entity_id: i32,
+    title: Option<String>,
+    subtitle: Option<String>,
+    codes: Option<String>,
+    body: Option<String>,
+    rank: f64,
+}
+
+/// SQLite lower() folds ASCII only; the tokenizer does the rest except ı
+fn fold_sql(expr: &str) -> String {
+    format!("replace(replace(lower({}), 'ı', 'i'), 'İ', 'i')", expr)
+}
+
+fn documents_sql(source: &Source, condition: &str) -> String {
+    format!(
+        "SELECT t.id * 10 + {}, {}, {}, {} FROM {} t WHERE {} AND {}",
+        source.kind, fold_sql(source.title), fold_sql(source.codes), fold_sql(source.body),
+        source.table, source.filter, condition
+    )
+}
+
+/// Statements re-indexing the rows of `source` that match `condition`
+fn reindex_sql(source: &Source, condition: &str) -> String {
+    format!(
+        "DELETE FROM search_index WHERE rowid IN (SELECT t.id * 10 + {} FROM {} t WHERE {});
+         INSERT INTO search_index (rowid, title, codes, body) {};",
+        source.kind, source.table, condition, documents_sql(source, condition)
+    )
+}
+
+/// Triggers keeping the index current, by name
+fn triggers() -> Vec<(String, String)> {
+    let mut triggers = Vec::new();
+    for source in &SOURCES {
+        let table = source.table;
+        let remove = format!("DELETE FROM search_index WHERE rowid = OLD.id * 10 + {};", source.kind);
+        triggers.push((
+            format!("search_{}_insert", table),
+            format!("AFTER INSERT ON {} BEGIN {} END", table, reindex_sql(source, "t.id = NEW.id")),
+        ));
+        triggers.push((
+            format!("search_{}_update", table),
+            format!("AFTER UPDATE ON {} BEGIN {} {} END", table, remove, reindex_sql(source, "t.id = NEW.id")),
+        ));
+        triggers.push((
+            format!("search_{}_delete", table),
+            format!("AFTER DELETE ON {} BEGIN {} END", table, remove),
+        ));
+
+        for (other, column, other_column) in source.dependents {
+            let old = reindex_sql(source, &format!("t.{} = OLD.{}", column, other_column));
+            let new = reindex_sql(source, &format!("t.{} = NEW.{}", column, other_column));
+            triggers.push((
+                format!("search_{}_{}_insert", table, other),
+                format!("AFTER INSERT ON {} BEGIN {} END", other, new),
+            ));
+            triggers.push((
+                format!("search_{}_{}_update", table, other),
+                format!("AFTER UPDATE ON {} BEGIN {} {} END", other, old, new),
+            ));
+            triggers.push((
+                format!("search_{}_{}_delete", table, other),
+                format!("AFTER DELETE ON {} BEGIN {} END", other, old),
+            ));
+        }
+    }
+    triggers
+}
+
+/// Bump when the indexed text, the triggers or the tokenizer change
+const INDEX_VERSION: i32 = 1;
+
+const CREATE_INDEX_SQL: &str =
+    "CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(title, codes, body, tokenize = 'unicode61 remove_diacritics 2')";
+
+/// Create the search index and its triggers and index every record, unless
+/// a filled index of the current version is already in place
+pub(crate) async fn build_index(conn: &DatabaseConnection) -> Result<(), DbErr> {
+    let marker = format!("search_index_v{}", INDEX_VERSION);
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, CREATE_INDEX_SQL.to_string())).await?;
+
+    let filled = conn.query_one(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT 1 FROM search_index LIMIT 1".to_string()
+    )).await?.is_some();
+    if filled && database::migration_applied(conn, &marker).await? {
+        return Ok(());
+    }
+
+    tracing::info!("Building search index (version {})...", INDEX_VERSION);
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, "DROP TABLE search_index".to_string())).await?;
+    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, CREATE_INDEX_SQL.to_string())).await?;
+
+    for (name, definition) in triggers() {
+        conn.execute(Statement::from_string(DatabaseBackend::Sqlite, format!("DROP TRIGGER IF EXISTS {}", name))).await?;
+        conn.execute(Statement::from_string(DatabaseBackend::Sqlite, format!("CREATE TRIGGER {} {}", name, definition))).await?;
+    }
+
+    for source in &SOURCES {
+        conn.execute(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            format!("INSERT INTO search_index (rowid, title, codes, body) {}", documents_sql(source, "1 = 1"))
+        )).await?;
+    }
+
+    database::mark_migration_applied(conn, &marker).await
+}
+
+/// Folded query words. Digit groups ("37 01 01") are one IMPA code.
+fn query_terms(query: &str) -> Vec<String> {
+    let terms: Vec<String> = normalize_name(query).split_whitespace().map(str::to_string).collect();
+    if terms.len() > 1 && terms.iter().all(|t| t.chars().all(|c| c.is_ascii_digit())) {
+        return vec![terms.concat()];
+    }
+    terms
+}
+
+/// Hits of one source, best first; no limit returns every match
+async fn search_source<C: ConnectionTrait>(conn: &C, source: &Source, terms: &[String], limit: Option<i32>) -> Result<Vec<HitRow>> {
+    let query = terms.iter().map(|t| format!("\"{}\"*", t)).collect::<Vec<_>>().join(" ");
+    let statement = Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            "SELECT t.id as entity_id, {} as title, {} as subtitle, {} as codes, {} as body, -bm25(search_index, 10.0, 5.0, 1.0) as rank
+             FROM search_index JOIN {} t ON t.id = search_index.rowid / 10
+             WHERE search_index MATCH ? AND search_index.rowid % 10 = {}
+             ORDER BY rank DESC LIMIT ?",
+            source.title, source.subtitle, source.codes, source.body, source.table, source.kind
+        ),
+        vec![Value::String(Some(Box::new(query))), Value::Int(Some(limit.unwrap_or(-1)))]
+    );
+
+    Ok(HitRow::find_by_statement(statement).all(conn).await?)
+}
+
+/// Text around the first matched word, matched words in <b></b>
+fn snippet(texts: &[&str], terms: &[String]) -> String {
+    let matches = |word: &str| {
+        normalize_name(word).split(' ').any(|part| !part.is_empty() && terms.iter().any(|t| part.starts_with(t.as_str())))
+    };
+    let text = texts.iter()
+        .find(|t| t.split_whitespace().any(matches))
+        .or_else(|| texts.iter().find(|t| !t.trim().is_empty()))
+        .copied()
+        .unwrap_or("");
+
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let start = words.iter().position(|w| matches(w)).unwrap_or(0).saturating_sub(4);
+    let end = (start + SNIPPET_WORDS).min(words.len());
+    let mut snippet = words[start..end].iter()
+        .map(|w| {
+            if !matches(w) {
+                return w.to_string();
+            }
+            let core = w.trim_end_matches(|c: char| !c.is_alphanumeric());
+            format!("<b>{}</b>{}", core, &w[core.len()..])
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    if start > 0 {
+        snippet = format!("… {}", snippet);
+    }
+    if end < words.len() {
+        snippet.push_str(" …");
+    }
+    snippet
+}
+
+/// IDs of all records of one scope matching a query, best match first
+pub(crate) async fn ranked_ids<C: ConnectionTrait>(conn: &C, scope: SearchScope, query: &str) -> Result<Vec<i32>> {
+    let terms = query_terms(query);
+    let Some(source) = SOURCES.iter().find(|s| s.scope == scope) else {
+        return Ok(Vec::new());
+    };
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(search_source(conn, source, &terms, None).await?.into_iter().map(|r| r.entity_id).collect())
+}
+
+/// Placeholders and values for `id IN (...)` over ranked IDs
+pub(crate) fn id_list(ids: &[i32]) -> (String, Vec<Value>) {
+    let placeholders = vec!["?"; ids.len()].join(", ");
+    (placeholders, ids.iter().map(|id| Value::Int(Some(*id))).collect())
+}
+
+/// Records loaded by `id_list`, back in ranked order
+pub(crate) fn in_rank_order<T>(ids: &[i32], records: Vec<T>, id: impl Fn(&T) -> i32) -> Vec<T> {
+    let mut by_id: HashMap<i32, T> = records.into_iter().map(|r| (id(&r), r)).collect();
+    ids.iter().filter_map(|id| by_id.remove(id)).collect()
+}
+
+/// Search the given scopes (all when empty), best matches first across scopes
+pub async fn search(query: &str, scopes: &[SearchScope], limit: i32) -> Result<Vec<SearchHit>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let terms = query_terms(query);
+    if terms.is_empty() {
+        return Ok(Vec::new());
+    }
+    let limit = if limit > 0 { limit } else { DEFAULT_LIMIT };
+
+    let mut hits = Vec::new();
+    for source in SOURCES.iter().filter(|s| scopes.is_empty() || scopes.contains(&s.scope)) {
+        for row in search_source(&conn, source, &terms, Some(limit)).await? {
+            let body = row.body.unwrap_or_default();
+            let codes = row.codes.unwrap_or_default();
+            let title = row.title.unwrap_or_default();
+            hits.push(SearchHit {
+                scope: source.scope,
+                entity_id: row.entity_id,
+                snippet: snippet(&[&body, &codes, &title], &terms),
+                title,
+                subtitle: row.subtitle.filter(|s| !s.trim().is_empty()),
+                score: (row.rank * 1000.0).round() / 1000.0,
+            });
+        }
+    }
+
+    hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+    hits.truncate(limit as usize);
+    Ok(hits)
+}
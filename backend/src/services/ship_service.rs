@@ -1,7 +1,8 @@
 //! Ship Service - CRUD operations for ships
 
-use crate::models::{Ship, CreateShipRequest, UpdateShipRequest};
+use crate::models::{Ship, CreateShipRequest, UpdateShipRequest, SearchScope};
 use crate::database;
+use crate::services::search_service;
 use anyhow::Result;
 use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
 
@@ -15,6 +16,7 @@ struct ShipRow {
     ship_type: Option<String>,
     gross_tonnage: Option<f64>,
     owner: Option<String>,
+    owner_id: Option<i32>,
     created_at: String,
     updated_at: String,
 }
@@ -29,12 +31,51 @@ impl From<ShipRow> for Ship {
             ship_type: row.ship_type,
             gross_tonnage: row.gross_tonnage,
             owner: row.owner,
+            owner_id: row.owner_id,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
     }
 }
 
+/// Resolve the owner of a ship to a customer account.
+///
+/// An explicit `owner_id` wins and its legal name becomes the owner text;
+/// a free-text owner is linked to an existing customer with the same name.
+async fn resolve_owner<C: ConnectionTrait>(conn: &C, owner_id: Option<i32>, owner: Option<String>) -> Result<(Option<i32>, Option<String>)> {
+    #[derive(FromQueryResult)]
+    struct CustomerNameRow {
+        id: i32,
+        legal_name: String,
+    }
+
+    let row: Option<CustomerNameRow> = match (owner_id, owner.as_deref().map(str::trim)) {
+        (Some(owner_id), _) => {
+            let row = CustomerNameRow::find_by_statement(Statement::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                "SELECT id, legal_name FROM customers WHERE id = ? AND is_active = 1",
+                vec![Value::Int(Some(owner_id))]
+            ))
+            .one(conn)
+            .await?;
+            Some(row.ok_or_else(|| anyhow::anyhow!("Customer not found"))?)
+        }
+        (None, Some(name)) if !name.is_empty() => CustomerNameRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "SELECT id, legal_name FROM customers WHERE legal_name = ? COLLATE NOCASE AND is_active = 1 ORDER BY id LIMIT 1",
+            vec![Value::String(Some(Box::new(name.to_string())))]
+        ))
+        .one(conn)
+        .await?,
+        _ => None,
+    };
+
+    Ok(match row {
+        Some(row) => (Some(row.id), Some(row.legal_name)),
+        None => (None, owner),
+    })
+}
+
 pub async fn get_all() -> Result<Vec<Ship>> {
     let conn = database::get_connection()
         .await
@@ -42,7 +83,7 @@ pub async fn get_all() -> Result<Vec<Ship>> {
 
     let rows: Vec<ShipRow> = ShipRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, created_at, updated_at FROM ships WHERE is_active = 1 ORDER BY name".to_string()
+        "SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, owner_id, created_at, updated_at FROM ships WHERE is_active = 1 ORDER BY name".to_string()
     ))
     .all(&conn)
     .await?;
@@ -57,7 +98,7 @@ pub async fn get_by_id(id: i32) -> Result<Option<Ship>> {
 
     let row: Option<ShipRow> = ShipRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, created_at, updated_at FROM ships WHERE id = ? AND is_active = 1",
+        "SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, owner_id, created_at, updated_at FROM ships WHERE id = ? AND is_active = 1",
         vec![Value::Int(Some(id))]
     ))
     .one(&conn)
@@ -72,18 +113,20 @@ pub async fn create(ship: CreateShipRequest) -> Result<Ship> {
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
     let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let (owner_id, owner) = resolve_owner(&conn, ship.owner_id, ship.owner.clone()).await?;
 
     // Insert the ship
     conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "INSERT INTO ships (name, imo_number, flag, ship_type, gross_tonnage, owner, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO ships (name, imo_number, flag, ship_type, gross_tonnage, owner, owner_id, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
         vec![
             Value::String(Some(Box::new(ship.name.clone()))),
             Value::String(Some(Box::new(ship.imo_number.clone()))),
             Value::String(Some(Box::new(ship.flag.clone()))),
             Value::String(ship.ship_type.clone().map(|s| Box::new(s))),
             Value::Double(ship.gross_tonnage),
-            Value::String(ship.owner.clone().map(|s| Box::new(s))),
+            Value::String(owner.map(|s| Box::new(s))),
+            Value::Int(owner_id),
             Value::String(Some(Box::new(now.clone()))),
             Value::String(Some(Box::new(now.clone()))),
         ]
@@ -92,7 +135,7 @@ pub async fn create(ship: CreateShipRequest) -> Result<Ship> {
     // Get the last inserted ID
     let result: Option<ShipRow> = ShipRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, created_at, updated_at FROM ships WHERE id = last_insert_rowid()".to_string()
+        "SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, owner_id, created_at, updated_at FROM ships WHERE id = last_insert_rowid()".to_string()
     ))
     .one(&conn)
     .await?;
@@ -118,11 +161,15 @@ pub async fn update(id: i32, ship: UpdateShipRequest) -> Result<Ship> {
     let flag = ship.flag.unwrap_or(existing.flag);
     let ship_type = ship.ship_type.or(existing.ship_type);
     let gross_tonnage = ship.gross_tonnage.or(existing.gross_tonnage);
-    let owner = ship.owner.or(existing.owner);
+    let (owner_id, owner) = if ship.owner_id.is_some() || ship.owner.is_some() {
+        resolve_owner(&conn, ship.owner_id, ship.owner).await?
+    } else {
+        (existing.owner_id, existing.owner)
+    };
 
     conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "UPDATE ships SET name = ?, imo_number = ?, flag = ?, ship_type = ?, gross_tonnage = ?, owner = ?, updated_at = ? WHERE id = ?",
+        "UPDATE ships SET name = ?, imo_number = ?, flag = ?, ship_type = ?, gross_tonnage = ?, owner = ?, owner_id = ?, updated_at = ? WHERE id = ?",
         vec![
             Value::String(Some(Box::new(name))),
             Value::String(Some(Box::new(imo_number))),
@@ -130,6 +177,7 @@ pub async fn update(id: i32, ship: UpdateShipRequest) -> Result<Ship> {
             Value::String(ship_type.map(|s| Box::new(s))),
             Value::Double(gross_tonnage),
             Value::String(owner.map(|s| Box::new(s))),
+            Value::Int(owner_id),
             Value::String(Some(Box::new(now))),
             Value::Int(Some(id)),
         ]
@@ -184,27 +232,27 @@ pub async fn delete(id: i32) -> Result<bool> {
     Ok(result.rows_affected() > 0)
 }
 
-/// Search ships by name, IMO or flag
+/// Search ships by name, IMO or flag (full-text, best match first)
 pub async fn search(query: &str) -> Result<Vec<Ship>> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    let search_term = format!("%{}%", query);
+    if query.trim().is_empty() {
+        return get_all().await;
+    }
 
+    let ids = search_service::ranked_ids(&conn, SearchScope::Ships, query).await?;
+    let (placeholders, values) = search_service::id_list(&ids);
     let rows: Vec<ShipRow> = ShipRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, created_at, updated_at FROM ships WHERE is_active = 1 AND (name LIKE ? OR imo_number LIKE ? OR flag LIKE ?) ORDER BY name",
-        vec![
-            Value::String(Some(Box::new(search_term.clone()))),
-            Value::String(Some(Box::new(search_term.clone()))),
-            Value::String(Some(Box::new(search_term))),
-        ]
+        format!("SELECT id, name, imo_number, flag, ship_type, gross_tonnage, owner, owner_id, created_at, updated_at FROM ships WHERE is_active = 1 AND id IN ({})", placeholders),
+        values
     ))
     .all(&conn)
     .await?;
 
-    Ok(rows.into_iter().map(Ship::from).collect())
+    Ok(search_service::in_rank_order(&ids, rows.into_iter().map(Ship::from).collect(), |s| s.id))
 }
 
 /// Count total ships
@@ -403,6 +403,7 @@ pub async fn get_calendar_data(start_date: &str, end_date: &str) -> Result<Calen
         latitude: Option<f64>,
         longitude: Option<f64>,
         notes: Option<String>,
+        is_international: i32,
         is_active: i32,
         created_at: String,
         updated_at: String,
@@ -412,7 +413,7 @@ pub async fn get_calendar_data(start_date: &str, end_date: &str) -> Result<Calen
         DatabaseBackend::Sqlite,
         r#"
         SELECT id, name, country, city, timezone, latitude, longitude, 
-               notes, is_active, created_at, updated_at
+               notes, is_international, is_active, created_at, updated_at
         FROM ports
         WHERE is_active = 1
         ORDER BY name ASC
@@ -430,6 +431,7 @@ pub async fn get_calendar_data(start_date: &str, end_date: &str) -> Result<Calen
         latitude: row.latitude,
         longitude: row.longitude,
         notes: row.notes,
+        is_international: row.is_international == 1,
         is_active: row.is_active == 1,
         created_at: row.created_at,
         updated_at: row.updated_at,
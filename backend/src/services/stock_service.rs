@@ -2,11 +2,13 @@
 
 use crate::models::{
     Stock, StockMovement, StockMovementType, StockWithMovements, StockSummary,
-    CreateStockRequest, UpdateStockRequest, CreateStockMovementRequest,
+    CreateStockRequest, UpdateStockRequest, CreateStockMovementRequest, WarehouseType,
 };
+use crate::models::OrderStatus;
 use crate::database;
+use crate::services::{price_agreement_service, unit_service, order_service, order_item_service, bonded_service};
 use anyhow::Result;
-use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult};
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
 
 #[derive(Debug, FromQueryResult)]
 struct StockRow {
@@ -16,6 +18,7 @@ struct StockRow {
     quantity: f64,
     unit: String,
     warehouse_location: Option<String>,
+    warehouse_type: String,
     minimum_quantity: f64,
     last_updated: String,
 }
@@ -29,6 +32,10 @@ impl From<StockRow> for Stock {
             quantity: row.quantity,
             unit: row.unit,
             warehouse_location: row.warehouse_location,
+            warehouse_type: match row.warehouse_type.as_str() {
+                "BONDED" => WarehouseType::Bonded,
+                _ => WarehouseType::General,
+            },
             minimum_quantity: row.minimum_quantity,
             last_updated: row.last_updated,
         }
@@ -46,7 +53,11 @@ struct StockMovementRow {
     reference_type: Option<String>,
     reference_id: Option<i32>,
     reference_info: Option<String>,
+    order_item_id: Option<i32>,
     notes: Option<String>,
+    entered_quantity: Option<f64>,
+    entered_unit: Option<String>,
+    customs_declaration_number: Option<String>,
     created_at: String,
 }
 
@@ -70,17 +81,16 @@ impl From<StockMovementRow> for StockMovement {
             reference_type: row.reference_type,
             reference_id: row.reference_id,
             reference_info: row.reference_info,
+            order_item_id: row.order_item_id,
             notes: row.notes,
+            entered_quantity: row.entered_quantity,
+            entered_unit: row.entered_unit,
+            customs_declaration_number: row.customs_declaration_number,
             created_at: row.created_at,
         }
     }
 }
 
-#[derive(Debug, FromQueryResult)]
-struct IdRow {
-    id: i32,
-}
-
 #[derive(Debug, FromQueryResult)]
 struct SummaryRow {
     total_items: i32,
@@ -90,15 +100,15 @@ struct SummaryRow {
 
 const STOCK_SELECT: &str = r#"
     s.id, s.supply_item_id, si.name as supply_item_name, 
-    s.quantity, s.unit, s.warehouse_location, 
+    s.quantity, s.unit, s.warehouse_location, s.warehouse_type,
     s.minimum_quantity, s.last_updated
 "#;
 
 const MOVEMENT_SELECT: &str = r#"
     sm.id, sm.stock_id, si.name as supply_item_name,
     sm.movement_type, sm.quantity, sm.unit,
-    sm.reference_type, sm.reference_id, sm.reference_info,
-    sm.notes, sm.created_at
+    sm.reference_type, sm.reference_id, sm.reference_info, sm.order_item_id,
+    sm.notes, sm.entered_quantity, sm.entered_unit, sm.customs_declaration_number, sm.created_at
 "#;
 
 // ============================================================================
@@ -194,47 +204,66 @@ pub async fn get_by_supply_item(supply_item_id: i32) -> Result<Option<Stock>> {
     Ok(row.map(Stock::from))
 }
 
-/// Create new stock entry
+/// Create new stock entry.
+///
+/// Bonded items are kept in the bonded warehouse and only there; bonded
+/// stock starts empty so that every unit on hand comes in under a customs
+/// declaration.
 pub async fn create(req: CreateStockRequest) -> Result<Stock> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    let warehouse_loc = req.warehouse_location
-        .map(|l| format!("'{}'", l.replace("'", "''")))
-        .unwrap_or_else(|| "NULL".to_string());
-
-    let sql = format!(
-        "INSERT INTO stock (supply_item_id, quantity, unit, warehouse_location, minimum_quantity) 
-         VALUES ({}, {}, '{}', {}, {})",
-        req.supply_item_id,
-        req.quantity,
-        req.unit.replace("'", "''"),
-        warehouse_loc,
-        req.minimum_quantity
-    );
-
-    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, sql)).await?;
-
-    // Get the created stock
-    let id_row: IdRow = IdRow::find_by_statement(
-        Statement::from_string(DatabaseBackend::Sqlite, "SELECT last_insert_rowid() as id".to_string())
-    )
-    .one(&conn)
-    .await?
-    .ok_or_else(|| anyhow::anyhow!("Failed to get created stock ID"))?;
+    let unit = unit_service::canonical(&conn, &req.unit).await?;
+    let is_bonded = bonded_service::is_bonded_item(&conn, req.supply_item_id).await?;
+    match (req.warehouse_type, is_bonded) {
+        (WarehouseType::General, true) => anyhow::bail!("Bonded items are kept in the bonded warehouse"),
+        (WarehouseType::Bonded, false) => anyhow::bail!("Only bonded items can be kept in the bonded warehouse"),
+        (WarehouseType::Bonded, true) if req.quantity != 0.0 => {
+            anyhow::bail!("Bonded stock starts empty; receive the goods with a customs declaration")
+        }
+        _ => {}
+    }
 
-    get_by_id(id_row.id)
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT INTO stock (supply_item_id, quantity, unit, warehouse_location, warehouse_type, minimum_quantity) VALUES (?, ?, ?, ?, ?, ?)",
+        vec![
+            Value::Int(Some(req.supply_item_id)),
+            Value::Double(Some(req.quantity)),
+            Value::String(Some(Box::new(unit))),
+            Value::String(req.warehouse_location.map(Box::new)),
+            Value::String(Some(Box::new(warehouse_type_str(req.warehouse_type).to_string()))),
+            Value::Double(Some(req.minimum_quantity)),
+        ]
+    )).await?;
+
+    get_by_id(result.last_insert_id() as i32)
         .await?
         .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created stock"))
 }
 
+fn warehouse_type_str(warehouse_type: WarehouseType) -> &'static str {
+    match warehouse_type {
+        WarehouseType::General => "GENERAL",
+        WarehouseType::Bonded => "BONDED",
+    }
+}
+
 /// Update stock
 pub async fn update(id: i32, req: UpdateStockRequest) -> Result<Stock> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
+    if req.quantity.is_some() {
+        let stock = get_by_id(id).await?
+            .ok_or_else(|| anyhow::anyhow!("Stock not found"))?;
+        if stock.warehouse_type == WarehouseType::Bonded {
+            anyhow::bail!("Bonded stock only changes through movements with a customs declaration");
+        }
+    }
+
     let mut updates = Vec::new();
     
     if let Some(quantity) = req.quantity {
@@ -268,6 +297,12 @@ pub async fn delete(id: i32) -> Result<bool> {
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
+    if let Some(stock) = get_by_id(id).await? {
+        if stock.warehouse_type == WarehouseType::Bonded && !get_movements(id).await?.is_empty() {
+            anyhow::bail!("Bonded stock with movements is kept for customs reconciliation");
+        }
+    }
+
     // Delete movements first
     let delete_movements = format!("DELETE FROM stock_movements WHERE stock_id = {}", id);
     conn.execute(Statement::from_string(DatabaseBackend::Sqlite, delete_movements)).await?;
@@ -331,7 +366,31 @@ pub async fn get_recent_movements(limit: i32) -> Result<Vec<StockMovement>> {
     Ok(rows.into_iter().map(StockMovement::from).collect())
 }
 
-/// Create stock movement and update stock quantity
+/// All movements of bonded stock, oldest first
+pub(crate) async fn bonded_movements<C: ConnectionTrait>(conn: &C) -> Result<Vec<StockMovement>> {
+    let rows: Vec<StockMovementRow> = StockMovementRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        format!(
+            "SELECT {} FROM stock_movements sm
+             JOIN stock s ON sm.stock_id = s.id
+             LEFT JOIN supply_items si ON s.supply_item_id = si.id
+             WHERE s.warehouse_type = 'BONDED'
+             ORDER BY sm.created_at ASC, sm.id ASC",
+            MOVEMENT_SELECT
+        )
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(StockMovement::from).collect())
+}
+
+/// Create stock movement and update stock quantity.
+///
+/// A quantity in another unit than the stock (boxes for a stock kept in
+/// pieces) is converted to the stock unit; incompatible units are an error.
+/// Bonded stock needs a customs declaration number on every movement, and
+/// goods issued from it for an order must go to an eligible ship.
 pub async fn create_movement(req: CreateStockMovementRequest) -> Result<StockMovement> {
     let conn = database::get_connection()
         .await
@@ -342,6 +401,25 @@ pub async fn create_movement(req: CreateStockMovementRequest) -> Result<StockMov
         .await?
         .ok_or_else(|| anyhow::anyhow!("Stock not found"))?;
 
+    let entered_unit = req.unit.as_deref().map(str::trim).filter(|u| !u.is_empty()).unwrap_or(&stock.unit);
+    let quantity = unit_service::convert(&conn, req.quantity, entered_unit, &stock.unit, Some(stock.supply_item_id)).await?;
+    let entered = entered_movement(&conn, (req.quantity, entered_unit), &stock).await?;
+    let declaration = customs_declaration(&stock, req.customs_declaration_number)?;
+    if stock.warehouse_type == WarehouseType::Bonded && req.movement_type == StockMovementType::Out {
+        // Bonded goods leave the warehouse only for an eligible ship's order
+        match (req.reference_type.as_deref(), req.reference_id) {
+            (Some("order"), Some(order_id)) => bonded_service::ensure_eligible(&conn, order_id).await?,
+            _ => anyhow::bail!("Bonded stock can only be issued against an order"),
+        }
+    }
+    if let Some(order_item_id) = req.order_item_id {
+        let item = order_item_service::get_by_id(order_item_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Order item {} not found", order_item_id))?;
+        if req.reference_type.as_deref() != Some("order") || req.reference_id != Some(item.order_id) {
+            anyhow::bail!("Order item {} is not on the referenced order", order_item_id);
+        }
+    }
+
     let movement_type_str = match req.movement_type {
         StockMovementType::In => "IN",
         StockMovementType::Out => "OUT",
@@ -349,82 +427,220 @@ pub async fn create_movement(req: CreateStockMovementRequest) -> Result<StockMov
         StockMovementType::Return => "RETURN",
     };
 
-    let ref_type = req.reference_type
-        .map(|r| format!("'{}'", r.replace("'", "''")))
-        .unwrap_or_else(|| "NULL".to_string());
-    
-    let ref_id = req.reference_id
-        .map(|i| i.to_string())
-        .unwrap_or_else(|| "NULL".to_string());
-    
-    let ref_info = req.reference_info
-        .map(|r| format!("'{}'", r.replace("'", "''")))
-        .unwrap_or_else(|| "NULL".to_string());
-    
-    let notes = req.notes
-        .map(|n| format!("'{}'", n.replace("'", "''")))
-        .unwrap_or_else(|| "NULL".to_string());
-
     // Insert movement
-    let sql = format!(
-        "INSERT INTO stock_movements (stock_id, movement_type, quantity, unit, reference_type, reference_id, reference_info, notes) 
-         VALUES ({}, '{}', {}, '{}', {}, {}, {}, {})",
-        req.stock_id,
-        movement_type_str,
-        req.quantity,
-        stock.unit.replace("'", "''"),
-        ref_type,
-        ref_id,
-        ref_info,
-        notes
-    );
-
-    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, sql)).await?;
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO stock_movements (stock_id, movement_type, quantity, unit, reference_type, reference_id, reference_info, order_item_id, notes, entered_quantity, entered_unit, customs_declaration_number)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(req.stock_id)),
+            Value::String(Some(Box::new(movement_type_str.to_string()))),
+            Value::Double(Some(quantity)),
+            Value::String(Some(Box::new(stock.unit.clone()))),
+            Value::String(req.reference_type.map(Box::new)),
+            Value::Int(req.reference_id),
+            Value::String(req.reference_info.map(Box::new)),
+            Value::Int(req.order_item_id),
+            Value::String(req.notes.map(Box::new)),
+            Value::Double(entered.as_ref().map(|(q, _)| *q)),
+            Value::String(entered.map(|(_, u)| Box::new(u))),
+            Value::String(declaration.map(Box::new)),
+        ]
+    )).await?;
+    let movement_id = result.last_insert_id() as i32;
 
     // Update stock quantity based on movement type
-    let quantity_change = match req.movement_type {
-        StockMovementType::In | StockMovementType::Return => req.quantity,
-        StockMovementType::Out => -req.quantity,
-        StockMovementType::Adjustment => req.quantity - stock.quantity, // Adjustment sets absolute value
+    let new_quantity = match req.movement_type {
+        StockMovementType::In | StockMovementType::Return => stock.quantity + quantity,
+        StockMovementType::Out => (stock.quantity - quantity).max(0.0),
+        StockMovementType::Adjustment => quantity, // Adjustment sets the exact quantity
     };
 
-    let new_quantity = if req.movement_type == StockMovementType::Adjustment {
-        req.quantity // Adjustment sets the exact quantity
-    } else {
-        (stock.quantity + quantity_change).max(0.0)
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE stock SET quantity = ?, last_updated = datetime('now') WHERE id = ?",
+        vec![Value::Double(Some(new_quantity)), Value::Int(Some(req.stock_id))]
+    )).await?;
+
+    movement_by_id(&conn, movement_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created movement"))
+}
+
+/// Entered quantity and unit worth keeping next to the converted movement (None when in the stock unit)
+async fn entered_movement<C: ConnectionTrait>(conn: &C, entered: (f64, &str), stock: &Stock) -> Result<Option<(f64, String)>> {
+    let (quantity, unit) = entered;
+    if unit_service::unit_key(unit) == unit_service::unit_key(&stock.unit) {
+        return Ok(None);
+    }
+    Ok(Some((quantity, unit_service::canonical(conn, unit).await?)))
+}
+
+/// Trimmed customs declaration number of a movement; required in the bonded warehouse
+fn customs_declaration(stock: &Stock, number: Option<String>) -> Result<Option<String>> {
+    let number = number.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+    if number.is_none() && stock.warehouse_type == WarehouseType::Bonded {
+        anyhow::bail!(
+            "Movements of bonded stock ({}) need a customs declaration number",
+            stock.supply_item_name.as_deref().unwrap_or("unknown item")
+        );
+    }
+    Ok(number)
+}
+
+async fn movement_by_id<C: ConnectionTrait>(conn: &C, id: i32) -> Result<Option<StockMovement>> {
+    let row: Option<StockMovementRow> = StockMovementRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            "SELECT {} FROM stock_movements sm
+             LEFT JOIN stock s ON sm.stock_id = s.id
+             LEFT JOIN supply_items si ON s.supply_item_id = si.id
+             WHERE sm.id = ?",
+            MOVEMENT_SELECT
+        ),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(StockMovement::from))
+}
+
+/// Stock record of the supply item with this IMPA code, read on the given connection or transaction.
+/// Duty-paid stock comes before bonded stock.
+pub(crate) async fn find_by_impa<C: ConnectionTrait>(conn: &C, impa_code: &str) -> Result<Option<Stock>> {
+    let row: Option<StockRow> = StockRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!(
+            "SELECT {} FROM stock s
+             LEFT JOIN supply_items si ON s.supply_item_id = si.id
+             WHERE replace(si.impa_code, ' ', '') = ?
+             ORDER BY s.warehouse_type = 'BONDED', s.id LIMIT 1",
+            STOCK_SELECT
+        ),
+        vec![Value::String(Some(Box::new(price_agreement_service::normalize_impa(impa_code))))]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(Stock::from))
+}
+
+/// Record an incoming (In / Return) or outgoing (Out) movement as part of a
+/// larger transaction and update the stock quantity. `quantity` is given in
+/// its own unit and converted to the stock unit. Bonded stock needs the
+/// customs declaration number. Returns the movement ID.
+pub(crate) async fn post_movement<C: ConnectionTrait>(
+    conn: &C,
+    stock: &Stock,
+    movement_type: StockMovementType,
+    quantity: (f64, &str),
+    reference: (&str, i32, Option<String>),
+    notes: Option<String>,
+    customs_declaration_number: Option<String>,
+) -> Result<i32> {
+    let declaration = customs_declaration(stock, customs_declaration_number)?;
+    let entered = entered_movement(conn, quantity, stock).await?;
+    let quantity = unit_service::convert(conn, quantity.0, quantity.1, &stock.unit, Some(stock.supply_item_id)).await?;
+    let (movement_type_str, quantity_change) = match movement_type {
+        StockMovementType::In => ("IN", quantity),
+        StockMovementType::Return => ("RETURN", quantity),
+        StockMovementType::Out => ("OUT", -quantity),
+        StockMovementType::Adjustment => anyhow::bail!("Adjustments set the stock level and cannot be posted"),
     };
+    let (reference_type, reference_id, reference_info) = reference;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO stock_movements (stock_id, movement_type, quantity, unit, reference_type, reference_id, reference_info, notes, entered_quantity, entered_unit, customs_declaration_number)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(stock.id)),
+            Value::String(Some(Box::new(movement_type_str.to_string()))),
+            Value::Double(Some(quantity)),
+            Value::String(Some(Box::new(stock.unit.clone()))),
+            Value::String(Some(Box::new(reference_type.to_string()))),
+            Value::Int(Some(reference_id)),
+            Value::String(reference_info.map(Box::new)),
+            Value::String(notes.map(Box::new)),
+            Value::Double(entered.as_ref().map(|(q, _)| *q)),
+            Value::String(entered.map(|(_, u)| Box::new(u))),
+            Value::String(declaration.map(Box::new)),
+        ]
+    )).await?;
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE stock SET quantity = MAX(quantity + ?, 0), last_updated = datetime('now') WHERE id = ?",
+        vec![Value::Double(Some(quantity_change)), Value::Int(Some(stock.id))]
+    )).await?;
+
+    Ok(result.last_insert_id() as i32)
+}
 
-    let update_stock = format!(
-        "UPDATE stock SET quantity = {}, last_updated = datetime('now') WHERE id = {}",
-        new_quantity, req.stock_id
-    );
-    conn.execute(Statement::from_string(DatabaseBackend::Sqlite, update_stock)).await?;
+/// Issue goods from the warehouse for an order line (an Out movement).
+///
+/// `quantity` is in the order line's unit (default: the whole line) and is
+/// converted to the stock unit. Without `stock_id` the stock record of the
+/// line's IMPA code is used. More than the stock on hand cannot be issued.
+/// Bonded stock is only issued to eligible ships, under a customs declaration.
+pub async fn issue_to_order(
+    order_item_id: i32,
+    stock_id: Option<i32>,
+    quantity: Option<f64>,
+    customs_declaration_number: Option<String>,
+) -> Result<StockMovement> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    // Get the created movement
-    let id_row: IdRow = IdRow::find_by_statement(
-        Statement::from_string(DatabaseBackend::Sqlite, "SELECT last_insert_rowid() as id".to_string())
-    )
-    .one(&conn)
-    .await?
-    .ok_or_else(|| anyhow::anyhow!("Failed to get created movement ID"))?;
+    let item = order_item_service::get_by_id(order_item_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order item not found"))?;
+    let order = order_service::get_by_id(item.order_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    if order.status == OrderStatus::Cancelled {
+        anyhow::bail!("Order {} is cancelled", order.order_number);
+    }
 
-    // Return the movement
-    let movement_sql = format!(
-        "SELECT {} FROM stock_movements sm
-         LEFT JOIN stock s ON sm.stock_id = s.id
-         LEFT JOIN supply_items si ON s.supply_item_id = si.id
-         WHERE sm.id = {}",
-        MOVEMENT_SELECT, id_row.id
-    );
+    let stock = match (stock_id, item.impa_code.as_deref()) {
+        (Some(id), _) => get_by_id(id).await?,
+        (None, Some(impa_code)) => find_by_impa(&conn, impa_code).await?,
+        (None, None) => None,
+    }
+    .ok_or_else(|| anyhow::anyhow!("{} has no stock record to issue from", item.product_name))?;
+    if stock.warehouse_type == WarehouseType::Bonded {
+        bonded_service::ensure_eligible(&conn, order.id).await?;
+    }
 
-    let row: StockMovementRow = StockMovementRow::find_by_statement(
-        Statement::from_string(DatabaseBackend::Sqlite, movement_sql)
-    )
-    .one(&conn)
-    .await?
-    .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created movement"))?;
+    let quantity = quantity.unwrap_or(item.quantity);
+    if quantity <= 0.0 {
+        anyhow::bail!("Issued quantity must be positive");
+    }
+    let stock_quantity = unit_service::convert(&conn, quantity, &item.unit, &stock.unit, Some(stock.supply_item_id)).await?;
+    if stock_quantity > stock.quantity + 1e-9 {
+        anyhow::bail!(
+            "Only {} {} of {} in stock ({} {} needed)",
+            stock.quantity, stock.unit, item.product_name, stock_quantity, stock.unit
+        );
+    }
 
-    Ok(StockMovement::from(row))
+    let txn = conn.begin().await?;
+    let movement_id = post_movement(
+        &txn,
+        &stock,
+        StockMovementType::Out,
+        (quantity, &item.unit),
+        ("order", order.id, Some(format!("{} / {}", order.order_number, item.product_name))),
+        None,
+        customs_declaration_number,
+    ).await?;
+    txn.commit().await?;
+
+    movement_by_id(&conn, movement_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created movement"))
 }
 
 /// Get stock with all its movements
@@ -464,16 +680,35 @@ pub async fn get_summary() -> Result<StockSummary> {
         out_of_stock_count: 0,
     });
 
-    // Calculate total value (quantity * unit_price for each item)
+    // Calculate total value (quantity * (unit_price + landed cost per unit) for each item).
+    // Landed cost per unit is averaged over the order lines (in the catalog currency) it was allocated to;
+    // allocations are summed per line first so each line's quantity counts once.
     let value_sql = r#"
-        SELECT COALESCE(SUM(s.quantity * si.unit_price), 0) as total_value
+        SELECT 
+            CAST(COALESCE(SUM(s.quantity * si.unit_price), 0) AS REAL) as catalog_value,
+            CAST(COALESCE(SUM(s.quantity * COALESCE(lc.per_unit, 0)), 0) AS REAL) as landed_cost_value
         FROM stock s
         JOIN supply_items si ON s.supply_item_id = si.id
+        LEFT JOIN (
+            SELECT la.stock_id, SUM(la.amount) / SUM(oi.quantity) as per_unit
+            FROM (
+                SELECT stock_id, order_item_id, SUM(amount) as amount
+                FROM landed_cost_allocations
+                GROUP BY stock_id, order_item_id
+            ) la
+            JOIN order_items oi ON la.order_item_id = oi.id
+            JOIN orders o ON oi.order_id = o.id
+            JOIN stock ls ON la.stock_id = ls.id
+            JOIN supply_items lsi ON ls.supply_item_id = lsi.id
+            WHERE oi.quantity > 0 AND o.currency = lsi.currency
+            GROUP BY la.stock_id
+        ) lc ON lc.stock_id = s.id
     "#;
 
     #[derive(Debug, FromQueryResult)]
     struct ValueRow {
-        total_value: f64,
+        catalog_value: f64,
+        landed_cost_value: f64,
     }
 
     let value_row: ValueRow = ValueRow::find_by_statement(
@@ -481,13 +716,14 @@ pub async fn get_summary() -> Result<StockSummary> {
     )
     .one(&conn)
     .await?
-    .unwrap_or(ValueRow { total_value: 0.0 });
+    .unwrap_or(ValueRow { catalog_value: 0.0, landed_cost_value: 0.0 });
 
     Ok(StockSummary {
         total_items: row.total_items,
         low_stock_count: row.low_stock_count,
         out_of_stock_count: row.out_of_stock_count,
-        total_value: value_row.total_value,
+        total_value: value_row.catalog_value + value_row.landed_cost_value,
+        landed_cost_value: value_row.landed_cost_value,
         currency: "USD".to_string(),
     })
 }
@@ -0,0 +1,626 @@
+//! Supplier Invoice Service - Payables side: supplier invoices and 3-way matching
+//!
+//! A supplier invoice is matched against the purchase order (the order's items
+//! and their estimated `buying_price`) and the goods receipts (stock IN
+//! movements referencing the order line, or the order for older receipts).
+
+use crate::models::{
+    SupplierInvoice, SupplierInvoiceLine, CreateSupplierInvoiceRequest, InvoiceStatus,
+    SupplierInvoiceMatch, SupplierInvoiceLineMatch, MatchStatus, DeliveryType, OrderItem,
+};
+use crate::database;
+use crate::services::invoice_service::{invoice_status_from_str, invoice_status_to_str, parse_date, AMOUNT_EPSILON};
+use crate::services::{order_item_service, order_service, price_agreement_service, supply_item_service};
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+/// Quantities closer than this are considered equal
+const QUANTITY_EPSILON: f64 = 0.0001;
+
+#[derive(Debug, FromQueryResult)]
+struct SupplierInvoiceRow {
+    id: i32,
+    supplier_id: i32,
+    supplier_name: Option<String>,
+    invoice_number: String,
+    order_id: Option<i32>,
+    order_number: Option<String>,
+    amount: f64,
+    paid_amount: f64,
+    currency: String,
+    issue_date: String,
+    due_date: String,
+    status: String,
+    approved_at: Option<String>,
+    notes: Option<String>,
+    created_at: String,
+    updated_at: String,
+}
+
+impl SupplierInvoiceRow {
+    fn into_supplier_invoice(self, lines: Vec<SupplierInvoiceLine>) -> SupplierInvoice {
+        SupplierInvoice {
+            id: self.id,
+            supplier_id: self.supplier_id,
+            supplier_name: self.supplier_name,
+            invoice_number: self.invoice_number,
+            order_id: self.order_id,
+            order_number: self.order_number,
+            amount: self.amount,
+            paid_amount: self.paid_amount,
+            balance: self.amount - self.paid_amount,
+            currency: self.currency,
+            issue_date: self.issue_date,
+            due_date: self.due_date,
+            status: invoice_status_from_str(&self.status),
+            approved_at: self.approved_at,
+            notes: self.notes,
+            lines,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, FromQueryResult)]
+struct LineRow {
+    id: i32,
+    supplier_invoice_id: i32,
+    order_item_id: Option<i32>,
+    supply_item_id: Option<i32>,
+    description: String,
+    quantity: f64,
+    unit_price: f64,
+}
+
+impl From<LineRow> for SupplierInvoiceLine {
+    fn from(row: LineRow) -> Self {
+        SupplierInvoiceLine {
+            id: row.id,
+            supplier_invoice_id: row.supplier_invoice_id,
+            order_item_id: row.order_item_id,
+            supply_item_id: row.supply_item_id,
+            description: row.description,
+            quantity: row.quantity,
+            unit_price: row.unit_price,
+            line_total: row.quantity * row.unit_price,
+        }
+    }
+}
+
+pub(crate) const SELECT_FIELDS: &str = r#"
+    si.id, si.supplier_id, s.name as supplier_name, si.invoice_number, si.order_id, o.order_number,
+    CAST(COALESCE((SELECT SUM(l.quantity * l.unit_price) FROM supplier_invoice_lines l WHERE l.supplier_invoice_id = si.id), 0.0) AS REAL) as amount,
+    CAST(COALESCE((SELECT SUM(sp.amount) FROM supplier_payments sp WHERE sp.supplier_invoice_id = si.id), 0.0) AS REAL) as paid_amount,
+    si.currency, si.issue_date, si.due_date, si.status, si.approved_at, si.notes, si.created_at, si.updated_at
+"#;
+
+pub(crate) const FROM_JOIN: &str = r#"
+    FROM supplier_invoices si
+    LEFT JOIN suppliers s ON si.supplier_id = s.id
+    LEFT JOIN orders o ON si.order_id = o.id
+"#;
+
+async fn get_lines<C: ConnectionTrait>(conn: &C, supplier_invoice_id: i32) -> Result<Vec<SupplierInvoiceLine>> {
+    let rows: Vec<LineRow> = LineRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT id, supplier_invoice_id, order_item_id, supply_item_id, description, quantity, unit_price
+        FROM supplier_invoice_lines
+        WHERE supplier_invoice_id = ?
+        ORDER BY id
+        "#,
+        vec![Value::Int(Some(supplier_invoice_id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(SupplierInvoiceLine::from).collect())
+}
+
+async fn rows_to_invoices<C: ConnectionTrait>(conn: &C, rows: Vec<SupplierInvoiceRow>) -> Result<Vec<SupplierInvoice>> {
+    let mut invoices = Vec::with_capacity(rows.len());
+    for row in rows {
+        let lines = get_lines(conn, row.id).await?;
+        invoices.push(row.into_supplier_invoice(lines));
+    }
+    Ok(invoices)
+}
+
+/// Get all supplier invoices with optional status filter
+pub async fn get_all(status_filter: Option<InvoiceStatus>) -> Result<Vec<SupplierInvoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<SupplierInvoiceRow> = match status_filter {
+        Some(status) => SupplierInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            format!("SELECT {} {} WHERE si.status = ? ORDER BY si.due_date ASC, si.id ASC", SELECT_FIELDS, FROM_JOIN),
+            vec![Value::String(Some(Box::new(invoice_status_to_str(status).to_string())))]
+        ))
+        .all(&conn)
+        .await?,
+        None => SupplierInvoiceRow::find_by_statement(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            format!("SELECT {} {} ORDER BY si.due_date ASC, si.id ASC", SELECT_FIELDS, FROM_JOIN)
+        ))
+        .all(&conn)
+        .await?,
+    };
+
+    rows_to_invoices(&conn, rows).await
+}
+
+/// Get supplier invoices for a supplier
+pub async fn get_by_supplier(supplier_id: i32) -> Result<Vec<SupplierInvoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<SupplierInvoiceRow> = SupplierInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE si.supplier_id = ? ORDER BY si.due_date ASC, si.id ASC", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(supplier_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    rows_to_invoices(&conn, rows).await
+}
+
+/// Get supplier invoices billed against an order
+pub async fn get_by_order(order_id: i32) -> Result<Vec<SupplierInvoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<SupplierInvoiceRow> = SupplierInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE si.order_id = ? ORDER BY si.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(order_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    rows_to_invoices(&conn, rows).await
+}
+
+/// Get supplier invoice by ID (with lines)
+pub async fn get_by_id(id: i32) -> Result<Option<SupplierInvoice>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let row: Option<SupplierInvoiceRow> = SupplierInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE si.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    match row {
+        Some(row) => {
+            let lines = get_lines(&conn, row.id).await?;
+            Ok(Some(row.into_supplier_invoice(lines)))
+        }
+        None => Ok(None),
+    }
+}
+
+/// Record a supplier invoice with its lines
+pub async fn create(req: CreateSupplierInvoiceRequest) -> Result<SupplierInvoice> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    if req.invoice_number.trim().is_empty() {
+        anyhow::bail!("Supplier invoice number is required");
+    }
+    if req.lines.is_empty() {
+        anyhow::bail!("Supplier invoice must have at least one line");
+    }
+    let issue_date = parse_date(&req.issue_date)?;
+    let due_date = parse_date(&req.due_date)?;
+    if due_date < issue_date {
+        anyhow::bail!("Due date cannot be before issue date");
+    }
+
+    if let Some(order_id) = req.order_id {
+        order_service::get_by_id(order_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Order not found"))?;
+    }
+    for line in &req.lines {
+        if line.quantity <= 0.0 || line.unit_price < 0.0 {
+            anyhow::bail!("Invalid quantity or price on line '{}'", line.description);
+        }
+        if let Some(order_item_id) = line.order_item_id {
+            let item = order_item_service::get_by_id(order_item_id).await?
+                .ok_or_else(|| anyhow::anyhow!("Order item {} not found", order_item_id))?;
+            if req.order_id != Some(item.order_id) {
+                anyhow::bail!("Order item {} does not belong to the invoiced order", order_item_id);
+            }
+        }
+        if let Some(supply_item_id) = line.supply_item_id {
+            let supply_item = supply_item_service::get_by_id(supply_item_id).await?
+                .ok_or_else(|| anyhow::anyhow!("Supply item {} not found", supply_item_id))?;
+            if supply_item.supplier_id != req.supplier_id {
+                anyhow::bail!("{} is not in the invoicing supplier's catalog", supply_item.name);
+            }
+        }
+    }
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO supplier_invoices (supplier_id, invoice_number, order_id, currency, issue_date, due_date, status, notes)
+        VALUES (?, ?, ?, ?, ?, ?, 'OPEN', ?)
+        "#,
+        vec![
+            Value::Int(Some(req.supplier_id)),
+            Value::String(Some(Box::new(req.invoice_number.trim().to_string()))),
+            Value::Int(req.order_id),
+            Value::String(Some(Box::new(req.currency))),
+            Value::String(Some(Box::new(issue_date.format("%Y-%m-%d").to_string()))),
+            Value::String(Some(Box::new(due_date.format("%Y-%m-%d").to_string()))),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    for line in req.lines {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            r#"
+            INSERT INTO supplier_invoice_lines (supplier_invoice_id, order_item_id, supply_item_id, description, quantity, unit_price)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+            vec![
+                Value::Int(Some(id)),
+                Value::Int(line.order_item_id),
+                Value::Int(line.supply_item_id),
+                Value::String(Some(Box::new(line.description))),
+                Value::Double(Some(line.quantity)),
+                Value::Double(Some(line.unit_price)),
+            ]
+        )).await?;
+    }
+
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created supplier invoice"))
+}
+
+/// Cancel a supplier invoice. Invoices with payments cannot be cancelled.
+pub async fn cancel(id: i32) -> Result<SupplierInvoice> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let invoice = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supplier invoice not found"))?;
+
+    if invoice.paid_amount > AMOUNT_EPSILON {
+        anyhow::bail!("Supplier invoice {} has payments and cannot be cancelled", invoice.invoice_number);
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE supplier_invoices SET status = 'CANCELLED', updated_at = datetime('now') WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supplier invoice not found after update"))
+}
+
+/// Quantity received into the warehouse for an order line.
+///
+/// Receipts naming the line always count. Older receipts only name the order;
+/// they count by catalog item when no other line of the order is for the same item.
+async fn received_quantity<C: ConnectionTrait>(
+    conn: &C,
+    order_item: &OrderItem,
+    supply_item_id: Option<i32>,
+    shared_with_other_lines: bool,
+) -> Result<f64> {
+    #[derive(Debug, FromQueryResult)]
+    struct QuantityRow {
+        quantity: f64,
+    }
+
+    let row: Option<QuantityRow> = QuantityRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT CAST(COALESCE(SUM(sm.quantity), 0.0) AS REAL) as quantity
+        FROM stock_movements sm
+        JOIN stock s ON sm.stock_id = s.id
+        WHERE sm.movement_type = 'IN'
+          AND sm.reference_type = 'order'
+          AND sm.reference_id = ?1
+          AND (sm.order_item_id = ?2 OR (sm.order_item_id IS NULL AND ?3 = 0 AND s.supply_item_id = ?4))
+        "#,
+        vec![
+            Value::Int(Some(order_item.order_id)),
+            Value::Int(Some(order_item.id)),
+            Value::Int(Some(shared_with_other_lines as i32)),
+            Value::Int(supply_item_id),
+        ]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.quantity).unwrap_or(0.0))
+}
+
+/// Whether two lines of an order are for the same item (by IMPA code, else by name)
+fn same_item(a: &OrderItem, b: &OrderItem) -> bool {
+    match (a.impa_code.as_deref(), b.impa_code.as_deref()) {
+        (Some(x), Some(y)) => price_agreement_service::normalize_impa(x) == price_agreement_service::normalize_impa(y),
+        (None, None) => a.product_name.trim().eq_ignore_ascii_case(b.product_name.trim()),
+        _ => false,
+    }
+}
+
+/// Catalog item of the supplier that an invoice line bills for an order line.
+///
+/// Ok(None) when the order line is not in the supplier's catalog: the invoice
+/// line's own catalog item must be the supplier's (and carry the order line's
+/// IMPA code), otherwise the order line's IMPA code is looked up.
+async fn supplier_item_for_line<C: ConnectionTrait>(
+    conn: &C,
+    supplier_id: i32,
+    line: &SupplierInvoiceLine,
+    order_item: &OrderItem,
+) -> Result<Option<i32>> {
+    if let Some(supply_item_id) = line.supply_item_id {
+        let Some(supply_item) = supply_item_service::get_by_id(supply_item_id).await? else {
+            return Ok(None);
+        };
+        let same_code = match (order_item.impa_code.as_deref(), supply_item.impa_code.as_deref()) {
+            (Some(ordered), Some(billed)) => {
+                price_agreement_service::normalize_impa(ordered) == price_agreement_service::normalize_impa(billed)
+            }
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        return Ok((supply_item.supplier_id == supplier_id && same_code).then_some(supply_item.id));
+    }
+    match &order_item.impa_code {
+        Some(impa_code) => find_supply_item_by_impa(conn, supplier_id, impa_code).await,
+        None => Ok(None),
+    }
+}
+
+/// Catalog item of this supplier with the given IMPA code
+async fn find_supply_item_by_impa<C: ConnectionTrait>(conn: &C, supplier_id: i32, impa_code: &str) -> Result<Option<i32>> {
+    #[derive(Debug, FromQueryResult)]
+    struct IdRow {
+        id: i32,
+    }
+
+    let row: Option<IdRow> = IdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT id FROM supply_items WHERE supplier_id = ? AND impa_code = ? ORDER BY id LIMIT 1",
+        vec![
+            Value::Int(Some(supplier_id)),
+            Value::String(Some(Box::new(impa_code.to_string()))),
+        ]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.map(|r| r.id))
+}
+
+/// 3-way match: invoice lines vs. purchase order lines vs. goods receipts.
+///
+/// Direct-to-ship lines never pass through the warehouse, so they are only
+/// matched against the order (2-way). Order lines that are not in the
+/// supplier's catalog are reported as billed by the wrong supplier.
+pub async fn match_invoice(id: i32) -> Result<SupplierInvoiceMatch> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let invoice = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supplier invoice not found"))?;
+
+    let order_items = match invoice.order_id {
+        Some(order_id) => order_item_service::get_by_order_id(order_id).await?,
+        None => Vec::new(),
+    };
+
+    let mut lines = Vec::with_capacity(invoice.lines.len());
+    for line in &invoice.lines {
+        let order_item = match (invoice.order_id, line.order_item_id) {
+            (Some(_), Some(order_item_id)) => order_item_service::get_by_id(order_item_id).await?,
+            _ => None,
+        };
+
+        let Some(order_item) = order_item else {
+            lines.push(SupplierInvoiceLineMatch {
+                line_id: line.id,
+                description: line.description.clone(),
+                order_item_id: line.order_item_id,
+                ordered_quantity: None,
+                ordered_price: None,
+                received_quantity: None,
+                invoiced_quantity: line.quantity,
+                invoiced_price: line.unit_price,
+                price_variance: 0.0,
+                status: MatchStatus::Unmatched,
+            });
+            continue;
+        };
+
+        let supply_item_id = supplier_item_for_line(&conn, invoice.supplier_id, line, &order_item).await?;
+        let received = if order_item.delivery_type == DeliveryType::DirectToShip {
+            None
+        } else {
+            let shared = order_items.iter().any(|i| i.id != order_item.id && same_item(i, &order_item));
+            Some(received_quantity(&conn, &order_item, supply_item_id, shared).await?)
+        };
+
+        let price_variance = (line.unit_price - order_item.buying_price) * line.quantity;
+
+        let status = if supply_item_id.is_none() {
+            MatchStatus::WrongSupplier
+        } else if received.is_some_and(|r| r <= QUANTITY_EPSILON) {
+            MatchStatus::NotReceived
+        } else if received.is_some_and(|r| (r - line.quantity).abs() > QUANTITY_EPSILON)
+            || (order_item.quantity - line.quantity).abs() > QUANTITY_EPSILON
+        {
+            MatchStatus::QuantityVariance
+        } else if price_variance.abs() > AMOUNT_EPSILON {
+            MatchStatus::PriceVariance
+        } else {
+            MatchStatus::Matched
+        };
+
+        lines.push(SupplierInvoiceLineMatch {
+            line_id: line.id,
+            description: line.description.clone(),
+            order_item_id: Some(order_item.id),
+            ordered_quantity: Some(order_item.quantity),
+            ordered_price: Some(order_item.buying_price),
+            received_quantity: received,
+            invoiced_quantity: line.quantity,
+            invoiced_price: line.unit_price,
+            price_variance,
+            status,
+        });
+    }
+
+    let is_fully_matched = lines.iter().all(|l| l.status == MatchStatus::Matched);
+    let total_price_variance = lines.iter().map(|l| l.price_variance).sum();
+
+    Ok(SupplierInvoiceMatch {
+        supplier_invoice_id: id,
+        lines,
+        is_fully_matched,
+        total_price_variance,
+    })
+}
+
+/// Approve a supplier invoice for payment.
+///
+/// Requires a clean 3-way match unless `accept_variances` is set; lines for
+/// another supplier's goods are never accepted. On approval the billed unit
+/// prices replace the estimated `buying_price` of the linked order items, so
+/// profit reports use actual cost. Lines for order items in another currency
+/// than the invoice are an error.
+pub async fn approve(id: i32, accept_variances: bool) -> Result<SupplierInvoice> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let invoice = get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supplier invoice not found"))?;
+    if invoice.status == InvoiceStatus::Cancelled {
+        anyhow::bail!("Supplier invoice {} is cancelled", invoice.invoice_number);
+    }
+    if invoice.approved_at.is_some() {
+        anyhow::bail!("Supplier invoice {} is already approved", invoice.invoice_number);
+    }
+
+    let matching = match_invoice(id).await?;
+    let wrong_supplier: Vec<&str> = matching.lines.iter()
+        .filter(|l| l.status == MatchStatus::WrongSupplier)
+        .map(|l| l.description.as_str())
+        .collect();
+    if !wrong_supplier.is_empty() {
+        anyhow::bail!(
+            "Supplier invoice bills order lines that are not in the supplier's catalog: {}",
+            wrong_supplier.join(", ")
+        );
+    }
+    if !matching.is_fully_matched && !accept_variances {
+        let problems: Vec<String> = matching.lines.iter()
+            .filter(|l| l.status != MatchStatus::Matched)
+            .map(|l| format!("{}: {}", l.description, l.status.display_name()))
+            .collect();
+        anyhow::bail!("Supplier invoice does not match: {}", problems.join(", "));
+    }
+
+    let mut billed_prices = Vec::new();
+    for line in &invoice.lines {
+        let Some(order_item_id) = line.order_item_id else {
+            continue;
+        };
+        let order_item = order_item_service::get_by_id(order_item_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Order item {} not found", order_item_id))?;
+        if order_item.currency != invoice.currency {
+            anyhow::bail!(
+                "{} is billed in {} but ordered in {}; the buying price cannot be updated",
+                line.description, invoice.currency, order_item.currency
+            );
+        }
+        billed_prices.push((order_item_id, line.unit_price));
+    }
+
+    let txn = conn.begin().await?;
+
+    for (order_item_id, unit_price) in billed_prices {
+        txn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "UPDATE order_items SET buying_price = ?, updated_at = datetime('now') WHERE id = ?",
+            vec![
+                Value::Double(Some(unit_price)),
+                Value::Int(Some(order_item_id)),
+            ]
+        )).await?;
+    }
+
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE supplier_invoices SET approved_at = datetime('now'), updated_at = datetime('now') WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supplier invoice not found after approval"))
+}
+
+/// Recompute the stored status of a supplier invoice from its payments
+pub(crate) async fn refresh_status<C: ConnectionTrait>(conn: &C, id: i32) -> Result<()> {
+    let row: Option<SupplierInvoiceRow> = SupplierInvoiceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE si.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(conn)
+    .await?;
+
+    let Some(row) = row else {
+        anyhow::bail!("Supplier invoice not found");
+    };
+    if row.status == "CANCELLED" {
+        return Ok(());
+    }
+
+    let status = if row.amount - row.paid_amount <= AMOUNT_EPSILON {
+        InvoiceStatus::Paid
+    } else if row.paid_amount > AMOUNT_EPSILON {
+        InvoiceStatus::PartiallyPaid
+    } else {
+        InvoiceStatus::Open
+    };
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE supplier_invoices SET status = ?, updated_at = datetime('now') WHERE id = ?",
+        vec![
+            Value::String(Some(Box::new(invoice_status_to_str(status).to_string()))),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+
+    Ok(())
+}
@@ -0,0 +1,240 @@
+//! Supplier Payment Service - Payments to suppliers and payables due
+
+use crate::models::{SupplierPayment, CreateSupplierPaymentRequest, PayableDue, InvoiceStatus};
+use crate::database;
+use crate::services::invoice_service::{parse_date, AMOUNT_EPSILON};
+use crate::services::payment_service::{payment_method_from_str, payment_method_to_str};
+use crate::services::supplier_invoice_service;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+#[derive(Debug, FromQueryResult)]
+struct SupplierPaymentRow {
+    id: i32,
+    supplier_invoice_id: i32,
+    invoice_number: Option<String>,
+    supplier_id: i32,
+    supplier_name: Option<String>,
+    amount: f64,
+    currency: String,
+    payment_date: String,
+    method: String,
+    reference: Option<String>,
+    notes: Option<String>,
+    created_at: String,
+}
+
+impl From<SupplierPaymentRow> for SupplierPayment {
+    fn from(row: SupplierPaymentRow) -> Self {
+        SupplierPayment {
+            id: row.id,
+            supplier_invoice_id: row.supplier_invoice_id,
+            invoice_number: row.invoice_number,
+            supplier_id: row.supplier_id,
+            supplier_name: row.supplier_name,
+            amount: row.amount,
+            currency: row.currency,
+            payment_date: row.payment_date,
+            method: payment_method_from_str(&row.method),
+            reference: row.reference,
+            notes: row.notes,
+            created_at: row.created_at,
+        }
+    }
+}
+
+const SELECT_FIELDS: &str = r#"
+    sp.id, sp.supplier_invoice_id, si.invoice_number, si.supplier_id, s.name as supplier_name,
+    sp.amount, si.currency, sp.payment_date, sp.method, sp.reference, sp.notes, sp.created_at
+"#;
+
+const FROM_JOIN: &str = r#"
+    FROM supplier_payments sp
+    JOIN supplier_invoices si ON sp.supplier_invoice_id = si.id
+    LEFT JOIN suppliers s ON si.supplier_id = s.id
+"#;
+
+/// Get payments made to a supplier
+pub async fn get_by_supplier(supplier_id: i32) -> Result<Vec<SupplierPayment>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<SupplierPaymentRow> = SupplierPaymentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE si.supplier_id = ? ORDER BY sp.payment_date DESC, sp.id DESC", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(supplier_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(SupplierPayment::from).collect())
+}
+
+/// Get payments made against a supplier invoice
+pub async fn get_by_invoice(supplier_invoice_id: i32) -> Result<Vec<SupplierPayment>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let rows: Vec<SupplierPaymentRow> = SupplierPaymentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE sp.supplier_invoice_id = ? ORDER BY sp.payment_date, sp.id", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(supplier_invoice_id))]
+    ))
+    .all(&conn)
+    .await?;
+
+    Ok(rows.into_iter().map(SupplierPayment::from).collect())
+}
+
+/// Pay (part of) an approved supplier invoice
+pub async fn record_payment(req: CreateSupplierPaymentRequest) -> Result<SupplierPayment> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let invoice = supplier_invoice_service::get_by_id(req.supplier_invoice_id).await?
+        .ok_or_else(|| anyhow::anyhow!("Supplier invoice not found"))?;
+
+    if invoice.status == InvoiceStatus::Cancelled {
+        anyhow::bail!("Supplier invoice {} is cancelled", invoice.invoice_number);
+    }
+    if invoice.approved_at.is_none() {
+        anyhow::bail!("Supplier invoice {} must be approved before payment", invoice.invoice_number);
+    }
+    if req.amount <= 0.0 {
+        anyhow::bail!("Payment amount must be positive");
+    }
+    if req.amount - invoice.balance > AMOUNT_EPSILON {
+        anyhow::bail!(
+            "Payment exceeds the open balance of {} ({:.2} {})",
+            invoice.invoice_number, invoice.balance, invoice.currency
+        );
+    }
+    let payment_date = parse_date(&req.payment_date)?;
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO supplier_payments (supplier_invoice_id, amount, payment_date, method, reference, notes)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+        vec![
+            Value::Int(Some(invoice.id)),
+            Value::Double(Some(req.amount)),
+            Value::String(Some(Box::new(payment_date.format("%Y-%m-%d").to_string()))),
+            Value::String(Some(Box::new(payment_method_to_str(req.method).to_string()))),
+            Value::String(req.reference.map(Box::new)),
+            Value::String(req.notes.map(Box::new)),
+        ]
+    )).await?;
+    let id = result.last_insert_id() as i32;
+
+    supplier_invoice_service::refresh_status(&txn, invoice.id).await?;
+    txn.commit().await?;
+
+    let row: Option<SupplierPaymentRow> = SupplierPaymentRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        format!("SELECT {} {} WHERE sp.id = ?", SELECT_FIELDS, FROM_JOIN),
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await?;
+
+    row.map(SupplierPayment::from)
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created supplier payment"))
+}
+
+/// Delete a supplier payment (re-opens the invoice balance)
+pub async fn delete(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    #[derive(Debug, FromQueryResult)]
+    struct InvoiceIdRow {
+        supplier_invoice_id: i32,
+    }
+
+    let Some(payment) = InvoiceIdRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT supplier_invoice_id FROM supplier_payments WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    ))
+    .one(&conn)
+    .await? else {
+        return Ok(false);
+    };
+
+    let txn = conn.begin().await?;
+
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM supplier_payments WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    supplier_invoice_service::refresh_status(&txn, payment.supplier_invoice_id).await?;
+    txn.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Open supplier invoices due within the next `days` days (overdue ones included)
+pub async fn get_payables_due(days: i32) -> Result<Vec<PayableDue>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let today = chrono::Utc::now().date_naive();
+    let until = today + chrono::Duration::days(days as i64);
+
+    #[derive(Debug, FromQueryResult)]
+    struct DueRow {
+        id: i32,
+        supplier_id: i32,
+        supplier_name: Option<String>,
+        invoice_number: String,
+        currency: String,
+        due_date: String,
+        balance: f64,
+    }
+
+    let rows: Vec<DueRow> = DueRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT si.id, si.supplier_id, s.name as supplier_name, si.invoice_number, si.currency, si.due_date,
+            CAST(COALESCE((SELECT SUM(l.quantity * l.unit_price) FROM supplier_invoice_lines l WHERE l.supplier_invoice_id = si.id), 0.0)
+               - COALESCE((SELECT SUM(sp.amount) FROM supplier_payments sp WHERE sp.supplier_invoice_id = si.id), 0.0) AS REAL) as balance
+        FROM supplier_invoices si
+        LEFT JOIN suppliers s ON si.supplier_id = s.id
+        WHERE si.status IN ('OPEN', 'PARTIALLY_PAID') AND si.due_date <= ?
+        ORDER BY si.due_date ASC, si.id ASC
+        "#,
+        vec![Value::String(Some(Box::new(until.format("%Y-%m-%d").to_string())))]
+    ))
+    .all(&conn)
+    .await?;
+
+    let mut due = Vec::with_capacity(rows.len());
+    for row in rows {
+        if row.balance <= AMOUNT_EPSILON {
+            continue;
+        }
+        let days_until_due = (parse_date(&row.due_date)? - today).num_days();
+        due.push(PayableDue {
+            supplier_invoice_id: row.id,
+            supplier_id: row.supplier_id,
+            supplier_name: row.supplier_name,
+            invoice_number: row.invoice_number,
+            currency: row.currency,
+            due_date: row.due_date,
+            balance: row.balance,
+            days_until_due,
+        });
+    }
+    Ok(due)
+}
@@ -1,7 +1,8 @@
 //! Supplier Service - CRUD operations for suppliers
 
-use crate::models::{Supplier, CreateSupplierRequest, UpdateSupplierRequest};
+use crate::models::{Supplier, CreateSupplierRequest, UpdateSupplierRequest, SearchScope};
 use crate::database;
+use crate::services::{category_service, search_service};
 use anyhow::Result;
 use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
 
@@ -16,6 +17,9 @@ struct SupplierRow {
     address: Option<String>,
     country: Option<String>,
     category: String,
+    category_id: Option<i32>,
+    category_name: Option<String>,
+    lead_time_days: Option<i32>,
     is_active: i32,
     created_at: String,
     updated_at: String,
@@ -32,6 +36,9 @@ impl From<SupplierRow> for Supplier {
             address: row.address,
             country: row.country,
             category: row.category,
+            category_id: row.category_id,
+            category_name: row.category_name,
+            lead_time_days: row.lead_time_days,
             is_active: row.is_active == 1,
             created_at: row.created_at,
             updated_at: row.updated_at,
@@ -39,6 +46,17 @@ impl From<SupplierRow> for Supplier {
     }
 }
 
+const SELECT_FIELDS: &str = "s.id, s.name, s.contact_person, s.email, s.phone, s.address, s.country, COALESCE(c.code, s.category) as category, s.category_id, c.name_tr as category_name, s.lead_time_days, s.is_active, s.created_at, s.updated_at";
+
+const FROM_JOIN: &str = "FROM suppliers s LEFT JOIN categories c ON s.category_id = c.id";
+
+fn check_lead_time(lead_time_days: Option<i32>) -> Result<()> {
+    if lead_time_days.is_some_and(|days| days < 0) {
+        anyhow::bail!("Lead time cannot be negative");
+    }
+    Ok(())
+}
+
 /// Get all active suppliers
 pub async fn get_all() -> Result<Vec<Supplier>> {
     let conn = database::get_connection()
@@ -47,7 +65,7 @@ pub async fn get_all() -> Result<Vec<Supplier>> {
 
     let rows: Vec<SupplierRow> = SupplierRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, contact_person, email, phone, address, country, category, is_active, created_at, updated_at FROM suppliers WHERE is_active = 1 ORDER BY name".to_string()
+        format!("SELECT {} {} WHERE s.is_active = 1 ORDER BY s.name", SELECT_FIELDS, FROM_JOIN)
     ))
     .all(&conn)
     .await?;
@@ -63,7 +81,7 @@ pub async fn get_by_id(id: i32) -> Result<Option<Supplier>> {
 
     let row: Option<SupplierRow> = SupplierRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, contact_person, email, phone, address, country, category, is_active, created_at, updated_at FROM suppliers WHERE id = ? AND is_active = 1",
+        format!("SELECT {} {} WHERE s.id = ? AND s.is_active = 1", SELECT_FIELDS, FROM_JOIN),
         vec![Value::Int(Some(id))]
     ))
     .one(&conn)
@@ -78,12 +96,14 @@ pub async fn create(supplier: CreateSupplierRequest) -> Result<Supplier> {
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
+    let category = category_service::active_code(&conn, supplier.category_id).await?;
+    check_lead_time(supplier.lead_time_days)?;
     let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
     // Insert the supplier
     conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "INSERT INTO suppliers (name, contact_person, email, phone, address, country, category, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO suppliers (name, contact_person, email, phone, address, country, category, category_id, lead_time_days, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         vec![
             Value::String(Some(Box::new(supplier.name.clone()))),
             Value::String(supplier.contact_person.clone().map(|s| Box::new(s))),
@@ -91,7 +111,9 @@ pub async fn create(supplier: CreateSupplierRequest) -> Result<Supplier> {
             Value::String(supplier.phone.clone().map(|s| Box::new(s))),
             Value::String(supplier.address.clone().map(|s| Box::new(s))),
             Value::String(supplier.country.clone().map(|s| Box::new(s))),
-            Value::String(Some(Box::new(supplier.category.clone()))),
+            Value::String(Some(Box::new(category))),
+            Value::Int(Some(supplier.category_id)),
+            Value::Int(supplier.lead_time_days),
             Value::String(Some(Box::new(now.clone()))),
             Value::String(Some(Box::new(now.clone()))),
         ]
@@ -100,7 +122,7 @@ pub async fn create(supplier: CreateSupplierRequest) -> Result<Supplier> {
     // Get the last inserted ID
     let result: Option<SupplierRow> = SupplierRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, contact_person, email, phone, address, country, category, is_active, created_at, updated_at FROM suppliers WHERE id = last_insert_rowid()".to_string()
+        format!("SELECT {} {} WHERE s.id = last_insert_rowid()", SELECT_FIELDS, FROM_JOIN)
     ))
     .one(&conn)
     .await?;
@@ -128,11 +150,16 @@ pub async fn update(id: i32, supplier: UpdateSupplierRequest) -> Result<Supplier
     let phone = supplier.phone.or(existing.phone);
     let address = supplier.address.or(existing.address);
     let country = supplier.country.or(existing.country);
-    let category = supplier.category.unwrap_or(existing.category);
+    let (category, category_id) = match supplier.category_id {
+        Some(category_id) => (category_service::active_code(&conn, category_id).await?, Some(category_id)),
+        None => (existing.category, existing.category_id),
+    };
+    check_lead_time(supplier.lead_time_days)?;
+    let lead_time_days = supplier.lead_time_days.or(existing.lead_time_days);
 
     conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "UPDATE suppliers SET name = ?, contact_person = ?, email = ?, phone = ?, address = ?, country = ?, category = ?, updated_at = ? WHERE id = ?",
+        "UPDATE suppliers SET name = ?, contact_person = ?, email = ?, phone = ?, address = ?, country = ?, category = ?, category_id = ?, lead_time_days = ?, updated_at = ? WHERE id = ?",
         vec![
             Value::String(Some(Box::new(name))),
             Value::String(contact_person.map(|s| Box::new(s))),
@@ -141,6 +168,8 @@ pub async fn update(id: i32, supplier: UpdateSupplierRequest) -> Result<Supplier
             Value::String(address.map(|s| Box::new(s))),
             Value::String(country.map(|s| Box::new(s))),
             Value::String(Some(Box::new(category))),
+            Value::Int(category_id),
+            Value::Int(lead_time_days),
             Value::String(Some(Box::new(now))),
             Value::Int(Some(id)),
         ]
@@ -189,39 +218,42 @@ pub async fn delete(id: i32) -> Result<bool> {
     Ok(result.rows_affected() > 0)
 }
 
-/// Search suppliers by name, category, or country
+/// Search suppliers by name, category, country or contact (full-text, best match first)
 pub async fn search(query: &str) -> Result<Vec<Supplier>> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    let search_term = format!("%{}%", query);
+    if query.trim().is_empty() {
+        return get_all().await;
+    }
 
+    let ids = search_service::ranked_ids(&conn, SearchScope::Suppliers, query).await?;
+    let (placeholders, values) = search_service::id_list(&ids);
     let rows: Vec<SupplierRow> = SupplierRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, contact_person, email, phone, address, country, category, is_active, created_at, updated_at FROM suppliers WHERE is_active = 1 AND (name LIKE ? OR category LIKE ? OR country LIKE ?) ORDER BY name",
-        vec![
-            Value::String(Some(Box::new(search_term.clone()))),
-            Value::String(Some(Box::new(search_term.clone()))),
-            Value::String(Some(Box::new(search_term))),
-        ]
+        format!("SELECT {} {} WHERE s.is_active = 1 AND s.id IN ({})", SELECT_FIELDS, FROM_JOIN, placeholders),
+        values
     ))
     .all(&conn)
     .await?;
 
-    Ok(rows.into_iter().map(Supplier::from).collect())
+    Ok(search_service::in_rank_order(&ids, rows.into_iter().map(Supplier::from).collect(), |s| s.id))
 }
 
-/// Get suppliers by category
-pub async fn get_by_category(category: &str) -> Result<Vec<Supplier>> {
+/// Get suppliers in a category or any of its subcategories
+pub async fn get_by_category(category_id: i32) -> Result<Vec<Supplier>> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
     let rows: Vec<SupplierRow> = SupplierRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "SELECT id, name, contact_person, email, phone, address, country, category, is_active, created_at, updated_at FROM suppliers WHERE is_active = 1 AND category = ? ORDER BY name",
-        vec![Value::String(Some(Box::new(category.to_string())))]
+        format!(
+            "SELECT {} {} WHERE s.is_active = 1 AND s.category_id IN ({}) ORDER BY s.name",
+            SELECT_FIELDS, FROM_JOIN, category_service::SUBTREE_SQL
+        ),
+        vec![Value::Int(Some(category_id))]
     ))
     .all(&conn)
     .await?;
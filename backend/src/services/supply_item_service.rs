@@ -1,9 +1,10 @@
 //! Supply Item Service - CRUD operations for supply items (product catalog)
 
-use crate::models::{SupplyItem, CreateSupplyItemRequest, UpdateSupplyItemRequest};
+use crate::models::{SupplyItem, CreateSupplyItemRequest, UpdateSupplyItemRequest, PriceChangeSource, SearchScope};
 use crate::database;
+use crate::services::{price_agreement_service, impa_service, category_service, price_history_service, search_service, compliance_service, bonded_service};
 use anyhow::Result;
-use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, Value};
+use sea_orm::{ConnectionTrait, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
 
 /// Raw query result for SupplyItem
 #[derive(Debug, FromQueryResult)]
@@ -12,14 +13,23 @@ struct SupplyItemRow {
     supplier_id: i32,
     supplier_name: Option<String>,
     impa_code: Option<String>,
+    supplier_sku: Option<String>,
     name: String,
     description: Option<String>,
     category: String,
+    category_id: Option<i32>,
+    category_name: Option<String>,
     unit: String,
     unit_price: f64,
     currency: String,
     minimum_order_quantity: Option<i32>,
     is_available: i32,
+    un_number: Option<String>,
+    hazard_class: Option<String>,
+    sds_required: i32,
+    prescription_only: i32,
+    is_bonded: i32,
+    sds_attachment_id: Option<i32>,
     created_at: String,
     updated_at: String,
 }
@@ -31,23 +41,32 @@ impl From<SupplyItemRow> for SupplyItem {
             supplier_id: row.supplier_id,
             supplier_name: row.supplier_name,
             impa_code: row.impa_code,
+            supplier_sku: row.supplier_sku,
             name: row.name,
             description: row.description,
             category: row.category,
+            category_id: row.category_id,
+            category_name: row.category_name,
             unit: row.unit,
             unit_price: row.unit_price,
             currency: row.currency,
             minimum_order_quantity: row.minimum_order_quantity,
             is_available: row.is_available == 1,
+            un_number: row.un_number,
+            hazard_class: row.hazard_class,
+            sds_required: row.sds_required == 1,
+            prescription_only: row.prescription_only == 1,
+            is_bonded: row.is_bonded == 1,
+            sds_attachment_id: row.sds_attachment_id,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
     }
 }
 
-const SELECT_FIELDS: &str = "si.id, si.supplier_id, s.name as supplier_name, si.impa_code, si.name, si.description, si.category, si.unit, si.unit_price, si.currency, si.minimum_order_quantity, si.is_available, si.created_at, si.updated_at";
+const SELECT_FIELDS: &str = "si.id, si.supplier_id, s.name as supplier_name, si.impa_code, si.supplier_sku, si.name, si.description, COALESCE(c.code, si.category) as category, si.category_id, c.name_tr as category_name, si.unit, si.unit_price, si.currency, si.minimum_order_quantity, si.is_available, si.un_number, si.hazard_class, si.sds_required, si.prescription_only, si.is_bonded, (SELECT a.id FROM attachments a WHERE a.entity_type = 'SUPPLY_ITEM' AND a.entity_id = si.id AND a.role = 'SDS') as sds_attachment_id, si.created_at, si.updated_at";
 
-const FROM_JOIN: &str = "FROM supply_items si LEFT JOIN suppliers s ON si.supplier_id = s.id";
+const FROM_JOIN: &str = "FROM supply_items si LEFT JOIN suppliers s ON si.supplier_id = s.id LEFT JOIN categories c ON si.category_id = c.id";
 
 /// Get all available supply items
 pub async fn get_all() -> Result<Vec<SupplyItem>> {
@@ -57,7 +76,7 @@ pub async fn get_all() -> Result<Vec<SupplyItem>> {
 
     let rows: Vec<SupplyItemRow> = SupplyItemRow::find_by_statement(Statement::from_string(
         DatabaseBackend::Sqlite,
-        format!("SELECT {} {} WHERE si.is_available = 1 ORDER BY si.category, si.name", SELECT_FIELDS, FROM_JOIN)
+        format!("SELECT {} {} WHERE si.is_available = 1 ORDER BY category, si.name", SELECT_FIELDS, FROM_JOIN)
     ))
     .all(&conn)
     .await?;
@@ -90,7 +109,7 @@ pub async fn get_by_supplier(supplier_id: i32) -> Result<Vec<SupplyItem>> {
 
     let rows: Vec<SupplyItemRow> = SupplyItemRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        format!("SELECT {} {} WHERE si.supplier_id = ? AND si.is_available = 1 ORDER BY si.category, si.name", SELECT_FIELDS, FROM_JOIN),
+        format!("SELECT {} {} WHERE si.supplier_id = ? AND si.is_available = 1 ORDER BY category, si.name", SELECT_FIELDS, FROM_JOIN),
         vec![Value::Int(Some(supplier_id))]
     ))
     .all(&conn)
@@ -99,16 +118,19 @@ pub async fn get_by_supplier(supplier_id: i32) -> Result<Vec<SupplyItem>> {
     Ok(rows.into_iter().map(SupplyItem::from).collect())
 }
 
-/// Get supply items by category
-pub async fn get_by_category(category: &str) -> Result<Vec<SupplyItem>> {
+/// Get supply items in a category or any of its subcategories
+pub async fn get_by_category(category_id: i32) -> Result<Vec<SupplyItem>> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
     let rows: Vec<SupplyItemRow> = SupplyItemRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        format!("SELECT {} {} WHERE si.category = ? AND si.is_available = 1 ORDER BY si.name", SELECT_FIELDS, FROM_JOIN),
-        vec![Value::String(Some(Box::new(category.to_string())))]
+        format!(
+            "SELECT {} {} WHERE si.category_id IN ({}) AND si.is_available = 1 ORDER BY si.name",
+            SELECT_FIELDS, FROM_JOIN, category_service::SUBTREE_SQL
+        ),
+        vec![Value::Int(Some(category_id))]
     ))
     .all(&conn)
     .await?;
@@ -116,45 +138,94 @@ pub async fn get_by_category(category: &str) -> Result<Vec<SupplyItem>> {
     Ok(rows.into_iter().map(SupplyItem::from).collect())
 }
 
-/// Create a new supply item
-pub async fn create(item: CreateSupplyItemRequest) -> Result<SupplyItem> {
+/// Create a new supply item.
+///
+/// The IMPA code is validated; a blank name, category or unit is filled in
+/// from the IMPA reference catalog. A category is required.
+pub async fn create(mut item: CreateSupplyItemRequest) -> Result<SupplyItem> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
+    item.impa_code = impa_service::validate_optional(&conn, item.impa_code).await?;
+    if let Some(reference) = match item.impa_code.as_deref() {
+        Some(code) => impa_service::find(&conn, code).await?,
+        None => None,
+    } {
+        if item.name.trim().is_empty() {
+            item.name = reference.description;
+        }
+        if item.category_id.is_none() {
+            item.category_id = reference.category_id;
+        }
+        if item.unit.trim().is_empty() {
+            item.unit = reference.unit.unwrap_or_default();
+        }
+    }
+
+    let category_id = item.category_id.ok_or_else(|| anyhow::anyhow!("Category is required"))?;
+    let category = category_service::active_code(&conn, category_id).await?;
+    (item.un_number, item.hazard_class) = compliance_service::check_dangerous_goods(item.un_number, item.hazard_class)?;
+
+    let txn = conn.begin().await?;
+    let id = insert(&txn, &item, &category, PriceChangeSource::Manual, &price_history_service::effective_date(None)?, None).await?;
+    txn.commit().await?;
+
+    get_by_id(id).await?
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created supply item"))
+}
+
+/// Insert a validated supply item with the opening entry of its price history
+pub(crate) async fn insert<C: ConnectionTrait>(
+    conn: &C,
+    item: &CreateSupplyItemRequest,
+    category: &str,
+    source: PriceChangeSource,
+    effective_date: &str,
+    reference: Option<&str>,
+) -> Result<i32> {
     let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
 
-    conn.execute(Statement::from_sql_and_values(
+    let result = conn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "INSERT INTO supply_items (supplier_id, impa_code, name, description, category, unit, unit_price, currency, minimum_order_quantity, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO supply_items (supplier_id, impa_code, supplier_sku, name, description, category, category_id, unit, unit_price, currency, minimum_order_quantity, un_number, hazard_class, sds_required, prescription_only, is_bonded, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         vec![
             Value::Int(Some(item.supplier_id)),
             Value::String(item.impa_code.clone().map(|s| Box::new(s))),
+            Value::String(item.supplier_sku.clone().map(Box::new)),
             Value::String(Some(Box::new(item.name.clone()))),
             Value::String(item.description.clone().map(|s| Box::new(s))),
-            Value::String(Some(Box::new(item.category.clone()))),
+            Value::String(Some(Box::new(category.to_string()))),
+            Value::Int(item.category_id),
             Value::String(Some(Box::new(item.unit.clone()))),
             Value::Double(Some(item.unit_price)),
             Value::String(Some(Box::new(item.currency.clone()))),
             Value::Int(item.minimum_order_quantity),
+            Value::String(item.un_number.clone().map(Box::new)),
+            Value::String(item.hazard_class.clone().map(Box::new)),
+            Value::Int(Some(item.sds_required as i32)),
+            Value::Int(Some(item.prescription_only as i32)),
+            Value::Int(Some(item.is_bonded as i32)),
             Value::String(Some(Box::new(now.clone()))),
             Value::String(Some(Box::new(now))),
         ]
     )).await?;
-
-    // Get the last inserted item
-    let result: Option<SupplyItemRow> = SupplyItemRow::find_by_statement(Statement::from_string(
-        DatabaseBackend::Sqlite,
-        format!("SELECT {} {} WHERE si.id = last_insert_rowid()", SELECT_FIELDS, FROM_JOIN)
-    ))
-    .one(&conn)
-    .await?;
-
-    result.map(SupplyItem::from)
-        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created supply item"))
+    let id = result.last_insert_id() as i32;
+
+    price_history_service::record(
+        conn,
+        id,
+        None,
+        (item.unit_price, &item.currency),
+        source,
+        effective_date,
+        reference,
+    ).await?;
+
+    Ok(id)
 }
 
-/// Update an existing supply item
+/// Update an existing supply item; a price or currency change is added to the price history
 pub async fn update(id: i32, item: UpdateSupplyItemRequest) -> Result<SupplyItem> {
     let conn = database::get_connection()
         .await
@@ -168,35 +239,74 @@ pub async fn update(id: i32, item: UpdateSupplyItemRequest) -> Result<SupplyItem
 
     // Update with new values or keep existing
     let supplier_id = item.supplier_id.unwrap_or(existing.supplier_id);
-    let impa_code = item.impa_code.or(existing.impa_code);
+    let impa_code = match item.impa_code {
+        Some(code) => impa_service::validate_optional(&conn, Some(code)).await?,
+        None => existing.impa_code,
+    };
+    let supplier_sku = item.supplier_sku.or(existing.supplier_sku);
     let name = item.name.unwrap_or(existing.name);
     let description = item.description.or(existing.description);
-    let category = item.category.unwrap_or(existing.category);
+    let (category, category_id) = match item.category_id {
+        Some(category_id) => (category_service::active_code(&conn, category_id).await?, Some(category_id)),
+        None => (existing.category, existing.category_id),
+    };
     let unit = item.unit.unwrap_or(existing.unit);
     let unit_price = item.unit_price.unwrap_or(existing.unit_price);
-    let currency = item.currency.unwrap_or(existing.currency);
+    let currency = item.currency.unwrap_or_else(|| existing.currency.clone());
     let minimum_order_quantity = item.minimum_order_quantity.or(existing.minimum_order_quantity);
     let is_available = item.is_available.unwrap_or(existing.is_available);
+    let (un_number, hazard_class) = compliance_service::check_dangerous_goods(
+        item.un_number.or(existing.un_number),
+        item.hazard_class.or(existing.hazard_class),
+    )?;
+    let sds_required = item.sds_required.unwrap_or(existing.sds_required);
+    let prescription_only = item.prescription_only.unwrap_or(existing.prescription_only);
+    let is_bonded = item.is_bonded.unwrap_or(existing.is_bonded);
+    if is_bonded != existing.is_bonded {
+        bonded_service::ensure_not_stocked(&conn, id).await?;
+    }
+    let effective_date = price_history_service::effective_date(item.price_effective_date.as_deref())?;
 
-    conn.execute(Statement::from_sql_and_values(
+    let txn = conn.begin().await?;
+
+    txn.execute(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        "UPDATE supply_items SET supplier_id = ?, impa_code = ?, name = ?, description = ?, category = ?, unit = ?, unit_price = ?, currency = ?, minimum_order_quantity = ?, is_available = ?, updated_at = ? WHERE id = ?",
+        "UPDATE supply_items SET supplier_id = ?, impa_code = ?, supplier_sku = ?, name = ?, description = ?, category = ?, category_id = ?, unit = ?, unit_price = ?, currency = ?, minimum_order_quantity = ?, is_available = ?, un_number = ?, hazard_class = ?, sds_required = ?, prescription_only = ?, is_bonded = ?, updated_at = ? WHERE id = ?",
         vec![
             Value::Int(Some(supplier_id)),
             Value::String(impa_code.map(|s| Box::new(s))),
+            Value::String(supplier_sku.map(Box::new)),
             Value::String(Some(Box::new(name))),
             Value::String(description.map(|s| Box::new(s))),
             Value::String(Some(Box::new(category))),
+            Value::Int(category_id),
             Value::String(Some(Box::new(unit))),
             Value::Double(Some(unit_price)),
-            Value::String(Some(Box::new(currency))),
+            Value::String(Some(Box::new(currency.clone()))),
             Value::Int(minimum_order_quantity),
             Value::Int(Some(if is_available { 1 } else { 0 })),
+            Value::String(un_number.map(Box::new)),
+            Value::String(hazard_class.map(Box::new)),
+            Value::Int(Some(sds_required as i32)),
+            Value::Int(Some(prescription_only as i32)),
+            Value::Int(Some(is_bonded as i32)),
             Value::String(Some(Box::new(now))),
             Value::Int(Some(id)),
         ]
     )).await?;
 
+    price_history_service::record(
+        &txn,
+        id,
+        Some((existing.unit_price, &existing.currency)),
+        (unit_price, &currency),
+        PriceChangeSource::Manual,
+        &effective_date,
+        None,
+    ).await?;
+
+    txn.commit().await?;
+
     get_by_id(id).await?
         .ok_or_else(|| anyhow::anyhow!("Failed to retrieve updated supply item"))
 }
@@ -233,28 +343,27 @@ pub async fn delete(id: i32) -> Result<bool> {
     Ok(result.rows_affected() > 0)
 }
 
-/// Search supply items by name, IMPA code, or description
+/// Search available supply items by name, IMPA code, description or supplier (full-text, best match first)
 pub async fn search(query: &str) -> Result<Vec<SupplyItem>> {
     let conn = database::get_connection()
         .await
         .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
 
-    let search_term = format!("%{}%", query);
+    if query.trim().is_empty() {
+        return get_all().await;
+    }
 
+    let ids = search_service::ranked_ids(&conn, SearchScope::SupplyItems, query).await?;
+    let (placeholders, values) = search_service::id_list(&ids);
     let rows: Vec<SupplyItemRow> = SupplyItemRow::find_by_statement(Statement::from_sql_and_values(
         DatabaseBackend::Sqlite,
-        format!("SELECT {} {} WHERE si.is_available = 1 AND (si.name LIKE ? OR si.impa_code LIKE ? OR si.description LIKE ? OR s.name LIKE ?) ORDER BY si.category, si.name", SELECT_FIELDS, FROM_JOIN),
-        vec![
-            Value::String(Some(Box::new(search_term.clone()))),
-            Value::String(Some(Box::new(search_term.clone()))),
-            Value::String(Some(Box::new(search_term.clone()))),
-            Value::String(Some(Box::new(search_term))),
-        ]
+        format!("SELECT {} {} WHERE si.is_available = 1 AND si.id IN ({})", SELECT_FIELDS, FROM_JOIN, placeholders),
+        values
     ))
     .all(&conn)
     .await?;
 
-    Ok(rows.into_iter().map(SupplyItem::from).collect())
+    Ok(search_service::in_rank_order(&ids, rows.into_iter().map(SupplyItem::from).collect(), |i| i.id))
 }
 
 /// Get supply item count
@@ -277,3 +386,27 @@ pub async fn count() -> Result<i64> {
 
     Ok(result.map(|r| r.count).unwrap_or(0))
 }
+
+/// Cheapest available supplier price for an IMPA code in a currency
+pub(crate) async fn current_buying_price<C: ConnectionTrait>(conn: &C, impa_code: &str, currency: &str) -> Result<Option<f64>> {
+    #[derive(Debug, FromQueryResult)]
+    struct PriceRow {
+        unit_price: Option<f64>,
+    }
+
+    let row: Option<PriceRow> = PriceRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        SELECT MIN(unit_price) as unit_price FROM supply_items
+        WHERE replace(impa_code, ' ', '') = ? AND currency = ? AND is_available = 1
+        "#,
+        vec![
+            Value::String(Some(Box::new(price_agreement_service::normalize_impa(impa_code)))),
+            Value::String(Some(Box::new(currency.to_string()))),
+        ]
+    ))
+    .one(conn)
+    .await?;
+
+    Ok(row.and_then(|r| r.unit_price))
+}
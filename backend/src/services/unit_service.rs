@@ -0,0 +1,503 @@
+//! Unit Service - Units of measure, pack sizes and quantity conversion
+//!
+//! Units of one dimension convert through their factor (1 G = 0.001 KG).
+//! Pack units (KOLI, RULO...) and units of different dimensions convert only
+//! through a supply item's pack sizes (1 KOLI = 24 ADET). Spellings are
+//! resolved through the unit codes and aliases ("pcs" is ADET).
+
+use crate::models::{UnitOfMeasure, UnitDimension, CreateUnitRequest, UpdateUnitRequest, PackSize};
+use crate::database;
+use crate::services::requisition_import_service::normalize_name;
+use anyhow::Result;
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, Statement, DatabaseBackend, FromQueryResult, TransactionTrait, Value};
+
+/// Pack sizes may contain other packs this deep (PALET -> KOLI -> ADET)
+const MAX_PACK_DEPTH: usize = 5;
+
+/// Code, Turkish name, English name, dimension, factor, aliases
+type DefaultUnit = (&'static str, &'static str, &'static str, UnitDimension, f64, &'static [&'static str]);
+
+const DEFAULT_UNITS: [DefaultUnit; 24] = [
+    ("KG", "Kilogram", "Kilogram", UnitDimension::Mass, 1.0, &["KGS", "KILO", "KILOGRAM"]),
+    ("G", "Gram", "Gram", UnitDimension::Mass, 0.001, &["GR", "GRAM", "GRS"]),
+    ("TON", "Ton", "Tonne", UnitDimension::Mass, 1000.0, &["MT", "TONNE"]),
+    ("LB", "Libre", "Pound", UnitDimension::Mass, 0.453_592_37, &["LBS", "POUND"]),
+    ("LT", "Litre", "Litre", UnitDimension::Volume, 1.0, &["L", "LTR", "LITRE", "LITER", "LTS"]),
+    ("ML", "Mililitre", "Millilitre", UnitDimension::Volume, 0.001, &["MILLILITRE", "MILLILITER"]),
+    ("M3", "Metreküp", "Cubic Metre", UnitDimension::Volume, 1000.0, &["CBM"]),
+    ("GAL", "Galon", "US Gallon", UnitDimension::Volume, 3.785_411_784, &["GALLON", "USG"]),
+    ("M", "Metre", "Metre", UnitDimension::Length, 1.0, &["METRE", "METER", "MTR"]),
+    ("CM", "Santimetre", "Centimetre", UnitDimension::Length, 0.01, &[]),
+    ("MM", "Milimetre", "Millimetre", UnitDimension::Length, 0.001, &[]),
+    ("ADET", "Adet", "Piece", UnitDimension::Count, 1.0, &["PCS", "PC", "EA", "EACH", "PIECE", "AD"]),
+    ("DUZINE", "Düzine", "Dozen", UnitDimension::Count, 12.0, &["DOZ", "DZ", "DOZEN"]),
+    ("CIFT", "Çift", "Pair", UnitDimension::Count, 2.0, &["PAIR", "PR"]),
+    ("KOLI", "Koli", "Box", UnitDimension::Pack, 1.0, &["BOX", "CTN", "CARTON"]),
+    ("PAKET", "Paket", "Pack", UnitDimension::Pack, 1.0, &["PKT", "PACK", "PACKET"]),
+    ("KASA", "Kasa", "Crate", UnitDimension::Pack, 1.0, &["CRATE", "CASE"]),
+    ("SET", "Set", "Set", UnitDimension::Pack, 1.0, &["KIT"]),
+    ("RULO", "Rulo", "Roll", UnitDimension::Pack, 1.0, &["ROLL", "COIL"]),
+    ("BIDON", "Bidon", "Can", UnitDimension::Pack, 1.0, &["CAN", "JERRYCAN"]),
+    ("KOVA", "Kova", "Pail", UnitDimension::Pack, 1.0, &["PAIL", "BUCKET"]),
+    ("CUVAL", "Çuval", "Sack", UnitDimension::Pack, 1.0, &["SACK", "BAG"]),
+    ("VARIL", "Varil", "Drum", UnitDimension::Pack, 1.0, &["DRUM", "BARREL"]),
+    ("PALET", "Palet", "Pallet", UnitDimension::Pack, 1.0, &["PALLET", "PLT"]),
+];
+
+#[derive(Debug, FromQueryResult)]
+struct UnitRow {
+    id: i32,
+    code: String,
+    name_tr: String,
+    name_en: Option<String>,
+    dimension: String,
+    factor: f64,
+    is_active: i32,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct AliasRow {
+    alias: String,
+    unit_id: i32,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct PackSizeRow {
+    id: i32,
+    supply_item_id: i32,
+    pack_unit: String,
+    quantity: f64,
+    content_unit: String,
+}
+
+impl From<PackSizeRow> for PackSize {
+    fn from(row: PackSizeRow) -> Self {
+        PackSize {
+            id: row.id,
+            supply_item_id: row.supply_item_id,
+            pack_unit: row.pack_unit,
+            quantity: row.quantity,
+            content_unit: row.content_unit,
+        }
+    }
+}
+
+fn dimension_from_str(dimension: &str) -> UnitDimension {
+    match dimension {
+        "MASS" => UnitDimension::Mass,
+        "VOLUME" => UnitDimension::Volume,
+        "LENGTH" => UnitDimension::Length,
+        "COUNT" => UnitDimension::Count,
+        _ => UnitDimension::Pack,
+    }
+}
+
+fn dimension_to_str(dimension: UnitDimension) -> &'static str {
+    match dimension {
+        UnitDimension::Mass => "MASS",
+        UnitDimension::Volume => "VOLUME",
+        UnitDimension::Length => "LENGTH",
+        UnitDimension::Count => "COUNT",
+        UnitDimension::Pack => "PACK",
+    }
+}
+
+/// Comparable form of a unit spelling: "Kg." and "kg" are both "KG"
+pub(crate) fn unit_key(unit: &str) -> String {
+    normalize_name(unit).to_uppercase().replace(' ', "")
+}
+
+/// All units with their aliases
+struct Registry {
+    units: Vec<UnitOfMeasure>,
+}
+
+impl Registry {
+    fn find(&self, unit: &str) -> Option<&UnitOfMeasure> {
+        let key = unit_key(unit);
+        self.units.iter().find(|u| u.code == key)
+            .or_else(|| self.units.iter().find(|u| u.aliases.contains(&key)))
+    }
+
+    fn resolve(&self, unit: &str) -> Result<&UnitOfMeasure> {
+        self.find(unit).ok_or_else(|| anyhow::anyhow!("Unknown unit '{}'", unit.trim()))
+    }
+
+    /// Dimension and size of one `code` in the dimension's base unit, through the
+    /// item's pack sizes where needed. Each pack unit is a dimension of its own.
+    fn measure(&self, code: &str, packs: &[PackSize], depth: usize) -> Option<(String, f64)> {
+        if depth > MAX_PACK_DEPTH {
+            return None;
+        }
+        if let Some(pack) = packs.iter().find(|p| p.pack_unit == code) {
+            let (dimension, factor) = self.measure(&pack.content_unit, packs, depth + 1)?;
+            return Some((dimension, factor * pack.quantity));
+        }
+        let unit = self.units.iter().find(|u| u.code == code)?;
+        match unit.dimension {
+            UnitDimension::Pack => Some((format!("PACK:{}", unit.code), 1.0)),
+            dimension => Some((dimension_to_str(dimension).to_string(), unit.factor)),
+        }
+    }
+
+    fn convert(&self, quantity: f64, from: &str, to: &str, packs: &[PackSize]) -> Result<f64> {
+        if unit_key(from) == unit_key(to) {
+            return Ok(quantity);
+        }
+        let from_unit = self.resolve(from)?;
+        let to_unit = self.resolve(to)?;
+        if from_unit.code == to_unit.code {
+            return Ok(quantity);
+        }
+
+        let factors = if from_unit.dimension == to_unit.dimension && from_unit.dimension != UnitDimension::Pack {
+            Some((from_unit.factor, to_unit.factor))
+        } else {
+            match (self.measure(&from_unit.code, packs, 0), self.measure(&to_unit.code, packs, 0)) {
+                (Some((from_dimension, from_factor)), Some((to_dimension, to_factor))) if from_dimension == to_dimension => {
+                    Some((from_factor, to_factor))
+                }
+                _ => None,
+            }
+        };
+
+        match factors {
+            Some((from_factor, to_factor)) if to_factor > 0.0 => {
+                Ok((quantity * from_factor / to_factor * 1_000_000.0).round() / 1_000_000.0)
+            }
+            _ => anyhow::bail!(
+                "Cannot convert {} to {}: the units are incompatible and no pack size links them",
+                from_unit.code, to_unit.code
+            ),
+        }
+    }
+}
+
+async fn load_registry<C: ConnectionTrait>(conn: &C) -> Result<Registry> {
+    let rows: Vec<UnitRow> = UnitRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT id, code, name_tr, name_en, dimension, factor, is_active FROM units ORDER BY dimension, factor, code".to_string()
+    ))
+    .all(conn)
+    .await?;
+
+    let aliases: Vec<AliasRow> = AliasRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT alias, unit_id FROM unit_aliases ORDER BY alias".to_string()
+    ))
+    .all(conn)
+    .await?;
+
+    let units = rows.into_iter().map(|row| UnitOfMeasure {
+        id: row.id,
+        aliases: aliases.iter().filter(|a| a.unit_id == row.id).map(|a| a.alias.clone()).collect(),
+        code: row.code,
+        name_tr: row.name_tr,
+        name_en: row.name_en,
+        dimension: dimension_from_str(&row.dimension),
+        factor: row.factor,
+        is_active: row.is_active == 1,
+    }).collect();
+
+    Ok(Registry { units })
+}
+
+async fn load_pack_sizes<C: ConnectionTrait>(conn: &C, supply_item_id: i32) -> Result<Vec<PackSize>> {
+    let rows: Vec<PackSizeRow> = PackSizeRow::find_by_statement(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "SELECT id, supply_item_id, pack_unit, quantity, content_unit FROM pack_sizes WHERE supply_item_id = ? ORDER BY pack_unit",
+        vec![Value::Int(Some(supply_item_id))]
+    ))
+    .all(conn)
+    .await?;
+
+    Ok(rows.into_iter().map(PackSize::from).collect())
+}
+
+/// Convert a quantity between units, using the supply item's pack sizes when
+/// the units are of different dimensions. Identical spellings always convert,
+/// even when the unit is not in the registry.
+pub(crate) async fn convert<C: ConnectionTrait>(
+    conn: &C,
+    quantity: f64,
+    from: &str,
+    to: &str,
+    supply_item_id: Option<i32>,
+) -> Result<f64> {
+    if unit_key(from) == unit_key(to) {
+        return Ok(quantity);
+    }
+    let registry = load_registry(conn).await?;
+    let packs = match supply_item_id {
+        Some(id) => load_pack_sizes(conn, id).await?,
+        None => Vec::new(),
+    };
+    registry.convert(quantity, from, to, &packs)
+}
+
+/// Registry code of a unit spelling ("pcs" -> "ADET"); unknown units are an error
+pub(crate) async fn canonical<C: ConnectionTrait>(conn: &C, unit: &str) -> Result<String> {
+    let registry = load_registry(conn).await?;
+    let unit = registry.resolve(unit)?;
+    if !unit.is_active {
+        anyhow::bail!("Unit {} is not active", unit.code);
+    }
+    Ok(unit.code.clone())
+}
+
+/// Fill the unit registry of a database that has none
+pub(crate) async fn register_default_units(conn: &DatabaseConnection) -> Result<(), DbErr> {
+    #[derive(Debug, FromQueryResult)]
+    struct CountRow {
+        count: i32,
+    }
+
+    let existing = CountRow::find_by_statement(Statement::from_string(
+        DatabaseBackend::Sqlite,
+        "SELECT COUNT(*) as count FROM units".to_string()
+    ))
+    .one(conn)
+    .await?
+    .map(|r| r.count)
+    .unwrap_or(0);
+    if existing > 0 {
+        return Ok(());
+    }
+
+    for (code, name_tr, name_en, dimension, factor, aliases) in DEFAULT_UNITS {
+        let result = conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "INSERT INTO units (code, name_tr, name_en, dimension, factor) VALUES (?, ?, ?, ?, ?)",
+            vec![
+                Value::String(Some(Box::new(code.to_string()))),
+                Value::String(Some(Box::new(name_tr.to_string()))),
+                Value::String(Some(Box::new(name_en.to_string()))),
+                Value::String(Some(Box::new(dimension_to_str(dimension).to_string()))),
+                Value::Double(Some(factor)),
+            ]
+        )).await?;
+        let unit_id = result.last_insert_id() as i32;
+        for alias in aliases {
+            conn.execute(Statement::from_sql_and_values(
+                DatabaseBackend::Sqlite,
+                "INSERT OR IGNORE INTO unit_aliases (alias, unit_id) VALUES (?, ?)",
+                vec![Value::String(Some(Box::new(alias.to_string()))), Value::Int(Some(unit_id))]
+            )).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Check aliases against the other units' codes and aliases; returns them as keys
+fn clean_aliases(registry: &Registry, code: &str, aliases: &[String]) -> Result<Vec<String>> {
+    let mut keys: Vec<String> = aliases.iter().map(|a| unit_key(a)).filter(|a| !a.is_empty() && a != code).collect();
+    keys.sort();
+    keys.dedup();
+    for key in &keys {
+        if let Some(other) = registry.find(key).filter(|u| u.code != code) {
+            anyhow::bail!("'{}' already stands for unit {}", key, other.code);
+        }
+    }
+    Ok(keys)
+}
+
+async fn save_aliases<C: ConnectionTrait>(conn: &C, unit_id: i32, aliases: &[String]) -> Result<()> {
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM unit_aliases WHERE unit_id = ?",
+        vec![Value::Int(Some(unit_id))]
+    )).await?;
+    for alias in aliases {
+        conn.execute(Statement::from_sql_and_values(
+            DatabaseBackend::Sqlite,
+            "INSERT INTO unit_aliases (alias, unit_id) VALUES (?, ?)",
+            vec![Value::String(Some(Box::new(alias.clone()))), Value::Int(Some(unit_id))]
+        )).await?;
+    }
+    Ok(())
+}
+
+/// Get all units of measure, by dimension and size
+pub async fn get_all() -> Result<Vec<UnitOfMeasure>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    Ok(load_registry(&conn).await?.units)
+}
+
+/// Add a unit of measure. Pack units always have factor 1.
+pub async fn create(req: CreateUnitRequest) -> Result<UnitOfMeasure> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let registry = load_registry(&conn).await?;
+    let code = unit_key(&req.code);
+    if code.is_empty() {
+        anyhow::bail!("Unit code is required");
+    }
+    if req.name_tr.trim().is_empty() {
+        anyhow::bail!("Turkish name is required");
+    }
+    if let Some(existing) = registry.find(&code) {
+        anyhow::bail!("'{}' already stands for unit {}", code, existing.code);
+    }
+    let factor = if req.dimension == UnitDimension::Pack { 1.0 } else { req.factor };
+    if !(factor.is_finite() && factor > 0.0) {
+        anyhow::bail!("Conversion factor must be positive");
+    }
+    let aliases = clean_aliases(&registry, &code, &req.aliases)?;
+
+    let txn = conn.begin().await?;
+    let result = txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "INSERT INTO units (code, name_tr, name_en, dimension, factor) VALUES (?, ?, ?, ?, ?)",
+        vec![
+            Value::String(Some(Box::new(code.clone()))),
+            Value::String(Some(Box::new(req.name_tr.trim().to_string()))),
+            Value::String(req.name_en.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()).map(Box::new)),
+            Value::String(Some(Box::new(dimension_to_str(req.dimension).to_string()))),
+            Value::Double(Some(factor)),
+        ]
+    )).await?;
+    save_aliases(&txn, result.last_insert_id() as i32, &aliases).await?;
+    txn.commit().await?;
+
+    load_registry(&conn).await?.units.into_iter()
+        .find(|u| u.code == code)
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve created unit"))
+}
+
+/// Update a unit of measure. The code and dimension stay, since quantities are stored in them.
+pub async fn update(id: i32, req: UpdateUnitRequest) -> Result<UnitOfMeasure> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let registry = load_registry(&conn).await?;
+    let existing = registry.units.iter().find(|u| u.id == id)
+        .ok_or_else(|| anyhow::anyhow!("Unit not found"))?;
+
+    let factor = match (existing.dimension, req.factor) {
+        (UnitDimension::Pack, _) | (_, None) => existing.factor,
+        (_, Some(factor)) if factor.is_finite() && factor > 0.0 => factor,
+        _ => anyhow::bail!("Conversion factor must be positive"),
+    };
+    let name_tr = req.name_tr.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()).unwrap_or(existing.name_tr.clone());
+    let name_en = req.name_en.or(existing.name_en.clone());
+    let is_active = req.is_active.unwrap_or(existing.is_active);
+    let aliases = match &req.aliases {
+        Some(aliases) => Some(clean_aliases(&registry, &existing.code, aliases)?),
+        None => None,
+    };
+
+    let txn = conn.begin().await?;
+    txn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "UPDATE units SET name_tr = ?, name_en = ?, factor = ?, is_active = ?, updated_at = datetime('now') WHERE id = ?",
+        vec![
+            Value::String(Some(Box::new(name_tr))),
+            Value::String(name_en.map(Box::new)),
+            Value::Double(Some(factor)),
+            Value::Int(Some(is_active as i32)),
+            Value::Int(Some(id)),
+        ]
+    )).await?;
+    if let Some(aliases) = aliases {
+        save_aliases(&txn, id, &aliases).await?;
+    }
+    txn.commit().await?;
+
+    load_registry(&conn).await?.units.into_iter()
+        .find(|u| u.id == id)
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve updated unit"))
+}
+
+/// Pack sizes of a supply item
+pub async fn get_pack_sizes(supply_item_id: i32) -> Result<Vec<PackSize>> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    load_pack_sizes(&conn, supply_item_id).await
+}
+
+/// Set how much one pack of a supply item holds (replaces an earlier size of the same pack unit)
+pub async fn set_pack_size(supply_item_id: i32, pack_unit: &str, quantity: f64, content_unit: &str) -> Result<PackSize> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let registry = load_registry(&conn).await?;
+    let pack = registry.resolve(pack_unit)?;
+    let content = registry.resolve(content_unit)?;
+    if !(quantity.is_finite() && quantity > 0.0) {
+        anyhow::bail!("Pack quantity must be positive");
+    }
+    if pack.code == content.code {
+        anyhow::bail!("A pack cannot contain its own unit");
+    }
+    if pack.dimension == content.dimension && pack.dimension != UnitDimension::Pack {
+        anyhow::bail!("{} and {} already convert without a pack size", pack.code, content.code);
+    }
+
+    let mut packs: Vec<PackSize> = load_pack_sizes(&conn, supply_item_id).await?
+        .into_iter()
+        .filter(|p| p.pack_unit != pack.code)
+        .collect();
+    packs.push(PackSize {
+        id: 0,
+        supply_item_id,
+        pack_unit: pack.code.clone(),
+        quantity,
+        content_unit: content.code.clone(),
+    });
+    if registry.measure(&pack.code, &packs, 0).is_none() {
+        anyhow::bail!("Pack sizes of this item would contain each other");
+    }
+
+    conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        r#"
+        INSERT INTO pack_sizes (supply_item_id, pack_unit, quantity, content_unit) VALUES (?, ?, ?, ?)
+        ON CONFLICT(supply_item_id, pack_unit) DO UPDATE SET quantity = excluded.quantity, content_unit = excluded.content_unit
+        "#,
+        vec![
+            Value::Int(Some(supply_item_id)),
+            Value::String(Some(Box::new(pack.code.clone()))),
+            Value::Double(Some(quantity)),
+            Value::String(Some(Box::new(content.code.clone()))),
+        ]
+    )).await?;
+
+    load_pack_sizes(&conn, supply_item_id).await?
+        .into_iter()
+        .find(|p| p.pack_unit == pack.code)
+        .ok_or_else(|| anyhow::anyhow!("Failed to retrieve pack size"))
+}
+
+/// Delete a pack size
+pub async fn delete_pack_size(id: i32) -> Result<bool> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    let result = conn.execute(Statement::from_sql_and_values(
+        DatabaseBackend::Sqlite,
+        "DELETE FROM pack_sizes WHERE id = ?",
+        vec![Value::Int(Some(id))]
+    )).await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Convert a quantity between units, with the pack sizes of a supply item when given
+pub async fn convert_quantity(quantity: f64, from_unit: &str, to_unit: &str, supply_item_id: Option<i32>) -> Result<f64> {
+    let conn = database::get_connection()
+        .await
+        .ok_or_else(|| anyhow::anyhow!("Database not connected"))?;
+
+    convert(&conn, quantity, from_unit, to_unit, supply_item_id).await
+}
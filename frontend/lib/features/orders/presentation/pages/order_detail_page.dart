@@ -684,13 +684,17 @@ class _OrderDetailPageState extends State<OrderDetailPage> {
         notes: null,
       );
 
-      await rust_api.addOrderItem(item: request);
+      final updated = await rust_api.addOrderItem(item: request);
+      final creditWarning = updated.creditCheck?.warning;
 
       if (mounted) {
         Navigator.pop(context);
         _loadData();
         ScaffoldMessenger.of(context).showSnackBar(
-          const SnackBar(content: Text('Ürün eklendi'), backgroundColor: Colors.green),
+          SnackBar(
+            content: Text(creditWarning == null ? 'Ürün eklendi' : 'Ürün eklendi. Kredi uyarısı: $creditWarning'),
+            backgroundColor: creditWarning == null ? Colors.green : Colors.orange,
+          ),
         );
       }
     } catch (e) {
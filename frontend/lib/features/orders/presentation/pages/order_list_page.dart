@@ -557,14 +557,18 @@ class _OrderListPageState extends State<OrderListPage> {
                       currency: selectedCurrency,
                     );
 
-                    final newOrder = await rust_api.createOrder(order: request);
+                    final created = await rust_api.createOrder(order: request);
+                    final newOrder = created.order;
+                    final creditWarning = created.creditCheck?.warning;
                     if (mounted) {
                       Navigator.pop(context);
                       _loadData();
                       ScaffoldMessenger.of(context).showSnackBar(
                         SnackBar(
-                          content: Text('Sipariş ${newOrder.orderNumber} oluşturuldu. Şimdi ürün ekleyebilirsiniz.'),
-                          backgroundColor: Colors.green,
+                          content: Text(creditWarning == null
+                              ? 'Sipariş ${newOrder.orderNumber} oluşturuldu. Şimdi ürün ekleyebilirsiniz.'
+                              : 'Sipariş ${newOrder.orderNumber} oluşturuldu. Kredi uyarısı: $creditWarning'),
+                          backgroundColor: creditWarning == null ? Colors.green : Colors.orange,
                           action: SnackBarAction(
                             label: 'Ürün Ekle',
                             textColor: Colors.white,
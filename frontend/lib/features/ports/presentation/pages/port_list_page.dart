@@ -228,6 +228,7 @@ class _PortListPageState extends State<PortListPage> {
     String selectedTimezone = existingPort?.timezone ?? 'Europe/Istanbul';
     final notesController = TextEditingController(text: existingPort?.notes ?? '');
     bool isActive = existingPort?.isActive ?? true;
+    bool isInternational = existingPort?.isInternational ?? false;
 
     await showDialog(
       context: context,
@@ -298,6 +299,13 @@ class _PortListPageState extends State<PortListPage> {
                     ),
                     maxLines: 2,
                   ),
+                  const SizedBox(height: 16),
+                  SwitchListTile(
+                    title: Text('Uluslararası Liman', style: GoogleFonts.inter()),
+                    value: isInternational,
+                    onChanged: (v) => setDialogState(() => isInternational = v),
+                    contentPadding: EdgeInsets.zero,
+                  ),
                   if (portId != null) ...[
                     const SizedBox(height: 16),
                     SwitchListTile(
@@ -337,6 +345,7 @@ class _PortListPageState extends State<PortListPage> {
                         latitude: null,
                         longitude: null,
                         notes: notesController.text.isEmpty ? null : notesController.text,
+                        isInternational: isInternational,
                       ),
                     );
                   } else {
@@ -350,6 +359,7 @@ class _PortListPageState extends State<PortListPage> {
                         latitude: null,
                         longitude: null,
                         notes: notesController.text.isEmpty ? null : notesController.text,
+                        isInternational: isInternational,
                         isActive: isActive,
                       ),
                     );
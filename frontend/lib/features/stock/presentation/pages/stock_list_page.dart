@@ -193,6 +193,9 @@ class _StockListPageState extends State<StockListPage> {
     final quantityController = TextEditingController();
     final notesController = TextEditingController();
     final referenceInfoController = TextEditingController();
+    final declarationController = TextEditingController();
+    final orderIdController = TextEditingController();
+    final orderItemIdController = TextEditingController();
     String? selectedRefType;
 
     showDialog(
@@ -239,6 +242,26 @@ class _StockListPageState extends State<StockListPage> {
                 onChanged: (val) => selectedRefType = val,
               ),
               const SizedBox(height: 16),
+              TextField(
+                controller: orderIdController,
+                decoration: const InputDecoration(
+                  labelText: 'Sipariş ID',
+                  hintText: 'Antrepo çıkışı için zorunlu',
+                  border: OutlineInputBorder(),
+                ),
+                keyboardType: TextInputType.number,
+              ),
+              const SizedBox(height: 16),
+              TextField(
+                controller: orderItemIdController,
+                decoration: const InputDecoration(
+                  labelText: 'Sipariş Kalemi ID',
+                  hintText: 'Mal kabulünün karşıladığı sipariş kalemi',
+                  border: OutlineInputBorder(),
+                ),
+                keyboardType: TextInputType.number,
+              ),
+              const SizedBox(height: 16),
               TextField(
                 controller: referenceInfoController,
                 decoration: const InputDecoration(
@@ -248,6 +271,15 @@ class _StockListPageState extends State<StockListPage> {
                 ),
               ),
               const SizedBox(height: 16),
+              TextField(
+                controller: declarationController,
+                decoration: const InputDecoration(
+                  labelText: 'Gümrük Beyanname No',
+                  hintText: 'Antrepo stoğu için zorunlu',
+                  border: OutlineInputBorder(),
+                ),
+              ),
+              const SizedBox(height: 16),
               TextField(
                 controller: notesController,
                 decoration: const InputDecoration(
@@ -285,11 +317,19 @@ class _StockListPageState extends State<StockListPage> {
                     movementType: type,
                     quantity: qty,
                     referenceType: selectedRefType,
-                    referenceId: null,
+                    referenceId: selectedRefType == 'order'
+                        ? int.tryParse(orderIdController.text.trim())
+                        : null,
+                    orderItemId: selectedRefType == 'order'
+                        ? int.tryParse(orderItemIdController.text.trim())
+                        : null,
                     referenceInfo: referenceInfoController.text.isEmpty 
                         ? null 
                         : referenceInfoController.text,
                     notes: notesController.text.isEmpty ? null : notesController.text,
+                    customsDeclarationNumber: declarationController.text.trim().isEmpty
+                        ? null
+                        : declarationController.text.trim(),
                   ),
                 );
                 if (mounted) {
@@ -413,6 +453,7 @@ class _StockListPageState extends State<StockListPage> {
     if (!mounted) return;
 
     int? selectedSupplyItemId;
+    bool isBondedItem = false;
     final quantityController = TextEditingController(text: '0');
     final minQtyController = TextEditingController(text: '10');
     final locationController = TextEditingController();
@@ -451,6 +492,8 @@ class _StockListPageState extends State<StockListPage> {
                       selectedSupplyItemId = val;
                       final item = supplyItems.firstWhere((i) => i.id == val);
                       selectedUnit = item.unit;
+                      isBondedItem = item.isBonded;
+                      if (isBondedItem) quantityController.text = '0';
                     });
                   },
                 ),
@@ -523,6 +566,7 @@ class _StockListPageState extends State<StockListPage> {
                       warehouseLocation: locationController.text.isEmpty 
                           ? null 
                           : locationController.text,
+                      warehouseType: isBondedItem ? WarehouseType.bonded : WarehouseType.general,
                       minimumQuantity: double.tryParse(minQtyController.text) ?? 10,
                     ),
                   );
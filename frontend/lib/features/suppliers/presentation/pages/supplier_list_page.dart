@@ -16,6 +16,7 @@ class SupplierListPage extends StatefulWidget {
 
 class _SupplierListPageState extends State<SupplierListPage> {
   List<Supplier> _suppliers = [];
+  List<Category> _categories = [];
   bool _isLoading = true;
   String _searchQuery = '';
   final TextEditingController _searchController = TextEditingController();
@@ -25,18 +26,6 @@ class _SupplierListPageState extends State<SupplierListPage> {
   List<PlutoRow> _rows = [];
   Key _gridKey = UniqueKey();
 
-  // Supplier categories
-  static const List<String> _categories = [
-    'Gıda',
-    'Teknik Malzeme',
-    'Güverte Malzemesi',
-    'Makine Malzemesi',
-    'Kimyasal',
-    'Elektrik',
-    'Emniyet Ekipmanı',
-    'Diğer',
-  ];
-
   @override
   void initState() {
     super.initState();
@@ -148,8 +137,10 @@ class _SupplierListPageState extends State<SupplierListPage> {
     setState(() => _isLoading = true);
     try {
       final suppliers = await rust_api.getAllSuppliers();
+      final categories = await rust_api.getAllCategories();
       setState(() {
         _suppliers = suppliers;
+        _categories = categories.where((c) => c.isActive).toList();
         _updateRows();
         _gridKey = UniqueKey();
         _isLoading = false;
@@ -285,7 +276,7 @@ class _SupplierListPageState extends State<SupplierListPage> {
             phone: request.phone,
             address: request.address,
             country: request.country,
-            category: request.category,
+            categoryId: request.categoryId,
           );
           await rust_api.updateSupplier(id: supplier.id, supplier: updateRequest);
         },
@@ -618,7 +609,7 @@ class _SupplierCard extends StatelessWidget {
 /// Supplier Form Dialog for Create/Edit
 class _SupplierFormDialog extends StatefulWidget {
   final Supplier? supplier;
-  final List<String> categories;
+  final List<Category> categories;
   final Future<void> Function(CreateSupplierRequest) onSave;
 
   const _SupplierFormDialog({
@@ -639,7 +630,7 @@ class _SupplierFormDialogState extends State<_SupplierFormDialog> {
   late TextEditingController _phoneController;
   late TextEditingController _addressController;
   late TextEditingController _countryController;
-  late String _selectedCategory;
+  late int? _selectedCategoryId;
   bool _isLoading = false;
 
   bool get isEditing => widget.supplier != null;
@@ -653,7 +644,12 @@ class _SupplierFormDialogState extends State<_SupplierFormDialog> {
     _phoneController = TextEditingController(text: widget.supplier?.phone ?? '');
     _addressController = TextEditingController(text: widget.supplier?.address ?? '');
     _countryController = TextEditingController(text: widget.supplier?.country ?? '');
-    _selectedCategory = widget.supplier?.category ?? widget.categories.first;
+    final supplierCategoryId = widget.supplier?.categoryId;
+    if (widget.categories.any((c) => c.id == supplierCategoryId)) {
+      _selectedCategoryId = supplierCategoryId;
+    } else {
+      _selectedCategoryId = widget.categories.isEmpty ? null : widget.categories.first.id;
+    }
   }
 
   @override
@@ -668,7 +664,8 @@ class _SupplierFormDialogState extends State<_SupplierFormDialog> {
   }
 
   Future<void> _submit() async {
-    if (!_formKey.currentState!.validate()) return;
+    final categoryId = _selectedCategoryId;
+    if (!_formKey.currentState!.validate() || categoryId == null) return;
 
     setState(() => _isLoading = true);
 
@@ -679,7 +676,7 @@ class _SupplierFormDialogState extends State<_SupplierFormDialog> {
       phone: _phoneController.text.trim().isEmpty ? null : _phoneController.text.trim(),
       address: _addressController.text.trim().isEmpty ? null : _addressController.text.trim(),
       country: _countryController.text.trim().isEmpty ? null : _countryController.text.trim().toUpperCase(),
-      category: _selectedCategory,
+      categoryId: categoryId,
     );
 
     try {
@@ -749,11 +746,11 @@ class _SupplierFormDialogState extends State<_SupplierFormDialog> {
                     const SizedBox(width: 16),
                     Expanded(
                       flex: 2,
-                      child: _buildDropdown(
+                      child: _buildCategoryDropdown(
                         label: 'Kategori *',
-                        value: _selectedCategory,
+                        value: _selectedCategoryId,
                         items: widget.categories,
-                        onChanged: (v) => setState(() => _selectedCategory = v!),
+                        onChanged: (v) => setState(() => _selectedCategoryId = v),
                       ),
                     ),
                   ],
@@ -912,11 +909,11 @@ class _SupplierFormDialogState extends State<_SupplierFormDialog> {
     );
   }
 
-  Widget _buildDropdown({
+  Widget _buildCategoryDropdown({
     required String label,
-    required String value,
-    required List<String> items,
-    required void Function(String?) onChanged,
+    required int? value,
+    required List<Category> items,
+    required void Function(int?) onChanged,
   }) {
     return Column(
       crossAxisAlignment: CrossAxisAlignment.start,
@@ -930,14 +927,15 @@ class _SupplierFormDialogState extends State<_SupplierFormDialog> {
           ),
         ),
         const SizedBox(height: 6),
-        DropdownButtonFormField<String>(
+        DropdownButtonFormField<int>(
           value: value,
           isExpanded: true,
           items: items.map((item) => DropdownMenuItem(
-            value: item,
-            child: Text(item, style: GoogleFonts.inter(fontSize: 14), overflow: TextOverflow.ellipsis),
+            value: item.id,
+            child: Text(item.path, style: GoogleFonts.inter(fontSize: 14), overflow: TextOverflow.ellipsis),
           )).toList(),
           onChanged: onChanged,
+          validator: (v) => v == null ? 'Kategori gerekli' : null,
           decoration: InputDecoration(
             filled: true,
             fillColor: AppTheme.background,
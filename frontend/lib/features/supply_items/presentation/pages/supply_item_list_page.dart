@@ -17,6 +17,7 @@ class SupplyItemListPage extends StatefulWidget {
 class _SupplyItemListPageState extends State<SupplyItemListPage> {
   List<SupplyItem> _items = [];
   List<Supplier> _suppliers = [];
+  List<Category> _categories = [];
   bool _isLoading = true;
   String _searchQuery = '';
   final TextEditingController _searchController = TextEditingController();
@@ -26,18 +27,6 @@ class _SupplyItemListPageState extends State<SupplyItemListPage> {
   List<PlutoRow> _rows = [];
   Key _gridKey = UniqueKey();
 
-  // Item categories
-  static const List<String> _categories = [
-    'Gıda',
-    'Teknik Malzeme',
-    'Güverte Malzemesi',
-    'Makine Malzemesi',
-    'Kimyasal',
-    'Elektrik',
-    'Emniyet Ekipmanı',
-    'Diğer',
-  ];
-
   // Units
   static const List<String> _units = [
     'Adet',
@@ -187,10 +176,12 @@ class _SupplyItemListPageState extends State<SupplyItemListPage> {
       final results = await Future.wait([
         rust_api.getAllSupplyItems(),
         rust_api.getAllSuppliers(),
+        rust_api.getAllCategories(),
       ]);
       setState(() {
         _items = results[0] as List<SupplyItem>;
         _suppliers = results[1] as List<Supplier>;
+        _categories = (results[2] as List<Category>).where((c) => c.isActive).toList();
         _updateRows();
         _gridKey = UniqueKey();
         _isLoading = false;
@@ -332,7 +323,7 @@ class _SupplyItemListPageState extends State<SupplyItemListPage> {
             impaCode: request.impaCode,
             name: request.name,
             description: request.description,
-            category: request.category,
+            categoryId: request.categoryId,
             unit: request.unit,
             unitPrice: request.unitPrice,
             currency: request.currency,
@@ -683,7 +674,7 @@ class _SupplyItemCard extends StatelessWidget {
 class _SupplyItemFormDialog extends StatefulWidget {
   final SupplyItem? item;
   final List<Supplier> suppliers;
-  final List<String> categories;
+  final List<Category> categories;
   final List<String> units;
   final List<String> currencies;
   final Future<void> Function(CreateSupplyItemRequest) onSave;
@@ -709,7 +700,7 @@ class _SupplyItemFormDialogState extends State<_SupplyItemFormDialog> {
   late TextEditingController _unitPriceController;
   late TextEditingController _minQtyController;
   late int? _selectedSupplierId;
-  late String _selectedCategory;
+  late int? _selectedCategoryId;
   late String _selectedUnit;
   late String _selectedCurrency;
   bool _isLoading = false;
@@ -729,7 +720,12 @@ class _SupplyItemFormDialogState extends State<_SupplyItemFormDialog> {
       text: widget.item?.minimumOrderQuantity?.toString() ?? '',
     );
     _selectedSupplierId = widget.item?.supplierId ?? (widget.suppliers.isNotEmpty ? widget.suppliers.first.id : null);
-    _selectedCategory = widget.item?.category ?? widget.categories.first;
+    final itemCategoryId = widget.item?.categoryId;
+    if (widget.categories.any((c) => c.id == itemCategoryId)) {
+      _selectedCategoryId = itemCategoryId;
+    } else {
+      _selectedCategoryId = isEditing || widget.categories.isEmpty ? null : widget.categories.first.id;
+    }
     _selectedUnit = widget.item?.unit ?? widget.units.first;
     _selectedCurrency = widget.item?.currency ?? widget.currencies.first;
   }
@@ -760,11 +756,14 @@ class _SupplyItemFormDialogState extends State<_SupplyItemFormDialog> {
       impaCode: _impaCodeController.text.trim().isEmpty ? null : _impaCodeController.text.trim(),
       name: _nameController.text.trim(),
       description: _descriptionController.text.trim().isEmpty ? null : _descriptionController.text.trim(),
-      category: _selectedCategory,
+      categoryId: _selectedCategoryId,
       unit: _selectedUnit,
       unitPrice: double.tryParse(_unitPriceController.text) ?? 0.0,
       currency: _selectedCurrency,
       minimumOrderQuantity: int.tryParse(_minQtyController.text),
+      sdsRequired: widget.item?.sdsRequired ?? false,
+      prescriptionOnly: widget.item?.prescriptionOnly ?? false,
+      isBonded: widget.item?.isBonded ?? false,
     );
 
     try {
@@ -853,13 +852,7 @@ class _SupplyItemFormDialogState extends State<_SupplyItemFormDialog> {
                   children: [
                     // Category
                     Expanded(
-                      child: _buildDropdownField(
-                        label: 'Kategori',
-                        value: _selectedCategory,
-                        items: widget.categories,
-                        onChanged: (v) => setState(() => _selectedCategory = v!),
-                        icon: Icons.category_outlined,
-                      ),
+                      child: _buildCategoryDropdown(),
                     ),
                     const SizedBox(width: 16),
                     // Supplier
@@ -1091,6 +1084,56 @@ class _SupplyItemFormDialogState extends State<_SupplyItemFormDialog> {
     );
   }
 
+  Widget _buildCategoryDropdown() {
+    return Column(
+      crossAxisAlignment: CrossAxisAlignment.start,
+      children: [
+        Text(
+          'Kategori',
+          style: GoogleFonts.inter(
+            fontSize: 13,
+            fontWeight: FontWeight.w500,
+            color: AppTheme.secondaryText,
+          ),
+        ),
+        const SizedBox(height: 6),
+        DropdownButtonFormField<int>(
+          value: _selectedCategoryId,
+          isExpanded: true,
+          onChanged: (v) => setState(() => _selectedCategoryId = v),
+          decoration: InputDecoration(
+            prefixIcon: const Icon(Icons.category_outlined, size: 18, color: AppTheme.secondaryText),
+            filled: true,
+            fillColor: AppTheme.background,
+            border: OutlineInputBorder(
+              borderRadius: BorderRadius.circular(8),
+              borderSide: const BorderSide(color: AppTheme.border),
+            ),
+            enabledBorder: OutlineInputBorder(
+              borderRadius: BorderRadius.circular(8),
+              borderSide: const BorderSide(color: AppTheme.border),
+            ),
+            focusedBorder: OutlineInputBorder(
+              borderRadius: BorderRadius.circular(8),
+              borderSide: const BorderSide(color: AppTheme.accent),
+            ),
+            contentPadding: const EdgeInsets.symmetric(horizontal: 12, vertical: 12),
+          ),
+          items: widget.categories.map((category) {
+            return DropdownMenuItem(
+              value: category.id,
+              child: Text(
+                category.path,
+                style: GoogleFonts.inter(fontSize: 14),
+                overflow: TextOverflow.ellipsis,
+              ),
+            );
+          }).toList(),
+        ),
+      ],
+    );
+  }
+
   Widget _buildSupplierDropdown() {
     return Column(
       crossAxisAlignment: CrossAxisAlignment.start,
@@ -7,322 +7,699 @@ import 'frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Get all ships from the database
-Future<List<Ship>> getAllShips() => RustLib.instance.api.crateApiGetAllShips();
+
+            
+
+            /// Get all ships from the database
+Future<List<Ship>>  getAllShips() => RustLib.instance.api.crateApiGetAllShips();
 
 /// Get a single ship by ID
-Future<Ship?> getShipById({required int id}) =>
-    RustLib.instance.api.crateApiGetShipById(id: id);
+Future<Ship?>  getShipById({required int id }) => RustLib.instance.api.crateApiGetShipById(id: id);
 
 /// Create a new ship
-Future<Ship> createShip({required CreateShipRequest ship}) =>
-    RustLib.instance.api.crateApiCreateShip(ship: ship);
+Future<Ship>  createShip({required CreateShipRequest ship }) => RustLib.instance.api.crateApiCreateShip(ship: ship);
 
 /// Update an existing ship
-Future<Ship> updateShip({required int id, required UpdateShipRequest ship}) =>
-    RustLib.instance.api.crateApiUpdateShip(id: id, ship: ship);
+Future<Ship>  updateShip({required int id , required UpdateShipRequest ship }) => RustLib.instance.api.crateApiUpdateShip(id: id, ship: ship);
 
 /// Delete a ship
-Future<bool> deleteShip({required int id}) =>
-    RustLib.instance.api.crateApiDeleteShip(id: id);
+Future<bool>  deleteShip({required int id }) => RustLib.instance.api.crateApiDeleteShip(id: id);
 
 /// Search ships by name, IMO or flag
-Future<List<Ship>> searchShips({required String query}) =>
-    RustLib.instance.api.crateApiSearchShips(query: query);
+Future<List<Ship>>  searchShips({required String query }) => RustLib.instance.api.crateApiSearchShips(query: query);
 
 /// Get total ship count
-Future<PlatformInt64> getShipCount() =>
-    RustLib.instance.api.crateApiGetShipCount();
+Future<PlatformInt64>  getShipCount() => RustLib.instance.api.crateApiGetShipCount();
+
+/// Get all active customers with their contacts
+Future<List<Customer>>  getAllCustomers() => RustLib.instance.api.crateApiGetAllCustomers();
+
+/// Get a single customer by ID
+Future<Customer?>  getCustomerById({required int id }) => RustLib.instance.api.crateApiGetCustomerById(id: id);
+
+/// Search customers by legal name or tax number
+Future<List<Customer>>  searchCustomers({required String query }) => RustLib.instance.api.crateApiSearchCustomers(query: query);
+
+/// Create a new customer with contacts
+Future<Customer>  createCustomer({required CreateCustomerRequest customer }) => RustLib.instance.api.crateApiCreateCustomer(customer: customer);
+
+/// Update an existing customer
+Future<Customer>  updateCustomer({required int id , required UpdateCustomerRequest customer }) => RustLib.instance.api.crateApiUpdateCustomer(id: id, customer: customer);
+
+/// Deactivate a customer
+Future<bool>  deleteCustomer({required int id }) => RustLib.instance.api.crateApiDeleteCustomer(id: id);
+
+/// Add a contact person to a customer
+Future<Customer>  addCustomerContact({required int customerId , required CreateCustomerContactRequest contact }) => RustLib.instance.api.crateApiAddCustomerContact(customerId: customerId, contact: contact);
+
+/// Remove a customer contact
+Future<bool>  deleteCustomerContact({required int contactId }) => RustLib.instance.api.crateApiDeleteCustomerContact(contactId: contactId);
+
+/// Get ships owned / managed by a customer
+Future<List<Ship>>  getCustomerShips({required int customerId }) => RustLib.instance.api.crateApiGetCustomerShips(customerId: customerId);
+
+/// Current credit exposure of a customer
+Future<CreditCheck>  getCustomerCreditStatus({required int customerId }) => RustLib.instance.api.crateApiGetCustomerCreditStatus(customerId: customerId);
+
+/// Check whether an order would push its customer over the credit limit
+Future<CreditCheck?>  checkOrderCreditLimit({required int orderId }) => RustLib.instance.api.crateApiCheckOrderCreditLimit(orderId: orderId);
+
+/// Get price agreements, optionally for a single customer
+Future<List<PriceAgreement>>  getPriceAgreements({int? customerId }) => RustLib.instance.api.crateApiGetPriceAgreements(customerId: customerId);
+
+/// Get a price agreement with its lines
+Future<PriceAgreement?>  getPriceAgreementById({required int id }) => RustLib.instance.api.crateApiGetPriceAgreementById(id: id);
+
+/// Create a price agreement with item / IMPA group lines
+Future<PriceAgreement>  createPriceAgreement({required CreatePriceAgreementRequest agreement }) => RustLib.instance.api.crateApiCreatePriceAgreement(agreement: agreement);
+
+/// Update a price agreement (name, validity, notes, active flag)
+Future<PriceAgreement>  updatePriceAgreement({required int id , required UpdatePriceAgreementRequest agreement }) => RustLib.instance.api.crateApiUpdatePriceAgreement(id: id, agreement: agreement);
+
+/// Delete a price agreement
+Future<bool>  deletePriceAgreement({required int id }) => RustLib.instance.api.crateApiDeletePriceAgreement(id: id);
+
+/// Add a price line to an agreement
+Future<PriceAgreement>  addPriceAgreementLine({required int agreementId , required CreatePriceAgreementLineRequest line }) => RustLib.instance.api.crateApiAddPriceAgreementLine(agreementId: agreementId, line: line);
+
+/// Remove a price line from an agreement
+Future<bool>  deletePriceAgreementLine({required int lineId }) => RustLib.instance.api.crateApiDeletePriceAgreementLine(lineId: lineId);
+
+/// Contract selling price for an IMPA item on an order (None = no contract)
+Future<ContractPrice?>  getContractPrice({required int orderId , required String impaCode , required double buyingPrice }) => RustLib.instance.api.crateApiGetContractPrice(orderId: orderId, impaCode: impaCode, buyingPrice: buyingPrice);
+
+/// Order lines priced outside the customer's contract
+Future<List<OffContractLine>>  getOffContractLines({int? customerId }) => RustLib.instance.api.crateApiGetOffContractLines(customerId: customerId);
+
+/// Get all pricing rules, most specific first
+Future<List<PricingRule>>  getPricingRules() => RustLib.instance.api.crateApiGetPricingRules();
+
+/// Create a markup / margin floor rule
+Future<PricingRule>  createPricingRule({required CreatePricingRuleRequest rule }) => RustLib.instance.api.crateApiCreatePricingRule(rule: rule);
+
+/// Update a pricing rule
+Future<PricingRule>  updatePricingRule({required int id , required UpdatePricingRuleRequest rule }) => RustLib.instance.api.crateApiUpdatePricingRule(id: id, rule: rule);
+
+/// Delete a pricing rule
+Future<bool>  deletePricingRule({required int id }) => RustLib.instance.api.crateApiDeletePricingRule(id: id);
+
+/// Get selling price rounding per currency
+Future<List<CurrencyRounding>>  getCurrencyRoundings() => RustLib.instance.api.crateApiGetCurrencyRoundings();
+
+/// Set the selling price rounding increment of a currency
+Future<CurrencyRounding>  setCurrencyRounding({required String currency , required double increment }) => RustLib.instance.api.crateApiSetCurrencyRounding(currency: currency, increment: increment);
+
+/// Propose a selling price for an item on an order, with the rule that fired
+Future<PriceProposal>  proposeSellingPrice({required int orderId , String? impaCode , required double buyingPrice }) => RustLib.instance.api.crateApiProposeSellingPrice(orderId: orderId, impaCode: impaCode, buyingPrice: buyingPrice);
+
+/// Re-price all lines of an order with the pricing engine
+Future<RepriceResult>  repriceOrder({required int orderId }) => RustLib.instance.api.crateApiRepriceOrder(orderId: orderId);
 
 /// Get all orders with optional status filter
-Future<List<Order>> getAllOrders({OrderStatus? statusFilter}) =>
-    RustLib.instance.api.crateApiGetAllOrders(statusFilter: statusFilter);
+Future<List<Order>>  getAllOrders({OrderStatus? statusFilter }) => RustLib.instance.api.crateApiGetAllOrders(statusFilter: statusFilter);
 
 /// Get a single order with all items
-Future<OrderWithItems?> getOrderWithItems({required int id}) =>
-    RustLib.instance.api.crateApiGetOrderWithItems(id: id);
+Future<OrderWithItems?>  getOrderWithItems({required int id }) => RustLib.instance.api.crateApiGetOrderWithItems(id: id);
 
-/// Create a new order
-Future<Order> createOrder({required CreateOrderRequest order}) =>
-    RustLib.instance.api.crateApiCreateOrder(order: order);
+/// Create a new order (with the customer's credit check)
+Future<OrderWithItems>  createOrder({required CreateOrderRequest order }) => RustLib.instance.api.crateApiCreateOrder(order: order);
 
 /// Update an existing order
-Future<Order> updateOrder(
-        {required int id, required UpdateOrderRequest order}) =>
-    RustLib.instance.api.crateApiUpdateOrder(id: id, order: order);
+Future<Order>  updateOrder({required int id , required UpdateOrderRequest order }) => RustLib.instance.api.crateApiUpdateOrder(id: id, order: order);
 
-/// Update order status (state machine enforced)
-Future<Order> updateOrderStatus(
-        {required int id, required OrderStatus newStatus}) =>
-    RustLib.instance.api
-        .crateApiUpdateOrderStatus(id: id, newStatus: newStatus);
+/// Update order status (state machine enforced; Delivered needs a signed delivery note or a waiver)
+Future<Order>  updateOrderStatus({required int id , required OrderStatus newStatus }) => RustLib.instance.api.crateApiUpdateOrderStatus(id: id, newStatus: newStatus);
 
 /// Get orders by ship visit ID
-Future<List<Order>> getOrdersByShipVisit({required int shipVisitId}) =>
-    RustLib.instance.api.crateApiGetOrdersByShipVisit(shipVisitId: shipVisitId);
+Future<List<Order>>  getOrdersByShipVisit({required int shipVisitId }) => RustLib.instance.api.crateApiGetOrdersByShipVisit(shipVisitId: shipVisitId);
 
 /// Delete an order (cascade deletes order items)
-Future<bool> deleteOrder({required int id}) =>
-    RustLib.instance.api.crateApiDeleteOrder(id: id);
+Future<bool>  deleteOrder({required int id }) => RustLib.instance.api.crateApiDeleteOrder(id: id);
+
+/// Copy an order's items into a new order for another ship visit, optionally at today's prices
+Future<OrderWithItems>  cloneOrder({required int orderId , required int targetShipVisitId , required bool refreshPrices }) => RustLib.instance.api.crateApiCloneOrder(orderId: orderId, targetShipVisitId: targetShipVisitId, refreshPrices: refreshPrices);
+
+/// Move the given items into a new order numbered after the original (linked for audit)
+Future<OrderWithItems>  splitOrder({required int orderId , required List<int> itemIds }) => RustLib.instance.api.crateApiSplitOrder(orderId: orderId, itemIds: itemIds);
+
+/// Merge orders for the same visit into the first one; the others are cancelled and linked
+Future<OrderWithItems>  mergeOrders({required List<int> orderIds }) => RustLib.instance.api.crateApiMergeOrders(orderIds: orderIds);
+
+/// Get the splits and merges an order took part in
+Future<List<OrderLink>>  getOrderLinks({required int orderId }) => RustLib.instance.api.crateApiGetOrderLinks(orderId: orderId);
 
 /// Get all items for an order
-Future<List<OrderItem>> getOrderItems({required int orderId}) =>
-    RustLib.instance.api.crateApiGetOrderItems(orderId: orderId);
+Future<List<OrderItem>>  getOrderItems({required int orderId }) => RustLib.instance.api.crateApiGetOrderItems(orderId: orderId);
 
-/// Add item to an order
-Future<OrderItem> addOrderItem({required CreateOrderItemRequest item}) =>
-    RustLib.instance.api.crateApiAddOrderItem(item: item);
+/// Add item to an order; returns the order with the customer's credit check
+Future<OrderWithItems>  addOrderItem({required CreateOrderItemRequest item }) => RustLib.instance.api.crateApiAddOrderItem(item: item);
 
 /// Update order item (prices, quantity)
-Future<OrderItem> updateOrderItem(
-        {required int id, required UpdateOrderItemRequest item}) =>
-    RustLib.instance.api.crateApiUpdateOrderItem(id: id, item: item);
+Future<OrderItem>  updateOrderItem({required int id , required UpdateOrderItemRequest item }) => RustLib.instance.api.crateApiUpdateOrderItem(id: id, item: item);
 
 /// Delete order item
-Future<bool> deleteOrderItem({required int id}) =>
-    RustLib.instance.api.crateApiDeleteOrderItem(id: id);
+Future<bool>  deleteOrderItem({required int id }) => RustLib.instance.api.crateApiDeleteOrderItem(id: id);
+
+/// Get the charges of an order
+Future<List<OrderCharge>>  getOrderCharges({required int orderId }) => RustLib.instance.api.crateApiGetOrderCharges(orderId: orderId);
+
+/// Add a charge or discount to an order
+Future<OrderCharge>  addOrderCharge({required CreateOrderChargeRequest charge }) => RustLib.instance.api.crateApiAddOrderCharge(charge: charge);
+
+/// Update an order charge
+Future<OrderCharge>  updateOrderCharge({required int id , required UpdateOrderChargeRequest charge }) => RustLib.instance.api.crateApiUpdateOrderCharge(id: id, charge: charge);
+
+/// Remove a charge from an order
+Future<bool>  deleteOrderCharge({required int id }) => RustLib.instance.api.crateApiDeleteOrderCharge(id: id);
+
+/// Get landed costs recorded against an order
+Future<List<LandedCost>>  getLandedCosts({required int orderId }) => RustLib.instance.api.crateApiGetLandedCosts(orderId: orderId);
+
+/// Record a landed cost and allocate it by value, weight or quantity
+Future<LandedCost>  createLandedCost({required CreateLandedCostRequest landedCost }) => RustLib.instance.api.crateApiCreateLandedCost(landedCost: landedCost);
+
+/// Delete a landed cost and its allocations
+Future<bool>  deleteLandedCost({required int id }) => RustLib.instance.api.crateApiDeleteLandedCost(id: id);
+
+/// Get the delivery events of an order
+Future<List<ItemDelivery>>  getOrderDeliveries({required int orderId }) => RustLib.instance.api.crateApiGetOrderDeliveries(orderId: orderId);
+
+/// Record a (partial) delivery of order lines; the order status follows the fulfilment
+Future<OrderWithItems>  recordDelivery({required RecordDeliveryRequest delivery }) => RustLib.instance.api.crateApiRecordDelivery(delivery: delivery);
+
+/// Delete a delivery event
+Future<bool>  deleteOrderDelivery({required int id }) => RustLib.instance.api.crateApiDeleteOrderDelivery(id: id);
+
+/// Close an order line short, dropping its undelivered remainder
+Future<OrderItem>  closeOrderItem({required int orderItemId , String? reason }) => RustLib.instance.api.crateApiCloseOrderItem(orderItemId: orderItemId, reason: reason);
+
+/// Get the lines of an order that still have quantities to deliver
+Future<List<OrderItem>>  getBackOrderedItems({required int orderId }) => RustLib.instance.api.crateApiGetBackOrderedItems(orderId: orderId);
+
+/// Move back-ordered quantities to a follow-up order for a later visit of the same ship
+Future<Order>  rollOverBackorders({required int orderId , required int shipVisitId }) => RustLib.instance.api.crateApiRollOverBackorders(orderId: orderId, shipVisitId: shipVisitId);
+
+/// Get the back-order follow-up orders of an order
+Future<List<Order>>  getFollowUpOrders({required int orderId }) => RustLib.instance.api.crateApiGetFollowUpOrders(orderId: orderId);
+
+/// Get the delivery notes of an order
+Future<List<DeliveryNote>>  getDeliveryNotes({required int orderId }) => RustLib.instance.api.crateApiGetDeliveryNotes(orderId: orderId);
+
+/// Get a delivery note by ID
+Future<DeliveryNote?>  getDeliveryNote({required int id }) => RustLib.instance.api.crateApiGetDeliveryNote(id: id);
+
+/// Generate a numbered delivery note from delivered order items
+Future<DeliveryNote>  createDeliveryNote({required CreateDeliveryNoteRequest note }) => RustLib.instance.api.crateApiCreateDeliveryNote(note: note);
+
+/// Attach the captured signature or the ship stamp photo to a delivery note
+Future<DeliveryNoteAttachment>  addDeliveryNoteAttachment({required int deliveryNoteId , required DeliveryNoteAttachmentKind kind , required String fileName , required String mimeType , required List<int> data }) => RustLib.instance.api.crateApiAddDeliveryNoteAttachment(deliveryNoteId: deliveryNoteId, kind: kind, fileName: fileName, mimeType: mimeType, data: data);
+
+/// Get the image bytes of a delivery note attachment
+Future<Uint8List>  getDeliveryNoteAttachmentData({required int attachmentId }) => RustLib.instance.api.crateApiGetDeliveryNoteAttachmentData(attachmentId: attachmentId);
+
+/// Complete a delivery note with the receiver's name and rank (requires a signature)
+Future<DeliveryNote>  completeDeliveryNote({required int id , required CompleteDeliveryNoteRequest completion }) => RustLib.instance.api.crateApiCompleteDeliveryNote(id: id, completion: completion);
+
+/// Delete an unsigned delivery note
+Future<bool>  deleteDeliveryNote({required int id }) => RustLib.instance.api.crateApiDeleteDeliveryNote(id: id);
+
+/// Allow an order to be marked delivered without a signed delivery note
+Future<Order>  waiveDeliveryNote({required int orderId , required String reason }) => RustLib.instance.api.crateApiWaiveDeliveryNote(orderId: orderId, reason: reason);
+
+/// Check an order for missing safety data sheets and pharmacist sign-offs
+Future<OrderCompliance>  getOrderCompliance({required int orderId }) => RustLib.instance.api.crateApiGetOrderCompliance(orderId: orderId);
+
+/// Sign off the prescription-only lines of an order
+Future<OrderCompliance>  signOffPrescriptionItems({required int orderId , required PharmacistSignOffRequest signOff }) => RustLib.instance.api.crateApiSignOffPrescriptionItems(orderId: orderId, signOff: signOff);
+
+/// Put the safety data sheet (PDF or image) of a supply item on file
+Future<SupplyItem>  setSupplyItemSds({required int supplyItemId , required String fileName , required String mimeType , required List<int> data }) => RustLib.instance.api.crateApiSetSupplyItemSds(supplyItemId: supplyItemId, fileName: fileName, mimeType: mimeType, data: data);
+
+/// Dangerous goods declaration of a delivery note
+Future<DangerousGoodsSummary>  getDangerousGoodsSummary({required int deliveryNoteId }) => RustLib.instance.api.crateApiGetDangerousGoodsSummary(deliveryNoteId: deliveryNoteId);
+
+/// Get the returns recorded against an order
+Future<List<OrderReturn>>  getOrderReturns({required int orderId }) => RustLib.instance.api.crateApiGetOrderReturns(orderId: orderId);
+
+/// Get a return by ID
+Future<OrderReturn?>  getOrderReturn({required int id }) => RustLib.instance.api.crateApiGetOrderReturn(id: id);
+
+/// Record returned quantities of delivered lines (Return stock movements, optional credit note)
+Future<OrderReturn>  createOrderReturn({required CreateReturnRequest orderReturn }) => RustLib.instance.api.crateApiCreateOrderReturn(orderReturn: orderReturn);
+
+/// Issue the credit note of a return recorded without one
+Future<CreditNote>  issueReturnCreditNote({required int returnId }) => RustLib.instance.api.crateApiIssueReturnCreditNote(returnId: returnId);
+
+/// Get the credit notes of an order
+Future<List<CreditNote>>  getOrderCreditNotes({required int orderId }) => RustLib.instance.api.crateApiGetOrderCreditNotes(orderId: orderId);
+
+/// Get the credit notes issued against an invoice
+Future<List<CreditNote>>  getInvoiceCreditNotes({required int invoiceId }) => RustLib.instance.api.crateApiGetInvoiceCreditNotes(invoiceId: invoiceId);
+
+/// Get all requisition templates
+Future<List<RequisitionTemplate>>  getAllRequisitionTemplates() => RustLib.instance.api.crateApiGetAllRequisitionTemplates();
+
+/// Get the templates usable for a ship (its own and those of its ship type)
+Future<List<RequisitionTemplate>>  getRequisitionTemplatesForShip({required int shipId }) => RustLib.instance.api.crateApiGetRequisitionTemplatesForShip(shipId: shipId);
+
+/// Get a requisition template by ID
+Future<RequisitionTemplate?>  getRequisitionTemplate({required int id }) => RustLib.instance.api.crateApiGetRequisitionTemplate(id: id);
+
+/// Create a requisition template for a ship or a ship type
+Future<RequisitionTemplate>  createRequisitionTemplate({required CreateRequisitionTemplateRequest template }) => RustLib.instance.api.crateApiCreateRequisitionTemplate(template: template);
+
+/// Save an order's lines as a template for its ship (or the ship's type)
+Future<RequisitionTemplate>  createRequisitionTemplateFromOrder({required int orderId , required String name , required bool forShipType , double? baseHeadcount }) => RustLib.instance.api.crateApiCreateRequisitionTemplateFromOrder(orderId: orderId, name: name, forShipType: forShipType, baseHeadcount: baseHeadcount);
+
+/// Update a requisition template
+Future<RequisitionTemplate>  updateRequisitionTemplate({required int id , required UpdateRequisitionTemplateRequest template }) => RustLib.instance.api.crateApiUpdateRequisitionTemplate(id: id, template: template);
+
+/// Delete a requisition template
+Future<bool>  deleteRequisitionTemplate({required int id }) => RustLib.instance.api.crateApiDeleteRequisitionTemplate(id: id);
+
+/// Create a new order from a template, scaling quantities by headcount or factor
+Future<OrderWithItems>  instantiateRequisitionTemplate({required InstantiateTemplateRequest request }) => RustLib.instance.api.crateApiInstantiateRequisitionTemplate(request: request);
+
+/// Read a requisition file and match its lines to supply items (nothing is saved)
+Future<RequisitionImportPreview>  previewRequisitionImport({required RequisitionImportRequest request }) => RustLib.instance.api.crateApiPreviewRequisitionImport(request: request);
+
+/// Add the confirmed preview lines to the order as items (all or nothing)
+Future<OrderWithItems>  commitRequisitionImport({required int orderId , required List<RequisitionImportLine> lines }) => RustLib.instance.api.crateApiCommitRequisitionImport(orderId: orderId, lines: lines);
+
+/// Diff a supplier price list against the supplier's items (nothing is saved)
+Future<PriceListPreview>  previewSupplierPriceList({required PriceListImportRequest request }) => RustLib.instance.api.crateApiPreviewSupplierPriceList(request: request);
+
+/// Apply a supplier price list: update prices, create new items, discontinue missing ones (all or nothing)
+Future<PriceListImportResult>  importSupplierPriceList({required PriceListImportRequest request }) => RustLib.instance.api.crateApiImportSupplierPriceList(request: request);
+
+/// Full-text search over supply items, suppliers, ships and orders (all scopes when empty)
+Future<List<SearchHit>>  search({required String query , required List<SearchScope> scopes , required int limit }) => RustLib.instance.api.crateApiSearch(query: query, scopes: scopes, limit: limit);
+
+/// Attach a file to a supply item, order, delivery note, supplier or ship
+Future<Attachment>  addAttachment({required CreateAttachmentRequest request }) => RustLib.instance.api.crateApiAddAttachment(request: request);
+
+/// Get all attachments of a record
+Future<List<Attachment>>  getAttachments({required AttachmentEntity entityType , required int entityId }) => RustLib.instance.api.crateApiGetAttachments(entityType: entityType, entityId: entityId);
+
+/// Get attachment metadata by ID
+Future<Attachment?>  getAttachment({required int id }) => RustLib.instance.api.crateApiGetAttachment(id: id);
+
+/// Get the file contents of an attachment
+Future<Uint8List>  getAttachmentData({required int id }) => RustLib.instance.api.crateApiGetAttachmentData(id: id);
+
+/// Get the PNG thumbnail of an image attachment
+Future<Uint8List>  getAttachmentThumbnail({required int id }) => RustLib.instance.api.crateApiGetAttachmentThumbnail(id: id);
+
+/// Change the caption of an attachment
+Future<Attachment>  setAttachmentCaption({required int id , String? caption }) => RustLib.instance.api.crateApiSetAttachmentCaption(id: id, caption: caption);
+
+/// Delete an attachment
+Future<bool>  deleteAttachment({required int id }) => RustLib.instance.api.crateApiDeleteAttachment(id: id);
+
+/// Remove stored files that are no longer attached to anything
+Future<int>  pruneAttachmentFiles() => RustLib.instance.api.crateApiPruneAttachmentFiles();
+
+/// Export an order with its items and totals
+Future<ExportResult>  exportOrder({required int orderId , required String outputPath }) => RustLib.instance.api.crateApiExportOrder(orderId: orderId, outputPath: outputPath);
+
+/// Export the order list with per-order totals
+Future<ExportResult>  exportOrders({required OrderExportFilter filter , required String outputPath }) => RustLib.instance.api.crateApiExportOrders(filter: filter, outputPath: outputPath);
+
+/// Export stock levels with all stock movements
+Future<ExportResult>  exportStock({required String outputPath }) => RustLib.instance.api.crateApiExportStock(outputPath: outputPath);
+
+/// Export the bonded stock reconciliation with the period's declared movements
+Future<ExportResult>  exportBondedReconciliation({required String periodStart , required String periodEnd , required String outputPath }) => RustLib.instance.api.crateApiExportBondedReconciliation(periodStart: periodStart, periodEnd: periodEnd, outputPath: outputPath);
+
+/// Export the profit report
+Future<ExportResult>  exportProfitReport({required String outputPath }) => RustLib.instance.api.crateApiExportProfitReport(outputPath: outputPath);
+
+/// Get the company profile printed on documents
+Future<CompanyProfile>  getCompanyProfile() => RustLib.instance.api.crateApiGetCompanyProfile();
+
+/// Save the company profile printed on documents
+Future<CompanyProfile>  updateCompanyProfile({required UpdateCompanyProfileRequest profile }) => RustLib.instance.api.crateApiUpdateCompanyProfile(profile: profile);
+
+/// Render a proforma, quotation, invoice or delivery note PDF for an order
+Future<PdfRenderResult>  renderOrderPdf({required PdfRenderRequest request }) => RustLib.instance.api.crateApiRenderOrderPdf(request: request);
+
+/// Validate an IMPA code; returns it without spaces
+Future<String>  validateImpaCode({required String code }) => RustLib.instance.api.crateApiValidateImpaCode(code: code);
+
+/// Look up an IMPA code in the reference catalog (for auto-filling items)
+Future<ImpaCatalogEntry?>  lookupImpaCode({required String code }) => RustLib.instance.api.crateApiLookupImpaCode(code: code);
+
+/// Search the IMPA catalog by code prefix or description
+Future<List<ImpaCatalogEntry>>  searchImpaCatalog({required String query , String? section , required int limit }) => RustLib.instance.api.crateApiSearchImpaCatalog(query: query, section: section, limit: limit);
+
+/// Get the IMPA catalog sections
+Future<List<ImpaSection>>  getImpaSections() => RustLib.instance.api.crateApiGetImpaSections();
+
+/// Set the supply item category auto-filled for an IMPA section
+Future<ImpaSection>  setImpaSectionCategory({required String section , int? categoryId }) => RustLib.instance.api.crateApiSetImpaSectionCategory(section: section, categoryId: categoryId);
+
+/// Load an IMPA Marine Stores Guide-style CSV/XLSX file into the reference catalog
+Future<ImpaCatalogImportResult>  importImpaCatalog({required ImpaCatalogImportRequest request }) => RustLib.instance.api.crateApiImportImpaCatalog(request: request);
+
+/// Supply items whose IMPA code is malformed or not in the reference catalog
+Future<List<ImpaCodeIssue>>  getImpaCodeIssues() => RustLib.instance.api.crateApiGetImpaCodeIssues();
+
+/// Get the category tree as a depth-first list (use `depth` to indent)
+Future<List<Category>>  getAllCategories() => RustLib.instance.api.crateApiGetAllCategories();
+
+/// Get a category by ID
+Future<Category?>  getCategoryById({required int id }) => RustLib.instance.api.crateApiGetCategoryById(id: id);
+
+/// Create a category (top-level when `parent_id` is empty)
+Future<Category>  createCategory({required CreateCategoryRequest request }) => RustLib.instance.api.crateApiCreateCategory(request: request);
+
+/// Update a category's code, names, order or active flag
+Future<Category>  updateCategory({required int id , required UpdateCategoryRequest request }) => RustLib.instance.api.crateApiUpdateCategory(id: id, request: request);
+
+/// Move a category under another parent (None = top level)
+Future<Category>  moveCategory({required int id , int? parentId }) => RustLib.instance.api.crateApiMoveCategory(id: id, parentId: parentId);
+
+/// Delete an unused category without subcategories
+Future<bool>  deleteCategory({required int id }) => RustLib.instance.api.crateApiDeleteCategory(id: id);
 
 /// Calculate order totals including profit
-Future<OrderTotals> calculateOrderTotals({required int orderId}) =>
-    RustLib.instance.api.crateApiCalculateOrderTotals(orderId: orderId);
-
-/// Calculate profit for a single item
-Future<ItemProfit> calculateItemProfit(
-        {required double buyingPrice,
-        required double sellingPrice,
-        required double quantity}) =>
-    RustLib.instance.api.crateApiCalculateItemProfit(
-        buyingPrice: buyingPrice,
-        sellingPrice: sellingPrice,
-        quantity: quantity);
+Future<OrderTotals>  calculateOrderTotals({required int orderId }) => RustLib.instance.api.crateApiCalculateOrderTotals(orderId: orderId);
+
+/// Calculate profit for a single item (flagged when below `min_margin_percent`)
+Future<ItemProfit>  calculateItemProfit({required double buyingPrice , required double sellingPrice , required double quantity , required double landedCostPerUnit , double? minMarginPercent }) => RustLib.instance.api.crateApiCalculateItemProfit(buyingPrice: buyingPrice, sellingPrice: sellingPrice, quantity: quantity, landedCostPerUnit: landedCostPerUnit, minMarginPercent: minMarginPercent);
+
+/// Profit of an order line including landed cost, checked against the margin floor of the pricing rules
+Future<ItemProfit>  calculateOrderItemProfit({required int orderItemId }) => RustLib.instance.api.crateApiCalculateOrderItemProfit(orderItemId: orderItemId);
 
 /// Get all suppliers
-Future<List<Supplier>> getAllSuppliers() =>
-    RustLib.instance.api.crateApiGetAllSuppliers();
+Future<List<Supplier>>  getAllSuppliers() => RustLib.instance.api.crateApiGetAllSuppliers();
 
 /// Get a single supplier by ID
-Future<Supplier?> getSupplierById({required int id}) =>
-    RustLib.instance.api.crateApiGetSupplierById(id: id);
+Future<Supplier?>  getSupplierById({required int id }) => RustLib.instance.api.crateApiGetSupplierById(id: id);
 
 /// Create a new supplier
-Future<Supplier> createSupplier({required CreateSupplierRequest supplier}) =>
-    RustLib.instance.api.crateApiCreateSupplier(supplier: supplier);
+Future<Supplier>  createSupplier({required CreateSupplierRequest supplier }) => RustLib.instance.api.crateApiCreateSupplier(supplier: supplier);
 
 /// Update an existing supplier
-Future<Supplier> updateSupplier(
-        {required int id, required UpdateSupplierRequest supplier}) =>
-    RustLib.instance.api.crateApiUpdateSupplier(id: id, supplier: supplier);
+Future<Supplier>  updateSupplier({required int id , required UpdateSupplierRequest supplier }) => RustLib.instance.api.crateApiUpdateSupplier(id: id, supplier: supplier);
 
 /// Delete a supplier
-Future<bool> deleteSupplier({required int id}) =>
-    RustLib.instance.api.crateApiDeleteSupplier(id: id);
+Future<bool>  deleteSupplier({required int id }) => RustLib.instance.api.crateApiDeleteSupplier(id: id);
 
 /// Search suppliers by name, category, or country
-Future<List<Supplier>> searchSuppliers({required String query}) =>
-    RustLib.instance.api.crateApiSearchSuppliers(query: query);
+Future<List<Supplier>>  searchSuppliers({required String query }) => RustLib.instance.api.crateApiSearchSuppliers(query: query);
 
-/// Get suppliers by category
-Future<List<Supplier>> getSuppliersByCategory({required String category}) =>
-    RustLib.instance.api.crateApiGetSuppliersByCategory(category: category);
+/// Get suppliers in a category or any of its subcategories
+Future<List<Supplier>>  getSuppliersByCategory({required int categoryId }) => RustLib.instance.api.crateApiGetSuppliersByCategory(categoryId: categoryId);
 
 /// Get total supplier count
-Future<PlatformInt64> getSupplierCount() =>
-    RustLib.instance.api.crateApiGetSupplierCount();
+Future<PlatformInt64>  getSupplierCount() => RustLib.instance.api.crateApiGetSupplierCount();
 
 /// Get all supply items
-Future<List<SupplyItem>> getAllSupplyItems() =>
-    RustLib.instance.api.crateApiGetAllSupplyItems();
+Future<List<SupplyItem>>  getAllSupplyItems() => RustLib.instance.api.crateApiGetAllSupplyItems();
 
 /// Get a single supply item by ID
-Future<SupplyItem?> getSupplyItemById({required int id}) =>
-    RustLib.instance.api.crateApiGetSupplyItemById(id: id);
+Future<SupplyItem?>  getSupplyItemById({required int id }) => RustLib.instance.api.crateApiGetSupplyItemById(id: id);
 
 /// Get supply items by supplier
-Future<List<SupplyItem>> getSupplyItemsBySupplier({required int supplierId}) =>
-    RustLib.instance.api
-        .crateApiGetSupplyItemsBySupplier(supplierId: supplierId);
+Future<List<SupplyItem>>  getSupplyItemsBySupplier({required int supplierId }) => RustLib.instance.api.crateApiGetSupplyItemsBySupplier(supplierId: supplierId);
 
-/// Get supply items by category
-Future<List<SupplyItem>> getSupplyItemsByCategory({required String category}) =>
-    RustLib.instance.api.crateApiGetSupplyItemsByCategory(category: category);
+/// Get supply items in a category or any of its subcategories
+Future<List<SupplyItem>>  getSupplyItemsByCategory({required int categoryId }) => RustLib.instance.api.crateApiGetSupplyItemsByCategory(categoryId: categoryId);
 
 /// Create a new supply item
-Future<SupplyItem> createSupplyItem({required CreateSupplyItemRequest item}) =>
-    RustLib.instance.api.crateApiCreateSupplyItem(item: item);
+Future<SupplyItem>  createSupplyItem({required CreateSupplyItemRequest item }) => RustLib.instance.api.crateApiCreateSupplyItem(item: item);
 
 /// Update an existing supply item
-Future<SupplyItem> updateSupplyItem(
-        {required int id, required UpdateSupplyItemRequest item}) =>
-    RustLib.instance.api.crateApiUpdateSupplyItem(id: id, item: item);
+Future<SupplyItem>  updateSupplyItem({required int id , required UpdateSupplyItemRequest item }) => RustLib.instance.api.crateApiUpdateSupplyItem(id: id, item: item);
 
 /// Delete a supply item
-Future<bool> deleteSupplyItem({required int id}) =>
-    RustLib.instance.api.crateApiDeleteSupplyItem(id: id);
+Future<bool>  deleteSupplyItem({required int id }) => RustLib.instance.api.crateApiDeleteSupplyItem(id: id);
 
 /// Search supply items by name, IMPA code, or description
-Future<List<SupplyItem>> searchSupplyItems({required String query}) =>
-    RustLib.instance.api.crateApiSearchSupplyItems(query: query);
+Future<List<SupplyItem>>  searchSupplyItems({required String query }) => RustLib.instance.api.crateApiSearchSupplyItems(query: query);
 
 /// Get total supply item count
-Future<PlatformInt64> getSupplyItemCount() =>
-    RustLib.instance.api.crateApiGetSupplyItemCount();
+Future<PlatformInt64>  getSupplyItemCount() => RustLib.instance.api.crateApiGetSupplyItemCount();
+
+/// Price changes of a supply item, newest first
+Future<List<PriceChange>>  getPriceHistory({required int supplyItemId }) => RustLib.instance.api.crateApiGetPriceHistory(supplyItemId: supplyItemId);
+
+/// Supply items whose price rose more than `min_rise_percent` between two dates (YYYY-MM-DD)
+Future<List<PriceRiseItem>>  getPriceRiseReport({required String startDate , required String endDate , required double minRisePercent , int? supplierId }) => RustLib.instance.api.crateApiGetPriceRiseReport(startDate: startDate, endDate: endDate, minRisePercent: minRisePercent, supplierId: supplierId);
+
+/// Get all equivalence groups with their supply items
+Future<List<EquivalenceGroup>>  getAllEquivalenceGroups() => RustLib.instance.api.crateApiGetAllEquivalenceGroups();
+
+/// Get an equivalence group by ID
+Future<EquivalenceGroup?>  getEquivalenceGroup({required int id }) => RustLib.instance.api.crateApiGetEquivalenceGroup(id: id);
+
+/// Link supply items as interchangeable
+Future<EquivalenceGroup>  createEquivalenceGroup({required CreateEquivalenceGroupRequest request }) => RustLib.instance.api.crateApiCreateEquivalenceGroup(request: request);
+
+/// Add a supply item to an equivalence group
+Future<EquivalenceGroup>  addToEquivalenceGroup({required int groupId , required int supplyItemId }) => RustLib.instance.api.crateApiAddToEquivalenceGroup(groupId: groupId, supplyItemId: supplyItemId);
+
+/// Take a supply item out of its equivalence group
+Future<bool>  removeFromEquivalenceGroup({required int supplyItemId }) => RustLib.instance.api.crateApiRemoveFromEquivalenceGroup(supplyItemId: supplyItemId);
+
+/// Delete an equivalence group (supply items are kept)
+Future<bool>  deleteEquivalenceGroup({required int id }) => RustLib.instance.api.crateApiDeleteEquivalenceGroup(id: id);
+
+/// Equivalent supply items of other suppliers with price, availability, stock and lead time
+Future<List<ProductAlternative>>  getAlternatives({required int supplyItemId }) => RustLib.instance.api.crateApiGetAlternatives(supplyItemId: supplyItemId);
+
+/// Suggest the cheapest in-stock equivalent for an order line (by supply item or IMPA code)
+Future<ProductAlternative?>  suggestOrderItemSource({required int orderId , int? supplyItemId , String? impaCode , required double quantity }) => RustLib.instance.api.crateApiSuggestOrderItemSource(orderId: orderId, supplyItemId: supplyItemId, impaCode: impaCode, quantity: quantity);
 
 /// Get all stock items
-Future<List<Stock>> getAllStock() => RustLib.instance.api.crateApiGetAllStock();
+Future<List<Stock>>  getAllStock() => RustLib.instance.api.crateApiGetAllStock();
 
 /// Get stock items with low quantity (below minimum)
-Future<List<Stock>> getLowStock() => RustLib.instance.api.crateApiGetLowStock();
+Future<List<Stock>>  getLowStock() => RustLib.instance.api.crateApiGetLowStock();
 
 /// Get a single stock item by ID
-Future<Stock?> getStockById({required int id}) =>
-    RustLib.instance.api.crateApiGetStockById(id: id);
+Future<Stock?>  getStockById({required int id }) => RustLib.instance.api.crateApiGetStockById(id: id);
 
 /// Get stock by supply item ID
-Future<Stock?> getStockBySupplyItem({required int supplyItemId}) =>
-    RustLib.instance.api
-        .crateApiGetStockBySupplyItem(supplyItemId: supplyItemId);
+Future<Stock?>  getStockBySupplyItem({required int supplyItemId }) => RustLib.instance.api.crateApiGetStockBySupplyItem(supplyItemId: supplyItemId);
 
 /// Create a new stock entry
-Future<Stock> createStock({required CreateStockRequest stock}) =>
-    RustLib.instance.api.crateApiCreateStock(stock: stock);
+Future<Stock>  createStock({required CreateStockRequest stock }) => RustLib.instance.api.crateApiCreateStock(stock: stock);
 
 /// Update stock entry
-Future<Stock> updateStock(
-        {required int id, required UpdateStockRequest stock}) =>
-    RustLib.instance.api.crateApiUpdateStock(id: id, stock: stock);
+Future<Stock>  updateStock({required int id , required UpdateStockRequest stock }) => RustLib.instance.api.crateApiUpdateStock(id: id, stock: stock);
 
 /// Delete stock entry
-Future<bool> deleteStock({required int id}) =>
-    RustLib.instance.api.crateApiDeleteStock(id: id);
+Future<bool>  deleteStock({required int id }) => RustLib.instance.api.crateApiDeleteStock(id: id);
 
 /// Get stock movements for a stock item
-Future<List<StockMovement>> getStockMovements({required int stockId}) =>
-    RustLib.instance.api.crateApiGetStockMovements(stockId: stockId);
+Future<List<StockMovement>>  getStockMovements({required int stockId }) => RustLib.instance.api.crateApiGetStockMovements(stockId: stockId);
 
 /// Get recent stock movements (all items)
-Future<List<StockMovement>> getRecentStockMovements({required int limit}) =>
-    RustLib.instance.api.crateApiGetRecentStockMovements(limit: limit);
+Future<List<StockMovement>>  getRecentStockMovements({required int limit }) => RustLib.instance.api.crateApiGetRecentStockMovements(limit: limit);
 
 /// Create stock movement (updates stock quantity automatically)
-Future<StockMovement> createStockMovement(
-        {required CreateStockMovementRequest movement}) =>
-    RustLib.instance.api.crateApiCreateStockMovement(movement: movement);
+Future<StockMovement>  createStockMovement({required CreateStockMovementRequest movement }) => RustLib.instance.api.crateApiCreateStockMovement(movement: movement);
+
+/// Issue stock for an order line, converting the line's unit to the stock unit.
+/// Bonded stock needs the customs declaration number.
+Future<StockMovement>  issueStockToOrder({required int orderItemId , int? stockId , double? quantity , String? customsDeclarationNumber }) => RustLib.instance.api.crateApiIssueStockToOrder(orderItemId: orderItemId, stockId: stockId, quantity: quantity, customsDeclarationNumber: customsDeclarationNumber);
 
 /// Get stock with all movements
-Future<StockWithMovements?> getStockWithMovements({required int id}) =>
-    RustLib.instance.api.crateApiGetStockWithMovements(id: id);
+Future<StockWithMovements?>  getStockWithMovements({required int id }) => RustLib.instance.api.crateApiGetStockWithMovements(id: id);
 
 /// Get stock summary for dashboard
-Future<StockSummary> getStockSummary() =>
-    RustLib.instance.api.crateApiGetStockSummary();
+Future<StockSummary>  getStockSummary() => RustLib.instance.api.crateApiGetStockSummary();
+
+/// Whether bonded goods may be supplied for an order (foreign-flag ship at an international port)
+Future<BondedEligibility>  getBondedEligibility({required int orderId }) => RustLib.instance.api.crateApiGetBondedEligibility(orderId: orderId);
+
+/// Bonded stock reconciliation for customs over a period (YYYY-MM-DD, inclusive)
+Future<BondedReconciliation>  getBondedReconciliation({required String periodStart , required String periodEnd }) => RustLib.instance.api.crateApiGetBondedReconciliation(periodStart: periodStart, periodEnd: periodEnd);
+
+/// Get all units of measure with their aliases
+Future<List<UnitOfMeasure>>  getAllUnits() => RustLib.instance.api.crateApiGetAllUnits();
+
+/// Add a unit of measure
+Future<UnitOfMeasure>  createUnit({required CreateUnitRequest request }) => RustLib.instance.api.crateApiCreateUnit(request: request);
+
+/// Update a unit of measure (names, factor, aliases, active flag)
+Future<UnitOfMeasure>  updateUnit({required int id , required UpdateUnitRequest request }) => RustLib.instance.api.crateApiUpdateUnit(id: id, request: request);
+
+/// Get the pack sizes of a supply item
+Future<List<PackSize>>  getPackSizes({required int supplyItemId }) => RustLib.instance.api.crateApiGetPackSizes(supplyItemId: supplyItemId);
+
+/// Set how much one pack of a supply item holds, e.g. 1 KOLI = 24 ADET
+Future<PackSize>  setPackSize({required int supplyItemId , required String packUnit , required double quantity , required String contentUnit }) => RustLib.instance.api.crateApiSetPackSize(supplyItemId: supplyItemId, packUnit: packUnit, quantity: quantity, contentUnit: contentUnit);
+
+/// Delete a pack size
+Future<bool>  deletePackSize({required int id }) => RustLib.instance.api.crateApiDeletePackSize(id: id);
+
+/// Convert a quantity between units (with a supply item's pack sizes when given)
+Future<double>  convertQuantity({required double quantity , required String fromUnit , required String toUnit , int? supplyItemId }) => RustLib.instance.api.crateApiConvertQuantity(quantity: quantity, fromUnit: fromUnit, toUnit: toUnit, supplyItemId: supplyItemId);
 
 /// Get all ports
-Future<List<Port>> getAllPorts() => RustLib.instance.api.crateApiGetAllPorts();
+Future<List<Port>>  getAllPorts() => RustLib.instance.api.crateApiGetAllPorts();
 
 /// Get active ports only
-Future<List<Port>> getActivePorts() =>
-    RustLib.instance.api.crateApiGetActivePorts();
+Future<List<Port>>  getActivePorts() => RustLib.instance.api.crateApiGetActivePorts();
 
 /// Get a single port by ID
-Future<Port?> getPortById({required int id}) =>
-    RustLib.instance.api.crateApiGetPortById(id: id);
+Future<Port?>  getPortById({required int id }) => RustLib.instance.api.crateApiGetPortById(id: id);
 
 /// Create a new port
-Future<Port> createPort({required CreatePortRequest port}) =>
-    RustLib.instance.api.crateApiCreatePort(port: port);
+Future<Port>  createPort({required CreatePortRequest port }) => RustLib.instance.api.crateApiCreatePort(port: port);
 
 /// Update an existing port
-Future<Port?> updatePort({required int id, required UpdatePortRequest port}) =>
-    RustLib.instance.api.crateApiUpdatePort(id: id, port: port);
+Future<Port?>  updatePort({required int id , required UpdatePortRequest port }) => RustLib.instance.api.crateApiUpdatePort(id: id, port: port);
 
 /// Delete a port
-Future<bool> deletePort({required int id}) =>
-    RustLib.instance.api.crateApiDeletePort(id: id);
+Future<bool>  deletePort({required int id }) => RustLib.instance.api.crateApiDeletePort(id: id);
 
 /// Get ports by country
-Future<List<Port>> getPortsByCountry({required String country}) =>
-    RustLib.instance.api.crateApiGetPortsByCountry(country: country);
+Future<List<Port>>  getPortsByCountry({required String country }) => RustLib.instance.api.crateApiGetPortsByCountry(country: country);
 
 /// Get all ship visits
-Future<List<ShipVisit>> getAllShipVisits() =>
-    RustLib.instance.api.crateApiGetAllShipVisits();
+Future<List<ShipVisit>>  getAllShipVisits() => RustLib.instance.api.crateApiGetAllShipVisits();
 
 /// Get upcoming ship visits (ETA >= today)
-Future<List<ShipVisit>> getUpcomingShipVisits() =>
-    RustLib.instance.api.crateApiGetUpcomingShipVisits();
+Future<List<ShipVisit>>  getUpcomingShipVisits() => RustLib.instance.api.crateApiGetUpcomingShipVisits();
 
 /// Get ship visits by port
-Future<List<ShipVisit>> getShipVisitsByPort({required int portId}) =>
-    RustLib.instance.api.crateApiGetShipVisitsByPort(portId: portId);
+Future<List<ShipVisit>>  getShipVisitsByPort({required int portId }) => RustLib.instance.api.crateApiGetShipVisitsByPort(portId: portId);
 
 /// Get ship visits by ship
-Future<List<ShipVisit>> getShipVisitsByShip({required int shipId}) =>
-    RustLib.instance.api.crateApiGetShipVisitsByShip(shipId: shipId);
+Future<List<ShipVisit>>  getShipVisitsByShip({required int shipId }) => RustLib.instance.api.crateApiGetShipVisitsByShip(shipId: shipId);
 
 /// Get a single ship visit by ID
-Future<ShipVisit?> getShipVisitById({required int id}) =>
-    RustLib.instance.api.crateApiGetShipVisitById(id: id);
+Future<ShipVisit?>  getShipVisitById({required int id }) => RustLib.instance.api.crateApiGetShipVisitById(id: id);
 
 /// Create a new ship visit
-Future<ShipVisit> createShipVisit({required CreateShipVisitRequest visit}) =>
-    RustLib.instance.api.crateApiCreateShipVisit(visit: visit);
+Future<ShipVisit>  createShipVisit({required CreateShipVisitRequest visit }) => RustLib.instance.api.crateApiCreateShipVisit(visit: visit);
 
 /// Update an existing ship visit
-Future<ShipVisit?> updateShipVisit(
-        {required int id, required UpdateShipVisitRequest visit}) =>
-    RustLib.instance.api.crateApiUpdateShipVisit(id: id, visit: visit);
+Future<ShipVisit?>  updateShipVisit({required int id , required UpdateShipVisitRequest visit }) => RustLib.instance.api.crateApiUpdateShipVisit(id: id, visit: visit);
 
 /// Update ship visit status
-Future<ShipVisit?> updateShipVisitStatus(
-        {required int id, required VisitStatus status}) =>
-    RustLib.instance.api.crateApiUpdateShipVisitStatus(id: id, status: status);
+Future<ShipVisit?>  updateShipVisitStatus({required int id , required VisitStatus status }) => RustLib.instance.api.crateApiUpdateShipVisitStatus(id: id, status: status);
 
 /// Delete a ship visit
-Future<bool> deleteShipVisit({required int id}) =>
-    RustLib.instance.api.crateApiDeleteShipVisit(id: id);
+Future<bool>  deleteShipVisit({required int id }) => RustLib.instance.api.crateApiDeleteShipVisit(id: id);
 
 /// Get ship visits within a date range
-Future<List<ShipVisit>> getShipVisitsByDateRange(
-        {required String startDate, required String endDate}) =>
-    RustLib.instance.api.crateApiGetShipVisitsByDateRange(
-        startDate: startDate, endDate: endDate);
+Future<List<ShipVisit>>  getShipVisitsByDateRange({required String startDate , required String endDate }) => RustLib.instance.api.crateApiGetShipVisitsByDateRange(startDate: startDate, endDate: endDate);
 
 /// Get calendar data for a date range (all events + ports)
-Future<CalendarData> getCalendarData(
-        {required String startDate, required String endDate}) =>
-    RustLib.instance.api
-        .crateApiGetCalendarData(startDate: startDate, endDate: endDate);
+Future<CalendarData>  getCalendarData({required String startDate , required String endDate }) => RustLib.instance.api.crateApiGetCalendarData(startDate: startDate, endDate: endDate);
 
 /// Get order totals (revenue, cost, profit)
-Future<OrderTotals> getOrderTotals({required int orderId}) =>
-    RustLib.instance.api.crateApiGetOrderTotals(orderId: orderId);
+Future<OrderTotals>  getOrderTotals({required int orderId }) => RustLib.instance.api.crateApiGetOrderTotals(orderId: orderId);
 
 /// Get profit summary for dashboard
-Future<ProfitSummary> getProfitSummary() =>
-    RustLib.instance.api.crateApiGetProfitSummary();
+Future<ProfitSummary>  getProfitSummary() => RustLib.instance.api.crateApiGetProfitSummary();
 
 /// Get top profitable orders
-Future<List<OrderProfitInfo>> getTopProfitableOrders({required int limit}) =>
-    RustLib.instance.api.crateApiGetTopProfitableOrders(limit: limit);
+Future<List<OrderProfitInfo>>  getTopProfitableOrders({required int limit }) => RustLib.instance.api.crateApiGetTopProfitableOrders(limit: limit);
+
+/// Get all invoices with optional status filter
+Future<List<Invoice>>  getAllInvoices({InvoiceStatus? statusFilter }) => RustLib.instance.api.crateApiGetAllInvoices(statusFilter: statusFilter);
+
+/// Get a single invoice by ID
+Future<Invoice?>  getInvoiceById({required int id }) => RustLib.instance.api.crateApiGetInvoiceById(id: id);
+
+/// Get invoices for an order
+Future<List<Invoice>>  getInvoicesByOrder({required int orderId }) => RustLib.instance.api.crateApiGetInvoicesByOrder(orderId: orderId);
+
+/// Get invoices billed to a customer (ship owner)
+Future<List<Invoice>>  getInvoicesByCustomer({required int customerId }) => RustLib.instance.api.crateApiGetInvoicesByCustomer(customerId: customerId);
+
+/// Create an invoice for an order (billed to the ship owner)
+Future<Invoice>  createInvoice({required CreateInvoiceRequest invoice }) => RustLib.instance.api.crateApiCreateInvoice(invoice: invoice);
+
+/// Cancel an invoice (only if no payments are applied)
+Future<Invoice>  cancelInvoice({required int id }) => RustLib.instance.api.crateApiCancelInvoice(id: id);
+
+/// Get all payments
+Future<List<Payment>>  getAllPayments() => RustLib.instance.api.crateApiGetAllPayments();
+
+/// Get payments received from a customer
+Future<List<Payment>>  getPaymentsByCustomer({required int customerId }) => RustLib.instance.api.crateApiGetPaymentsByCustomer(customerId: customerId);
+
+/// Get a single payment with its allocations
+Future<Payment?>  getPaymentById({required int id }) => RustLib.instance.api.crateApiGetPaymentById(id: id);
+
+/// Record a payment and apply it to invoices (partial, multi-currency, overpayment credit)
+Future<Payment>  recordPayment({required CreatePaymentRequest payment }) => RustLib.instance.api.crateApiRecordPayment(payment: payment);
+
+/// Apply the unallocated credit of a payment to invoices
+Future<Payment>  allocatePayment({required int paymentId , required List<PaymentAllocationRequest> allocations }) => RustLib.instance.api.crateApiAllocatePayment(paymentId: paymentId, allocations: allocations);
+
+/// Delete a payment (re-opens the invoices it settled)
+Future<bool>  deletePayment({required int id }) => RustLib.instance.api.crateApiDeletePayment(id: id);
+
+/// Get a customer's account balance per currency
+Future<List<CustomerBalance>>  getCustomerBalances({required int customerId }) => RustLib.instance.api.crateApiGetCustomerBalances(customerId: customerId);
+
+/// Receivables aging (0-30 / 31-60 / 61-90 / 90+ days past due) per owner
+Future<List<ReceivablesAgingRow>>  getReceivablesAging({required String asOf }) => RustLib.instance.api.crateApiGetReceivablesAging(asOf: asOf);
+
+/// Get all supplier invoices with optional status filter
+Future<List<SupplierInvoice>>  getAllSupplierInvoices({InvoiceStatus? statusFilter }) => RustLib.instance.api.crateApiGetAllSupplierInvoices(statusFilter: statusFilter);
+
+/// Get a single supplier invoice with its lines
+Future<SupplierInvoice?>  getSupplierInvoiceById({required int id }) => RustLib.instance.api.crateApiGetSupplierInvoiceById(id: id);
+
+/// Get invoices received from a supplier
+Future<List<SupplierInvoice>>  getSupplierInvoicesBySupplier({required int supplierId }) => RustLib.instance.api.crateApiGetSupplierInvoicesBySupplier(supplierId: supplierId);
+
+/// Get supplier invoices billed against an order
+Future<List<SupplierInvoice>>  getSupplierInvoicesByOrder({required int orderId }) => RustLib.instance.api.crateApiGetSupplierInvoicesByOrder(orderId: orderId);
+
+/// Record a supplier invoice with its lines
+Future<SupplierInvoice>  createSupplierInvoice({required CreateSupplierInvoiceRequest invoice }) => RustLib.instance.api.crateApiCreateSupplierInvoice(invoice: invoice);
+
+/// Cancel a supplier invoice (only if nothing has been paid)
+Future<SupplierInvoice>  cancelSupplierInvoice({required int id }) => RustLib.instance.api.crateApiCancelSupplierInvoice(id: id);
+
+/// 3-way match of a supplier invoice against the order and goods receipts
+Future<SupplierInvoiceMatch>  matchSupplierInvoice({required int id }) => RustLib.instance.api.crateApiMatchSupplierInvoice(id: id);
+
+/// Approve a supplier invoice for payment (updates order item costs to billed prices)
+Future<SupplierInvoice>  approveSupplierInvoice({required int id , required bool acceptVariances }) => RustLib.instance.api.crateApiApproveSupplierInvoice(id: id, acceptVariances: acceptVariances);
+
+/// Get payments made to a supplier
+Future<List<SupplierPayment>>  getSupplierPaymentsBySupplier({required int supplierId }) => RustLib.instance.api.crateApiGetSupplierPaymentsBySupplier(supplierId: supplierId);
+
+/// Get payments made against a supplier invoice
+Future<List<SupplierPayment>>  getSupplierPaymentsByInvoice({required int supplierInvoiceId }) => RustLib.instance.api.crateApiGetSupplierPaymentsByInvoice(supplierInvoiceId: supplierInvoiceId);
+
+/// Pay (part of) an approved supplier invoice
+Future<SupplierPayment>  recordSupplierPayment({required CreateSupplierPaymentRequest payment }) => RustLib.instance.api.crateApiRecordSupplierPayment(payment: payment);
+
+/// Delete a supplier payment
+Future<bool>  deleteSupplierPayment({required int id }) => RustLib.instance.api.crateApiDeleteSupplierPayment(id: id);
+
+/// Open supplier invoices due within the next `days` days (overdue included)
+Future<List<PayableDue>>  getPayablesDue({required int days }) => RustLib.instance.api.crateApiGetPayablesDue(days: days);
 
 /// Initialize the database connection with custom URL
-Future<void> initDatabase({required String databaseUrl}) =>
-    RustLib.instance.api.crateApiInitDatabase(databaseUrl: databaseUrl);
+Future<void>  initDatabase({required String databaseUrl }) => RustLib.instance.api.crateApiInitDatabase(databaseUrl: databaseUrl);
 
 /// Initialize SQLite database with default local path
-Future<String> initLocalDatabase() =>
-    RustLib.instance.api.crateApiInitLocalDatabase();
+Future<String>  initLocalDatabase() => RustLib.instance.api.crateApiInitLocalDatabase();
 
 /// Check if database is connected
-Future<bool> isDatabaseConnected() =>
-    RustLib.instance.api.crateApiIsDatabaseConnected();
+Future<bool>  isDatabaseConnected() => RustLib.instance.api.crateApiIsDatabaseConnected();
 
 /// Load demo/seed data for Egeport presentation
-Future<String> loadSeedData() => RustLib.instance.api.crateApiLoadSeedData();
+Future<String>  loadSeedData() => RustLib.instance.api.crateApiLoadSeedData();
 
 /// Simple greet function to test FRB integration
-Future<String> greet({required String name}) =>
-    RustLib.instance.api.crateApiGreet(name: name);
+Future<String>  greet({required String name }) => RustLib.instance.api.crateApiGreet(name: name);
 
 /// Get current version info
-Future<String> getVersion() => RustLib.instance.api.crateApiGetVersion();
+Future<String>  getVersion() => RustLib.instance.api.crateApiGetVersion();
+
+            
+            
\ No newline at end of file
@@ -7,5587 +7,14262 @@ import 'api.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
 
-  RustLib._();
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
+                  RustLib._();
 
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  @override
-  Future<void> executeRustInitializers() async {}
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
 
-  @override
-  String get codegenVersion => '2.11.1';
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  @override
-  int get rustContentHash => 1380362590;
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'ssms_core',
-    ioDirectory: '../backend/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
+                  @override
+                  int get rustContentHash => -463526686;
 
-abstract class RustLibApi extends BaseApi {
-  Future<OrderItem> crateApiAddOrderItem(
-      {required CreateOrderItemRequest item});
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'ssms_core',
+                    ioDirectory: '../backend/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<ItemProfit> crateApiCalculateItemProfit(
-      {required double buyingPrice,
-      required double sellingPrice,
-      required double quantity});
+                abstract class RustLibApi extends BaseApi {
+                  Future<Attachment> crateApiAddAttachment({required CreateAttachmentRequest request });
 
-  Future<OrderTotals> crateApiCalculateOrderTotals({required int orderId});
+Future<Customer> crateApiAddCustomerContact({required int customerId , required CreateCustomerContactRequest contact });
 
-  Future<Order> crateApiCreateOrder({required CreateOrderRequest order});
+Future<DeliveryNoteAttachment> crateApiAddDeliveryNoteAttachment({required int deliveryNoteId , required DeliveryNoteAttachmentKind kind , required String fileName , required String mimeType , required List<int> data });
 
-  Future<Port> crateApiCreatePort({required CreatePortRequest port});
+Future<OrderCharge> crateApiAddOrderCharge({required CreateOrderChargeRequest charge });
 
-  Future<Ship> crateApiCreateShip({required CreateShipRequest ship});
+Future<OrderWithItems> crateApiAddOrderItem({required CreateOrderItemRequest item });
 
-  Future<ShipVisit> crateApiCreateShipVisit(
-      {required CreateShipVisitRequest visit});
+Future<PriceAgreement> crateApiAddPriceAgreementLine({required int agreementId , required CreatePriceAgreementLineRequest line });
 
-  Future<Stock> crateApiCreateStock({required CreateStockRequest stock});
+Future<EquivalenceGroup> crateApiAddToEquivalenceGroup({required int groupId , required int supplyItemId });
 
-  Future<StockMovement> crateApiCreateStockMovement(
-      {required CreateStockMovementRequest movement});
+Future<Payment> crateApiAllocatePayment({required int paymentId , required List<PaymentAllocationRequest> allocations });
 
-  Future<Supplier> crateApiCreateSupplier(
-      {required CreateSupplierRequest supplier});
+Future<SupplierInvoice> crateApiApproveSupplierInvoice({required int id , required bool acceptVariances });
 
-  Future<SupplyItem> crateApiCreateSupplyItem(
-      {required CreateSupplyItemRequest item});
+Future<ItemProfit> crateApiCalculateItemProfit({required double buyingPrice , required double sellingPrice , required double quantity , required double landedCostPerUnit , double? minMarginPercent });
 
-  Future<bool> crateApiDeleteOrder({required int id});
+Future<ItemProfit> crateApiCalculateOrderItemProfit({required int orderItemId });
 
-  Future<bool> crateApiDeleteOrderItem({required int id});
+Future<OrderTotals> crateApiCalculateOrderTotals({required int orderId });
 
-  Future<bool> crateApiDeletePort({required int id});
+Future<Invoice> crateApiCancelInvoice({required int id });
 
-  Future<bool> crateApiDeleteShip({required int id});
+Future<SupplierInvoice> crateApiCancelSupplierInvoice({required int id });
 
-  Future<bool> crateApiDeleteShipVisit({required int id});
+Future<CreditCheck?> crateApiCheckOrderCreditLimit({required int orderId });
 
-  Future<bool> crateApiDeleteStock({required int id});
+Future<OrderWithItems> crateApiCloneOrder({required int orderId , required int targetShipVisitId , required bool refreshPrices });
 
-  Future<bool> crateApiDeleteSupplier({required int id});
+Future<OrderItem> crateApiCloseOrderItem({required int orderItemId , String? reason });
 
-  Future<bool> crateApiDeleteSupplyItem({required int id});
+Future<OrderWithItems> crateApiCommitRequisitionImport({required int orderId , required List<RequisitionImportLine> lines });
 
-  Future<List<Port>> crateApiGetActivePorts();
+Future<DeliveryNote> crateApiCompleteDeliveryNote({required int id , required CompleteDeliveryNoteRequest completion });
 
-  Future<List<Order>> crateApiGetAllOrders({OrderStatus? statusFilter});
+Future<double> crateApiConvertQuantity({required double quantity , required String fromUnit , required String toUnit , int? supplyItemId });
 
-  Future<List<Port>> crateApiGetAllPorts();
+Future<Category> crateApiCreateCategory({required CreateCategoryRequest request });
 
-  Future<List<ShipVisit>> crateApiGetAllShipVisits();
+Future<Customer> crateApiCreateCustomer({required CreateCustomerRequest customer });
 
-  Future<List<Ship>> crateApiGetAllShips();
+Future<DeliveryNote> crateApiCreateDeliveryNote({required CreateDeliveryNoteRequest note });
 
-  Future<List<Stock>> crateApiGetAllStock();
+Future<EquivalenceGroup> crateApiCreateEquivalenceGroup({required CreateEquivalenceGroupRequest request });
 
-  Future<List<Supplier>> crateApiGetAllSuppliers();
+Future<Invoice> crateApiCreateInvoice({required CreateInvoiceRequest invoice });
 
-  Future<List<SupplyItem>> crateApiGetAllSupplyItems();
+Future<LandedCost> crateApiCreateLandedCost({required CreateLandedCostRequest landedCost });
 
-  Future<CalendarData> crateApiGetCalendarData(
-      {required String startDate, required String endDate});
+Future<OrderWithItems> crateApiCreateOrder({required CreateOrderRequest order });
 
-  Future<List<Stock>> crateApiGetLowStock();
+Future<OrderReturn> crateApiCreateOrderReturn({required CreateReturnRequest orderReturn });
 
-  Future<List<OrderItem>> crateApiGetOrderItems({required int orderId});
+Future<Port> crateApiCreatePort({required CreatePortRequest port });
 
-  Future<OrderTotals> crateApiGetOrderTotals({required int orderId});
+Future<PriceAgreement> crateApiCreatePriceAgreement({required CreatePriceAgreementRequest agreement });
 
-  Future<OrderWithItems?> crateApiGetOrderWithItems({required int id});
+Future<PricingRule> crateApiCreatePricingRule({required CreatePricingRuleRequest rule });
 
-  Future<List<Order>> crateApiGetOrdersByShipVisit({required int shipVisitId});
+Future<RequisitionTemplate> crateApiCreateRequisitionTemplate({required CreateRequisitionTemplateRequest template });
 
-  Future<Port?> crateApiGetPortById({required int id});
+Future<RequisitionTemplate> crateApiCreateRequisitionTemplateFromOrder({required int orderId , required String name , required bool forShipType , double? baseHeadcount });
 
-  Future<List<Port>> crateApiGetPortsByCountry({required String country});
+Future<Ship> crateApiCreateShip({required CreateShipRequest ship });
 
-  Future<ProfitSummary> crateApiGetProfitSummary();
+Future<ShipVisit> crateApiCreateShipVisit({required CreateShipVisitRequest visit });
 
-  Future<List<StockMovement>> crateApiGetRecentStockMovements(
-      {required int limit});
+Future<Stock> crateApiCreateStock({required CreateStockRequest stock });
 
-  Future<Ship?> crateApiGetShipById({required int id});
+Future<StockMovement> crateApiCreateStockMovement({required CreateStockMovementRequest movement });
 
-  Future<PlatformInt64> crateApiGetShipCount();
+Future<Supplier> crateApiCreateSupplier({required CreateSupplierRequest supplier });
 
-  Future<ShipVisit?> crateApiGetShipVisitById({required int id});
+Future<SupplierInvoice> crateApiCreateSupplierInvoice({required CreateSupplierInvoiceRequest invoice });
 
-  Future<List<ShipVisit>> crateApiGetShipVisitsByDateRange(
-      {required String startDate, required String endDate});
+Future<SupplyItem> crateApiCreateSupplyItem({required CreateSupplyItemRequest item });
 
-  Future<List<ShipVisit>> crateApiGetShipVisitsByPort({required int portId});
+Future<UnitOfMeasure> crateApiCreateUnit({required CreateUnitRequest request });
 
-  Future<List<ShipVisit>> crateApiGetShipVisitsByShip({required int shipId});
+Future<bool> crateApiDeleteAttachment({required int id });
 
-  Future<Stock?> crateApiGetStockById({required int id});
+Future<bool> crateApiDeleteCategory({required int id });
 
-  Future<Stock?> crateApiGetStockBySupplyItem({required int supplyItemId});
+Future<bool> crateApiDeleteCustomer({required int id });
 
-  Future<List<StockMovement>> crateApiGetStockMovements({required int stockId});
+Future<bool> crateApiDeleteCustomerContact({required int contactId });
 
-  Future<StockSummary> crateApiGetStockSummary();
+Future<bool> crateApiDeleteDeliveryNote({required int id });
 
-  Future<StockWithMovements?> crateApiGetStockWithMovements({required int id});
+Future<bool> crateApiDeleteEquivalenceGroup({required int id });
 
-  Future<Supplier?> crateApiGetSupplierById({required int id});
+Future<bool> crateApiDeleteLandedCost({required int id });
 
-  Future<PlatformInt64> crateApiGetSupplierCount();
+Future<bool> crateApiDeleteOrder({required int id });
 
-  Future<List<Supplier>> crateApiGetSuppliersByCategory(
-      {required String category});
+Future<bool> crateApiDeleteOrderCharge({required int id });
 
-  Future<SupplyItem?> crateApiGetSupplyItemById({required int id});
+Future<bool> crateApiDeleteOrderDelivery({required int id });
 
-  Future<PlatformInt64> crateApiGetSupplyItemCount();
+Future<bool> crateApiDeleteOrderItem({required int id });
 
-  Future<List<SupplyItem>> crateApiGetSupplyItemsByCategory(
-      {required String category});
+Future<bool> crateApiDeletePackSize({required int id });
 
-  Future<List<SupplyItem>> crateApiGetSupplyItemsBySupplier(
-      {required int supplierId});
+Future<bool> crateApiDeletePayment({required int id });
 
-  Future<List<OrderProfitInfo>> crateApiGetTopProfitableOrders(
-      {required int limit});
+Future<bool> crateApiDeletePort({required int id });
 
-  Future<List<ShipVisit>> crateApiGetUpcomingShipVisits();
+Future<bool> crateApiDeletePriceAgreement({required int id });
 
-  Future<String> crateApiGetVersion();
+Future<bool> crateApiDeletePriceAgreementLine({required int lineId });
 
-  Future<String> crateApiGreet({required String name});
+Future<bool> crateApiDeletePricingRule({required int id });
 
-  Future<void> crateApiInitDatabase({required String databaseUrl});
+Future<bool> crateApiDeleteRequisitionTemplate({required int id });
 
-  Future<String> crateApiInitLocalDatabase();
+Future<bool> crateApiDeleteShip({required int id });
 
-  Future<bool> crateApiIsDatabaseConnected();
+Future<bool> crateApiDeleteShipVisit({required int id });
 
-  Future<String> crateApiLoadSeedData();
+Future<bool> crateApiDeleteStock({required int id });
 
-  Future<List<Ship>> crateApiSearchShips({required String query});
+Future<bool> crateApiDeleteSupplier({required int id });
 
-  Future<List<Supplier>> crateApiSearchSuppliers({required String query});
+Future<bool> crateApiDeleteSupplierPayment({required int id });
 
-  Future<List<SupplyItem>> crateApiSearchSupplyItems({required String query});
+Future<bool> crateApiDeleteSupplyItem({required int id });
 
-  Future<Order> crateApiUpdateOrder(
-      {required int id, required UpdateOrderRequest order});
+Future<ExportResult> crateApiExportBondedReconciliation({required String periodStart , required String periodEnd , required String outputPath });
 
-  Future<OrderItem> crateApiUpdateOrderItem(
-      {required int id, required UpdateOrderItemRequest item});
+Future<ExportResult> crateApiExportOrder({required int orderId , required String outputPath });
 
-  Future<Order> crateApiUpdateOrderStatus(
-      {required int id, required OrderStatus newStatus});
+Future<ExportResult> crateApiExportOrders({required OrderExportFilter filter , required String outputPath });
 
-  Future<Port?> crateApiUpdatePort(
-      {required int id, required UpdatePortRequest port});
+Future<ExportResult> crateApiExportProfitReport({required String outputPath });
 
-  Future<Ship> crateApiUpdateShip(
-      {required int id, required UpdateShipRequest ship});
+Future<ExportResult> crateApiExportStock({required String outputPath });
 
-  Future<ShipVisit?> crateApiUpdateShipVisit(
-      {required int id, required UpdateShipVisitRequest visit});
+Future<List<Port>> crateApiGetActivePorts();
 
-  Future<ShipVisit?> crateApiUpdateShipVisitStatus(
-      {required int id, required VisitStatus status});
+Future<List<Category>> crateApiGetAllCategories();
 
-  Future<Stock> crateApiUpdateStock(
-      {required int id, required UpdateStockRequest stock});
+Future<List<Customer>> crateApiGetAllCustomers();
 
-  Future<Supplier> crateApiUpdateSupplier(
-      {required int id, required UpdateSupplierRequest supplier});
+Future<List<EquivalenceGroup>> crateApiGetAllEquivalenceGroups();
 
-  Future<SupplyItem> crateApiUpdateSupplyItem(
-      {required int id, required UpdateSupplyItemRequest item});
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<OrderItem> crateApiAddOrderItem(
-      {required CreateOrderItemRequest item}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_order_item_request(item, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_order_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiAddOrderItemConstMeta,
-      argValues: [item],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAddOrderItemConstMeta => const TaskConstMeta(
-        debugName: "add_order_item",
-        argNames: ["item"],
-      );
-
-  @override
-  Future<ItemProfit> crateApiCalculateItemProfit(
-      {required double buyingPrice,
-      required double sellingPrice,
-      required double quantity}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_f_64(buyingPrice, serializer);
-        sse_encode_f_64(sellingPrice, serializer);
-        sse_encode_f_64(quantity, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_item_profit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiCalculateItemProfitConstMeta,
-      argValues: [buyingPrice, sellingPrice, quantity],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCalculateItemProfitConstMeta =>
-      const TaskConstMeta(
-        debugName: "calculate_item_profit",
-        argNames: ["buyingPrice", "sellingPrice", "quantity"],
-      );
-
-  @override
-  Future<OrderTotals> crateApiCalculateOrderTotals({required int orderId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(orderId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_order_totals,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCalculateOrderTotalsConstMeta,
-      argValues: [orderId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCalculateOrderTotalsConstMeta =>
-      const TaskConstMeta(
-        debugName: "calculate_order_totals",
-        argNames: ["orderId"],
-      );
-
-  @override
-  Future<Order> crateApiCreateOrder({required CreateOrderRequest order}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_order_request(order, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_order,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreateOrderConstMeta,
-      argValues: [order],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreateOrderConstMeta => const TaskConstMeta(
-        debugName: "create_order",
-        argNames: ["order"],
-      );
-
-  @override
-  Future<Port> crateApiCreatePort({required CreatePortRequest port}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_port_request(port, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_port,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreatePortConstMeta,
-      argValues: [port],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreatePortConstMeta => const TaskConstMeta(
-        debugName: "create_port",
-        argNames: ["port"],
-      );
-
-  @override
-  Future<Ship> crateApiCreateShip({required CreateShipRequest ship}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_ship_request(ship, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_ship,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreateShipConstMeta,
-      argValues: [ship],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreateShipConstMeta => const TaskConstMeta(
-        debugName: "create_ship",
-        argNames: ["ship"],
-      );
-
-  @override
-  Future<ShipVisit> crateApiCreateShipVisit(
-      {required CreateShipVisitRequest visit}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_ship_visit_request(visit, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreateShipVisitConstMeta,
-      argValues: [visit],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreateShipVisitConstMeta => const TaskConstMeta(
-        debugName: "create_ship_visit",
-        argNames: ["visit"],
-      );
-
-  @override
-  Future<Stock> crateApiCreateStock({required CreateStockRequest stock}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_stock_request(stock, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_stock,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreateStockConstMeta,
-      argValues: [stock],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreateStockConstMeta => const TaskConstMeta(
-        debugName: "create_stock",
-        argNames: ["stock"],
-      );
-
-  @override
-  Future<StockMovement> crateApiCreateStockMovement(
-      {required CreateStockMovementRequest movement}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_stock_movement_request(
-            movement, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_stock_movement,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreateStockMovementConstMeta,
-      argValues: [movement],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreateStockMovementConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_stock_movement",
-        argNames: ["movement"],
-      );
-
-  @override
-  Future<Supplier> crateApiCreateSupplier(
-      {required CreateSupplierRequest supplier}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_supplier_request(supplier, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_supplier,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreateSupplierConstMeta,
-      argValues: [supplier],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreateSupplierConstMeta => const TaskConstMeta(
-        debugName: "create_supplier",
-        argNames: ["supplier"],
-      );
-
-  @override
-  Future<SupplyItem> crateApiCreateSupplyItem(
-      {required CreateSupplyItemRequest item}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_box_autoadd_create_supply_item_request(item, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_supply_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiCreateSupplyItemConstMeta,
-      argValues: [item],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiCreateSupplyItemConstMeta => const TaskConstMeta(
-        debugName: "create_supply_item",
-        argNames: ["item"],
-      );
-
-  @override
-  Future<bool> crateApiDeleteOrder({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeleteOrderConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeleteOrderConstMeta => const TaskConstMeta(
-        debugName: "delete_order",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<bool> crateApiDeleteOrderItem({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeleteOrderItemConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeleteOrderItemConstMeta => const TaskConstMeta(
-        debugName: "delete_order_item",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<bool> crateApiDeletePort({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeletePortConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeletePortConstMeta => const TaskConstMeta(
-        debugName: "delete_port",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<bool> crateApiDeleteShip({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 15, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeleteShipConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeleteShipConstMeta => const TaskConstMeta(
-        debugName: "delete_ship",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<bool> crateApiDeleteShipVisit({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeleteShipVisitConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeleteShipVisitConstMeta => const TaskConstMeta(
-        debugName: "delete_ship_visit",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<bool> crateApiDeleteStock({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 17, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeleteStockConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeleteStockConstMeta => const TaskConstMeta(
-        debugName: "delete_stock",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<bool> crateApiDeleteSupplier({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 18, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeleteSupplierConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeleteSupplierConstMeta => const TaskConstMeta(
-        debugName: "delete_supplier",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<bool> crateApiDeleteSupplyItem({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 19, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiDeleteSupplyItemConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDeleteSupplyItemConstMeta => const TaskConstMeta(
-        debugName: "delete_supply_item",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<List<Port>> crateApiGetActivePorts() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 20, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_port,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetActivePortsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetActivePortsConstMeta => const TaskConstMeta(
-        debugName: "get_active_ports",
-        argNames: [],
-      );
-
-  @override
-  Future<List<Order>> crateApiGetAllOrders({OrderStatus? statusFilter}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_opt_box_autoadd_order_status(statusFilter, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 21, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_order,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllOrdersConstMeta,
-      argValues: [statusFilter],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllOrdersConstMeta => const TaskConstMeta(
-        debugName: "get_all_orders",
-        argNames: ["statusFilter"],
-      );
-
-  @override
-  Future<List<Port>> crateApiGetAllPorts() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 22, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_port,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllPortsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllPortsConstMeta => const TaskConstMeta(
-        debugName: "get_all_ports",
-        argNames: [],
-      );
-
-  @override
-  Future<List<ShipVisit>> crateApiGetAllShipVisits() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 23, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllShipVisitsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllShipVisitsConstMeta => const TaskConstMeta(
-        debugName: "get_all_ship_visits",
-        argNames: [],
-      );
-
-  @override
-  Future<List<Ship>> crateApiGetAllShips() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 24, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_ship,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllShipsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllShipsConstMeta => const TaskConstMeta(
-        debugName: "get_all_ships",
-        argNames: [],
-      );
-
-  @override
-  Future<List<Stock>> crateApiGetAllStock() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 25, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_stock,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllStockConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllStockConstMeta => const TaskConstMeta(
-        debugName: "get_all_stock",
-        argNames: [],
-      );
-
-  @override
-  Future<List<Supplier>> crateApiGetAllSuppliers() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 26, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_supplier,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllSuppliersConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllSuppliersConstMeta => const TaskConstMeta(
-        debugName: "get_all_suppliers",
-        argNames: [],
-      );
-
-  @override
-  Future<List<SupplyItem>> crateApiGetAllSupplyItems() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 27, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_supply_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetAllSupplyItemsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetAllSupplyItemsConstMeta => const TaskConstMeta(
-        debugName: "get_all_supply_items",
-        argNames: [],
-      );
-
-  @override
-  Future<CalendarData> crateApiGetCalendarData(
-      {required String startDate, required String endDate}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(startDate, serializer);
-        sse_encode_String(endDate, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 28, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_calendar_data,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetCalendarDataConstMeta,
-      argValues: [startDate, endDate],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetCalendarDataConstMeta => const TaskConstMeta(
-        debugName: "get_calendar_data",
-        argNames: ["startDate", "endDate"],
-      );
-
-  @override
-  Future<List<Stock>> crateApiGetLowStock() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 29, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_stock,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetLowStockConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetLowStockConstMeta => const TaskConstMeta(
-        debugName: "get_low_stock",
-        argNames: [],
-      );
-
-  @override
-  Future<List<OrderItem>> crateApiGetOrderItems({required int orderId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(orderId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 30, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_order_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetOrderItemsConstMeta,
-      argValues: [orderId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetOrderItemsConstMeta => const TaskConstMeta(
-        debugName: "get_order_items",
-        argNames: ["orderId"],
-      );
-
-  @override
-  Future<OrderTotals> crateApiGetOrderTotals({required int orderId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(orderId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 31, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_order_totals,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetOrderTotalsConstMeta,
-      argValues: [orderId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetOrderTotalsConstMeta => const TaskConstMeta(
-        debugName: "get_order_totals",
-        argNames: ["orderId"],
-      );
-
-  @override
-  Future<OrderWithItems?> crateApiGetOrderWithItems({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 32, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_order_with_items,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetOrderWithItemsConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetOrderWithItemsConstMeta => const TaskConstMeta(
-        debugName: "get_order_with_items",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<List<Order>> crateApiGetOrdersByShipVisit({required int shipVisitId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(shipVisitId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 33, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_order,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetOrdersByShipVisitConstMeta,
-      argValues: [shipVisitId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetOrdersByShipVisitConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_orders_by_ship_visit",
-        argNames: ["shipVisitId"],
-      );
-
-  @override
-  Future<Port?> crateApiGetPortById({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 34, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_port,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetPortByIdConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetPortByIdConstMeta => const TaskConstMeta(
-        debugName: "get_port_by_id",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<List<Port>> crateApiGetPortsByCountry({required String country}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(country, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 35, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_port,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetPortsByCountryConstMeta,
-      argValues: [country],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetPortsByCountryConstMeta => const TaskConstMeta(
-        debugName: "get_ports_by_country",
-        argNames: ["country"],
-      );
-
-  @override
-  Future<ProfitSummary> crateApiGetProfitSummary() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 36, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_profit_summary,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetProfitSummaryConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetProfitSummaryConstMeta => const TaskConstMeta(
-        debugName: "get_profit_summary",
-        argNames: [],
-      );
-
-  @override
-  Future<List<StockMovement>> crateApiGetRecentStockMovements(
-      {required int limit}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(limit, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 37, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_stock_movement,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetRecentStockMovementsConstMeta,
-      argValues: [limit],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetRecentStockMovementsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_recent_stock_movements",
-        argNames: ["limit"],
-      );
-
-  @override
-  Future<Ship?> crateApiGetShipById({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 38, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_ship,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetShipByIdConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetShipByIdConstMeta => const TaskConstMeta(
-        debugName: "get_ship_by_id",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<PlatformInt64> crateApiGetShipCount() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 39, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_i_64,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetShipCountConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetShipCountConstMeta => const TaskConstMeta(
-        debugName: "get_ship_count",
-        argNames: [],
-      );
-
-  @override
-  Future<ShipVisit?> crateApiGetShipVisitById({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 40, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetShipVisitByIdConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetShipVisitByIdConstMeta => const TaskConstMeta(
-        debugName: "get_ship_visit_by_id",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<List<ShipVisit>> crateApiGetShipVisitsByDateRange(
-      {required String startDate, required String endDate}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(startDate, serializer);
-        sse_encode_String(endDate, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 41, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetShipVisitsByDateRangeConstMeta,
-      argValues: [startDate, endDate],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetShipVisitsByDateRangeConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_ship_visits_by_date_range",
-        argNames: ["startDate", "endDate"],
-      );
-
-  @override
-  Future<List<ShipVisit>> crateApiGetShipVisitsByPort({required int portId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(portId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 42, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetShipVisitsByPortConstMeta,
-      argValues: [portId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetShipVisitsByPortConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_ship_visits_by_port",
-        argNames: ["portId"],
-      );
-
-  @override
-  Future<List<ShipVisit>> crateApiGetShipVisitsByShip({required int shipId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(shipId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 43, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetShipVisitsByShipConstMeta,
-      argValues: [shipId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetShipVisitsByShipConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_ship_visits_by_ship",
-        argNames: ["shipId"],
-      );
-
-  @override
-  Future<Stock?> crateApiGetStockById({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 44, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_stock,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetStockByIdConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetStockByIdConstMeta => const TaskConstMeta(
-        debugName: "get_stock_by_id",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<Stock?> crateApiGetStockBySupplyItem({required int supplyItemId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(supplyItemId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 45, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_stock,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetStockBySupplyItemConstMeta,
-      argValues: [supplyItemId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetStockBySupplyItemConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_stock_by_supply_item",
-        argNames: ["supplyItemId"],
-      );
-
-  @override
-  Future<List<StockMovement>> crateApiGetStockMovements(
-      {required int stockId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(stockId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 46, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_stock_movement,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetStockMovementsConstMeta,
-      argValues: [stockId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetStockMovementsConstMeta => const TaskConstMeta(
-        debugName: "get_stock_movements",
-        argNames: ["stockId"],
-      );
-
-  @override
-  Future<StockSummary> crateApiGetStockSummary() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 47, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_stock_summary,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetStockSummaryConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetStockSummaryConstMeta => const TaskConstMeta(
-        debugName: "get_stock_summary",
-        argNames: [],
-      );
-
-  @override
-  Future<StockWithMovements?> crateApiGetStockWithMovements({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 48, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_stock_with_movements,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetStockWithMovementsConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetStockWithMovementsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_stock_with_movements",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<Supplier?> crateApiGetSupplierById({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 49, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_supplier,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSupplierByIdConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupplierByIdConstMeta => const TaskConstMeta(
-        debugName: "get_supplier_by_id",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<PlatformInt64> crateApiGetSupplierCount() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 50, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_i_64,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSupplierCountConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupplierCountConstMeta => const TaskConstMeta(
-        debugName: "get_supplier_count",
-        argNames: [],
-      );
-
-  @override
-  Future<List<Supplier>> crateApiGetSuppliersByCategory(
-      {required String category}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(category, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 51, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_supplier,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSuppliersByCategoryConstMeta,
-      argValues: [category],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSuppliersByCategoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_suppliers_by_category",
-        argNames: ["category"],
-      );
-
-  @override
-  Future<SupplyItem?> crateApiGetSupplyItemById({required int id}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 52, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_supply_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSupplyItemByIdConstMeta,
-      argValues: [id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupplyItemByIdConstMeta => const TaskConstMeta(
-        debugName: "get_supply_item_by_id",
-        argNames: ["id"],
-      );
-
-  @override
-  Future<PlatformInt64> crateApiGetSupplyItemCount() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 53, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_i_64,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSupplyItemCountConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupplyItemCountConstMeta => const TaskConstMeta(
-        debugName: "get_supply_item_count",
-        argNames: [],
-      );
-
-  @override
-  Future<List<SupplyItem>> crateApiGetSupplyItemsByCategory(
-      {required String category}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(category, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 54, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_supply_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSupplyItemsByCategoryConstMeta,
-      argValues: [category],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupplyItemsByCategoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_supply_items_by_category",
-        argNames: ["category"],
-      );
-
-  @override
-  Future<List<SupplyItem>> crateApiGetSupplyItemsBySupplier(
-      {required int supplierId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(supplierId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 55, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_supply_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetSupplyItemsBySupplierConstMeta,
-      argValues: [supplierId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetSupplyItemsBySupplierConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_supply_items_by_supplier",
-        argNames: ["supplierId"],
-      );
-
-  @override
-  Future<List<OrderProfitInfo>> crateApiGetTopProfitableOrders(
-      {required int limit}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(limit, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 56, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_order_profit_info,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetTopProfitableOrdersConstMeta,
-      argValues: [limit],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetTopProfitableOrdersConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_top_profitable_orders",
-        argNames: ["limit"],
-      );
-
-  @override
-  Future<List<ShipVisit>> crateApiGetUpcomingShipVisits() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 57, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiGetUpcomingShipVisitsConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetUpcomingShipVisitsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_upcoming_ship_visits",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiGetVersion() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 58, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiGetVersionConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGetVersionConstMeta => const TaskConstMeta(
-        debugName: "get_version",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiGreet({required String name}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(name, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 59, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiGreetConstMeta,
-      argValues: [name],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiGreetConstMeta => const TaskConstMeta(
-        debugName: "greet",
-        argNames: ["name"],
-      );
-
-  @override
-  Future<void> crateApiInitDatabase({required String databaseUrl}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(databaseUrl, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 60, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiInitDatabaseConstMeta,
-      argValues: [databaseUrl],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiInitDatabaseConstMeta => const TaskConstMeta(
-        debugName: "init_database",
-        argNames: ["databaseUrl"],
-      );
-
-  @override
-  Future<String> crateApiInitLocalDatabase() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 61, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiInitLocalDatabaseConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiInitLocalDatabaseConstMeta => const TaskConstMeta(
-        debugName: "init_local_database",
-        argNames: [],
-      );
-
-  @override
-  Future<bool> crateApiIsDatabaseConnected() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 62, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiIsDatabaseConnectedConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiIsDatabaseConnectedConstMeta =>
-      const TaskConstMeta(
-        debugName: "is_database_connected",
-        argNames: [],
-      );
-
-  @override
-  Future<String> crateApiLoadSeedData() {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 63, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiLoadSeedDataConstMeta,
-      argValues: [],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiLoadSeedDataConstMeta => const TaskConstMeta(
-        debugName: "load_seed_data",
-        argNames: [],
-      );
-
-  @override
-  Future<List<Ship>> crateApiSearchShips({required String query}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 64, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_ship,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiSearchShipsConstMeta,
-      argValues: [query],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSearchShipsConstMeta => const TaskConstMeta(
-        debugName: "search_ships",
-        argNames: ["query"],
-      );
-
-  @override
-  Future<List<Supplier>> crateApiSearchSuppliers({required String query}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 65, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_supplier,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiSearchSuppliersConstMeta,
-      argValues: [query],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSearchSuppliersConstMeta => const TaskConstMeta(
-        debugName: "search_suppliers",
-        argNames: ["query"],
-      );
-
-  @override
-  Future<List<SupplyItem>> crateApiSearchSupplyItems({required String query}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(query, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 66, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_supply_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiSearchSupplyItemsConstMeta,
-      argValues: [query],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiSearchSupplyItemsConstMeta => const TaskConstMeta(
-        debugName: "search_supply_items",
-        argNames: ["query"],
-      );
-
-  @override
-  Future<Order> crateApiUpdateOrder(
-      {required int id, required UpdateOrderRequest order}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_order_request(order, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 67, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_order,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateOrderConstMeta,
-      argValues: [id, order],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateOrderConstMeta => const TaskConstMeta(
-        debugName: "update_order",
-        argNames: ["id", "order"],
-      );
-
-  @override
-  Future<OrderItem> crateApiUpdateOrderItem(
-      {required int id, required UpdateOrderItemRequest item}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_order_item_request(item, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 68, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_order_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateOrderItemConstMeta,
-      argValues: [id, item],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateOrderItemConstMeta => const TaskConstMeta(
-        debugName: "update_order_item",
-        argNames: ["id", "item"],
-      );
-
-  @override
-  Future<Order> crateApiUpdateOrderStatus(
-      {required int id, required OrderStatus newStatus}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_order_status(newStatus, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 69, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_order,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateOrderStatusConstMeta,
-      argValues: [id, newStatus],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateOrderStatusConstMeta => const TaskConstMeta(
-        debugName: "update_order_status",
-        argNames: ["id", "newStatus"],
-      );
-
-  @override
-  Future<Port?> crateApiUpdatePort(
-      {required int id, required UpdatePortRequest port}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_port_request(port, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 70, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_port,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdatePortConstMeta,
-      argValues: [id, port],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdatePortConstMeta => const TaskConstMeta(
-        debugName: "update_port",
-        argNames: ["id", "port"],
-      );
-
-  @override
-  Future<Ship> crateApiUpdateShip(
-      {required int id, required UpdateShipRequest ship}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_ship_request(ship, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 71, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_ship,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateShipConstMeta,
-      argValues: [id, ship],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateShipConstMeta => const TaskConstMeta(
-        debugName: "update_ship",
-        argNames: ["id", "ship"],
-      );
-
-  @override
-  Future<ShipVisit?> crateApiUpdateShipVisit(
-      {required int id, required UpdateShipVisitRequest visit}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_ship_visit_request(visit, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 72, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateShipVisitConstMeta,
-      argValues: [id, visit],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateShipVisitConstMeta => const TaskConstMeta(
-        debugName: "update_ship_visit",
-        argNames: ["id", "visit"],
-      );
-
-  @override
-  Future<ShipVisit?> crateApiUpdateShipVisitStatus(
-      {required int id, required VisitStatus status}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_visit_status(status, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 73, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_ship_visit,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateShipVisitStatusConstMeta,
-      argValues: [id, status],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateShipVisitStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_ship_visit_status",
-        argNames: ["id", "status"],
-      );
-
-  @override
-  Future<Stock> crateApiUpdateStock(
-      {required int id, required UpdateStockRequest stock}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_stock_request(stock, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 74, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_stock,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateStockConstMeta,
-      argValues: [id, stock],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateStockConstMeta => const TaskConstMeta(
-        debugName: "update_stock",
-        argNames: ["id", "stock"],
-      );
-
-  @override
-  Future<Supplier> crateApiUpdateSupplier(
-      {required int id, required UpdateSupplierRequest supplier}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_supplier_request(supplier, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 75, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_supplier,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateSupplierConstMeta,
-      argValues: [id, supplier],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateSupplierConstMeta => const TaskConstMeta(
-        debugName: "update_supplier",
-        argNames: ["id", "supplier"],
-      );
-
-  @override
-  Future<SupplyItem> crateApiUpdateSupplyItem(
-      {required int id, required UpdateSupplyItemRequest item}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_i_32(id, serializer);
-        sse_encode_box_autoadd_update_supply_item_request(item, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 76, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_supply_item,
-        decodeErrorData: sse_decode_String,
-      ),
-      constMeta: kCrateApiUpdateSupplyItemConstMeta,
-      argValues: [id, item],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiUpdateSupplyItemConstMeta => const TaskConstMeta(
-        debugName: "update_supply_item",
-        argNames: ["id", "item"],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  bool dco_decode_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  CreateOrderItemRequest dco_decode_box_autoadd_create_order_item_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_order_item_request(raw);
-  }
-
-  @protected
-  CreateOrderRequest dco_decode_box_autoadd_create_order_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_order_request(raw);
-  }
-
-  @protected
-  CreatePortRequest dco_decode_box_autoadd_create_port_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_port_request(raw);
-  }
-
-  @protected
-  CreateShipRequest dco_decode_box_autoadd_create_ship_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_ship_request(raw);
-  }
-
-  @protected
-  CreateShipVisitRequest dco_decode_box_autoadd_create_ship_visit_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_ship_visit_request(raw);
-  }
-
-  @protected
-  CreateStockMovementRequest
-      dco_decode_box_autoadd_create_stock_movement_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_stock_movement_request(raw);
-  }
-
-  @protected
-  CreateStockRequest dco_decode_box_autoadd_create_stock_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_stock_request(raw);
-  }
-
-  @protected
-  CreateSupplierRequest dco_decode_box_autoadd_create_supplier_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_supplier_request(raw);
-  }
-
-  @protected
-  CreateSupplyItemRequest dco_decode_box_autoadd_create_supply_item_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_create_supply_item_request(raw);
-  }
-
-  @protected
-  DeliveryType dco_decode_box_autoadd_delivery_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_delivery_type(raw);
-  }
-
-  @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  OrderStatus dco_decode_box_autoadd_order_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_order_status(raw);
-  }
-
-  @protected
-  OrderWithItems dco_decode_box_autoadd_order_with_items(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_order_with_items(raw);
-  }
-
-  @protected
-  Port dco_decode_box_autoadd_port(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_port(raw);
-  }
-
-  @protected
-  Ship dco_decode_box_autoadd_ship(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ship(raw);
-  }
-
-  @protected
-  ShipVisit dco_decode_box_autoadd_ship_visit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_ship_visit(raw);
-  }
-
-  @protected
-  Stock dco_decode_box_autoadd_stock(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_stock(raw);
-  }
-
-  @protected
-  StockWithMovements dco_decode_box_autoadd_stock_with_movements(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_stock_with_movements(raw);
-  }
-
-  @protected
-  Supplier dco_decode_box_autoadd_supplier(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_supplier(raw);
-  }
-
-  @protected
-  SupplyItem dco_decode_box_autoadd_supply_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_supply_item(raw);
-  }
-
-  @protected
-  UpdateOrderItemRequest dco_decode_box_autoadd_update_order_item_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_order_item_request(raw);
-  }
-
-  @protected
-  UpdateOrderRequest dco_decode_box_autoadd_update_order_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_order_request(raw);
-  }
-
-  @protected
-  UpdatePortRequest dco_decode_box_autoadd_update_port_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_port_request(raw);
-  }
-
-  @protected
-  UpdateShipRequest dco_decode_box_autoadd_update_ship_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_ship_request(raw);
-  }
-
-  @protected
-  UpdateShipVisitRequest dco_decode_box_autoadd_update_ship_visit_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_ship_visit_request(raw);
-  }
-
-  @protected
-  UpdateStockRequest dco_decode_box_autoadd_update_stock_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_stock_request(raw);
-  }
-
-  @protected
-  UpdateSupplierRequest dco_decode_box_autoadd_update_supplier_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_supplier_request(raw);
-  }
-
-  @protected
-  UpdateSupplyItemRequest dco_decode_box_autoadd_update_supply_item_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_update_supply_item_request(raw);
-  }
-
-  @protected
-  VisitStatus dco_decode_box_autoadd_visit_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_visit_status(raw);
-  }
-
-  @protected
-  CalendarData dco_decode_calendar_data(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return CalendarData(
-      events: dco_decode_list_calendar_event(arr[0]),
-      ports: dco_decode_list_port(arr[1]),
-    );
-  }
-
-  @protected
-  CalendarEvent dco_decode_calendar_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 12)
-      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
-    return CalendarEvent(
-      id: dco_decode_String(arr[0]),
-      eventType: dco_decode_calendar_event_type(arr[1]),
-      title: dco_decode_String(arr[2]),
-      subtitle: dco_decode_opt_String(arr[3]),
-      startDate: dco_decode_String(arr[4]),
-      endDate: dco_decode_String(arr[5]),
-      color: dco_decode_String(arr[6]),
-      status: dco_decode_String(arr[7]),
-      relatedShipId: dco_decode_opt_box_autoadd_i_32(arr[8]),
-      relatedPortId: dco_decode_opt_box_autoadd_i_32(arr[9]),
-      relatedOrderId: dco_decode_opt_box_autoadd_i_32(arr[10]),
-      metadata: dco_decode_opt_String(arr[11]),
-    );
-  }
-
-  @protected
-  CalendarEventType dco_decode_calendar_event_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CalendarEventType.values[raw as int];
-  }
-
-  @protected
-  CreateOrderItemRequest dco_decode_create_order_item_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 13)
-      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
-    return CreateOrderItemRequest(
-      orderId: dco_decode_i_32(arr[0]),
-      productName: dco_decode_String(arr[1]),
-      impaCode: dco_decode_opt_String(arr[2]),
-      description: dco_decode_opt_String(arr[3]),
-      quantity: dco_decode_f_64(arr[4]),
-      unit: dco_decode_String(arr[5]),
-      buyingPrice: dco_decode_f_64(arr[6]),
-      sellingPrice: dco_decode_f_64(arr[7]),
-      currency: dco_decode_String(arr[8]),
-      deliveryType: dco_decode_delivery_type(arr[9]),
-      warehouseDeliveryDate: dco_decode_opt_String(arr[10]),
-      shipDeliveryDate: dco_decode_opt_String(arr[11]),
-      notes: dco_decode_opt_String(arr[12]),
-    );
-  }
-
-  @protected
-  CreateOrderRequest dco_decode_create_order_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return CreateOrderRequest(
-      shipId: dco_decode_i_32(arr[0]),
-      shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[1]),
-      deliveryPort: dco_decode_opt_String(arr[2]),
-      notes: dco_decode_opt_String(arr[3]),
-      currency: dco_decode_String(arr[4]),
-    );
-  }
-
-  @protected
-  CreatePortRequest dco_decode_create_port_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return CreatePortRequest(
-      name: dco_decode_String(arr[0]),
-      country: dco_decode_String(arr[1]),
-      city: dco_decode_opt_String(arr[2]),
-      timezone: dco_decode_String(arr[3]),
-      latitude: dco_decode_opt_box_autoadd_f_64(arr[4]),
-      longitude: dco_decode_opt_box_autoadd_f_64(arr[5]),
-      notes: dco_decode_opt_String(arr[6]),
-    );
-  }
-
-  @protected
-  CreateShipRequest dco_decode_create_ship_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return CreateShipRequest(
-      name: dco_decode_String(arr[0]),
-      imoNumber: dco_decode_String(arr[1]),
-      flag: dco_decode_String(arr[2]),
-      shipType: dco_decode_opt_String(arr[3]),
-      grossTonnage: dco_decode_opt_box_autoadd_f_64(arr[4]),
-      owner: dco_decode_opt_String(arr[5]),
-    );
-  }
-
-  @protected
-  CreateShipVisitRequest dco_decode_create_ship_visit_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return CreateShipVisitRequest(
-      shipId: dco_decode_i_32(arr[0]),
-      portId: dco_decode_i_32(arr[1]),
-      eta: dco_decode_String(arr[2]),
-      etd: dco_decode_String(arr[3]),
-      agentInfo: dco_decode_opt_String(arr[4]),
-      notes: dco_decode_opt_String(arr[5]),
-    );
-  }
-
-  @protected
-  CreateStockMovementRequest dco_decode_create_stock_movement_request(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return CreateStockMovementRequest(
-      stockId: dco_decode_i_32(arr[0]),
-      movementType: dco_decode_stock_movement_type(arr[1]),
-      quantity: dco_decode_f_64(arr[2]),
-      referenceType: dco_decode_opt_String(arr[3]),
-      referenceId: dco_decode_opt_box_autoadd_i_32(arr[4]),
-      referenceInfo: dco_decode_opt_String(arr[5]),
-      notes: dco_decode_opt_String(arr[6]),
-    );
-  }
-
-  @protected
-  CreateStockRequest dco_decode_create_stock_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return CreateStockRequest(
-      supplyItemId: dco_decode_i_32(arr[0]),
-      quantity: dco_decode_f_64(arr[1]),
-      unit: dco_decode_String(arr[2]),
-      warehouseLocation: dco_decode_opt_String(arr[3]),
-      minimumQuantity: dco_decode_f_64(arr[4]),
-    );
-  }
-
-  @protected
-  CreateSupplierRequest dco_decode_create_supplier_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return CreateSupplierRequest(
-      name: dco_decode_String(arr[0]),
-      contactPerson: dco_decode_opt_String(arr[1]),
-      email: dco_decode_opt_String(arr[2]),
-      phone: dco_decode_opt_String(arr[3]),
-      address: dco_decode_opt_String(arr[4]),
-      country: dco_decode_opt_String(arr[5]),
-      category: dco_decode_String(arr[6]),
-    );
-  }
-
-  @protected
-  CreateSupplyItemRequest dco_decode_create_supply_item_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return CreateSupplyItemRequest(
-      supplierId: dco_decode_i_32(arr[0]),
-      impaCode: dco_decode_opt_String(arr[1]),
-      name: dco_decode_String(arr[2]),
-      description: dco_decode_opt_String(arr[3]),
-      category: dco_decode_String(arr[4]),
-      unit: dco_decode_String(arr[5]),
-      unitPrice: dco_decode_f_64(arr[6]),
-      currency: dco_decode_String(arr[7]),
-      minimumOrderQuantity: dco_decode_opt_box_autoadd_i_32(arr[8]),
-    );
-  }
-
-  @protected
-  DeliveryType dco_decode_delivery_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DeliveryType.values[raw as int];
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  ItemProfit dco_decode_item_profit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return ItemProfit(
-      totalCost: dco_decode_f_64(arr[0]),
-      totalRevenue: dco_decode_f_64(arr[1]),
-      grossProfit: dco_decode_f_64(arr[2]),
-      marginPercent: dco_decode_opt_box_autoadd_f_64(arr[3]),
-    );
-  }
-
-  @protected
-  List<CalendarEvent> dco_decode_list_calendar_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_calendar_event).toList();
-  }
-
-  @protected
-  List<Order> dco_decode_list_order(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_order).toList();
-  }
-
-  @protected
-  List<OrderItem> dco_decode_list_order_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_order_item).toList();
-  }
-
-  @protected
-  List<OrderProfitInfo> dco_decode_list_order_profit_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_order_profit_info).toList();
-  }
-
-  @protected
-  List<Port> dco_decode_list_port(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_port).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<Ship> dco_decode_list_ship(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ship).toList();
-  }
-
-  @protected
-  List<ShipVisit> dco_decode_list_ship_visit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_ship_visit).toList();
-  }
-
-  @protected
-  List<Stock> dco_decode_list_stock(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_stock).toList();
-  }
-
-  @protected
-  List<StockMovement> dco_decode_list_stock_movement(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_stock_movement).toList();
-  }
-
-  @protected
-  List<Supplier> dco_decode_list_supplier(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_supplier).toList();
-  }
-
-  @protected
-  List<SupplyItem> dco_decode_list_supply_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_supply_item).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
-  }
-
-  @protected
-  DeliveryType? dco_decode_opt_box_autoadd_delivery_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_delivery_type(raw);
-  }
-
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
-  }
-
-  @protected
-  OrderStatus? dco_decode_opt_box_autoadd_order_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_order_status(raw);
-  }
-
-  @protected
-  OrderWithItems? dco_decode_opt_box_autoadd_order_with_items(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_order_with_items(raw);
-  }
-
-  @protected
-  Port? dco_decode_opt_box_autoadd_port(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_port(raw);
-  }
-
-  @protected
-  Ship? dco_decode_opt_box_autoadd_ship(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_ship(raw);
-  }
-
-  @protected
-  ShipVisit? dco_decode_opt_box_autoadd_ship_visit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_ship_visit(raw);
-  }
-
-  @protected
-  Stock? dco_decode_opt_box_autoadd_stock(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_stock(raw);
-  }
-
-  @protected
-  StockWithMovements? dco_decode_opt_box_autoadd_stock_with_movements(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_stock_with_movements(raw);
-  }
-
-  @protected
-  Supplier? dco_decode_opt_box_autoadd_supplier(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_supplier(raw);
-  }
-
-  @protected
-  SupplyItem? dco_decode_opt_box_autoadd_supply_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_supply_item(raw);
-  }
-
-  @protected
-  VisitStatus? dco_decode_opt_box_autoadd_visit_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_visit_status(raw);
-  }
-
-  @protected
-  Order dco_decode_order(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 12)
-      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
-    return Order(
-      id: dco_decode_i_32(arr[0]),
-      orderNumber: dco_decode_String(arr[1]),
-      shipId: dco_decode_i_32(arr[2]),
-      shipName: dco_decode_opt_String(arr[3]),
-      shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[4]),
-      shipVisitInfo: dco_decode_opt_String(arr[5]),
-      status: dco_decode_order_status(arr[6]),
-      deliveryPort: dco_decode_opt_String(arr[7]),
-      notes: dco_decode_opt_String(arr[8]),
-      currency: dco_decode_String(arr[9]),
-      createdAt: dco_decode_String(arr[10]),
-      updatedAt: dco_decode_String(arr[11]),
-    );
-  }
-
-  @protected
-  OrderItem dco_decode_order_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 14)
-      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
-    return OrderItem(
-      id: dco_decode_i_32(arr[0]),
-      orderId: dco_decode_i_32(arr[1]),
-      productName: dco_decode_String(arr[2]),
-      impaCode: dco_decode_opt_String(arr[3]),
-      description: dco_decode_opt_String(arr[4]),
-      quantity: dco_decode_f_64(arr[5]),
-      unit: dco_decode_String(arr[6]),
-      buyingPrice: dco_decode_f_64(arr[7]),
-      sellingPrice: dco_decode_f_64(arr[8]),
-      currency: dco_decode_String(arr[9]),
-      deliveryType: dco_decode_delivery_type(arr[10]),
-      warehouseDeliveryDate: dco_decode_opt_String(arr[11]),
-      shipDeliveryDate: dco_decode_opt_String(arr[12]),
-      notes: dco_decode_opt_String(arr[13]),
-    );
-  }
-
-  @protected
-  OrderProfitInfo dco_decode_order_profit_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return OrderProfitInfo(
-      orderId: dco_decode_i_32(arr[0]),
-      orderNumber: dco_decode_String(arr[1]),
-      shipName: dco_decode_String(arr[2]),
-      totalRevenue: dco_decode_f_64(arr[3]),
-      totalCost: dco_decode_f_64(arr[4]),
-      profit: dco_decode_f_64(arr[5]),
-      marginPercent: dco_decode_f_64(arr[6]),
-      currency: dco_decode_String(arr[7]),
-    );
-  }
-
-  @protected
-  OrderStatus dco_decode_order_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return OrderStatus.values[raw as int];
-  }
-
-  @protected
-  OrderTotals dco_decode_order_totals(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return OrderTotals(
-      itemCount: dco_decode_i_32(arr[0]),
-      totalCost: dco_decode_f_64(arr[1]),
-      totalRevenue: dco_decode_f_64(arr[2]),
-      grossProfit: dco_decode_f_64(arr[3]),
-      marginPercent: dco_decode_opt_box_autoadd_f_64(arr[4]),
-      currency: dco_decode_String(arr[5]),
-    );
-  }
-
-  @protected
-  OrderWithItems dco_decode_order_with_items(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return OrderWithItems(
-      order: dco_decode_order(arr[0]),
-      items: dco_decode_list_order_item(arr[1]),
-      totals: dco_decode_order_totals(arr[2]),
-    );
-  }
-
-  @protected
-  Port dco_decode_port(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return Port(
-      id: dco_decode_i_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      country: dco_decode_String(arr[2]),
-      city: dco_decode_opt_String(arr[3]),
-      timezone: dco_decode_String(arr[4]),
-      latitude: dco_decode_opt_box_autoadd_f_64(arr[5]),
-      longitude: dco_decode_opt_box_autoadd_f_64(arr[6]),
-      notes: dco_decode_opt_String(arr[7]),
-      isActive: dco_decode_bool(arr[8]),
-      createdAt: dco_decode_String(arr[9]),
-      updatedAt: dco_decode_String(arr[10]),
-    );
-  }
-
-  @protected
-  ProfitSummary dco_decode_profit_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return ProfitSummary(
-      totalOrders: dco_decode_i_32(arr[0]),
-      totalRevenue: dco_decode_f_64(arr[1]),
-      totalCost: dco_decode_f_64(arr[2]),
-      totalProfit: dco_decode_f_64(arr[3]),
-      averageMargin: dco_decode_opt_box_autoadd_f_64(arr[4]),
-      currency: dco_decode_String(arr[5]),
-    );
-  }
-
-  @protected
-  Ship dco_decode_ship(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return Ship(
-      id: dco_decode_i_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      imoNumber: dco_decode_String(arr[2]),
-      flag: dco_decode_String(arr[3]),
-      shipType: dco_decode_opt_String(arr[4]),
-      grossTonnage: dco_decode_opt_box_autoadd_f_64(arr[5]),
-      owner: dco_decode_opt_String(arr[6]),
-      createdAt: dco_decode_String(arr[7]),
-      updatedAt: dco_decode_String(arr[8]),
-    );
-  }
-
-  @protected
-  ShipVisit dco_decode_ship_visit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 14)
-      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
-    return ShipVisit(
-      id: dco_decode_i_32(arr[0]),
-      shipId: dco_decode_i_32(arr[1]),
-      shipName: dco_decode_opt_String(arr[2]),
-      portId: dco_decode_i_32(arr[3]),
-      portName: dco_decode_opt_String(arr[4]),
-      eta: dco_decode_String(arr[5]),
-      etd: dco_decode_String(arr[6]),
-      ata: dco_decode_opt_String(arr[7]),
-      atd: dco_decode_opt_String(arr[8]),
-      status: dco_decode_visit_status(arr[9]),
-      agentInfo: dco_decode_opt_String(arr[10]),
-      notes: dco_decode_opt_String(arr[11]),
-      createdAt: dco_decode_String(arr[12]),
-      updatedAt: dco_decode_String(arr[13]),
-    );
-  }
-
-  @protected
-  Stock dco_decode_stock(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return Stock(
-      id: dco_decode_i_32(arr[0]),
-      supplyItemId: dco_decode_i_32(arr[1]),
-      supplyItemName: dco_decode_opt_String(arr[2]),
-      quantity: dco_decode_f_64(arr[3]),
-      unit: dco_decode_String(arr[4]),
-      warehouseLocation: dco_decode_opt_String(arr[5]),
-      minimumQuantity: dco_decode_f_64(arr[6]),
-      lastUpdated: dco_decode_String(arr[7]),
-    );
-  }
-
-  @protected
-  StockMovement dco_decode_stock_movement(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return StockMovement(
-      id: dco_decode_i_32(arr[0]),
-      stockId: dco_decode_i_32(arr[1]),
-      supplyItemName: dco_decode_opt_String(arr[2]),
-      movementType: dco_decode_stock_movement_type(arr[3]),
-      quantity: dco_decode_f_64(arr[4]),
-      unit: dco_decode_String(arr[5]),
-      referenceType: dco_decode_opt_String(arr[6]),
-      referenceId: dco_decode_opt_box_autoadd_i_32(arr[7]),
-      referenceInfo: dco_decode_opt_String(arr[8]),
-      notes: dco_decode_opt_String(arr[9]),
-      createdAt: dco_decode_String(arr[10]),
-    );
-  }
-
-  @protected
-  StockMovementType dco_decode_stock_movement_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return StockMovementType.values[raw as int];
-  }
-
-  @protected
-  StockSummary dco_decode_stock_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return StockSummary(
-      totalItems: dco_decode_i_32(arr[0]),
-      lowStockCount: dco_decode_i_32(arr[1]),
-      outOfStockCount: dco_decode_i_32(arr[2]),
-      totalValue: dco_decode_f_64(arr[3]),
-      currency: dco_decode_String(arr[4]),
-    );
-  }
-
-  @protected
-  StockWithMovements dco_decode_stock_with_movements(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return StockWithMovements(
-      stock: dco_decode_stock(arr[0]),
-      movements: dco_decode_list_stock_movement(arr[1]),
-    );
-  }
-
-  @protected
-  Supplier dco_decode_supplier(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return Supplier(
-      id: dco_decode_i_32(arr[0]),
-      name: dco_decode_String(arr[1]),
-      contactPerson: dco_decode_opt_String(arr[2]),
-      email: dco_decode_opt_String(arr[3]),
-      phone: dco_decode_opt_String(arr[4]),
-      address: dco_decode_opt_String(arr[5]),
-      country: dco_decode_opt_String(arr[6]),
-      category: dco_decode_String(arr[7]),
-      isActive: dco_decode_bool(arr[8]),
-      createdAt: dco_decode_String(arr[9]),
-      updatedAt: dco_decode_String(arr[10]),
-    );
-  }
-
-  @protected
-  SupplyItem dco_decode_supply_item(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 14)
-      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
-    return SupplyItem(
-      id: dco_decode_i_32(arr[0]),
-      supplierId: dco_decode_i_32(arr[1]),
-      supplierName: dco_decode_opt_String(arr[2]),
-      impaCode: dco_decode_opt_String(arr[3]),
-      name: dco_decode_String(arr[4]),
-      description: dco_decode_opt_String(arr[5]),
-      category: dco_decode_String(arr[6]),
-      unit: dco_decode_String(arr[7]),
-      unitPrice: dco_decode_f_64(arr[8]),
-      currency: dco_decode_String(arr[9]),
-      minimumOrderQuantity: dco_decode_opt_box_autoadd_i_32(arr[10]),
-      isAvailable: dco_decode_bool(arr[11]),
-      createdAt: dco_decode_String(arr[12]),
-      updatedAt: dco_decode_String(arr[13]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  UpdateOrderItemRequest dco_decode_update_order_item_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 11)
-      throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
-    return UpdateOrderItemRequest(
-      productName: dco_decode_opt_String(arr[0]),
-      impaCode: dco_decode_opt_String(arr[1]),
-      description: dco_decode_opt_String(arr[2]),
-      quantity: dco_decode_opt_box_autoadd_f_64(arr[3]),
-      unit: dco_decode_opt_String(arr[4]),
-      buyingPrice: dco_decode_opt_box_autoadd_f_64(arr[5]),
-      sellingPrice: dco_decode_opt_box_autoadd_f_64(arr[6]),
-      deliveryType: dco_decode_opt_box_autoadd_delivery_type(arr[7]),
-      warehouseDeliveryDate: dco_decode_opt_String(arr[8]),
-      shipDeliveryDate: dco_decode_opt_String(arr[9]),
-      notes: dco_decode_opt_String(arr[10]),
-    );
-  }
-
-  @protected
-  UpdateOrderRequest dco_decode_update_order_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return UpdateOrderRequest(
-      shipId: dco_decode_opt_box_autoadd_i_32(arr[0]),
-      shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[1]),
-      deliveryPort: dco_decode_opt_String(arr[2]),
-      notes: dco_decode_opt_String(arr[3]),
-      currency: dco_decode_opt_String(arr[4]),
-    );
-  }
-
-  @protected
-  UpdatePortRequest dco_decode_update_port_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return UpdatePortRequest(
-      name: dco_decode_opt_String(arr[0]),
-      country: dco_decode_opt_String(arr[1]),
-      city: dco_decode_opt_String(arr[2]),
-      timezone: dco_decode_opt_String(arr[3]),
-      latitude: dco_decode_opt_box_autoadd_f_64(arr[4]),
-      longitude: dco_decode_opt_box_autoadd_f_64(arr[5]),
-      notes: dco_decode_opt_String(arr[6]),
-      isActive: dco_decode_opt_box_autoadd_bool(arr[7]),
-    );
-  }
-
-  @protected
-  UpdateShipRequest dco_decode_update_ship_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return UpdateShipRequest(
-      name: dco_decode_opt_String(arr[0]),
-      imoNumber: dco_decode_opt_String(arr[1]),
-      flag: dco_decode_opt_String(arr[2]),
-      shipType: dco_decode_opt_String(arr[3]),
-      grossTonnage: dco_decode_opt_box_autoadd_f_64(arr[4]),
-      owner: dco_decode_opt_String(arr[5]),
-    );
-  }
-
-  @protected
-  UpdateShipVisitRequest dco_decode_update_ship_visit_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return UpdateShipVisitRequest(
-      portId: dco_decode_opt_box_autoadd_i_32(arr[0]),
-      eta: dco_decode_opt_String(arr[1]),
-      etd: dco_decode_opt_String(arr[2]),
-      ata: dco_decode_opt_String(arr[3]),
-      atd: dco_decode_opt_String(arr[4]),
-      status: dco_decode_opt_box_autoadd_visit_status(arr[5]),
-      agentInfo: dco_decode_opt_String(arr[6]),
-      notes: dco_decode_opt_String(arr[7]),
-    );
-  }
-
-  @protected
-  UpdateStockRequest dco_decode_update_stock_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return UpdateStockRequest(
-      quantity: dco_decode_opt_box_autoadd_f_64(arr[0]),
-      warehouseLocation: dco_decode_opt_String(arr[1]),
-      minimumQuantity: dco_decode_opt_box_autoadd_f_64(arr[2]),
-    );
-  }
-
-  @protected
-  UpdateSupplierRequest dco_decode_update_supplier_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return UpdateSupplierRequest(
-      name: dco_decode_opt_String(arr[0]),
-      contactPerson: dco_decode_opt_String(arr[1]),
-      email: dco_decode_opt_String(arr[2]),
-      phone: dco_decode_opt_String(arr[3]),
-      address: dco_decode_opt_String(arr[4]),
-      country: dco_decode_opt_String(arr[5]),
-      category: dco_decode_opt_String(arr[6]),
-    );
-  }
-
-  @protected
-  UpdateSupplyItemRequest dco_decode_update_supply_item_request(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return UpdateSupplyItemRequest(
-      supplierId: dco_decode_opt_box_autoadd_i_32(arr[0]),
-      impaCode: dco_decode_opt_String(arr[1]),
-      name: dco_decode_opt_String(arr[2]),
-      description: dco_decode_opt_String(arr[3]),
-      category: dco_decode_opt_String(arr[4]),
-      unit: dco_decode_opt_String(arr[5]),
-      unitPrice: dco_decode_opt_box_autoadd_f_64(arr[6]),
-      currency: dco_decode_opt_String(arr[7]),
-      minimumOrderQuantity: dco_decode_opt_box_autoadd_i_32(arr[8]),
-      isAvailable: dco_decode_opt_box_autoadd_bool(arr[9]),
-    );
-  }
-
-  @protected
-  VisitStatus dco_decode_visit_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return VisitStatus.values[raw as int];
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_bool(deserializer));
-  }
-
-  @protected
-  CreateOrderItemRequest sse_decode_box_autoadd_create_order_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_order_item_request(deserializer));
-  }
-
-  @protected
-  CreateOrderRequest sse_decode_box_autoadd_create_order_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_order_request(deserializer));
-  }
-
-  @protected
-  CreatePortRequest sse_decode_box_autoadd_create_port_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_port_request(deserializer));
-  }
-
-  @protected
-  CreateShipRequest sse_decode_box_autoadd_create_ship_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_ship_request(deserializer));
-  }
-
-  @protected
-  CreateShipVisitRequest sse_decode_box_autoadd_create_ship_visit_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_ship_visit_request(deserializer));
-  }
-
-  @protected
-  CreateStockMovementRequest
-      sse_decode_box_autoadd_create_stock_movement_request(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_stock_movement_request(deserializer));
-  }
-
-  @protected
-  CreateStockRequest sse_decode_box_autoadd_create_stock_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_stock_request(deserializer));
-  }
-
-  @protected
-  CreateSupplierRequest sse_decode_box_autoadd_create_supplier_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_supplier_request(deserializer));
-  }
-
-  @protected
-  CreateSupplyItemRequest sse_decode_box_autoadd_create_supply_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_create_supply_item_request(deserializer));
-  }
-
-  @protected
-  DeliveryType sse_decode_box_autoadd_delivery_type(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_delivery_type(deserializer));
-  }
-
-  @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  OrderStatus sse_decode_box_autoadd_order_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_order_status(deserializer));
-  }
-
-  @protected
-  OrderWithItems sse_decode_box_autoadd_order_with_items(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_order_with_items(deserializer));
-  }
-
-  @protected
-  Port sse_decode_box_autoadd_port(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_port(deserializer));
-  }
-
-  @protected
-  Ship sse_decode_box_autoadd_ship(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ship(deserializer));
-  }
-
-  @protected
-  ShipVisit sse_decode_box_autoadd_ship_visit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_ship_visit(deserializer));
-  }
-
-  @protected
-  Stock sse_decode_box_autoadd_stock(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_stock(deserializer));
-  }
-
-  @protected
-  StockWithMovements sse_decode_box_autoadd_stock_with_movements(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_stock_with_movements(deserializer));
-  }
-
-  @protected
-  Supplier sse_decode_box_autoadd_supplier(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_supplier(deserializer));
-  }
-
-  @protected
-  SupplyItem sse_decode_box_autoadd_supply_item(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_supply_item(deserializer));
-  }
-
-  @protected
-  UpdateOrderItemRequest sse_decode_box_autoadd_update_order_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_order_item_request(deserializer));
-  }
-
-  @protected
-  UpdateOrderRequest sse_decode_box_autoadd_update_order_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_order_request(deserializer));
-  }
-
-  @protected
-  UpdatePortRequest sse_decode_box_autoadd_update_port_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_port_request(deserializer));
-  }
-
-  @protected
-  UpdateShipRequest sse_decode_box_autoadd_update_ship_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_ship_request(deserializer));
-  }
-
-  @protected
-  UpdateShipVisitRequest sse_decode_box_autoadd_update_ship_visit_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_ship_visit_request(deserializer));
-  }
-
-  @protected
-  UpdateStockRequest sse_decode_box_autoadd_update_stock_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_stock_request(deserializer));
-  }
-
-  @protected
-  UpdateSupplierRequest sse_decode_box_autoadd_update_supplier_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_supplier_request(deserializer));
-  }
-
-  @protected
-  UpdateSupplyItemRequest sse_decode_box_autoadd_update_supply_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_update_supply_item_request(deserializer));
-  }
-
-  @protected
-  VisitStatus sse_decode_box_autoadd_visit_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_visit_status(deserializer));
-  }
-
-  @protected
-  CalendarData sse_decode_calendar_data(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_events = sse_decode_list_calendar_event(deserializer);
-    var var_ports = sse_decode_list_port(deserializer);
-    return CalendarData(events: var_events, ports: var_ports);
-  }
-
-  @protected
-  CalendarEvent sse_decode_calendar_event(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_eventType = sse_decode_calendar_event_type(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_subtitle = sse_decode_opt_String(deserializer);
-    var var_startDate = sse_decode_String(deserializer);
-    var var_endDate = sse_decode_String(deserializer);
-    var var_color = sse_decode_String(deserializer);
-    var var_status = sse_decode_String(deserializer);
-    var var_relatedShipId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_relatedPortId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_relatedOrderId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_metadata = sse_decode_opt_String(deserializer);
-    return CalendarEvent(
-        id: var_id,
-        eventType: var_eventType,
-        title: var_title,
-        subtitle: var_subtitle,
-        startDate: var_startDate,
-        endDate: var_endDate,
-        color: var_color,
-        status: var_status,
-        relatedShipId: var_relatedShipId,
-        relatedPortId: var_relatedPortId,
-        relatedOrderId: var_relatedOrderId,
-        metadata: var_metadata);
-  }
-
-  @protected
-  CalendarEventType sse_decode_calendar_event_type(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return CalendarEventType.values[inner];
-  }
-
-  @protected
-  CreateOrderItemRequest sse_decode_create_order_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_orderId = sse_decode_i_32(deserializer);
-    var var_productName = sse_decode_String(deserializer);
-    var var_impaCode = sse_decode_opt_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_quantity = sse_decode_f_64(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_buyingPrice = sse_decode_f_64(deserializer);
-    var var_sellingPrice = sse_decode_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    var var_deliveryType = sse_decode_delivery_type(deserializer);
-    var var_warehouseDeliveryDate = sse_decode_opt_String(deserializer);
-    var var_shipDeliveryDate = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    return CreateOrderItemRequest(
-        orderId: var_orderId,
-        productName: var_productName,
-        impaCode: var_impaCode,
-        description: var_description,
-        quantity: var_quantity,
-        unit: var_unit,
-        buyingPrice: var_buyingPrice,
-        sellingPrice: var_sellingPrice,
-        currency: var_currency,
-        deliveryType: var_deliveryType,
-        warehouseDeliveryDate: var_warehouseDeliveryDate,
-        shipDeliveryDate: var_shipDeliveryDate,
-        notes: var_notes);
-  }
-
-  @protected
-  CreateOrderRequest sse_decode_create_order_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_shipId = sse_decode_i_32(deserializer);
-    var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_deliveryPort = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    return CreateOrderRequest(
-        shipId: var_shipId,
-        shipVisitId: var_shipVisitId,
-        deliveryPort: var_deliveryPort,
-        notes: var_notes,
-        currency: var_currency);
-  }
-
-  @protected
-  CreatePortRequest sse_decode_create_port_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_country = sse_decode_String(deserializer);
-    var var_city = sse_decode_opt_String(deserializer);
-    var var_timezone = sse_decode_String(deserializer);
-    var var_latitude = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_longitude = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    return CreatePortRequest(
-        name: var_name,
-        country: var_country,
-        city: var_city,
-        timezone: var_timezone,
-        latitude: var_latitude,
-        longitude: var_longitude,
-        notes: var_notes);
-  }
-
-  @protected
-  CreateShipRequest sse_decode_create_ship_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_imoNumber = sse_decode_String(deserializer);
-    var var_flag = sse_decode_String(deserializer);
-    var var_shipType = sse_decode_opt_String(deserializer);
-    var var_grossTonnage = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_owner = sse_decode_opt_String(deserializer);
-    return CreateShipRequest(
-        name: var_name,
-        imoNumber: var_imoNumber,
-        flag: var_flag,
-        shipType: var_shipType,
-        grossTonnage: var_grossTonnage,
-        owner: var_owner);
-  }
-
-  @protected
-  CreateShipVisitRequest sse_decode_create_ship_visit_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_shipId = sse_decode_i_32(deserializer);
-    var var_portId = sse_decode_i_32(deserializer);
-    var var_eta = sse_decode_String(deserializer);
-    var var_etd = sse_decode_String(deserializer);
-    var var_agentInfo = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    return CreateShipVisitRequest(
-        shipId: var_shipId,
-        portId: var_portId,
-        eta: var_eta,
-        etd: var_etd,
-        agentInfo: var_agentInfo,
-        notes: var_notes);
-  }
-
-  @protected
-  CreateStockMovementRequest sse_decode_create_stock_movement_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_stockId = sse_decode_i_32(deserializer);
-    var var_movementType = sse_decode_stock_movement_type(deserializer);
-    var var_quantity = sse_decode_f_64(deserializer);
-    var var_referenceType = sse_decode_opt_String(deserializer);
-    var var_referenceId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_referenceInfo = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    return CreateStockMovementRequest(
-        stockId: var_stockId,
-        movementType: var_movementType,
-        quantity: var_quantity,
-        referenceType: var_referenceType,
-        referenceId: var_referenceId,
-        referenceInfo: var_referenceInfo,
-        notes: var_notes);
-  }
-
-  @protected
-  CreateStockRequest sse_decode_create_stock_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_supplyItemId = sse_decode_i_32(deserializer);
-    var var_quantity = sse_decode_f_64(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_warehouseLocation = sse_decode_opt_String(deserializer);
-    var var_minimumQuantity = sse_decode_f_64(deserializer);
-    return CreateStockRequest(
-        supplyItemId: var_supplyItemId,
-        quantity: var_quantity,
-        unit: var_unit,
-        warehouseLocation: var_warehouseLocation,
-        minimumQuantity: var_minimumQuantity);
-  }
-
-  @protected
-  CreateSupplierRequest sse_decode_create_supplier_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_contactPerson = sse_decode_opt_String(deserializer);
-    var var_email = sse_decode_opt_String(deserializer);
-    var var_phone = sse_decode_opt_String(deserializer);
-    var var_address = sse_decode_opt_String(deserializer);
-    var var_country = sse_decode_opt_String(deserializer);
-    var var_category = sse_decode_String(deserializer);
-    return CreateSupplierRequest(
-        name: var_name,
-        contactPerson: var_contactPerson,
-        email: var_email,
-        phone: var_phone,
-        address: var_address,
-        country: var_country,
-        category: var_category);
-  }
-
-  @protected
-  CreateSupplyItemRequest sse_decode_create_supply_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_supplierId = sse_decode_i_32(deserializer);
-    var var_impaCode = sse_decode_opt_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_category = sse_decode_String(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_unitPrice = sse_decode_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    var var_minimumOrderQuantity =
-        sse_decode_opt_box_autoadd_i_32(deserializer);
-    return CreateSupplyItemRequest(
-        supplierId: var_supplierId,
-        impaCode: var_impaCode,
-        name: var_name,
-        description: var_description,
-        category: var_category,
-        unit: var_unit,
-        unitPrice: var_unitPrice,
-        currency: var_currency,
-        minimumOrderQuantity: var_minimumOrderQuantity);
-  }
-
-  @protected
-  DeliveryType sse_decode_delivery_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return DeliveryType.values[inner];
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  ItemProfit sse_decode_item_profit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalCost = sse_decode_f_64(deserializer);
-    var var_totalRevenue = sse_decode_f_64(deserializer);
-    var var_grossProfit = sse_decode_f_64(deserializer);
-    var var_marginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
-    return ItemProfit(
-        totalCost: var_totalCost,
-        totalRevenue: var_totalRevenue,
-        grossProfit: var_grossProfit,
-        marginPercent: var_marginPercent);
-  }
-
-  @protected
-  List<CalendarEvent> sse_decode_list_calendar_event(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <CalendarEvent>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_calendar_event(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<Order> sse_decode_list_order(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Order>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_order(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<OrderItem> sse_decode_list_order_item(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OrderItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_order_item(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<OrderProfitInfo> sse_decode_list_order_profit_info(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <OrderProfitInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_order_profit_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<Port> sse_decode_list_port(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Port>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_port(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<Ship> sse_decode_list_ship(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Ship>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ship(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<ShipVisit> sse_decode_list_ship_visit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ShipVisit>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_ship_visit(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<Stock> sse_decode_list_stock(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Stock>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_stock(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<StockMovement> sse_decode_list_stock_movement(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <StockMovement>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_stock_movement(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<Supplier> sse_decode_list_supplier(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Supplier>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_supplier(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<SupplyItem> sse_decode_list_supply_item(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SupplyItem>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_supply_item(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_bool(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  DeliveryType? sse_decode_opt_box_autoadd_delivery_type(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_delivery_type(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  OrderStatus? sse_decode_opt_box_autoadd_order_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_order_status(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  OrderWithItems? sse_decode_opt_box_autoadd_order_with_items(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_order_with_items(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Port? sse_decode_opt_box_autoadd_port(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_port(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Ship? sse_decode_opt_box_autoadd_ship(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_ship(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ShipVisit? sse_decode_opt_box_autoadd_ship_visit(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_ship_visit(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Stock? sse_decode_opt_box_autoadd_stock(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_stock(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  StockWithMovements? sse_decode_opt_box_autoadd_stock_with_movements(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_stock_with_movements(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Supplier? sse_decode_opt_box_autoadd_supplier(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_supplier(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  SupplyItem? sse_decode_opt_box_autoadd_supply_item(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_supply_item(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  VisitStatus? sse_decode_opt_box_autoadd_visit_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_visit_status(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Order sse_decode_order(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_orderNumber = sse_decode_String(deserializer);
-    var var_shipId = sse_decode_i_32(deserializer);
-    var var_shipName = sse_decode_opt_String(deserializer);
-    var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_shipVisitInfo = sse_decode_opt_String(deserializer);
-    var var_status = sse_decode_order_status(deserializer);
-    var var_deliveryPort = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    return Order(
-        id: var_id,
-        orderNumber: var_orderNumber,
-        shipId: var_shipId,
-        shipName: var_shipName,
-        shipVisitId: var_shipVisitId,
-        shipVisitInfo: var_shipVisitInfo,
-        status: var_status,
-        deliveryPort: var_deliveryPort,
-        notes: var_notes,
-        currency: var_currency,
-        createdAt: var_createdAt,
-        updatedAt: var_updatedAt);
-  }
-
-  @protected
-  OrderItem sse_decode_order_item(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_orderId = sse_decode_i_32(deserializer);
-    var var_productName = sse_decode_String(deserializer);
-    var var_impaCode = sse_decode_opt_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_quantity = sse_decode_f_64(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_buyingPrice = sse_decode_f_64(deserializer);
-    var var_sellingPrice = sse_decode_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    var var_deliveryType = sse_decode_delivery_type(deserializer);
-    var var_warehouseDeliveryDate = sse_decode_opt_String(deserializer);
-    var var_shipDeliveryDate = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    return OrderItem(
-        id: var_id,
-        orderId: var_orderId,
-        productName: var_productName,
-        impaCode: var_impaCode,
-        description: var_description,
-        quantity: var_quantity,
-        unit: var_unit,
-        buyingPrice: var_buyingPrice,
-        sellingPrice: var_sellingPrice,
-        currency: var_currency,
-        deliveryType: var_deliveryType,
-        warehouseDeliveryDate: var_warehouseDeliveryDate,
-        shipDeliveryDate: var_shipDeliveryDate,
-        notes: var_notes);
-  }
-
-  @protected
-  OrderProfitInfo sse_decode_order_profit_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_orderId = sse_decode_i_32(deserializer);
-    var var_orderNumber = sse_decode_String(deserializer);
-    var var_shipName = sse_decode_String(deserializer);
-    var var_totalRevenue = sse_decode_f_64(deserializer);
-    var var_totalCost = sse_decode_f_64(deserializer);
-    var var_profit = sse_decode_f_64(deserializer);
-    var var_marginPercent = sse_decode_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    return OrderProfitInfo(
-        orderId: var_orderId,
-        orderNumber: var_orderNumber,
-        shipName: var_shipName,
-        totalRevenue: var_totalRevenue,
-        totalCost: var_totalCost,
-        profit: var_profit,
-        marginPercent: var_marginPercent,
-        currency: var_currency);
-  }
-
-  @protected
-  OrderStatus sse_decode_order_status(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return OrderStatus.values[inner];
-  }
-
-  @protected
-  OrderTotals sse_decode_order_totals(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_itemCount = sse_decode_i_32(deserializer);
-    var var_totalCost = sse_decode_f_64(deserializer);
-    var var_totalRevenue = sse_decode_f_64(deserializer);
-    var var_grossProfit = sse_decode_f_64(deserializer);
-    var var_marginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    return OrderTotals(
-        itemCount: var_itemCount,
-        totalCost: var_totalCost,
-        totalRevenue: var_totalRevenue,
-        grossProfit: var_grossProfit,
-        marginPercent: var_marginPercent,
-        currency: var_currency);
-  }
-
-  @protected
-  OrderWithItems sse_decode_order_with_items(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_order = sse_decode_order(deserializer);
-    var var_items = sse_decode_list_order_item(deserializer);
-    var var_totals = sse_decode_order_totals(deserializer);
-    return OrderWithItems(
-        order: var_order, items: var_items, totals: var_totals);
-  }
-
-  @protected
-  Port sse_decode_port(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_country = sse_decode_String(deserializer);
-    var var_city = sse_decode_opt_String(deserializer);
-    var var_timezone = sse_decode_String(deserializer);
-    var var_latitude = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_longitude = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    var var_isActive = sse_decode_bool(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    return Port(
-        id: var_id,
-        name: var_name,
-        country: var_country,
-        city: var_city,
-        timezone: var_timezone,
-        latitude: var_latitude,
-        longitude: var_longitude,
-        notes: var_notes,
-        isActive: var_isActive,
-        createdAt: var_createdAt,
-        updatedAt: var_updatedAt);
-  }
-
-  @protected
-  ProfitSummary sse_decode_profit_summary(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalOrders = sse_decode_i_32(deserializer);
-    var var_totalRevenue = sse_decode_f_64(deserializer);
-    var var_totalCost = sse_decode_f_64(deserializer);
-    var var_totalProfit = sse_decode_f_64(deserializer);
-    var var_averageMargin = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    return ProfitSummary(
-        totalOrders: var_totalOrders,
-        totalRevenue: var_totalRevenue,
-        totalCost: var_totalCost,
-        totalProfit: var_totalProfit,
-        averageMargin: var_averageMargin,
-        currency: var_currency);
-  }
-
-  @protected
-  Ship sse_decode_ship(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_imoNumber = sse_decode_String(deserializer);
-    var var_flag = sse_decode_String(deserializer);
-    var var_shipType = sse_decode_opt_String(deserializer);
-    var var_grossTonnage = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_owner = sse_decode_opt_String(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    return Ship(
-        id: var_id,
-        name: var_name,
-        imoNumber: var_imoNumber,
-        flag: var_flag,
-        shipType: var_shipType,
-        grossTonnage: var_grossTonnage,
-        owner: var_owner,
-        createdAt: var_createdAt,
-        updatedAt: var_updatedAt);
-  }
-
-  @protected
-  ShipVisit sse_decode_ship_visit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_shipId = sse_decode_i_32(deserializer);
-    var var_shipName = sse_decode_opt_String(deserializer);
-    var var_portId = sse_decode_i_32(deserializer);
-    var var_portName = sse_decode_opt_String(deserializer);
-    var var_eta = sse_decode_String(deserializer);
-    var var_etd = sse_decode_String(deserializer);
-    var var_ata = sse_decode_opt_String(deserializer);
-    var var_atd = sse_decode_opt_String(deserializer);
-    var var_status = sse_decode_visit_status(deserializer);
-    var var_agentInfo = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    return ShipVisit(
-        id: var_id,
-        shipId: var_shipId,
-        shipName: var_shipName,
-        portId: var_portId,
-        portName: var_portName,
-        eta: var_eta,
-        etd: var_etd,
-        ata: var_ata,
-        atd: var_atd,
-        status: var_status,
-        agentInfo: var_agentInfo,
-        notes: var_notes,
-        createdAt: var_createdAt,
-        updatedAt: var_updatedAt);
-  }
-
-  @protected
-  Stock sse_decode_stock(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_supplyItemId = sse_decode_i_32(deserializer);
-    var var_supplyItemName = sse_decode_opt_String(deserializer);
-    var var_quantity = sse_decode_f_64(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_warehouseLocation = sse_decode_opt_String(deserializer);
-    var var_minimumQuantity = sse_decode_f_64(deserializer);
-    var var_lastUpdated = sse_decode_String(deserializer);
-    return Stock(
-        id: var_id,
-        supplyItemId: var_supplyItemId,
-        supplyItemName: var_supplyItemName,
-        quantity: var_quantity,
-        unit: var_unit,
-        warehouseLocation: var_warehouseLocation,
-        minimumQuantity: var_minimumQuantity,
-        lastUpdated: var_lastUpdated);
-  }
-
-  @protected
-  StockMovement sse_decode_stock_movement(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_stockId = sse_decode_i_32(deserializer);
-    var var_supplyItemName = sse_decode_opt_String(deserializer);
-    var var_movementType = sse_decode_stock_movement_type(deserializer);
-    var var_quantity = sse_decode_f_64(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_referenceType = sse_decode_opt_String(deserializer);
-    var var_referenceId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_referenceInfo = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    return StockMovement(
-        id: var_id,
-        stockId: var_stockId,
-        supplyItemName: var_supplyItemName,
-        movementType: var_movementType,
-        quantity: var_quantity,
-        unit: var_unit,
-        referenceType: var_referenceType,
-        referenceId: var_referenceId,
-        referenceInfo: var_referenceInfo,
-        notes: var_notes,
-        createdAt: var_createdAt);
-  }
-
-  @protected
-  StockMovementType sse_decode_stock_movement_type(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return StockMovementType.values[inner];
-  }
-
-  @protected
-  StockSummary sse_decode_stock_summary(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_totalItems = sse_decode_i_32(deserializer);
-    var var_lowStockCount = sse_decode_i_32(deserializer);
-    var var_outOfStockCount = sse_decode_i_32(deserializer);
-    var var_totalValue = sse_decode_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    return StockSummary(
-        totalItems: var_totalItems,
-        lowStockCount: var_lowStockCount,
-        outOfStockCount: var_outOfStockCount,
-        totalValue: var_totalValue,
-        currency: var_currency);
-  }
-
-  @protected
-  StockWithMovements sse_decode_stock_with_movements(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_stock = sse_decode_stock(deserializer);
-    var var_movements = sse_decode_list_stock_movement(deserializer);
-    return StockWithMovements(stock: var_stock, movements: var_movements);
-  }
-
-  @protected
-  Supplier sse_decode_supplier(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_contactPerson = sse_decode_opt_String(deserializer);
-    var var_email = sse_decode_opt_String(deserializer);
-    var var_phone = sse_decode_opt_String(deserializer);
-    var var_address = sse_decode_opt_String(deserializer);
-    var var_country = sse_decode_opt_String(deserializer);
-    var var_category = sse_decode_String(deserializer);
-    var var_isActive = sse_decode_bool(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    return Supplier(
-        id: var_id,
-        name: var_name,
-        contactPerson: var_contactPerson,
-        email: var_email,
-        phone: var_phone,
-        address: var_address,
-        country: var_country,
-        category: var_category,
-        isActive: var_isActive,
-        createdAt: var_createdAt,
-        updatedAt: var_updatedAt);
-  }
-
-  @protected
-  SupplyItem sse_decode_supply_item(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_i_32(deserializer);
-    var var_supplierId = sse_decode_i_32(deserializer);
-    var var_supplierName = sse_decode_opt_String(deserializer);
-    var var_impaCode = sse_decode_opt_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_category = sse_decode_String(deserializer);
-    var var_unit = sse_decode_String(deserializer);
-    var var_unitPrice = sse_decode_f_64(deserializer);
-    var var_currency = sse_decode_String(deserializer);
-    var var_minimumOrderQuantity =
-        sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_isAvailable = sse_decode_bool(deserializer);
-    var var_createdAt = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_String(deserializer);
-    return SupplyItem(
-        id: var_id,
-        supplierId: var_supplierId,
-        supplierName: var_supplierName,
-        impaCode: var_impaCode,
-        name: var_name,
-        description: var_description,
-        category: var_category,
-        unit: var_unit,
-        unitPrice: var_unitPrice,
-        currency: var_currency,
-        minimumOrderQuantity: var_minimumOrderQuantity,
-        isAvailable: var_isAvailable,
-        createdAt: var_createdAt,
-        updatedAt: var_updatedAt);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  UpdateOrderItemRequest sse_decode_update_order_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_productName = sse_decode_opt_String(deserializer);
-    var var_impaCode = sse_decode_opt_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_quantity = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_unit = sse_decode_opt_String(deserializer);
-    var var_buyingPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_sellingPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_deliveryType =
-        sse_decode_opt_box_autoadd_delivery_type(deserializer);
-    var var_warehouseDeliveryDate = sse_decode_opt_String(deserializer);
-    var var_shipDeliveryDate = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    return UpdateOrderItemRequest(
-        productName: var_productName,
-        impaCode: var_impaCode,
-        description: var_description,
-        quantity: var_quantity,
-        unit: var_unit,
-        buyingPrice: var_buyingPrice,
-        sellingPrice: var_sellingPrice,
-        deliveryType: var_deliveryType,
-        warehouseDeliveryDate: var_warehouseDeliveryDate,
-        shipDeliveryDate: var_shipDeliveryDate,
-        notes: var_notes);
-  }
-
-  @protected
-  UpdateOrderRequest sse_decode_update_order_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_shipId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_deliveryPort = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    var var_currency = sse_decode_opt_String(deserializer);
-    return UpdateOrderRequest(
-        shipId: var_shipId,
-        shipVisitId: var_shipVisitId,
-        deliveryPort: var_deliveryPort,
-        notes: var_notes,
-        currency: var_currency);
-  }
-
-  @protected
-  UpdatePortRequest sse_decode_update_port_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_country = sse_decode_opt_String(deserializer);
-    var var_city = sse_decode_opt_String(deserializer);
-    var var_timezone = sse_decode_opt_String(deserializer);
-    var var_latitude = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_longitude = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    var var_isActive = sse_decode_opt_box_autoadd_bool(deserializer);
-    return UpdatePortRequest(
-        name: var_name,
-        country: var_country,
-        city: var_city,
-        timezone: var_timezone,
-        latitude: var_latitude,
-        longitude: var_longitude,
-        notes: var_notes,
-        isActive: var_isActive);
-  }
-
-  @protected
-  UpdateShipRequest sse_decode_update_ship_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_imoNumber = sse_decode_opt_String(deserializer);
-    var var_flag = sse_decode_opt_String(deserializer);
-    var var_shipType = sse_decode_opt_String(deserializer);
-    var var_grossTonnage = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_owner = sse_decode_opt_String(deserializer);
-    return UpdateShipRequest(
-        name: var_name,
-        imoNumber: var_imoNumber,
-        flag: var_flag,
-        shipType: var_shipType,
-        grossTonnage: var_grossTonnage,
-        owner: var_owner);
-  }
-
-  @protected
-  UpdateShipVisitRequest sse_decode_update_ship_visit_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_portId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_eta = sse_decode_opt_String(deserializer);
-    var var_etd = sse_decode_opt_String(deserializer);
-    var var_ata = sse_decode_opt_String(deserializer);
-    var var_atd = sse_decode_opt_String(deserializer);
-    var var_status = sse_decode_opt_box_autoadd_visit_status(deserializer);
-    var var_agentInfo = sse_decode_opt_String(deserializer);
-    var var_notes = sse_decode_opt_String(deserializer);
-    return UpdateShipVisitRequest(
-        portId: var_portId,
-        eta: var_eta,
-        etd: var_etd,
-        ata: var_ata,
-        atd: var_atd,
-        status: var_status,
-        agentInfo: var_agentInfo,
-        notes: var_notes);
-  }
-
-  @protected
-  UpdateStockRequest sse_decode_update_stock_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_quantity = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_warehouseLocation = sse_decode_opt_String(deserializer);
-    var var_minimumQuantity = sse_decode_opt_box_autoadd_f_64(deserializer);
-    return UpdateStockRequest(
-        quantity: var_quantity,
-        warehouseLocation: var_warehouseLocation,
-        minimumQuantity: var_minimumQuantity);
-  }
-
-  @protected
-  UpdateSupplierRequest sse_decode_update_supplier_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_contactPerson = sse_decode_opt_String(deserializer);
-    var var_email = sse_decode_opt_String(deserializer);
-    var var_phone = sse_decode_opt_String(deserializer);
-    var var_address = sse_decode_opt_String(deserializer);
-    var var_country = sse_decode_opt_String(deserializer);
-    var var_category = sse_decode_opt_String(deserializer);
-    return UpdateSupplierRequest(
-        name: var_name,
-        contactPerson: var_contactPerson,
-        email: var_email,
-        phone: var_phone,
-        address: var_address,
-        country: var_country,
-        category: var_category);
-  }
-
-  @protected
-  UpdateSupplyItemRequest sse_decode_update_supply_item_request(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_supplierId = sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_impaCode = sse_decode_opt_String(deserializer);
-    var var_name = sse_decode_opt_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_category = sse_decode_opt_String(deserializer);
-    var var_unit = sse_decode_opt_String(deserializer);
-    var var_unitPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
-    var var_currency = sse_decode_opt_String(deserializer);
-    var var_minimumOrderQuantity =
-        sse_decode_opt_box_autoadd_i_32(deserializer);
-    var var_isAvailable = sse_decode_opt_box_autoadd_bool(deserializer);
-    return UpdateSupplyItemRequest(
-        supplierId: var_supplierId,
-        impaCode: var_impaCode,
-        name: var_name,
-        description: var_description,
-        category: var_category,
-        unit: var_unit,
-        unitPrice: var_unitPrice,
-        currency: var_currency,
-        minimumOrderQuantity: var_minimumOrderQuantity,
-        isAvailable: var_isAvailable);
-  }
-
-  @protected
-  VisitStatus sse_decode_visit_status(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return VisitStatus.values[inner];
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_order_item_request(
-      CreateOrderItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_order_item_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_order_request(
-      CreateOrderRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_order_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_port_request(
-      CreatePortRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_port_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_ship_request(
-      CreateShipRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_ship_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_ship_visit_request(
-      CreateShipVisitRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_ship_visit_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_stock_movement_request(
-      CreateStockMovementRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_stock_movement_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_stock_request(
-      CreateStockRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_stock_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_supplier_request(
-      CreateSupplierRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_supplier_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_create_supply_item_request(
-      CreateSupplyItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_create_supply_item_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_delivery_type(
-      DeliveryType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_delivery_type(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_order_status(
-      OrderStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_order_status(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_order_with_items(
-      OrderWithItems self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_order_with_items(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_port(Port self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_port(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_ship(Ship self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ship(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_ship_visit(
-      ShipVisit self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_ship_visit(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_stock(Stock self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_stock(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_stock_with_movements(
-      StockWithMovements self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_stock_with_movements(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_supplier(
-      Supplier self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_supplier(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_supply_item(
-      SupplyItem self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_supply_item(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_order_item_request(
-      UpdateOrderItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_order_item_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_order_request(
-      UpdateOrderRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_order_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_port_request(
-      UpdatePortRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_port_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_ship_request(
-      UpdateShipRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_ship_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_ship_visit_request(
-      UpdateShipVisitRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_ship_visit_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_stock_request(
-      UpdateStockRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_stock_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_supplier_request(
-      UpdateSupplierRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_supplier_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_update_supply_item_request(
-      UpdateSupplyItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_update_supply_item_request(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_visit_status(
-      VisitStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_visit_status(self, serializer);
-  }
-
-  @protected
-  void sse_encode_calendar_data(CalendarData self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_calendar_event(self.events, serializer);
-    sse_encode_list_port(self.ports, serializer);
-  }
-
-  @protected
-  void sse_encode_calendar_event(CalendarEvent self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_calendar_event_type(self.eventType, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_opt_String(self.subtitle, serializer);
-    sse_encode_String(self.startDate, serializer);
-    sse_encode_String(self.endDate, serializer);
-    sse_encode_String(self.color, serializer);
-    sse_encode_String(self.status, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.relatedShipId, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.relatedPortId, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.relatedOrderId, serializer);
-    sse_encode_opt_String(self.metadata, serializer);
-  }
-
-  @protected
-  void sse_encode_calendar_event_type(
-      CalendarEventType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_create_order_item_request(
-      CreateOrderItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.orderId, serializer);
-    sse_encode_String(self.productName, serializer);
-    sse_encode_opt_String(self.impaCode, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_f_64(self.quantity, serializer);
-    sse_encode_String(self.unit, serializer);
-    sse_encode_f_64(self.buyingPrice, serializer);
-    sse_encode_f_64(self.sellingPrice, serializer);
-    sse_encode_String(self.currency, serializer);
-    sse_encode_delivery_type(self.deliveryType, serializer);
-    sse_encode_opt_String(self.warehouseDeliveryDate, serializer);
-    sse_encode_opt_String(self.shipDeliveryDate, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_create_order_request(
-      CreateOrderRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.shipId, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
-    sse_encode_opt_String(self.deliveryPort, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-    sse_encode_String(self.currency, serializer);
-  }
-
-  @protected
-  void sse_encode_create_port_request(
-      CreatePortRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.country, serializer);
-    sse_encode_opt_String(self.city, serializer);
-    sse_encode_String(self.timezone, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.latitude, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.longitude, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_create_ship_request(
-      CreateShipRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.imoNumber, serializer);
-    sse_encode_String(self.flag, serializer);
-    sse_encode_opt_String(self.shipType, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.grossTonnage, serializer);
-    sse_encode_opt_String(self.owner, serializer);
-  }
-
-  @protected
-  void sse_encode_create_ship_visit_request(
-      CreateShipVisitRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.shipId, serializer);
-    sse_encode_i_32(self.portId, serializer);
-    sse_encode_String(self.eta, serializer);
-    sse_encode_String(self.etd, serializer);
-    sse_encode_opt_String(self.agentInfo, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_create_stock_movement_request(
-      CreateStockMovementRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.stockId, serializer);
-    sse_encode_stock_movement_type(self.movementType, serializer);
-    sse_encode_f_64(self.quantity, serializer);
-    sse_encode_opt_String(self.referenceType, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.referenceId, serializer);
-    sse_encode_opt_String(self.referenceInfo, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_create_stock_request(
-      CreateStockRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.supplyItemId, serializer);
-    sse_encode_f_64(self.quantity, serializer);
-    sse_encode_String(self.unit, serializer);
-    sse_encode_opt_String(self.warehouseLocation, serializer);
-    sse_encode_f_64(self.minimumQuantity, serializer);
-  }
-
-  @protected
-  void sse_encode_create_supplier_request(
-      CreateSupplierRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.contactPerson, serializer);
-    sse_encode_opt_String(self.email, serializer);
-    sse_encode_opt_String(self.phone, serializer);
-    sse_encode_opt_String(self.address, serializer);
-    sse_encode_opt_String(self.country, serializer);
-    sse_encode_String(self.category, serializer);
-  }
-
-  @protected
-  void sse_encode_create_supply_item_request(
-      CreateSupplyItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.supplierId, serializer);
-    sse_encode_opt_String(self.impaCode, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_String(self.category, serializer);
-    sse_encode_String(self.unit, serializer);
-    sse_encode_f_64(self.unitPrice, serializer);
-    sse_encode_String(self.currency, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.minimumOrderQuantity, serializer);
-  }
-
-  @protected
-  void sse_encode_delivery_type(DeliveryType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_item_profit(ItemProfit self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self.totalCost, serializer);
-    sse_encode_f_64(self.totalRevenue, serializer);
-    sse_encode_f_64(self.grossProfit, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.marginPercent, serializer);
-  }
-
-  @protected
-  void sse_encode_list_calendar_event(
-      List<CalendarEvent> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_calendar_event(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_order(List<Order> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_order(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_order_item(
-      List<OrderItem> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_order_item(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_order_profit_info(
-      List<OrderProfitInfo> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_order_profit_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_port(List<Port> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_port(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_ship(List<Ship> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ship(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_ship_visit(
-      List<ShipVisit> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_ship_visit(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_stock(List<Stock> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_stock(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_stock_movement(
-      List<StockMovement> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_stock_movement(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_supplier(List<Supplier> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_supplier(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_supply_item(
-      List<SupplyItem> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_supply_item(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_bool(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_delivery_type(
-      DeliveryType? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_delivery_type(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_order_status(
-      OrderStatus? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_order_status(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_order_with_items(
-      OrderWithItems? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_order_with_items(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_port(Port? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_port(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_ship(Ship? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_ship(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_ship_visit(
-      ShipVisit? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_ship_visit(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_stock(Stock? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_stock(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_stock_with_movements(
-      StockWithMovements? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_stock_with_movements(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_supplier(
-      Supplier? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_supplier(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_supply_item(
-      SupplyItem? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_supply_item(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_visit_status(
-      VisitStatus? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_visit_status(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_order(Order self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.orderNumber, serializer);
-    sse_encode_i_32(self.shipId, serializer);
-    sse_encode_opt_String(self.shipName, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
-    sse_encode_opt_String(self.shipVisitInfo, serializer);
-    sse_encode_order_status(self.status, serializer);
-    sse_encode_opt_String(self.deliveryPort, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-    sse_encode_String(self.currency, serializer);
-    sse_encode_String(self.createdAt, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_order_item(OrderItem self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_i_32(self.orderId, serializer);
-    sse_encode_String(self.productName, serializer);
-    sse_encode_opt_String(self.impaCode, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_f_64(self.quantity, serializer);
-    sse_encode_String(self.unit, serializer);
-    sse_encode_f_64(self.buyingPrice, serializer);
-    sse_encode_f_64(self.sellingPrice, serializer);
-    sse_encode_String(self.currency, serializer);
-    sse_encode_delivery_type(self.deliveryType, serializer);
-    sse_encode_opt_String(self.warehouseDeliveryDate, serializer);
-    sse_encode_opt_String(self.shipDeliveryDate, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_order_profit_info(
-      OrderProfitInfo self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.orderId, serializer);
-    sse_encode_String(self.orderNumber, serializer);
-    sse_encode_String(self.shipName, serializer);
-    sse_encode_f_64(self.totalRevenue, serializer);
-    sse_encode_f_64(self.totalCost, serializer);
-    sse_encode_f_64(self.profit, serializer);
-    sse_encode_f_64(self.marginPercent, serializer);
-    sse_encode_String(self.currency, serializer);
-  }
-
-  @protected
-  void sse_encode_order_status(OrderStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_order_totals(OrderTotals self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.itemCount, serializer);
-    sse_encode_f_64(self.totalCost, serializer);
-    sse_encode_f_64(self.totalRevenue, serializer);
-    sse_encode_f_64(self.grossProfit, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.marginPercent, serializer);
-    sse_encode_String(self.currency, serializer);
-  }
-
-  @protected
-  void sse_encode_order_with_items(
-      OrderWithItems self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_order(self.order, serializer);
-    sse_encode_list_order_item(self.items, serializer);
-    sse_encode_order_totals(self.totals, serializer);
-  }
-
-  @protected
-  void sse_encode_port(Port self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.country, serializer);
-    sse_encode_opt_String(self.city, serializer);
-    sse_encode_String(self.timezone, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.latitude, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.longitude, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-    sse_encode_bool(self.isActive, serializer);
-    sse_encode_String(self.createdAt, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_profit_summary(ProfitSummary self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.totalOrders, serializer);
-    sse_encode_f_64(self.totalRevenue, serializer);
-    sse_encode_f_64(self.totalCost, serializer);
-    sse_encode_f_64(self.totalProfit, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.averageMargin, serializer);
-    sse_encode_String(self.currency, serializer);
-  }
-
-  @protected
-  void sse_encode_ship(Ship self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.imoNumber, serializer);
-    sse_encode_String(self.flag, serializer);
-    sse_encode_opt_String(self.shipType, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.grossTonnage, serializer);
-    sse_encode_opt_String(self.owner, serializer);
-    sse_encode_String(self.createdAt, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_ship_visit(ShipVisit self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_i_32(self.shipId, serializer);
-    sse_encode_opt_String(self.shipName, serializer);
-    sse_encode_i_32(self.portId, serializer);
-    sse_encode_opt_String(self.portName, serializer);
-    sse_encode_String(self.eta, serializer);
-    sse_encode_String(self.etd, serializer);
-    sse_encode_opt_String(self.ata, serializer);
-    sse_encode_opt_String(self.atd, serializer);
-    sse_encode_visit_status(self.status, serializer);
-    sse_encode_opt_String(self.agentInfo, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-    sse_encode_String(self.createdAt, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_stock(Stock self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_i_32(self.supplyItemId, serializer);
-    sse_encode_opt_String(self.supplyItemName, serializer);
-    sse_encode_f_64(self.quantity, serializer);
-    sse_encode_String(self.unit, serializer);
-    sse_encode_opt_String(self.warehouseLocation, serializer);
-    sse_encode_f_64(self.minimumQuantity, serializer);
-    sse_encode_String(self.lastUpdated, serializer);
-  }
-
-  @protected
-  void sse_encode_stock_movement(StockMovement self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_i_32(self.stockId, serializer);
-    sse_encode_opt_String(self.supplyItemName, serializer);
-    sse_encode_stock_movement_type(self.movementType, serializer);
-    sse_encode_f_64(self.quantity, serializer);
-    sse_encode_String(self.unit, serializer);
-    sse_encode_opt_String(self.referenceType, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.referenceId, serializer);
-    sse_encode_opt_String(self.referenceInfo, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-    sse_encode_String(self.createdAt, serializer);
-  }
-
-  @protected
-  void sse_encode_stock_movement_type(
-      StockMovementType self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_stock_summary(StockSummary self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.totalItems, serializer);
-    sse_encode_i_32(self.lowStockCount, serializer);
-    sse_encode_i_32(self.outOfStockCount, serializer);
-    sse_encode_f_64(self.totalValue, serializer);
-    sse_encode_String(self.currency, serializer);
-  }
-
-  @protected
-  void sse_encode_stock_with_movements(
-      StockWithMovements self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_stock(self.stock, serializer);
-    sse_encode_list_stock_movement(self.movements, serializer);
-  }
-
-  @protected
-  void sse_encode_supplier(Supplier self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.contactPerson, serializer);
-    sse_encode_opt_String(self.email, serializer);
-    sse_encode_opt_String(self.phone, serializer);
-    sse_encode_opt_String(self.address, serializer);
-    sse_encode_opt_String(self.country, serializer);
-    sse_encode_String(self.category, serializer);
-    sse_encode_bool(self.isActive, serializer);
-    sse_encode_String(self.createdAt, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_supply_item(SupplyItem self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.id, serializer);
-    sse_encode_i_32(self.supplierId, serializer);
-    sse_encode_opt_String(self.supplierName, serializer);
-    sse_encode_opt_String(self.impaCode, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_String(self.category, serializer);
-    sse_encode_String(self.unit, serializer);
-    sse_encode_f_64(self.unitPrice, serializer);
-    sse_encode_String(self.currency, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.minimumOrderQuantity, serializer);
-    sse_encode_bool(self.isAvailable, serializer);
-    sse_encode_String(self.createdAt, serializer);
-    sse_encode_String(self.updatedAt, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_update_order_item_request(
-      UpdateOrderItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.productName, serializer);
-    sse_encode_opt_String(self.impaCode, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.quantity, serializer);
-    sse_encode_opt_String(self.unit, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.buyingPrice, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.sellingPrice, serializer);
-    sse_encode_opt_box_autoadd_delivery_type(self.deliveryType, serializer);
-    sse_encode_opt_String(self.warehouseDeliveryDate, serializer);
-    sse_encode_opt_String(self.shipDeliveryDate, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_update_order_request(
-      UpdateOrderRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_i_32(self.shipId, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
-    sse_encode_opt_String(self.deliveryPort, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-    sse_encode_opt_String(self.currency, serializer);
-  }
-
-  @protected
-  void sse_encode_update_port_request(
-      UpdatePortRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.name, serializer);
-    sse_encode_opt_String(self.country, serializer);
-    sse_encode_opt_String(self.city, serializer);
-    sse_encode_opt_String(self.timezone, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.latitude, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.longitude, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-    sse_encode_opt_box_autoadd_bool(self.isActive, serializer);
-  }
-
-  @protected
-  void sse_encode_update_ship_request(
-      UpdateShipRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.name, serializer);
-    sse_encode_opt_String(self.imoNumber, serializer);
-    sse_encode_opt_String(self.flag, serializer);
-    sse_encode_opt_String(self.shipType, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.grossTonnage, serializer);
-    sse_encode_opt_String(self.owner, serializer);
-  }
-
-  @protected
-  void sse_encode_update_ship_visit_request(
-      UpdateShipVisitRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_i_32(self.portId, serializer);
-    sse_encode_opt_String(self.eta, serializer);
-    sse_encode_opt_String(self.etd, serializer);
-    sse_encode_opt_String(self.ata, serializer);
-    sse_encode_opt_String(self.atd, serializer);
-    sse_encode_opt_box_autoadd_visit_status(self.status, serializer);
-    sse_encode_opt_String(self.agentInfo, serializer);
-    sse_encode_opt_String(self.notes, serializer);
-  }
-
-  @protected
-  void sse_encode_update_stock_request(
-      UpdateStockRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_f_64(self.quantity, serializer);
-    sse_encode_opt_String(self.warehouseLocation, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.minimumQuantity, serializer);
-  }
-
-  @protected
-  void sse_encode_update_supplier_request(
-      UpdateSupplierRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.name, serializer);
-    sse_encode_opt_String(self.contactPerson, serializer);
-    sse_encode_opt_String(self.email, serializer);
-    sse_encode_opt_String(self.phone, serializer);
-    sse_encode_opt_String(self.address, serializer);
-    sse_encode_opt_String(self.country, serializer);
-    sse_encode_opt_String(self.category, serializer);
-  }
-
-  @protected
-  void sse_encode_update_supply_item_request(
-      UpdateSupplyItemRequest self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_i_32(self.supplierId, serializer);
-    sse_encode_opt_String(self.impaCode, serializer);
-    sse_encode_opt_String(self.name, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_opt_String(self.category, serializer);
-    sse_encode_opt_String(self.unit, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.unitPrice, serializer);
-    sse_encode_opt_String(self.currency, serializer);
-    sse_encode_opt_box_autoadd_i_32(self.minimumOrderQuantity, serializer);
-    sse_encode_opt_box_autoadd_bool(self.isAvailable, serializer);
-  }
-
-  @protected
-  void sse_encode_visit_status(VisitStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-}
+Future<List<Invoice>> crateApiGetAllInvoices({InvoiceStatus? statusFilter });
+
+Future<List<Order>> crateApiGetAllOrders({OrderStatus? statusFilter });
+
+Future<List<Payment>> crateApiGetAllPayments();
+
+Future<List<Port>> crateApiGetAllPorts();
+
+Future<List<RequisitionTemplate>> crateApiGetAllRequisitionTemplates();
+
+Future<List<ShipVisit>> crateApiGetAllShipVisits();
+
+Future<List<Ship>> crateApiGetAllShips();
+
+Future<List<Stock>> crateApiGetAllStock();
+
+Future<List<SupplierInvoice>> crateApiGetAllSupplierInvoices({InvoiceStatus? statusFilter });
+
+Future<List<Supplier>> crateApiGetAllSuppliers();
+
+Future<List<SupplyItem>> crateApiGetAllSupplyItems();
+
+Future<List<UnitOfMeasure>> crateApiGetAllUnits();
+
+Future<List<ProductAlternative>> crateApiGetAlternatives({required int supplyItemId });
+
+Future<Attachment?> crateApiGetAttachment({required int id });
+
+Future<Uint8List> crateApiGetAttachmentData({required int id });
+
+Future<Uint8List> crateApiGetAttachmentThumbnail({required int id });
+
+Future<List<Attachment>> crateApiGetAttachments({required AttachmentEntity entityType , required int entityId });
+
+Future<List<OrderItem>> crateApiGetBackOrderedItems({required int orderId });
+
+Future<BondedEligibility> crateApiGetBondedEligibility({required int orderId });
+
+Future<BondedReconciliation> crateApiGetBondedReconciliation({required String periodStart , required String periodEnd });
+
+Future<CalendarData> crateApiGetCalendarData({required String startDate , required String endDate });
+
+Future<Category?> crateApiGetCategoryById({required int id });
+
+Future<CompanyProfile> crateApiGetCompanyProfile();
+
+Future<ContractPrice?> crateApiGetContractPrice({required int orderId , required String impaCode , required double buyingPrice });
+
+Future<List<CurrencyRounding>> crateApiGetCurrencyRoundings();
+
+Future<List<CustomerBalance>> crateApiGetCustomerBalances({required int customerId });
+
+Future<Customer?> crateApiGetCustomerById({required int id });
+
+Future<CreditCheck> crateApiGetCustomerCreditStatus({required int customerId });
+
+Future<List<Ship>> crateApiGetCustomerShips({required int customerId });
+
+Future<DangerousGoodsSummary> crateApiGetDangerousGoodsSummary({required int deliveryNoteId });
+
+Future<DeliveryNote?> crateApiGetDeliveryNote({required int id });
+
+Future<Uint8List> crateApiGetDeliveryNoteAttachmentData({required int attachmentId });
+
+Future<List<DeliveryNote>> crateApiGetDeliveryNotes({required int orderId });
+
+Future<EquivalenceGroup?> crateApiGetEquivalenceGroup({required int id });
+
+Future<List<Order>> crateApiGetFollowUpOrders({required int orderId });
+
+Future<List<ImpaCodeIssue>> crateApiGetImpaCodeIssues();
+
+Future<List<ImpaSection>> crateApiGetImpaSections();
+
+Future<Invoice?> crateApiGetInvoiceById({required int id });
+
+Future<List<CreditNote>> crateApiGetInvoiceCreditNotes({required int invoiceId });
+
+Future<List<Invoice>> crateApiGetInvoicesByCustomer({required int customerId });
+
+Future<List<Invoice>> crateApiGetInvoicesByOrder({required int orderId });
+
+Future<List<LandedCost>> crateApiGetLandedCosts({required int orderId });
+
+Future<List<Stock>> crateApiGetLowStock();
+
+Future<List<OffContractLine>> crateApiGetOffContractLines({int? customerId });
+
+Future<List<OrderCharge>> crateApiGetOrderCharges({required int orderId });
+
+Future<OrderCompliance> crateApiGetOrderCompliance({required int orderId });
+
+Future<List<CreditNote>> crateApiGetOrderCreditNotes({required int orderId });
+
+Future<List<ItemDelivery>> crateApiGetOrderDeliveries({required int orderId });
+
+Future<List<OrderItem>> crateApiGetOrderItems({required int orderId });
+
+Future<List<OrderLink>> crateApiGetOrderLinks({required int orderId });
+
+Future<OrderReturn?> crateApiGetOrderReturn({required int id });
+
+Future<List<OrderReturn>> crateApiGetOrderReturns({required int orderId });
+
+Future<OrderTotals> crateApiGetOrderTotals({required int orderId });
+
+Future<OrderWithItems?> crateApiGetOrderWithItems({required int id });
+
+Future<List<Order>> crateApiGetOrdersByShipVisit({required int shipVisitId });
+
+Future<List<PackSize>> crateApiGetPackSizes({required int supplyItemId });
+
+Future<List<PayableDue>> crateApiGetPayablesDue({required int days });
+
+Future<Payment?> crateApiGetPaymentById({required int id });
+
+Future<List<Payment>> crateApiGetPaymentsByCustomer({required int customerId });
+
+Future<Port?> crateApiGetPortById({required int id });
+
+Future<List<Port>> crateApiGetPortsByCountry({required String country });
+
+Future<PriceAgreement?> crateApiGetPriceAgreementById({required int id });
+
+Future<List<PriceAgreement>> crateApiGetPriceAgreements({int? customerId });
+
+Future<List<PriceChange>> crateApiGetPriceHistory({required int supplyItemId });
+
+Future<List<PriceRiseItem>> crateApiGetPriceRiseReport({required String startDate , required String endDate , required double minRisePercent , int? supplierId });
+
+Future<List<PricingRule>> crateApiGetPricingRules();
+
+Future<ProfitSummary> crateApiGetProfitSummary();
+
+Future<List<ReceivablesAgingRow>> crateApiGetReceivablesAging({required String asOf });
+
+Future<List<StockMovement>> crateApiGetRecentStockMovements({required int limit });
+
+Future<RequisitionTemplate?> crateApiGetRequisitionTemplate({required int id });
+
+Future<List<RequisitionTemplate>> crateApiGetRequisitionTemplatesForShip({required int shipId });
+
+Future<Ship?> crateApiGetShipById({required int id });
+
+Future<PlatformInt64> crateApiGetShipCount();
+
+Future<ShipVisit?> crateApiGetShipVisitById({required int id });
+
+Future<List<ShipVisit>> crateApiGetShipVisitsByDateRange({required String startDate , required String endDate });
+
+Future<List<ShipVisit>> crateApiGetShipVisitsByPort({required int portId });
+
+Future<List<ShipVisit>> crateApiGetShipVisitsByShip({required int shipId });
+
+Future<Stock?> crateApiGetStockById({required int id });
+
+Future<Stock?> crateApiGetStockBySupplyItem({required int supplyItemId });
+
+Future<List<StockMovement>> crateApiGetStockMovements({required int stockId });
+
+Future<StockSummary> crateApiGetStockSummary();
+
+Future<StockWithMovements?> crateApiGetStockWithMovements({required int id });
+
+Future<Supplier?> crateApiGetSupplierById({required int id });
+
+Future<PlatformInt64> crateApiGetSupplierCount();
+
+Future<SupplierInvoice?> crateApiGetSupplierInvoiceById({required int id });
+
+Future<List<SupplierInvoice>> crateApiGetSupplierInvoicesByOrder({required int orderId });
+
+Future<List<SupplierInvoice>> crateApiGetSupplierInvoicesBySupplier({required int supplierId });
+
+Future<List<SupplierPayment>> crateApiGetSupplierPaymentsByInvoice({required int supplierInvoiceId });
+
+Future<List<SupplierPayment>> crateApiGetSupplierPaymentsBySupplier({required int supplierId });
+
+Future<List<Supplier>> crateApiGetSuppliersByCategory({required int categoryId });
+
+Future<SupplyItem?> crateApiGetSupplyItemById({required int id });
+
+Future<PlatformInt64> crateApiGetSupplyItemCount();
+
+Future<List<SupplyItem>> crateApiGetSupplyItemsByCategory({required int categoryId });
+
+Future<List<SupplyItem>> crateApiGetSupplyItemsBySupplier({required int supplierId });
+
+Future<List<OrderProfitInfo>> crateApiGetTopProfitableOrders({required int limit });
+
+Future<List<ShipVisit>> crateApiGetUpcomingShipVisits();
+
+Future<String> crateApiGetVersion();
+
+Future<String> crateApiGreet({required String name });
+
+Future<ImpaCatalogImportResult> crateApiImportImpaCatalog({required ImpaCatalogImportRequest request });
+
+Future<PriceListImportResult> crateApiImportSupplierPriceList({required PriceListImportRequest request });
+
+Future<void> crateApiInitDatabase({required String databaseUrl });
+
+Future<String> crateApiInitLocalDatabase();
+
+Future<OrderWithItems> crateApiInstantiateRequisitionTemplate({required InstantiateTemplateRequest request });
+
+Future<bool> crateApiIsDatabaseConnected();
+
+Future<CreditNote> crateApiIssueReturnCreditNote({required int returnId });
+
+Future<StockMovement> crateApiIssueStockToOrder({required int orderItemId , int? stockId , double? quantity , String? customsDeclarationNumber });
+
+Future<String> crateApiLoadSeedData();
+
+Future<ImpaCatalogEntry?> crateApiLookupImpaCode({required String code });
+
+Future<SupplierInvoiceMatch> crateApiMatchSupplierInvoice({required int id });
+
+Future<OrderWithItems> crateApiMergeOrders({required List<int> orderIds });
+
+Future<Category> crateApiMoveCategory({required int id , int? parentId });
+
+Future<RequisitionImportPreview> crateApiPreviewRequisitionImport({required RequisitionImportRequest request });
+
+Future<PriceListPreview> crateApiPreviewSupplierPriceList({required PriceListImportRequest request });
+
+Future<PriceProposal> crateApiProposeSellingPrice({required int orderId , String? impaCode , required double buyingPrice });
+
+Future<int> crateApiPruneAttachmentFiles();
+
+Future<OrderWithItems> crateApiRecordDelivery({required RecordDeliveryRequest delivery });
+
+Future<Payment> crateApiRecordPayment({required CreatePaymentRequest payment });
+
+Future<SupplierPayment> crateApiRecordSupplierPayment({required CreateSupplierPaymentRequest payment });
+
+Future<bool> crateApiRemoveFromEquivalenceGroup({required int supplyItemId });
+
+Future<PdfRenderResult> crateApiRenderOrderPdf({required PdfRenderRequest request });
+
+Future<RepriceResult> crateApiRepriceOrder({required int orderId });
+
+Future<Order> crateApiRollOverBackorders({required int orderId , required int shipVisitId });
+
+Future<List<SearchHit>> crateApiSearch({required String query , required List<SearchScope> scopes , required int limit });
+
+Future<List<Customer>> crateApiSearchCustomers({required String query });
+
+Future<List<ImpaCatalogEntry>> crateApiSearchImpaCatalog({required String query , String? section , required int limit });
+
+Future<List<Ship>> crateApiSearchShips({required String query });
+
+Future<List<Supplier>> crateApiSearchSuppliers({required String query });
+
+Future<List<SupplyItem>> crateApiSearchSupplyItems({required String query });
+
+Future<Attachment> crateApiSetAttachmentCaption({required int id , String? caption });
+
+Future<CurrencyRounding> crateApiSetCurrencyRounding({required String currency , required double increment });
+
+Future<ImpaSection> crateApiSetImpaSectionCategory({required String section , int? categoryId });
+
+Future<PackSize> crateApiSetPackSize({required int supplyItemId , required String packUnit , required double quantity , required String contentUnit });
+
+Future<SupplyItem> crateApiSetSupplyItemSds({required int supplyItemId , required String fileName , required String mimeType , required List<int> data });
+
+Future<OrderCompliance> crateApiSignOffPrescriptionItems({required int orderId , required PharmacistSignOffRequest signOff });
+
+Future<OrderWithItems> crateApiSplitOrder({required int orderId , required List<int> itemIds });
+
+Future<ProductAlternative?> crateApiSuggestOrderItemSource({required int orderId , int? supplyItemId , String? impaCode , required double quantity });
+
+Future<Category> crateApiUpdateCategory({required int id , required UpdateCategoryRequest request });
+
+Future<CompanyProfile> crateApiUpdateCompanyProfile({required UpdateCompanyProfileRequest profile });
+
+Future<Customer> crateApiUpdateCustomer({required int id , required UpdateCustomerRequest customer });
+
+Future<Order> crateApiUpdateOrder({required int id , required UpdateOrderRequest order });
+
+Future<OrderCharge> crateApiUpdateOrderCharge({required int id , required UpdateOrderChargeRequest charge });
+
+Future<OrderItem> crateApiUpdateOrderItem({required int id , required UpdateOrderItemRequest item });
+
+Future<Order> crateApiUpdateOrderStatus({required int id , required OrderStatus newStatus });
+
+Future<Port?> crateApiUpdatePort({required int id , required UpdatePortRequest port });
+
+Future<PriceAgreement> crateApiUpdatePriceAgreement({required int id , required UpdatePriceAgreementRequest agreement });
+
+Future<PricingRule> crateApiUpdatePricingRule({required int id , required UpdatePricingRuleRequest rule });
+
+Future<RequisitionTemplate> crateApiUpdateRequisitionTemplate({required int id , required UpdateRequisitionTemplateRequest template });
+
+Future<Ship> crateApiUpdateShip({required int id , required UpdateShipRequest ship });
+
+Future<ShipVisit?> crateApiUpdateShipVisit({required int id , required UpdateShipVisitRequest visit });
+
+Future<ShipVisit?> crateApiUpdateShipVisitStatus({required int id , required VisitStatus status });
+
+Future<Stock> crateApiUpdateStock({required int id , required UpdateStockRequest stock });
+
+Future<Supplier> crateApiUpdateSupplier({required int id , required UpdateSupplierRequest supplier });
+
+Future<SupplyItem> crateApiUpdateSupplyItem({required int id , required UpdateSupplyItemRequest item });
+
+Future<UnitOfMeasure> crateApiUpdateUnit({required int id , required UpdateUnitRequest request });
+
+Future<String> crateApiValidateImpaCode({required String code });
+
+Future<Order> crateApiWaiveDeliveryNote({required int orderId , required String reason });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<Attachment> crateApiAddAttachment({required CreateAttachmentRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_attachment_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_attachment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddAttachmentConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddAttachmentConstMeta => const TaskConstMeta(
+            debugName: "add_attachment",
+            argNames: ["request"],
+        );
+        
+
+@override Future<Customer> crateApiAddCustomerContact({required int customerId , required CreateCustomerContactRequest contact })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(customerId, serializer);
+sse_encode_box_autoadd_create_customer_contact_request(contact, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_customer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddCustomerContactConstMeta,
+            argValues: [customerId, contact],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddCustomerContactConstMeta => const TaskConstMeta(
+            debugName: "add_customer_contact",
+            argNames: ["customerId", "contact"],
+        );
+        
+
+@override Future<DeliveryNoteAttachment> crateApiAddDeliveryNoteAttachment({required int deliveryNoteId , required DeliveryNoteAttachmentKind kind , required String fileName , required String mimeType , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(deliveryNoteId, serializer);
+sse_encode_delivery_note_attachment_kind(kind, serializer);
+sse_encode_String(fileName, serializer);
+sse_encode_String(mimeType, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_delivery_note_attachment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddDeliveryNoteAttachmentConstMeta,
+            argValues: [deliveryNoteId, kind, fileName, mimeType, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddDeliveryNoteAttachmentConstMeta => const TaskConstMeta(
+            debugName: "add_delivery_note_attachment",
+            argNames: ["deliveryNoteId", "kind", "fileName", "mimeType", "data"],
+        );
+        
+
+@override Future<OrderCharge> crateApiAddOrderCharge({required CreateOrderChargeRequest charge })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_order_charge_request(charge, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_charge,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddOrderChargeConstMeta,
+            argValues: [charge],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddOrderChargeConstMeta => const TaskConstMeta(
+            debugName: "add_order_charge",
+            argNames: ["charge"],
+        );
+        
+
+@override Future<OrderWithItems> crateApiAddOrderItem({required CreateOrderItemRequest item })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_order_item_request(item, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddOrderItemConstMeta,
+            argValues: [item],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddOrderItemConstMeta => const TaskConstMeta(
+            debugName: "add_order_item",
+            argNames: ["item"],
+        );
+        
+
+@override Future<PriceAgreement> crateApiAddPriceAgreementLine({required int agreementId , required CreatePriceAgreementLineRequest line })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(agreementId, serializer);
+sse_encode_box_autoadd_create_price_agreement_line_request(line, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_agreement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddPriceAgreementLineConstMeta,
+            argValues: [agreementId, line],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddPriceAgreementLineConstMeta => const TaskConstMeta(
+            debugName: "add_price_agreement_line",
+            argNames: ["agreementId", "line"],
+        );
+        
+
+@override Future<EquivalenceGroup> crateApiAddToEquivalenceGroup({required int groupId , required int supplyItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(groupId, serializer);
+sse_encode_i_32(supplyItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_equivalence_group,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAddToEquivalenceGroupConstMeta,
+            argValues: [groupId, supplyItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAddToEquivalenceGroupConstMeta => const TaskConstMeta(
+            debugName: "add_to_equivalence_group",
+            argNames: ["groupId", "supplyItemId"],
+        );
+        
+
+@override Future<Payment> crateApiAllocatePayment({required int paymentId , required List<PaymentAllocationRequest> allocations })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(paymentId, serializer);
+sse_encode_list_payment_allocation_request(allocations, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiAllocatePaymentConstMeta,
+            argValues: [paymentId, allocations],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAllocatePaymentConstMeta => const TaskConstMeta(
+            debugName: "allocate_payment",
+            argNames: ["paymentId", "allocations"],
+        );
+        
+
+@override Future<SupplierInvoice> crateApiApproveSupplierInvoice({required int id , required bool acceptVariances })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_bool(acceptVariances, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supplier_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiApproveSupplierInvoiceConstMeta,
+            argValues: [id, acceptVariances],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApproveSupplierInvoiceConstMeta => const TaskConstMeta(
+            debugName: "approve_supplier_invoice",
+            argNames: ["id", "acceptVariances"],
+        );
+        
+
+@override Future<ItemProfit> crateApiCalculateItemProfit({required double buyingPrice , required double sellingPrice , required double quantity , required double landedCostPerUnit , double? minMarginPercent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(buyingPrice, serializer);
+sse_encode_f_64(sellingPrice, serializer);
+sse_encode_f_64(quantity, serializer);
+sse_encode_f_64(landedCostPerUnit, serializer);
+sse_encode_opt_box_autoadd_f_64(minMarginPercent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_item_profit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiCalculateItemProfitConstMeta,
+            argValues: [buyingPrice, sellingPrice, quantity, landedCostPerUnit, minMarginPercent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCalculateItemProfitConstMeta => const TaskConstMeta(
+            debugName: "calculate_item_profit",
+            argNames: ["buyingPrice", "sellingPrice", "quantity", "landedCostPerUnit", "minMarginPercent"],
+        );
+        
+
+@override Future<ItemProfit> crateApiCalculateOrderItemProfit({required int orderItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_item_profit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCalculateOrderItemProfitConstMeta,
+            argValues: [orderItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCalculateOrderItemProfitConstMeta => const TaskConstMeta(
+            debugName: "calculate_order_item_profit",
+            argNames: ["orderItemId"],
+        );
+        
+
+@override Future<OrderTotals> crateApiCalculateOrderTotals({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_totals,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCalculateOrderTotalsConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCalculateOrderTotalsConstMeta => const TaskConstMeta(
+            debugName: "calculate_order_totals",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<Invoice> crateApiCancelInvoice({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCancelInvoiceConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCancelInvoiceConstMeta => const TaskConstMeta(
+            debugName: "cancel_invoice",
+            argNames: ["id"],
+        );
+        
+
+@override Future<SupplierInvoice> crateApiCancelSupplierInvoice({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supplier_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCancelSupplierInvoiceConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCancelSupplierInvoiceConstMeta => const TaskConstMeta(
+            debugName: "cancel_supplier_invoice",
+            argNames: ["id"],
+        );
+        
+
+@override Future<CreditCheck?> crateApiCheckOrderCreditLimit({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_credit_check,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCheckOrderCreditLimitConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCheckOrderCreditLimitConstMeta => const TaskConstMeta(
+            debugName: "check_order_credit_limit",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<OrderWithItems> crateApiCloneOrder({required int orderId , required int targetShipVisitId , required bool refreshPrices })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_i_32(targetShipVisitId, serializer);
+sse_encode_bool(refreshPrices, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCloneOrderConstMeta,
+            argValues: [orderId, targetShipVisitId, refreshPrices],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCloneOrderConstMeta => const TaskConstMeta(
+            debugName: "clone_order",
+            argNames: ["orderId", "targetShipVisitId", "refreshPrices"],
+        );
+        
+
+@override Future<OrderItem> crateApiCloseOrderItem({required int orderItemId , String? reason })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderItemId, serializer);
+sse_encode_opt_String(reason, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCloseOrderItemConstMeta,
+            argValues: [orderItemId, reason],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCloseOrderItemConstMeta => const TaskConstMeta(
+            debugName: "close_order_item",
+            argNames: ["orderItemId", "reason"],
+        );
+        
+
+@override Future<OrderWithItems> crateApiCommitRequisitionImport({required int orderId , required List<RequisitionImportLine> lines })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_list_requisition_import_line(lines, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCommitRequisitionImportConstMeta,
+            argValues: [orderId, lines],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCommitRequisitionImportConstMeta => const TaskConstMeta(
+            debugName: "commit_requisition_import",
+            argNames: ["orderId", "lines"],
+        );
+        
+
+@override Future<DeliveryNote> crateApiCompleteDeliveryNote({required int id , required CompleteDeliveryNoteRequest completion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_complete_delivery_note_request(completion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_delivery_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCompleteDeliveryNoteConstMeta,
+            argValues: [id, completion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCompleteDeliveryNoteConstMeta => const TaskConstMeta(
+            debugName: "complete_delivery_note",
+            argNames: ["id", "completion"],
+        );
+        
+
+@override Future<double> crateApiConvertQuantity({required double quantity , required String fromUnit , required String toUnit , int? supplyItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(quantity, serializer);
+sse_encode_String(fromUnit, serializer);
+sse_encode_String(toUnit, serializer);
+sse_encode_opt_box_autoadd_i_32(supplyItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiConvertQuantityConstMeta,
+            argValues: [quantity, fromUnit, toUnit, supplyItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiConvertQuantityConstMeta => const TaskConstMeta(
+            debugName: "convert_quantity",
+            argNames: ["quantity", "fromUnit", "toUnit", "supplyItemId"],
+        );
+        
+
+@override Future<Category> crateApiCreateCategory({required CreateCategoryRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_category_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_category,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateCategoryConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateCategoryConstMeta => const TaskConstMeta(
+            debugName: "create_category",
+            argNames: ["request"],
+        );
+        
+
+@override Future<Customer> crateApiCreateCustomer({required CreateCustomerRequest customer })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_customer_request(customer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_customer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateCustomerConstMeta,
+            argValues: [customer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateCustomerConstMeta => const TaskConstMeta(
+            debugName: "create_customer",
+            argNames: ["customer"],
+        );
+        
+
+@override Future<DeliveryNote> crateApiCreateDeliveryNote({required CreateDeliveryNoteRequest note })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_delivery_note_request(note, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_delivery_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateDeliveryNoteConstMeta,
+            argValues: [note],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateDeliveryNoteConstMeta => const TaskConstMeta(
+            debugName: "create_delivery_note",
+            argNames: ["note"],
+        );
+        
+
+@override Future<EquivalenceGroup> crateApiCreateEquivalenceGroup({required CreateEquivalenceGroupRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_equivalence_group_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_equivalence_group,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateEquivalenceGroupConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateEquivalenceGroupConstMeta => const TaskConstMeta(
+            debugName: "create_equivalence_group",
+            argNames: ["request"],
+        );
+        
+
+@override Future<Invoice> crateApiCreateInvoice({required CreateInvoiceRequest invoice })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_invoice_request(invoice, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateInvoiceConstMeta,
+            argValues: [invoice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateInvoiceConstMeta => const TaskConstMeta(
+            debugName: "create_invoice",
+            argNames: ["invoice"],
+        );
+        
+
+@override Future<LandedCost> crateApiCreateLandedCost({required CreateLandedCostRequest landedCost })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_landed_cost_request(landedCost, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_landed_cost,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateLandedCostConstMeta,
+            argValues: [landedCost],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateLandedCostConstMeta => const TaskConstMeta(
+            debugName: "create_landed_cost",
+            argNames: ["landedCost"],
+        );
+        
+
+@override Future<OrderWithItems> crateApiCreateOrder({required CreateOrderRequest order })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_order_request(order, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateOrderConstMeta,
+            argValues: [order],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateOrderConstMeta => const TaskConstMeta(
+            debugName: "create_order",
+            argNames: ["order"],
+        );
+        
+
+@override Future<OrderReturn> crateApiCreateOrderReturn({required CreateReturnRequest orderReturn })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_return_request(orderReturn, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_return,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateOrderReturnConstMeta,
+            argValues: [orderReturn],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateOrderReturnConstMeta => const TaskConstMeta(
+            debugName: "create_order_return",
+            argNames: ["orderReturn"],
+        );
+        
+
+@override Future<Port> crateApiCreatePort({required CreatePortRequest port })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_port_request(port, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_port,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreatePortConstMeta,
+            argValues: [port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreatePortConstMeta => const TaskConstMeta(
+            debugName: "create_port",
+            argNames: ["port"],
+        );
+        
+
+@override Future<PriceAgreement> crateApiCreatePriceAgreement({required CreatePriceAgreementRequest agreement })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_price_agreement_request(agreement, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_agreement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreatePriceAgreementConstMeta,
+            argValues: [agreement],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreatePriceAgreementConstMeta => const TaskConstMeta(
+            debugName: "create_price_agreement",
+            argNames: ["agreement"],
+        );
+        
+
+@override Future<PricingRule> crateApiCreatePricingRule({required CreatePricingRuleRequest rule })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_pricing_rule_request(rule, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pricing_rule,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreatePricingRuleConstMeta,
+            argValues: [rule],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreatePricingRuleConstMeta => const TaskConstMeta(
+            debugName: "create_pricing_rule",
+            argNames: ["rule"],
+        );
+        
+
+@override Future<RequisitionTemplate> crateApiCreateRequisitionTemplate({required CreateRequisitionTemplateRequest template })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_requisition_template_request(template, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_requisition_template,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateRequisitionTemplateConstMeta,
+            argValues: [template],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateRequisitionTemplateConstMeta => const TaskConstMeta(
+            debugName: "create_requisition_template",
+            argNames: ["template"],
+        );
+        
+
+@override Future<RequisitionTemplate> crateApiCreateRequisitionTemplateFromOrder({required int orderId , required String name , required bool forShipType , double? baseHeadcount })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_String(name, serializer);
+sse_encode_bool(forShipType, serializer);
+sse_encode_opt_box_autoadd_f_64(baseHeadcount, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_requisition_template,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateRequisitionTemplateFromOrderConstMeta,
+            argValues: [orderId, name, forShipType, baseHeadcount],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateRequisitionTemplateFromOrderConstMeta => const TaskConstMeta(
+            debugName: "create_requisition_template_from_order",
+            argNames: ["orderId", "name", "forShipType", "baseHeadcount"],
+        );
+        
+
+@override Future<Ship> crateApiCreateShip({required CreateShipRequest ship })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_ship_request(ship, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ship,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateShipConstMeta,
+            argValues: [ship],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateShipConstMeta => const TaskConstMeta(
+            debugName: "create_ship",
+            argNames: ["ship"],
+        );
+        
+
+@override Future<ShipVisit> crateApiCreateShipVisit({required CreateShipVisitRequest visit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_ship_visit_request(visit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateShipVisitConstMeta,
+            argValues: [visit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateShipVisitConstMeta => const TaskConstMeta(
+            debugName: "create_ship_visit",
+            argNames: ["visit"],
+        );
+        
+
+@override Future<Stock> crateApiCreateStock({required CreateStockRequest stock })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_stock_request(stock, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stock,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateStockConstMeta,
+            argValues: [stock],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateStockConstMeta => const TaskConstMeta(
+            debugName: "create_stock",
+            argNames: ["stock"],
+        );
+        
+
+@override Future<StockMovement> crateApiCreateStockMovement({required CreateStockMovementRequest movement })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_stock_movement_request(movement, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stock_movement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateStockMovementConstMeta,
+            argValues: [movement],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateStockMovementConstMeta => const TaskConstMeta(
+            debugName: "create_stock_movement",
+            argNames: ["movement"],
+        );
+        
+
+@override Future<Supplier> crateApiCreateSupplier({required CreateSupplierRequest supplier })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_supplier_request(supplier, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supplier,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateSupplierConstMeta,
+            argValues: [supplier],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateSupplierConstMeta => const TaskConstMeta(
+            debugName: "create_supplier",
+            argNames: ["supplier"],
+        );
+        
+
+@override Future<SupplierInvoice> crateApiCreateSupplierInvoice({required CreateSupplierInvoiceRequest invoice })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_supplier_invoice_request(invoice, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supplier_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateSupplierInvoiceConstMeta,
+            argValues: [invoice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateSupplierInvoiceConstMeta => const TaskConstMeta(
+            debugName: "create_supplier_invoice",
+            argNames: ["invoice"],
+        );
+        
+
+@override Future<SupplyItem> crateApiCreateSupplyItem({required CreateSupplyItemRequest item })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_supply_item_request(item, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateSupplyItemConstMeta,
+            argValues: [item],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateSupplyItemConstMeta => const TaskConstMeta(
+            debugName: "create_supply_item",
+            argNames: ["item"],
+        );
+        
+
+@override Future<UnitOfMeasure> crateApiCreateUnit({required CreateUnitRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_unit_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit_of_measure,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiCreateUnitConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiCreateUnitConstMeta => const TaskConstMeta(
+            debugName: "create_unit",
+            argNames: ["request"],
+        );
+        
+
+@override Future<bool> crateApiDeleteAttachment({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteAttachmentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteAttachmentConstMeta => const TaskConstMeta(
+            debugName: "delete_attachment",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteCategory({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteCategoryConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteCategoryConstMeta => const TaskConstMeta(
+            debugName: "delete_category",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteCustomer({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteCustomerConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteCustomerConstMeta => const TaskConstMeta(
+            debugName: "delete_customer",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteCustomerContact({required int contactId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(contactId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteCustomerContactConstMeta,
+            argValues: [contactId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteCustomerContactConstMeta => const TaskConstMeta(
+            debugName: "delete_customer_contact",
+            argNames: ["contactId"],
+        );
+        
+
+@override Future<bool> crateApiDeleteDeliveryNote({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteDeliveryNoteConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteDeliveryNoteConstMeta => const TaskConstMeta(
+            debugName: "delete_delivery_note",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteEquivalenceGroup({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteEquivalenceGroupConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteEquivalenceGroupConstMeta => const TaskConstMeta(
+            debugName: "delete_equivalence_group",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteLandedCost({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteLandedCostConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteLandedCostConstMeta => const TaskConstMeta(
+            debugName: "delete_landed_cost",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteOrder({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteOrderConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteOrderConstMeta => const TaskConstMeta(
+            debugName: "delete_order",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteOrderCharge({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteOrderChargeConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteOrderChargeConstMeta => const TaskConstMeta(
+            debugName: "delete_order_charge",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteOrderDelivery({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteOrderDeliveryConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteOrderDeliveryConstMeta => const TaskConstMeta(
+            debugName: "delete_order_delivery",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteOrderItem({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteOrderItemConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteOrderItemConstMeta => const TaskConstMeta(
+            debugName: "delete_order_item",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeletePackSize({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeletePackSizeConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeletePackSizeConstMeta => const TaskConstMeta(
+            debugName: "delete_pack_size",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeletePayment({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeletePaymentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeletePaymentConstMeta => const TaskConstMeta(
+            debugName: "delete_payment",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeletePort({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeletePortConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeletePortConstMeta => const TaskConstMeta(
+            debugName: "delete_port",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeletePriceAgreement({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeletePriceAgreementConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeletePriceAgreementConstMeta => const TaskConstMeta(
+            debugName: "delete_price_agreement",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeletePriceAgreementLine({required int lineId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(lineId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeletePriceAgreementLineConstMeta,
+            argValues: [lineId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeletePriceAgreementLineConstMeta => const TaskConstMeta(
+            debugName: "delete_price_agreement_line",
+            argNames: ["lineId"],
+        );
+        
+
+@override Future<bool> crateApiDeletePricingRule({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeletePricingRuleConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeletePricingRuleConstMeta => const TaskConstMeta(
+            debugName: "delete_pricing_rule",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteRequisitionTemplate({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteRequisitionTemplateConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteRequisitionTemplateConstMeta => const TaskConstMeta(
+            debugName: "delete_requisition_template",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteShip({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteShipConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteShipConstMeta => const TaskConstMeta(
+            debugName: "delete_ship",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteShipVisit({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteShipVisitConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteShipVisitConstMeta => const TaskConstMeta(
+            debugName: "delete_ship_visit",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteStock({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteStockConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteStockConstMeta => const TaskConstMeta(
+            debugName: "delete_stock",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteSupplier({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteSupplierConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteSupplierConstMeta => const TaskConstMeta(
+            debugName: "delete_supplier",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteSupplierPayment({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteSupplierPaymentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteSupplierPaymentConstMeta => const TaskConstMeta(
+            debugName: "delete_supplier_payment",
+            argNames: ["id"],
+        );
+        
+
+@override Future<bool> crateApiDeleteSupplyItem({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDeleteSupplyItemConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDeleteSupplyItemConstMeta => const TaskConstMeta(
+            debugName: "delete_supply_item",
+            argNames: ["id"],
+        );
+        
+
+@override Future<ExportResult> crateApiExportBondedReconciliation({required String periodStart , required String periodEnd , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(periodStart, serializer);
+sse_encode_String(periodEnd, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_export_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportBondedReconciliationConstMeta,
+            argValues: [periodStart, periodEnd, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportBondedReconciliationConstMeta => const TaskConstMeta(
+            debugName: "export_bonded_reconciliation",
+            argNames: ["periodStart", "periodEnd", "outputPath"],
+        );
+        
+
+@override Future<ExportResult> crateApiExportOrder({required int orderId , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_export_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportOrderConstMeta,
+            argValues: [orderId, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportOrderConstMeta => const TaskConstMeta(
+            debugName: "export_order",
+            argNames: ["orderId", "outputPath"],
+        );
+        
+
+@override Future<ExportResult> crateApiExportOrders({required OrderExportFilter filter , required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_order_export_filter(filter, serializer);
+sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_export_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportOrdersConstMeta,
+            argValues: [filter, outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportOrdersConstMeta => const TaskConstMeta(
+            debugName: "export_orders",
+            argNames: ["filter", "outputPath"],
+        );
+        
+
+@override Future<ExportResult> crateApiExportProfitReport({required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_export_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportProfitReportConstMeta,
+            argValues: [outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportProfitReportConstMeta => const TaskConstMeta(
+            debugName: "export_profit_report",
+            argNames: ["outputPath"],
+        );
+        
+
+@override Future<ExportResult> crateApiExportStock({required String outputPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(outputPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_export_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiExportStockConstMeta,
+            argValues: [outputPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExportStockConstMeta => const TaskConstMeta(
+            debugName: "export_stock",
+            argNames: ["outputPath"],
+        );
+        
+
+@override Future<List<Port>> crateApiGetActivePorts()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_port,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetActivePortsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetActivePortsConstMeta => const TaskConstMeta(
+            debugName: "get_active_ports",
+            argNames: [],
+        );
+        
+
+@override Future<List<Category>> crateApiGetAllCategories()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_category,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllCategoriesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllCategoriesConstMeta => const TaskConstMeta(
+            debugName: "get_all_categories",
+            argNames: [],
+        );
+        
+
+@override Future<List<Customer>> crateApiGetAllCustomers()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_customer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllCustomersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllCustomersConstMeta => const TaskConstMeta(
+            debugName: "get_all_customers",
+            argNames: [],
+        );
+        
+
+@override Future<List<EquivalenceGroup>> crateApiGetAllEquivalenceGroups()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_equivalence_group,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllEquivalenceGroupsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllEquivalenceGroupsConstMeta => const TaskConstMeta(
+            debugName: "get_all_equivalence_groups",
+            argNames: [],
+        );
+        
+
+@override Future<List<Invoice>> crateApiGetAllInvoices({InvoiceStatus? statusFilter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_invoice_status(statusFilter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllInvoicesConstMeta,
+            argValues: [statusFilter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllInvoicesConstMeta => const TaskConstMeta(
+            debugName: "get_all_invoices",
+            argNames: ["statusFilter"],
+        );
+        
+
+@override Future<List<Order>> crateApiGetAllOrders({OrderStatus? statusFilter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_order_status(statusFilter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllOrdersConstMeta,
+            argValues: [statusFilter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllOrdersConstMeta => const TaskConstMeta(
+            debugName: "get_all_orders",
+            argNames: ["statusFilter"],
+        );
+        
+
+@override Future<List<Payment>> crateApiGetAllPayments()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllPaymentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllPaymentsConstMeta => const TaskConstMeta(
+            debugName: "get_all_payments",
+            argNames: [],
+        );
+        
+
+@override Future<List<Port>> crateApiGetAllPorts()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_port,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllPortsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllPortsConstMeta => const TaskConstMeta(
+            debugName: "get_all_ports",
+            argNames: [],
+        );
+        
+
+@override Future<List<RequisitionTemplate>> crateApiGetAllRequisitionTemplates()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_requisition_template,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllRequisitionTemplatesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllRequisitionTemplatesConstMeta => const TaskConstMeta(
+            debugName: "get_all_requisition_templates",
+            argNames: [],
+        );
+        
+
+@override Future<List<ShipVisit>> crateApiGetAllShipVisits()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllShipVisitsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllShipVisitsConstMeta => const TaskConstMeta(
+            debugName: "get_all_ship_visits",
+            argNames: [],
+        );
+        
+
+@override Future<List<Ship>> crateApiGetAllShips()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllShipsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllShipsConstMeta => const TaskConstMeta(
+            debugName: "get_all_ships",
+            argNames: [],
+        );
+        
+
+@override Future<List<Stock>> crateApiGetAllStock()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_stock,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllStockConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllStockConstMeta => const TaskConstMeta(
+            debugName: "get_all_stock",
+            argNames: [],
+        );
+        
+
+@override Future<List<SupplierInvoice>> crateApiGetAllSupplierInvoices({InvoiceStatus? statusFilter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_invoice_status(statusFilter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllSupplierInvoicesConstMeta,
+            argValues: [statusFilter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllSupplierInvoicesConstMeta => const TaskConstMeta(
+            debugName: "get_all_supplier_invoices",
+            argNames: ["statusFilter"],
+        );
+        
+
+@override Future<List<Supplier>> crateApiGetAllSuppliers()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllSuppliersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllSuppliersConstMeta => const TaskConstMeta(
+            debugName: "get_all_suppliers",
+            argNames: [],
+        );
+        
+
+@override Future<List<SupplyItem>> crateApiGetAllSupplyItems()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllSupplyItemsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllSupplyItemsConstMeta => const TaskConstMeta(
+            debugName: "get_all_supply_items",
+            argNames: [],
+        );
+        
+
+@override Future<List<UnitOfMeasure>> crateApiGetAllUnits()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_unit_of_measure,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAllUnitsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAllUnitsConstMeta => const TaskConstMeta(
+            debugName: "get_all_units",
+            argNames: [],
+        );
+        
+
+@override Future<List<ProductAlternative>> crateApiGetAlternatives({required int supplyItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplyItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_product_alternative,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAlternativesConstMeta,
+            argValues: [supplyItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAlternativesConstMeta => const TaskConstMeta(
+            debugName: "get_alternatives",
+            argNames: ["supplyItemId"],
+        );
+        
+
+@override Future<Attachment?> crateApiGetAttachment({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_attachment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAttachmentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAttachmentConstMeta => const TaskConstMeta(
+            debugName: "get_attachment",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Uint8List> crateApiGetAttachmentData({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAttachmentDataConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAttachmentDataConstMeta => const TaskConstMeta(
+            debugName: "get_attachment_data",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Uint8List> crateApiGetAttachmentThumbnail({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAttachmentThumbnailConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAttachmentThumbnailConstMeta => const TaskConstMeta(
+            debugName: "get_attachment_thumbnail",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<Attachment>> crateApiGetAttachments({required AttachmentEntity entityType , required int entityId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_attachment_entity(entityType, serializer);
+sse_encode_i_32(entityId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_attachment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetAttachmentsConstMeta,
+            argValues: [entityType, entityId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetAttachmentsConstMeta => const TaskConstMeta(
+            debugName: "get_attachments",
+            argNames: ["entityType", "entityId"],
+        );
+        
+
+@override Future<List<OrderItem>> crateApiGetBackOrderedItems({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetBackOrderedItemsConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetBackOrderedItemsConstMeta => const TaskConstMeta(
+            debugName: "get_back_ordered_items",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<BondedEligibility> crateApiGetBondedEligibility({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bonded_eligibility,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetBondedEligibilityConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetBondedEligibilityConstMeta => const TaskConstMeta(
+            debugName: "get_bonded_eligibility",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<BondedReconciliation> crateApiGetBondedReconciliation({required String periodStart , required String periodEnd })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(periodStart, serializer);
+sse_encode_String(periodEnd, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bonded_reconciliation,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetBondedReconciliationConstMeta,
+            argValues: [periodStart, periodEnd],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetBondedReconciliationConstMeta => const TaskConstMeta(
+            debugName: "get_bonded_reconciliation",
+            argNames: ["periodStart", "periodEnd"],
+        );
+        
+
+@override Future<CalendarData> crateApiGetCalendarData({required String startDate , required String endDate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(startDate, serializer);
+sse_encode_String(endDate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_calendar_data,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCalendarDataConstMeta,
+            argValues: [startDate, endDate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCalendarDataConstMeta => const TaskConstMeta(
+            debugName: "get_calendar_data",
+            argNames: ["startDate", "endDate"],
+        );
+        
+
+@override Future<Category?> crateApiGetCategoryById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_category,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCategoryByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCategoryByIdConstMeta => const TaskConstMeta(
+            debugName: "get_category_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<CompanyProfile> crateApiGetCompanyProfile()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_company_profile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCompanyProfileConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCompanyProfileConstMeta => const TaskConstMeta(
+            debugName: "get_company_profile",
+            argNames: [],
+        );
+        
+
+@override Future<ContractPrice?> crateApiGetContractPrice({required int orderId , required String impaCode , required double buyingPrice })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_String(impaCode, serializer);
+sse_encode_f_64(buyingPrice, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_contract_price,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetContractPriceConstMeta,
+            argValues: [orderId, impaCode, buyingPrice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetContractPriceConstMeta => const TaskConstMeta(
+            debugName: "get_contract_price",
+            argNames: ["orderId", "impaCode", "buyingPrice"],
+        );
+        
+
+@override Future<List<CurrencyRounding>> crateApiGetCurrencyRoundings()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_currency_rounding,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCurrencyRoundingsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCurrencyRoundingsConstMeta => const TaskConstMeta(
+            debugName: "get_currency_roundings",
+            argNames: [],
+        );
+        
+
+@override Future<List<CustomerBalance>> crateApiGetCustomerBalances({required int customerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(customerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_customer_balance,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCustomerBalancesConstMeta,
+            argValues: [customerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCustomerBalancesConstMeta => const TaskConstMeta(
+            debugName: "get_customer_balances",
+            argNames: ["customerId"],
+        );
+        
+
+@override Future<Customer?> crateApiGetCustomerById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_customer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCustomerByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCustomerByIdConstMeta => const TaskConstMeta(
+            debugName: "get_customer_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<CreditCheck> crateApiGetCustomerCreditStatus({required int customerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(customerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_credit_check,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCustomerCreditStatusConstMeta,
+            argValues: [customerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCustomerCreditStatusConstMeta => const TaskConstMeta(
+            debugName: "get_customer_credit_status",
+            argNames: ["customerId"],
+        );
+        
+
+@override Future<List<Ship>> crateApiGetCustomerShips({required int customerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(customerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetCustomerShipsConstMeta,
+            argValues: [customerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetCustomerShipsConstMeta => const TaskConstMeta(
+            debugName: "get_customer_ships",
+            argNames: ["customerId"],
+        );
+        
+
+@override Future<DangerousGoodsSummary> crateApiGetDangerousGoodsSummary({required int deliveryNoteId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(deliveryNoteId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dangerous_goods_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDangerousGoodsSummaryConstMeta,
+            argValues: [deliveryNoteId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDangerousGoodsSummaryConstMeta => const TaskConstMeta(
+            debugName: "get_dangerous_goods_summary",
+            argNames: ["deliveryNoteId"],
+        );
+        
+
+@override Future<DeliveryNote?> crateApiGetDeliveryNote({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_delivery_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDeliveryNoteConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDeliveryNoteConstMeta => const TaskConstMeta(
+            debugName: "get_delivery_note",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Uint8List> crateApiGetDeliveryNoteAttachmentData({required int attachmentId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(attachmentId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDeliveryNoteAttachmentDataConstMeta,
+            argValues: [attachmentId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDeliveryNoteAttachmentDataConstMeta => const TaskConstMeta(
+            debugName: "get_delivery_note_attachment_data",
+            argNames: ["attachmentId"],
+        );
+        
+
+@override Future<List<DeliveryNote>> crateApiGetDeliveryNotes({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_delivery_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetDeliveryNotesConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetDeliveryNotesConstMeta => const TaskConstMeta(
+            debugName: "get_delivery_notes",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<EquivalenceGroup?> crateApiGetEquivalenceGroup({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_equivalence_group,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetEquivalenceGroupConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetEquivalenceGroupConstMeta => const TaskConstMeta(
+            debugName: "get_equivalence_group",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<Order>> crateApiGetFollowUpOrders({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetFollowUpOrdersConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetFollowUpOrdersConstMeta => const TaskConstMeta(
+            debugName: "get_follow_up_orders",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<ImpaCodeIssue>> crateApiGetImpaCodeIssues()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_impa_code_issue,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetImpaCodeIssuesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetImpaCodeIssuesConstMeta => const TaskConstMeta(
+            debugName: "get_impa_code_issues",
+            argNames: [],
+        );
+        
+
+@override Future<List<ImpaSection>> crateApiGetImpaSections()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_impa_section,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetImpaSectionsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetImpaSectionsConstMeta => const TaskConstMeta(
+            debugName: "get_impa_sections",
+            argNames: [],
+        );
+        
+
+@override Future<Invoice?> crateApiGetInvoiceById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetInvoiceByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetInvoiceByIdConstMeta => const TaskConstMeta(
+            debugName: "get_invoice_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<CreditNote>> crateApiGetInvoiceCreditNotes({required int invoiceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(invoiceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_credit_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetInvoiceCreditNotesConstMeta,
+            argValues: [invoiceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetInvoiceCreditNotesConstMeta => const TaskConstMeta(
+            debugName: "get_invoice_credit_notes",
+            argNames: ["invoiceId"],
+        );
+        
+
+@override Future<List<Invoice>> crateApiGetInvoicesByCustomer({required int customerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(customerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetInvoicesByCustomerConstMeta,
+            argValues: [customerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetInvoicesByCustomerConstMeta => const TaskConstMeta(
+            debugName: "get_invoices_by_customer",
+            argNames: ["customerId"],
+        );
+        
+
+@override Future<List<Invoice>> crateApiGetInvoicesByOrder({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetInvoicesByOrderConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetInvoicesByOrderConstMeta => const TaskConstMeta(
+            debugName: "get_invoices_by_order",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<LandedCost>> crateApiGetLandedCosts({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_landed_cost,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetLandedCostsConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLandedCostsConstMeta => const TaskConstMeta(
+            debugName: "get_landed_costs",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<Stock>> crateApiGetLowStock()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_stock,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetLowStockConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetLowStockConstMeta => const TaskConstMeta(
+            debugName: "get_low_stock",
+            argNames: [],
+        );
+        
+
+@override Future<List<OffContractLine>> crateApiGetOffContractLines({int? customerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_i_32(customerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_off_contract_line,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOffContractLinesConstMeta,
+            argValues: [customerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOffContractLinesConstMeta => const TaskConstMeta(
+            debugName: "get_off_contract_lines",
+            argNames: ["customerId"],
+        );
+        
+
+@override Future<List<OrderCharge>> crateApiGetOrderCharges({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order_charge,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderChargesConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderChargesConstMeta => const TaskConstMeta(
+            debugName: "get_order_charges",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<OrderCompliance> crateApiGetOrderCompliance({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_compliance,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderComplianceConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderComplianceConstMeta => const TaskConstMeta(
+            debugName: "get_order_compliance",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<CreditNote>> crateApiGetOrderCreditNotes({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_credit_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderCreditNotesConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderCreditNotesConstMeta => const TaskConstMeta(
+            debugName: "get_order_credit_notes",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<ItemDelivery>> crateApiGetOrderDeliveries({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_item_delivery,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderDeliveriesConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderDeliveriesConstMeta => const TaskConstMeta(
+            debugName: "get_order_deliveries",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<OrderItem>> crateApiGetOrderItems({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderItemsConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderItemsConstMeta => const TaskConstMeta(
+            debugName: "get_order_items",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<OrderLink>> crateApiGetOrderLinks({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order_link,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderLinksConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderLinksConstMeta => const TaskConstMeta(
+            debugName: "get_order_links",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<OrderReturn?> crateApiGetOrderReturn({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_order_return,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderReturnConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderReturnConstMeta => const TaskConstMeta(
+            debugName: "get_order_return",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<OrderReturn>> crateApiGetOrderReturns({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order_return,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderReturnsConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderReturnsConstMeta => const TaskConstMeta(
+            debugName: "get_order_returns",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<OrderTotals> crateApiGetOrderTotals({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_totals,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderTotalsConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderTotalsConstMeta => const TaskConstMeta(
+            debugName: "get_order_totals",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<OrderWithItems?> crateApiGetOrderWithItems({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrderWithItemsConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrderWithItemsConstMeta => const TaskConstMeta(
+            debugName: "get_order_with_items",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<Order>> crateApiGetOrdersByShipVisit({required int shipVisitId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(shipVisitId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetOrdersByShipVisitConstMeta,
+            argValues: [shipVisitId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetOrdersByShipVisitConstMeta => const TaskConstMeta(
+            debugName: "get_orders_by_ship_visit",
+            argNames: ["shipVisitId"],
+        );
+        
+
+@override Future<List<PackSize>> crateApiGetPackSizes({required int supplyItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplyItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_pack_size,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPackSizesConstMeta,
+            argValues: [supplyItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPackSizesConstMeta => const TaskConstMeta(
+            debugName: "get_pack_sizes",
+            argNames: ["supplyItemId"],
+        );
+        
+
+@override Future<List<PayableDue>> crateApiGetPayablesDue({required int days })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(days, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_payable_due,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPayablesDueConstMeta,
+            argValues: [days],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPayablesDueConstMeta => const TaskConstMeta(
+            debugName: "get_payables_due",
+            argNames: ["days"],
+        );
+        
+
+@override Future<Payment?> crateApiGetPaymentById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPaymentByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPaymentByIdConstMeta => const TaskConstMeta(
+            debugName: "get_payment_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<Payment>> crateApiGetPaymentsByCustomer({required int customerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(customerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPaymentsByCustomerConstMeta,
+            argValues: [customerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPaymentsByCustomerConstMeta => const TaskConstMeta(
+            debugName: "get_payments_by_customer",
+            argNames: ["customerId"],
+        );
+        
+
+@override Future<Port?> crateApiGetPortById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_port,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPortByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPortByIdConstMeta => const TaskConstMeta(
+            debugName: "get_port_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<Port>> crateApiGetPortsByCountry({required String country })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(country, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_port,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPortsByCountryConstMeta,
+            argValues: [country],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPortsByCountryConstMeta => const TaskConstMeta(
+            debugName: "get_ports_by_country",
+            argNames: ["country"],
+        );
+        
+
+@override Future<PriceAgreement?> crateApiGetPriceAgreementById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_price_agreement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPriceAgreementByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPriceAgreementByIdConstMeta => const TaskConstMeta(
+            debugName: "get_price_agreement_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<PriceAgreement>> crateApiGetPriceAgreements({int? customerId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_box_autoadd_i_32(customerId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_price_agreement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPriceAgreementsConstMeta,
+            argValues: [customerId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPriceAgreementsConstMeta => const TaskConstMeta(
+            debugName: "get_price_agreements",
+            argNames: ["customerId"],
+        );
+        
+
+@override Future<List<PriceChange>> crateApiGetPriceHistory({required int supplyItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplyItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_price_change,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPriceHistoryConstMeta,
+            argValues: [supplyItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPriceHistoryConstMeta => const TaskConstMeta(
+            debugName: "get_price_history",
+            argNames: ["supplyItemId"],
+        );
+        
+
+@override Future<List<PriceRiseItem>> crateApiGetPriceRiseReport({required String startDate , required String endDate , required double minRisePercent , int? supplierId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(startDate, serializer);
+sse_encode_String(endDate, serializer);
+sse_encode_f_64(minRisePercent, serializer);
+sse_encode_opt_box_autoadd_i_32(supplierId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_price_rise_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPriceRiseReportConstMeta,
+            argValues: [startDate, endDate, minRisePercent, supplierId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPriceRiseReportConstMeta => const TaskConstMeta(
+            debugName: "get_price_rise_report",
+            argNames: ["startDate", "endDate", "minRisePercent", "supplierId"],
+        );
+        
+
+@override Future<List<PricingRule>> crateApiGetPricingRules()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_pricing_rule,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetPricingRulesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetPricingRulesConstMeta => const TaskConstMeta(
+            debugName: "get_pricing_rules",
+            argNames: [],
+        );
+        
+
+@override Future<ProfitSummary> crateApiGetProfitSummary()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_profit_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetProfitSummaryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetProfitSummaryConstMeta => const TaskConstMeta(
+            debugName: "get_profit_summary",
+            argNames: [],
+        );
+        
+
+@override Future<List<ReceivablesAgingRow>> crateApiGetReceivablesAging({required String asOf })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(asOf, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_receivables_aging_row,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetReceivablesAgingConstMeta,
+            argValues: [asOf],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetReceivablesAgingConstMeta => const TaskConstMeta(
+            debugName: "get_receivables_aging",
+            argNames: ["asOf"],
+        );
+        
+
+@override Future<List<StockMovement>> crateApiGetRecentStockMovements({required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_stock_movement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRecentStockMovementsConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRecentStockMovementsConstMeta => const TaskConstMeta(
+            debugName: "get_recent_stock_movements",
+            argNames: ["limit"],
+        );
+        
+
+@override Future<RequisitionTemplate?> crateApiGetRequisitionTemplate({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_requisition_template,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRequisitionTemplateConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRequisitionTemplateConstMeta => const TaskConstMeta(
+            debugName: "get_requisition_template",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<RequisitionTemplate>> crateApiGetRequisitionTemplatesForShip({required int shipId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(shipId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_requisition_template,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetRequisitionTemplatesForShipConstMeta,
+            argValues: [shipId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetRequisitionTemplatesForShipConstMeta => const TaskConstMeta(
+            debugName: "get_requisition_templates_for_ship",
+            argNames: ["shipId"],
+        );
+        
+
+@override Future<Ship?> crateApiGetShipById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ship,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetShipByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShipByIdConstMeta => const TaskConstMeta(
+            debugName: "get_ship_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiGetShipCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetShipCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShipCountConstMeta => const TaskConstMeta(
+            debugName: "get_ship_count",
+            argNames: [],
+        );
+        
+
+@override Future<ShipVisit?> crateApiGetShipVisitById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetShipVisitByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShipVisitByIdConstMeta => const TaskConstMeta(
+            debugName: "get_ship_visit_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<ShipVisit>> crateApiGetShipVisitsByDateRange({required String startDate , required String endDate })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(startDate, serializer);
+sse_encode_String(endDate, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetShipVisitsByDateRangeConstMeta,
+            argValues: [startDate, endDate],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShipVisitsByDateRangeConstMeta => const TaskConstMeta(
+            debugName: "get_ship_visits_by_date_range",
+            argNames: ["startDate", "endDate"],
+        );
+        
+
+@override Future<List<ShipVisit>> crateApiGetShipVisitsByPort({required int portId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(portId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetShipVisitsByPortConstMeta,
+            argValues: [portId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShipVisitsByPortConstMeta => const TaskConstMeta(
+            debugName: "get_ship_visits_by_port",
+            argNames: ["portId"],
+        );
+        
+
+@override Future<List<ShipVisit>> crateApiGetShipVisitsByShip({required int shipId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(shipId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetShipVisitsByShipConstMeta,
+            argValues: [shipId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetShipVisitsByShipConstMeta => const TaskConstMeta(
+            debugName: "get_ship_visits_by_ship",
+            argNames: ["shipId"],
+        );
+        
+
+@override Future<Stock?> crateApiGetStockById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_stock,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetStockByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetStockByIdConstMeta => const TaskConstMeta(
+            debugName: "get_stock_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Stock?> crateApiGetStockBySupplyItem({required int supplyItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplyItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_stock,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetStockBySupplyItemConstMeta,
+            argValues: [supplyItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetStockBySupplyItemConstMeta => const TaskConstMeta(
+            debugName: "get_stock_by_supply_item",
+            argNames: ["supplyItemId"],
+        );
+        
+
+@override Future<List<StockMovement>> crateApiGetStockMovements({required int stockId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(stockId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_stock_movement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetStockMovementsConstMeta,
+            argValues: [stockId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetStockMovementsConstMeta => const TaskConstMeta(
+            debugName: "get_stock_movements",
+            argNames: ["stockId"],
+        );
+        
+
+@override Future<StockSummary> crateApiGetStockSummary()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stock_summary,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetStockSummaryConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetStockSummaryConstMeta => const TaskConstMeta(
+            debugName: "get_stock_summary",
+            argNames: [],
+        );
+        
+
+@override Future<StockWithMovements?> crateApiGetStockWithMovements({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_stock_with_movements,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetStockWithMovementsConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetStockWithMovementsConstMeta => const TaskConstMeta(
+            debugName: "get_stock_with_movements",
+            argNames: ["id"],
+        );
+        
+
+@override Future<Supplier?> crateApiGetSupplierById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_supplier,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplierByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplierByIdConstMeta => const TaskConstMeta(
+            debugName: "get_supplier_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiGetSupplierCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplierCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplierCountConstMeta => const TaskConstMeta(
+            debugName: "get_supplier_count",
+            argNames: [],
+        );
+        
+
+@override Future<SupplierInvoice?> crateApiGetSupplierInvoiceById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_supplier_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplierInvoiceByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplierInvoiceByIdConstMeta => const TaskConstMeta(
+            debugName: "get_supplier_invoice_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<List<SupplierInvoice>> crateApiGetSupplierInvoicesByOrder({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplierInvoicesByOrderConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplierInvoicesByOrderConstMeta => const TaskConstMeta(
+            debugName: "get_supplier_invoices_by_order",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<List<SupplierInvoice>> crateApiGetSupplierInvoicesBySupplier({required int supplierId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplierId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier_invoice,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplierInvoicesBySupplierConstMeta,
+            argValues: [supplierId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplierInvoicesBySupplierConstMeta => const TaskConstMeta(
+            debugName: "get_supplier_invoices_by_supplier",
+            argNames: ["supplierId"],
+        );
+        
+
+@override Future<List<SupplierPayment>> crateApiGetSupplierPaymentsByInvoice({required int supplierInvoiceId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplierInvoiceId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplierPaymentsByInvoiceConstMeta,
+            argValues: [supplierInvoiceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplierPaymentsByInvoiceConstMeta => const TaskConstMeta(
+            debugName: "get_supplier_payments_by_invoice",
+            argNames: ["supplierInvoiceId"],
+        );
+        
+
+@override Future<List<SupplierPayment>> crateApiGetSupplierPaymentsBySupplier({required int supplierId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplierId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplierPaymentsBySupplierConstMeta,
+            argValues: [supplierId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplierPaymentsBySupplierConstMeta => const TaskConstMeta(
+            debugName: "get_supplier_payments_by_supplier",
+            argNames: ["supplierId"],
+        );
+        
+
+@override Future<List<Supplier>> crateApiGetSuppliersByCategory({required int categoryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(categoryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSuppliersByCategoryConstMeta,
+            argValues: [categoryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSuppliersByCategoryConstMeta => const TaskConstMeta(
+            debugName: "get_suppliers_by_category",
+            argNames: ["categoryId"],
+        );
+        
+
+@override Future<SupplyItem?> crateApiGetSupplyItemById({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplyItemByIdConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplyItemByIdConstMeta => const TaskConstMeta(
+            debugName: "get_supply_item_by_id",
+            argNames: ["id"],
+        );
+        
+
+@override Future<PlatformInt64> crateApiGetSupplyItemCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplyItemCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplyItemCountConstMeta => const TaskConstMeta(
+            debugName: "get_supply_item_count",
+            argNames: [],
+        );
+        
+
+@override Future<List<SupplyItem>> crateApiGetSupplyItemsByCategory({required int categoryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(categoryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplyItemsByCategoryConstMeta,
+            argValues: [categoryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplyItemsByCategoryConstMeta => const TaskConstMeta(
+            debugName: "get_supply_items_by_category",
+            argNames: ["categoryId"],
+        );
+        
+
+@override Future<List<SupplyItem>> crateApiGetSupplyItemsBySupplier({required int supplierId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplierId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetSupplyItemsBySupplierConstMeta,
+            argValues: [supplierId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetSupplyItemsBySupplierConstMeta => const TaskConstMeta(
+            debugName: "get_supply_items_by_supplier",
+            argNames: ["supplierId"],
+        );
+        
+
+@override Future<List<OrderProfitInfo>> crateApiGetTopProfitableOrders({required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_order_profit_info,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetTopProfitableOrdersConstMeta,
+            argValues: [limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetTopProfitableOrdersConstMeta => const TaskConstMeta(
+            debugName: "get_top_profitable_orders",
+            argNames: ["limit"],
+        );
+        
+
+@override Future<List<ShipVisit>> crateApiGetUpcomingShipVisits()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiGetUpcomingShipVisitsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetUpcomingShipVisitsConstMeta => const TaskConstMeta(
+            debugName: "get_upcoming_ship_visits",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiGetVersion()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGetVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGetVersionConstMeta => const TaskConstMeta(
+            debugName: "get_version",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiGreet({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<ImpaCatalogImportResult> crateApiImportImpaCatalog({required ImpaCatalogImportRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_impa_catalog_import_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_impa_catalog_import_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiImportImpaCatalogConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportImpaCatalogConstMeta => const TaskConstMeta(
+            debugName: "import_impa_catalog",
+            argNames: ["request"],
+        );
+        
+
+@override Future<PriceListImportResult> crateApiImportSupplierPriceList({required PriceListImportRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_price_list_import_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_list_import_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiImportSupplierPriceListConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiImportSupplierPriceListConstMeta => const TaskConstMeta(
+            debugName: "import_supplier_price_list",
+            argNames: ["request"],
+        );
+        
+
+@override Future<void> crateApiInitDatabase({required String databaseUrl })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(databaseUrl, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiInitDatabaseConstMeta,
+            argValues: [databaseUrl],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitDatabaseConstMeta => const TaskConstMeta(
+            debugName: "init_database",
+            argNames: ["databaseUrl"],
+        );
+        
+
+@override Future<String> crateApiInitLocalDatabase()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiInitLocalDatabaseConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInitLocalDatabaseConstMeta => const TaskConstMeta(
+            debugName: "init_local_database",
+            argNames: [],
+        );
+        
+
+@override Future<OrderWithItems> crateApiInstantiateRequisitionTemplate({required InstantiateTemplateRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_instantiate_template_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiInstantiateRequisitionTemplateConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiInstantiateRequisitionTemplateConstMeta => const TaskConstMeta(
+            debugName: "instantiate_requisition_template",
+            argNames: ["request"],
+        );
+        
+
+@override Future<bool> crateApiIsDatabaseConnected()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIsDatabaseConnectedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIsDatabaseConnectedConstMeta => const TaskConstMeta(
+            debugName: "is_database_connected",
+            argNames: [],
+        );
+        
+
+@override Future<CreditNote> crateApiIssueReturnCreditNote({required int returnId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(returnId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_credit_note,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIssueReturnCreditNoteConstMeta,
+            argValues: [returnId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIssueReturnCreditNoteConstMeta => const TaskConstMeta(
+            debugName: "issue_return_credit_note",
+            argNames: ["returnId"],
+        );
+        
+
+@override Future<StockMovement> crateApiIssueStockToOrder({required int orderItemId , int? stockId , double? quantity , String? customsDeclarationNumber })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderItemId, serializer);
+sse_encode_opt_box_autoadd_i_32(stockId, serializer);
+sse_encode_opt_box_autoadd_f_64(quantity, serializer);
+sse_encode_opt_String(customsDeclarationNumber, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stock_movement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIssueStockToOrderConstMeta,
+            argValues: [orderItemId, stockId, quantity, customsDeclarationNumber],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIssueStockToOrderConstMeta => const TaskConstMeta(
+            debugName: "issue_stock_to_order",
+            argNames: ["orderItemId", "stockId", "quantity", "customsDeclarationNumber"],
+        );
+        
+
+@override Future<String> crateApiLoadSeedData()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLoadSeedDataConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLoadSeedDataConstMeta => const TaskConstMeta(
+            debugName: "load_seed_data",
+            argNames: [],
+        );
+        
+
+@override Future<ImpaCatalogEntry?> crateApiLookupImpaCode({required String code })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(code, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_impa_catalog_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiLookupImpaCodeConstMeta,
+            argValues: [code],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLookupImpaCodeConstMeta => const TaskConstMeta(
+            debugName: "lookup_impa_code",
+            argNames: ["code"],
+        );
+        
+
+@override Future<SupplierInvoiceMatch> crateApiMatchSupplierInvoice({required int id })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supplier_invoice_match,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMatchSupplierInvoiceConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMatchSupplierInvoiceConstMeta => const TaskConstMeta(
+            debugName: "match_supplier_invoice",
+            argNames: ["id"],
+        );
+        
+
+@override Future<OrderWithItems> crateApiMergeOrders({required List<int> orderIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_i_32_loose(orderIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMergeOrdersConstMeta,
+            argValues: [orderIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMergeOrdersConstMeta => const TaskConstMeta(
+            debugName: "merge_orders",
+            argNames: ["orderIds"],
+        );
+        
+
+@override Future<Category> crateApiMoveCategory({required int id , int? parentId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_opt_box_autoadd_i_32(parentId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_category,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiMoveCategoryConstMeta,
+            argValues: [id, parentId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoveCategoryConstMeta => const TaskConstMeta(
+            debugName: "move_category",
+            argNames: ["id", "parentId"],
+        );
+        
+
+@override Future<RequisitionImportPreview> crateApiPreviewRequisitionImport({required RequisitionImportRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_requisition_import_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_requisition_import_preview,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPreviewRequisitionImportConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPreviewRequisitionImportConstMeta => const TaskConstMeta(
+            debugName: "preview_requisition_import",
+            argNames: ["request"],
+        );
+        
+
+@override Future<PriceListPreview> crateApiPreviewSupplierPriceList({required PriceListImportRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_price_list_import_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_list_preview,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPreviewSupplierPriceListConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPreviewSupplierPriceListConstMeta => const TaskConstMeta(
+            debugName: "preview_supplier_price_list",
+            argNames: ["request"],
+        );
+        
+
+@override Future<PriceProposal> crateApiProposeSellingPrice({required int orderId , String? impaCode , required double buyingPrice })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_opt_String(impaCode, serializer);
+sse_encode_f_64(buyingPrice, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_proposal,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiProposeSellingPriceConstMeta,
+            argValues: [orderId, impaCode, buyingPrice],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProposeSellingPriceConstMeta => const TaskConstMeta(
+            debugName: "propose_selling_price",
+            argNames: ["orderId", "impaCode", "buyingPrice"],
+        );
+        
+
+@override Future<int> crateApiPruneAttachmentFiles()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_32,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiPruneAttachmentFilesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPruneAttachmentFilesConstMeta => const TaskConstMeta(
+            debugName: "prune_attachment_files",
+            argNames: [],
+        );
+        
+
+@override Future<OrderWithItems> crateApiRecordDelivery({required RecordDeliveryRequest delivery })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_record_delivery_request(delivery, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordDeliveryConstMeta,
+            argValues: [delivery],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordDeliveryConstMeta => const TaskConstMeta(
+            debugName: "record_delivery",
+            argNames: ["delivery"],
+        );
+        
+
+@override Future<Payment> crateApiRecordPayment({required CreatePaymentRequest payment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_payment_request(payment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordPaymentConstMeta,
+            argValues: [payment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordPaymentConstMeta => const TaskConstMeta(
+            debugName: "record_payment",
+            argNames: ["payment"],
+        );
+        
+
+@override Future<SupplierPayment> crateApiRecordSupplierPayment({required CreateSupplierPaymentRequest payment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_create_supplier_payment_request(payment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supplier_payment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRecordSupplierPaymentConstMeta,
+            argValues: [payment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecordSupplierPaymentConstMeta => const TaskConstMeta(
+            debugName: "record_supplier_payment",
+            argNames: ["payment"],
+        );
+        
+
+@override Future<bool> crateApiRemoveFromEquivalenceGroup({required int supplyItemId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplyItemId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRemoveFromEquivalenceGroupConstMeta,
+            argValues: [supplyItemId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRemoveFromEquivalenceGroupConstMeta => const TaskConstMeta(
+            debugName: "remove_from_equivalence_group",
+            argNames: ["supplyItemId"],
+        );
+        
+
+@override Future<PdfRenderResult> crateApiRenderOrderPdf({required PdfRenderRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_pdf_render_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pdf_render_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRenderOrderPdfConstMeta,
+            argValues: [request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRenderOrderPdfConstMeta => const TaskConstMeta(
+            debugName: "render_order_pdf",
+            argNames: ["request"],
+        );
+        
+
+@override Future<RepriceResult> crateApiRepriceOrder({required int orderId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_reprice_result,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRepriceOrderConstMeta,
+            argValues: [orderId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRepriceOrderConstMeta => const TaskConstMeta(
+            debugName: "reprice_order",
+            argNames: ["orderId"],
+        );
+        
+
+@override Future<Order> crateApiRollOverBackorders({required int orderId , required int shipVisitId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_i_32(shipVisitId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiRollOverBackordersConstMeta,
+            argValues: [orderId, shipVisitId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRollOverBackordersConstMeta => const TaskConstMeta(
+            debugName: "roll_over_backorders",
+            argNames: ["orderId", "shipVisitId"],
+        );
+        
+
+@override Future<List<SearchHit>> crateApiSearch({required String query , required List<SearchScope> scopes , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_list_search_scope(scopes, serializer);
+sse_encode_i_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_search_hit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchConstMeta,
+            argValues: [query, scopes, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConstMeta => const TaskConstMeta(
+            debugName: "search",
+            argNames: ["query", "scopes", "limit"],
+        );
+        
+
+@override Future<List<Customer>> crateApiSearchCustomers({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_customer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchCustomersConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchCustomersConstMeta => const TaskConstMeta(
+            debugName: "search_customers",
+            argNames: ["query"],
+        );
+        
+
+@override Future<List<ImpaCatalogEntry>> crateApiSearchImpaCatalog({required String query , String? section , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+sse_encode_opt_String(section, serializer);
+sse_encode_i_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_impa_catalog_entry,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchImpaCatalogConstMeta,
+            argValues: [query, section, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchImpaCatalogConstMeta => const TaskConstMeta(
+            debugName: "search_impa_catalog",
+            argNames: ["query", "section", "limit"],
+        );
+        
+
+@override Future<List<Ship>> crateApiSearchShips({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_ship,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchShipsConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchShipsConstMeta => const TaskConstMeta(
+            debugName: "search_ships",
+            argNames: ["query"],
+        );
+        
+
+@override Future<List<Supplier>> crateApiSearchSuppliers({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supplier,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchSuppliersConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSuppliersConstMeta => const TaskConstMeta(
+            debugName: "search_suppliers",
+            argNames: ["query"],
+        );
+        
+
+@override Future<List<SupplyItem>> crateApiSearchSupplyItems({required String query })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(query, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchSupplyItemsConstMeta,
+            argValues: [query],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSupplyItemsConstMeta => const TaskConstMeta(
+            debugName: "search_supply_items",
+            argNames: ["query"],
+        );
+        
+
+@override Future<Attachment> crateApiSetAttachmentCaption({required int id , String? caption })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_opt_String(caption, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_attachment,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetAttachmentCaptionConstMeta,
+            argValues: [id, caption],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetAttachmentCaptionConstMeta => const TaskConstMeta(
+            debugName: "set_attachment_caption",
+            argNames: ["id", "caption"],
+        );
+        
+
+@override Future<CurrencyRounding> crateApiSetCurrencyRounding({required String currency , required double increment })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(currency, serializer);
+sse_encode_f_64(increment, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_currency_rounding,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetCurrencyRoundingConstMeta,
+            argValues: [currency, increment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetCurrencyRoundingConstMeta => const TaskConstMeta(
+            debugName: "set_currency_rounding",
+            argNames: ["currency", "increment"],
+        );
+        
+
+@override Future<ImpaSection> crateApiSetImpaSectionCategory({required String section , int? categoryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(section, serializer);
+sse_encode_opt_box_autoadd_i_32(categoryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_impa_section,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetImpaSectionCategoryConstMeta,
+            argValues: [section, categoryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetImpaSectionCategoryConstMeta => const TaskConstMeta(
+            debugName: "set_impa_section_category",
+            argNames: ["section", "categoryId"],
+        );
+        
+
+@override Future<PackSize> crateApiSetPackSize({required int supplyItemId , required String packUnit , required double quantity , required String contentUnit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplyItemId, serializer);
+sse_encode_String(packUnit, serializer);
+sse_encode_f_64(quantity, serializer);
+sse_encode_String(contentUnit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pack_size,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetPackSizeConstMeta,
+            argValues: [supplyItemId, packUnit, quantity, contentUnit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetPackSizeConstMeta => const TaskConstMeta(
+            debugName: "set_pack_size",
+            argNames: ["supplyItemId", "packUnit", "quantity", "contentUnit"],
+        );
+        
+
+@override Future<SupplyItem> crateApiSetSupplyItemSds({required int supplyItemId , required String fileName , required String mimeType , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(supplyItemId, serializer);
+sse_encode_String(fileName, serializer);
+sse_encode_String(mimeType, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSetSupplyItemSdsConstMeta,
+            argValues: [supplyItemId, fileName, mimeType, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSetSupplyItemSdsConstMeta => const TaskConstMeta(
+            debugName: "set_supply_item_sds",
+            argNames: ["supplyItemId", "fileName", "mimeType", "data"],
+        );
+        
+
+@override Future<OrderCompliance> crateApiSignOffPrescriptionItems({required int orderId , required PharmacistSignOffRequest signOff })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_box_autoadd_pharmacist_sign_off_request(signOff, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_compliance,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSignOffPrescriptionItemsConstMeta,
+            argValues: [orderId, signOff],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSignOffPrescriptionItemsConstMeta => const TaskConstMeta(
+            debugName: "sign_off_prescription_items",
+            argNames: ["orderId", "signOff"],
+        );
+        
+
+@override Future<OrderWithItems> crateApiSplitOrder({required int orderId , required List<int> itemIds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_list_prim_i_32_loose(itemIds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_with_items,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSplitOrderConstMeta,
+            argValues: [orderId, itemIds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSplitOrderConstMeta => const TaskConstMeta(
+            debugName: "split_order",
+            argNames: ["orderId", "itemIds"],
+        );
+        
+
+@override Future<ProductAlternative?> crateApiSuggestOrderItemSource({required int orderId , int? supplyItemId , String? impaCode , required double quantity })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_opt_box_autoadd_i_32(supplyItemId, serializer);
+sse_encode_opt_String(impaCode, serializer);
+sse_encode_f_64(quantity, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_product_alternative,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSuggestOrderItemSourceConstMeta,
+            argValues: [orderId, supplyItemId, impaCode, quantity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSuggestOrderItemSourceConstMeta => const TaskConstMeta(
+            debugName: "suggest_order_item_source",
+            argNames: ["orderId", "supplyItemId", "impaCode", "quantity"],
+        );
+        
+
+@override Future<Category> crateApiUpdateCategory({required int id , required UpdateCategoryRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_category_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_category,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateCategoryConstMeta,
+            argValues: [id, request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateCategoryConstMeta => const TaskConstMeta(
+            debugName: "update_category",
+            argNames: ["id", "request"],
+        );
+        
+
+@override Future<CompanyProfile> crateApiUpdateCompanyProfile({required UpdateCompanyProfileRequest profile })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_update_company_profile_request(profile, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_company_profile,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateCompanyProfileConstMeta,
+            argValues: [profile],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateCompanyProfileConstMeta => const TaskConstMeta(
+            debugName: "update_company_profile",
+            argNames: ["profile"],
+        );
+        
+
+@override Future<Customer> crateApiUpdateCustomer({required int id , required UpdateCustomerRequest customer })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_customer_request(customer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_customer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateCustomerConstMeta,
+            argValues: [id, customer],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateCustomerConstMeta => const TaskConstMeta(
+            debugName: "update_customer",
+            argNames: ["id", "customer"],
+        );
+        
+
+@override Future<Order> crateApiUpdateOrder({required int id , required UpdateOrderRequest order })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_order_request(order, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateOrderConstMeta,
+            argValues: [id, order],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateOrderConstMeta => const TaskConstMeta(
+            debugName: "update_order",
+            argNames: ["id", "order"],
+        );
+        
+
+@override Future<OrderCharge> crateApiUpdateOrderCharge({required int id , required UpdateOrderChargeRequest charge })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_order_charge_request(charge, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_charge,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateOrderChargeConstMeta,
+            argValues: [id, charge],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateOrderChargeConstMeta => const TaskConstMeta(
+            debugName: "update_order_charge",
+            argNames: ["id", "charge"],
+        );
+        
+
+@override Future<OrderItem> crateApiUpdateOrderItem({required int id , required UpdateOrderItemRequest item })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_order_item_request(item, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateOrderItemConstMeta,
+            argValues: [id, item],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateOrderItemConstMeta => const TaskConstMeta(
+            debugName: "update_order_item",
+            argNames: ["id", "item"],
+        );
+        
+
+@override Future<Order> crateApiUpdateOrderStatus({required int id , required OrderStatus newStatus })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_order_status(newStatus, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateOrderStatusConstMeta,
+            argValues: [id, newStatus],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateOrderStatusConstMeta => const TaskConstMeta(
+            debugName: "update_order_status",
+            argNames: ["id", "newStatus"],
+        );
+        
+
+@override Future<Port?> crateApiUpdatePort({required int id , required UpdatePortRequest port })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_port_request(port, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_port,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdatePortConstMeta,
+            argValues: [id, port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdatePortConstMeta => const TaskConstMeta(
+            debugName: "update_port",
+            argNames: ["id", "port"],
+        );
+        
+
+@override Future<PriceAgreement> crateApiUpdatePriceAgreement({required int id , required UpdatePriceAgreementRequest agreement })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_price_agreement_request(agreement, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_agreement,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdatePriceAgreementConstMeta,
+            argValues: [id, agreement],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdatePriceAgreementConstMeta => const TaskConstMeta(
+            debugName: "update_price_agreement",
+            argNames: ["id", "agreement"],
+        );
+        
+
+@override Future<PricingRule> crateApiUpdatePricingRule({required int id , required UpdatePricingRuleRequest rule })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_pricing_rule_request(rule, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pricing_rule,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdatePricingRuleConstMeta,
+            argValues: [id, rule],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdatePricingRuleConstMeta => const TaskConstMeta(
+            debugName: "update_pricing_rule",
+            argNames: ["id", "rule"],
+        );
+        
+
+@override Future<RequisitionTemplate> crateApiUpdateRequisitionTemplate({required int id , required UpdateRequisitionTemplateRequest template })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_requisition_template_request(template, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_requisition_template,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateRequisitionTemplateConstMeta,
+            argValues: [id, template],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateRequisitionTemplateConstMeta => const TaskConstMeta(
+            debugName: "update_requisition_template",
+            argNames: ["id", "template"],
+        );
+        
+
+@override Future<Ship> crateApiUpdateShip({required int id , required UpdateShipRequest ship })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_ship_request(ship, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ship,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateShipConstMeta,
+            argValues: [id, ship],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateShipConstMeta => const TaskConstMeta(
+            debugName: "update_ship",
+            argNames: ["id", "ship"],
+        );
+        
+
+@override Future<ShipVisit?> crateApiUpdateShipVisit({required int id , required UpdateShipVisitRequest visit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_ship_visit_request(visit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateShipVisitConstMeta,
+            argValues: [id, visit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateShipVisitConstMeta => const TaskConstMeta(
+            debugName: "update_ship_visit",
+            argNames: ["id", "visit"],
+        );
+        
+
+@override Future<ShipVisit?> crateApiUpdateShipVisitStatus({required int id , required VisitStatus status })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_visit_status(status, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ship_visit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateShipVisitStatusConstMeta,
+            argValues: [id, status],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateShipVisitStatusConstMeta => const TaskConstMeta(
+            debugName: "update_ship_visit_status",
+            argNames: ["id", "status"],
+        );
+        
+
+@override Future<Stock> crateApiUpdateStock({required int id , required UpdateStockRequest stock })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_stock_request(stock, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_stock,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateStockConstMeta,
+            argValues: [id, stock],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateStockConstMeta => const TaskConstMeta(
+            debugName: "update_stock",
+            argNames: ["id", "stock"],
+        );
+        
+
+@override Future<Supplier> crateApiUpdateSupplier({required int id , required UpdateSupplierRequest supplier })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_supplier_request(supplier, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supplier,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateSupplierConstMeta,
+            argValues: [id, supplier],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateSupplierConstMeta => const TaskConstMeta(
+            debugName: "update_supplier",
+            argNames: ["id", "supplier"],
+        );
+        
+
+@override Future<SupplyItem> crateApiUpdateSupplyItem({required int id , required UpdateSupplyItemRequest item })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_supply_item_request(item, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_supply_item,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateSupplyItemConstMeta,
+            argValues: [id, item],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateSupplyItemConstMeta => const TaskConstMeta(
+            debugName: "update_supply_item",
+            argNames: ["id", "item"],
+        );
+        
+
+@override Future<UnitOfMeasure> crateApiUpdateUnit({required int id , required UpdateUnitRequest request })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(id, serializer);
+sse_encode_box_autoadd_update_unit_request(request, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit_of_measure,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiUpdateUnitConstMeta,
+            argValues: [id, request],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiUpdateUnitConstMeta => const TaskConstMeta(
+            debugName: "update_unit",
+            argNames: ["id", "request"],
+        );
+        
+
+@override Future<String> crateApiValidateImpaCode({required String code })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(code, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiValidateImpaCodeConstMeta,
+            argValues: [code],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiValidateImpaCodeConstMeta => const TaskConstMeta(
+            debugName: "validate_impa_code",
+            argNames: ["code"],
+        );
+        
+
+@override Future<Order> crateApiWaiveDeliveryNote({required int orderId , required String reason })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_32(orderId, serializer);
+sse_encode_String(reason, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_order,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiWaiveDeliveryNoteConstMeta,
+            argValues: [orderId, reason],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWaiveDeliveryNoteConstMeta => const TaskConstMeta(
+            debugName: "waive_delivery_note",
+            argNames: ["orderId", "reason"],
+        );
+        
+
+
+
+                  @protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AllocationMethod dco_decode_allocation_method(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AllocationMethod.values[raw as int]; }
+
+@protected AlternativeLink dco_decode_alternative_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AlternativeLink.values[raw as int]; }
+
+@protected Attachment dco_decode_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return Attachment(id: dco_decode_i_32(arr[0]),
+entityType: dco_decode_attachment_entity(arr[1]),
+entityId: dco_decode_i_32(arr[2]),
+role: dco_decode_opt_String(arr[3]),
+fileName: dco_decode_String(arr[4]),
+mimeType: dco_decode_String(arr[5]),
+contentHash: dco_decode_String(arr[6]),
+sizeBytes: dco_decode_i_64(arr[7]),
+width: dco_decode_opt_box_autoadd_i_32(arr[8]),
+height: dco_decode_opt_box_autoadd_i_32(arr[9]),
+hasThumbnail: dco_decode_bool(arr[10]),
+caption: dco_decode_opt_String(arr[11]),
+createdAt: dco_decode_String(arr[12]),); }
+
+@protected AttachmentEntity dco_decode_attachment_entity(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AttachmentEntity.values[raw as int]; }
+
+@protected BondedEligibility dco_decode_bonded_eligibility(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return BondedEligibility(orderId: dco_decode_i_32(arr[0]),
+shipName: dco_decode_opt_String(arr[1]),
+shipFlag: dco_decode_opt_String(arr[2]),
+portName: dco_decode_opt_String(arr[3]),
+portCountry: dco_decode_opt_String(arr[4]),
+isInternationalPort: dco_decode_bool(arr[5]),
+isEligible: dco_decode_bool(arr[6]),
+reason: dco_decode_opt_String(arr[7]),); }
+
+@protected BondedReconciliation dco_decode_bonded_reconciliation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BondedReconciliation(periodStart: dco_decode_String(arr[0]),
+periodEnd: dco_decode_String(arr[1]),
+lines: dco_decode_list_bonded_reconciliation_line(arr[2]),
+movements: dco_decode_list_stock_movement(arr[3]),); }
+
+@protected BondedReconciliationLine dco_decode_bonded_reconciliation_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return BondedReconciliationLine(stockId: dco_decode_i_32(arr[0]),
+supplyItemId: dco_decode_i_32(arr[1]),
+supplyItemName: dco_decode_opt_String(arr[2]),
+unit: dco_decode_String(arr[3]),
+warehouseLocation: dco_decode_opt_String(arr[4]),
+openingQuantity: dco_decode_f_64(arr[5]),
+receivedQuantity: dco_decode_f_64(arr[6]),
+returnedQuantity: dco_decode_f_64(arr[7]),
+issuedQuantity: dco_decode_f_64(arr[8]),
+adjustedQuantity: dco_decode_f_64(arr[9]),
+closingQuantity: dco_decode_f_64(arr[10]),
+expectedQuantity: dco_decode_f_64(arr[11]),
+recordedQuantity: dco_decode_f_64(arr[12]),
+difference: dco_decode_f_64(arr[13]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected Attachment dco_decode_box_autoadd_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_attachment(raw); }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected Category dco_decode_box_autoadd_category(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_category(raw); }
+
+@protected ChargeBilling dco_decode_box_autoadd_charge_billing(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_charge_billing(raw); }
+
+@protected CompleteDeliveryNoteRequest dco_decode_box_autoadd_complete_delivery_note_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_complete_delivery_note_request(raw); }
+
+@protected ContractPrice dco_decode_box_autoadd_contract_price(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_contract_price(raw); }
+
+@protected CreateAttachmentRequest dco_decode_box_autoadd_create_attachment_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_attachment_request(raw); }
+
+@protected CreateCategoryRequest dco_decode_box_autoadd_create_category_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_category_request(raw); }
+
+@protected CreateCustomerContactRequest dco_decode_box_autoadd_create_customer_contact_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_customer_contact_request(raw); }
+
+@protected CreateCustomerRequest dco_decode_box_autoadd_create_customer_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_customer_request(raw); }
+
+@protected CreateDeliveryNoteRequest dco_decode_box_autoadd_create_delivery_note_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_delivery_note_request(raw); }
+
+@protected CreateEquivalenceGroupRequest dco_decode_box_autoadd_create_equivalence_group_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_equivalence_group_request(raw); }
+
+@protected CreateInvoiceRequest dco_decode_box_autoadd_create_invoice_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_invoice_request(raw); }
+
+@protected CreateLandedCostRequest dco_decode_box_autoadd_create_landed_cost_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_landed_cost_request(raw); }
+
+@protected CreateOrderChargeRequest dco_decode_box_autoadd_create_order_charge_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_order_charge_request(raw); }
+
+@protected CreateOrderItemRequest dco_decode_box_autoadd_create_order_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_order_item_request(raw); }
+
+@protected CreateOrderRequest dco_decode_box_autoadd_create_order_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_order_request(raw); }
+
+@protected CreatePaymentRequest dco_decode_box_autoadd_create_payment_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_payment_request(raw); }
+
+@protected CreatePortRequest dco_decode_box_autoadd_create_port_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_port_request(raw); }
+
+@protected CreatePriceAgreementLineRequest dco_decode_box_autoadd_create_price_agreement_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_price_agreement_line_request(raw); }
+
+@protected CreatePriceAgreementRequest dco_decode_box_autoadd_create_price_agreement_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_price_agreement_request(raw); }
+
+@protected CreatePricingRuleRequest dco_decode_box_autoadd_create_pricing_rule_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_pricing_rule_request(raw); }
+
+@protected CreateRequisitionTemplateRequest dco_decode_box_autoadd_create_requisition_template_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_requisition_template_request(raw); }
+
+@protected CreateReturnRequest dco_decode_box_autoadd_create_return_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_return_request(raw); }
+
+@protected CreateShipRequest dco_decode_box_autoadd_create_ship_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_ship_request(raw); }
+
+@protected CreateShipVisitRequest dco_decode_box_autoadd_create_ship_visit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_ship_visit_request(raw); }
+
+@protected CreateStockMovementRequest dco_decode_box_autoadd_create_stock_movement_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_stock_movement_request(raw); }
+
+@protected CreateStockRequest dco_decode_box_autoadd_create_stock_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_stock_request(raw); }
+
+@protected CreateSupplierInvoiceRequest dco_decode_box_autoadd_create_supplier_invoice_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_supplier_invoice_request(raw); }
+
+@protected CreateSupplierPaymentRequest dco_decode_box_autoadd_create_supplier_payment_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_supplier_payment_request(raw); }
+
+@protected CreateSupplierRequest dco_decode_box_autoadd_create_supplier_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_supplier_request(raw); }
+
+@protected CreateSupplyItemRequest dco_decode_box_autoadd_create_supply_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_supply_item_request(raw); }
+
+@protected CreateUnitRequest dco_decode_box_autoadd_create_unit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_create_unit_request(raw); }
+
+@protected CreditCheck dco_decode_box_autoadd_credit_check(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_credit_check(raw); }
+
+@protected Customer dco_decode_box_autoadd_customer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_customer(raw); }
+
+@protected DeliveryNote dco_decode_box_autoadd_delivery_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_delivery_note(raw); }
+
+@protected DeliveryType dco_decode_box_autoadd_delivery_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_delivery_type(raw); }
+
+@protected EquivalenceGroup dco_decode_box_autoadd_equivalence_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_equivalence_group(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected int dco_decode_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected ImpaCatalogEntry dco_decode_box_autoadd_impa_catalog_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_impa_catalog_entry(raw); }
+
+@protected ImpaCatalogImportRequest dco_decode_box_autoadd_impa_catalog_import_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_impa_catalog_import_request(raw); }
+
+@protected InstantiateTemplateRequest dco_decode_box_autoadd_instantiate_template_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_instantiate_template_request(raw); }
+
+@protected Invoice dco_decode_box_autoadd_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_invoice(raw); }
+
+@protected InvoiceStatus dco_decode_box_autoadd_invoice_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_invoice_status(raw); }
+
+@protected OrderExportFilter dco_decode_box_autoadd_order_export_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_order_export_filter(raw); }
+
+@protected OrderReturn dco_decode_box_autoadd_order_return(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_order_return(raw); }
+
+@protected OrderStatus dco_decode_box_autoadd_order_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_order_status(raw); }
+
+@protected OrderWithItems dco_decode_box_autoadd_order_with_items(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_order_with_items(raw); }
+
+@protected Payment dco_decode_box_autoadd_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_payment(raw); }
+
+@protected PdfRenderRequest dco_decode_box_autoadd_pdf_render_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pdf_render_request(raw); }
+
+@protected PharmacistSignOffRequest dco_decode_box_autoadd_pharmacist_sign_off_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_pharmacist_sign_off_request(raw); }
+
+@protected Port dco_decode_box_autoadd_port(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_port(raw); }
+
+@protected PriceAgreement dco_decode_box_autoadd_price_agreement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_price_agreement(raw); }
+
+@protected PriceListImportRequest dco_decode_box_autoadd_price_list_import_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_price_list_import_request(raw); }
+
+@protected PriceListMatchKind dco_decode_box_autoadd_price_list_match_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_price_list_match_kind(raw); }
+
+@protected ProductAlternative dco_decode_box_autoadd_product_alternative(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_product_alternative(raw); }
+
+@protected RecordDeliveryRequest dco_decode_box_autoadd_record_delivery_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_record_delivery_request(raw); }
+
+@protected RequisitionImportRequest dco_decode_box_autoadd_requisition_import_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_requisition_import_request(raw); }
+
+@protected RequisitionTemplate dco_decode_box_autoadd_requisition_template(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_requisition_template(raw); }
+
+@protected Ship dco_decode_box_autoadd_ship(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ship(raw); }
+
+@protected ShipVisit dco_decode_box_autoadd_ship_visit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ship_visit(raw); }
+
+@protected Stock dco_decode_box_autoadd_stock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stock(raw); }
+
+@protected StockWithMovements dco_decode_box_autoadd_stock_with_movements(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stock_with_movements(raw); }
+
+@protected Supplier dco_decode_box_autoadd_supplier(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_supplier(raw); }
+
+@protected SupplierInvoice dco_decode_box_autoadd_supplier_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_supplier_invoice(raw); }
+
+@protected SupplyItem dco_decode_box_autoadd_supply_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_supply_item(raw); }
+
+@protected UpdateCategoryRequest dco_decode_box_autoadd_update_category_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_category_request(raw); }
+
+@protected UpdateCompanyProfileRequest dco_decode_box_autoadd_update_company_profile_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_company_profile_request(raw); }
+
+@protected UpdateCustomerRequest dco_decode_box_autoadd_update_customer_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_customer_request(raw); }
+
+@protected UpdateOrderChargeRequest dco_decode_box_autoadd_update_order_charge_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_order_charge_request(raw); }
+
+@protected UpdateOrderItemRequest dco_decode_box_autoadd_update_order_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_order_item_request(raw); }
+
+@protected UpdateOrderRequest dco_decode_box_autoadd_update_order_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_order_request(raw); }
+
+@protected UpdatePortRequest dco_decode_box_autoadd_update_port_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_port_request(raw); }
+
+@protected UpdatePriceAgreementRequest dco_decode_box_autoadd_update_price_agreement_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_price_agreement_request(raw); }
+
+@protected UpdatePricingRuleRequest dco_decode_box_autoadd_update_pricing_rule_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_pricing_rule_request(raw); }
+
+@protected UpdateRequisitionTemplateRequest dco_decode_box_autoadd_update_requisition_template_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_requisition_template_request(raw); }
+
+@protected UpdateShipRequest dco_decode_box_autoadd_update_ship_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_ship_request(raw); }
+
+@protected UpdateShipVisitRequest dco_decode_box_autoadd_update_ship_visit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_ship_visit_request(raw); }
+
+@protected UpdateStockRequest dco_decode_box_autoadd_update_stock_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_stock_request(raw); }
+
+@protected UpdateSupplierRequest dco_decode_box_autoadd_update_supplier_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_supplier_request(raw); }
+
+@protected UpdateSupplyItemRequest dco_decode_box_autoadd_update_supply_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_supply_item_request(raw); }
+
+@protected UpdateUnitRequest dco_decode_box_autoadd_update_unit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_update_unit_request(raw); }
+
+@protected VisitStatus dco_decode_box_autoadd_visit_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_visit_status(raw); }
+
+@protected CalendarData dco_decode_calendar_data(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CalendarData(events: dco_decode_list_calendar_event(arr[0]),
+ports: dco_decode_list_port(arr[1]),); }
+
+@protected CalendarEvent dco_decode_calendar_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return CalendarEvent(id: dco_decode_String(arr[0]),
+eventType: dco_decode_calendar_event_type(arr[1]),
+title: dco_decode_String(arr[2]),
+subtitle: dco_decode_opt_String(arr[3]),
+startDate: dco_decode_String(arr[4]),
+endDate: dco_decode_String(arr[5]),
+color: dco_decode_String(arr[6]),
+status: dco_decode_String(arr[7]),
+relatedShipId: dco_decode_opt_box_autoadd_i_32(arr[8]),
+relatedPortId: dco_decode_opt_box_autoadd_i_32(arr[9]),
+relatedOrderId: dco_decode_opt_box_autoadd_i_32(arr[10]),
+metadata: dco_decode_opt_String(arr[11]),); }
+
+@protected CalendarEventType dco_decode_calendar_event_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CalendarEventType.values[raw as int]; }
+
+@protected Category dco_decode_category(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return Category(id: dco_decode_i_32(arr[0]),
+parentId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+code: dco_decode_String(arr[2]),
+nameTr: dco_decode_String(arr[3]),
+nameEn: dco_decode_opt_String(arr[4]),
+depth: dco_decode_i_32(arr[5]),
+path: dco_decode_String(arr[6]),
+sortOrder: dco_decode_i_32(arr[7]),
+isActive: dco_decode_bool(arr[8]),
+itemCount: dco_decode_i_32(arr[9]),
+createdAt: dco_decode_String(arr[10]),
+updatedAt: dco_decode_String(arr[11]),); }
+
+@protected ChargeBilling dco_decode_charge_billing(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChargeBilling.values[raw as int]; }
+
+@protected ChargeType dco_decode_charge_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ChargeType.values[raw as int]; }
+
+@protected CompanyProfile dco_decode_company_profile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return CompanyProfile(name: dco_decode_String(arr[0]),
+address: dco_decode_opt_String(arr[1]),
+phone: dco_decode_opt_String(arr[2]),
+email: dco_decode_opt_String(arr[3]),
+website: dco_decode_opt_String(arr[4]),
+taxOffice: dco_decode_opt_String(arr[5]),
+taxNumber: dco_decode_opt_String(arr[6]),
+bankName: dco_decode_opt_String(arr[7]),
+iban: dco_decode_opt_String(arr[8]),
+swift: dco_decode_opt_String(arr[9]),
+updatedAt: dco_decode_opt_String(arr[10]),); }
+
+@protected CompleteDeliveryNoteRequest dco_decode_complete_delivery_note_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CompleteDeliveryNoteRequest(receiverName: dco_decode_String(arr[0]),
+receiverRank: dco_decode_String(arr[1]),
+signedAt: dco_decode_opt_String(arr[2]),); }
+
+@protected ComplianceIssue dco_decode_compliance_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ComplianceIssue(orderItemId: dco_decode_i_32(arr[0]),
+productName: dco_decode_String(arr[1]),
+kind: dco_decode_compliance_issue_kind(arr[2]),
+detail: dco_decode_String(arr[3]),); }
+
+@protected ComplianceIssueKind dco_decode_compliance_issue_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ComplianceIssueKind.values[raw as int]; }
+
+@protected ContractPrice dco_decode_contract_price(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ContractPrice(agreementId: dco_decode_i_32(arr[0]),
+agreementName: dco_decode_String(arr[1]),
+lineId: dco_decode_i_32(arr[2]),
+sellingPrice: dco_decode_f_64(arr[3]),
+isGroupPrice: dco_decode_bool(arr[4]),); }
+
+@protected CreateAttachmentRequest dco_decode_create_attachment_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CreateAttachmentRequest(entityType: dco_decode_attachment_entity(arr[0]),
+entityId: dco_decode_i_32(arr[1]),
+fileName: dco_decode_String(arr[2]),
+mimeType: dco_decode_String(arr[3]),
+data: dco_decode_list_prim_u_8_strict(arr[4]),
+caption: dco_decode_opt_String(arr[5]),); }
+
+@protected CreateCategoryRequest dco_decode_create_category_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CreateCategoryRequest(parentId: dco_decode_opt_box_autoadd_i_32(arr[0]),
+code: dco_decode_String(arr[1]),
+nameTr: dco_decode_String(arr[2]),
+nameEn: dco_decode_opt_String(arr[3]),
+sortOrder: dco_decode_opt_box_autoadd_i_32(arr[4]),); }
+
+@protected CreateCustomerContactRequest dco_decode_create_customer_contact_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CreateCustomerContactRequest(name: dco_decode_String(arr[0]),
+role: dco_decode_opt_String(arr[1]),
+email: dco_decode_opt_String(arr[2]),
+phone: dco_decode_opt_String(arr[3]),
+isPrimary: dco_decode_bool(arr[4]),); }
+
+@protected CreateCustomerRequest dco_decode_create_customer_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return CreateCustomerRequest(legalName: dco_decode_String(arr[0]),
+taxNumber: dco_decode_opt_String(arr[1]),
+taxOffice: dco_decode_opt_String(arr[2]),
+billingAddress: dco_decode_opt_String(arr[3]),
+country: dco_decode_opt_String(arr[4]),
+paymentTermsDays: dco_decode_opt_box_autoadd_i_32(arr[5]),
+creditLimit: dco_decode_opt_box_autoadd_f_64(arr[6]),
+defaultCurrency: dco_decode_opt_String(arr[7]),
+notes: dco_decode_opt_String(arr[8]),
+contacts: dco_decode_list_create_customer_contact_request(arr[9]),); }
+
+@protected CreateDeliveryNoteRequest dco_decode_create_delivery_note_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CreateDeliveryNoteRequest(orderId: dco_decode_i_32(arr[0]),
+deliveryIds: dco_decode_opt_list_prim_i_32_strict(arr[1]),
+notes: dco_decode_opt_String(arr[2]),); }
+
+@protected CreateEquivalenceGroupRequest dco_decode_create_equivalence_group_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CreateEquivalenceGroupRequest(name: dco_decode_String(arr[0]),
+notes: dco_decode_opt_String(arr[1]),
+supplyItemIds: dco_decode_list_prim_i_32_strict(arr[2]),); }
+
+@protected CreateInvoiceRequest dco_decode_create_invoice_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CreateInvoiceRequest(orderId: dco_decode_i_32(arr[0]),
+issueDate: dco_decode_opt_String(arr[1]),
+dueDate: dco_decode_opt_String(arr[2]),
+amount: dco_decode_opt_box_autoadd_f_64(arr[3]),
+notes: dco_decode_opt_String(arr[4]),); }
+
+@protected CreateLandedCostRequest dco_decode_create_landed_cost_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return CreateLandedCostRequest(orderId: dco_decode_i_32(arr[0]),
+stockMovementId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+costType: dco_decode_landed_cost_type(arr[2]),
+description: dco_decode_opt_String(arr[3]),
+amount: dco_decode_f_64(arr[4]),
+allocationMethod: dco_decode_allocation_method(arr[5]),
+reference: dco_decode_opt_String(arr[6]),
+weights: dco_decode_list_item_weight(arr[7]),); }
+
+@protected CreateOrderChargeRequest dco_decode_create_order_charge_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CreateOrderChargeRequest(orderId: dco_decode_i_32(arr[0]),
+chargeType: dco_decode_charge_type(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+amount: dco_decode_f_64(arr[3]),
+billing: dco_decode_charge_billing(arr[4]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[5]),
+notes: dco_decode_opt_String(arr[6]),); }
+
+@protected CreateOrderItemRequest dco_decode_create_order_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return CreateOrderItemRequest(orderId: dco_decode_i_32(arr[0]),
+productName: dco_decode_String(arr[1]),
+impaCode: dco_decode_opt_String(arr[2]),
+description: dco_decode_opt_String(arr[3]),
+quantity: dco_decode_f_64(arr[4]),
+unit: dco_decode_String(arr[5]),
+buyingPrice: dco_decode_f_64(arr[6]),
+sellingPrice: dco_decode_f_64(arr[7]),
+currency: dco_decode_String(arr[8]),
+deliveryType: dco_decode_delivery_type(arr[9]),
+warehouseDeliveryDate: dco_decode_opt_String(arr[10]),
+shipDeliveryDate: dco_decode_opt_String(arr[11]),
+notes: dco_decode_opt_String(arr[12]),); }
+
+@protected CreateOrderRequest dco_decode_create_order_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CreateOrderRequest(shipId: dco_decode_i_32(arr[0]),
+shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+deliveryPort: dco_decode_opt_String(arr[2]),
+notes: dco_decode_opt_String(arr[3]),
+currency: dco_decode_String(arr[4]),); }
+
+@protected CreatePaymentRequest dco_decode_create_payment_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return CreatePaymentRequest(customerId: dco_decode_i_32(arr[0]),
+amount: dco_decode_f_64(arr[1]),
+currency: dco_decode_String(arr[2]),
+paymentDate: dco_decode_String(arr[3]),
+method: dco_decode_payment_method(arr[4]),
+reference: dco_decode_opt_String(arr[5]),
+notes: dco_decode_opt_String(arr[6]),
+allocations: dco_decode_list_payment_allocation_request(arr[7]),); }
+
+@protected CreatePortRequest dco_decode_create_port_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return CreatePortRequest(name: dco_decode_String(arr[0]),
+country: dco_decode_String(arr[1]),
+city: dco_decode_opt_String(arr[2]),
+timezone: dco_decode_String(arr[3]),
+latitude: dco_decode_opt_box_autoadd_f_64(arr[4]),
+longitude: dco_decode_opt_box_autoadd_f_64(arr[5]),
+notes: dco_decode_opt_String(arr[6]),
+isInternational: dco_decode_bool(arr[7]),); }
+
+@protected CreatePriceAgreementLineRequest dco_decode_create_price_agreement_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CreatePriceAgreementLineRequest(impaCode: dco_decode_opt_String(arr[0]),
+impaGroup: dco_decode_opt_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+fixedPrice: dco_decode_opt_box_autoadd_f_64(arr[3]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[4]),); }
+
+@protected CreatePriceAgreementRequest dco_decode_create_price_agreement_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CreatePriceAgreementRequest(customerId: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+currency: dco_decode_String(arr[2]),
+validFrom: dco_decode_String(arr[3]),
+validTo: dco_decode_String(arr[4]),
+notes: dco_decode_opt_String(arr[5]),
+lines: dco_decode_list_create_price_agreement_line_request(arr[6]),); }
+
+@protected CreatePricingRuleRequest dco_decode_create_pricing_rule_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CreatePricingRuleRequest(name: dco_decode_String(arr[0]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+supplierId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+customerId: dco_decode_opt_box_autoadd_i_32(arr[3]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[4]),
+minMarginPercent: dco_decode_opt_box_autoadd_f_64(arr[5]),
+notes: dco_decode_opt_String(arr[6]),); }
+
+@protected CreateRequisitionTemplateRequest dco_decode_create_requisition_template_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CreateRequisitionTemplateRequest(name: dco_decode_String(arr[0]),
+shipId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+shipType: dco_decode_opt_String(arr[2]),
+baseHeadcount: dco_decode_opt_box_autoadd_f_64(arr[3]),
+notes: dco_decode_opt_String(arr[4]),
+lines: dco_decode_list_requisition_template_line_request(arr[5]),); }
+
+@protected CreateReturnRequest dco_decode_create_return_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CreateReturnRequest(orderId: dco_decode_i_32(arr[0]),
+returnDate: dco_decode_opt_String(arr[1]),
+notes: dco_decode_opt_String(arr[2]),
+lines: dco_decode_list_return_line_request(arr[3]),
+issueCreditNote: dco_decode_bool(arr[4]),
+customsDeclarationNumber: dco_decode_opt_String(arr[5]),); }
+
+@protected CreateShipRequest dco_decode_create_ship_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CreateShipRequest(name: dco_decode_String(arr[0]),
+imoNumber: dco_decode_String(arr[1]),
+flag: dco_decode_String(arr[2]),
+shipType: dco_decode_opt_String(arr[3]),
+grossTonnage: dco_decode_opt_box_autoadd_f_64(arr[4]),
+owner: dco_decode_opt_String(arr[5]),
+ownerId: dco_decode_opt_box_autoadd_i_32(arr[6]),); }
+
+@protected CreateShipVisitRequest dco_decode_create_ship_visit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CreateShipVisitRequest(shipId: dco_decode_i_32(arr[0]),
+portId: dco_decode_i_32(arr[1]),
+eta: dco_decode_String(arr[2]),
+etd: dco_decode_String(arr[3]),
+agentInfo: dco_decode_opt_String(arr[4]),
+notes: dco_decode_opt_String(arr[5]),); }
+
+@protected CreateStockMovementRequest dco_decode_create_stock_movement_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return CreateStockMovementRequest(stockId: dco_decode_i_32(arr[0]),
+movementType: dco_decode_stock_movement_type(arr[1]),
+quantity: dco_decode_f_64(arr[2]),
+unit: dco_decode_opt_String(arr[3]),
+referenceType: dco_decode_opt_String(arr[4]),
+referenceId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+referenceInfo: dco_decode_opt_String(arr[6]),
+orderItemId: dco_decode_opt_box_autoadd_i_32(arr[7]),
+notes: dco_decode_opt_String(arr[8]),
+customsDeclarationNumber: dco_decode_opt_String(arr[9]),); }
+
+@protected CreateStockRequest dco_decode_create_stock_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CreateStockRequest(supplyItemId: dco_decode_i_32(arr[0]),
+quantity: dco_decode_f_64(arr[1]),
+unit: dco_decode_String(arr[2]),
+warehouseLocation: dco_decode_opt_String(arr[3]),
+warehouseType: dco_decode_warehouse_type(arr[4]),
+minimumQuantity: dco_decode_f_64(arr[5]),); }
+
+@protected CreateSupplierInvoiceLineRequest dco_decode_create_supplier_invoice_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return CreateSupplierInvoiceLineRequest(orderItemId: dco_decode_opt_box_autoadd_i_32(arr[0]),
+supplyItemId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+description: dco_decode_String(arr[2]),
+quantity: dco_decode_f_64(arr[3]),
+unitPrice: dco_decode_f_64(arr[4]),); }
+
+@protected CreateSupplierInvoiceRequest dco_decode_create_supplier_invoice_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return CreateSupplierInvoiceRequest(supplierId: dco_decode_i_32(arr[0]),
+invoiceNumber: dco_decode_String(arr[1]),
+orderId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+currency: dco_decode_String(arr[3]),
+issueDate: dco_decode_String(arr[4]),
+dueDate: dco_decode_String(arr[5]),
+notes: dco_decode_opt_String(arr[6]),
+lines: dco_decode_list_create_supplier_invoice_line_request(arr[7]),); }
+
+@protected CreateSupplierPaymentRequest dco_decode_create_supplier_payment_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CreateSupplierPaymentRequest(supplierInvoiceId: dco_decode_i_32(arr[0]),
+amount: dco_decode_f_64(arr[1]),
+paymentDate: dco_decode_String(arr[2]),
+method: dco_decode_payment_method(arr[3]),
+reference: dco_decode_opt_String(arr[4]),
+notes: dco_decode_opt_String(arr[5]),); }
+
+@protected CreateSupplierRequest dco_decode_create_supplier_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return CreateSupplierRequest(name: dco_decode_String(arr[0]),
+contactPerson: dco_decode_opt_String(arr[1]),
+email: dco_decode_opt_String(arr[2]),
+phone: dco_decode_opt_String(arr[3]),
+address: dco_decode_opt_String(arr[4]),
+country: dco_decode_opt_String(arr[5]),
+categoryId: dco_decode_i_32(arr[6]),
+leadTimeDays: dco_decode_opt_box_autoadd_i_32(arr[7]),); }
+
+@protected CreateSupplyItemRequest dco_decode_create_supply_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return CreateSupplyItemRequest(supplierId: dco_decode_i_32(arr[0]),
+impaCode: dco_decode_opt_String(arr[1]),
+supplierSku: dco_decode_opt_String(arr[2]),
+name: dco_decode_String(arr[3]),
+description: dco_decode_opt_String(arr[4]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+unit: dco_decode_String(arr[6]),
+unitPrice: dco_decode_f_64(arr[7]),
+currency: dco_decode_String(arr[8]),
+minimumOrderQuantity: dco_decode_opt_box_autoadd_i_32(arr[9]),
+unNumber: dco_decode_opt_String(arr[10]),
+hazardClass: dco_decode_opt_String(arr[11]),
+sdsRequired: dco_decode_bool(arr[12]),
+prescriptionOnly: dco_decode_bool(arr[13]),
+isBonded: dco_decode_bool(arr[14]),); }
+
+@protected CreateUnitRequest dco_decode_create_unit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CreateUnitRequest(code: dco_decode_String(arr[0]),
+nameTr: dco_decode_String(arr[1]),
+nameEn: dco_decode_opt_String(arr[2]),
+dimension: dco_decode_unit_dimension(arr[3]),
+factor: dco_decode_f_64(arr[4]),
+aliases: dco_decode_list_String(arr[5]),); }
+
+@protected CreditCheck dco_decode_credit_check(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return CreditCheck(customerId: dco_decode_i_32(arr[0]),
+customerName: dco_decode_String(arr[1]),
+creditLimit: dco_decode_opt_box_autoadd_f_64(arr[2]),
+currency: dco_decode_String(arr[3]),
+openReceivables: dco_decode_f_64(arr[4]),
+uninvoicedOrders: dco_decode_f_64(arr[5]),
+orderAmount: dco_decode_f_64(arr[6]),
+projectedExposure: dco_decode_f_64(arr[7]),
+exceedsLimit: dco_decode_bool(arr[8]),
+otherCurrencies: dco_decode_list_currency_exposure(arr[9]),
+warning: dco_decode_opt_String(arr[10]),); }
+
+@protected CreditNote dco_decode_credit_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return CreditNote(id: dco_decode_i_32(arr[0]),
+creditNoteNumber: dco_decode_String(arr[1]),
+invoiceId: dco_decode_i_32(arr[2]),
+invoiceNumber: dco_decode_opt_String(arr[3]),
+orderId: dco_decode_i_32(arr[4]),
+returnId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+customerName: dco_decode_String(arr[6]),
+amount: dco_decode_f_64(arr[7]),
+currency: dco_decode_String(arr[8]),
+issueDate: dco_decode_String(arr[9]),
+notes: dco_decode_opt_String(arr[10]),
+createdAt: dco_decode_String(arr[11]),); }
+
+@protected CurrencyExposure dco_decode_currency_exposure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return CurrencyExposure(currency: dco_decode_String(arr[0]),
+openReceivables: dco_decode_f_64(arr[1]),
+uninvoicedOrders: dco_decode_f_64(arr[2]),); }
+
+@protected CurrencyRounding dco_decode_currency_rounding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CurrencyRounding(currency: dco_decode_String(arr[0]),
+increment: dco_decode_f_64(arr[1]),); }
+
+@protected Customer dco_decode_customer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return Customer(id: dco_decode_i_32(arr[0]),
+legalName: dco_decode_String(arr[1]),
+taxNumber: dco_decode_opt_String(arr[2]),
+taxOffice: dco_decode_opt_String(arr[3]),
+billingAddress: dco_decode_opt_String(arr[4]),
+country: dco_decode_opt_String(arr[5]),
+paymentTermsDays: dco_decode_i_32(arr[6]),
+creditLimit: dco_decode_opt_box_autoadd_f_64(arr[7]),
+defaultCurrency: dco_decode_String(arr[8]),
+notes: dco_decode_opt_String(arr[9]),
+isActive: dco_decode_bool(arr[10]),
+contacts: dco_decode_list_customer_contact(arr[11]),
+createdAt: dco_decode_String(arr[12]),
+updatedAt: dco_decode_String(arr[13]),); }
+
+@protected CustomerBalance dco_decode_customer_balance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return CustomerBalance(customerId: dco_decode_i_32(arr[0]),
+customerName: dco_decode_String(arr[1]),
+currency: dco_decode_String(arr[2]),
+totalInvoiced: dco_decode_f_64(arr[3]),
+totalPaid: dco_decode_f_64(arr[4]),
+totalCredited: dco_decode_f_64(arr[5]),
+openBalance: dco_decode_f_64(arr[6]),
+credit: dco_decode_f_64(arr[7]),); }
+
+@protected CustomerContact dco_decode_customer_contact(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return CustomerContact(id: dco_decode_i_32(arr[0]),
+customerId: dco_decode_i_32(arr[1]),
+name: dco_decode_String(arr[2]),
+role: dco_decode_opt_String(arr[3]),
+email: dco_decode_opt_String(arr[4]),
+phone: dco_decode_opt_String(arr[5]),
+isPrimary: dco_decode_bool(arr[6]),); }
+
+@protected DangerousGoodsClassTotal dco_decode_dangerous_goods_class_total(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DangerousGoodsClassTotal(hazardClass: dco_decode_String(arr[0]),
+description: dco_decode_String(arr[1]),
+lineCount: dco_decode_i_32(arr[2]),
+unNumbers: dco_decode_list_String(arr[3]),); }
+
+@protected DangerousGoodsLine dco_decode_dangerous_goods_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return DangerousGoodsLine(orderItemId: dco_decode_i_32(arr[0]),
+productName: dco_decode_String(arr[1]),
+impaCode: dco_decode_opt_String(arr[2]),
+unNumber: dco_decode_String(arr[3]),
+hazardClass: dco_decode_String(arr[4]),
+quantity: dco_decode_f_64(arr[5]),
+unit: dco_decode_String(arr[6]),
+sdsOnFile: dco_decode_bool(arr[7]),); }
+
+@protected DangerousGoodsSummary dco_decode_dangerous_goods_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return DangerousGoodsSummary(deliveryNoteId: dco_decode_i_32(arr[0]),
+noteNumber: dco_decode_String(arr[1]),
+orderNumber: dco_decode_opt_String(arr[2]),
+shipName: dco_decode_opt_String(arr[3]),
+lines: dco_decode_list_dangerous_goods_line(arr[4]),
+classes: dco_decode_list_dangerous_goods_class_total(arr[5]),); }
+
+@protected DeliveryLine dco_decode_delivery_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DeliveryLine(orderItemId: dco_decode_i_32(arr[0]),
+quantity: dco_decode_f_64(arr[1]),); }
+
+@protected DeliveryNote dco_decode_delivery_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return DeliveryNote(id: dco_decode_i_32(arr[0]),
+noteNumber: dco_decode_String(arr[1]),
+orderId: dco_decode_i_32(arr[2]),
+orderNumber: dco_decode_opt_String(arr[3]),
+shipName: dco_decode_opt_String(arr[4]),
+shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+status: dco_decode_delivery_note_status(arr[6]),
+receiverName: dco_decode_opt_String(arr[7]),
+receiverRank: dco_decode_opt_String(arr[8]),
+signedAt: dco_decode_opt_String(arr[9]),
+notes: dco_decode_opt_String(arr[10]),
+lines: dco_decode_list_delivery_note_line(arr[11]),
+attachments: dco_decode_list_delivery_note_attachment(arr[12]),
+createdAt: dco_decode_String(arr[13]),); }
+
+@protected DeliveryNoteAttachment dco_decode_delivery_note_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return DeliveryNoteAttachment(id: dco_decode_i_32(arr[0]),
+deliveryNoteId: dco_decode_i_32(arr[1]),
+kind: dco_decode_delivery_note_attachment_kind(arr[2]),
+fileName: dco_decode_String(arr[3]),
+mimeType: dco_decode_String(arr[4]),
+sizeBytes: dco_decode_i_64(arr[5]),
+createdAt: dco_decode_String(arr[6]),); }
+
+@protected DeliveryNoteAttachmentKind dco_decode_delivery_note_attachment_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeliveryNoteAttachmentKind.values[raw as int]; }
+
+@protected DeliveryNoteLine dco_decode_delivery_note_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DeliveryNoteLine(orderItemId: dco_decode_i_32(arr[0]),
+productName: dco_decode_String(arr[1]),
+impaCode: dco_decode_opt_String(arr[2]),
+unit: dco_decode_String(arr[3]),
+quantity: dco_decode_f_64(arr[4]),); }
+
+@protected DeliveryNoteStatus dco_decode_delivery_note_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeliveryNoteStatus.values[raw as int]; }
+
+@protected DeliveryType dco_decode_delivery_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeliveryType.values[raw as int]; }
+
+@protected EquivalenceGroup dco_decode_equivalence_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return EquivalenceGroup(id: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+notes: dco_decode_opt_String(arr[2]),
+members: dco_decode_list_supply_item(arr[3]),
+createdAt: dco_decode_String(arr[4]),
+updatedAt: dco_decode_String(arr[5]),); }
+
+@protected ExportResult dco_decode_export_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ExportResult(files: dco_decode_list_String(arr[0]),
+rowCount: dco_decode_i_32(arr[1]),); }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FulfilmentStatus dco_decode_fulfilment_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FulfilmentStatus.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImpaCatalogEntry dco_decode_impa_catalog_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ImpaCatalogEntry(code: dco_decode_String(arr[0]),
+description: dco_decode_String(arr[1]),
+unit: dco_decode_opt_String(arr[2]),
+section: dco_decode_String(arr[3]),
+sectionName: dco_decode_opt_String(arr[4]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+category: dco_decode_opt_String(arr[6]),); }
+
+@protected ImpaCatalogImportRequest dco_decode_impa_catalog_import_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ImpaCatalogImportRequest(filePath: dco_decode_String(arr[0]),
+mapping: dco_decode_impa_column_mapping(arr[1]),
+headerRow: dco_decode_i_32(arr[2]),
+sheetName: dco_decode_opt_String(arr[3]),
+delimiter: dco_decode_opt_String(arr[4]),
+replaceExisting: dco_decode_bool(arr[5]),); }
+
+@protected ImpaCatalogImportResult dco_decode_impa_catalog_import_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ImpaCatalogImportResult(inserted: dco_decode_i_32(arr[0]),
+updated: dco_decode_i_32(arr[1]),
+sections: dco_decode_i_32(arr[2]),
+errors: dco_decode_list_String(arr[3]),); }
+
+@protected ImpaCodeIssue dco_decode_impa_code_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ImpaCodeIssue(supplyItemId: dco_decode_i_32(arr[0]),
+supplyItemName: dco_decode_String(arr[1]),
+supplierName: dco_decode_opt_String(arr[2]),
+impaCode: dco_decode_String(arr[3]),
+problem: dco_decode_impa_code_problem(arr[4]),
+message: dco_decode_String(arr[5]),); }
+
+@protected ImpaCodeProblem dco_decode_impa_code_problem(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ImpaCodeProblem.values[raw as int]; }
+
+@protected ImpaColumnMapping dco_decode_impa_column_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ImpaColumnMapping(code: dco_decode_String(arr[0]),
+description: dco_decode_String(arr[1]),
+unit: dco_decode_opt_String(arr[2]),
+section: dco_decode_opt_String(arr[3]),); }
+
+@protected ImpaSection dco_decode_impa_section(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ImpaSection(code: dco_decode_String(arr[0]),
+name: dco_decode_opt_String(arr[1]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+category: dco_decode_opt_String(arr[3]),
+entryCount: dco_decode_i_32(arr[4]),); }
+
+@protected InstantiateTemplateRequest dco_decode_instantiate_template_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return InstantiateTemplateRequest(templateId: dco_decode_i_32(arr[0]),
+shipId: dco_decode_i_32(arr[1]),
+shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+headcount: dco_decode_opt_box_autoadd_f_64(arr[3]),
+scaleFactor: dco_decode_opt_box_autoadd_f_64(arr[4]),
+currency: dco_decode_String(arr[5]),); }
+
+@protected Invoice dco_decode_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+                return Invoice(id: dco_decode_i_32(arr[0]),
+invoiceNumber: dco_decode_String(arr[1]),
+orderId: dco_decode_i_32(arr[2]),
+orderNumber: dco_decode_opt_String(arr[3]),
+customerId: dco_decode_opt_box_autoadd_i_32(arr[4]),
+customerName: dco_decode_String(arr[5]),
+amount: dco_decode_f_64(arr[6]),
+paidAmount: dco_decode_f_64(arr[7]),
+creditedAmount: dco_decode_f_64(arr[8]),
+balance: dco_decode_f_64(arr[9]),
+currency: dco_decode_String(arr[10]),
+issueDate: dco_decode_String(arr[11]),
+dueDate: dco_decode_String(arr[12]),
+status: dco_decode_invoice_status(arr[13]),
+notes: dco_decode_opt_String(arr[14]),
+createdAt: dco_decode_String(arr[15]),
+updatedAt: dco_decode_String(arr[16]),); }
+
+@protected InvoiceStatus dco_decode_invoice_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return InvoiceStatus.values[raw as int]; }
+
+@protected ItemDelivery dco_decode_item_delivery(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return ItemDelivery(id: dco_decode_i_32(arr[0]),
+orderItemId: dco_decode_i_32(arr[1]),
+productName: dco_decode_opt_String(arr[2]),
+quantity: dco_decode_f_64(arr[3]),
+deliveredAt: dco_decode_String(arr[4]),
+shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+notes: dco_decode_opt_String(arr[6]),
+createdAt: dco_decode_String(arr[7]),); }
+
+@protected ItemProfit dco_decode_item_profit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return ItemProfit(totalCost: dco_decode_f_64(arr[0]),
+totalRevenue: dco_decode_f_64(arr[1]),
+grossProfit: dco_decode_f_64(arr[2]),
+marginPercent: dco_decode_opt_box_autoadd_f_64(arr[3]),
+landedCost: dco_decode_f_64(arr[4]),
+minMarginPercent: dco_decode_opt_box_autoadd_f_64(arr[5]),
+belowMarginFloor: dco_decode_bool(arr[6]),); }
+
+@protected ItemWeight dco_decode_item_weight(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ItemWeight(orderItemId: dco_decode_i_32(arr[0]),
+weightKg: dco_decode_f_64(arr[1]),); }
+
+@protected LandedCost dco_decode_landed_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return LandedCost(id: dco_decode_i_32(arr[0]),
+orderId: dco_decode_i_32(arr[1]),
+orderNumber: dco_decode_opt_String(arr[2]),
+stockMovementId: dco_decode_opt_box_autoadd_i_32(arr[3]),
+costType: dco_decode_landed_cost_type(arr[4]),
+description: dco_decode_opt_String(arr[5]),
+amount: dco_decode_f_64(arr[6]),
+currency: dco_decode_String(arr[7]),
+allocationMethod: dco_decode_allocation_method(arr[8]),
+reference: dco_decode_opt_String(arr[9]),
+allocations: dco_decode_list_landed_cost_allocation(arr[10]),
+createdAt: dco_decode_String(arr[11]),); }
+
+@protected LandedCostAllocation dco_decode_landed_cost_allocation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return LandedCostAllocation(id: dco_decode_i_32(arr[0]),
+landedCostId: dco_decode_i_32(arr[1]),
+orderItemId: dco_decode_i_32(arr[2]),
+productName: dco_decode_opt_String(arr[3]),
+basis: dco_decode_f_64(arr[4]),
+amount: dco_decode_f_64(arr[5]),
+amountPerUnit: dco_decode_f_64(arr[6]),); }
+
+@protected LandedCostType dco_decode_landed_cost_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LandedCostType.values[raw as int]; }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<Attachment> dco_decode_list_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_attachment).toList(); }
+
+@protected List<BondedReconciliationLine> dco_decode_list_bonded_reconciliation_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_bonded_reconciliation_line).toList(); }
+
+@protected List<CalendarEvent> dco_decode_list_calendar_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_calendar_event).toList(); }
+
+@protected List<Category> dco_decode_list_category(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_category).toList(); }
+
+@protected List<ComplianceIssue> dco_decode_list_compliance_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_compliance_issue).toList(); }
+
+@protected List<CreateCustomerContactRequest> dco_decode_list_create_customer_contact_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_create_customer_contact_request).toList(); }
+
+@protected List<CreatePriceAgreementLineRequest> dco_decode_list_create_price_agreement_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_create_price_agreement_line_request).toList(); }
+
+@protected List<CreateSupplierInvoiceLineRequest> dco_decode_list_create_supplier_invoice_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_create_supplier_invoice_line_request).toList(); }
+
+@protected List<CreditNote> dco_decode_list_credit_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_credit_note).toList(); }
+
+@protected List<CurrencyExposure> dco_decode_list_currency_exposure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_currency_exposure).toList(); }
+
+@protected List<CurrencyRounding> dco_decode_list_currency_rounding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_currency_rounding).toList(); }
+
+@protected List<Customer> dco_decode_list_customer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_customer).toList(); }
+
+@protected List<CustomerBalance> dco_decode_list_customer_balance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_customer_balance).toList(); }
+
+@protected List<CustomerContact> dco_decode_list_customer_contact(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_customer_contact).toList(); }
+
+@protected List<DangerousGoodsClassTotal> dco_decode_list_dangerous_goods_class_total(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_dangerous_goods_class_total).toList(); }
+
+@protected List<DangerousGoodsLine> dco_decode_list_dangerous_goods_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_dangerous_goods_line).toList(); }
+
+@protected List<DeliveryLine> dco_decode_list_delivery_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_delivery_line).toList(); }
+
+@protected List<DeliveryNote> dco_decode_list_delivery_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_delivery_note).toList(); }
+
+@protected List<DeliveryNoteAttachment> dco_decode_list_delivery_note_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_delivery_note_attachment).toList(); }
+
+@protected List<DeliveryNoteLine> dco_decode_list_delivery_note_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_delivery_note_line).toList(); }
+
+@protected List<EquivalenceGroup> dco_decode_list_equivalence_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_equivalence_group).toList(); }
+
+@protected List<ImpaCatalogEntry> dco_decode_list_impa_catalog_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_impa_catalog_entry).toList(); }
+
+@protected List<ImpaCodeIssue> dco_decode_list_impa_code_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_impa_code_issue).toList(); }
+
+@protected List<ImpaSection> dco_decode_list_impa_section(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_impa_section).toList(); }
+
+@protected List<Invoice> dco_decode_list_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_invoice).toList(); }
+
+@protected List<ItemDelivery> dco_decode_list_item_delivery(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_item_delivery).toList(); }
+
+@protected List<ItemWeight> dco_decode_list_item_weight(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_item_weight).toList(); }
+
+@protected List<LandedCost> dco_decode_list_landed_cost(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_landed_cost).toList(); }
+
+@protected List<LandedCostAllocation> dco_decode_list_landed_cost_allocation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_landed_cost_allocation).toList(); }
+
+@protected List<OffContractLine> dco_decode_list_off_contract_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_off_contract_line).toList(); }
+
+@protected List<Order> dco_decode_list_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_order).toList(); }
+
+@protected List<OrderCharge> dco_decode_list_order_charge(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_order_charge).toList(); }
+
+@protected List<OrderItem> dco_decode_list_order_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_order_item).toList(); }
+
+@protected List<OrderLink> dco_decode_list_order_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_order_link).toList(); }
+
+@protected List<OrderProfitInfo> dco_decode_list_order_profit_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_order_profit_info).toList(); }
+
+@protected List<OrderReturn> dco_decode_list_order_return(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_order_return).toList(); }
+
+@protected List<OrderReturnLine> dco_decode_list_order_return_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_order_return_line).toList(); }
+
+@protected List<PackSize> dco_decode_list_pack_size(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_pack_size).toList(); }
+
+@protected List<PayableDue> dco_decode_list_payable_due(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_payable_due).toList(); }
+
+@protected List<Payment> dco_decode_list_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_payment).toList(); }
+
+@protected List<PaymentAllocation> dco_decode_list_payment_allocation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_payment_allocation).toList(); }
+
+@protected List<PaymentAllocationRequest> dco_decode_list_payment_allocation_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_payment_allocation_request).toList(); }
+
+@protected List<Port> dco_decode_list_port(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_port).toList(); }
+
+@protected List<PriceAgreement> dco_decode_list_price_agreement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_price_agreement).toList(); }
+
+@protected List<PriceAgreementLine> dco_decode_list_price_agreement_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_price_agreement_line).toList(); }
+
+@protected List<PriceChange> dco_decode_list_price_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_price_change).toList(); }
+
+@protected List<PriceListChange> dco_decode_list_price_list_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_price_list_change).toList(); }
+
+@protected List<PriceRiseItem> dco_decode_list_price_rise_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_price_rise_item).toList(); }
+
+@protected List<PricingRule> dco_decode_list_pricing_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_pricing_rule).toList(); }
+
+@protected List<int> dco_decode_list_prim_i_32_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int32List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<ProductAlternative> dco_decode_list_product_alternative(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_product_alternative).toList(); }
+
+@protected List<ReceivablesAgingRow> dco_decode_list_receivables_aging_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_receivables_aging_row).toList(); }
+
+@protected List<RepricedItem> dco_decode_list_repriced_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_repriced_item).toList(); }
+
+@protected List<RequisitionImportLine> dco_decode_list_requisition_import_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_requisition_import_line).toList(); }
+
+@protected List<RequisitionTemplate> dco_decode_list_requisition_template(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_requisition_template).toList(); }
+
+@protected List<RequisitionTemplateLine> dco_decode_list_requisition_template_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_requisition_template_line).toList(); }
+
+@protected List<RequisitionTemplateLineRequest> dco_decode_list_requisition_template_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_requisition_template_line_request).toList(); }
+
+@protected List<ReturnLineRequest> dco_decode_list_return_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_return_line_request).toList(); }
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_hit).toList(); }
+
+@protected List<SearchScope> dco_decode_list_search_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_scope).toList(); }
+
+@protected List<Ship> dco_decode_list_ship(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ship).toList(); }
+
+@protected List<ShipVisit> dco_decode_list_ship_visit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ship_visit).toList(); }
+
+@protected List<Stock> dco_decode_list_stock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_stock).toList(); }
+
+@protected List<StockMovement> dco_decode_list_stock_movement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_stock_movement).toList(); }
+
+@protected List<Supplier> dco_decode_list_supplier(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_supplier).toList(); }
+
+@protected List<SupplierInvoice> dco_decode_list_supplier_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_supplier_invoice).toList(); }
+
+@protected List<SupplierInvoiceLine> dco_decode_list_supplier_invoice_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_supplier_invoice_line).toList(); }
+
+@protected List<SupplierInvoiceLineMatch> dco_decode_list_supplier_invoice_line_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_supplier_invoice_line_match).toList(); }
+
+@protected List<SupplierPayment> dco_decode_list_supplier_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_supplier_payment).toList(); }
+
+@protected List<SupplyItem> dco_decode_list_supply_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_supply_item).toList(); }
+
+@protected List<UnitOfMeasure> dco_decode_list_unit_of_measure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_unit_of_measure).toList(); }
+
+@protected MatchStatus dco_decode_match_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MatchStatus.values[raw as int]; }
+
+@protected OffContractLine dco_decode_off_contract_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return OffContractLine(orderId: dco_decode_i_32(arr[0]),
+orderNumber: dco_decode_String(arr[1]),
+orderItemId: dco_decode_i_32(arr[2]),
+customerId: dco_decode_i_32(arr[3]),
+customerName: dco_decode_String(arr[4]),
+productName: dco_decode_String(arr[5]),
+impaCode: dco_decode_String(arr[6]),
+quantity: dco_decode_f_64(arr[7]),
+currency: dco_decode_String(arr[8]),
+sellingPrice: dco_decode_f_64(arr[9]),
+contractPrice: dco_decode_f_64(arr[10]),
+revenueDifference: dco_decode_f_64(arr[11]),
+agreementId: dco_decode_i_32(arr[12]),
+agreementName: dco_decode_String(arr[13]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected Attachment? dco_decode_opt_box_autoadd_attachment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_attachment(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected Category? dco_decode_opt_box_autoadd_category(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_category(raw); }
+
+@protected ChargeBilling? dco_decode_opt_box_autoadd_charge_billing(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_charge_billing(raw); }
+
+@protected ContractPrice? dco_decode_opt_box_autoadd_contract_price(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_contract_price(raw); }
+
+@protected CreditCheck? dco_decode_opt_box_autoadd_credit_check(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_credit_check(raw); }
+
+@protected Customer? dco_decode_opt_box_autoadd_customer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_customer(raw); }
+
+@protected DeliveryNote? dco_decode_opt_box_autoadd_delivery_note(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_delivery_note(raw); }
+
+@protected DeliveryType? dco_decode_opt_box_autoadd_delivery_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_delivery_type(raw); }
+
+@protected EquivalenceGroup? dco_decode_opt_box_autoadd_equivalence_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_equivalence_group(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_32(raw); }
+
+@protected ImpaCatalogEntry? dco_decode_opt_box_autoadd_impa_catalog_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_impa_catalog_entry(raw); }
+
+@protected Invoice? dco_decode_opt_box_autoadd_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_invoice(raw); }
+
+@protected InvoiceStatus? dco_decode_opt_box_autoadd_invoice_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_invoice_status(raw); }
+
+@protected OrderReturn? dco_decode_opt_box_autoadd_order_return(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_order_return(raw); }
+
+@protected OrderStatus? dco_decode_opt_box_autoadd_order_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_order_status(raw); }
+
+@protected OrderWithItems? dco_decode_opt_box_autoadd_order_with_items(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_order_with_items(raw); }
+
+@protected Payment? dco_decode_opt_box_autoadd_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_payment(raw); }
+
+@protected Port? dco_decode_opt_box_autoadd_port(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_port(raw); }
+
+@protected PriceAgreement? dco_decode_opt_box_autoadd_price_agreement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_price_agreement(raw); }
+
+@protected PriceListMatchKind? dco_decode_opt_box_autoadd_price_list_match_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_price_list_match_kind(raw); }
+
+@protected ProductAlternative? dco_decode_opt_box_autoadd_product_alternative(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_product_alternative(raw); }
+
+@protected RequisitionTemplate? dco_decode_opt_box_autoadd_requisition_template(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_requisition_template(raw); }
+
+@protected Ship? dco_decode_opt_box_autoadd_ship(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ship(raw); }
+
+@protected ShipVisit? dco_decode_opt_box_autoadd_ship_visit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ship_visit(raw); }
+
+@protected Stock? dco_decode_opt_box_autoadd_stock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_stock(raw); }
+
+@protected StockWithMovements? dco_decode_opt_box_autoadd_stock_with_movements(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_stock_with_movements(raw); }
+
+@protected Supplier? dco_decode_opt_box_autoadd_supplier(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_supplier(raw); }
+
+@protected SupplierInvoice? dco_decode_opt_box_autoadd_supplier_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_supplier_invoice(raw); }
+
+@protected SupplyItem? dco_decode_opt_box_autoadd_supply_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_supply_item(raw); }
+
+@protected VisitStatus? dco_decode_opt_box_autoadd_visit_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_visit_status(raw); }
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_String(raw); }
+
+@protected Int32List? dco_decode_opt_list_prim_i_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_i_32_strict(raw); }
+
+@protected List<RequisitionTemplateLineRequest>? dco_decode_opt_list_requisition_template_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_requisition_template_line_request(raw); }
+
+@protected Order dco_decode_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+                return Order(id: dco_decode_i_32(arr[0]),
+orderNumber: dco_decode_String(arr[1]),
+shipId: dco_decode_i_32(arr[2]),
+shipName: dco_decode_opt_String(arr[3]),
+shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[4]),
+shipVisitInfo: dco_decode_opt_String(arr[5]),
+customerId: dco_decode_opt_box_autoadd_i_32(arr[6]),
+customerName: dco_decode_opt_String(arr[7]),
+paymentTermsDays: dco_decode_opt_box_autoadd_i_32(arr[8]),
+status: dco_decode_order_status(arr[9]),
+deliveryPort: dco_decode_opt_String(arr[10]),
+notes: dco_decode_opt_String(arr[11]),
+currency: dco_decode_String(arr[12]),
+parentOrderId: dco_decode_opt_box_autoadd_i_32(arr[13]),
+deliveryNoteWaiver: dco_decode_opt_String(arr[14]),
+createdAt: dco_decode_String(arr[15]),
+updatedAt: dco_decode_String(arr[16]),); }
+
+@protected OrderCharge dco_decode_order_charge(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return OrderCharge(id: dco_decode_i_32(arr[0]),
+orderId: dco_decode_i_32(arr[1]),
+chargeType: dco_decode_charge_type(arr[2]),
+description: dco_decode_opt_String(arr[3]),
+billing: dco_decode_charge_billing(arr[4]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[5]),
+costAmount: dco_decode_f_64(arr[6]),
+revenueAmount: dco_decode_f_64(arr[7]),
+currency: dco_decode_String(arr[8]),
+notes: dco_decode_opt_String(arr[9]),
+createdAt: dco_decode_String(arr[10]),); }
+
+@protected OrderCompliance dco_decode_order_compliance(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return OrderCompliance(orderId: dco_decode_i_32(arr[0]),
+orderNumber: dco_decode_String(arr[1]),
+regulatedLines: dco_decode_i_32(arr[2]),
+issues: dco_decode_list_compliance_issue(arr[3]),
+isCompliant: dco_decode_bool(arr[4]),); }
+
+@protected OrderExportFilter dco_decode_order_export_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return OrderExportFilter(status: dco_decode_opt_box_autoadd_order_status(arr[0]),
+shipId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+customerId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+dateFrom: dco_decode_opt_String(arr[3]),
+dateTo: dco_decode_opt_String(arr[4]),); }
+
+@protected OrderItem dco_decode_order_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 23) throw Exception('unexpected arr length: expect 23 but see ${arr.length}');
+                return OrderItem(id: dco_decode_i_32(arr[0]),
+orderId: dco_decode_i_32(arr[1]),
+productName: dco_decode_String(arr[2]),
+impaCode: dco_decode_opt_String(arr[3]),
+description: dco_decode_opt_String(arr[4]),
+quantity: dco_decode_f_64(arr[5]),
+unit: dco_decode_String(arr[6]),
+buyingPrice: dco_decode_f_64(arr[7]),
+sellingPrice: dco_decode_f_64(arr[8]),
+currency: dco_decode_String(arr[9]),
+deliveryType: dco_decode_delivery_type(arr[10]),
+warehouseDeliveryDate: dco_decode_opt_String(arr[11]),
+shipDeliveryDate: dco_decode_opt_String(arr[12]),
+notes: dco_decode_opt_String(arr[13]),
+deliveredQuantity: dco_decode_f_64(arr[14]),
+backOrderedQuantity: dco_decode_f_64(arr[15]),
+rolledOverQuantity: dco_decode_f_64(arr[16]),
+closedQuantity: dco_decode_f_64(arr[17]),
+closedAt: dco_decode_opt_String(arr[18]),
+closeReason: dco_decode_opt_String(arr[19]),
+fulfilment: dco_decode_fulfilment_status(arr[20]),
+backorderOfItemId: dco_decode_opt_box_autoadd_i_32(arr[21]),
+returnedQuantity: dco_decode_f_64(arr[22]),); }
+
+@protected OrderLink dco_decode_order_link(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return OrderLink(id: dco_decode_i_32(arr[0]),
+linkType: dco_decode_order_link_type(arr[1]),
+sourceOrderId: dco_decode_i_32(arr[2]),
+sourceOrderNumber: dco_decode_opt_String(arr[3]),
+targetOrderId: dco_decode_i_32(arr[4]),
+targetOrderNumber: dco_decode_opt_String(arr[5]),
+orderItemIds: dco_decode_list_prim_i_32_strict(arr[6]),
+createdAt: dco_decode_String(arr[7]),); }
+
+@protected OrderLinkType dco_decode_order_link_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OrderLinkType.values[raw as int]; }
+
+@protected OrderProfitInfo dco_decode_order_profit_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return OrderProfitInfo(orderId: dco_decode_i_32(arr[0]),
+orderNumber: dco_decode_String(arr[1]),
+shipName: dco_decode_String(arr[2]),
+totalRevenue: dco_decode_f_64(arr[3]),
+totalCost: dco_decode_f_64(arr[4]),
+profit: dco_decode_f_64(arr[5]),
+marginPercent: dco_decode_f_64(arr[6]),
+currency: dco_decode_String(arr[7]),); }
+
+@protected OrderReturn dco_decode_order_return(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return OrderReturn(id: dco_decode_i_32(arr[0]),
+returnNumber: dco_decode_String(arr[1]),
+orderId: dco_decode_i_32(arr[2]),
+orderNumber: dco_decode_opt_String(arr[3]),
+returnDate: dco_decode_String(arr[4]),
+notes: dco_decode_opt_String(arr[5]),
+creditAmount: dco_decode_f_64(arr[6]),
+currency: dco_decode_String(arr[7]),
+creditNoteId: dco_decode_opt_box_autoadd_i_32(arr[8]),
+creditNoteNumber: dco_decode_opt_String(arr[9]),
+lines: dco_decode_list_order_return_line(arr[10]),
+createdAt: dco_decode_String(arr[11]),); }
+
+@protected OrderReturnLine dco_decode_order_return_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return OrderReturnLine(id: dco_decode_i_32(arr[0]),
+orderItemId: dco_decode_i_32(arr[1]),
+productName: dco_decode_opt_String(arr[2]),
+quantity: dco_decode_f_64(arr[3]),
+unit: dco_decode_opt_String(arr[4]),
+reason: dco_decode_return_reason(arr[5]),
+restocked: dco_decode_bool(arr[6]),
+stockMovementId: dco_decode_opt_box_autoadd_i_32(arr[7]),
+creditAmount: dco_decode_f_64(arr[8]),
+costAmount: dco_decode_f_64(arr[9]),); }
+
+@protected OrderStatus dco_decode_order_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OrderStatus.values[raw as int]; }
+
+@protected OrderTotals dco_decode_order_totals(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return OrderTotals(itemCount: dco_decode_i_32(arr[0]),
+chargeCount: dco_decode_i_32(arr[1]),
+totalCost: dco_decode_f_64(arr[2]),
+totalRevenue: dco_decode_f_64(arr[3]),
+chargesCost: dco_decode_f_64(arr[4]),
+chargesRevenue: dco_decode_f_64(arr[5]),
+landedCost: dco_decode_f_64(arr[6]),
+returnsRevenue: dco_decode_f_64(arr[7]),
+returnsCost: dco_decode_f_64(arr[8]),
+grossProfit: dco_decode_f_64(arr[9]),
+marginPercent: dco_decode_opt_box_autoadd_f_64(arr[10]),
+currency: dco_decode_String(arr[11]),); }
+
+@protected OrderWithItems dco_decode_order_with_items(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return OrderWithItems(order: dco_decode_order(arr[0]),
+items: dco_decode_list_order_item(arr[1]),
+totals: dco_decode_order_totals(arr[2]),
+creditCheck: dco_decode_opt_box_autoadd_credit_check(arr[3]),); }
+
+@protected PackSize dco_decode_pack_size(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PackSize(id: dco_decode_i_32(arr[0]),
+supplyItemId: dco_decode_i_32(arr[1]),
+packUnit: dco_decode_String(arr[2]),
+quantity: dco_decode_f_64(arr[3]),
+contentUnit: dco_decode_String(arr[4]),); }
+
+@protected PayableDue dco_decode_payable_due(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return PayableDue(supplierInvoiceId: dco_decode_i_32(arr[0]),
+supplierId: dco_decode_i_32(arr[1]),
+supplierName: dco_decode_opt_String(arr[2]),
+invoiceNumber: dco_decode_String(arr[3]),
+currency: dco_decode_String(arr[4]),
+dueDate: dco_decode_String(arr[5]),
+balance: dco_decode_f_64(arr[6]),
+daysUntilDue: dco_decode_i_64(arr[7]),); }
+
+@protected Payment dco_decode_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return Payment(id: dco_decode_i_32(arr[0]),
+customerId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+customerName: dco_decode_String(arr[2]),
+amount: dco_decode_f_64(arr[3]),
+currency: dco_decode_String(arr[4]),
+allocatedAmount: dco_decode_f_64(arr[5]),
+unallocatedAmount: dco_decode_f_64(arr[6]),
+paymentDate: dco_decode_String(arr[7]),
+method: dco_decode_payment_method(arr[8]),
+reference: dco_decode_opt_String(arr[9]),
+notes: dco_decode_opt_String(arr[10]),
+allocations: dco_decode_list_payment_allocation(arr[11]),
+createdAt: dco_decode_String(arr[12]),); }
+
+@protected PaymentAllocation dco_decode_payment_allocation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PaymentAllocation(id: dco_decode_i_32(arr[0]),
+paymentId: dco_decode_i_32(arr[1]),
+invoiceId: dco_decode_i_32(arr[2]),
+invoiceNumber: dco_decode_opt_String(arr[3]),
+amount: dco_decode_f_64(arr[4]),
+exchangeRate: dco_decode_f_64(arr[5]),
+invoiceAmount: dco_decode_f_64(arr[6]),); }
+
+@protected PaymentAllocationRequest dco_decode_payment_allocation_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PaymentAllocationRequest(invoiceId: dco_decode_i_32(arr[0]),
+amount: dco_decode_f_64(arr[1]),
+exchangeRate: dco_decode_opt_box_autoadd_f_64(arr[2]),); }
+
+@protected PaymentMethod dco_decode_payment_method(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaymentMethod.values[raw as int]; }
+
+@protected PdfDocumentKind dco_decode_pdf_document_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PdfDocumentKind.values[raw as int]; }
+
+@protected PdfRenderRequest dco_decode_pdf_render_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PdfRenderRequest(orderId: dco_decode_i_32(arr[0]),
+kind: dco_decode_pdf_document_kind(arr[1]),
+documentId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+taxRatePercent: dco_decode_f_64(arr[3]),
+outputPath: dco_decode_String(arr[4]),); }
+
+@protected PdfRenderResult dco_decode_pdf_render_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PdfRenderResult(filePath: dco_decode_String(arr[0]),
+pageCount: dco_decode_i_32(arr[1]),); }
+
+@protected PharmacistSignOffRequest dco_decode_pharmacist_sign_off_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PharmacistSignOffRequest(pharmacistName: dco_decode_String(arr[0]),
+licenseNumber: dco_decode_String(arr[1]),
+notes: dco_decode_opt_String(arr[2]),); }
+
+@protected Port dco_decode_port(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return Port(id: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+country: dco_decode_String(arr[2]),
+city: dco_decode_opt_String(arr[3]),
+timezone: dco_decode_String(arr[4]),
+latitude: dco_decode_opt_box_autoadd_f_64(arr[5]),
+longitude: dco_decode_opt_box_autoadd_f_64(arr[6]),
+notes: dco_decode_opt_String(arr[7]),
+isInternational: dco_decode_bool(arr[8]),
+isActive: dco_decode_bool(arr[9]),
+createdAt: dco_decode_String(arr[10]),
+updatedAt: dco_decode_String(arr[11]),); }
+
+@protected PriceAgreement dco_decode_price_agreement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return PriceAgreement(id: dco_decode_i_32(arr[0]),
+customerId: dco_decode_i_32(arr[1]),
+customerName: dco_decode_opt_String(arr[2]),
+name: dco_decode_String(arr[3]),
+currency: dco_decode_String(arr[4]),
+validFrom: dco_decode_String(arr[5]),
+validTo: dco_decode_String(arr[6]),
+notes: dco_decode_opt_String(arr[7]),
+isActive: dco_decode_bool(arr[8]),
+lines: dco_decode_list_price_agreement_line(arr[9]),
+createdAt: dco_decode_String(arr[10]),
+updatedAt: dco_decode_String(arr[11]),); }
+
+@protected PriceAgreementLine dco_decode_price_agreement_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PriceAgreementLine(id: dco_decode_i_32(arr[0]),
+agreementId: dco_decode_i_32(arr[1]),
+impaCode: dco_decode_opt_String(arr[2]),
+impaGroup: dco_decode_opt_String(arr[3]),
+description: dco_decode_opt_String(arr[4]),
+fixedPrice: dco_decode_opt_box_autoadd_f_64(arr[5]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[6]),); }
+
+@protected PriceChange dco_decode_price_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return PriceChange(id: dco_decode_i_32(arr[0]),
+supplyItemId: dco_decode_i_32(arr[1]),
+effectiveDate: dco_decode_String(arr[2]),
+oldPrice: dco_decode_opt_box_autoadd_f_64(arr[3]),
+oldCurrency: dco_decode_opt_String(arr[4]),
+newPrice: dco_decode_f_64(arr[5]),
+currency: dco_decode_String(arr[6]),
+changePercent: dco_decode_opt_box_autoadd_f_64(arr[7]),
+source: dco_decode_price_change_source(arr[8]),
+reference: dco_decode_opt_String(arr[9]),
+createdAt: dco_decode_String(arr[10]),); }
+
+@protected PriceChangeSource dco_decode_price_change_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceChangeSource.values[raw as int]; }
+
+@protected PriceListChange dco_decode_price_list_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return PriceListChange(rowNumber: dco_decode_opt_box_autoadd_i_32(arr[0]),
+kind: dco_decode_price_list_change_kind(arr[1]),
+matchedBy: dco_decode_opt_box_autoadd_price_list_match_kind(arr[2]),
+supplyItemId: dco_decode_opt_box_autoadd_i_32(arr[3]),
+supplierSku: dco_decode_opt_String(arr[4]),
+impaCode: dco_decode_opt_String(arr[5]),
+name: dco_decode_String(arr[6]),
+unit: dco_decode_opt_String(arr[7]),
+oldPrice: dco_decode_opt_box_autoadd_f_64(arr[8]),
+oldCurrency: dco_decode_opt_String(arr[9]),
+newPrice: dco_decode_opt_box_autoadd_f_64(arr[10]),
+currency: dco_decode_opt_String(arr[11]),
+changePercent: dco_decode_opt_box_autoadd_f_64(arr[12]),
+error: dco_decode_opt_String(arr[13]),); }
+
+@protected PriceListChangeKind dco_decode_price_list_change_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceListChangeKind.values[raw as int]; }
+
+@protected PriceListColumnMapping dco_decode_price_list_column_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PriceListColumnMapping(supplierSku: dco_decode_opt_String(arr[0]),
+impaCode: dco_decode_opt_String(arr[1]),
+name: dco_decode_String(arr[2]),
+unit: dco_decode_opt_String(arr[3]),
+price: dco_decode_String(arr[4]),
+currency: dco_decode_opt_String(arr[5]),); }
+
+@protected PriceListImportRequest dco_decode_price_list_import_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return PriceListImportRequest(supplierId: dco_decode_i_32(arr[0]),
+filePath: dco_decode_String(arr[1]),
+mapping: dco_decode_price_list_column_mapping(arr[2]),
+headerRow: dco_decode_i_32(arr[3]),
+sheetName: dco_decode_opt_String(arr[4]),
+delimiter: dco_decode_opt_String(arr[5]),
+currency: dco_decode_String(arr[6]),
+defaultCategoryId: dco_decode_opt_box_autoadd_i_32(arr[7]),
+effectiveDate: dco_decode_opt_String(arr[8]),
+discontinueMissing: dco_decode_bool(arr[9]),); }
+
+@protected PriceListImportResult dco_decode_price_list_import_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return PriceListImportResult(created: dco_decode_i_32(arr[0]),
+updated: dco_decode_i_32(arr[1]),
+unchanged: dco_decode_i_32(arr[2]),
+discontinued: dco_decode_i_32(arr[3]),); }
+
+@protected PriceListMatchKind dco_decode_price_list_match_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceListMatchKind.values[raw as int]; }
+
+@protected PriceListPreview dco_decode_price_list_preview(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return PriceListPreview(supplierId: dco_decode_i_32(arr[0]),
+supplierName: dco_decode_String(arr[1]),
+lines: dco_decode_list_price_list_change(arr[2]),
+newItems: dco_decode_i_32(arr[3]),
+priceUp: dco_decode_i_32(arr[4]),
+priceDown: dco_decode_i_32(arr[5]),
+currencyChanged: dco_decode_i_32(arr[6]),
+unchanged: dco_decode_i_32(arr[7]),
+discontinued: dco_decode_i_32(arr[8]),
+errors: dco_decode_i_32(arr[9]),); }
+
+@protected PriceProposal dco_decode_price_proposal(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return PriceProposal(buyingPrice: dco_decode_f_64(arr[0]),
+sellingPrice: dco_decode_f_64(arr[1]),
+currency: dco_decode_String(arr[2]),
+source: dco_decode_price_source(arr[3]),
+ruleId: dco_decode_opt_box_autoadd_i_32(arr[4]),
+agreementId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[6]),
+minMarginPercent: dco_decode_opt_box_autoadd_f_64(arr[7]),
+marginPercent: dco_decode_opt_box_autoadd_f_64(arr[8]),
+floorApplied: dco_decode_bool(arr[9]),
+belowMarginFloor: dco_decode_bool(arr[10]),
+roundingIncrement: dco_decode_f_64(arr[11]),
+explanation: dco_decode_String(arr[12]),); }
+
+@protected PriceRiseItem dco_decode_price_rise_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return PriceRiseItem(supplyItemId: dco_decode_i_32(arr[0]),
+supplyItemName: dco_decode_String(arr[1]),
+impaCode: dco_decode_opt_String(arr[2]),
+supplierId: dco_decode_i_32(arr[3]),
+supplierName: dco_decode_opt_String(arr[4]),
+currency: dco_decode_String(arr[5]),
+startPrice: dco_decode_f_64(arr[6]),
+endPrice: dco_decode_f_64(arr[7]),
+changePercent: dco_decode_f_64(arr[8]),
+changeCount: dco_decode_i_32(arr[9]),
+lastChangeDate: dco_decode_String(arr[10]),); }
+
+@protected PriceSource dco_decode_price_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceSource.values[raw as int]; }
+
+@protected PricingRule dco_decode_pricing_rule(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return PricingRule(id: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+category: dco_decode_opt_String(arr[3]),
+supplierId: dco_decode_opt_box_autoadd_i_32(arr[4]),
+supplierName: dco_decode_opt_String(arr[5]),
+customerId: dco_decode_opt_box_autoadd_i_32(arr[6]),
+customerName: dco_decode_opt_String(arr[7]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[8]),
+minMarginPercent: dco_decode_opt_box_autoadd_f_64(arr[9]),
+notes: dco_decode_opt_String(arr[10]),
+isActive: dco_decode_bool(arr[11]),
+createdAt: dco_decode_String(arr[12]),
+updatedAt: dco_decode_String(arr[13]),); }
+
+@protected ProductAlternative dco_decode_product_alternative(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return ProductAlternative(supplyItemId: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+impaCode: dco_decode_opt_String(arr[2]),
+supplierSku: dco_decode_opt_String(arr[3]),
+supplierId: dco_decode_i_32(arr[4]),
+supplierName: dco_decode_opt_String(arr[5]),
+unit: dco_decode_String(arr[6]),
+unitPrice: dco_decode_f_64(arr[7]),
+currency: dco_decode_String(arr[8]),
+isAvailable: dco_decode_bool(arr[9]),
+stockQuantity: dco_decode_f_64(arr[10]),
+leadTimeDays: dco_decode_opt_box_autoadd_i_32(arr[11]),
+linkedBy: dco_decode_alternative_link(arr[12]),); }
+
+@protected ProfitSummary dco_decode_profit_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ProfitSummary(totalOrders: dco_decode_i_32(arr[0]),
+totalRevenue: dco_decode_f_64(arr[1]),
+totalCost: dco_decode_f_64(arr[2]),
+totalProfit: dco_decode_f_64(arr[3]),
+averageMargin: dco_decode_opt_box_autoadd_f_64(arr[4]),
+currency: dco_decode_String(arr[5]),); }
+
+@protected ReceivablesAgingRow dco_decode_receivables_aging_row(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return ReceivablesAgingRow(customerId: dco_decode_opt_box_autoadd_i_32(arr[0]),
+customerName: dco_decode_String(arr[1]),
+currency: dco_decode_String(arr[2]),
+days030: dco_decode_f_64(arr[3]),
+days3160: dco_decode_f_64(arr[4]),
+days6190: dco_decode_f_64(arr[5]),
+daysOver90: dco_decode_f_64(arr[6]),
+total: dco_decode_f_64(arr[7]),
+credit: dco_decode_f_64(arr[8]),); }
+
+@protected RecordDeliveryRequest dco_decode_record_delivery_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RecordDeliveryRequest(orderId: dco_decode_i_32(arr[0]),
+deliveredAt: dco_decode_opt_String(arr[1]),
+shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+notes: dco_decode_opt_String(arr[3]),
+lines: dco_decode_list_delivery_line(arr[4]),); }
+
+@protected RepriceResult dco_decode_reprice_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RepriceResult(orderId: dco_decode_i_32(arr[0]),
+updatedCount: dco_decode_i_32(arr[1]),
+unchangedCount: dco_decode_i_32(arr[2]),
+skippedCount: dco_decode_i_32(arr[3]),
+items: dco_decode_list_repriced_item(arr[4]),); }
+
+@protected RepricedItem dco_decode_repriced_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RepricedItem(orderItemId: dco_decode_i_32(arr[0]),
+productName: dco_decode_String(arr[1]),
+impaCode: dco_decode_opt_String(arr[2]),
+oldSellingPrice: dco_decode_f_64(arr[3]),
+newSellingPrice: dco_decode_f_64(arr[4]),
+proposal: dco_decode_price_proposal(arr[5]),); }
+
+@protected RequisitionColumnMapping dco_decode_requisition_column_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return RequisitionColumnMapping(impaCode: dco_decode_opt_String(arr[0]),
+description: dco_decode_String(arr[1]),
+quantity: dco_decode_String(arr[2]),
+unit: dco_decode_opt_String(arr[3]),
+notes: dco_decode_opt_String(arr[4]),); }
+
+@protected RequisitionImportLine dco_decode_requisition_import_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return RequisitionImportLine(rowNumber: dco_decode_i_32(arr[0]),
+impaCode: dco_decode_opt_String(arr[1]),
+description: dco_decode_String(arr[2]),
+quantity: dco_decode_f_64(arr[3]),
+unit: dco_decode_String(arr[4]),
+notes: dco_decode_opt_String(arr[5]),
+matchKind: dco_decode_requisition_match_kind(arr[6]),
+supplyItemId: dco_decode_opt_box_autoadd_i_32(arr[7]),
+supplyItemName: dco_decode_opt_String(arr[8]),
+matchScore: dco_decode_f_64(arr[9]),
+include: dco_decode_bool(arr[10]),
+error: dco_decode_opt_String(arr[11]),); }
+
+@protected RequisitionImportPreview dco_decode_requisition_import_preview(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RequisitionImportPreview(orderId: dco_decode_i_32(arr[0]),
+orderNumber: dco_decode_String(arr[1]),
+lines: dco_decode_list_requisition_import_line(arr[2]),
+impaMatched: dco_decode_i_32(arr[3]),
+fuzzyMatched: dco_decode_i_32(arr[4]),
+unmatched: dco_decode_i_32(arr[5]),
+errors: dco_decode_i_32(arr[6]),); }
+
+@protected RequisitionImportRequest dco_decode_requisition_import_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RequisitionImportRequest(orderId: dco_decode_i_32(arr[0]),
+filePath: dco_decode_String(arr[1]),
+mapping: dco_decode_requisition_column_mapping(arr[2]),
+headerRow: dco_decode_i_32(arr[3]),
+sheetName: dco_decode_opt_String(arr[4]),
+delimiter: dco_decode_opt_String(arr[5]),); }
+
+@protected RequisitionMatchKind dco_decode_requisition_match_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RequisitionMatchKind.values[raw as int]; }
+
+@protected RequisitionTemplate dco_decode_requisition_template(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return RequisitionTemplate(id: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+shipId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+shipName: dco_decode_opt_String(arr[3]),
+shipType: dco_decode_opt_String(arr[4]),
+baseHeadcount: dco_decode_opt_box_autoadd_f_64(arr[5]),
+notes: dco_decode_opt_String(arr[6]),
+lines: dco_decode_list_requisition_template_line(arr[7]),
+createdAt: dco_decode_String(arr[8]),
+updatedAt: dco_decode_String(arr[9]),); }
+
+@protected RequisitionTemplateLine dco_decode_requisition_template_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return RequisitionTemplateLine(id: dco_decode_i_32(arr[0]),
+templateId: dco_decode_i_32(arr[1]),
+productName: dco_decode_String(arr[2]),
+impaCode: dco_decode_opt_String(arr[3]),
+description: dco_decode_opt_String(arr[4]),
+quantity: dco_decode_f_64(arr[5]),
+unit: dco_decode_String(arr[6]),
+notes: dco_decode_opt_String(arr[7]),); }
+
+@protected RequisitionTemplateLineRequest dco_decode_requisition_template_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RequisitionTemplateLineRequest(productName: dco_decode_String(arr[0]),
+impaCode: dco_decode_opt_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+quantity: dco_decode_f_64(arr[3]),
+unit: dco_decode_String(arr[4]),
+notes: dco_decode_opt_String(arr[5]),); }
+
+@protected ReturnLineRequest dco_decode_return_line_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ReturnLineRequest(orderItemId: dco_decode_i_32(arr[0]),
+quantity: dco_decode_f_64(arr[1]),
+reason: dco_decode_return_reason(arr[2]),
+restock: dco_decode_bool(arr[3]),); }
+
+@protected ReturnReason dco_decode_return_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ReturnReason.values[raw as int]; }
+
+@protected SearchHit dco_decode_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SearchHit(scope: dco_decode_search_scope(arr[0]),
+entityId: dco_decode_i_32(arr[1]),
+title: dco_decode_String(arr[2]),
+subtitle: dco_decode_opt_String(arr[3]),
+snippet: dco_decode_String(arr[4]),
+score: dco_decode_f_64(arr[5]),); }
+
+@protected SearchScope dco_decode_search_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SearchScope.values[raw as int]; }
+
+@protected Ship dco_decode_ship(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return Ship(id: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+imoNumber: dco_decode_String(arr[2]),
+flag: dco_decode_String(arr[3]),
+shipType: dco_decode_opt_String(arr[4]),
+grossTonnage: dco_decode_opt_box_autoadd_f_64(arr[5]),
+owner: dco_decode_opt_String(arr[6]),
+ownerId: dco_decode_opt_box_autoadd_i_32(arr[7]),
+createdAt: dco_decode_String(arr[8]),
+updatedAt: dco_decode_String(arr[9]),); }
+
+@protected ShipVisit dco_decode_ship_visit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return ShipVisit(id: dco_decode_i_32(arr[0]),
+shipId: dco_decode_i_32(arr[1]),
+shipName: dco_decode_opt_String(arr[2]),
+portId: dco_decode_i_32(arr[3]),
+portName: dco_decode_opt_String(arr[4]),
+eta: dco_decode_String(arr[5]),
+etd: dco_decode_String(arr[6]),
+ata: dco_decode_opt_String(arr[7]),
+atd: dco_decode_opt_String(arr[8]),
+status: dco_decode_visit_status(arr[9]),
+agentInfo: dco_decode_opt_String(arr[10]),
+notes: dco_decode_opt_String(arr[11]),
+createdAt: dco_decode_String(arr[12]),
+updatedAt: dco_decode_String(arr[13]),); }
+
+@protected Stock dco_decode_stock(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return Stock(id: dco_decode_i_32(arr[0]),
+supplyItemId: dco_decode_i_32(arr[1]),
+supplyItemName: dco_decode_opt_String(arr[2]),
+quantity: dco_decode_f_64(arr[3]),
+unit: dco_decode_String(arr[4]),
+warehouseLocation: dco_decode_opt_String(arr[5]),
+warehouseType: dco_decode_warehouse_type(arr[6]),
+minimumQuantity: dco_decode_f_64(arr[7]),
+lastUpdated: dco_decode_String(arr[8]),); }
+
+@protected StockMovement dco_decode_stock_movement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return StockMovement(id: dco_decode_i_32(arr[0]),
+stockId: dco_decode_i_32(arr[1]),
+supplyItemName: dco_decode_opt_String(arr[2]),
+movementType: dco_decode_stock_movement_type(arr[3]),
+quantity: dco_decode_f_64(arr[4]),
+unit: dco_decode_String(arr[5]),
+referenceType: dco_decode_opt_String(arr[6]),
+referenceId: dco_decode_opt_box_autoadd_i_32(arr[7]),
+referenceInfo: dco_decode_opt_String(arr[8]),
+orderItemId: dco_decode_opt_box_autoadd_i_32(arr[9]),
+notes: dco_decode_opt_String(arr[10]),
+enteredQuantity: dco_decode_opt_box_autoadd_f_64(arr[11]),
+enteredUnit: dco_decode_opt_String(arr[12]),
+customsDeclarationNumber: dco_decode_opt_String(arr[13]),
+createdAt: dco_decode_String(arr[14]),); }
+
+@protected StockMovementType dco_decode_stock_movement_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StockMovementType.values[raw as int]; }
+
+@protected StockSummary dco_decode_stock_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return StockSummary(totalItems: dco_decode_i_32(arr[0]),
+lowStockCount: dco_decode_i_32(arr[1]),
+outOfStockCount: dco_decode_i_32(arr[2]),
+totalValue: dco_decode_f_64(arr[3]),
+landedCostValue: dco_decode_f_64(arr[4]),
+currency: dco_decode_String(arr[5]),); }
+
+@protected StockWithMovements dco_decode_stock_with_movements(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return StockWithMovements(stock: dco_decode_stock(arr[0]),
+movements: dco_decode_list_stock_movement(arr[1]),); }
+
+@protected Supplier dco_decode_supplier(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return Supplier(id: dco_decode_i_32(arr[0]),
+name: dco_decode_String(arr[1]),
+contactPerson: dco_decode_opt_String(arr[2]),
+email: dco_decode_opt_String(arr[3]),
+phone: dco_decode_opt_String(arr[4]),
+address: dco_decode_opt_String(arr[5]),
+country: dco_decode_opt_String(arr[6]),
+category: dco_decode_String(arr[7]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[8]),
+categoryName: dco_decode_opt_String(arr[9]),
+leadTimeDays: dco_decode_opt_box_autoadd_i_32(arr[10]),
+isActive: dco_decode_bool(arr[11]),
+createdAt: dco_decode_String(arr[12]),
+updatedAt: dco_decode_String(arr[13]),); }
+
+@protected SupplierInvoice dco_decode_supplier_invoice(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 18) throw Exception('unexpected arr length: expect 18 but see ${arr.length}');
+                return SupplierInvoice(id: dco_decode_i_32(arr[0]),
+supplierId: dco_decode_i_32(arr[1]),
+supplierName: dco_decode_opt_String(arr[2]),
+invoiceNumber: dco_decode_String(arr[3]),
+orderId: dco_decode_opt_box_autoadd_i_32(arr[4]),
+orderNumber: dco_decode_opt_String(arr[5]),
+amount: dco_decode_f_64(arr[6]),
+paidAmount: dco_decode_f_64(arr[7]),
+balance: dco_decode_f_64(arr[8]),
+currency: dco_decode_String(arr[9]),
+issueDate: dco_decode_String(arr[10]),
+dueDate: dco_decode_String(arr[11]),
+status: dco_decode_invoice_status(arr[12]),
+approvedAt: dco_decode_opt_String(arr[13]),
+notes: dco_decode_opt_String(arr[14]),
+lines: dco_decode_list_supplier_invoice_line(arr[15]),
+createdAt: dco_decode_String(arr[16]),
+updatedAt: dco_decode_String(arr[17]),); }
+
+@protected SupplierInvoiceLine dco_decode_supplier_invoice_line(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return SupplierInvoiceLine(id: dco_decode_i_32(arr[0]),
+supplierInvoiceId: dco_decode_i_32(arr[1]),
+orderItemId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+supplyItemId: dco_decode_opt_box_autoadd_i_32(arr[3]),
+description: dco_decode_String(arr[4]),
+quantity: dco_decode_f_64(arr[5]),
+unitPrice: dco_decode_f_64(arr[6]),
+lineTotal: dco_decode_f_64(arr[7]),); }
+
+@protected SupplierInvoiceLineMatch dco_decode_supplier_invoice_line_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return SupplierInvoiceLineMatch(lineId: dco_decode_i_32(arr[0]),
+description: dco_decode_String(arr[1]),
+orderItemId: dco_decode_opt_box_autoadd_i_32(arr[2]),
+orderedQuantity: dco_decode_opt_box_autoadd_f_64(arr[3]),
+orderedPrice: dco_decode_opt_box_autoadd_f_64(arr[4]),
+receivedQuantity: dco_decode_opt_box_autoadd_f_64(arr[5]),
+invoicedQuantity: dco_decode_f_64(arr[6]),
+invoicedPrice: dco_decode_f_64(arr[7]),
+priceVariance: dco_decode_f_64(arr[8]),
+status: dco_decode_match_status(arr[9]),); }
+
+@protected SupplierInvoiceMatch dco_decode_supplier_invoice_match(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SupplierInvoiceMatch(supplierInvoiceId: dco_decode_i_32(arr[0]),
+lines: dco_decode_list_supplier_invoice_line_match(arr[1]),
+isFullyMatched: dco_decode_bool(arr[2]),
+totalPriceVariance: dco_decode_f_64(arr[3]),); }
+
+@protected SupplierPayment dco_decode_supplier_payment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return SupplierPayment(id: dco_decode_i_32(arr[0]),
+supplierInvoiceId: dco_decode_i_32(arr[1]),
+invoiceNumber: dco_decode_opt_String(arr[2]),
+supplierId: dco_decode_i_32(arr[3]),
+supplierName: dco_decode_opt_String(arr[4]),
+amount: dco_decode_f_64(arr[5]),
+currency: dco_decode_String(arr[6]),
+paymentDate: dco_decode_String(arr[7]),
+method: dco_decode_payment_method(arr[8]),
+reference: dco_decode_opt_String(arr[9]),
+notes: dco_decode_opt_String(arr[10]),
+createdAt: dco_decode_String(arr[11]),); }
+
+@protected SupplyItem dco_decode_supply_item(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 23) throw Exception('unexpected arr length: expect 23 but see ${arr.length}');
+                return SupplyItem(id: dco_decode_i_32(arr[0]),
+supplierId: dco_decode_i_32(arr[1]),
+supplierName: dco_decode_opt_String(arr[2]),
+impaCode: dco_decode_opt_String(arr[3]),
+supplierSku: dco_decode_opt_String(arr[4]),
+name: dco_decode_String(arr[5]),
+description: dco_decode_opt_String(arr[6]),
+category: dco_decode_String(arr[7]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[8]),
+categoryName: dco_decode_opt_String(arr[9]),
+unit: dco_decode_String(arr[10]),
+unitPrice: dco_decode_f_64(arr[11]),
+currency: dco_decode_String(arr[12]),
+minimumOrderQuantity: dco_decode_opt_box_autoadd_i_32(arr[13]),
+isAvailable: dco_decode_bool(arr[14]),
+unNumber: dco_decode_opt_String(arr[15]),
+hazardClass: dco_decode_opt_String(arr[16]),
+sdsRequired: dco_decode_bool(arr[17]),
+prescriptionOnly: dco_decode_bool(arr[18]),
+isBonded: dco_decode_bool(arr[19]),
+sdsAttachmentId: dco_decode_opt_box_autoadd_i_32(arr[20]),
+createdAt: dco_decode_String(arr[21]),
+updatedAt: dco_decode_String(arr[22]),); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UnitDimension dco_decode_unit_dimension(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UnitDimension.values[raw as int]; }
+
+@protected UnitOfMeasure dco_decode_unit_of_measure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return UnitOfMeasure(id: dco_decode_i_32(arr[0]),
+code: dco_decode_String(arr[1]),
+nameTr: dco_decode_String(arr[2]),
+nameEn: dco_decode_opt_String(arr[3]),
+dimension: dco_decode_unit_dimension(arr[4]),
+factor: dco_decode_f_64(arr[5]),
+aliases: dco_decode_list_String(arr[6]),
+isActive: dco_decode_bool(arr[7]),); }
+
+@protected UpdateCategoryRequest dco_decode_update_category_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UpdateCategoryRequest(code: dco_decode_opt_String(arr[0]),
+nameTr: dco_decode_opt_String(arr[1]),
+nameEn: dco_decode_opt_String(arr[2]),
+sortOrder: dco_decode_opt_box_autoadd_i_32(arr[3]),
+isActive: dco_decode_opt_box_autoadd_bool(arr[4]),); }
+
+@protected UpdateCompanyProfileRequest dco_decode_update_company_profile_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return UpdateCompanyProfileRequest(name: dco_decode_String(arr[0]),
+address: dco_decode_opt_String(arr[1]),
+phone: dco_decode_opt_String(arr[2]),
+email: dco_decode_opt_String(arr[3]),
+website: dco_decode_opt_String(arr[4]),
+taxOffice: dco_decode_opt_String(arr[5]),
+taxNumber: dco_decode_opt_String(arr[6]),
+bankName: dco_decode_opt_String(arr[7]),
+iban: dco_decode_opt_String(arr[8]),
+swift: dco_decode_opt_String(arr[9]),); }
+
+@protected UpdateCustomerRequest dco_decode_update_customer_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return UpdateCustomerRequest(legalName: dco_decode_opt_String(arr[0]),
+taxNumber: dco_decode_opt_String(arr[1]),
+taxOffice: dco_decode_opt_String(arr[2]),
+billingAddress: dco_decode_opt_String(arr[3]),
+country: dco_decode_opt_String(arr[4]),
+paymentTermsDays: dco_decode_opt_box_autoadd_i_32(arr[5]),
+creditLimit: dco_decode_opt_box_autoadd_f_64(arr[6]),
+clearCreditLimit: dco_decode_bool(arr[7]),
+defaultCurrency: dco_decode_opt_String(arr[8]),
+notes: dco_decode_opt_String(arr[9]),); }
+
+@protected UpdateOrderChargeRequest dco_decode_update_order_charge_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UpdateOrderChargeRequest(description: dco_decode_opt_String(arr[0]),
+amount: dco_decode_opt_box_autoadd_f_64(arr[1]),
+billing: dco_decode_opt_box_autoadd_charge_billing(arr[2]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[3]),
+notes: dco_decode_opt_String(arr[4]),); }
+
+@protected UpdateOrderItemRequest dco_decode_update_order_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return UpdateOrderItemRequest(productName: dco_decode_opt_String(arr[0]),
+impaCode: dco_decode_opt_String(arr[1]),
+description: dco_decode_opt_String(arr[2]),
+quantity: dco_decode_opt_box_autoadd_f_64(arr[3]),
+unit: dco_decode_opt_String(arr[4]),
+buyingPrice: dco_decode_opt_box_autoadd_f_64(arr[5]),
+sellingPrice: dco_decode_opt_box_autoadd_f_64(arr[6]),
+deliveryType: dco_decode_opt_box_autoadd_delivery_type(arr[7]),
+warehouseDeliveryDate: dco_decode_opt_String(arr[8]),
+shipDeliveryDate: dco_decode_opt_String(arr[9]),
+notes: dco_decode_opt_String(arr[10]),); }
+
+@protected UpdateOrderRequest dco_decode_update_order_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UpdateOrderRequest(shipId: dco_decode_opt_box_autoadd_i_32(arr[0]),
+shipVisitId: dco_decode_opt_box_autoadd_i_32(arr[1]),
+deliveryPort: dco_decode_opt_String(arr[2]),
+notes: dco_decode_opt_String(arr[3]),
+currency: dco_decode_opt_String(arr[4]),); }
+
+@protected UpdatePortRequest dco_decode_update_port_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return UpdatePortRequest(name: dco_decode_opt_String(arr[0]),
+country: dco_decode_opt_String(arr[1]),
+city: dco_decode_opt_String(arr[2]),
+timezone: dco_decode_opt_String(arr[3]),
+latitude: dco_decode_opt_box_autoadd_f_64(arr[4]),
+longitude: dco_decode_opt_box_autoadd_f_64(arr[5]),
+notes: dco_decode_opt_String(arr[6]),
+isInternational: dco_decode_opt_box_autoadd_bool(arr[7]),
+isActive: dco_decode_opt_box_autoadd_bool(arr[8]),); }
+
+@protected UpdatePriceAgreementRequest dco_decode_update_price_agreement_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UpdatePriceAgreementRequest(name: dco_decode_opt_String(arr[0]),
+validFrom: dco_decode_opt_String(arr[1]),
+validTo: dco_decode_opt_String(arr[2]),
+notes: dco_decode_opt_String(arr[3]),
+isActive: dco_decode_opt_box_autoadd_bool(arr[4]),); }
+
+@protected UpdatePricingRuleRequest dco_decode_update_pricing_rule_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UpdatePricingRuleRequest(name: dco_decode_opt_String(arr[0]),
+markupPercent: dco_decode_opt_box_autoadd_f_64(arr[1]),
+minMarginPercent: dco_decode_opt_box_autoadd_f_64(arr[2]),
+notes: dco_decode_opt_String(arr[3]),
+isActive: dco_decode_opt_box_autoadd_bool(arr[4]),); }
+
+@protected UpdateRequisitionTemplateRequest dco_decode_update_requisition_template_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UpdateRequisitionTemplateRequest(name: dco_decode_opt_String(arr[0]),
+baseHeadcount: dco_decode_opt_box_autoadd_f_64(arr[1]),
+notes: dco_decode_opt_String(arr[2]),
+lines: dco_decode_opt_list_requisition_template_line_request(arr[3]),); }
+
+@protected UpdateShipRequest dco_decode_update_ship_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return UpdateShipRequest(name: dco_decode_opt_String(arr[0]),
+imoNumber: dco_decode_opt_String(arr[1]),
+flag: dco_decode_opt_String(arr[2]),
+shipType: dco_decode_opt_String(arr[3]),
+grossTonnage: dco_decode_opt_box_autoadd_f_64(arr[4]),
+owner: dco_decode_opt_String(arr[5]),
+ownerId: dco_decode_opt_box_autoadd_i_32(arr[6]),); }
+
+@protected UpdateShipVisitRequest dco_decode_update_ship_visit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return UpdateShipVisitRequest(portId: dco_decode_opt_box_autoadd_i_32(arr[0]),
+eta: dco_decode_opt_String(arr[1]),
+etd: dco_decode_opt_String(arr[2]),
+ata: dco_decode_opt_String(arr[3]),
+atd: dco_decode_opt_String(arr[4]),
+status: dco_decode_opt_box_autoadd_visit_status(arr[5]),
+agentInfo: dco_decode_opt_String(arr[6]),
+notes: dco_decode_opt_String(arr[7]),); }
+
+@protected UpdateStockRequest dco_decode_update_stock_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UpdateStockRequest(quantity: dco_decode_opt_box_autoadd_f_64(arr[0]),
+warehouseLocation: dco_decode_opt_String(arr[1]),
+minimumQuantity: dco_decode_opt_box_autoadd_f_64(arr[2]),); }
+
+@protected UpdateSupplierRequest dco_decode_update_supplier_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return UpdateSupplierRequest(name: dco_decode_opt_String(arr[0]),
+contactPerson: dco_decode_opt_String(arr[1]),
+email: dco_decode_opt_String(arr[2]),
+phone: dco_decode_opt_String(arr[3]),
+address: dco_decode_opt_String(arr[4]),
+country: dco_decode_opt_String(arr[5]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[6]),
+leadTimeDays: dco_decode_opt_box_autoadd_i_32(arr[7]),); }
+
+@protected UpdateSupplyItemRequest dco_decode_update_supply_item_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 17) throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+                return UpdateSupplyItemRequest(supplierId: dco_decode_opt_box_autoadd_i_32(arr[0]),
+impaCode: dco_decode_opt_String(arr[1]),
+supplierSku: dco_decode_opt_String(arr[2]),
+name: dco_decode_opt_String(arr[3]),
+description: dco_decode_opt_String(arr[4]),
+categoryId: dco_decode_opt_box_autoadd_i_32(arr[5]),
+unit: dco_decode_opt_String(arr[6]),
+unitPrice: dco_decode_opt_box_autoadd_f_64(arr[7]),
+currency: dco_decode_opt_String(arr[8]),
+minimumOrderQuantity: dco_decode_opt_box_autoadd_i_32(arr[9]),
+isAvailable: dco_decode_opt_box_autoadd_bool(arr[10]),
+priceEffectiveDate: dco_decode_opt_String(arr[11]),
+unNumber: dco_decode_opt_String(arr[12]),
+hazardClass: dco_decode_opt_String(arr[13]),
+sdsRequired: dco_decode_opt_box_autoadd_bool(arr[14]),
+prescriptionOnly: dco_decode_opt_box_autoadd_bool(arr[15]),
+isBonded: dco_decode_opt_box_autoadd_bool(arr[16]),); }
+
+@protected UpdateUnitRequest dco_decode_update_unit_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return UpdateUnitRequest(nameTr: dco_decode_opt_String(arr[0]),
+nameEn: dco_decode_opt_String(arr[1]),
+factor: dco_decode_opt_box_autoadd_f_64(arr[2]),
+aliases: dco_decode_opt_list_String(arr[3]),
+isActive: dco_decode_opt_box_autoadd_bool(arr[4]),); }
+
+@protected VisitStatus dco_decode_visit_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return VisitStatus.values[raw as int]; }
+
+@protected WarehouseType dco_decode_warehouse_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return WarehouseType.values[raw as int]; }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AllocationMethod sse_decode_allocation_method(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AllocationMethod.values[inner]; }
+
+@protected AlternativeLink sse_decode_alternative_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AlternativeLink.values[inner]; }
+
+@protected Attachment sse_decode_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_entityType = sse_decode_attachment_entity(deserializer);
+var var_entityId = sse_decode_i_32(deserializer);
+var var_role = sse_decode_opt_String(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_contentHash = sse_decode_String(deserializer);
+var var_sizeBytes = sse_decode_i_64(deserializer);
+var var_width = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_height = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_hasThumbnail = sse_decode_bool(deserializer);
+var var_caption = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return Attachment(id: var_id, entityType: var_entityType, entityId: var_entityId, role: var_role, fileName: var_fileName, mimeType: var_mimeType, contentHash: var_contentHash, sizeBytes: var_sizeBytes, width: var_width, height: var_height, hasThumbnail: var_hasThumbnail, caption: var_caption, createdAt: var_createdAt); }
+
+@protected AttachmentEntity sse_decode_attachment_entity(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AttachmentEntity.values[inner]; }
+
+@protected BondedEligibility sse_decode_bonded_eligibility(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_shipName = sse_decode_opt_String(deserializer);
+var var_shipFlag = sse_decode_opt_String(deserializer);
+var var_portName = sse_decode_opt_String(deserializer);
+var var_portCountry = sse_decode_opt_String(deserializer);
+var var_isInternationalPort = sse_decode_bool(deserializer);
+var var_isEligible = sse_decode_bool(deserializer);
+var var_reason = sse_decode_opt_String(deserializer);
+return BondedEligibility(orderId: var_orderId, shipName: var_shipName, shipFlag: var_shipFlag, portName: var_portName, portCountry: var_portCountry, isInternationalPort: var_isInternationalPort, isEligible: var_isEligible, reason: var_reason); }
+
+@protected BondedReconciliation sse_decode_bonded_reconciliation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_periodStart = sse_decode_String(deserializer);
+var var_periodEnd = sse_decode_String(deserializer);
+var var_lines = sse_decode_list_bonded_reconciliation_line(deserializer);
+var var_movements = sse_decode_list_stock_movement(deserializer);
+return BondedReconciliation(periodStart: var_periodStart, periodEnd: var_periodEnd, lines: var_lines, movements: var_movements); }
+
+@protected BondedReconciliationLine sse_decode_bonded_reconciliation_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stockId = sse_decode_i_32(deserializer);
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_supplyItemName = sse_decode_opt_String(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_warehouseLocation = sse_decode_opt_String(deserializer);
+var var_openingQuantity = sse_decode_f_64(deserializer);
+var var_receivedQuantity = sse_decode_f_64(deserializer);
+var var_returnedQuantity = sse_decode_f_64(deserializer);
+var var_issuedQuantity = sse_decode_f_64(deserializer);
+var var_adjustedQuantity = sse_decode_f_64(deserializer);
+var var_closingQuantity = sse_decode_f_64(deserializer);
+var var_expectedQuantity = sse_decode_f_64(deserializer);
+var var_recordedQuantity = sse_decode_f_64(deserializer);
+var var_difference = sse_decode_f_64(deserializer);
+return BondedReconciliationLine(stockId: var_stockId, supplyItemId: var_supplyItemId, supplyItemName: var_supplyItemName, unit: var_unit, warehouseLocation: var_warehouseLocation, openingQuantity: var_openingQuantity, receivedQuantity: var_receivedQuantity, returnedQuantity: var_returnedQuantity, issuedQuantity: var_issuedQuantity, adjustedQuantity: var_adjustedQuantity, closingQuantity: var_closingQuantity, expectedQuantity: var_expectedQuantity, recordedQuantity: var_recordedQuantity, difference: var_difference); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected Attachment sse_decode_box_autoadd_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_attachment(deserializer)); }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected Category sse_decode_box_autoadd_category(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_category(deserializer)); }
+
+@protected ChargeBilling sse_decode_box_autoadd_charge_billing(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_charge_billing(deserializer)); }
+
+@protected CompleteDeliveryNoteRequest sse_decode_box_autoadd_complete_delivery_note_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_complete_delivery_note_request(deserializer)); }
+
+@protected ContractPrice sse_decode_box_autoadd_contract_price(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_contract_price(deserializer)); }
+
+@protected CreateAttachmentRequest sse_decode_box_autoadd_create_attachment_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_attachment_request(deserializer)); }
+
+@protected CreateCategoryRequest sse_decode_box_autoadd_create_category_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_category_request(deserializer)); }
+
+@protected CreateCustomerContactRequest sse_decode_box_autoadd_create_customer_contact_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_customer_contact_request(deserializer)); }
+
+@protected CreateCustomerRequest sse_decode_box_autoadd_create_customer_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_customer_request(deserializer)); }
+
+@protected CreateDeliveryNoteRequest sse_decode_box_autoadd_create_delivery_note_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_delivery_note_request(deserializer)); }
+
+@protected CreateEquivalenceGroupRequest sse_decode_box_autoadd_create_equivalence_group_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_equivalence_group_request(deserializer)); }
+
+@protected CreateInvoiceRequest sse_decode_box_autoadd_create_invoice_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_invoice_request(deserializer)); }
+
+@protected CreateLandedCostRequest sse_decode_box_autoadd_create_landed_cost_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_landed_cost_request(deserializer)); }
+
+@protected CreateOrderChargeRequest sse_decode_box_autoadd_create_order_charge_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_order_charge_request(deserializer)); }
+
+@protected CreateOrderItemRequest sse_decode_box_autoadd_create_order_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_order_item_request(deserializer)); }
+
+@protected CreateOrderRequest sse_decode_box_autoadd_create_order_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_order_request(deserializer)); }
+
+@protected CreatePaymentRequest sse_decode_box_autoadd_create_payment_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_payment_request(deserializer)); }
+
+@protected CreatePortRequest sse_decode_box_autoadd_create_port_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_port_request(deserializer)); }
+
+@protected CreatePriceAgreementLineRequest sse_decode_box_autoadd_create_price_agreement_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_price_agreement_line_request(deserializer)); }
+
+@protected CreatePriceAgreementRequest sse_decode_box_autoadd_create_price_agreement_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_price_agreement_request(deserializer)); }
+
+@protected CreatePricingRuleRequest sse_decode_box_autoadd_create_pricing_rule_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_pricing_rule_request(deserializer)); }
+
+@protected CreateRequisitionTemplateRequest sse_decode_box_autoadd_create_requisition_template_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_requisition_template_request(deserializer)); }
+
+@protected CreateReturnRequest sse_decode_box_autoadd_create_return_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_return_request(deserializer)); }
+
+@protected CreateShipRequest sse_decode_box_autoadd_create_ship_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_ship_request(deserializer)); }
+
+@protected CreateShipVisitRequest sse_decode_box_autoadd_create_ship_visit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_ship_visit_request(deserializer)); }
+
+@protected CreateStockMovementRequest sse_decode_box_autoadd_create_stock_movement_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_stock_movement_request(deserializer)); }
+
+@protected CreateStockRequest sse_decode_box_autoadd_create_stock_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_stock_request(deserializer)); }
+
+@protected CreateSupplierInvoiceRequest sse_decode_box_autoadd_create_supplier_invoice_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_supplier_invoice_request(deserializer)); }
+
+@protected CreateSupplierPaymentRequest sse_decode_box_autoadd_create_supplier_payment_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_supplier_payment_request(deserializer)); }
+
+@protected CreateSupplierRequest sse_decode_box_autoadd_create_supplier_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_supplier_request(deserializer)); }
+
+@protected CreateSupplyItemRequest sse_decode_box_autoadd_create_supply_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_supply_item_request(deserializer)); }
+
+@protected CreateUnitRequest sse_decode_box_autoadd_create_unit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_create_unit_request(deserializer)); }
+
+@protected CreditCheck sse_decode_box_autoadd_credit_check(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_credit_check(deserializer)); }
+
+@protected Customer sse_decode_box_autoadd_customer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_customer(deserializer)); }
+
+@protected DeliveryNote sse_decode_box_autoadd_delivery_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_delivery_note(deserializer)); }
+
+@protected DeliveryType sse_decode_box_autoadd_delivery_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_delivery_type(deserializer)); }
+
+@protected EquivalenceGroup sse_decode_box_autoadd_equivalence_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_equivalence_group(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_32(deserializer)); }
+
+@protected ImpaCatalogEntry sse_decode_box_autoadd_impa_catalog_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_impa_catalog_entry(deserializer)); }
+
+@protected ImpaCatalogImportRequest sse_decode_box_autoadd_impa_catalog_import_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_impa_catalog_import_request(deserializer)); }
+
+@protected InstantiateTemplateRequest sse_decode_box_autoadd_instantiate_template_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_instantiate_template_request(deserializer)); }
+
+@protected Invoice sse_decode_box_autoadd_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_invoice(deserializer)); }
+
+@protected InvoiceStatus sse_decode_box_autoadd_invoice_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_invoice_status(deserializer)); }
+
+@protected OrderExportFilter sse_decode_box_autoadd_order_export_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_order_export_filter(deserializer)); }
+
+@protected OrderReturn sse_decode_box_autoadd_order_return(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_order_return(deserializer)); }
+
+@protected OrderStatus sse_decode_box_autoadd_order_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_order_status(deserializer)); }
+
+@protected OrderWithItems sse_decode_box_autoadd_order_with_items(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_order_with_items(deserializer)); }
+
+@protected Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_payment(deserializer)); }
+
+@protected PdfRenderRequest sse_decode_box_autoadd_pdf_render_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pdf_render_request(deserializer)); }
+
+@protected PharmacistSignOffRequest sse_decode_box_autoadd_pharmacist_sign_off_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_pharmacist_sign_off_request(deserializer)); }
+
+@protected Port sse_decode_box_autoadd_port(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_port(deserializer)); }
+
+@protected PriceAgreement sse_decode_box_autoadd_price_agreement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_price_agreement(deserializer)); }
+
+@protected PriceListImportRequest sse_decode_box_autoadd_price_list_import_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_price_list_import_request(deserializer)); }
+
+@protected PriceListMatchKind sse_decode_box_autoadd_price_list_match_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_price_list_match_kind(deserializer)); }
+
+@protected ProductAlternative sse_decode_box_autoadd_product_alternative(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_product_alternative(deserializer)); }
+
+@protected RecordDeliveryRequest sse_decode_box_autoadd_record_delivery_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_record_delivery_request(deserializer)); }
+
+@protected RequisitionImportRequest sse_decode_box_autoadd_requisition_import_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_requisition_import_request(deserializer)); }
+
+@protected RequisitionTemplate sse_decode_box_autoadd_requisition_template(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_requisition_template(deserializer)); }
+
+@protected Ship sse_decode_box_autoadd_ship(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ship(deserializer)); }
+
+@protected ShipVisit sse_decode_box_autoadd_ship_visit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ship_visit(deserializer)); }
+
+@protected Stock sse_decode_box_autoadd_stock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stock(deserializer)); }
+
+@protected StockWithMovements sse_decode_box_autoadd_stock_with_movements(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stock_with_movements(deserializer)); }
+
+@protected Supplier sse_decode_box_autoadd_supplier(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_supplier(deserializer)); }
+
+@protected SupplierInvoice sse_decode_box_autoadd_supplier_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_supplier_invoice(deserializer)); }
+
+@protected SupplyItem sse_decode_box_autoadd_supply_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_supply_item(deserializer)); }
+
+@protected UpdateCategoryRequest sse_decode_box_autoadd_update_category_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_category_request(deserializer)); }
+
+@protected UpdateCompanyProfileRequest sse_decode_box_autoadd_update_company_profile_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_company_profile_request(deserializer)); }
+
+@protected UpdateCustomerRequest sse_decode_box_autoadd_update_customer_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_customer_request(deserializer)); }
+
+@protected UpdateOrderChargeRequest sse_decode_box_autoadd_update_order_charge_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_order_charge_request(deserializer)); }
+
+@protected UpdateOrderItemRequest sse_decode_box_autoadd_update_order_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_order_item_request(deserializer)); }
+
+@protected UpdateOrderRequest sse_decode_box_autoadd_update_order_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_order_request(deserializer)); }
+
+@protected UpdatePortRequest sse_decode_box_autoadd_update_port_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_port_request(deserializer)); }
+
+@protected UpdatePriceAgreementRequest sse_decode_box_autoadd_update_price_agreement_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_price_agreement_request(deserializer)); }
+
+@protected UpdatePricingRuleRequest sse_decode_box_autoadd_update_pricing_rule_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_pricing_rule_request(deserializer)); }
+
+@protected UpdateRequisitionTemplateRequest sse_decode_box_autoadd_update_requisition_template_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_requisition_template_request(deserializer)); }
+
+@protected UpdateShipRequest sse_decode_box_autoadd_update_ship_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_ship_request(deserializer)); }
+
+@protected UpdateShipVisitRequest sse_decode_box_autoadd_update_ship_visit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_ship_visit_request(deserializer)); }
+
+@protected UpdateStockRequest sse_decode_box_autoadd_update_stock_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_stock_request(deserializer)); }
+
+@protected UpdateSupplierRequest sse_decode_box_autoadd_update_supplier_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_supplier_request(deserializer)); }
+
+@protected UpdateSupplyItemRequest sse_decode_box_autoadd_update_supply_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_supply_item_request(deserializer)); }
+
+@protected UpdateUnitRequest sse_decode_box_autoadd_update_unit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_update_unit_request(deserializer)); }
+
+@protected VisitStatus sse_decode_box_autoadd_visit_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_visit_status(deserializer)); }
+
+@protected CalendarData sse_decode_calendar_data(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_events = sse_decode_list_calendar_event(deserializer);
+var var_ports = sse_decode_list_port(deserializer);
+return CalendarData(events: var_events, ports: var_ports); }
+
+@protected CalendarEvent sse_decode_calendar_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_eventType = sse_decode_calendar_event_type(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_subtitle = sse_decode_opt_String(deserializer);
+var var_startDate = sse_decode_String(deserializer);
+var var_endDate = sse_decode_String(deserializer);
+var var_color = sse_decode_String(deserializer);
+var var_status = sse_decode_String(deserializer);
+var var_relatedShipId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_relatedPortId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_relatedOrderId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_metadata = sse_decode_opt_String(deserializer);
+return CalendarEvent(id: var_id, eventType: var_eventType, title: var_title, subtitle: var_subtitle, startDate: var_startDate, endDate: var_endDate, color: var_color, status: var_status, relatedShipId: var_relatedShipId, relatedPortId: var_relatedPortId, relatedOrderId: var_relatedOrderId, metadata: var_metadata); }
+
+@protected CalendarEventType sse_decode_calendar_event_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CalendarEventType.values[inner]; }
+
+@protected Category sse_decode_category(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_parentId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_code = sse_decode_String(deserializer);
+var var_nameTr = sse_decode_String(deserializer);
+var var_nameEn = sse_decode_opt_String(deserializer);
+var var_depth = sse_decode_i_32(deserializer);
+var var_path = sse_decode_String(deserializer);
+var var_sortOrder = sse_decode_i_32(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+var var_itemCount = sse_decode_i_32(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return Category(id: var_id, parentId: var_parentId, code: var_code, nameTr: var_nameTr, nameEn: var_nameEn, depth: var_depth, path: var_path, sortOrder: var_sortOrder, isActive: var_isActive, itemCount: var_itemCount, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected ChargeBilling sse_decode_charge_billing(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChargeBilling.values[inner]; }
+
+@protected ChargeType sse_decode_charge_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ChargeType.values[inner]; }
+
+@protected CompanyProfile sse_decode_company_profile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+var var_phone = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+var var_website = sse_decode_opt_String(deserializer);
+var var_taxOffice = sse_decode_opt_String(deserializer);
+var var_taxNumber = sse_decode_opt_String(deserializer);
+var var_bankName = sse_decode_opt_String(deserializer);
+var var_iban = sse_decode_opt_String(deserializer);
+var var_swift = sse_decode_opt_String(deserializer);
+var var_updatedAt = sse_decode_opt_String(deserializer);
+return CompanyProfile(name: var_name, address: var_address, phone: var_phone, email: var_email, website: var_website, taxOffice: var_taxOffice, taxNumber: var_taxNumber, bankName: var_bankName, iban: var_iban, swift: var_swift, updatedAt: var_updatedAt); }
+
+@protected CompleteDeliveryNoteRequest sse_decode_complete_delivery_note_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_receiverName = sse_decode_String(deserializer);
+var var_receiverRank = sse_decode_String(deserializer);
+var var_signedAt = sse_decode_opt_String(deserializer);
+return CompleteDeliveryNoteRequest(receiverName: var_receiverName, receiverRank: var_receiverRank, signedAt: var_signedAt); }
+
+@protected ComplianceIssue sse_decode_compliance_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_kind = sse_decode_compliance_issue_kind(deserializer);
+var var_detail = sse_decode_String(deserializer);
+return ComplianceIssue(orderItemId: var_orderItemId, productName: var_productName, kind: var_kind, detail: var_detail); }
+
+@protected ComplianceIssueKind sse_decode_compliance_issue_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ComplianceIssueKind.values[inner]; }
+
+@protected ContractPrice sse_decode_contract_price(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_agreementId = sse_decode_i_32(deserializer);
+var var_agreementName = sse_decode_String(deserializer);
+var var_lineId = sse_decode_i_32(deserializer);
+var var_sellingPrice = sse_decode_f_64(deserializer);
+var var_isGroupPrice = sse_decode_bool(deserializer);
+return ContractPrice(agreementId: var_agreementId, agreementName: var_agreementName, lineId: var_lineId, sellingPrice: var_sellingPrice, isGroupPrice: var_isGroupPrice); }
+
+@protected CreateAttachmentRequest sse_decode_create_attachment_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_entityType = sse_decode_attachment_entity(deserializer);
+var var_entityId = sse_decode_i_32(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_caption = sse_decode_opt_String(deserializer);
+return CreateAttachmentRequest(entityType: var_entityType, entityId: var_entityId, fileName: var_fileName, mimeType: var_mimeType, data: var_data, caption: var_caption); }
+
+@protected CreateCategoryRequest sse_decode_create_category_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_parentId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_code = sse_decode_String(deserializer);
+var var_nameTr = sse_decode_String(deserializer);
+var var_nameEn = sse_decode_opt_String(deserializer);
+var var_sortOrder = sse_decode_opt_box_autoadd_i_32(deserializer);
+return CreateCategoryRequest(parentId: var_parentId, code: var_code, nameTr: var_nameTr, nameEn: var_nameEn, sortOrder: var_sortOrder); }
+
+@protected CreateCustomerContactRequest sse_decode_create_customer_contact_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_role = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+var var_phone = sse_decode_opt_String(deserializer);
+var var_isPrimary = sse_decode_bool(deserializer);
+return CreateCustomerContactRequest(name: var_name, role: var_role, email: var_email, phone: var_phone, isPrimary: var_isPrimary); }
+
+@protected CreateCustomerRequest sse_decode_create_customer_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_legalName = sse_decode_String(deserializer);
+var var_taxNumber = sse_decode_opt_String(deserializer);
+var var_taxOffice = sse_decode_opt_String(deserializer);
+var var_billingAddress = sse_decode_opt_String(deserializer);
+var var_country = sse_decode_opt_String(deserializer);
+var var_paymentTermsDays = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_creditLimit = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_defaultCurrency = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_contacts = sse_decode_list_create_customer_contact_request(deserializer);
+return CreateCustomerRequest(legalName: var_legalName, taxNumber: var_taxNumber, taxOffice: var_taxOffice, billingAddress: var_billingAddress, country: var_country, paymentTermsDays: var_paymentTermsDays, creditLimit: var_creditLimit, defaultCurrency: var_defaultCurrency, notes: var_notes, contacts: var_contacts); }
+
+@protected CreateDeliveryNoteRequest sse_decode_create_delivery_note_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_deliveryIds = sse_decode_opt_list_prim_i_32_strict(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return CreateDeliveryNoteRequest(orderId: var_orderId, deliveryIds: var_deliveryIds, notes: var_notes); }
+
+@protected CreateEquivalenceGroupRequest sse_decode_create_equivalence_group_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_supplyItemIds = sse_decode_list_prim_i_32_strict(deserializer);
+return CreateEquivalenceGroupRequest(name: var_name, notes: var_notes, supplyItemIds: var_supplyItemIds); }
+
+@protected CreateInvoiceRequest sse_decode_create_invoice_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_issueDate = sse_decode_opt_String(deserializer);
+var var_dueDate = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return CreateInvoiceRequest(orderId: var_orderId, issueDate: var_issueDate, dueDate: var_dueDate, amount: var_amount, notes: var_notes); }
+
+@protected CreateLandedCostRequest sse_decode_create_landed_cost_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_stockMovementId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_costType = sse_decode_landed_cost_type(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_allocationMethod = sse_decode_allocation_method(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_weights = sse_decode_list_item_weight(deserializer);
+return CreateLandedCostRequest(orderId: var_orderId, stockMovementId: var_stockMovementId, costType: var_costType, description: var_description, amount: var_amount, allocationMethod: var_allocationMethod, reference: var_reference, weights: var_weights); }
+
+@protected CreateOrderChargeRequest sse_decode_create_order_charge_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_chargeType = sse_decode_charge_type(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_billing = sse_decode_charge_billing(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return CreateOrderChargeRequest(orderId: var_orderId, chargeType: var_chargeType, description: var_description, amount: var_amount, billing: var_billing, markupPercent: var_markupPercent, notes: var_notes); }
+
+@protected CreateOrderItemRequest sse_decode_create_order_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_buyingPrice = sse_decode_f_64(deserializer);
+var var_sellingPrice = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_deliveryType = sse_decode_delivery_type(deserializer);
+var var_warehouseDeliveryDate = sse_decode_opt_String(deserializer);
+var var_shipDeliveryDate = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return CreateOrderItemRequest(orderId: var_orderId, productName: var_productName, impaCode: var_impaCode, description: var_description, quantity: var_quantity, unit: var_unit, buyingPrice: var_buyingPrice, sellingPrice: var_sellingPrice, currency: var_currency, deliveryType: var_deliveryType, warehouseDeliveryDate: var_warehouseDeliveryDate, shipDeliveryDate: var_shipDeliveryDate, notes: var_notes); }
+
+@protected CreateOrderRequest sse_decode_create_order_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_shipId = sse_decode_i_32(deserializer);
+var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_deliveryPort = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+return CreateOrderRequest(shipId: var_shipId, shipVisitId: var_shipVisitId, deliveryPort: var_deliveryPort, notes: var_notes, currency: var_currency); }
+
+@protected CreatePaymentRequest sse_decode_create_payment_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_customerId = sse_decode_i_32(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_paymentDate = sse_decode_String(deserializer);
+var var_method = sse_decode_payment_method(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_allocations = sse_decode_list_payment_allocation_request(deserializer);
+return CreatePaymentRequest(customerId: var_customerId, amount: var_amount, currency: var_currency, paymentDate: var_paymentDate, method: var_method, reference: var_reference, notes: var_notes, allocations: var_allocations); }
+
+@protected CreatePortRequest sse_decode_create_port_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_country = sse_decode_String(deserializer);
+var var_city = sse_decode_opt_String(deserializer);
+var var_timezone = sse_decode_String(deserializer);
+var var_latitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_longitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isInternational = sse_decode_bool(deserializer);
+return CreatePortRequest(name: var_name, country: var_country, city: var_city, timezone: var_timezone, latitude: var_latitude, longitude: var_longitude, notes: var_notes, isInternational: var_isInternational); }
+
+@protected CreatePriceAgreementLineRequest sse_decode_create_price_agreement_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_impaGroup = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_fixedPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+return CreatePriceAgreementLineRequest(impaCode: var_impaCode, impaGroup: var_impaGroup, description: var_description, fixedPrice: var_fixedPrice, markupPercent: var_markupPercent); }
+
+@protected CreatePriceAgreementRequest sse_decode_create_price_agreement_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_customerId = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_validFrom = sse_decode_String(deserializer);
+var var_validTo = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_create_price_agreement_line_request(deserializer);
+return CreatePriceAgreementRequest(customerId: var_customerId, name: var_name, currency: var_currency, validFrom: var_validFrom, validTo: var_validTo, notes: var_notes, lines: var_lines); }
+
+@protected CreatePricingRuleRequest sse_decode_create_pricing_rule_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_supplierId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_minMarginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return CreatePricingRuleRequest(name: var_name, categoryId: var_categoryId, supplierId: var_supplierId, customerId: var_customerId, markupPercent: var_markupPercent, minMarginPercent: var_minMarginPercent, notes: var_notes); }
+
+@protected CreateRequisitionTemplateRequest sse_decode_create_requisition_template_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_shipId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_shipType = sse_decode_opt_String(deserializer);
+var var_baseHeadcount = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_requisition_template_line_request(deserializer);
+return CreateRequisitionTemplateRequest(name: var_name, shipId: var_shipId, shipType: var_shipType, baseHeadcount: var_baseHeadcount, notes: var_notes, lines: var_lines); }
+
+@protected CreateReturnRequest sse_decode_create_return_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_returnDate = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_return_line_request(deserializer);
+var var_issueCreditNote = sse_decode_bool(deserializer);
+var var_customsDeclarationNumber = sse_decode_opt_String(deserializer);
+return CreateReturnRequest(orderId: var_orderId, returnDate: var_returnDate, notes: var_notes, lines: var_lines, issueCreditNote: var_issueCreditNote, customsDeclarationNumber: var_customsDeclarationNumber); }
+
+@protected CreateShipRequest sse_decode_create_ship_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_imoNumber = sse_decode_String(deserializer);
+var var_flag = sse_decode_String(deserializer);
+var var_shipType = sse_decode_opt_String(deserializer);
+var var_grossTonnage = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_owner = sse_decode_opt_String(deserializer);
+var var_ownerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+return CreateShipRequest(name: var_name, imoNumber: var_imoNumber, flag: var_flag, shipType: var_shipType, grossTonnage: var_grossTonnage, owner: var_owner, ownerId: var_ownerId); }
+
+@protected CreateShipVisitRequest sse_decode_create_ship_visit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_shipId = sse_decode_i_32(deserializer);
+var var_portId = sse_decode_i_32(deserializer);
+var var_eta = sse_decode_String(deserializer);
+var var_etd = sse_decode_String(deserializer);
+var var_agentInfo = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return CreateShipVisitRequest(shipId: var_shipId, portId: var_portId, eta: var_eta, etd: var_etd, agentInfo: var_agentInfo, notes: var_notes); }
+
+@protected CreateStockMovementRequest sse_decode_create_stock_movement_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stockId = sse_decode_i_32(deserializer);
+var var_movementType = sse_decode_stock_movement_type(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_referenceType = sse_decode_opt_String(deserializer);
+var var_referenceId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_referenceInfo = sse_decode_opt_String(deserializer);
+var var_orderItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_customsDeclarationNumber = sse_decode_opt_String(deserializer);
+return CreateStockMovementRequest(stockId: var_stockId, movementType: var_movementType, quantity: var_quantity, unit: var_unit, referenceType: var_referenceType, referenceId: var_referenceId, referenceInfo: var_referenceInfo, orderItemId: var_orderItemId, notes: var_notes, customsDeclarationNumber: var_customsDeclarationNumber); }
+
+@protected CreateStockRequest sse_decode_create_stock_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_warehouseLocation = sse_decode_opt_String(deserializer);
+var var_warehouseType = sse_decode_warehouse_type(deserializer);
+var var_minimumQuantity = sse_decode_f_64(deserializer);
+return CreateStockRequest(supplyItemId: var_supplyItemId, quantity: var_quantity, unit: var_unit, warehouseLocation: var_warehouseLocation, warehouseType: var_warehouseType, minimumQuantity: var_minimumQuantity); }
+
+@protected CreateSupplierInvoiceLineRequest sse_decode_create_supplier_invoice_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_supplyItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unitPrice = sse_decode_f_64(deserializer);
+return CreateSupplierInvoiceLineRequest(orderItemId: var_orderItemId, supplyItemId: var_supplyItemId, description: var_description, quantity: var_quantity, unitPrice: var_unitPrice); }
+
+@protected CreateSupplierInvoiceRequest sse_decode_create_supplier_invoice_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_invoiceNumber = sse_decode_String(deserializer);
+var var_orderId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_issueDate = sse_decode_String(deserializer);
+var var_dueDate = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_create_supplier_invoice_line_request(deserializer);
+return CreateSupplierInvoiceRequest(supplierId: var_supplierId, invoiceNumber: var_invoiceNumber, orderId: var_orderId, currency: var_currency, issueDate: var_issueDate, dueDate: var_dueDate, notes: var_notes, lines: var_lines); }
+
+@protected CreateSupplierPaymentRequest sse_decode_create_supplier_payment_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierInvoiceId = sse_decode_i_32(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_paymentDate = sse_decode_String(deserializer);
+var var_method = sse_decode_payment_method(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return CreateSupplierPaymentRequest(supplierInvoiceId: var_supplierInvoiceId, amount: var_amount, paymentDate: var_paymentDate, method: var_method, reference: var_reference, notes: var_notes); }
+
+@protected CreateSupplierRequest sse_decode_create_supplier_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_contactPerson = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+var var_phone = sse_decode_opt_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+var var_country = sse_decode_opt_String(deserializer);
+var var_categoryId = sse_decode_i_32(deserializer);
+var var_leadTimeDays = sse_decode_opt_box_autoadd_i_32(deserializer);
+return CreateSupplierRequest(name: var_name, contactPerson: var_contactPerson, email: var_email, phone: var_phone, address: var_address, country: var_country, categoryId: var_categoryId, leadTimeDays: var_leadTimeDays); }
+
+@protected CreateSupplyItemRequest sse_decode_create_supply_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_supplierSku = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_unitPrice = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_minimumOrderQuantity = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_unNumber = sse_decode_opt_String(deserializer);
+var var_hazardClass = sse_decode_opt_String(deserializer);
+var var_sdsRequired = sse_decode_bool(deserializer);
+var var_prescriptionOnly = sse_decode_bool(deserializer);
+var var_isBonded = sse_decode_bool(deserializer);
+return CreateSupplyItemRequest(supplierId: var_supplierId, impaCode: var_impaCode, supplierSku: var_supplierSku, name: var_name, description: var_description, categoryId: var_categoryId, unit: var_unit, unitPrice: var_unitPrice, currency: var_currency, minimumOrderQuantity: var_minimumOrderQuantity, unNumber: var_unNumber, hazardClass: var_hazardClass, sdsRequired: var_sdsRequired, prescriptionOnly: var_prescriptionOnly, isBonded: var_isBonded); }
+
+@protected CreateUnitRequest sse_decode_create_unit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_nameTr = sse_decode_String(deserializer);
+var var_nameEn = sse_decode_opt_String(deserializer);
+var var_dimension = sse_decode_unit_dimension(deserializer);
+var var_factor = sse_decode_f_64(deserializer);
+var var_aliases = sse_decode_list_String(deserializer);
+return CreateUnitRequest(code: var_code, nameTr: var_nameTr, nameEn: var_nameEn, dimension: var_dimension, factor: var_factor, aliases: var_aliases); }
+
+@protected CreditCheck sse_decode_credit_check(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_customerId = sse_decode_i_32(deserializer);
+var var_customerName = sse_decode_String(deserializer);
+var var_creditLimit = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_openReceivables = sse_decode_f_64(deserializer);
+var var_uninvoicedOrders = sse_decode_f_64(deserializer);
+var var_orderAmount = sse_decode_f_64(deserializer);
+var var_projectedExposure = sse_decode_f_64(deserializer);
+var var_exceedsLimit = sse_decode_bool(deserializer);
+var var_otherCurrencies = sse_decode_list_currency_exposure(deserializer);
+var var_warning = sse_decode_opt_String(deserializer);
+return CreditCheck(customerId: var_customerId, customerName: var_customerName, creditLimit: var_creditLimit, currency: var_currency, openReceivables: var_openReceivables, uninvoicedOrders: var_uninvoicedOrders, orderAmount: var_orderAmount, projectedExposure: var_projectedExposure, exceedsLimit: var_exceedsLimit, otherCurrencies: var_otherCurrencies, warning: var_warning); }
+
+@protected CreditNote sse_decode_credit_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_creditNoteNumber = sse_decode_String(deserializer);
+var var_invoiceId = sse_decode_i_32(deserializer);
+var var_invoiceNumber = sse_decode_opt_String(deserializer);
+var var_orderId = sse_decode_i_32(deserializer);
+var var_returnId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerName = sse_decode_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_issueDate = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return CreditNote(id: var_id, creditNoteNumber: var_creditNoteNumber, invoiceId: var_invoiceId, invoiceNumber: var_invoiceNumber, orderId: var_orderId, returnId: var_returnId, customerName: var_customerName, amount: var_amount, currency: var_currency, issueDate: var_issueDate, notes: var_notes, createdAt: var_createdAt); }
+
+@protected CurrencyExposure sse_decode_currency_exposure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_currency = sse_decode_String(deserializer);
+var var_openReceivables = sse_decode_f_64(deserializer);
+var var_uninvoicedOrders = sse_decode_f_64(deserializer);
+return CurrencyExposure(currency: var_currency, openReceivables: var_openReceivables, uninvoicedOrders: var_uninvoicedOrders); }
+
+@protected CurrencyRounding sse_decode_currency_rounding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_currency = sse_decode_String(deserializer);
+var var_increment = sse_decode_f_64(deserializer);
+return CurrencyRounding(currency: var_currency, increment: var_increment); }
+
+@protected Customer sse_decode_customer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_legalName = sse_decode_String(deserializer);
+var var_taxNumber = sse_decode_opt_String(deserializer);
+var var_taxOffice = sse_decode_opt_String(deserializer);
+var var_billingAddress = sse_decode_opt_String(deserializer);
+var var_country = sse_decode_opt_String(deserializer);
+var var_paymentTermsDays = sse_decode_i_32(deserializer);
+var var_creditLimit = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_defaultCurrency = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+var var_contacts = sse_decode_list_customer_contact(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return Customer(id: var_id, legalName: var_legalName, taxNumber: var_taxNumber, taxOffice: var_taxOffice, billingAddress: var_billingAddress, country: var_country, paymentTermsDays: var_paymentTermsDays, creditLimit: var_creditLimit, defaultCurrency: var_defaultCurrency, notes: var_notes, isActive: var_isActive, contacts: var_contacts, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected CustomerBalance sse_decode_customer_balance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_customerId = sse_decode_i_32(deserializer);
+var var_customerName = sse_decode_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_totalInvoiced = sse_decode_f_64(deserializer);
+var var_totalPaid = sse_decode_f_64(deserializer);
+var var_totalCredited = sse_decode_f_64(deserializer);
+var var_openBalance = sse_decode_f_64(deserializer);
+var var_credit = sse_decode_f_64(deserializer);
+return CustomerBalance(customerId: var_customerId, customerName: var_customerName, currency: var_currency, totalInvoiced: var_totalInvoiced, totalPaid: var_totalPaid, totalCredited: var_totalCredited, openBalance: var_openBalance, credit: var_credit); }
+
+@protected CustomerContact sse_decode_customer_contact(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_customerId = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_role = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+var var_phone = sse_decode_opt_String(deserializer);
+var var_isPrimary = sse_decode_bool(deserializer);
+return CustomerContact(id: var_id, customerId: var_customerId, name: var_name, role: var_role, email: var_email, phone: var_phone, isPrimary: var_isPrimary); }
+
+@protected DangerousGoodsClassTotal sse_decode_dangerous_goods_class_total(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hazardClass = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_lineCount = sse_decode_i_32(deserializer);
+var var_unNumbers = sse_decode_list_String(deserializer);
+return DangerousGoodsClassTotal(hazardClass: var_hazardClass, description: var_description, lineCount: var_lineCount, unNumbers: var_unNumbers); }
+
+@protected DangerousGoodsLine sse_decode_dangerous_goods_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_unNumber = sse_decode_String(deserializer);
+var var_hazardClass = sse_decode_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_sdsOnFile = sse_decode_bool(deserializer);
+return DangerousGoodsLine(orderItemId: var_orderItemId, productName: var_productName, impaCode: var_impaCode, unNumber: var_unNumber, hazardClass: var_hazardClass, quantity: var_quantity, unit: var_unit, sdsOnFile: var_sdsOnFile); }
+
+@protected DangerousGoodsSummary sse_decode_dangerous_goods_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_deliveryNoteId = sse_decode_i_32(deserializer);
+var var_noteNumber = sse_decode_String(deserializer);
+var var_orderNumber = sse_decode_opt_String(deserializer);
+var var_shipName = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_dangerous_goods_line(deserializer);
+var var_classes = sse_decode_list_dangerous_goods_class_total(deserializer);
+return DangerousGoodsSummary(deliveryNoteId: var_deliveryNoteId, noteNumber: var_noteNumber, orderNumber: var_orderNumber, shipName: var_shipName, lines: var_lines, classes: var_classes); }
+
+@protected DeliveryLine sse_decode_delivery_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+return DeliveryLine(orderItemId: var_orderItemId, quantity: var_quantity); }
+
+@protected DeliveryNote sse_decode_delivery_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_noteNumber = sse_decode_String(deserializer);
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_opt_String(deserializer);
+var var_shipName = sse_decode_opt_String(deserializer);
+var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_status = sse_decode_delivery_note_status(deserializer);
+var var_receiverName = sse_decode_opt_String(deserializer);
+var var_receiverRank = sse_decode_opt_String(deserializer);
+var var_signedAt = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_delivery_note_line(deserializer);
+var var_attachments = sse_decode_list_delivery_note_attachment(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return DeliveryNote(id: var_id, noteNumber: var_noteNumber, orderId: var_orderId, orderNumber: var_orderNumber, shipName: var_shipName, shipVisitId: var_shipVisitId, status: var_status, receiverName: var_receiverName, receiverRank: var_receiverRank, signedAt: var_signedAt, notes: var_notes, lines: var_lines, attachments: var_attachments, createdAt: var_createdAt); }
+
+@protected DeliveryNoteAttachment sse_decode_delivery_note_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_deliveryNoteId = sse_decode_i_32(deserializer);
+var var_kind = sse_decode_delivery_note_attachment_kind(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_sizeBytes = sse_decode_i_64(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return DeliveryNoteAttachment(id: var_id, deliveryNoteId: var_deliveryNoteId, kind: var_kind, fileName: var_fileName, mimeType: var_mimeType, sizeBytes: var_sizeBytes, createdAt: var_createdAt); }
+
+@protected DeliveryNoteAttachmentKind sse_decode_delivery_note_attachment_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DeliveryNoteAttachmentKind.values[inner]; }
+
+@protected DeliveryNoteLine sse_decode_delivery_note_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+return DeliveryNoteLine(orderItemId: var_orderItemId, productName: var_productName, impaCode: var_impaCode, unit: var_unit, quantity: var_quantity); }
+
+@protected DeliveryNoteStatus sse_decode_delivery_note_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DeliveryNoteStatus.values[inner]; }
+
+@protected DeliveryType sse_decode_delivery_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DeliveryType.values[inner]; }
+
+@protected EquivalenceGroup sse_decode_equivalence_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_members = sse_decode_list_supply_item(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return EquivalenceGroup(id: var_id, name: var_name, notes: var_notes, members: var_members, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected ExportResult sse_decode_export_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_files = sse_decode_list_String(deserializer);
+var var_rowCount = sse_decode_i_32(deserializer);
+return ExportResult(files: var_files, rowCount: var_rowCount); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FulfilmentStatus sse_decode_fulfilment_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FulfilmentStatus.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImpaCatalogEntry sse_decode_impa_catalog_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_section = sse_decode_String(deserializer);
+var var_sectionName = sse_decode_opt_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_category = sse_decode_opt_String(deserializer);
+return ImpaCatalogEntry(code: var_code, description: var_description, unit: var_unit, section: var_section, sectionName: var_sectionName, categoryId: var_categoryId, category: var_category); }
+
+@protected ImpaCatalogImportRequest sse_decode_impa_catalog_import_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filePath = sse_decode_String(deserializer);
+var var_mapping = sse_decode_impa_column_mapping(deserializer);
+var var_headerRow = sse_decode_i_32(deserializer);
+var var_sheetName = sse_decode_opt_String(deserializer);
+var var_delimiter = sse_decode_opt_String(deserializer);
+var var_replaceExisting = sse_decode_bool(deserializer);
+return ImpaCatalogImportRequest(filePath: var_filePath, mapping: var_mapping, headerRow: var_headerRow, sheetName: var_sheetName, delimiter: var_delimiter, replaceExisting: var_replaceExisting); }
+
+@protected ImpaCatalogImportResult sse_decode_impa_catalog_import_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_inserted = sse_decode_i_32(deserializer);
+var var_updated = sse_decode_i_32(deserializer);
+var var_sections = sse_decode_i_32(deserializer);
+var var_errors = sse_decode_list_String(deserializer);
+return ImpaCatalogImportResult(inserted: var_inserted, updated: var_updated, sections: var_sections, errors: var_errors); }
+
+@protected ImpaCodeIssue sse_decode_impa_code_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_supplyItemName = sse_decode_String(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_impaCode = sse_decode_String(deserializer);
+var var_problem = sse_decode_impa_code_problem(deserializer);
+var var_message = sse_decode_String(deserializer);
+return ImpaCodeIssue(supplyItemId: var_supplyItemId, supplyItemName: var_supplyItemName, supplierName: var_supplierName, impaCode: var_impaCode, problem: var_problem, message: var_message); }
+
+@protected ImpaCodeProblem sse_decode_impa_code_problem(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ImpaCodeProblem.values[inner]; }
+
+@protected ImpaColumnMapping sse_decode_impa_column_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_section = sse_decode_opt_String(deserializer);
+return ImpaColumnMapping(code: var_code, description: var_description, unit: var_unit, section: var_section); }
+
+@protected ImpaSection sse_decode_impa_section(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_category = sse_decode_opt_String(deserializer);
+var var_entryCount = sse_decode_i_32(deserializer);
+return ImpaSection(code: var_code, name: var_name, categoryId: var_categoryId, category: var_category, entryCount: var_entryCount); }
+
+@protected InstantiateTemplateRequest sse_decode_instantiate_template_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_templateId = sse_decode_i_32(deserializer);
+var var_shipId = sse_decode_i_32(deserializer);
+var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_headcount = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_scaleFactor = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+return InstantiateTemplateRequest(templateId: var_templateId, shipId: var_shipId, shipVisitId: var_shipVisitId, headcount: var_headcount, scaleFactor: var_scaleFactor, currency: var_currency); }
+
+@protected Invoice sse_decode_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_invoiceNumber = sse_decode_String(deserializer);
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_opt_String(deserializer);
+var var_customerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerName = sse_decode_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_paidAmount = sse_decode_f_64(deserializer);
+var var_creditedAmount = sse_decode_f_64(deserializer);
+var var_balance = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_issueDate = sse_decode_String(deserializer);
+var var_dueDate = sse_decode_String(deserializer);
+var var_status = sse_decode_invoice_status(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return Invoice(id: var_id, invoiceNumber: var_invoiceNumber, orderId: var_orderId, orderNumber: var_orderNumber, customerId: var_customerId, customerName: var_customerName, amount: var_amount, paidAmount: var_paidAmount, creditedAmount: var_creditedAmount, balance: var_balance, currency: var_currency, issueDate: var_issueDate, dueDate: var_dueDate, status: var_status, notes: var_notes, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected InvoiceStatus sse_decode_invoice_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return InvoiceStatus.values[inner]; }
+
+@protected ItemDelivery sse_decode_item_delivery(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_deliveredAt = sse_decode_String(deserializer);
+var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return ItemDelivery(id: var_id, orderItemId: var_orderItemId, productName: var_productName, quantity: var_quantity, deliveredAt: var_deliveredAt, shipVisitId: var_shipVisitId, notes: var_notes, createdAt: var_createdAt); }
+
+@protected ItemProfit sse_decode_item_profit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalCost = sse_decode_f_64(deserializer);
+var var_totalRevenue = sse_decode_f_64(deserializer);
+var var_grossProfit = sse_decode_f_64(deserializer);
+var var_marginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_landedCost = sse_decode_f_64(deserializer);
+var var_minMarginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_belowMarginFloor = sse_decode_bool(deserializer);
+return ItemProfit(totalCost: var_totalCost, totalRevenue: var_totalRevenue, grossProfit: var_grossProfit, marginPercent: var_marginPercent, landedCost: var_landedCost, minMarginPercent: var_minMarginPercent, belowMarginFloor: var_belowMarginFloor); }
+
+@protected ItemWeight sse_decode_item_weight(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_weightKg = sse_decode_f_64(deserializer);
+return ItemWeight(orderItemId: var_orderItemId, weightKg: var_weightKg); }
+
+@protected LandedCost sse_decode_landed_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_opt_String(deserializer);
+var var_stockMovementId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_costType = sse_decode_landed_cost_type(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_allocationMethod = sse_decode_allocation_method(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_allocations = sse_decode_list_landed_cost_allocation(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return LandedCost(id: var_id, orderId: var_orderId, orderNumber: var_orderNumber, stockMovementId: var_stockMovementId, costType: var_costType, description: var_description, amount: var_amount, currency: var_currency, allocationMethod: var_allocationMethod, reference: var_reference, allocations: var_allocations, createdAt: var_createdAt); }
+
+@protected LandedCostAllocation sse_decode_landed_cost_allocation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_landedCostId = sse_decode_i_32(deserializer);
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_opt_String(deserializer);
+var var_basis = sse_decode_f_64(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_amountPerUnit = sse_decode_f_64(deserializer);
+return LandedCostAllocation(id: var_id, landedCostId: var_landedCostId, orderItemId: var_orderItemId, productName: var_productName, basis: var_basis, amount: var_amount, amountPerUnit: var_amountPerUnit); }
+
+@protected LandedCostType sse_decode_landed_cost_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LandedCostType.values[inner]; }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Attachment> sse_decode_list_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Attachment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_attachment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BondedReconciliationLine> sse_decode_list_bonded_reconciliation_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BondedReconciliationLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_bonded_reconciliation_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CalendarEvent> sse_decode_list_calendar_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CalendarEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_calendar_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Category> sse_decode_list_category(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Category>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_category(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ComplianceIssue> sse_decode_list_compliance_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ComplianceIssue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_compliance_issue(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CreateCustomerContactRequest> sse_decode_list_create_customer_contact_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CreateCustomerContactRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_create_customer_contact_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CreatePriceAgreementLineRequest> sse_decode_list_create_price_agreement_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CreatePriceAgreementLineRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_create_price_agreement_line_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CreateSupplierInvoiceLineRequest> sse_decode_list_create_supplier_invoice_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CreateSupplierInvoiceLineRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_create_supplier_invoice_line_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CreditNote> sse_decode_list_credit_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CreditNote>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_credit_note(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CurrencyExposure> sse_decode_list_currency_exposure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CurrencyExposure>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_currency_exposure(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CurrencyRounding> sse_decode_list_currency_rounding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CurrencyRounding>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_currency_rounding(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Customer> sse_decode_list_customer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Customer>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_customer(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CustomerBalance> sse_decode_list_customer_balance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CustomerBalance>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_customer_balance(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CustomerContact> sse_decode_list_customer_contact(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CustomerContact>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_customer_contact(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DangerousGoodsClassTotal> sse_decode_list_dangerous_goods_class_total(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DangerousGoodsClassTotal>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_dangerous_goods_class_total(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DangerousGoodsLine> sse_decode_list_dangerous_goods_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DangerousGoodsLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_dangerous_goods_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DeliveryLine> sse_decode_list_delivery_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DeliveryLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_delivery_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DeliveryNote> sse_decode_list_delivery_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DeliveryNote>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_delivery_note(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DeliveryNoteAttachment> sse_decode_list_delivery_note_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DeliveryNoteAttachment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_delivery_note_attachment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DeliveryNoteLine> sse_decode_list_delivery_note_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DeliveryNoteLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_delivery_note_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EquivalenceGroup> sse_decode_list_equivalence_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EquivalenceGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_equivalence_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ImpaCatalogEntry> sse_decode_list_impa_catalog_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ImpaCatalogEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_impa_catalog_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ImpaCodeIssue> sse_decode_list_impa_code_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ImpaCodeIssue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_impa_code_issue(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ImpaSection> sse_decode_list_impa_section(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ImpaSection>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_impa_section(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Invoice> sse_decode_list_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Invoice>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_invoice(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ItemDelivery> sse_decode_list_item_delivery(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ItemDelivery>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_item_delivery(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ItemWeight> sse_decode_list_item_weight(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ItemWeight>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_item_weight(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LandedCost> sse_decode_list_landed_cost(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LandedCost>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_landed_cost(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LandedCostAllocation> sse_decode_list_landed_cost_allocation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LandedCostAllocation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_landed_cost_allocation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OffContractLine> sse_decode_list_off_contract_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OffContractLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_off_contract_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Order> sse_decode_list_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Order>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_order(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OrderCharge> sse_decode_list_order_charge(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OrderCharge>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_order_charge(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OrderItem> sse_decode_list_order_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OrderItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_order_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OrderLink> sse_decode_list_order_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OrderLink>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_order_link(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OrderProfitInfo> sse_decode_list_order_profit_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OrderProfitInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_order_profit_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OrderReturn> sse_decode_list_order_return(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OrderReturn>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_order_return(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OrderReturnLine> sse_decode_list_order_return_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OrderReturnLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_order_return_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PackSize> sse_decode_list_pack_size(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PackSize>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_pack_size(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PayableDue> sse_decode_list_payable_due(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PayableDue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_payable_due(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Payment> sse_decode_list_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Payment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_payment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PaymentAllocation> sse_decode_list_payment_allocation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PaymentAllocation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_payment_allocation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PaymentAllocationRequest> sse_decode_list_payment_allocation_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PaymentAllocationRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_payment_allocation_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Port> sse_decode_list_port(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Port>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_port(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PriceAgreement> sse_decode_list_price_agreement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PriceAgreement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_price_agreement(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PriceAgreementLine> sse_decode_list_price_agreement_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PriceAgreementLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_price_agreement_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PriceChange> sse_decode_list_price_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PriceChange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_price_change(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PriceListChange> sse_decode_list_price_list_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PriceListChange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_price_list_change(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PriceRiseItem> sse_decode_list_price_rise_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PriceRiseItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_price_rise_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PricingRule> sse_decode_list_pricing_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PricingRule>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_pricing_rule(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt32List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<ProductAlternative> sse_decode_list_product_alternative(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProductAlternative>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_product_alternative(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReceivablesAgingRow> sse_decode_list_receivables_aging_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReceivablesAgingRow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_receivables_aging_row(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RepricedItem> sse_decode_list_repriced_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RepricedItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_repriced_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RequisitionImportLine> sse_decode_list_requisition_import_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RequisitionImportLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_requisition_import_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RequisitionTemplate> sse_decode_list_requisition_template(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RequisitionTemplate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_requisition_template(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RequisitionTemplateLine> sse_decode_list_requisition_template_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RequisitionTemplateLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_requisition_template_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RequisitionTemplateLineRequest> sse_decode_list_requisition_template_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RequisitionTemplateLineRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_requisition_template_line_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReturnLineRequest> sse_decode_list_return_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReturnLineRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_return_line_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchScope> sse_decode_list_search_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchScope>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_scope(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Ship> sse_decode_list_ship(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Ship>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ship(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ShipVisit> sse_decode_list_ship_visit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ShipVisit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ship_visit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Stock> sse_decode_list_stock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Stock>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_stock(deserializer)); }
+        return ans_;
+         }
+
+@protected List<StockMovement> sse_decode_list_stock_movement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <StockMovement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_stock_movement(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Supplier> sse_decode_list_supplier(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Supplier>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_supplier(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SupplierInvoice> sse_decode_list_supplier_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SupplierInvoice>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_supplier_invoice(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SupplierInvoiceLine> sse_decode_list_supplier_invoice_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SupplierInvoiceLine>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_supplier_invoice_line(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SupplierInvoiceLineMatch> sse_decode_list_supplier_invoice_line_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SupplierInvoiceLineMatch>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_supplier_invoice_line_match(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SupplierPayment> sse_decode_list_supplier_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SupplierPayment>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_supplier_payment(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SupplyItem> sse_decode_list_supply_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SupplyItem>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_supply_item(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UnitOfMeasure> sse_decode_list_unit_of_measure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UnitOfMeasure>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_unit_of_measure(deserializer)); }
+        return ans_;
+         }
+
+@protected MatchStatus sse_decode_match_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MatchStatus.values[inner]; }
+
+@protected OffContractLine sse_decode_off_contract_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_String(deserializer);
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_customerId = sse_decode_i_32(deserializer);
+var var_customerName = sse_decode_String(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_sellingPrice = sse_decode_f_64(deserializer);
+var var_contractPrice = sse_decode_f_64(deserializer);
+var var_revenueDifference = sse_decode_f_64(deserializer);
+var var_agreementId = sse_decode_i_32(deserializer);
+var var_agreementName = sse_decode_String(deserializer);
+return OffContractLine(orderId: var_orderId, orderNumber: var_orderNumber, orderItemId: var_orderItemId, customerId: var_customerId, customerName: var_customerName, productName: var_productName, impaCode: var_impaCode, quantity: var_quantity, currency: var_currency, sellingPrice: var_sellingPrice, contractPrice: var_contractPrice, revenueDifference: var_revenueDifference, agreementId: var_agreementId, agreementName: var_agreementName); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Attachment? sse_decode_opt_box_autoadd_attachment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_attachment(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Category? sse_decode_opt_box_autoadd_category(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_category(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ChargeBilling? sse_decode_opt_box_autoadd_charge_billing(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_charge_billing(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ContractPrice? sse_decode_opt_box_autoadd_contract_price(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_contract_price(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CreditCheck? sse_decode_opt_box_autoadd_credit_check(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_credit_check(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Customer? sse_decode_opt_box_autoadd_customer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_customer(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DeliveryNote? sse_decode_opt_box_autoadd_delivery_note(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_delivery_note(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DeliveryType? sse_decode_opt_box_autoadd_delivery_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_delivery_type(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected EquivalenceGroup? sse_decode_opt_box_autoadd_equivalence_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_equivalence_group(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ImpaCatalogEntry? sse_decode_opt_box_autoadd_impa_catalog_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_impa_catalog_entry(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Invoice? sse_decode_opt_box_autoadd_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_invoice(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected InvoiceStatus? sse_decode_opt_box_autoadd_invoice_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_invoice_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OrderReturn? sse_decode_opt_box_autoadd_order_return(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_order_return(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OrderStatus? sse_decode_opt_box_autoadd_order_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_order_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OrderWithItems? sse_decode_opt_box_autoadd_order_with_items(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_order_with_items(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_payment(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Port? sse_decode_opt_box_autoadd_port(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_port(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PriceAgreement? sse_decode_opt_box_autoadd_price_agreement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_price_agreement(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PriceListMatchKind? sse_decode_opt_box_autoadd_price_list_match_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_price_list_match_kind(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProductAlternative? sse_decode_opt_box_autoadd_product_alternative(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_product_alternative(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RequisitionTemplate? sse_decode_opt_box_autoadd_requisition_template(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_requisition_template(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Ship? sse_decode_opt_box_autoadd_ship(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ship(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ShipVisit? sse_decode_opt_box_autoadd_ship_visit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ship_visit(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Stock? sse_decode_opt_box_autoadd_stock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_stock(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected StockWithMovements? sse_decode_opt_box_autoadd_stock_with_movements(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_stock_with_movements(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Supplier? sse_decode_opt_box_autoadd_supplier(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_supplier(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SupplierInvoice? sse_decode_opt_box_autoadd_supplier_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_supplier_invoice(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SupplyItem? sse_decode_opt_box_autoadd_supply_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_supply_item(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected VisitStatus? sse_decode_opt_box_autoadd_visit_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_visit_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Int32List? sse_decode_opt_list_prim_i_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_i_32_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<RequisitionTemplateLineRequest>? sse_decode_opt_list_requisition_template_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_requisition_template_line_request(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Order sse_decode_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_String(deserializer);
+var var_shipId = sse_decode_i_32(deserializer);
+var var_shipName = sse_decode_opt_String(deserializer);
+var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_shipVisitInfo = sse_decode_opt_String(deserializer);
+var var_customerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerName = sse_decode_opt_String(deserializer);
+var var_paymentTermsDays = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_status = sse_decode_order_status(deserializer);
+var var_deliveryPort = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_parentOrderId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_deliveryNoteWaiver = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return Order(id: var_id, orderNumber: var_orderNumber, shipId: var_shipId, shipName: var_shipName, shipVisitId: var_shipVisitId, shipVisitInfo: var_shipVisitInfo, customerId: var_customerId, customerName: var_customerName, paymentTermsDays: var_paymentTermsDays, status: var_status, deliveryPort: var_deliveryPort, notes: var_notes, currency: var_currency, parentOrderId: var_parentOrderId, deliveryNoteWaiver: var_deliveryNoteWaiver, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected OrderCharge sse_decode_order_charge(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_orderId = sse_decode_i_32(deserializer);
+var var_chargeType = sse_decode_charge_type(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_billing = sse_decode_charge_billing(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_costAmount = sse_decode_f_64(deserializer);
+var var_revenueAmount = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return OrderCharge(id: var_id, orderId: var_orderId, chargeType: var_chargeType, description: var_description, billing: var_billing, markupPercent: var_markupPercent, costAmount: var_costAmount, revenueAmount: var_revenueAmount, currency: var_currency, notes: var_notes, createdAt: var_createdAt); }
+
+@protected OrderCompliance sse_decode_order_compliance(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_String(deserializer);
+var var_regulatedLines = sse_decode_i_32(deserializer);
+var var_issues = sse_decode_list_compliance_issue(deserializer);
+var var_isCompliant = sse_decode_bool(deserializer);
+return OrderCompliance(orderId: var_orderId, orderNumber: var_orderNumber, regulatedLines: var_regulatedLines, issues: var_issues, isCompliant: var_isCompliant); }
+
+@protected OrderExportFilter sse_decode_order_export_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_status = sse_decode_opt_box_autoadd_order_status(deserializer);
+var var_shipId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_dateFrom = sse_decode_opt_String(deserializer);
+var var_dateTo = sse_decode_opt_String(deserializer);
+return OrderExportFilter(status: var_status, shipId: var_shipId, customerId: var_customerId, dateFrom: var_dateFrom, dateTo: var_dateTo); }
+
+@protected OrderItem sse_decode_order_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_orderId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_buyingPrice = sse_decode_f_64(deserializer);
+var var_sellingPrice = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_deliveryType = sse_decode_delivery_type(deserializer);
+var var_warehouseDeliveryDate = sse_decode_opt_String(deserializer);
+var var_shipDeliveryDate = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_deliveredQuantity = sse_decode_f_64(deserializer);
+var var_backOrderedQuantity = sse_decode_f_64(deserializer);
+var var_rolledOverQuantity = sse_decode_f_64(deserializer);
+var var_closedQuantity = sse_decode_f_64(deserializer);
+var var_closedAt = sse_decode_opt_String(deserializer);
+var var_closeReason = sse_decode_opt_String(deserializer);
+var var_fulfilment = sse_decode_fulfilment_status(deserializer);
+var var_backorderOfItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_returnedQuantity = sse_decode_f_64(deserializer);
+return OrderItem(id: var_id, orderId: var_orderId, productName: var_productName, impaCode: var_impaCode, description: var_description, quantity: var_quantity, unit: var_unit, buyingPrice: var_buyingPrice, sellingPrice: var_sellingPrice, currency: var_currency, deliveryType: var_deliveryType, warehouseDeliveryDate: var_warehouseDeliveryDate, shipDeliveryDate: var_shipDeliveryDate, notes: var_notes, deliveredQuantity: var_deliveredQuantity, backOrderedQuantity: var_backOrderedQuantity, rolledOverQuantity: var_rolledOverQuantity, closedQuantity: var_closedQuantity, closedAt: var_closedAt, closeReason: var_closeReason, fulfilment: var_fulfilment, backorderOfItemId: var_backorderOfItemId, returnedQuantity: var_returnedQuantity); }
+
+@protected OrderLink sse_decode_order_link(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_linkType = sse_decode_order_link_type(deserializer);
+var var_sourceOrderId = sse_decode_i_32(deserializer);
+var var_sourceOrderNumber = sse_decode_opt_String(deserializer);
+var var_targetOrderId = sse_decode_i_32(deserializer);
+var var_targetOrderNumber = sse_decode_opt_String(deserializer);
+var var_orderItemIds = sse_decode_list_prim_i_32_strict(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return OrderLink(id: var_id, linkType: var_linkType, sourceOrderId: var_sourceOrderId, sourceOrderNumber: var_sourceOrderNumber, targetOrderId: var_targetOrderId, targetOrderNumber: var_targetOrderNumber, orderItemIds: var_orderItemIds, createdAt: var_createdAt); }
+
+@protected OrderLinkType sse_decode_order_link_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OrderLinkType.values[inner]; }
+
+@protected OrderProfitInfo sse_decode_order_profit_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_String(deserializer);
+var var_shipName = sse_decode_String(deserializer);
+var var_totalRevenue = sse_decode_f_64(deserializer);
+var var_totalCost = sse_decode_f_64(deserializer);
+var var_profit = sse_decode_f_64(deserializer);
+var var_marginPercent = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+return OrderProfitInfo(orderId: var_orderId, orderNumber: var_orderNumber, shipName: var_shipName, totalRevenue: var_totalRevenue, totalCost: var_totalCost, profit: var_profit, marginPercent: var_marginPercent, currency: var_currency); }
+
+@protected OrderReturn sse_decode_order_return(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_returnNumber = sse_decode_String(deserializer);
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_opt_String(deserializer);
+var var_returnDate = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_creditAmount = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_creditNoteId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_creditNoteNumber = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_order_return_line(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return OrderReturn(id: var_id, returnNumber: var_returnNumber, orderId: var_orderId, orderNumber: var_orderNumber, returnDate: var_returnDate, notes: var_notes, creditAmount: var_creditAmount, currency: var_currency, creditNoteId: var_creditNoteId, creditNoteNumber: var_creditNoteNumber, lines: var_lines, createdAt: var_createdAt); }
+
+@protected OrderReturnLine sse_decode_order_return_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_reason = sse_decode_return_reason(deserializer);
+var var_restocked = sse_decode_bool(deserializer);
+var var_stockMovementId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_creditAmount = sse_decode_f_64(deserializer);
+var var_costAmount = sse_decode_f_64(deserializer);
+return OrderReturnLine(id: var_id, orderItemId: var_orderItemId, productName: var_productName, quantity: var_quantity, unit: var_unit, reason: var_reason, restocked: var_restocked, stockMovementId: var_stockMovementId, creditAmount: var_creditAmount, costAmount: var_costAmount); }
+
+@protected OrderStatus sse_decode_order_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OrderStatus.values[inner]; }
+
+@protected OrderTotals sse_decode_order_totals(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_itemCount = sse_decode_i_32(deserializer);
+var var_chargeCount = sse_decode_i_32(deserializer);
+var var_totalCost = sse_decode_f_64(deserializer);
+var var_totalRevenue = sse_decode_f_64(deserializer);
+var var_chargesCost = sse_decode_f_64(deserializer);
+var var_chargesRevenue = sse_decode_f_64(deserializer);
+var var_landedCost = sse_decode_f_64(deserializer);
+var var_returnsRevenue = sse_decode_f_64(deserializer);
+var var_returnsCost = sse_decode_f_64(deserializer);
+var var_grossProfit = sse_decode_f_64(deserializer);
+var var_marginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+return OrderTotals(itemCount: var_itemCount, chargeCount: var_chargeCount, totalCost: var_totalCost, totalRevenue: var_totalRevenue, chargesCost: var_chargesCost, chargesRevenue: var_chargesRevenue, landedCost: var_landedCost, returnsRevenue: var_returnsRevenue, returnsCost: var_returnsCost, grossProfit: var_grossProfit, marginPercent: var_marginPercent, currency: var_currency); }
+
+@protected OrderWithItems sse_decode_order_with_items(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_order = sse_decode_order(deserializer);
+var var_items = sse_decode_list_order_item(deserializer);
+var var_totals = sse_decode_order_totals(deserializer);
+var var_creditCheck = sse_decode_opt_box_autoadd_credit_check(deserializer);
+return OrderWithItems(order: var_order, items: var_items, totals: var_totals, creditCheck: var_creditCheck); }
+
+@protected PackSize sse_decode_pack_size(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_packUnit = sse_decode_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_contentUnit = sse_decode_String(deserializer);
+return PackSize(id: var_id, supplyItemId: var_supplyItemId, packUnit: var_packUnit, quantity: var_quantity, contentUnit: var_contentUnit); }
+
+@protected PayableDue sse_decode_payable_due(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierInvoiceId = sse_decode_i_32(deserializer);
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_invoiceNumber = sse_decode_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_dueDate = sse_decode_String(deserializer);
+var var_balance = sse_decode_f_64(deserializer);
+var var_daysUntilDue = sse_decode_i_64(deserializer);
+return PayableDue(supplierInvoiceId: var_supplierInvoiceId, supplierId: var_supplierId, supplierName: var_supplierName, invoiceNumber: var_invoiceNumber, currency: var_currency, dueDate: var_dueDate, balance: var_balance, daysUntilDue: var_daysUntilDue); }
+
+@protected Payment sse_decode_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_customerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerName = sse_decode_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_allocatedAmount = sse_decode_f_64(deserializer);
+var var_unallocatedAmount = sse_decode_f_64(deserializer);
+var var_paymentDate = sse_decode_String(deserializer);
+var var_method = sse_decode_payment_method(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_allocations = sse_decode_list_payment_allocation(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return Payment(id: var_id, customerId: var_customerId, customerName: var_customerName, amount: var_amount, currency: var_currency, allocatedAmount: var_allocatedAmount, unallocatedAmount: var_unallocatedAmount, paymentDate: var_paymentDate, method: var_method, reference: var_reference, notes: var_notes, allocations: var_allocations, createdAt: var_createdAt); }
+
+@protected PaymentAllocation sse_decode_payment_allocation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_paymentId = sse_decode_i_32(deserializer);
+var var_invoiceId = sse_decode_i_32(deserializer);
+var var_invoiceNumber = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_exchangeRate = sse_decode_f_64(deserializer);
+var var_invoiceAmount = sse_decode_f_64(deserializer);
+return PaymentAllocation(id: var_id, paymentId: var_paymentId, invoiceId: var_invoiceId, invoiceNumber: var_invoiceNumber, amount: var_amount, exchangeRate: var_exchangeRate, invoiceAmount: var_invoiceAmount); }
+
+@protected PaymentAllocationRequest sse_decode_payment_allocation_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_invoiceId = sse_decode_i_32(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_exchangeRate = sse_decode_opt_box_autoadd_f_64(deserializer);
+return PaymentAllocationRequest(invoiceId: var_invoiceId, amount: var_amount, exchangeRate: var_exchangeRate); }
+
+@protected PaymentMethod sse_decode_payment_method(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PaymentMethod.values[inner]; }
+
+@protected PdfDocumentKind sse_decode_pdf_document_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PdfDocumentKind.values[inner]; }
+
+@protected PdfRenderRequest sse_decode_pdf_render_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_kind = sse_decode_pdf_document_kind(deserializer);
+var var_documentId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_taxRatePercent = sse_decode_f_64(deserializer);
+var var_outputPath = sse_decode_String(deserializer);
+return PdfRenderRequest(orderId: var_orderId, kind: var_kind, documentId: var_documentId, taxRatePercent: var_taxRatePercent, outputPath: var_outputPath); }
+
+@protected PdfRenderResult sse_decode_pdf_render_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_filePath = sse_decode_String(deserializer);
+var var_pageCount = sse_decode_i_32(deserializer);
+return PdfRenderResult(filePath: var_filePath, pageCount: var_pageCount); }
+
+@protected PharmacistSignOffRequest sse_decode_pharmacist_sign_off_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pharmacistName = sse_decode_String(deserializer);
+var var_licenseNumber = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return PharmacistSignOffRequest(pharmacistName: var_pharmacistName, licenseNumber: var_licenseNumber, notes: var_notes); }
+
+@protected Port sse_decode_port(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_country = sse_decode_String(deserializer);
+var var_city = sse_decode_opt_String(deserializer);
+var var_timezone = sse_decode_String(deserializer);
+var var_latitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_longitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isInternational = sse_decode_bool(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return Port(id: var_id, name: var_name, country: var_country, city: var_city, timezone: var_timezone, latitude: var_latitude, longitude: var_longitude, notes: var_notes, isInternational: var_isInternational, isActive: var_isActive, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected PriceAgreement sse_decode_price_agreement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_customerId = sse_decode_i_32(deserializer);
+var var_customerName = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_validFrom = sse_decode_String(deserializer);
+var var_validTo = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+var var_lines = sse_decode_list_price_agreement_line(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return PriceAgreement(id: var_id, customerId: var_customerId, customerName: var_customerName, name: var_name, currency: var_currency, validFrom: var_validFrom, validTo: var_validTo, notes: var_notes, isActive: var_isActive, lines: var_lines, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected PriceAgreementLine sse_decode_price_agreement_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_agreementId = sse_decode_i_32(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_impaGroup = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_fixedPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+return PriceAgreementLine(id: var_id, agreementId: var_agreementId, impaCode: var_impaCode, impaGroup: var_impaGroup, description: var_description, fixedPrice: var_fixedPrice, markupPercent: var_markupPercent); }
+
+@protected PriceChange sse_decode_price_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_effectiveDate = sse_decode_String(deserializer);
+var var_oldPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_oldCurrency = sse_decode_opt_String(deserializer);
+var var_newPrice = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_changePercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_source = sse_decode_price_change_source(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return PriceChange(id: var_id, supplyItemId: var_supplyItemId, effectiveDate: var_effectiveDate, oldPrice: var_oldPrice, oldCurrency: var_oldCurrency, newPrice: var_newPrice, currency: var_currency, changePercent: var_changePercent, source: var_source, reference: var_reference, createdAt: var_createdAt); }
+
+@protected PriceChangeSource sse_decode_price_change_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PriceChangeSource.values[inner]; }
+
+@protected PriceListChange sse_decode_price_list_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rowNumber = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_kind = sse_decode_price_list_change_kind(deserializer);
+var var_matchedBy = sse_decode_opt_box_autoadd_price_list_match_kind(deserializer);
+var var_supplyItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_supplierSku = sse_decode_opt_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_oldPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_oldCurrency = sse_decode_opt_String(deserializer);
+var var_newPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_currency = sse_decode_opt_String(deserializer);
+var var_changePercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return PriceListChange(rowNumber: var_rowNumber, kind: var_kind, matchedBy: var_matchedBy, supplyItemId: var_supplyItemId, supplierSku: var_supplierSku, impaCode: var_impaCode, name: var_name, unit: var_unit, oldPrice: var_oldPrice, oldCurrency: var_oldCurrency, newPrice: var_newPrice, currency: var_currency, changePercent: var_changePercent, error: var_error); }
+
+@protected PriceListChangeKind sse_decode_price_list_change_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PriceListChangeKind.values[inner]; }
+
+@protected PriceListColumnMapping sse_decode_price_list_column_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierSku = sse_decode_opt_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_price = sse_decode_String(deserializer);
+var var_currency = sse_decode_opt_String(deserializer);
+return PriceListColumnMapping(supplierSku: var_supplierSku, impaCode: var_impaCode, name: var_name, unit: var_unit, price: var_price, currency: var_currency); }
+
+@protected PriceListImportRequest sse_decode_price_list_import_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+var var_mapping = sse_decode_price_list_column_mapping(deserializer);
+var var_headerRow = sse_decode_i_32(deserializer);
+var var_sheetName = sse_decode_opt_String(deserializer);
+var var_delimiter = sse_decode_opt_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_defaultCategoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_effectiveDate = sse_decode_opt_String(deserializer);
+var var_discontinueMissing = sse_decode_bool(deserializer);
+return PriceListImportRequest(supplierId: var_supplierId, filePath: var_filePath, mapping: var_mapping, headerRow: var_headerRow, sheetName: var_sheetName, delimiter: var_delimiter, currency: var_currency, defaultCategoryId: var_defaultCategoryId, effectiveDate: var_effectiveDate, discontinueMissing: var_discontinueMissing); }
+
+@protected PriceListImportResult sse_decode_price_list_import_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_created = sse_decode_i_32(deserializer);
+var var_updated = sse_decode_i_32(deserializer);
+var var_unchanged = sse_decode_i_32(deserializer);
+var var_discontinued = sse_decode_i_32(deserializer);
+return PriceListImportResult(created: var_created, updated: var_updated, unchanged: var_unchanged, discontinued: var_discontinued); }
+
+@protected PriceListMatchKind sse_decode_price_list_match_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PriceListMatchKind.values[inner]; }
+
+@protected PriceListPreview sse_decode_price_list_preview(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_supplierName = sse_decode_String(deserializer);
+var var_lines = sse_decode_list_price_list_change(deserializer);
+var var_newItems = sse_decode_i_32(deserializer);
+var var_priceUp = sse_decode_i_32(deserializer);
+var var_priceDown = sse_decode_i_32(deserializer);
+var var_currencyChanged = sse_decode_i_32(deserializer);
+var var_unchanged = sse_decode_i_32(deserializer);
+var var_discontinued = sse_decode_i_32(deserializer);
+var var_errors = sse_decode_i_32(deserializer);
+return PriceListPreview(supplierId: var_supplierId, supplierName: var_supplierName, lines: var_lines, newItems: var_newItems, priceUp: var_priceUp, priceDown: var_priceDown, currencyChanged: var_currencyChanged, unchanged: var_unchanged, discontinued: var_discontinued, errors: var_errors); }
+
+@protected PriceProposal sse_decode_price_proposal(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_buyingPrice = sse_decode_f_64(deserializer);
+var var_sellingPrice = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_source = sse_decode_price_source(deserializer);
+var var_ruleId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_agreementId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_minMarginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_marginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_floorApplied = sse_decode_bool(deserializer);
+var var_belowMarginFloor = sse_decode_bool(deserializer);
+var var_roundingIncrement = sse_decode_f_64(deserializer);
+var var_explanation = sse_decode_String(deserializer);
+return PriceProposal(buyingPrice: var_buyingPrice, sellingPrice: var_sellingPrice, currency: var_currency, source: var_source, ruleId: var_ruleId, agreementId: var_agreementId, markupPercent: var_markupPercent, minMarginPercent: var_minMarginPercent, marginPercent: var_marginPercent, floorApplied: var_floorApplied, belowMarginFloor: var_belowMarginFloor, roundingIncrement: var_roundingIncrement, explanation: var_explanation); }
+
+@protected PriceRiseItem sse_decode_price_rise_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_supplyItemName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_startPrice = sse_decode_f_64(deserializer);
+var var_endPrice = sse_decode_f_64(deserializer);
+var var_changePercent = sse_decode_f_64(deserializer);
+var var_changeCount = sse_decode_i_32(deserializer);
+var var_lastChangeDate = sse_decode_String(deserializer);
+return PriceRiseItem(supplyItemId: var_supplyItemId, supplyItemName: var_supplyItemName, impaCode: var_impaCode, supplierId: var_supplierId, supplierName: var_supplierName, currency: var_currency, startPrice: var_startPrice, endPrice: var_endPrice, changePercent: var_changePercent, changeCount: var_changeCount, lastChangeDate: var_lastChangeDate); }
+
+@protected PriceSource sse_decode_price_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PriceSource.values[inner]; }
+
+@protected PricingRule sse_decode_pricing_rule(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_category = sse_decode_opt_String(deserializer);
+var var_supplierId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_customerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerName = sse_decode_opt_String(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_minMarginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return PricingRule(id: var_id, name: var_name, categoryId: var_categoryId, category: var_category, supplierId: var_supplierId, supplierName: var_supplierName, customerId: var_customerId, customerName: var_customerName, markupPercent: var_markupPercent, minMarginPercent: var_minMarginPercent, notes: var_notes, isActive: var_isActive, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected ProductAlternative sse_decode_product_alternative(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_supplierSku = sse_decode_opt_String(deserializer);
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_unitPrice = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_isAvailable = sse_decode_bool(deserializer);
+var var_stockQuantity = sse_decode_f_64(deserializer);
+var var_leadTimeDays = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_linkedBy = sse_decode_alternative_link(deserializer);
+return ProductAlternative(supplyItemId: var_supplyItemId, name: var_name, impaCode: var_impaCode, supplierSku: var_supplierSku, supplierId: var_supplierId, supplierName: var_supplierName, unit: var_unit, unitPrice: var_unitPrice, currency: var_currency, isAvailable: var_isAvailable, stockQuantity: var_stockQuantity, leadTimeDays: var_leadTimeDays, linkedBy: var_linkedBy); }
+
+@protected ProfitSummary sse_decode_profit_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalOrders = sse_decode_i_32(deserializer);
+var var_totalRevenue = sse_decode_f_64(deserializer);
+var var_totalCost = sse_decode_f_64(deserializer);
+var var_totalProfit = sse_decode_f_64(deserializer);
+var var_averageMargin = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+return ProfitSummary(totalOrders: var_totalOrders, totalRevenue: var_totalRevenue, totalCost: var_totalCost, totalProfit: var_totalProfit, averageMargin: var_averageMargin, currency: var_currency); }
+
+@protected ReceivablesAgingRow sse_decode_receivables_aging_row(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_customerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_customerName = sse_decode_String(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_days030 = sse_decode_f_64(deserializer);
+var var_days3160 = sse_decode_f_64(deserializer);
+var var_days6190 = sse_decode_f_64(deserializer);
+var var_daysOver90 = sse_decode_f_64(deserializer);
+var var_total = sse_decode_f_64(deserializer);
+var var_credit = sse_decode_f_64(deserializer);
+return ReceivablesAgingRow(customerId: var_customerId, customerName: var_customerName, currency: var_currency, days030: var_days030, days3160: var_days3160, days6190: var_days6190, daysOver90: var_daysOver90, total: var_total, credit: var_credit); }
+
+@protected RecordDeliveryRequest sse_decode_record_delivery_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_deliveredAt = sse_decode_opt_String(deserializer);
+var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_delivery_line(deserializer);
+return RecordDeliveryRequest(orderId: var_orderId, deliveredAt: var_deliveredAt, shipVisitId: var_shipVisitId, notes: var_notes, lines: var_lines); }
+
+@protected RepriceResult sse_decode_reprice_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_updatedCount = sse_decode_i_32(deserializer);
+var var_unchangedCount = sse_decode_i_32(deserializer);
+var var_skippedCount = sse_decode_i_32(deserializer);
+var var_items = sse_decode_list_repriced_item(deserializer);
+return RepriceResult(orderId: var_orderId, updatedCount: var_updatedCount, unchangedCount: var_unchangedCount, skippedCount: var_skippedCount, items: var_items); }
+
+@protected RepricedItem sse_decode_repriced_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_oldSellingPrice = sse_decode_f_64(deserializer);
+var var_newSellingPrice = sse_decode_f_64(deserializer);
+var var_proposal = sse_decode_price_proposal(deserializer);
+return RepricedItem(orderItemId: var_orderItemId, productName: var_productName, impaCode: var_impaCode, oldSellingPrice: var_oldSellingPrice, newSellingPrice: var_newSellingPrice, proposal: var_proposal); }
+
+@protected RequisitionColumnMapping sse_decode_requisition_column_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_quantity = sse_decode_String(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return RequisitionColumnMapping(impaCode: var_impaCode, description: var_description, quantity: var_quantity, unit: var_unit, notes: var_notes); }
+
+@protected RequisitionImportLine sse_decode_requisition_import_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_rowNumber = sse_decode_i_32(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_matchKind = sse_decode_requisition_match_kind(deserializer);
+var var_supplyItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_supplyItemName = sse_decode_opt_String(deserializer);
+var var_matchScore = sse_decode_f_64(deserializer);
+var var_include = sse_decode_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return RequisitionImportLine(rowNumber: var_rowNumber, impaCode: var_impaCode, description: var_description, quantity: var_quantity, unit: var_unit, notes: var_notes, matchKind: var_matchKind, supplyItemId: var_supplyItemId, supplyItemName: var_supplyItemName, matchScore: var_matchScore, include: var_include, error: var_error); }
+
+@protected RequisitionImportPreview sse_decode_requisition_import_preview(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_orderNumber = sse_decode_String(deserializer);
+var var_lines = sse_decode_list_requisition_import_line(deserializer);
+var var_impaMatched = sse_decode_i_32(deserializer);
+var var_fuzzyMatched = sse_decode_i_32(deserializer);
+var var_unmatched = sse_decode_i_32(deserializer);
+var var_errors = sse_decode_i_32(deserializer);
+return RequisitionImportPreview(orderId: var_orderId, orderNumber: var_orderNumber, lines: var_lines, impaMatched: var_impaMatched, fuzzyMatched: var_fuzzyMatched, unmatched: var_unmatched, errors: var_errors); }
+
+@protected RequisitionImportRequest sse_decode_requisition_import_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderId = sse_decode_i_32(deserializer);
+var var_filePath = sse_decode_String(deserializer);
+var var_mapping = sse_decode_requisition_column_mapping(deserializer);
+var var_headerRow = sse_decode_i_32(deserializer);
+var var_sheetName = sse_decode_opt_String(deserializer);
+var var_delimiter = sse_decode_opt_String(deserializer);
+return RequisitionImportRequest(orderId: var_orderId, filePath: var_filePath, mapping: var_mapping, headerRow: var_headerRow, sheetName: var_sheetName, delimiter: var_delimiter); }
+
+@protected RequisitionMatchKind sse_decode_requisition_match_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RequisitionMatchKind.values[inner]; }
+
+@protected RequisitionTemplate sse_decode_requisition_template(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_shipId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_shipName = sse_decode_opt_String(deserializer);
+var var_shipType = sse_decode_opt_String(deserializer);
+var var_baseHeadcount = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_requisition_template_line(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return RequisitionTemplate(id: var_id, name: var_name, shipId: var_shipId, shipName: var_shipName, shipType: var_shipType, baseHeadcount: var_baseHeadcount, notes: var_notes, lines: var_lines, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected RequisitionTemplateLine sse_decode_requisition_template_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_templateId = sse_decode_i_32(deserializer);
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return RequisitionTemplateLine(id: var_id, templateId: var_templateId, productName: var_productName, impaCode: var_impaCode, description: var_description, quantity: var_quantity, unit: var_unit, notes: var_notes); }
+
+@protected RequisitionTemplateLineRequest sse_decode_requisition_template_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_productName = sse_decode_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return RequisitionTemplateLineRequest(productName: var_productName, impaCode: var_impaCode, description: var_description, quantity: var_quantity, unit: var_unit, notes: var_notes); }
+
+@protected ReturnLineRequest sse_decode_return_line_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_orderItemId = sse_decode_i_32(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_reason = sse_decode_return_reason(deserializer);
+var var_restock = sse_decode_bool(deserializer);
+return ReturnLineRequest(orderItemId: var_orderItemId, quantity: var_quantity, reason: var_reason, restock: var_restock); }
+
+@protected ReturnReason sse_decode_return_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ReturnReason.values[inner]; }
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_scope = sse_decode_search_scope(deserializer);
+var var_entityId = sse_decode_i_32(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_subtitle = sse_decode_opt_String(deserializer);
+var var_snippet = sse_decode_String(deserializer);
+var var_score = sse_decode_f_64(deserializer);
+return SearchHit(scope: var_scope, entityId: var_entityId, title: var_title, subtitle: var_subtitle, snippet: var_snippet, score: var_score); }
+
+@protected SearchScope sse_decode_search_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SearchScope.values[inner]; }
+
+@protected Ship sse_decode_ship(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_imoNumber = sse_decode_String(deserializer);
+var var_flag = sse_decode_String(deserializer);
+var var_shipType = sse_decode_opt_String(deserializer);
+var var_grossTonnage = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_owner = sse_decode_opt_String(deserializer);
+var var_ownerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return Ship(id: var_id, name: var_name, imoNumber: var_imoNumber, flag: var_flag, shipType: var_shipType, grossTonnage: var_grossTonnage, owner: var_owner, ownerId: var_ownerId, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected ShipVisit sse_decode_ship_visit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_shipId = sse_decode_i_32(deserializer);
+var var_shipName = sse_decode_opt_String(deserializer);
+var var_portId = sse_decode_i_32(deserializer);
+var var_portName = sse_decode_opt_String(deserializer);
+var var_eta = sse_decode_String(deserializer);
+var var_etd = sse_decode_String(deserializer);
+var var_ata = sse_decode_opt_String(deserializer);
+var var_atd = sse_decode_opt_String(deserializer);
+var var_status = sse_decode_visit_status(deserializer);
+var var_agentInfo = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return ShipVisit(id: var_id, shipId: var_shipId, shipName: var_shipName, portId: var_portId, portName: var_portName, eta: var_eta, etd: var_etd, ata: var_ata, atd: var_atd, status: var_status, agentInfo: var_agentInfo, notes: var_notes, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected Stock sse_decode_stock(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_supplyItemId = sse_decode_i_32(deserializer);
+var var_supplyItemName = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_warehouseLocation = sse_decode_opt_String(deserializer);
+var var_warehouseType = sse_decode_warehouse_type(deserializer);
+var var_minimumQuantity = sse_decode_f_64(deserializer);
+var var_lastUpdated = sse_decode_String(deserializer);
+return Stock(id: var_id, supplyItemId: var_supplyItemId, supplyItemName: var_supplyItemName, quantity: var_quantity, unit: var_unit, warehouseLocation: var_warehouseLocation, warehouseType: var_warehouseType, minimumQuantity: var_minimumQuantity, lastUpdated: var_lastUpdated); }
+
+@protected StockMovement sse_decode_stock_movement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_stockId = sse_decode_i_32(deserializer);
+var var_supplyItemName = sse_decode_opt_String(deserializer);
+var var_movementType = sse_decode_stock_movement_type(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_referenceType = sse_decode_opt_String(deserializer);
+var var_referenceId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_referenceInfo = sse_decode_opt_String(deserializer);
+var var_orderItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_enteredQuantity = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_enteredUnit = sse_decode_opt_String(deserializer);
+var var_customsDeclarationNumber = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return StockMovement(id: var_id, stockId: var_stockId, supplyItemName: var_supplyItemName, movementType: var_movementType, quantity: var_quantity, unit: var_unit, referenceType: var_referenceType, referenceId: var_referenceId, referenceInfo: var_referenceInfo, orderItemId: var_orderItemId, notes: var_notes, enteredQuantity: var_enteredQuantity, enteredUnit: var_enteredUnit, customsDeclarationNumber: var_customsDeclarationNumber, createdAt: var_createdAt); }
+
+@protected StockMovementType sse_decode_stock_movement_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return StockMovementType.values[inner]; }
+
+@protected StockSummary sse_decode_stock_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_totalItems = sse_decode_i_32(deserializer);
+var var_lowStockCount = sse_decode_i_32(deserializer);
+var var_outOfStockCount = sse_decode_i_32(deserializer);
+var var_totalValue = sse_decode_f_64(deserializer);
+var var_landedCostValue = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+return StockSummary(totalItems: var_totalItems, lowStockCount: var_lowStockCount, outOfStockCount: var_outOfStockCount, totalValue: var_totalValue, landedCostValue: var_landedCostValue, currency: var_currency); }
+
+@protected StockWithMovements sse_decode_stock_with_movements(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_stock = sse_decode_stock(deserializer);
+var var_movements = sse_decode_list_stock_movement(deserializer);
+return StockWithMovements(stock: var_stock, movements: var_movements); }
+
+@protected Supplier sse_decode_supplier(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_contactPerson = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+var var_phone = sse_decode_opt_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+var var_country = sse_decode_opt_String(deserializer);
+var var_category = sse_decode_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_categoryName = sse_decode_opt_String(deserializer);
+var var_leadTimeDays = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return Supplier(id: var_id, name: var_name, contactPerson: var_contactPerson, email: var_email, phone: var_phone, address: var_address, country: var_country, category: var_category, categoryId: var_categoryId, categoryName: var_categoryName, leadTimeDays: var_leadTimeDays, isActive: var_isActive, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected SupplierInvoice sse_decode_supplier_invoice(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_invoiceNumber = sse_decode_String(deserializer);
+var var_orderId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_orderNumber = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_paidAmount = sse_decode_f_64(deserializer);
+var var_balance = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_issueDate = sse_decode_String(deserializer);
+var var_dueDate = sse_decode_String(deserializer);
+var var_status = sse_decode_invoice_status(deserializer);
+var var_approvedAt = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_list_supplier_invoice_line(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return SupplierInvoice(id: var_id, supplierId: var_supplierId, supplierName: var_supplierName, invoiceNumber: var_invoiceNumber, orderId: var_orderId, orderNumber: var_orderNumber, amount: var_amount, paidAmount: var_paidAmount, balance: var_balance, currency: var_currency, issueDate: var_issueDate, dueDate: var_dueDate, status: var_status, approvedAt: var_approvedAt, notes: var_notes, lines: var_lines, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected SupplierInvoiceLine sse_decode_supplier_invoice_line(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_supplierInvoiceId = sse_decode_i_32(deserializer);
+var var_orderItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_supplyItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_quantity = sse_decode_f_64(deserializer);
+var var_unitPrice = sse_decode_f_64(deserializer);
+var var_lineTotal = sse_decode_f_64(deserializer);
+return SupplierInvoiceLine(id: var_id, supplierInvoiceId: var_supplierInvoiceId, orderItemId: var_orderItemId, supplyItemId: var_supplyItemId, description: var_description, quantity: var_quantity, unitPrice: var_unitPrice, lineTotal: var_lineTotal); }
+
+@protected SupplierInvoiceLineMatch sse_decode_supplier_invoice_line_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_lineId = sse_decode_i_32(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_orderItemId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_orderedQuantity = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_orderedPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_receivedQuantity = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_invoicedQuantity = sse_decode_f_64(deserializer);
+var var_invoicedPrice = sse_decode_f_64(deserializer);
+var var_priceVariance = sse_decode_f_64(deserializer);
+var var_status = sse_decode_match_status(deserializer);
+return SupplierInvoiceLineMatch(lineId: var_lineId, description: var_description, orderItemId: var_orderItemId, orderedQuantity: var_orderedQuantity, orderedPrice: var_orderedPrice, receivedQuantity: var_receivedQuantity, invoicedQuantity: var_invoicedQuantity, invoicedPrice: var_invoicedPrice, priceVariance: var_priceVariance, status: var_status); }
+
+@protected SupplierInvoiceMatch sse_decode_supplier_invoice_match(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierInvoiceId = sse_decode_i_32(deserializer);
+var var_lines = sse_decode_list_supplier_invoice_line_match(deserializer);
+var var_isFullyMatched = sse_decode_bool(deserializer);
+var var_totalPriceVariance = sse_decode_f_64(deserializer);
+return SupplierInvoiceMatch(supplierInvoiceId: var_supplierInvoiceId, lines: var_lines, isFullyMatched: var_isFullyMatched, totalPriceVariance: var_totalPriceVariance); }
+
+@protected SupplierPayment sse_decode_supplier_payment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_supplierInvoiceId = sse_decode_i_32(deserializer);
+var var_invoiceNumber = sse_decode_opt_String(deserializer);
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_paymentDate = sse_decode_String(deserializer);
+var var_method = sse_decode_payment_method(deserializer);
+var var_reference = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+return SupplierPayment(id: var_id, supplierInvoiceId: var_supplierInvoiceId, invoiceNumber: var_invoiceNumber, supplierId: var_supplierId, supplierName: var_supplierName, amount: var_amount, currency: var_currency, paymentDate: var_paymentDate, method: var_method, reference: var_reference, notes: var_notes, createdAt: var_createdAt); }
+
+@protected SupplyItem sse_decode_supply_item(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_supplierId = sse_decode_i_32(deserializer);
+var var_supplierName = sse_decode_opt_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_supplierSku = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_category = sse_decode_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_categoryName = sse_decode_opt_String(deserializer);
+var var_unit = sse_decode_String(deserializer);
+var var_unitPrice = sse_decode_f_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+var var_minimumOrderQuantity = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_isAvailable = sse_decode_bool(deserializer);
+var var_unNumber = sse_decode_opt_String(deserializer);
+var var_hazardClass = sse_decode_opt_String(deserializer);
+var var_sdsRequired = sse_decode_bool(deserializer);
+var var_prescriptionOnly = sse_decode_bool(deserializer);
+var var_isBonded = sse_decode_bool(deserializer);
+var var_sdsAttachmentId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_createdAt = sse_decode_String(deserializer);
+var var_updatedAt = sse_decode_String(deserializer);
+return SupplyItem(id: var_id, supplierId: var_supplierId, supplierName: var_supplierName, impaCode: var_impaCode, supplierSku: var_supplierSku, name: var_name, description: var_description, category: var_category, categoryId: var_categoryId, categoryName: var_categoryName, unit: var_unit, unitPrice: var_unitPrice, currency: var_currency, minimumOrderQuantity: var_minimumOrderQuantity, isAvailable: var_isAvailable, unNumber: var_unNumber, hazardClass: var_hazardClass, sdsRequired: var_sdsRequired, prescriptionOnly: var_prescriptionOnly, isBonded: var_isBonded, sdsAttachmentId: var_sdsAttachmentId, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UnitDimension sse_decode_unit_dimension(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UnitDimension.values[inner]; }
+
+@protected UnitOfMeasure sse_decode_unit_of_measure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_i_32(deserializer);
+var var_code = sse_decode_String(deserializer);
+var var_nameTr = sse_decode_String(deserializer);
+var var_nameEn = sse_decode_opt_String(deserializer);
+var var_dimension = sse_decode_unit_dimension(deserializer);
+var var_factor = sse_decode_f_64(deserializer);
+var var_aliases = sse_decode_list_String(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+return UnitOfMeasure(id: var_id, code: var_code, nameTr: var_nameTr, nameEn: var_nameEn, dimension: var_dimension, factor: var_factor, aliases: var_aliases, isActive: var_isActive); }
+
+@protected UpdateCategoryRequest sse_decode_update_category_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_opt_String(deserializer);
+var var_nameTr = sse_decode_opt_String(deserializer);
+var var_nameEn = sse_decode_opt_String(deserializer);
+var var_sortOrder = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_isActive = sse_decode_opt_box_autoadd_bool(deserializer);
+return UpdateCategoryRequest(code: var_code, nameTr: var_nameTr, nameEn: var_nameEn, sortOrder: var_sortOrder, isActive: var_isActive); }
+
+@protected UpdateCompanyProfileRequest sse_decode_update_company_profile_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+var var_phone = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+var var_website = sse_decode_opt_String(deserializer);
+var var_taxOffice = sse_decode_opt_String(deserializer);
+var var_taxNumber = sse_decode_opt_String(deserializer);
+var var_bankName = sse_decode_opt_String(deserializer);
+var var_iban = sse_decode_opt_String(deserializer);
+var var_swift = sse_decode_opt_String(deserializer);
+return UpdateCompanyProfileRequest(name: var_name, address: var_address, phone: var_phone, email: var_email, website: var_website, taxOffice: var_taxOffice, taxNumber: var_taxNumber, bankName: var_bankName, iban: var_iban, swift: var_swift); }
+
+@protected UpdateCustomerRequest sse_decode_update_customer_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_legalName = sse_decode_opt_String(deserializer);
+var var_taxNumber = sse_decode_opt_String(deserializer);
+var var_taxOffice = sse_decode_opt_String(deserializer);
+var var_billingAddress = sse_decode_opt_String(deserializer);
+var var_country = sse_decode_opt_String(deserializer);
+var var_paymentTermsDays = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_creditLimit = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_clearCreditLimit = sse_decode_bool(deserializer);
+var var_defaultCurrency = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return UpdateCustomerRequest(legalName: var_legalName, taxNumber: var_taxNumber, taxOffice: var_taxOffice, billingAddress: var_billingAddress, country: var_country, paymentTermsDays: var_paymentTermsDays, creditLimit: var_creditLimit, clearCreditLimit: var_clearCreditLimit, defaultCurrency: var_defaultCurrency, notes: var_notes); }
+
+@protected UpdateOrderChargeRequest sse_decode_update_order_charge_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_description = sse_decode_opt_String(deserializer);
+var var_amount = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_billing = sse_decode_opt_box_autoadd_charge_billing(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return UpdateOrderChargeRequest(description: var_description, amount: var_amount, billing: var_billing, markupPercent: var_markupPercent, notes: var_notes); }
+
+@protected UpdateOrderItemRequest sse_decode_update_order_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_productName = sse_decode_opt_String(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_quantity = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_buyingPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_sellingPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_deliveryType = sse_decode_opt_box_autoadd_delivery_type(deserializer);
+var var_warehouseDeliveryDate = sse_decode_opt_String(deserializer);
+var var_shipDeliveryDate = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return UpdateOrderItemRequest(productName: var_productName, impaCode: var_impaCode, description: var_description, quantity: var_quantity, unit: var_unit, buyingPrice: var_buyingPrice, sellingPrice: var_sellingPrice, deliveryType: var_deliveryType, warehouseDeliveryDate: var_warehouseDeliveryDate, shipDeliveryDate: var_shipDeliveryDate, notes: var_notes); }
+
+@protected UpdateOrderRequest sse_decode_update_order_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_shipId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_shipVisitId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_deliveryPort = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_currency = sse_decode_opt_String(deserializer);
+return UpdateOrderRequest(shipId: var_shipId, shipVisitId: var_shipVisitId, deliveryPort: var_deliveryPort, notes: var_notes, currency: var_currency); }
+
+@protected UpdatePortRequest sse_decode_update_port_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_country = sse_decode_opt_String(deserializer);
+var var_city = sse_decode_opt_String(deserializer);
+var var_timezone = sse_decode_opt_String(deserializer);
+var var_latitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_longitude = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isInternational = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_isActive = sse_decode_opt_box_autoadd_bool(deserializer);
+return UpdatePortRequest(name: var_name, country: var_country, city: var_city, timezone: var_timezone, latitude: var_latitude, longitude: var_longitude, notes: var_notes, isInternational: var_isInternational, isActive: var_isActive); }
+
+@protected UpdatePriceAgreementRequest sse_decode_update_price_agreement_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_validFrom = sse_decode_opt_String(deserializer);
+var var_validTo = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isActive = sse_decode_opt_box_autoadd_bool(deserializer);
+return UpdatePriceAgreementRequest(name: var_name, validFrom: var_validFrom, validTo: var_validTo, notes: var_notes, isActive: var_isActive); }
+
+@protected UpdatePricingRuleRequest sse_decode_update_pricing_rule_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_markupPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_minMarginPercent = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_isActive = sse_decode_opt_box_autoadd_bool(deserializer);
+return UpdatePricingRuleRequest(name: var_name, markupPercent: var_markupPercent, minMarginPercent: var_minMarginPercent, notes: var_notes, isActive: var_isActive); }
+
+@protected UpdateRequisitionTemplateRequest sse_decode_update_requisition_template_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_baseHeadcount = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+var var_lines = sse_decode_opt_list_requisition_template_line_request(deserializer);
+return UpdateRequisitionTemplateRequest(name: var_name, baseHeadcount: var_baseHeadcount, notes: var_notes, lines: var_lines); }
+
+@protected UpdateShipRequest sse_decode_update_ship_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_imoNumber = sse_decode_opt_String(deserializer);
+var var_flag = sse_decode_opt_String(deserializer);
+var var_shipType = sse_decode_opt_String(deserializer);
+var var_grossTonnage = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_owner = sse_decode_opt_String(deserializer);
+var var_ownerId = sse_decode_opt_box_autoadd_i_32(deserializer);
+return UpdateShipRequest(name: var_name, imoNumber: var_imoNumber, flag: var_flag, shipType: var_shipType, grossTonnage: var_grossTonnage, owner: var_owner, ownerId: var_ownerId); }
+
+@protected UpdateShipVisitRequest sse_decode_update_ship_visit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_portId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_eta = sse_decode_opt_String(deserializer);
+var var_etd = sse_decode_opt_String(deserializer);
+var var_ata = sse_decode_opt_String(deserializer);
+var var_atd = sse_decode_opt_String(deserializer);
+var var_status = sse_decode_opt_box_autoadd_visit_status(deserializer);
+var var_agentInfo = sse_decode_opt_String(deserializer);
+var var_notes = sse_decode_opt_String(deserializer);
+return UpdateShipVisitRequest(portId: var_portId, eta: var_eta, etd: var_etd, ata: var_ata, atd: var_atd, status: var_status, agentInfo: var_agentInfo, notes: var_notes); }
+
+@protected UpdateStockRequest sse_decode_update_stock_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_quantity = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_warehouseLocation = sse_decode_opt_String(deserializer);
+var var_minimumQuantity = sse_decode_opt_box_autoadd_f_64(deserializer);
+return UpdateStockRequest(quantity: var_quantity, warehouseLocation: var_warehouseLocation, minimumQuantity: var_minimumQuantity); }
+
+@protected UpdateSupplierRequest sse_decode_update_supplier_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_opt_String(deserializer);
+var var_contactPerson = sse_decode_opt_String(deserializer);
+var var_email = sse_decode_opt_String(deserializer);
+var var_phone = sse_decode_opt_String(deserializer);
+var var_address = sse_decode_opt_String(deserializer);
+var var_country = sse_decode_opt_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_leadTimeDays = sse_decode_opt_box_autoadd_i_32(deserializer);
+return UpdateSupplierRequest(name: var_name, contactPerson: var_contactPerson, email: var_email, phone: var_phone, address: var_address, country: var_country, categoryId: var_categoryId, leadTimeDays: var_leadTimeDays); }
+
+@protected UpdateSupplyItemRequest sse_decode_update_supply_item_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_supplierId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_impaCode = sse_decode_opt_String(deserializer);
+var var_supplierSku = sse_decode_opt_String(deserializer);
+var var_name = sse_decode_opt_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_categoryId = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_unit = sse_decode_opt_String(deserializer);
+var var_unitPrice = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_currency = sse_decode_opt_String(deserializer);
+var var_minimumOrderQuantity = sse_decode_opt_box_autoadd_i_32(deserializer);
+var var_isAvailable = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_priceEffectiveDate = sse_decode_opt_String(deserializer);
+var var_unNumber = sse_decode_opt_String(deserializer);
+var var_hazardClass = sse_decode_opt_String(deserializer);
+var var_sdsRequired = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_prescriptionOnly = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_isBonded = sse_decode_opt_box_autoadd_bool(deserializer);
+return UpdateSupplyItemRequest(supplierId: var_supplierId, impaCode: var_impaCode, supplierSku: var_supplierSku, name: var_name, description: var_description, categoryId: var_categoryId, unit: var_unit, unitPrice: var_unitPrice, currency: var_currency, minimumOrderQuantity: var_minimumOrderQuantity, isAvailable: var_isAvailable, priceEffectiveDate: var_priceEffectiveDate, unNumber: var_unNumber, hazardClass: var_hazardClass, sdsRequired: var_sdsRequired, prescriptionOnly: var_prescriptionOnly, isBonded: var_isBonded); }
+
+@protected UpdateUnitRequest sse_decode_update_unit_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nameTr = sse_decode_opt_String(deserializer);
+var var_nameEn = sse_decode_opt_String(deserializer);
+var var_factor = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_aliases = sse_decode_opt_list_String(deserializer);
+var var_isActive = sse_decode_opt_box_autoadd_bool(deserializer);
+return UpdateUnitRequest(nameTr: var_nameTr, nameEn: var_nameEn, factor: var_factor, aliases: var_aliases, isActive: var_isActive); }
+
+@protected VisitStatus sse_decode_visit_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return VisitStatus.values[inner]; }
+
+@protected WarehouseType sse_decode_warehouse_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return WarehouseType.values[inner]; }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_allocation_method(AllocationMethod self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_alternative_link(AlternativeLink self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_attachment(Attachment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_attachment_entity(self.entityType, serializer);
+sse_encode_i_32(self.entityId, serializer);
+sse_encode_opt_String(self.role, serializer);
+sse_encode_String(self.fileName, serializer);
+sse_encode_String(self.mimeType, serializer);
+sse_encode_String(self.contentHash, serializer);
+sse_encode_i_64(self.sizeBytes, serializer);
+sse_encode_opt_box_autoadd_i_32(self.width, serializer);
+sse_encode_opt_box_autoadd_i_32(self.height, serializer);
+sse_encode_bool(self.hasThumbnail, serializer);
+sse_encode_opt_String(self.caption, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_attachment_entity(AttachmentEntity self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bonded_eligibility(BondedEligibility self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.shipName, serializer);
+sse_encode_opt_String(self.shipFlag, serializer);
+sse_encode_opt_String(self.portName, serializer);
+sse_encode_opt_String(self.portCountry, serializer);
+sse_encode_bool(self.isInternationalPort, serializer);
+sse_encode_bool(self.isEligible, serializer);
+sse_encode_opt_String(self.reason, serializer);
+ }
+
+@protected void sse_encode_bonded_reconciliation(BondedReconciliation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.periodStart, serializer);
+sse_encode_String(self.periodEnd, serializer);
+sse_encode_list_bonded_reconciliation_line(self.lines, serializer);
+sse_encode_list_stock_movement(self.movements, serializer);
+ }
+
+@protected void sse_encode_bonded_reconciliation_line(BondedReconciliationLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.stockId, serializer);
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_opt_String(self.supplyItemName, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_opt_String(self.warehouseLocation, serializer);
+sse_encode_f_64(self.openingQuantity, serializer);
+sse_encode_f_64(self.receivedQuantity, serializer);
+sse_encode_f_64(self.returnedQuantity, serializer);
+sse_encode_f_64(self.issuedQuantity, serializer);
+sse_encode_f_64(self.adjustedQuantity, serializer);
+sse_encode_f_64(self.closingQuantity, serializer);
+sse_encode_f_64(self.expectedQuantity, serializer);
+sse_encode_f_64(self.recordedQuantity, serializer);
+sse_encode_f_64(self.difference, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_attachment(Attachment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_attachment(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_category(Category self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_category(self, serializer); }
+
+@protected void sse_encode_box_autoadd_charge_billing(ChargeBilling self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_charge_billing(self, serializer); }
+
+@protected void sse_encode_box_autoadd_complete_delivery_note_request(CompleteDeliveryNoteRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_complete_delivery_note_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_contract_price(ContractPrice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_contract_price(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_attachment_request(CreateAttachmentRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_attachment_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_category_request(CreateCategoryRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_category_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_customer_contact_request(CreateCustomerContactRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_customer_contact_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_customer_request(CreateCustomerRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_customer_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_delivery_note_request(CreateDeliveryNoteRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_delivery_note_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_equivalence_group_request(CreateEquivalenceGroupRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_equivalence_group_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_invoice_request(CreateInvoiceRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_invoice_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_landed_cost_request(CreateLandedCostRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_landed_cost_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_order_charge_request(CreateOrderChargeRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_order_charge_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_order_item_request(CreateOrderItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_order_item_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_order_request(CreateOrderRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_order_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_payment_request(CreatePaymentRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_payment_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_port_request(CreatePortRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_port_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_price_agreement_line_request(CreatePriceAgreementLineRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_price_agreement_line_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_price_agreement_request(CreatePriceAgreementRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_price_agreement_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_pricing_rule_request(CreatePricingRuleRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_pricing_rule_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_requisition_template_request(CreateRequisitionTemplateRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_requisition_template_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_return_request(CreateReturnRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_return_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_ship_request(CreateShipRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_ship_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_ship_visit_request(CreateShipVisitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_ship_visit_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_stock_movement_request(CreateStockMovementRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_stock_movement_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_stock_request(CreateStockRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_stock_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_supplier_invoice_request(CreateSupplierInvoiceRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_supplier_invoice_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_supplier_payment_request(CreateSupplierPaymentRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_supplier_payment_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_supplier_request(CreateSupplierRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_supplier_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_supply_item_request(CreateSupplyItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_supply_item_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_create_unit_request(CreateUnitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_create_unit_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_credit_check(CreditCheck self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_credit_check(self, serializer); }
+
+@protected void sse_encode_box_autoadd_customer(Customer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_customer(self, serializer); }
+
+@protected void sse_encode_box_autoadd_delivery_note(DeliveryNote self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_delivery_note(self, serializer); }
+
+@protected void sse_encode_box_autoadd_delivery_type(DeliveryType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_delivery_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_equivalence_group(EquivalenceGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_equivalence_group(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_impa_catalog_entry(ImpaCatalogEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_impa_catalog_entry(self, serializer); }
+
+@protected void sse_encode_box_autoadd_impa_catalog_import_request(ImpaCatalogImportRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_impa_catalog_import_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_instantiate_template_request(InstantiateTemplateRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_instantiate_template_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_invoice(Invoice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_invoice(self, serializer); }
+
+@protected void sse_encode_box_autoadd_invoice_status(InvoiceStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_invoice_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_order_export_filter(OrderExportFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_order_export_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_order_return(OrderReturn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_order_return(self, serializer); }
+
+@protected void sse_encode_box_autoadd_order_status(OrderStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_order_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_order_with_items(OrderWithItems self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_order_with_items(self, serializer); }
+
+@protected void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_payment(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pdf_render_request(PdfRenderRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pdf_render_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_pharmacist_sign_off_request(PharmacistSignOffRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pharmacist_sign_off_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_port(Port self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_port(self, serializer); }
+
+@protected void sse_encode_box_autoadd_price_agreement(PriceAgreement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_price_agreement(self, serializer); }
+
+@protected void sse_encode_box_autoadd_price_list_import_request(PriceListImportRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_price_list_import_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_price_list_match_kind(PriceListMatchKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_price_list_match_kind(self, serializer); }
+
+@protected void sse_encode_box_autoadd_product_alternative(ProductAlternative self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_product_alternative(self, serializer); }
+
+@protected void sse_encode_box_autoadd_record_delivery_request(RecordDeliveryRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_record_delivery_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_requisition_import_request(RequisitionImportRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_requisition_import_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_requisition_template(RequisitionTemplate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_requisition_template(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ship(Ship self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ship(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ship_visit(ShipVisit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ship_visit(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stock(Stock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stock(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stock_with_movements(StockWithMovements self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stock_with_movements(self, serializer); }
+
+@protected void sse_encode_box_autoadd_supplier(Supplier self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_supplier(self, serializer); }
+
+@protected void sse_encode_box_autoadd_supplier_invoice(SupplierInvoice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_supplier_invoice(self, serializer); }
+
+@protected void sse_encode_box_autoadd_supply_item(SupplyItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_supply_item(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_category_request(UpdateCategoryRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_category_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_company_profile_request(UpdateCompanyProfileRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_company_profile_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_customer_request(UpdateCustomerRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_customer_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_order_charge_request(UpdateOrderChargeRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_order_charge_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_order_item_request(UpdateOrderItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_order_item_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_order_request(UpdateOrderRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_order_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_port_request(UpdatePortRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_port_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_price_agreement_request(UpdatePriceAgreementRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_price_agreement_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_pricing_rule_request(UpdatePricingRuleRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_pricing_rule_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_requisition_template_request(UpdateRequisitionTemplateRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_requisition_template_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_ship_request(UpdateShipRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_ship_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_ship_visit_request(UpdateShipVisitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_ship_visit_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_stock_request(UpdateStockRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_stock_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_supplier_request(UpdateSupplierRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_supplier_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_supply_item_request(UpdateSupplyItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_supply_item_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_update_unit_request(UpdateUnitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_update_unit_request(self, serializer); }
+
+@protected void sse_encode_box_autoadd_visit_status(VisitStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_visit_status(self, serializer); }
+
+@protected void sse_encode_calendar_data(CalendarData self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_calendar_event(self.events, serializer);
+sse_encode_list_port(self.ports, serializer);
+ }
+
+@protected void sse_encode_calendar_event(CalendarEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_calendar_event_type(self.eventType, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_opt_String(self.subtitle, serializer);
+sse_encode_String(self.startDate, serializer);
+sse_encode_String(self.endDate, serializer);
+sse_encode_String(self.color, serializer);
+sse_encode_String(self.status, serializer);
+sse_encode_opt_box_autoadd_i_32(self.relatedShipId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.relatedPortId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.relatedOrderId, serializer);
+sse_encode_opt_String(self.metadata, serializer);
+ }
+
+@protected void sse_encode_calendar_event_type(CalendarEventType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_category(Category self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_opt_box_autoadd_i_32(self.parentId, serializer);
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.nameTr, serializer);
+sse_encode_opt_String(self.nameEn, serializer);
+sse_encode_i_32(self.depth, serializer);
+sse_encode_String(self.path, serializer);
+sse_encode_i_32(self.sortOrder, serializer);
+sse_encode_bool(self.isActive, serializer);
+sse_encode_i_32(self.itemCount, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_charge_billing(ChargeBilling self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_charge_type(ChargeType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_company_profile(CompanyProfile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.address, serializer);
+sse_encode_opt_String(self.phone, serializer);
+sse_encode_opt_String(self.email, serializer);
+sse_encode_opt_String(self.website, serializer);
+sse_encode_opt_String(self.taxOffice, serializer);
+sse_encode_opt_String(self.taxNumber, serializer);
+sse_encode_opt_String(self.bankName, serializer);
+sse_encode_opt_String(self.iban, serializer);
+sse_encode_opt_String(self.swift, serializer);
+sse_encode_opt_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_complete_delivery_note_request(CompleteDeliveryNoteRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.receiverName, serializer);
+sse_encode_String(self.receiverRank, serializer);
+sse_encode_opt_String(self.signedAt, serializer);
+ }
+
+@protected void sse_encode_compliance_issue(ComplianceIssue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_compliance_issue_kind(self.kind, serializer);
+sse_encode_String(self.detail, serializer);
+ }
+
+@protected void sse_encode_compliance_issue_kind(ComplianceIssueKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_contract_price(ContractPrice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.agreementId, serializer);
+sse_encode_String(self.agreementName, serializer);
+sse_encode_i_32(self.lineId, serializer);
+sse_encode_f_64(self.sellingPrice, serializer);
+sse_encode_bool(self.isGroupPrice, serializer);
+ }
+
+@protected void sse_encode_create_attachment_request(CreateAttachmentRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_attachment_entity(self.entityType, serializer);
+sse_encode_i_32(self.entityId, serializer);
+sse_encode_String(self.fileName, serializer);
+sse_encode_String(self.mimeType, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_opt_String(self.caption, serializer);
+ }
+
+@protected void sse_encode_create_category_request(CreateCategoryRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.parentId, serializer);
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.nameTr, serializer);
+sse_encode_opt_String(self.nameEn, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sortOrder, serializer);
+ }
+
+@protected void sse_encode_create_customer_contact_request(CreateCustomerContactRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.role, serializer);
+sse_encode_opt_String(self.email, serializer);
+sse_encode_opt_String(self.phone, serializer);
+sse_encode_bool(self.isPrimary, serializer);
+ }
+
+@protected void sse_encode_create_customer_request(CreateCustomerRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.legalName, serializer);
+sse_encode_opt_String(self.taxNumber, serializer);
+sse_encode_opt_String(self.taxOffice, serializer);
+sse_encode_opt_String(self.billingAddress, serializer);
+sse_encode_opt_String(self.country, serializer);
+sse_encode_opt_box_autoadd_i_32(self.paymentTermsDays, serializer);
+sse_encode_opt_box_autoadd_f_64(self.creditLimit, serializer);
+sse_encode_opt_String(self.defaultCurrency, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_create_customer_contact_request(self.contacts, serializer);
+ }
+
+@protected void sse_encode_create_delivery_note_request(CreateDeliveryNoteRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_list_prim_i_32_strict(self.deliveryIds, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_create_equivalence_group_request(CreateEquivalenceGroupRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_prim_i_32_strict(self.supplyItemIds, serializer);
+ }
+
+@protected void sse_encode_create_invoice_request(CreateInvoiceRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.issueDate, serializer);
+sse_encode_opt_String(self.dueDate, serializer);
+sse_encode_opt_box_autoadd_f_64(self.amount, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_create_landed_cost_request(CreateLandedCostRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.stockMovementId, serializer);
+sse_encode_landed_cost_type(self.costType, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_allocation_method(self.allocationMethod, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_list_item_weight(self.weights, serializer);
+ }
+
+@protected void sse_encode_create_order_charge_request(CreateOrderChargeRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_charge_type(self.chargeType, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_charge_billing(self.billing, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_create_order_item_request(CreateOrderItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_f_64(self.buyingPrice, serializer);
+sse_encode_f_64(self.sellingPrice, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_delivery_type(self.deliveryType, serializer);
+sse_encode_opt_String(self.warehouseDeliveryDate, serializer);
+sse_encode_opt_String(self.shipDeliveryDate, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_create_order_request(CreateOrderRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.shipId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
+sse_encode_opt_String(self.deliveryPort, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_create_payment_request(CreatePaymentRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.customerId, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.paymentDate, serializer);
+sse_encode_payment_method(self.method, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_payment_allocation_request(self.allocations, serializer);
+ }
+
+@protected void sse_encode_create_port_request(CreatePortRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.country, serializer);
+sse_encode_opt_String(self.city, serializer);
+sse_encode_String(self.timezone, serializer);
+sse_encode_opt_box_autoadd_f_64(self.latitude, serializer);
+sse_encode_opt_box_autoadd_f_64(self.longitude, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_bool(self.isInternational, serializer);
+ }
+
+@protected void sse_encode_create_price_agreement_line_request(CreatePriceAgreementLineRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.impaGroup, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_f_64(self.fixedPrice, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+ }
+
+@protected void sse_encode_create_price_agreement_request(CreatePriceAgreementRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.customerId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.validFrom, serializer);
+sse_encode_String(self.validTo, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_create_price_agreement_line_request(self.lines, serializer);
+ }
+
+@protected void sse_encode_create_pricing_rule_request(CreatePricingRuleRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.supplierId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.customerId, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minMarginPercent, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_create_requisition_template_request(CreateRequisitionTemplateRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipId, serializer);
+sse_encode_opt_String(self.shipType, serializer);
+sse_encode_opt_box_autoadd_f_64(self.baseHeadcount, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_requisition_template_line_request(self.lines, serializer);
+ }
+
+@protected void sse_encode_create_return_request(CreateReturnRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.returnDate, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_return_line_request(self.lines, serializer);
+sse_encode_bool(self.issueCreditNote, serializer);
+sse_encode_opt_String(self.customsDeclarationNumber, serializer);
+ }
+
+@protected void sse_encode_create_ship_request(CreateShipRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.imoNumber, serializer);
+sse_encode_String(self.flag, serializer);
+sse_encode_opt_String(self.shipType, serializer);
+sse_encode_opt_box_autoadd_f_64(self.grossTonnage, serializer);
+sse_encode_opt_String(self.owner, serializer);
+sse_encode_opt_box_autoadd_i_32(self.ownerId, serializer);
+ }
+
+@protected void sse_encode_create_ship_visit_request(CreateShipVisitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.shipId, serializer);
+sse_encode_i_32(self.portId, serializer);
+sse_encode_String(self.eta, serializer);
+sse_encode_String(self.etd, serializer);
+sse_encode_opt_String(self.agentInfo, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_create_stock_movement_request(CreateStockMovementRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.stockId, serializer);
+sse_encode_stock_movement_type(self.movementType, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_opt_String(self.referenceType, serializer);
+sse_encode_opt_box_autoadd_i_32(self.referenceId, serializer);
+sse_encode_opt_String(self.referenceInfo, serializer);
+sse_encode_opt_box_autoadd_i_32(self.orderItemId, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_opt_String(self.customsDeclarationNumber, serializer);
+ }
+
+@protected void sse_encode_create_stock_request(CreateStockRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_opt_String(self.warehouseLocation, serializer);
+sse_encode_warehouse_type(self.warehouseType, serializer);
+sse_encode_f_64(self.minimumQuantity, serializer);
+ }
+
+@protected void sse_encode_create_supplier_invoice_line_request(CreateSupplierInvoiceLineRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.orderItemId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.supplyItemId, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_f_64(self.unitPrice, serializer);
+ }
+
+@protected void sse_encode_create_supplier_invoice_request(CreateSupplierInvoiceRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_String(self.invoiceNumber, serializer);
+sse_encode_opt_box_autoadd_i_32(self.orderId, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.issueDate, serializer);
+sse_encode_String(self.dueDate, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_create_supplier_invoice_line_request(self.lines, serializer);
+ }
+
+@protected void sse_encode_create_supplier_payment_request(CreateSupplierPaymentRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplierInvoiceId, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_String(self.paymentDate, serializer);
+sse_encode_payment_method(self.method, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_create_supplier_request(CreateSupplierRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.contactPerson, serializer);
+sse_encode_opt_String(self.email, serializer);
+sse_encode_opt_String(self.phone, serializer);
+sse_encode_opt_String(self.address, serializer);
+sse_encode_opt_String(self.country, serializer);
+sse_encode_i_32(self.categoryId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.leadTimeDays, serializer);
+ }
+
+@protected void sse_encode_create_supply_item_request(CreateSupplyItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.supplierSku, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_f_64(self.unitPrice, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_i_32(self.minimumOrderQuantity, serializer);
+sse_encode_opt_String(self.unNumber, serializer);
+sse_encode_opt_String(self.hazardClass, serializer);
+sse_encode_bool(self.sdsRequired, serializer);
+sse_encode_bool(self.prescriptionOnly, serializer);
+sse_encode_bool(self.isBonded, serializer);
+ }
+
+@protected void sse_encode_create_unit_request(CreateUnitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.nameTr, serializer);
+sse_encode_opt_String(self.nameEn, serializer);
+sse_encode_unit_dimension(self.dimension, serializer);
+sse_encode_f_64(self.factor, serializer);
+sse_encode_list_String(self.aliases, serializer);
+ }
+
+@protected void sse_encode_credit_check(CreditCheck self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.customerId, serializer);
+sse_encode_String(self.customerName, serializer);
+sse_encode_opt_box_autoadd_f_64(self.creditLimit, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.openReceivables, serializer);
+sse_encode_f_64(self.uninvoicedOrders, serializer);
+sse_encode_f_64(self.orderAmount, serializer);
+sse_encode_f_64(self.projectedExposure, serializer);
+sse_encode_bool(self.exceedsLimit, serializer);
+sse_encode_list_currency_exposure(self.otherCurrencies, serializer);
+sse_encode_opt_String(self.warning, serializer);
+ }
+
+@protected void sse_encode_credit_note(CreditNote self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.creditNoteNumber, serializer);
+sse_encode_i_32(self.invoiceId, serializer);
+sse_encode_opt_String(self.invoiceNumber, serializer);
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.returnId, serializer);
+sse_encode_String(self.customerName, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.issueDate, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_currency_exposure(CurrencyExposure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.openReceivables, serializer);
+sse_encode_f_64(self.uninvoicedOrders, serializer);
+ }
+
+@protected void sse_encode_currency_rounding(CurrencyRounding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.increment, serializer);
+ }
+
+@protected void sse_encode_customer(Customer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.legalName, serializer);
+sse_encode_opt_String(self.taxNumber, serializer);
+sse_encode_opt_String(self.taxOffice, serializer);
+sse_encode_opt_String(self.billingAddress, serializer);
+sse_encode_opt_String(self.country, serializer);
+sse_encode_i_32(self.paymentTermsDays, serializer);
+sse_encode_opt_box_autoadd_f_64(self.creditLimit, serializer);
+sse_encode_String(self.defaultCurrency, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_bool(self.isActive, serializer);
+sse_encode_list_customer_contact(self.contacts, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_customer_balance(CustomerBalance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.customerId, serializer);
+sse_encode_String(self.customerName, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.totalInvoiced, serializer);
+sse_encode_f_64(self.totalPaid, serializer);
+sse_encode_f_64(self.totalCredited, serializer);
+sse_encode_f_64(self.openBalance, serializer);
+sse_encode_f_64(self.credit, serializer);
+ }
+
+@protected void sse_encode_customer_contact(CustomerContact self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.customerId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.role, serializer);
+sse_encode_opt_String(self.email, serializer);
+sse_encode_opt_String(self.phone, serializer);
+sse_encode_bool(self.isPrimary, serializer);
+ }
+
+@protected void sse_encode_dangerous_goods_class_total(DangerousGoodsClassTotal self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.hazardClass, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_i_32(self.lineCount, serializer);
+sse_encode_list_String(self.unNumbers, serializer);
+ }
+
+@protected void sse_encode_dangerous_goods_line(DangerousGoodsLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_String(self.unNumber, serializer);
+sse_encode_String(self.hazardClass, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_bool(self.sdsOnFile, serializer);
+ }
+
+@protected void sse_encode_dangerous_goods_summary(DangerousGoodsSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.deliveryNoteId, serializer);
+sse_encode_String(self.noteNumber, serializer);
+sse_encode_opt_String(self.orderNumber, serializer);
+sse_encode_opt_String(self.shipName, serializer);
+sse_encode_list_dangerous_goods_line(self.lines, serializer);
+sse_encode_list_dangerous_goods_class_total(self.classes, serializer);
+ }
+
+@protected void sse_encode_delivery_line(DeliveryLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_f_64(self.quantity, serializer);
+ }
+
+@protected void sse_encode_delivery_note(DeliveryNote self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.noteNumber, serializer);
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.orderNumber, serializer);
+sse_encode_opt_String(self.shipName, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
+sse_encode_delivery_note_status(self.status, serializer);
+sse_encode_opt_String(self.receiverName, serializer);
+sse_encode_opt_String(self.receiverRank, serializer);
+sse_encode_opt_String(self.signedAt, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_delivery_note_line(self.lines, serializer);
+sse_encode_list_delivery_note_attachment(self.attachments, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_delivery_note_attachment(DeliveryNoteAttachment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.deliveryNoteId, serializer);
+sse_encode_delivery_note_attachment_kind(self.kind, serializer);
+sse_encode_String(self.fileName, serializer);
+sse_encode_String(self.mimeType, serializer);
+sse_encode_i_64(self.sizeBytes, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_delivery_note_attachment_kind(DeliveryNoteAttachmentKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_delivery_note_line(DeliveryNoteLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_f_64(self.quantity, serializer);
+ }
+
+@protected void sse_encode_delivery_note_status(DeliveryNoteStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_delivery_type(DeliveryType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_equivalence_group(EquivalenceGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_supply_item(self.members, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_export_result(ExportResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.files, serializer);
+sse_encode_i_32(self.rowCount, serializer);
+ }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_fulfilment_status(FulfilmentStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_impa_catalog_entry(ImpaCatalogEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_String(self.section, serializer);
+sse_encode_opt_String(self.sectionName, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_String(self.category, serializer);
+ }
+
+@protected void sse_encode_impa_catalog_import_request(ImpaCatalogImportRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.filePath, serializer);
+sse_encode_impa_column_mapping(self.mapping, serializer);
+sse_encode_i_32(self.headerRow, serializer);
+sse_encode_opt_String(self.sheetName, serializer);
+sse_encode_opt_String(self.delimiter, serializer);
+sse_encode_bool(self.replaceExisting, serializer);
+ }
+
+@protected void sse_encode_impa_catalog_import_result(ImpaCatalogImportResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.inserted, serializer);
+sse_encode_i_32(self.updated, serializer);
+sse_encode_i_32(self.sections, serializer);
+sse_encode_list_String(self.errors, serializer);
+ }
+
+@protected void sse_encode_impa_code_issue(ImpaCodeIssue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_String(self.supplyItemName, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_String(self.impaCode, serializer);
+sse_encode_impa_code_problem(self.problem, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_impa_code_problem(ImpaCodeProblem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_impa_column_mapping(ImpaColumnMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_opt_String(self.section, serializer);
+ }
+
+@protected void sse_encode_impa_section(ImpaSection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.code, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_String(self.category, serializer);
+sse_encode_i_32(self.entryCount, serializer);
+ }
+
+@protected void sse_encode_instantiate_template_request(InstantiateTemplateRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.templateId, serializer);
+sse_encode_i_32(self.shipId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
+sse_encode_opt_box_autoadd_f_64(self.headcount, serializer);
+sse_encode_opt_box_autoadd_f_64(self.scaleFactor, serializer);
+sse_encode_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_invoice(Invoice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.invoiceNumber, serializer);
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.orderNumber, serializer);
+sse_encode_opt_box_autoadd_i_32(self.customerId, serializer);
+sse_encode_String(self.customerName, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_f_64(self.paidAmount, serializer);
+sse_encode_f_64(self.creditedAmount, serializer);
+sse_encode_f_64(self.balance, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.issueDate, serializer);
+sse_encode_String(self.dueDate, serializer);
+sse_encode_invoice_status(self.status, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_invoice_status(InvoiceStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_item_delivery(ItemDelivery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_opt_String(self.productName, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.deliveredAt, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_item_profit(ItemProfit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.totalCost, serializer);
+sse_encode_f_64(self.totalRevenue, serializer);
+sse_encode_f_64(self.grossProfit, serializer);
+sse_encode_opt_box_autoadd_f_64(self.marginPercent, serializer);
+sse_encode_f_64(self.landedCost, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minMarginPercent, serializer);
+sse_encode_bool(self.belowMarginFloor, serializer);
+ }
+
+@protected void sse_encode_item_weight(ItemWeight self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_f_64(self.weightKg, serializer);
+ }
+
+@protected void sse_encode_landed_cost(LandedCost self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.orderNumber, serializer);
+sse_encode_opt_box_autoadd_i_32(self.stockMovementId, serializer);
+sse_encode_landed_cost_type(self.costType, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_allocation_method(self.allocationMethod, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_list_landed_cost_allocation(self.allocations, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_landed_cost_allocation(LandedCostAllocation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.landedCostId, serializer);
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_opt_String(self.productName, serializer);
+sse_encode_f_64(self.basis, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_f_64(self.amountPerUnit, serializer);
+ }
+
+@protected void sse_encode_landed_cost_type(LandedCostType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_attachment(List<Attachment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_attachment(item, serializer); } }
+
+@protected void sse_encode_list_bonded_reconciliation_line(List<BondedReconciliationLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_bonded_reconciliation_line(item, serializer); } }
+
+@protected void sse_encode_list_calendar_event(List<CalendarEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_calendar_event(item, serializer); } }
+
+@protected void sse_encode_list_category(List<Category> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_category(item, serializer); } }
+
+@protected void sse_encode_list_compliance_issue(List<ComplianceIssue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_compliance_issue(item, serializer); } }
+
+@protected void sse_encode_list_create_customer_contact_request(List<CreateCustomerContactRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_create_customer_contact_request(item, serializer); } }
+
+@protected void sse_encode_list_create_price_agreement_line_request(List<CreatePriceAgreementLineRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_create_price_agreement_line_request(item, serializer); } }
+
+@protected void sse_encode_list_create_supplier_invoice_line_request(List<CreateSupplierInvoiceLineRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_create_supplier_invoice_line_request(item, serializer); } }
+
+@protected void sse_encode_list_credit_note(List<CreditNote> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_credit_note(item, serializer); } }
+
+@protected void sse_encode_list_currency_exposure(List<CurrencyExposure> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_currency_exposure(item, serializer); } }
+
+@protected void sse_encode_list_currency_rounding(List<CurrencyRounding> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_currency_rounding(item, serializer); } }
+
+@protected void sse_encode_list_customer(List<Customer> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_customer(item, serializer); } }
+
+@protected void sse_encode_list_customer_balance(List<CustomerBalance> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_customer_balance(item, serializer); } }
+
+@protected void sse_encode_list_customer_contact(List<CustomerContact> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_customer_contact(item, serializer); } }
+
+@protected void sse_encode_list_dangerous_goods_class_total(List<DangerousGoodsClassTotal> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_dangerous_goods_class_total(item, serializer); } }
+
+@protected void sse_encode_list_dangerous_goods_line(List<DangerousGoodsLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_dangerous_goods_line(item, serializer); } }
+
+@protected void sse_encode_list_delivery_line(List<DeliveryLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_delivery_line(item, serializer); } }
+
+@protected void sse_encode_list_delivery_note(List<DeliveryNote> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_delivery_note(item, serializer); } }
+
+@protected void sse_encode_list_delivery_note_attachment(List<DeliveryNoteAttachment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_delivery_note_attachment(item, serializer); } }
+
+@protected void sse_encode_list_delivery_note_line(List<DeliveryNoteLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_delivery_note_line(item, serializer); } }
+
+@protected void sse_encode_list_equivalence_group(List<EquivalenceGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_equivalence_group(item, serializer); } }
+
+@protected void sse_encode_list_impa_catalog_entry(List<ImpaCatalogEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_impa_catalog_entry(item, serializer); } }
+
+@protected void sse_encode_list_impa_code_issue(List<ImpaCodeIssue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_impa_code_issue(item, serializer); } }
+
+@protected void sse_encode_list_impa_section(List<ImpaSection> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_impa_section(item, serializer); } }
+
+@protected void sse_encode_list_invoice(List<Invoice> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_invoice(item, serializer); } }
+
+@protected void sse_encode_list_item_delivery(List<ItemDelivery> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_item_delivery(item, serializer); } }
+
+@protected void sse_encode_list_item_weight(List<ItemWeight> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_item_weight(item, serializer); } }
+
+@protected void sse_encode_list_landed_cost(List<LandedCost> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_landed_cost(item, serializer); } }
+
+@protected void sse_encode_list_landed_cost_allocation(List<LandedCostAllocation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_landed_cost_allocation(item, serializer); } }
+
+@protected void sse_encode_list_off_contract_line(List<OffContractLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_off_contract_line(item, serializer); } }
+
+@protected void sse_encode_list_order(List<Order> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_order(item, serializer); } }
+
+@protected void sse_encode_list_order_charge(List<OrderCharge> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_order_charge(item, serializer); } }
+
+@protected void sse_encode_list_order_item(List<OrderItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_order_item(item, serializer); } }
+
+@protected void sse_encode_list_order_link(List<OrderLink> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_order_link(item, serializer); } }
+
+@protected void sse_encode_list_order_profit_info(List<OrderProfitInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_order_profit_info(item, serializer); } }
+
+@protected void sse_encode_list_order_return(List<OrderReturn> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_order_return(item, serializer); } }
+
+@protected void sse_encode_list_order_return_line(List<OrderReturnLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_order_return_line(item, serializer); } }
+
+@protected void sse_encode_list_pack_size(List<PackSize> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_pack_size(item, serializer); } }
+
+@protected void sse_encode_list_payable_due(List<PayableDue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_payable_due(item, serializer); } }
+
+@protected void sse_encode_list_payment(List<Payment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_payment(item, serializer); } }
+
+@protected void sse_encode_list_payment_allocation(List<PaymentAllocation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_payment_allocation(item, serializer); } }
+
+@protected void sse_encode_list_payment_allocation_request(List<PaymentAllocationRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_payment_allocation_request(item, serializer); } }
+
+@protected void sse_encode_list_port(List<Port> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_port(item, serializer); } }
+
+@protected void sse_encode_list_price_agreement(List<PriceAgreement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_price_agreement(item, serializer); } }
+
+@protected void sse_encode_list_price_agreement_line(List<PriceAgreementLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_price_agreement_line(item, serializer); } }
+
+@protected void sse_encode_list_price_change(List<PriceChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_price_change(item, serializer); } }
+
+@protected void sse_encode_list_price_list_change(List<PriceListChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_price_list_change(item, serializer); } }
+
+@protected void sse_encode_list_price_rise_item(List<PriceRiseItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_price_rise_item(item, serializer); } }
+
+@protected void sse_encode_list_pricing_rule(List<PricingRule> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_pricing_rule(item, serializer); } }
+
+@protected void sse_encode_list_prim_i_32_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self is Int32List ? self : Int32List.fromList(self)); }
+
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt32List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_product_alternative(List<ProductAlternative> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_product_alternative(item, serializer); } }
+
+@protected void sse_encode_list_receivables_aging_row(List<ReceivablesAgingRow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_receivables_aging_row(item, serializer); } }
+
+@protected void sse_encode_list_repriced_item(List<RepricedItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_repriced_item(item, serializer); } }
+
+@protected void sse_encode_list_requisition_import_line(List<RequisitionImportLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_requisition_import_line(item, serializer); } }
+
+@protected void sse_encode_list_requisition_template(List<RequisitionTemplate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_requisition_template(item, serializer); } }
+
+@protected void sse_encode_list_requisition_template_line(List<RequisitionTemplateLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_requisition_template_line(item, serializer); } }
+
+@protected void sse_encode_list_requisition_template_line_request(List<RequisitionTemplateLineRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_requisition_template_line_request(item, serializer); } }
+
+@protected void sse_encode_list_return_line_request(List<ReturnLineRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_return_line_request(item, serializer); } }
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_search_scope(List<SearchScope> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_scope(item, serializer); } }
+
+@protected void sse_encode_list_ship(List<Ship> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ship(item, serializer); } }
+
+@protected void sse_encode_list_ship_visit(List<ShipVisit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ship_visit(item, serializer); } }
+
+@protected void sse_encode_list_stock(List<Stock> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_stock(item, serializer); } }
+
+@protected void sse_encode_list_stock_movement(List<StockMovement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_stock_movement(item, serializer); } }
+
+@protected void sse_encode_list_supplier(List<Supplier> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_supplier(item, serializer); } }
+
+@protected void sse_encode_list_supplier_invoice(List<SupplierInvoice> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_supplier_invoice(item, serializer); } }
+
+@protected void sse_encode_list_supplier_invoice_line(List<SupplierInvoiceLine> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_supplier_invoice_line(item, serializer); } }
+
+@protected void sse_encode_list_supplier_invoice_line_match(List<SupplierInvoiceLineMatch> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_supplier_invoice_line_match(item, serializer); } }
+
+@protected void sse_encode_list_supplier_payment(List<SupplierPayment> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_supplier_payment(item, serializer); } }
+
+@protected void sse_encode_list_supply_item(List<SupplyItem> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_supply_item(item, serializer); } }
+
+@protected void sse_encode_list_unit_of_measure(List<UnitOfMeasure> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_unit_of_measure(item, serializer); } }
+
+@protected void sse_encode_match_status(MatchStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_off_contract_line(OffContractLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_String(self.orderNumber, serializer);
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_i_32(self.customerId, serializer);
+sse_encode_String(self.customerName, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_String(self.impaCode, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.sellingPrice, serializer);
+sse_encode_f_64(self.contractPrice, serializer);
+sse_encode_f_64(self.revenueDifference, serializer);
+sse_encode_i_32(self.agreementId, serializer);
+sse_encode_String(self.agreementName, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_attachment(Attachment? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_attachment(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_category(Category? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_category(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_charge_billing(ChargeBilling? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_charge_billing(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_contract_price(ContractPrice? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_contract_price(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_credit_check(CreditCheck? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_credit_check(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_customer(Customer? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_customer(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_delivery_note(DeliveryNote? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_delivery_note(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_delivery_type(DeliveryType? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_delivery_type(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_equivalence_group(EquivalenceGroup? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_equivalence_group(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_impa_catalog_entry(ImpaCatalogEntry? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_impa_catalog_entry(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_invoice(Invoice? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_invoice(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_invoice_status(InvoiceStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_invoice_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_order_return(OrderReturn? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_order_return(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_order_status(OrderStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_order_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_order_with_items(OrderWithItems? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_order_with_items(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_payment(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_port(Port? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_port(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_price_agreement(PriceAgreement? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_price_agreement(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_price_list_match_kind(PriceListMatchKind? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_price_list_match_kind(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_product_alternative(ProductAlternative? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_product_alternative(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_requisition_template(RequisitionTemplate? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_requisition_template(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ship(Ship? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ship(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ship_visit(ShipVisit? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ship_visit(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_stock(Stock? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_stock(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_stock_with_movements(StockWithMovements? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_stock_with_movements(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_supplier(Supplier? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_supplier(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_supplier_invoice(SupplierInvoice? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_supplier_invoice(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_supply_item(SupplyItem? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_supply_item(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_visit_status(VisitStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_visit_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_i_32_strict(Int32List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_i_32_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_requisition_template_line_request(List<RequisitionTemplateLineRequest>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_requisition_template_line_request(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_order(Order self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.orderNumber, serializer);
+sse_encode_i_32(self.shipId, serializer);
+sse_encode_opt_String(self.shipName, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
+sse_encode_opt_String(self.shipVisitInfo, serializer);
+sse_encode_opt_box_autoadd_i_32(self.customerId, serializer);
+sse_encode_opt_String(self.customerName, serializer);
+sse_encode_opt_box_autoadd_i_32(self.paymentTermsDays, serializer);
+sse_encode_order_status(self.status, serializer);
+sse_encode_opt_String(self.deliveryPort, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_i_32(self.parentOrderId, serializer);
+sse_encode_opt_String(self.deliveryNoteWaiver, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_order_charge(OrderCharge self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_charge_type(self.chargeType, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_charge_billing(self.billing, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+sse_encode_f_64(self.costAmount, serializer);
+sse_encode_f_64(self.revenueAmount, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_order_compliance(OrderCompliance self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_String(self.orderNumber, serializer);
+sse_encode_i_32(self.regulatedLines, serializer);
+sse_encode_list_compliance_issue(self.issues, serializer);
+sse_encode_bool(self.isCompliant, serializer);
+ }
+
+@protected void sse_encode_order_export_filter(OrderExportFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_order_status(self.status, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.customerId, serializer);
+sse_encode_opt_String(self.dateFrom, serializer);
+sse_encode_opt_String(self.dateTo, serializer);
+ }
+
+@protected void sse_encode_order_item(OrderItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_f_64(self.buyingPrice, serializer);
+sse_encode_f_64(self.sellingPrice, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_delivery_type(self.deliveryType, serializer);
+sse_encode_opt_String(self.warehouseDeliveryDate, serializer);
+sse_encode_opt_String(self.shipDeliveryDate, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_f_64(self.deliveredQuantity, serializer);
+sse_encode_f_64(self.backOrderedQuantity, serializer);
+sse_encode_f_64(self.rolledOverQuantity, serializer);
+sse_encode_f_64(self.closedQuantity, serializer);
+sse_encode_opt_String(self.closedAt, serializer);
+sse_encode_opt_String(self.closeReason, serializer);
+sse_encode_fulfilment_status(self.fulfilment, serializer);
+sse_encode_opt_box_autoadd_i_32(self.backorderOfItemId, serializer);
+sse_encode_f_64(self.returnedQuantity, serializer);
+ }
+
+@protected void sse_encode_order_link(OrderLink self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_order_link_type(self.linkType, serializer);
+sse_encode_i_32(self.sourceOrderId, serializer);
+sse_encode_opt_String(self.sourceOrderNumber, serializer);
+sse_encode_i_32(self.targetOrderId, serializer);
+sse_encode_opt_String(self.targetOrderNumber, serializer);
+sse_encode_list_prim_i_32_strict(self.orderItemIds, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_order_link_type(OrderLinkType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_order_profit_info(OrderProfitInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_String(self.orderNumber, serializer);
+sse_encode_String(self.shipName, serializer);
+sse_encode_f_64(self.totalRevenue, serializer);
+sse_encode_f_64(self.totalCost, serializer);
+sse_encode_f_64(self.profit, serializer);
+sse_encode_f_64(self.marginPercent, serializer);
+sse_encode_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_order_return(OrderReturn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.returnNumber, serializer);
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.orderNumber, serializer);
+sse_encode_String(self.returnDate, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_f_64(self.creditAmount, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_i_32(self.creditNoteId, serializer);
+sse_encode_opt_String(self.creditNoteNumber, serializer);
+sse_encode_list_order_return_line(self.lines, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_order_return_line(OrderReturnLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_opt_String(self.productName, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_return_reason(self.reason, serializer);
+sse_encode_bool(self.restocked, serializer);
+sse_encode_opt_box_autoadd_i_32(self.stockMovementId, serializer);
+sse_encode_f_64(self.creditAmount, serializer);
+sse_encode_f_64(self.costAmount, serializer);
+ }
+
+@protected void sse_encode_order_status(OrderStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_order_totals(OrderTotals self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.itemCount, serializer);
+sse_encode_i_32(self.chargeCount, serializer);
+sse_encode_f_64(self.totalCost, serializer);
+sse_encode_f_64(self.totalRevenue, serializer);
+sse_encode_f_64(self.chargesCost, serializer);
+sse_encode_f_64(self.chargesRevenue, serializer);
+sse_encode_f_64(self.landedCost, serializer);
+sse_encode_f_64(self.returnsRevenue, serializer);
+sse_encode_f_64(self.returnsCost, serializer);
+sse_encode_f_64(self.grossProfit, serializer);
+sse_encode_opt_box_autoadd_f_64(self.marginPercent, serializer);
+sse_encode_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_order_with_items(OrderWithItems self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_order(self.order, serializer);
+sse_encode_list_order_item(self.items, serializer);
+sse_encode_order_totals(self.totals, serializer);
+sse_encode_opt_box_autoadd_credit_check(self.creditCheck, serializer);
+ }
+
+@protected void sse_encode_pack_size(PackSize self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_String(self.packUnit, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.contentUnit, serializer);
+ }
+
+@protected void sse_encode_payable_due(PayableDue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplierInvoiceId, serializer);
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_String(self.invoiceNumber, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.dueDate, serializer);
+sse_encode_f_64(self.balance, serializer);
+sse_encode_i_64(self.daysUntilDue, serializer);
+ }
+
+@protected void sse_encode_payment(Payment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_opt_box_autoadd_i_32(self.customerId, serializer);
+sse_encode_String(self.customerName, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.allocatedAmount, serializer);
+sse_encode_f_64(self.unallocatedAmount, serializer);
+sse_encode_String(self.paymentDate, serializer);
+sse_encode_payment_method(self.method, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_payment_allocation(self.allocations, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_payment_allocation(PaymentAllocation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.paymentId, serializer);
+sse_encode_i_32(self.invoiceId, serializer);
+sse_encode_opt_String(self.invoiceNumber, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_f_64(self.exchangeRate, serializer);
+sse_encode_f_64(self.invoiceAmount, serializer);
+ }
+
+@protected void sse_encode_payment_allocation_request(PaymentAllocationRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.invoiceId, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_opt_box_autoadd_f_64(self.exchangeRate, serializer);
+ }
+
+@protected void sse_encode_payment_method(PaymentMethod self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pdf_document_kind(PdfDocumentKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pdf_render_request(PdfRenderRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_pdf_document_kind(self.kind, serializer);
+sse_encode_opt_box_autoadd_i_32(self.documentId, serializer);
+sse_encode_f_64(self.taxRatePercent, serializer);
+sse_encode_String(self.outputPath, serializer);
+ }
+
+@protected void sse_encode_pdf_render_result(PdfRenderResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.filePath, serializer);
+sse_encode_i_32(self.pageCount, serializer);
+ }
+
+@protected void sse_encode_pharmacist_sign_off_request(PharmacistSignOffRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.pharmacistName, serializer);
+sse_encode_String(self.licenseNumber, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_port(Port self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.country, serializer);
+sse_encode_opt_String(self.city, serializer);
+sse_encode_String(self.timezone, serializer);
+sse_encode_opt_box_autoadd_f_64(self.latitude, serializer);
+sse_encode_opt_box_autoadd_f_64(self.longitude, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_bool(self.isInternational, serializer);
+sse_encode_bool(self.isActive, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_price_agreement(PriceAgreement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.customerId, serializer);
+sse_encode_opt_String(self.customerName, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.validFrom, serializer);
+sse_encode_String(self.validTo, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_bool(self.isActive, serializer);
+sse_encode_list_price_agreement_line(self.lines, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_price_agreement_line(PriceAgreementLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.agreementId, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.impaGroup, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_f_64(self.fixedPrice, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+ }
+
+@protected void sse_encode_price_change(PriceChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_String(self.effectiveDate, serializer);
+sse_encode_opt_box_autoadd_f_64(self.oldPrice, serializer);
+sse_encode_opt_String(self.oldCurrency, serializer);
+sse_encode_f_64(self.newPrice, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_f_64(self.changePercent, serializer);
+sse_encode_price_change_source(self.source, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_price_change_source(PriceChangeSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_price_list_change(PriceListChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.rowNumber, serializer);
+sse_encode_price_list_change_kind(self.kind, serializer);
+sse_encode_opt_box_autoadd_price_list_match_kind(self.matchedBy, serializer);
+sse_encode_opt_box_autoadd_i_32(self.supplyItemId, serializer);
+sse_encode_opt_String(self.supplierSku, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_opt_box_autoadd_f_64(self.oldPrice, serializer);
+sse_encode_opt_String(self.oldCurrency, serializer);
+sse_encode_opt_box_autoadd_f_64(self.newPrice, serializer);
+sse_encode_opt_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_f_64(self.changePercent, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_price_list_change_kind(PriceListChangeKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_price_list_column_mapping(PriceListColumnMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.supplierSku, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_String(self.price, serializer);
+sse_encode_opt_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_price_list_import_request(PriceListImportRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_String(self.filePath, serializer);
+sse_encode_price_list_column_mapping(self.mapping, serializer);
+sse_encode_i_32(self.headerRow, serializer);
+sse_encode_opt_String(self.sheetName, serializer);
+sse_encode_opt_String(self.delimiter, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_i_32(self.defaultCategoryId, serializer);
+sse_encode_opt_String(self.effectiveDate, serializer);
+sse_encode_bool(self.discontinueMissing, serializer);
+ }
+
+@protected void sse_encode_price_list_import_result(PriceListImportResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.created, serializer);
+sse_encode_i_32(self.updated, serializer);
+sse_encode_i_32(self.unchanged, serializer);
+sse_encode_i_32(self.discontinued, serializer);
+ }
+
+@protected void sse_encode_price_list_match_kind(PriceListMatchKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_price_list_preview(PriceListPreview self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_String(self.supplierName, serializer);
+sse_encode_list_price_list_change(self.lines, serializer);
+sse_encode_i_32(self.newItems, serializer);
+sse_encode_i_32(self.priceUp, serializer);
+sse_encode_i_32(self.priceDown, serializer);
+sse_encode_i_32(self.currencyChanged, serializer);
+sse_encode_i_32(self.unchanged, serializer);
+sse_encode_i_32(self.discontinued, serializer);
+sse_encode_i_32(self.errors, serializer);
+ }
+
+@protected void sse_encode_price_proposal(PriceProposal self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.buyingPrice, serializer);
+sse_encode_f_64(self.sellingPrice, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_price_source(self.source, serializer);
+sse_encode_opt_box_autoadd_i_32(self.ruleId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.agreementId, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minMarginPercent, serializer);
+sse_encode_opt_box_autoadd_f_64(self.marginPercent, serializer);
+sse_encode_bool(self.floorApplied, serializer);
+sse_encode_bool(self.belowMarginFloor, serializer);
+sse_encode_f_64(self.roundingIncrement, serializer);
+sse_encode_String(self.explanation, serializer);
+ }
+
+@protected void sse_encode_price_rise_item(PriceRiseItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_String(self.supplyItemName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.startPrice, serializer);
+sse_encode_f_64(self.endPrice, serializer);
+sse_encode_f_64(self.changePercent, serializer);
+sse_encode_i_32(self.changeCount, serializer);
+sse_encode_String(self.lastChangeDate, serializer);
+ }
+
+@protected void sse_encode_price_source(PriceSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pricing_rule(PricingRule self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_String(self.category, serializer);
+sse_encode_opt_box_autoadd_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_opt_box_autoadd_i_32(self.customerId, serializer);
+sse_encode_opt_String(self.customerName, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minMarginPercent, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_bool(self.isActive, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_product_alternative(ProductAlternative self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.supplierSku, serializer);
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_f_64(self.unitPrice, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_bool(self.isAvailable, serializer);
+sse_encode_f_64(self.stockQuantity, serializer);
+sse_encode_opt_box_autoadd_i_32(self.leadTimeDays, serializer);
+sse_encode_alternative_link(self.linkedBy, serializer);
+ }
+
+@protected void sse_encode_profit_summary(ProfitSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.totalOrders, serializer);
+sse_encode_f_64(self.totalRevenue, serializer);
+sse_encode_f_64(self.totalCost, serializer);
+sse_encode_f_64(self.totalProfit, serializer);
+sse_encode_opt_box_autoadd_f_64(self.averageMargin, serializer);
+sse_encode_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_receivables_aging_row(ReceivablesAgingRow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.customerId, serializer);
+sse_encode_String(self.customerName, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_f_64(self.days030, serializer);
+sse_encode_f_64(self.days3160, serializer);
+sse_encode_f_64(self.days6190, serializer);
+sse_encode_f_64(self.daysOver90, serializer);
+sse_encode_f_64(self.total, serializer);
+sse_encode_f_64(self.credit, serializer);
+ }
+
+@protected void sse_encode_record_delivery_request(RecordDeliveryRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.deliveredAt, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_delivery_line(self.lines, serializer);
+ }
+
+@protected void sse_encode_reprice_result(RepriceResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_i_32(self.updatedCount, serializer);
+sse_encode_i_32(self.unchangedCount, serializer);
+sse_encode_i_32(self.skippedCount, serializer);
+sse_encode_list_repriced_item(self.items, serializer);
+ }
+
+@protected void sse_encode_repriced_item(RepricedItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_f_64(self.oldSellingPrice, serializer);
+sse_encode_f_64(self.newSellingPrice, serializer);
+sse_encode_price_proposal(self.proposal, serializer);
+ }
+
+@protected void sse_encode_requisition_column_mapping(RequisitionColumnMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_String(self.quantity, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_requisition_import_line(RequisitionImportLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.rowNumber, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_requisition_match_kind(self.matchKind, serializer);
+sse_encode_opt_box_autoadd_i_32(self.supplyItemId, serializer);
+sse_encode_opt_String(self.supplyItemName, serializer);
+sse_encode_f_64(self.matchScore, serializer);
+sse_encode_bool(self.include, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_requisition_import_preview(RequisitionImportPreview self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_String(self.orderNumber, serializer);
+sse_encode_list_requisition_import_line(self.lines, serializer);
+sse_encode_i_32(self.impaMatched, serializer);
+sse_encode_i_32(self.fuzzyMatched, serializer);
+sse_encode_i_32(self.unmatched, serializer);
+sse_encode_i_32(self.errors, serializer);
+ }
+
+@protected void sse_encode_requisition_import_request(RequisitionImportRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderId, serializer);
+sse_encode_String(self.filePath, serializer);
+sse_encode_requisition_column_mapping(self.mapping, serializer);
+sse_encode_i_32(self.headerRow, serializer);
+sse_encode_opt_String(self.sheetName, serializer);
+sse_encode_opt_String(self.delimiter, serializer);
+ }
+
+@protected void sse_encode_requisition_match_kind(RequisitionMatchKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_requisition_template(RequisitionTemplate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipId, serializer);
+sse_encode_opt_String(self.shipName, serializer);
+sse_encode_opt_String(self.shipType, serializer);
+sse_encode_opt_box_autoadd_f_64(self.baseHeadcount, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_requisition_template_line(self.lines, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_requisition_template_line(RequisitionTemplateLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.templateId, serializer);
+sse_encode_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_requisition_template_line_request(RequisitionTemplateLineRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_return_line_request(ReturnLineRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.orderItemId, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_return_reason(self.reason, serializer);
+sse_encode_bool(self.restock, serializer);
+ }
+
+@protected void sse_encode_return_reason(ReturnReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_scope(self.scope, serializer);
+sse_encode_i_32(self.entityId, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_opt_String(self.subtitle, serializer);
+sse_encode_String(self.snippet, serializer);
+sse_encode_f_64(self.score, serializer);
+ }
+
+@protected void sse_encode_search_scope(SearchScope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ship(Ship self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.imoNumber, serializer);
+sse_encode_String(self.flag, serializer);
+sse_encode_opt_String(self.shipType, serializer);
+sse_encode_opt_box_autoadd_f_64(self.grossTonnage, serializer);
+sse_encode_opt_String(self.owner, serializer);
+sse_encode_opt_box_autoadd_i_32(self.ownerId, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_ship_visit(ShipVisit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.shipId, serializer);
+sse_encode_opt_String(self.shipName, serializer);
+sse_encode_i_32(self.portId, serializer);
+sse_encode_opt_String(self.portName, serializer);
+sse_encode_String(self.eta, serializer);
+sse_encode_String(self.etd, serializer);
+sse_encode_opt_String(self.ata, serializer);
+sse_encode_opt_String(self.atd, serializer);
+sse_encode_visit_status(self.status, serializer);
+sse_encode_opt_String(self.agentInfo, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_stock(Stock self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.supplyItemId, serializer);
+sse_encode_opt_String(self.supplyItemName, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_opt_String(self.warehouseLocation, serializer);
+sse_encode_warehouse_type(self.warehouseType, serializer);
+sse_encode_f_64(self.minimumQuantity, serializer);
+sse_encode_String(self.lastUpdated, serializer);
+ }
+
+@protected void sse_encode_stock_movement(StockMovement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.stockId, serializer);
+sse_encode_opt_String(self.supplyItemName, serializer);
+sse_encode_stock_movement_type(self.movementType, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_opt_String(self.referenceType, serializer);
+sse_encode_opt_box_autoadd_i_32(self.referenceId, serializer);
+sse_encode_opt_String(self.referenceInfo, serializer);
+sse_encode_opt_box_autoadd_i_32(self.orderItemId, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_opt_box_autoadd_f_64(self.enteredQuantity, serializer);
+sse_encode_opt_String(self.enteredUnit, serializer);
+sse_encode_opt_String(self.customsDeclarationNumber, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_stock_movement_type(StockMovementType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_stock_summary(StockSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.totalItems, serializer);
+sse_encode_i_32(self.lowStockCount, serializer);
+sse_encode_i_32(self.outOfStockCount, serializer);
+sse_encode_f_64(self.totalValue, serializer);
+sse_encode_f_64(self.landedCostValue, serializer);
+sse_encode_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_stock_with_movements(StockWithMovements self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stock(self.stock, serializer);
+sse_encode_list_stock_movement(self.movements, serializer);
+ }
+
+@protected void sse_encode_supplier(Supplier self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.contactPerson, serializer);
+sse_encode_opt_String(self.email, serializer);
+sse_encode_opt_String(self.phone, serializer);
+sse_encode_opt_String(self.address, serializer);
+sse_encode_opt_String(self.country, serializer);
+sse_encode_String(self.category, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_String(self.categoryName, serializer);
+sse_encode_opt_box_autoadd_i_32(self.leadTimeDays, serializer);
+sse_encode_bool(self.isActive, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_supplier_invoice(SupplierInvoice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_String(self.invoiceNumber, serializer);
+sse_encode_opt_box_autoadd_i_32(self.orderId, serializer);
+sse_encode_opt_String(self.orderNumber, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_f_64(self.paidAmount, serializer);
+sse_encode_f_64(self.balance, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.issueDate, serializer);
+sse_encode_String(self.dueDate, serializer);
+sse_encode_invoice_status(self.status, serializer);
+sse_encode_opt_String(self.approvedAt, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_list_supplier_invoice_line(self.lines, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_supplier_invoice_line(SupplierInvoiceLine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.supplierInvoiceId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.orderItemId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.supplyItemId, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_f_64(self.quantity, serializer);
+sse_encode_f_64(self.unitPrice, serializer);
+sse_encode_f_64(self.lineTotal, serializer);
+ }
+
+@protected void sse_encode_supplier_invoice_line_match(SupplierInvoiceLineMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.lineId, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_opt_box_autoadd_i_32(self.orderItemId, serializer);
+sse_encode_opt_box_autoadd_f_64(self.orderedQuantity, serializer);
+sse_encode_opt_box_autoadd_f_64(self.orderedPrice, serializer);
+sse_encode_opt_box_autoadd_f_64(self.receivedQuantity, serializer);
+sse_encode_f_64(self.invoicedQuantity, serializer);
+sse_encode_f_64(self.invoicedPrice, serializer);
+sse_encode_f_64(self.priceVariance, serializer);
+sse_encode_match_status(self.status, serializer);
+ }
+
+@protected void sse_encode_supplier_invoice_match(SupplierInvoiceMatch self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.supplierInvoiceId, serializer);
+sse_encode_list_supplier_invoice_line_match(self.lines, serializer);
+sse_encode_bool(self.isFullyMatched, serializer);
+sse_encode_f_64(self.totalPriceVariance, serializer);
+ }
+
+@protected void sse_encode_supplier_payment(SupplierPayment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.supplierInvoiceId, serializer);
+sse_encode_opt_String(self.invoiceNumber, serializer);
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_f_64(self.amount, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_String(self.paymentDate, serializer);
+sse_encode_payment_method(self.method, serializer);
+sse_encode_opt_String(self.reference, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_String(self.createdAt, serializer);
+ }
+
+@protected void sse_encode_supply_item(SupplyItem self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.supplierName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.supplierSku, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_String(self.category, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_String(self.categoryName, serializer);
+sse_encode_String(self.unit, serializer);
+sse_encode_f_64(self.unitPrice, serializer);
+sse_encode_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_i_32(self.minimumOrderQuantity, serializer);
+sse_encode_bool(self.isAvailable, serializer);
+sse_encode_opt_String(self.unNumber, serializer);
+sse_encode_opt_String(self.hazardClass, serializer);
+sse_encode_bool(self.sdsRequired, serializer);
+sse_encode_bool(self.prescriptionOnly, serializer);
+sse_encode_bool(self.isBonded, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sdsAttachmentId, serializer);
+sse_encode_String(self.createdAt, serializer);
+sse_encode_String(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_unit_dimension(UnitDimension self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_unit_of_measure(UnitOfMeasure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.id, serializer);
+sse_encode_String(self.code, serializer);
+sse_encode_String(self.nameTr, serializer);
+sse_encode_opt_String(self.nameEn, serializer);
+sse_encode_unit_dimension(self.dimension, serializer);
+sse_encode_f_64(self.factor, serializer);
+sse_encode_list_String(self.aliases, serializer);
+sse_encode_bool(self.isActive, serializer);
+ }
+
+@protected void sse_encode_update_category_request(UpdateCategoryRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.code, serializer);
+sse_encode_opt_String(self.nameTr, serializer);
+sse_encode_opt_String(self.nameEn, serializer);
+sse_encode_opt_box_autoadd_i_32(self.sortOrder, serializer);
+sse_encode_opt_box_autoadd_bool(self.isActive, serializer);
+ }
+
+@protected void sse_encode_update_company_profile_request(UpdateCompanyProfileRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.address, serializer);
+sse_encode_opt_String(self.phone, serializer);
+sse_encode_opt_String(self.email, serializer);
+sse_encode_opt_String(self.website, serializer);
+sse_encode_opt_String(self.taxOffice, serializer);
+sse_encode_opt_String(self.taxNumber, serializer);
+sse_encode_opt_String(self.bankName, serializer);
+sse_encode_opt_String(self.iban, serializer);
+sse_encode_opt_String(self.swift, serializer);
+ }
+
+@protected void sse_encode_update_customer_request(UpdateCustomerRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.legalName, serializer);
+sse_encode_opt_String(self.taxNumber, serializer);
+sse_encode_opt_String(self.taxOffice, serializer);
+sse_encode_opt_String(self.billingAddress, serializer);
+sse_encode_opt_String(self.country, serializer);
+sse_encode_opt_box_autoadd_i_32(self.paymentTermsDays, serializer);
+sse_encode_opt_box_autoadd_f_64(self.creditLimit, serializer);
+sse_encode_bool(self.clearCreditLimit, serializer);
+sse_encode_opt_String(self.defaultCurrency, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_update_order_charge_request(UpdateOrderChargeRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_f_64(self.amount, serializer);
+sse_encode_opt_box_autoadd_charge_billing(self.billing, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_update_order_item_request(UpdateOrderItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.productName, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_f_64(self.quantity, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_opt_box_autoadd_f_64(self.buyingPrice, serializer);
+sse_encode_opt_box_autoadd_f_64(self.sellingPrice, serializer);
+sse_encode_opt_box_autoadd_delivery_type(self.deliveryType, serializer);
+sse_encode_opt_String(self.warehouseDeliveryDate, serializer);
+sse_encode_opt_String(self.shipDeliveryDate, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_update_order_request(UpdateOrderRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.shipId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.shipVisitId, serializer);
+sse_encode_opt_String(self.deliveryPort, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_opt_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_update_port_request(UpdatePortRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.country, serializer);
+sse_encode_opt_String(self.city, serializer);
+sse_encode_opt_String(self.timezone, serializer);
+sse_encode_opt_box_autoadd_f_64(self.latitude, serializer);
+sse_encode_opt_box_autoadd_f_64(self.longitude, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_opt_box_autoadd_bool(self.isInternational, serializer);
+sse_encode_opt_box_autoadd_bool(self.isActive, serializer);
+ }
+
+@protected void sse_encode_update_price_agreement_request(UpdatePriceAgreementRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.validFrom, serializer);
+sse_encode_opt_String(self.validTo, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_opt_box_autoadd_bool(self.isActive, serializer);
+ }
+
+@protected void sse_encode_update_pricing_rule_request(UpdatePricingRuleRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_box_autoadd_f_64(self.markupPercent, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minMarginPercent, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_opt_box_autoadd_bool(self.isActive, serializer);
+ }
+
+@protected void sse_encode_update_requisition_template_request(UpdateRequisitionTemplateRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_box_autoadd_f_64(self.baseHeadcount, serializer);
+sse_encode_opt_String(self.notes, serializer);
+sse_encode_opt_list_requisition_template_line_request(self.lines, serializer);
+ }
+
+@protected void sse_encode_update_ship_request(UpdateShipRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.imoNumber, serializer);
+sse_encode_opt_String(self.flag, serializer);
+sse_encode_opt_String(self.shipType, serializer);
+sse_encode_opt_box_autoadd_f_64(self.grossTonnage, serializer);
+sse_encode_opt_String(self.owner, serializer);
+sse_encode_opt_box_autoadd_i_32(self.ownerId, serializer);
+ }
+
+@protected void sse_encode_update_ship_visit_request(UpdateShipVisitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.portId, serializer);
+sse_encode_opt_String(self.eta, serializer);
+sse_encode_opt_String(self.etd, serializer);
+sse_encode_opt_String(self.ata, serializer);
+sse_encode_opt_String(self.atd, serializer);
+sse_encode_opt_box_autoadd_visit_status(self.status, serializer);
+sse_encode_opt_String(self.agentInfo, serializer);
+sse_encode_opt_String(self.notes, serializer);
+ }
+
+@protected void sse_encode_update_stock_request(UpdateStockRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.quantity, serializer);
+sse_encode_opt_String(self.warehouseLocation, serializer);
+sse_encode_opt_box_autoadd_f_64(self.minimumQuantity, serializer);
+ }
+
+@protected void sse_encode_update_supplier_request(UpdateSupplierRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.contactPerson, serializer);
+sse_encode_opt_String(self.email, serializer);
+sse_encode_opt_String(self.phone, serializer);
+sse_encode_opt_String(self.address, serializer);
+sse_encode_opt_String(self.country, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_box_autoadd_i_32(self.leadTimeDays, serializer);
+ }
+
+@protected void sse_encode_update_supply_item_request(UpdateSupplyItemRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_i_32(self.supplierId, serializer);
+sse_encode_opt_String(self.impaCode, serializer);
+sse_encode_opt_String(self.supplierSku, serializer);
+sse_encode_opt_String(self.name, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_opt_box_autoadd_i_32(self.categoryId, serializer);
+sse_encode_opt_String(self.unit, serializer);
+sse_encode_opt_box_autoadd_f_64(self.unitPrice, serializer);
+sse_encode_opt_String(self.currency, serializer);
+sse_encode_opt_box_autoadd_i_32(self.minimumOrderQuantity, serializer);
+sse_encode_opt_box_autoadd_bool(self.isAvailable, serializer);
+sse_encode_opt_String(self.priceEffectiveDate, serializer);
+sse_encode_opt_String(self.unNumber, serializer);
+sse_encode_opt_String(self.hazardClass, serializer);
+sse_encode_opt_box_autoadd_bool(self.sdsRequired, serializer);
+sse_encode_opt_box_autoadd_bool(self.prescriptionOnly, serializer);
+sse_encode_opt_box_autoadd_bool(self.isBonded, serializer);
+ }
+
+@protected void sse_encode_update_unit_request(UpdateUnitRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.nameTr, serializer);
+sse_encode_opt_String(self.nameEn, serializer);
+sse_encode_opt_box_autoadd_f_64(self.factor, serializer);
+sse_encode_opt_list_String(self.aliases, serializer);
+sse_encode_opt_box_autoadd_bool(self.isActive, serializer);
+ }
+
+@protected void sse_encode_visit_status(VisitStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_warehouse_type(WarehouseType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+                }
+                
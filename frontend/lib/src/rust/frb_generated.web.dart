@@ -29,16 +29,70 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
                   @protected String dco_decode_String(dynamic raw);
 
+@protected AllocationMethod dco_decode_allocation_method(dynamic raw);
+
+@protected AlternativeLink dco_decode_alternative_link(dynamic raw);
+
+@protected Attachment dco_decode_attachment(dynamic raw);
+
+@protected AttachmentEntity dco_decode_attachment_entity(dynamic raw);
+
+@protected BondedEligibility dco_decode_bonded_eligibility(dynamic raw);
+
+@protected BondedReconciliation dco_decode_bonded_reconciliation(dynamic raw);
+
+@protected BondedReconciliationLine dco_decode_bonded_reconciliation_line(dynamic raw);
+
 @protected bool dco_decode_bool(dynamic raw);
 
+@protected Attachment dco_decode_box_autoadd_attachment(dynamic raw);
+
 @protected bool dco_decode_box_autoadd_bool(dynamic raw);
 
+@protected Category dco_decode_box_autoadd_category(dynamic raw);
+
+@protected ChargeBilling dco_decode_box_autoadd_charge_billing(dynamic raw);
+
+@protected CompleteDeliveryNoteRequest dco_decode_box_autoadd_complete_delivery_note_request(dynamic raw);
+
+@protected ContractPrice dco_decode_box_autoadd_contract_price(dynamic raw);
+
+@protected CreateAttachmentRequest dco_decode_box_autoadd_create_attachment_request(dynamic raw);
+
+@protected CreateCategoryRequest dco_decode_box_autoadd_create_category_request(dynamic raw);
+
+@protected CreateCustomerContactRequest dco_decode_box_autoadd_create_customer_contact_request(dynamic raw);
+
+@protected CreateCustomerRequest dco_decode_box_autoadd_create_customer_request(dynamic raw);
+
+@protected CreateDeliveryNoteRequest dco_decode_box_autoadd_create_delivery_note_request(dynamic raw);
+
+@protected CreateEquivalenceGroupRequest dco_decode_box_autoadd_create_equivalence_group_request(dynamic raw);
+
+@protected CreateInvoiceRequest dco_decode_box_autoadd_create_invoice_request(dynamic raw);
+
+@protected CreateLandedCostRequest dco_decode_box_autoadd_create_landed_cost_request(dynamic raw);
+
+@protected CreateOrderChargeRequest dco_decode_box_autoadd_create_order_charge_request(dynamic raw);
+
 @protected CreateOrderItemRequest dco_decode_box_autoadd_create_order_item_request(dynamic raw);
 
 @protected CreateOrderRequest dco_decode_box_autoadd_create_order_request(dynamic raw);
 
+@protected CreatePaymentRequest dco_decode_box_autoadd_create_payment_request(dynamic raw);
+
 @protected CreatePortRequest dco_decode_box_autoadd_create_port_request(dynamic raw);
 
+@protected CreatePriceAgreementLineRequest dco_decode_box_autoadd_create_price_agreement_line_request(dynamic raw);
+
+@protected CreatePriceAgreementRequest dco_decode_box_autoadd_create_price_agreement_request(dynamic raw);
+
+@protected CreatePricingRuleRequest dco_decode_box_autoadd_create_pricing_rule_request(dynamic raw);
+
+@protected CreateRequisitionTemplateRequest dco_decode_box_autoadd_create_requisition_template_request(dynamic raw);
+
+@protected CreateReturnRequest dco_decode_box_autoadd_create_return_request(dynamic raw);
+
 @protected CreateShipRequest dco_decode_box_autoadd_create_ship_request(dynamic raw);
 
 @protected CreateShipVisitRequest dco_decode_box_autoadd_create_ship_visit_request(dynamic raw);
@@ -47,22 +101,70 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected CreateStockRequest dco_decode_box_autoadd_create_stock_request(dynamic raw);
 
+@protected CreateSupplierInvoiceRequest dco_decode_box_autoadd_create_supplier_invoice_request(dynamic raw);
+
+@protected CreateSupplierPaymentRequest dco_decode_box_autoadd_create_supplier_payment_request(dynamic raw);
+
 @protected CreateSupplierRequest dco_decode_box_autoadd_create_supplier_request(dynamic raw);
 
 @protected CreateSupplyItemRequest dco_decode_box_autoadd_create_supply_item_request(dynamic raw);
 
+@protected CreateUnitRequest dco_decode_box_autoadd_create_unit_request(dynamic raw);
+
+@protected CreditCheck dco_decode_box_autoadd_credit_check(dynamic raw);
+
+@protected Customer dco_decode_box_autoadd_customer(dynamic raw);
+
+@protected DeliveryNote dco_decode_box_autoadd_delivery_note(dynamic raw);
+
 @protected DeliveryType dco_decode_box_autoadd_delivery_type(dynamic raw);
 
+@protected EquivalenceGroup dco_decode_box_autoadd_equivalence_group(dynamic raw);
+
 @protected double dco_decode_box_autoadd_f_64(dynamic raw);
 
 @protected int dco_decode_box_autoadd_i_32(dynamic raw);
 
+@protected ImpaCatalogEntry dco_decode_box_autoadd_impa_catalog_entry(dynamic raw);
+
+@protected ImpaCatalogImportRequest dco_decode_box_autoadd_impa_catalog_import_request(dynamic raw);
+
+@protected InstantiateTemplateRequest dco_decode_box_autoadd_instantiate_template_request(dynamic raw);
+
+@protected Invoice dco_decode_box_autoadd_invoice(dynamic raw);
+
+@protected InvoiceStatus dco_decode_box_autoadd_invoice_status(dynamic raw);
+
+@protected OrderExportFilter dco_decode_box_autoadd_order_export_filter(dynamic raw);
+
+@protected OrderReturn dco_decode_box_autoadd_order_return(dynamic raw);
+
 @protected OrderStatus dco_decode_box_autoadd_order_status(dynamic raw);
 
 @protected OrderWithItems dco_decode_box_autoadd_order_with_items(dynamic raw);
 
+@protected Payment dco_decode_box_autoadd_payment(dynamic raw);
+
+@protected PdfRenderRequest dco_decode_box_autoadd_pdf_render_request(dynamic raw);
+
+@protected PharmacistSignOffRequest dco_decode_box_autoadd_pharmacist_sign_off_request(dynamic raw);
+
 @protected Port dco_decode_box_autoadd_port(dynamic raw);
 
+@protected PriceAgreement dco_decode_box_autoadd_price_agreement(dynamic raw);
+
+@protected PriceListImportRequest dco_decode_box_autoadd_price_list_import_request(dynamic raw);
+
+@protected PriceListMatchKind dco_decode_box_autoadd_price_list_match_kind(dynamic raw);
+
+@protected ProductAlternative dco_decode_box_autoadd_product_alternative(dynamic raw);
+
+@protected RecordDeliveryRequest dco_decode_box_autoadd_record_delivery_request(dynamic raw);
+
+@protected RequisitionImportRequest dco_decode_box_autoadd_requisition_import_request(dynamic raw);
+
+@protected RequisitionTemplate dco_decode_box_autoadd_requisition_template(dynamic raw);
+
 @protected Ship dco_decode_box_autoadd_ship(dynamic raw);
 
 @protected ShipVisit dco_decode_box_autoadd_ship_visit(dynamic raw);
@@ -73,14 +175,30 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected Supplier dco_decode_box_autoadd_supplier(dynamic raw);
 
+@protected SupplierInvoice dco_decode_box_autoadd_supplier_invoice(dynamic raw);
+
 @protected SupplyItem dco_decode_box_autoadd_supply_item(dynamic raw);
 
+@protected UpdateCategoryRequest dco_decode_box_autoadd_update_category_request(dynamic raw);
+
+@protected UpdateCompanyProfileRequest dco_decode_box_autoadd_update_company_profile_request(dynamic raw);
+
+@protected UpdateCustomerRequest dco_decode_box_autoadd_update_customer_request(dynamic raw);
+
+@protected UpdateOrderChargeRequest dco_decode_box_autoadd_update_order_charge_request(dynamic raw);
+
 @protected UpdateOrderItemRequest dco_decode_box_autoadd_update_order_item_request(dynamic raw);
 
 @protected UpdateOrderRequest dco_decode_box_autoadd_update_order_request(dynamic raw);
 
 @protected UpdatePortRequest dco_decode_box_autoadd_update_port_request(dynamic raw);
 
+@protected UpdatePriceAgreementRequest dco_decode_box_autoadd_update_price_agreement_request(dynamic raw);
+
+@protected UpdatePricingRuleRequest dco_decode_box_autoadd_update_pricing_rule_request(dynamic raw);
+
+@protected UpdateRequisitionTemplateRequest dco_decode_box_autoadd_update_requisition_template_request(dynamic raw);
+
 @protected UpdateShipRequest dco_decode_box_autoadd_update_ship_request(dynamic raw);
 
 @protected UpdateShipVisitRequest dco_decode_box_autoadd_update_ship_visit_request(dynamic raw);
@@ -91,6 +209,8 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected UpdateSupplyItemRequest dco_decode_box_autoadd_update_supply_item_request(dynamic raw);
 
+@protected UpdateUnitRequest dco_decode_box_autoadd_update_unit_request(dynamic raw);
+
 @protected VisitStatus dco_decode_box_autoadd_visit_status(dynamic raw);
 
 @protected CalendarData dco_decode_calendar_data(dynamic raw);
@@ -99,12 +219,58 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected CalendarEventType dco_decode_calendar_event_type(dynamic raw);
 
+@protected Category dco_decode_category(dynamic raw);
+
+@protected ChargeBilling dco_decode_charge_billing(dynamic raw);
+
+@protected ChargeType dco_decode_charge_type(dynamic raw);
+
+@protected CompanyProfile dco_decode_company_profile(dynamic raw);
+
+@protected CompleteDeliveryNoteRequest dco_decode_complete_delivery_note_request(dynamic raw);
+
+@protected ComplianceIssue dco_decode_compliance_issue(dynamic raw);
+
+@protected ComplianceIssueKind dco_decode_compliance_issue_kind(dynamic raw);
+
+@protected ContractPrice dco_decode_contract_price(dynamic raw);
+
+@protected CreateAttachmentRequest dco_decode_create_attachment_request(dynamic raw);
+
+@protected CreateCategoryRequest dco_decode_create_category_request(dynamic raw);
+
+@protected CreateCustomerContactRequest dco_decode_create_customer_contact_request(dynamic raw);
+
+@protected CreateCustomerRequest dco_decode_create_customer_request(dynamic raw);
+
+@protected CreateDeliveryNoteRequest dco_decode_create_delivery_note_request(dynamic raw);
+
+@protected CreateEquivalenceGroupRequest dco_decode_create_equivalence_group_request(dynamic raw);
+
+@protected CreateInvoiceRequest dco_decode_create_invoice_request(dynamic raw);
+
+@protected CreateLandedCostRequest dco_decode_create_landed_cost_request(dynamic raw);
+
+@protected CreateOrderChargeRequest dco_decode_create_order_charge_request(dynamic raw);
+
 @protected CreateOrderItemRequest dco_decode_create_order_item_request(dynamic raw);
 
 @protected CreateOrderRequest dco_decode_create_order_request(dynamic raw);
 
+@protected CreatePaymentRequest dco_decode_create_payment_request(dynamic raw);
+
 @protected CreatePortRequest dco_decode_create_port_request(dynamic raw);
 
+@protected CreatePriceAgreementLineRequest dco_decode_create_price_agreement_line_request(dynamic raw);
+
+@protected CreatePriceAgreementRequest dco_decode_create_price_agreement_request(dynamic raw);
+
+@protected CreatePricingRuleRequest dco_decode_create_pricing_rule_request(dynamic raw);
+
+@protected CreateRequisitionTemplateRequest dco_decode_create_requisition_template_request(dynamic raw);
+
+@protected CreateReturnRequest dco_decode_create_return_request(dynamic raw);
+
 @protected CreateShipRequest dco_decode_create_ship_request(dynamic raw);
 
 @protected CreateShipVisitRequest dco_decode_create_ship_visit_request(dynamic raw);
@@ -113,32 +279,224 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected CreateStockRequest dco_decode_create_stock_request(dynamic raw);
 
+@protected CreateSupplierInvoiceLineRequest dco_decode_create_supplier_invoice_line_request(dynamic raw);
+
+@protected CreateSupplierInvoiceRequest dco_decode_create_supplier_invoice_request(dynamic raw);
+
+@protected CreateSupplierPaymentRequest dco_decode_create_supplier_payment_request(dynamic raw);
+
 @protected CreateSupplierRequest dco_decode_create_supplier_request(dynamic raw);
 
 @protected CreateSupplyItemRequest dco_decode_create_supply_item_request(dynamic raw);
 
+@protected CreateUnitRequest dco_decode_create_unit_request(dynamic raw);
+
+@protected CreditCheck dco_decode_credit_check(dynamic raw);
+
+@protected CreditNote dco_decode_credit_note(dynamic raw);
+
+@protected CurrencyExposure dco_decode_currency_exposure(dynamic raw);
+
+@protected CurrencyRounding dco_decode_currency_rounding(dynamic raw);
+
+@protected Customer dco_decode_customer(dynamic raw);
+
+@protected CustomerBalance dco_decode_customer_balance(dynamic raw);
+
+@protected CustomerContact dco_decode_customer_contact(dynamic raw);
+
+@protected DangerousGoodsClassTotal dco_decode_dangerous_goods_class_total(dynamic raw);
+
+@protected DangerousGoodsLine dco_decode_dangerous_goods_line(dynamic raw);
+
+@protected DangerousGoodsSummary dco_decode_dangerous_goods_summary(dynamic raw);
+
+@protected DeliveryLine dco_decode_delivery_line(dynamic raw);
+
+@protected DeliveryNote dco_decode_delivery_note(dynamic raw);
+
+@protected DeliveryNoteAttachment dco_decode_delivery_note_attachment(dynamic raw);
+
+@protected DeliveryNoteAttachmentKind dco_decode_delivery_note_attachment_kind(dynamic raw);
+
+@protected DeliveryNoteLine dco_decode_delivery_note_line(dynamic raw);
+
+@protected DeliveryNoteStatus dco_decode_delivery_note_status(dynamic raw);
+
 @protected DeliveryType dco_decode_delivery_type(dynamic raw);
 
+@protected EquivalenceGroup dco_decode_equivalence_group(dynamic raw);
+
+@protected ExportResult dco_decode_export_result(dynamic raw);
+
 @protected double dco_decode_f_64(dynamic raw);
 
+@protected FulfilmentStatus dco_decode_fulfilment_status(dynamic raw);
+
 @protected int dco_decode_i_32(dynamic raw);
 
 @protected PlatformInt64 dco_decode_i_64(dynamic raw);
 
+@protected ImpaCatalogEntry dco_decode_impa_catalog_entry(dynamic raw);
+
+@protected ImpaCatalogImportRequest dco_decode_impa_catalog_import_request(dynamic raw);
+
+@protected ImpaCatalogImportResult dco_decode_impa_catalog_import_result(dynamic raw);
+
+@protected ImpaCodeIssue dco_decode_impa_code_issue(dynamic raw);
+
+@protected ImpaCodeProblem dco_decode_impa_code_problem(dynamic raw);
+
+@protected ImpaColumnMapping dco_decode_impa_column_mapping(dynamic raw);
+
+@protected ImpaSection dco_decode_impa_section(dynamic raw);
+
+@protected InstantiateTemplateRequest dco_decode_instantiate_template_request(dynamic raw);
+
+@protected Invoice dco_decode_invoice(dynamic raw);
+
+@protected InvoiceStatus dco_decode_invoice_status(dynamic raw);
+
+@protected ItemDelivery dco_decode_item_delivery(dynamic raw);
+
 @protected ItemProfit dco_decode_item_profit(dynamic raw);
 
+@protected ItemWeight dco_decode_item_weight(dynamic raw);
+
+@protected LandedCost dco_decode_landed_cost(dynamic raw);
+
+@protected LandedCostAllocation dco_decode_landed_cost_allocation(dynamic raw);
+
+@protected LandedCostType dco_decode_landed_cost_type(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<Attachment> dco_decode_list_attachment(dynamic raw);
+
+@protected List<BondedReconciliationLine> dco_decode_list_bonded_reconciliation_line(dynamic raw);
+
 @protected List<CalendarEvent> dco_decode_list_calendar_event(dynamic raw);
 
+@protected List<Category> dco_decode_list_category(dynamic raw);
+
+@protected List<ComplianceIssue> dco_decode_list_compliance_issue(dynamic raw);
+
+@protected List<CreateCustomerContactRequest> dco_decode_list_create_customer_contact_request(dynamic raw);
+
+@protected List<CreatePriceAgreementLineRequest> dco_decode_list_create_price_agreement_line_request(dynamic raw);
+
+@protected List<CreateSupplierInvoiceLineRequest> dco_decode_list_create_supplier_invoice_line_request(dynamic raw);
+
+@protected List<CreditNote> dco_decode_list_credit_note(dynamic raw);
+
+@protected List<CurrencyExposure> dco_decode_list_currency_exposure(dynamic raw);
+
+@protected List<CurrencyRounding> dco_decode_list_currency_rounding(dynamic raw);
+
+@protected List<Customer> dco_decode_list_customer(dynamic raw);
+
+@protected List<CustomerBalance> dco_decode_list_customer_balance(dynamic raw);
+
+@protected List<CustomerContact> dco_decode_list_customer_contact(dynamic raw);
+
+@protected List<DangerousGoodsClassTotal> dco_decode_list_dangerous_goods_class_total(dynamic raw);
+
+@protected List<DangerousGoodsLine> dco_decode_list_dangerous_goods_line(dynamic raw);
+
+@protected List<DeliveryLine> dco_decode_list_delivery_line(dynamic raw);
+
+@protected List<DeliveryNote> dco_decode_list_delivery_note(dynamic raw);
+
+@protected List<DeliveryNoteAttachment> dco_decode_list_delivery_note_attachment(dynamic raw);
+
+@protected List<DeliveryNoteLine> dco_decode_list_delivery_note_line(dynamic raw);
+
+@protected List<EquivalenceGroup> dco_decode_list_equivalence_group(dynamic raw);
+
+@protected List<ImpaCatalogEntry> dco_decode_list_impa_catalog_entry(dynamic raw);
+
+@protected List<ImpaCodeIssue> dco_decode_list_impa_code_issue(dynamic raw);
+
+@protected List<ImpaSection> dco_decode_list_impa_section(dynamic raw);
+
+@protected List<Invoice> dco_decode_list_invoice(dynamic raw);
+
+@protected List<ItemDelivery> dco_decode_list_item_delivery(dynamic raw);
+
+@protected List<ItemWeight> dco_decode_list_item_weight(dynamic raw);
+
+@protected List<LandedCost> dco_decode_list_landed_cost(dynamic raw);
+
+@protected List<LandedCostAllocation> dco_decode_list_landed_cost_allocation(dynamic raw);
+
+@protected List<OffContractLine> dco_decode_list_off_contract_line(dynamic raw);
+
 @protected List<Order> dco_decode_list_order(dynamic raw);
 
+@protected List<OrderCharge> dco_decode_list_order_charge(dynamic raw);
+
 @protected List<OrderItem> dco_decode_list_order_item(dynamic raw);
 
+@protected List<OrderLink> dco_decode_list_order_link(dynamic raw);
+
 @protected List<OrderProfitInfo> dco_decode_list_order_profit_info(dynamic raw);
 
+@protected List<OrderReturn> dco_decode_list_order_return(dynamic raw);
+
+@protected List<OrderReturnLine> dco_decode_list_order_return_line(dynamic raw);
+
+@protected List<PackSize> dco_decode_list_pack_size(dynamic raw);
+
+@protected List<PayableDue> dco_decode_list_payable_due(dynamic raw);
+
+@protected List<Payment> dco_decode_list_payment(dynamic raw);
+
+@protected List<PaymentAllocation> dco_decode_list_payment_allocation(dynamic raw);
+
+@protected List<PaymentAllocationRequest> dco_decode_list_payment_allocation_request(dynamic raw);
+
 @protected List<Port> dco_decode_list_port(dynamic raw);
 
+@protected List<PriceAgreement> dco_decode_list_price_agreement(dynamic raw);
+
+@protected List<PriceAgreementLine> dco_decode_list_price_agreement_line(dynamic raw);
+
+@protected List<PriceChange> dco_decode_list_price_change(dynamic raw);
+
+@protected List<PriceListChange> dco_decode_list_price_list_change(dynamic raw);
+
+@protected List<PriceRiseItem> dco_decode_list_price_rise_item(dynamic raw);
+
+@protected List<PricingRule> dco_decode_list_pricing_rule(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_i_32_loose(dynamic raw);
+
+@protected Int32List dco_decode_list_prim_i_32_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
 @protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+@protected List<ProductAlternative> dco_decode_list_product_alternative(dynamic raw);
+
+@protected List<ReceivablesAgingRow> dco_decode_list_receivables_aging_row(dynamic raw);
+
+@protected List<RepricedItem> dco_decode_list_repriced_item(dynamic raw);
+
+@protected List<RequisitionImportLine> dco_decode_list_requisition_import_line(dynamic raw);
+
+@protected List<RequisitionTemplate> dco_decode_list_requisition_template(dynamic raw);
+
+@protected List<RequisitionTemplateLine> dco_decode_list_requisition_template_line(dynamic raw);
+
+@protected List<RequisitionTemplateLineRequest> dco_decode_list_requisition_template_line_request(dynamic raw);
+
+@protected List<ReturnLineRequest> dco_decode_list_return_line_request(dynamic raw);
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+@protected List<SearchScope> dco_decode_list_search_scope(dynamic raw);
+
 @protected List<Ship> dco_decode_list_ship(dynamic raw);
 
 @protected List<ShipVisit> dco_decode_list_ship_visit(dynamic raw);
@@ -149,24 +507,72 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected List<Supplier> dco_decode_list_supplier(dynamic raw);
 
+@protected List<SupplierInvoice> dco_decode_list_supplier_invoice(dynamic raw);
+
+@protected List<SupplierInvoiceLine> dco_decode_list_supplier_invoice_line(dynamic raw);
+
+@protected List<SupplierInvoiceLineMatch> dco_decode_list_supplier_invoice_line_match(dynamic raw);
+
+@protected List<SupplierPayment> dco_decode_list_supplier_payment(dynamic raw);
+
 @protected List<SupplyItem> dco_decode_list_supply_item(dynamic raw);
 
+@protected List<UnitOfMeasure> dco_decode_list_unit_of_measure(dynamic raw);
+
+@protected MatchStatus dco_decode_match_status(dynamic raw);
+
+@protected OffContractLine dco_decode_off_contract_line(dynamic raw);
+
 @protected String? dco_decode_opt_String(dynamic raw);
 
+@protected Attachment? dco_decode_opt_box_autoadd_attachment(dynamic raw);
+
 @protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
 
+@protected Category? dco_decode_opt_box_autoadd_category(dynamic raw);
+
+@protected ChargeBilling? dco_decode_opt_box_autoadd_charge_billing(dynamic raw);
+
+@protected ContractPrice? dco_decode_opt_box_autoadd_contract_price(dynamic raw);
+
+@protected CreditCheck? dco_decode_opt_box_autoadd_credit_check(dynamic raw);
+
+@protected Customer? dco_decode_opt_box_autoadd_customer(dynamic raw);
+
+@protected DeliveryNote? dco_decode_opt_box_autoadd_delivery_note(dynamic raw);
+
 @protected DeliveryType? dco_decode_opt_box_autoadd_delivery_type(dynamic raw);
 
+@protected EquivalenceGroup? dco_decode_opt_box_autoadd_equivalence_group(dynamic raw);
+
 @protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
 
 @protected int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
+@protected ImpaCatalogEntry? dco_decode_opt_box_autoadd_impa_catalog_entry(dynamic raw);
+
+@protected Invoice? dco_decode_opt_box_autoadd_invoice(dynamic raw);
+
+@protected InvoiceStatus? dco_decode_opt_box_autoadd_invoice_status(dynamic raw);
+
+@protected OrderReturn? dco_decode_opt_box_autoadd_order_return(dynamic raw);
+
 @protected OrderStatus? dco_decode_opt_box_autoadd_order_status(dynamic raw);
 
 @protected OrderWithItems? dco_decode_opt_box_autoadd_order_with_items(dynamic raw);
 
+@protected Payment? dco_decode_opt_box_autoadd_payment(dynamic raw);
+
 @protected Port? dco_decode_opt_box_autoadd_port(dynamic raw);
 
+@protected PriceAgreement? dco_decode_opt_box_autoadd_price_agreement(dynamic raw);
+
+@protected PriceListMatchKind? dco_decode_opt_box_autoadd_price_list_match_kind(dynamic raw);
+
+@protected ProductAlternative? dco_decode_opt_box_autoadd_product_alternative(dynamic raw);
+
+@protected RequisitionTemplate? dco_decode_opt_box_autoadd_requisition_template(dynamic raw);
+
 @protected Ship? dco_decode_opt_box_autoadd_ship(dynamic raw);
 
 @protected ShipVisit? dco_decode_opt_box_autoadd_ship_visit(dynamic raw);
@@ -177,26 +583,132 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected Supplier? dco_decode_opt_box_autoadd_supplier(dynamic raw);
 
+@protected SupplierInvoice? dco_decode_opt_box_autoadd_supplier_invoice(dynamic raw);
+
 @protected SupplyItem? dco_decode_opt_box_autoadd_supply_item(dynamic raw);
 
 @protected VisitStatus? dco_decode_opt_box_autoadd_visit_status(dynamic raw);
 
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
+
+@protected Int32List? dco_decode_opt_list_prim_i_32_strict(dynamic raw);
+
+@protected List<RequisitionTemplateLineRequest>? dco_decode_opt_list_requisition_template_line_request(dynamic raw);
+
 @protected Order dco_decode_order(dynamic raw);
 
+@protected OrderCharge dco_decode_order_charge(dynamic raw);
+
+@protected OrderCompliance dco_decode_order_compliance(dynamic raw);
+
+@protected OrderExportFilter dco_decode_order_export_filter(dynamic raw);
+
 @protected OrderItem dco_decode_order_item(dynamic raw);
 
+@protected OrderLink dco_decode_order_link(dynamic raw);
+
+@protected OrderLinkType dco_decode_order_link_type(dynamic raw);
+
 @protected OrderProfitInfo dco_decode_order_profit_info(dynamic raw);
 
+@protected OrderReturn dco_decode_order_return(dynamic raw);
+
+@protected OrderReturnLine dco_decode_order_return_line(dynamic raw);
+
 @protected OrderStatus dco_decode_order_status(dynamic raw);
 
 @protected OrderTotals dco_decode_order_totals(dynamic raw);
 
 @protected OrderWithItems dco_decode_order_with_items(dynamic raw);
 
+@protected PackSize dco_decode_pack_size(dynamic raw);
+
+@protected PayableDue dco_decode_payable_due(dynamic raw);
+
+@protected Payment dco_decode_payment(dynamic raw);
+
+@protected PaymentAllocation dco_decode_payment_allocation(dynamic raw);
+
+@protected PaymentAllocationRequest dco_decode_payment_allocation_request(dynamic raw);
+
+@protected PaymentMethod dco_decode_payment_method(dynamic raw);
+
+@protected PdfDocumentKind dco_decode_pdf_document_kind(dynamic raw);
+
+@protected PdfRenderRequest dco_decode_pdf_render_request(dynamic raw);
+
+@protected PdfRenderResult dco_decode_pdf_render_result(dynamic raw);
+
+@protected PharmacistSignOffRequest dco_decode_pharmacist_sign_off_request(dynamic raw);
+
 @protected Port dco_decode_port(dynamic raw);
 
+@protected PriceAgreement dco_decode_price_agreement(dynamic raw);
+
+@protected PriceAgreementLine dco_decode_price_agreement_line(dynamic raw);
+
+@protected PriceChange dco_decode_price_change(dynamic raw);
+
+@protected PriceChangeSource dco_decode_price_change_source(dynamic raw);
+
+@protected PriceListChange dco_decode_price_list_change(dynamic raw);
+
+@protected PriceListChangeKind dco_decode_price_list_change_kind(dynamic raw);
+
+@protected PriceListColumnMapping dco_decode_price_list_column_mapping(dynamic raw);
+
+@protected PriceListImportRequest dco_decode_price_list_import_request(dynamic raw);
+
+@protected PriceListImportResult dco_decode_price_list_import_result(dynamic raw);
+
+@protected PriceListMatchKind dco_decode_price_list_match_kind(dynamic raw);
+
+@protected PriceListPreview dco_decode_price_list_preview(dynamic raw);
+
+@protected PriceProposal dco_decode_price_proposal(dynamic raw);
+
+@protected PriceRiseItem dco_decode_price_rise_item(dynamic raw);
+
+@protected PriceSource dco_decode_price_source(dynamic raw);
+
+@protected PricingRule dco_decode_pricing_rule(dynamic raw);
+
+@protected ProductAlternative dco_decode_product_alternative(dynamic raw);
+
 @protected ProfitSummary dco_decode_profit_summary(dynamic raw);
 
+@protected ReceivablesAgingRow dco_decode_receivables_aging_row(dynamic raw);
+
+@protected RecordDeliveryRequest dco_decode_record_delivery_request(dynamic raw);
+
+@protected RepriceResult dco_decode_reprice_result(dynamic raw);
+
+@protected RepricedItem dco_decode_repriced_item(dynamic raw);
+
+@protected RequisitionColumnMapping dco_decode_requisition_column_mapping(dynamic raw);
+
+@protected RequisitionImportLine dco_decode_requisition_import_line(dynamic raw);
+
+@protected RequisitionImportPreview dco_decode_requisition_import_preview(dynamic raw);
+
+@protected RequisitionImportRequest dco_decode_requisition_import_request(dynamic raw);
+
+@protected RequisitionMatchKind dco_decode_requisition_match_kind(dynamic raw);
+
+@protected RequisitionTemplate dco_decode_requisition_template(dynamic raw);
+
+@protected RequisitionTemplateLine dco_decode_requisition_template_line(dynamic raw);
+
+@protected RequisitionTemplateLineRequest dco_decode_requisition_template_line_request(dynamic raw);
+
+@protected ReturnLineRequest dco_decode_return_line_request(dynamic raw);
+
+@protected ReturnReason dco_decode_return_reason(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SearchScope dco_decode_search_scope(dynamic raw);
+
 @protected Ship dco_decode_ship(dynamic raw);
 
 @protected ShipVisit dco_decode_ship_visit(dynamic raw);
@@ -213,409 +725,1497 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected Supplier dco_decode_supplier(dynamic raw);
 
+@protected SupplierInvoice dco_decode_supplier_invoice(dynamic raw);
+
+@protected SupplierInvoiceLine dco_decode_supplier_invoice_line(dynamic raw);
+
+@protected SupplierInvoiceLineMatch dco_decode_supplier_invoice_line_match(dynamic raw);
+
+@protected SupplierInvoiceMatch dco_decode_supplier_invoice_match(dynamic raw);
+
+@protected SupplierPayment dco_decode_supplier_payment(dynamic raw);
+
 @protected SupplyItem dco_decode_supply_item(dynamic raw);
 
 @protected int dco_decode_u_8(dynamic raw);
 
-@protected void dco_decode_unit(dynamic raw);
+@protected void dco_decode_unit(dynamic raw);
+
+@protected UnitDimension dco_decode_unit_dimension(dynamic raw);
+
+@protected UnitOfMeasure dco_decode_unit_of_measure(dynamic raw);
+
+@protected UpdateCategoryRequest dco_decode_update_category_request(dynamic raw);
+
+@protected UpdateCompanyProfileRequest dco_decode_update_company_profile_request(dynamic raw);
+
+@protected UpdateCustomerRequest dco_decode_update_customer_request(dynamic raw);
+
+@protected UpdateOrderChargeRequest dco_decode_update_order_charge_request(dynamic raw);
+
+@protected UpdateOrderItemRequest dco_decode_update_order_item_request(dynamic raw);
+
+@protected UpdateOrderRequest dco_decode_update_order_request(dynamic raw);
+
+@protected UpdatePortRequest dco_decode_update_port_request(dynamic raw);
+
+@protected UpdatePriceAgreementRequest dco_decode_update_price_agreement_request(dynamic raw);
+
+@protected UpdatePricingRuleRequest dco_decode_update_pricing_rule_request(dynamic raw);
+
+@protected UpdateRequisitionTemplateRequest dco_decode_update_requisition_template_request(dynamic raw);
+
+@protected UpdateShipRequest dco_decode_update_ship_request(dynamic raw);
+
+@protected UpdateShipVisitRequest dco_decode_update_ship_visit_request(dynamic raw);
+
+@protected UpdateStockRequest dco_decode_update_stock_request(dynamic raw);
+
+@protected UpdateSupplierRequest dco_decode_update_supplier_request(dynamic raw);
+
+@protected UpdateSupplyItemRequest dco_decode_update_supply_item_request(dynamic raw);
+
+@protected UpdateUnitRequest dco_decode_update_unit_request(dynamic raw);
+
+@protected VisitStatus dco_decode_visit_status(dynamic raw);
+
+@protected WarehouseType dco_decode_warehouse_type(dynamic raw);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AllocationMethod sse_decode_allocation_method(SseDeserializer deserializer);
+
+@protected AlternativeLink sse_decode_alternative_link(SseDeserializer deserializer);
+
+@protected Attachment sse_decode_attachment(SseDeserializer deserializer);
+
+@protected AttachmentEntity sse_decode_attachment_entity(SseDeserializer deserializer);
+
+@protected BondedEligibility sse_decode_bonded_eligibility(SseDeserializer deserializer);
+
+@protected BondedReconciliation sse_decode_bonded_reconciliation(SseDeserializer deserializer);
+
+@protected BondedReconciliationLine sse_decode_bonded_reconciliation_line(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected Attachment sse_decode_box_autoadd_attachment(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected Category sse_decode_box_autoadd_category(SseDeserializer deserializer);
+
+@protected ChargeBilling sse_decode_box_autoadd_charge_billing(SseDeserializer deserializer);
+
+@protected CompleteDeliveryNoteRequest sse_decode_box_autoadd_complete_delivery_note_request(SseDeserializer deserializer);
+
+@protected ContractPrice sse_decode_box_autoadd_contract_price(SseDeserializer deserializer);
+
+@protected CreateAttachmentRequest sse_decode_box_autoadd_create_attachment_request(SseDeserializer deserializer);
+
+@protected CreateCategoryRequest sse_decode_box_autoadd_create_category_request(SseDeserializer deserializer);
+
+@protected CreateCustomerContactRequest sse_decode_box_autoadd_create_customer_contact_request(SseDeserializer deserializer);
+
+@protected CreateCustomerRequest sse_decode_box_autoadd_create_customer_request(SseDeserializer deserializer);
+
+@protected CreateDeliveryNoteRequest sse_decode_box_autoadd_create_delivery_note_request(SseDeserializer deserializer);
+
+@protected CreateEquivalenceGroupRequest sse_decode_box_autoadd_create_equivalence_group_request(SseDeserializer deserializer);
+
+@protected CreateInvoiceRequest sse_decode_box_autoadd_create_invoice_request(SseDeserializer deserializer);
+
+@protected CreateLandedCostRequest sse_decode_box_autoadd_create_landed_cost_request(SseDeserializer deserializer);
+
+@protected CreateOrderChargeRequest sse_decode_box_autoadd_create_order_charge_request(SseDeserializer deserializer);
+
+@protected CreateOrderItemRequest sse_decode_box_autoadd_create_order_item_request(SseDeserializer deserializer);
+
+@protected CreateOrderRequest sse_decode_box_autoadd_create_order_request(SseDeserializer deserializer);
+
+@protected CreatePaymentRequest sse_decode_box_autoadd_create_payment_request(SseDeserializer deserializer);
+
+@protected CreatePortRequest sse_decode_box_autoadd_create_port_request(SseDeserializer deserializer);
+
+@protected CreatePriceAgreementLineRequest sse_decode_box_autoadd_create_price_agreement_line_request(SseDeserializer deserializer);
+
+@protected CreatePriceAgreementRequest sse_decode_box_autoadd_create_price_agreement_request(SseDeserializer deserializer);
+
+@protected CreatePricingRuleRequest sse_decode_box_autoadd_create_pricing_rule_request(SseDeserializer deserializer);
+
+@protected CreateRequisitionTemplateRequest sse_decode_box_autoadd_create_requisition_template_request(SseDeserializer deserializer);
+
+@protected CreateReturnRequest sse_decode_box_autoadd_create_return_request(SseDeserializer deserializer);
+
+@protected CreateShipRequest sse_decode_box_autoadd_create_ship_request(SseDeserializer deserializer);
+
+@protected CreateShipVisitRequest sse_decode_box_autoadd_create_ship_visit_request(SseDeserializer deserializer);
+
+@protected CreateStockMovementRequest sse_decode_box_autoadd_create_stock_movement_request(SseDeserializer deserializer);
+
+@protected CreateStockRequest sse_decode_box_autoadd_create_stock_request(SseDeserializer deserializer);
+
+@protected CreateSupplierInvoiceRequest sse_decode_box_autoadd_create_supplier_invoice_request(SseDeserializer deserializer);
+
+@protected CreateSupplierPaymentRequest sse_decode_box_autoadd_create_supplier_payment_request(SseDeserializer deserializer);
+
+@protected CreateSupplierRequest sse_decode_box_autoadd_create_supplier_request(SseDeserializer deserializer);
+
+@protected CreateSupplyItemRequest sse_decode_box_autoadd_create_supply_item_request(SseDeserializer deserializer);
+
+@protected CreateUnitRequest sse_decode_box_autoadd_create_unit_request(SseDeserializer deserializer);
+
+@protected CreditCheck sse_decode_box_autoadd_credit_check(SseDeserializer deserializer);
+
+@protected Customer sse_decode_box_autoadd_customer(SseDeserializer deserializer);
+
+@protected DeliveryNote sse_decode_box_autoadd_delivery_note(SseDeserializer deserializer);
+
+@protected DeliveryType sse_decode_box_autoadd_delivery_type(SseDeserializer deserializer);
+
+@protected EquivalenceGroup sse_decode_box_autoadd_equivalence_group(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected ImpaCatalogEntry sse_decode_box_autoadd_impa_catalog_entry(SseDeserializer deserializer);
+
+@protected ImpaCatalogImportRequest sse_decode_box_autoadd_impa_catalog_import_request(SseDeserializer deserializer);
+
+@protected InstantiateTemplateRequest sse_decode_box_autoadd_instantiate_template_request(SseDeserializer deserializer);
+
+@protected Invoice sse_decode_box_autoadd_invoice(SseDeserializer deserializer);
+
+@protected InvoiceStatus sse_decode_box_autoadd_invoice_status(SseDeserializer deserializer);
+
+@protected OrderExportFilter sse_decode_box_autoadd_order_export_filter(SseDeserializer deserializer);
+
+@protected OrderReturn sse_decode_box_autoadd_order_return(SseDeserializer deserializer);
+
+@protected OrderStatus sse_decode_box_autoadd_order_status(SseDeserializer deserializer);
+
+@protected OrderWithItems sse_decode_box_autoadd_order_with_items(SseDeserializer deserializer);
+
+@protected Payment sse_decode_box_autoadd_payment(SseDeserializer deserializer);
+
+@protected PdfRenderRequest sse_decode_box_autoadd_pdf_render_request(SseDeserializer deserializer);
+
+@protected PharmacistSignOffRequest sse_decode_box_autoadd_pharmacist_sign_off_request(SseDeserializer deserializer);
+
+@protected Port sse_decode_box_autoadd_port(SseDeserializer deserializer);
+
+@protected PriceAgreement sse_decode_box_autoadd_price_agreement(SseDeserializer deserializer);
+
+@protected PriceListImportRequest sse_decode_box_autoadd_price_list_import_request(SseDeserializer deserializer);
+
+@protected PriceListMatchKind sse_decode_box_autoadd_price_list_match_kind(SseDeserializer deserializer);
+
+@protected ProductAlternative sse_decode_box_autoadd_product_alternative(SseDeserializer deserializer);
+
+@protected RecordDeliveryRequest sse_decode_box_autoadd_record_delivery_request(SseDeserializer deserializer);
+
+@protected RequisitionImportRequest sse_decode_box_autoadd_requisition_import_request(SseDeserializer deserializer);
+
+@protected RequisitionTemplate sse_decode_box_autoadd_requisition_template(SseDeserializer deserializer);
+
+@protected Ship sse_decode_box_autoadd_ship(SseDeserializer deserializer);
+
+@protected ShipVisit sse_decode_box_autoadd_ship_visit(SseDeserializer deserializer);
+
+@protected Stock sse_decode_box_autoadd_stock(SseDeserializer deserializer);
+
+@protected StockWithMovements sse_decode_box_autoadd_stock_with_movements(SseDeserializer deserializer);
+
+@protected Supplier sse_decode_box_autoadd_supplier(SseDeserializer deserializer);
+
+@protected SupplierInvoice sse_decode_box_autoadd_supplier_invoice(SseDeserializer deserializer);
+
+@protected SupplyItem sse_decode_box_autoadd_supply_item(SseDeserializer deserializer);
+
+@protected UpdateCategoryRequest sse_decode_box_autoadd_update_category_request(SseDeserializer deserializer);
+
+@protected UpdateCompanyProfileRequest sse_decode_box_autoadd_update_company_profile_request(SseDeserializer deserializer);
+
+@protected UpdateCustomerRequest sse_decode_box_autoadd_update_customer_request(SseDeserializer deserializer);
+
+@protected UpdateOrderChargeRequest sse_decode_box_autoadd_update_order_charge_request(SseDeserializer deserializer);
+
+@protected UpdateOrderItemRequest sse_decode_box_autoadd_update_order_item_request(SseDeserializer deserializer);
+
+@protected UpdateOrderRequest sse_decode_box_autoadd_update_order_request(SseDeserializer deserializer);
+
+@protected UpdatePortRequest sse_decode_box_autoadd_update_port_request(SseDeserializer deserializer);
+
+@protected UpdatePriceAgreementRequest sse_decode_box_autoadd_update_price_agreement_request(SseDeserializer deserializer);
+
+@protected UpdatePricingRuleRequest sse_decode_box_autoadd_update_pricing_rule_request(SseDeserializer deserializer);
+
+@protected UpdateRequisitionTemplateRequest sse_decode_box_autoadd_update_requisition_template_request(SseDeserializer deserializer);
+
+@protected UpdateShipRequest sse_decode_box_autoadd_update_ship_request(SseDeserializer deserializer);
+
+@protected UpdateShipVisitRequest sse_decode_box_autoadd_update_ship_visit_request(SseDeserializer deserializer);
+
+@protected UpdateStockRequest sse_decode_box_autoadd_update_stock_request(SseDeserializer deserializer);
+
+@protected UpdateSupplierRequest sse_decode_box_autoadd_update_supplier_request(SseDeserializer deserializer);
+
+@protected UpdateSupplyItemRequest sse_decode_box_autoadd_update_supply_item_request(SseDeserializer deserializer);
+
+@protected UpdateUnitRequest sse_decode_box_autoadd_update_unit_request(SseDeserializer deserializer);
+
+@protected VisitStatus sse_decode_box_autoadd_visit_status(SseDeserializer deserializer);
+
+@protected CalendarData sse_decode_calendar_data(SseDeserializer deserializer);
+
+@protected CalendarEvent sse_decode_calendar_event(SseDeserializer deserializer);
+
+@protected CalendarEventType sse_decode_calendar_event_type(SseDeserializer deserializer);
+
+@protected Category sse_decode_category(SseDeserializer deserializer);
+
+@protected ChargeBilling sse_decode_charge_billing(SseDeserializer deserializer);
+
+@protected ChargeType sse_decode_charge_type(SseDeserializer deserializer);
+
+@protected CompanyProfile sse_decode_company_profile(SseDeserializer deserializer);
+
+@protected CompleteDeliveryNoteRequest sse_decode_complete_delivery_note_request(SseDeserializer deserializer);
+
+@protected ComplianceIssue sse_decode_compliance_issue(SseDeserializer deserializer);
+
+@protected ComplianceIssueKind sse_decode_compliance_issue_kind(SseDeserializer deserializer);
+
+@protected ContractPrice sse_decode_contract_price(SseDeserializer deserializer);
+
+@protected CreateAttachmentRequest sse_decode_create_attachment_request(SseDeserializer deserializer);
+
+@protected CreateCategoryRequest sse_decode_create_category_request(SseDeserializer deserializer);
+
+@protected CreateCustomerContactRequest sse_decode_create_customer_contact_request(SseDeserializer deserializer);
+
+@protected CreateCustomerRequest sse_decode_create_customer_request(SseDeserializer deserializer);
+
+@protected CreateDeliveryNoteRequest sse_decode_create_delivery_note_request(SseDeserializer deserializer);
+
+@protected CreateEquivalenceGroupRequest sse_decode_create_equivalence_group_request(SseDeserializer deserializer);
+
+@protected CreateInvoiceRequest sse_decode_create_invoice_request(SseDeserializer deserializer);
+
+@protected CreateLandedCostRequest sse_decode_create_landed_cost_request(SseDeserializer deserializer);
+
+@protected CreateOrderChargeRequest sse_decode_create_order_charge_request(SseDeserializer deserializer);
+
+@protected CreateOrderItemRequest sse_decode_create_order_item_request(SseDeserializer deserializer);
+
+@protected CreateOrderRequest sse_decode_create_order_request(SseDeserializer deserializer);
+
+@protected CreatePaymentRequest sse_decode_create_payment_request(SseDeserializer deserializer);
+
+@protected CreatePortRequest sse_decode_create_port_request(SseDeserializer deserializer);
+
+@protected CreatePriceAgreementLineRequest sse_decode_create_price_agreement_line_request(SseDeserializer deserializer);
+
+@protected CreatePriceAgreementRequest sse_decode_create_price_agreement_request(SseDeserializer deserializer);
+
+@protected CreatePricingRuleRequest sse_decode_create_pricing_rule_request(SseDeserializer deserializer);
+
+@protected CreateRequisitionTemplateRequest sse_decode_create_requisition_template_request(SseDeserializer deserializer);
+
+@protected CreateReturnRequest sse_decode_create_return_request(SseDeserializer deserializer);
+
+@protected CreateShipRequest sse_decode_create_ship_request(SseDeserializer deserializer);
+
+@protected CreateShipVisitRequest sse_decode_create_ship_visit_request(SseDeserializer deserializer);
+
+@protected CreateStockMovementRequest sse_decode_create_stock_movement_request(SseDeserializer deserializer);
+
+@protected CreateStockRequest sse_decode_create_stock_request(SseDeserializer deserializer);
+
+@protected CreateSupplierInvoiceLineRequest sse_decode_create_supplier_invoice_line_request(SseDeserializer deserializer);
+
+@protected CreateSupplierInvoiceRequest sse_decode_create_supplier_invoice_request(SseDeserializer deserializer);
+
+@protected CreateSupplierPaymentRequest sse_decode_create_supplier_payment_request(SseDeserializer deserializer);
+
+@protected CreateSupplierRequest sse_decode_create_supplier_request(SseDeserializer deserializer);
+
+@protected CreateSupplyItemRequest sse_decode_create_supply_item_request(SseDeserializer deserializer);
+
+@protected CreateUnitRequest sse_decode_create_unit_request(SseDeserializer deserializer);
+
+@protected CreditCheck sse_decode_credit_check(SseDeserializer deserializer);
+
+@protected CreditNote sse_decode_credit_note(SseDeserializer deserializer);
+
+@protected CurrencyExposure sse_decode_currency_exposure(SseDeserializer deserializer);
+
+@protected CurrencyRounding sse_decode_currency_rounding(SseDeserializer deserializer);
+
+@protected Customer sse_decode_customer(SseDeserializer deserializer);
+
+@protected CustomerBalance sse_decode_customer_balance(SseDeserializer deserializer);
+
+@protected CustomerContact sse_decode_customer_contact(SseDeserializer deserializer);
+
+@protected DangerousGoodsClassTotal sse_decode_dangerous_goods_class_total(SseDeserializer deserializer);
+
+@protected DangerousGoodsLine sse_decode_dangerous_goods_line(SseDeserializer deserializer);
+
+@protected DangerousGoodsSummary sse_decode_dangerous_goods_summary(SseDeserializer deserializer);
+
+@protected DeliveryLine sse_decode_delivery_line(SseDeserializer deserializer);
+
+@protected DeliveryNote sse_decode_delivery_note(SseDeserializer deserializer);
+
+@protected DeliveryNoteAttachment sse_decode_delivery_note_attachment(SseDeserializer deserializer);
+
+@protected DeliveryNoteAttachmentKind sse_decode_delivery_note_attachment_kind(SseDeserializer deserializer);
+
+@protected DeliveryNoteLine sse_decode_delivery_note_line(SseDeserializer deserializer);
+
+@protected DeliveryNoteStatus sse_decode_delivery_note_status(SseDeserializer deserializer);
+
+@protected DeliveryType sse_decode_delivery_type(SseDeserializer deserializer);
+
+@protected EquivalenceGroup sse_decode_equivalence_group(SseDeserializer deserializer);
+
+@protected ExportResult sse_decode_export_result(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FulfilmentStatus sse_decode_fulfilment_status(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected ImpaCatalogEntry sse_decode_impa_catalog_entry(SseDeserializer deserializer);
+
+@protected ImpaCatalogImportRequest sse_decode_impa_catalog_import_request(SseDeserializer deserializer);
+
+@protected ImpaCatalogImportResult sse_decode_impa_catalog_import_result(SseDeserializer deserializer);
+
+@protected ImpaCodeIssue sse_decode_impa_code_issue(SseDeserializer deserializer);
+
+@protected ImpaCodeProblem sse_decode_impa_code_problem(SseDeserializer deserializer);
+
+@protected ImpaColumnMapping sse_decode_impa_column_mapping(SseDeserializer deserializer);
+
+@protected ImpaSection sse_decode_impa_section(SseDeserializer deserializer);
+
+@protected InstantiateTemplateRequest sse_decode_instantiate_template_request(SseDeserializer deserializer);
+
+@protected Invoice sse_decode_invoice(SseDeserializer deserializer);
+
+@protected InvoiceStatus sse_decode_invoice_status(SseDeserializer deserializer);
+
+@protected ItemDelivery sse_decode_item_delivery(SseDeserializer deserializer);
+
+@protected ItemProfit sse_decode_item_profit(SseDeserializer deserializer);
+
+@protected ItemWeight sse_decode_item_weight(SseDeserializer deserializer);
+
+@protected LandedCost sse_decode_landed_cost(SseDeserializer deserializer);
+
+@protected LandedCostAllocation sse_decode_landed_cost_allocation(SseDeserializer deserializer);
+
+@protected LandedCostType sse_decode_landed_cost_type(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<Attachment> sse_decode_list_attachment(SseDeserializer deserializer);
+
+@protected List<BondedReconciliationLine> sse_decode_list_bonded_reconciliation_line(SseDeserializer deserializer);
+
+@protected List<CalendarEvent> sse_decode_list_calendar_event(SseDeserializer deserializer);
+
+@protected List<Category> sse_decode_list_category(SseDeserializer deserializer);
+
+@protected List<ComplianceIssue> sse_decode_list_compliance_issue(SseDeserializer deserializer);
+
+@protected List<CreateCustomerContactRequest> sse_decode_list_create_customer_contact_request(SseDeserializer deserializer);
+
+@protected List<CreatePriceAgreementLineRequest> sse_decode_list_create_price_agreement_line_request(SseDeserializer deserializer);
+
+@protected List<CreateSupplierInvoiceLineRequest> sse_decode_list_create_supplier_invoice_line_request(SseDeserializer deserializer);
+
+@protected List<CreditNote> sse_decode_list_credit_note(SseDeserializer deserializer);
+
+@protected List<CurrencyExposure> sse_decode_list_currency_exposure(SseDeserializer deserializer);
+
+@protected List<CurrencyRounding> sse_decode_list_currency_rounding(SseDeserializer deserializer);
+
+@protected List<Customer> sse_decode_list_customer(SseDeserializer deserializer);
+
+@protected List<CustomerBalance> sse_decode_list_customer_balance(SseDeserializer deserializer);
+
+@protected List<CustomerContact> sse_decode_list_customer_contact(SseDeserializer deserializer);
+
+@protected List<DangerousGoodsClassTotal> sse_decode_list_dangerous_goods_class_total(SseDeserializer deserializer);
+
+@protected List<DangerousGoodsLine> sse_decode_list_dangerous_goods_line(SseDeserializer deserializer);
+
+@protected List<DeliveryLine> sse_decode_list_delivery_line(SseDeserializer deserializer);
+
+@protected List<DeliveryNote> sse_decode_list_delivery_note(SseDeserializer deserializer);
+
+@protected List<DeliveryNoteAttachment> sse_decode_list_delivery_note_attachment(SseDeserializer deserializer);
+
+@protected List<DeliveryNoteLine> sse_decode_list_delivery_note_line(SseDeserializer deserializer);
+
+@protected List<EquivalenceGroup> sse_decode_list_equivalence_group(SseDeserializer deserializer);
+
+@protected List<ImpaCatalogEntry> sse_decode_list_impa_catalog_entry(SseDeserializer deserializer);
+
+@protected List<ImpaCodeIssue> sse_decode_list_impa_code_issue(SseDeserializer deserializer);
+
+@protected List<ImpaSection> sse_decode_list_impa_section(SseDeserializer deserializer);
+
+@protected List<Invoice> sse_decode_list_invoice(SseDeserializer deserializer);
+
+@protected List<ItemDelivery> sse_decode_list_item_delivery(SseDeserializer deserializer);
+
+@protected List<ItemWeight> sse_decode_list_item_weight(SseDeserializer deserializer);
+
+@protected List<LandedCost> sse_decode_list_landed_cost(SseDeserializer deserializer);
+
+@protected List<LandedCostAllocation> sse_decode_list_landed_cost_allocation(SseDeserializer deserializer);
+
+@protected List<OffContractLine> sse_decode_list_off_contract_line(SseDeserializer deserializer);
+
+@protected List<Order> sse_decode_list_order(SseDeserializer deserializer);
+
+@protected List<OrderCharge> sse_decode_list_order_charge(SseDeserializer deserializer);
+
+@protected List<OrderItem> sse_decode_list_order_item(SseDeserializer deserializer);
+
+@protected List<OrderLink> sse_decode_list_order_link(SseDeserializer deserializer);
+
+@protected List<OrderProfitInfo> sse_decode_list_order_profit_info(SseDeserializer deserializer);
+
+@protected List<OrderReturn> sse_decode_list_order_return(SseDeserializer deserializer);
+
+@protected List<OrderReturnLine> sse_decode_list_order_return_line(SseDeserializer deserializer);
+
+@protected List<PackSize> sse_decode_list_pack_size(SseDeserializer deserializer);
+
+@protected List<PayableDue> sse_decode_list_payable_due(SseDeserializer deserializer);
+
+@protected List<Payment> sse_decode_list_payment(SseDeserializer deserializer);
+
+@protected List<PaymentAllocation> sse_decode_list_payment_allocation(SseDeserializer deserializer);
+
+@protected List<PaymentAllocationRequest> sse_decode_list_payment_allocation_request(SseDeserializer deserializer);
+
+@protected List<Port> sse_decode_list_port(SseDeserializer deserializer);
+
+@protected List<PriceAgreement> sse_decode_list_price_agreement(SseDeserializer deserializer);
+
+@protected List<PriceAgreementLine> sse_decode_list_price_agreement_line(SseDeserializer deserializer);
+
+@protected List<PriceChange> sse_decode_list_price_change(SseDeserializer deserializer);
+
+@protected List<PriceListChange> sse_decode_list_price_list_change(SseDeserializer deserializer);
+
+@protected List<PriceRiseItem> sse_decode_list_price_rise_item(SseDeserializer deserializer);
+
+@protected List<PricingRule> sse_decode_list_pricing_rule(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_i_32_loose(SseDeserializer deserializer);
+
+@protected Int32List sse_decode_list_prim_i_32_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<ProductAlternative> sse_decode_list_product_alternative(SseDeserializer deserializer);
+
+@protected List<ReceivablesAgingRow> sse_decode_list_receivables_aging_row(SseDeserializer deserializer);
+
+@protected List<RepricedItem> sse_decode_list_repriced_item(SseDeserializer deserializer);
+
+@protected List<RequisitionImportLine> sse_decode_list_requisition_import_line(SseDeserializer deserializer);
+
+@protected List<RequisitionTemplate> sse_decode_list_requisition_template(SseDeserializer deserializer);
+
+@protected List<RequisitionTemplateLine> sse_decode_list_requisition_template_line(SseDeserializer deserializer);
+
+@protected List<RequisitionTemplateLineRequest> sse_decode_list_requisition_template_line_request(SseDeserializer deserializer);
+
+@protected List<ReturnLineRequest> sse_decode_list_return_line_request(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<SearchScope> sse_decode_list_search_scope(SseDeserializer deserializer);
+
+@protected List<Ship> sse_decode_list_ship(SseDeserializer deserializer);
+
+@protected List<ShipVisit> sse_decode_list_ship_visit(SseDeserializer deserializer);
+
+@protected List<Stock> sse_decode_list_stock(SseDeserializer deserializer);
+
+@protected List<StockMovement> sse_decode_list_stock_movement(SseDeserializer deserializer);
+
+@protected List<Supplier> sse_decode_list_supplier(SseDeserializer deserializer);
+
+@protected List<SupplierInvoice> sse_decode_list_supplier_invoice(SseDeserializer deserializer);
+
+@protected List<SupplierInvoiceLine> sse_decode_list_supplier_invoice_line(SseDeserializer deserializer);
+
+@protected List<SupplierInvoiceLineMatch> sse_decode_list_supplier_invoice_line_match(SseDeserializer deserializer);
+
+@protected List<SupplierPayment> sse_decode_list_supplier_payment(SseDeserializer deserializer);
+
+@protected List<SupplyItem> sse_decode_list_supply_item(SseDeserializer deserializer);
+
+@protected List<UnitOfMeasure> sse_decode_list_unit_of_measure(SseDeserializer deserializer);
+
+@protected MatchStatus sse_decode_match_status(SseDeserializer deserializer);
+
+@protected OffContractLine sse_decode_off_contract_line(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected Attachment? sse_decode_opt_box_autoadd_attachment(SseDeserializer deserializer);
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected Category? sse_decode_opt_box_autoadd_category(SseDeserializer deserializer);
+
+@protected ChargeBilling? sse_decode_opt_box_autoadd_charge_billing(SseDeserializer deserializer);
+
+@protected ContractPrice? sse_decode_opt_box_autoadd_contract_price(SseDeserializer deserializer);
+
+@protected CreditCheck? sse_decode_opt_box_autoadd_credit_check(SseDeserializer deserializer);
+
+@protected Customer? sse_decode_opt_box_autoadd_customer(SseDeserializer deserializer);
+
+@protected DeliveryNote? sse_decode_opt_box_autoadd_delivery_note(SseDeserializer deserializer);
+
+@protected DeliveryType? sse_decode_opt_box_autoadd_delivery_type(SseDeserializer deserializer);
+
+@protected EquivalenceGroup? sse_decode_opt_box_autoadd_equivalence_group(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+@protected ImpaCatalogEntry? sse_decode_opt_box_autoadd_impa_catalog_entry(SseDeserializer deserializer);
+
+@protected Invoice? sse_decode_opt_box_autoadd_invoice(SseDeserializer deserializer);
+
+@protected InvoiceStatus? sse_decode_opt_box_autoadd_invoice_status(SseDeserializer deserializer);
+
+@protected OrderReturn? sse_decode_opt_box_autoadd_order_return(SseDeserializer deserializer);
+
+@protected OrderStatus? sse_decode_opt_box_autoadd_order_status(SseDeserializer deserializer);
+
+@protected OrderWithItems? sse_decode_opt_box_autoadd_order_with_items(SseDeserializer deserializer);
+
+@protected Payment? sse_decode_opt_box_autoadd_payment(SseDeserializer deserializer);
+
+@protected Port? sse_decode_opt_box_autoadd_port(SseDeserializer deserializer);
+
+@protected PriceAgreement? sse_decode_opt_box_autoadd_price_agreement(SseDeserializer deserializer);
+
+@protected PriceListMatchKind? sse_decode_opt_box_autoadd_price_list_match_kind(SseDeserializer deserializer);
+
+@protected ProductAlternative? sse_decode_opt_box_autoadd_product_alternative(SseDeserializer deserializer);
+
+@protected RequisitionTemplate? sse_decode_opt_box_autoadd_requisition_template(SseDeserializer deserializer);
+
+@protected Ship? sse_decode_opt_box_autoadd_ship(SseDeserializer deserializer);
+
+@protected ShipVisit? sse_decode_opt_box_autoadd_ship_visit(SseDeserializer deserializer);
+
+@protected Stock? sse_decode_opt_box_autoadd_stock(SseDeserializer deserializer);
+
+@protected StockWithMovements? sse_decode_opt_box_autoadd_stock_with_movements(SseDeserializer deserializer);
+
+@protected Supplier? sse_decode_opt_box_autoadd_supplier(SseDeserializer deserializer);
+
+@protected SupplierInvoice? sse_decode_opt_box_autoadd_supplier_invoice(SseDeserializer deserializer);
+
+@protected SupplyItem? sse_decode_opt_box_autoadd_supply_item(SseDeserializer deserializer);
+
+@protected VisitStatus? sse_decode_opt_box_autoadd_visit_status(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected Int32List? sse_decode_opt_list_prim_i_32_strict(SseDeserializer deserializer);
+
+@protected List<RequisitionTemplateLineRequest>? sse_decode_opt_list_requisition_template_line_request(SseDeserializer deserializer);
+
+@protected Order sse_decode_order(SseDeserializer deserializer);
+
+@protected OrderCharge sse_decode_order_charge(SseDeserializer deserializer);
+
+@protected OrderCompliance sse_decode_order_compliance(SseDeserializer deserializer);
+
+@protected OrderExportFilter sse_decode_order_export_filter(SseDeserializer deserializer);
+
+@protected OrderItem sse_decode_order_item(SseDeserializer deserializer);
+
+@protected OrderLink sse_decode_order_link(SseDeserializer deserializer);
+
+@protected OrderLinkType sse_decode_order_link_type(SseDeserializer deserializer);
+
+@protected OrderProfitInfo sse_decode_order_profit_info(SseDeserializer deserializer);
+
+@protected OrderReturn sse_decode_order_return(SseDeserializer deserializer);
+
+@protected OrderReturnLine sse_decode_order_return_line(SseDeserializer deserializer);
+
+@protected OrderStatus sse_decode_order_status(SseDeserializer deserializer);
+
+@protected OrderTotals sse_decode_order_totals(SseDeserializer deserializer);
+
+@protected OrderWithItems sse_decode_order_with_items(SseDeserializer deserializer);
+
+@protected PackSize sse_decode_pack_size(SseDeserializer deserializer);
+
+@protected PayableDue sse_decode_payable_due(SseDeserializer deserializer);
+
+@protected Payment sse_decode_payment(SseDeserializer deserializer);
+
+@protected PaymentAllocation sse_decode_payment_allocation(SseDeserializer deserializer);
+
+@protected PaymentAllocationRequest sse_decode_payment_allocation_request(SseDeserializer deserializer);
+
+@protected PaymentMethod sse_decode_payment_method(SseDeserializer deserializer);
+
+@protected PdfDocumentKind sse_decode_pdf_document_kind(SseDeserializer deserializer);
+
+@protected PdfRenderRequest sse_decode_pdf_render_request(SseDeserializer deserializer);
+
+@protected PdfRenderResult sse_decode_pdf_render_result(SseDeserializer deserializer);
+
+@protected PharmacistSignOffRequest sse_decode_pharmacist_sign_off_request(SseDeserializer deserializer);
+
+@protected Port sse_decode_port(SseDeserializer deserializer);
+
+@protected PriceAgreement sse_decode_price_agreement(SseDeserializer deserializer);
+
+@protected PriceAgreementLine sse_decode_price_agreement_line(SseDeserializer deserializer);
+
+@protected PriceChange sse_decode_price_change(SseDeserializer deserializer);
+
+@protected PriceChangeSource sse_decode_price_change_source(SseDeserializer deserializer);
+
+@protected PriceListChange sse_decode_price_list_change(SseDeserializer deserializer);
+
+@protected PriceListChangeKind sse_decode_price_list_change_kind(SseDeserializer deserializer);
+
+@protected PriceListColumnMapping sse_decode_price_list_column_mapping(SseDeserializer deserializer);
+
+@protected PriceListImportRequest sse_decode_price_list_import_request(SseDeserializer deserializer);
+
+@protected PriceListImportResult sse_decode_price_list_import_result(SseDeserializer deserializer);
+
+@protected PriceListMatchKind sse_decode_price_list_match_kind(SseDeserializer deserializer);
+
+@protected PriceListPreview sse_decode_price_list_preview(SseDeserializer deserializer);
+
+@protected PriceProposal sse_decode_price_proposal(SseDeserializer deserializer);
+
+@protected PriceRiseItem sse_decode_price_rise_item(SseDeserializer deserializer);
+
+@protected PriceSource sse_decode_price_source(SseDeserializer deserializer);
+
+@protected PricingRule sse_decode_pricing_rule(SseDeserializer deserializer);
+
+@protected ProductAlternative sse_decode_product_alternative(SseDeserializer deserializer);
+
+@protected ProfitSummary sse_decode_profit_summary(SseDeserializer deserializer);
+
+@protected ReceivablesAgingRow sse_decode_receivables_aging_row(SseDeserializer deserializer);
+
+@protected RecordDeliveryRequest sse_decode_record_delivery_request(SseDeserializer deserializer);
+
+@protected RepriceResult sse_decode_reprice_result(SseDeserializer deserializer);
+
+@protected RepricedItem sse_decode_repriced_item(SseDeserializer deserializer);
+
+@protected RequisitionColumnMapping sse_decode_requisition_column_mapping(SseDeserializer deserializer);
+
+@protected RequisitionImportLine sse_decode_requisition_import_line(SseDeserializer deserializer);
+
+@protected RequisitionImportPreview sse_decode_requisition_import_preview(SseDeserializer deserializer);
+
+@protected RequisitionImportRequest sse_decode_requisition_import_request(SseDeserializer deserializer);
+
+@protected RequisitionMatchKind sse_decode_requisition_match_kind(SseDeserializer deserializer);
+
+@protected RequisitionTemplate sse_decode_requisition_template(SseDeserializer deserializer);
+
+@protected RequisitionTemplateLine sse_decode_requisition_template_line(SseDeserializer deserializer);
+
+@protected RequisitionTemplateLineRequest sse_decode_requisition_template_line_request(SseDeserializer deserializer);
+
+@protected ReturnLineRequest sse_decode_return_line_request(SseDeserializer deserializer);
+
+@protected ReturnReason sse_decode_return_reason(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SearchScope sse_decode_search_scope(SseDeserializer deserializer);
+
+@protected Ship sse_decode_ship(SseDeserializer deserializer);
+
+@protected ShipVisit sse_decode_ship_visit(SseDeserializer deserializer);
+
+@protected Stock sse_decode_stock(SseDeserializer deserializer);
+
+@protected StockMovement sse_decode_stock_movement(SseDeserializer deserializer);
+
+@protected StockMovementType sse_decode_stock_movement_type(SseDeserializer deserializer);
+
+@protected StockSummary sse_decode_stock_summary(SseDeserializer deserializer);
+
+@protected StockWithMovements sse_decode_stock_with_movements(SseDeserializer deserializer);
+
+@protected Supplier sse_decode_supplier(SseDeserializer deserializer);
+
+@protected SupplierInvoice sse_decode_supplier_invoice(SseDeserializer deserializer);
+
+@protected SupplierInvoiceLine sse_decode_supplier_invoice_line(SseDeserializer deserializer);
+
+@protected SupplierInvoiceLineMatch sse_decode_supplier_invoice_line_match(SseDeserializer deserializer);
+
+@protected SupplierInvoiceMatch sse_decode_supplier_invoice_match(SseDeserializer deserializer);
+
+@protected SupplierPayment sse_decode_supplier_payment(SseDeserializer deserializer);
+
+@protected SupplyItem sse_decode_supply_item(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UnitDimension sse_decode_unit_dimension(SseDeserializer deserializer);
+
+@protected UnitOfMeasure sse_decode_unit_of_measure(SseDeserializer deserializer);
+
+@protected UpdateCategoryRequest sse_decode_update_category_request(SseDeserializer deserializer);
+
+@protected UpdateCompanyProfileRequest sse_decode_update_company_profile_request(SseDeserializer deserializer);
+
+@protected UpdateCustomerRequest sse_decode_update_customer_request(SseDeserializer deserializer);
+
+@protected UpdateOrderChargeRequest sse_decode_update_order_charge_request(SseDeserializer deserializer);
+
+@protected UpdateOrderItemRequest sse_decode_update_order_item_request(SseDeserializer deserializer);
+
+@protected UpdateOrderRequest sse_decode_update_order_request(SseDeserializer deserializer);
+
+@protected UpdatePortRequest sse_decode_update_port_request(SseDeserializer deserializer);
+
+@protected UpdatePriceAgreementRequest sse_decode_update_price_agreement_request(SseDeserializer deserializer);
+
+@protected UpdatePricingRuleRequest sse_decode_update_pricing_rule_request(SseDeserializer deserializer);
+
+@protected UpdateRequisitionTemplateRequest sse_decode_update_requisition_template_request(SseDeserializer deserializer);
+
+@protected UpdateShipRequest sse_decode_update_ship_request(SseDeserializer deserializer);
+
+@protected UpdateShipVisitRequest sse_decode_update_ship_visit_request(SseDeserializer deserializer);
+
+@protected UpdateStockRequest sse_decode_update_stock_request(SseDeserializer deserializer);
+
+@protected UpdateSupplierRequest sse_decode_update_supplier_request(SseDeserializer deserializer);
+
+@protected UpdateSupplyItemRequest sse_decode_update_supply_item_request(SseDeserializer deserializer);
+
+@protected UpdateUnitRequest sse_decode_update_unit_request(SseDeserializer deserializer);
+
+@protected VisitStatus sse_decode_visit_status(SseDeserializer deserializer);
+
+@protected WarehouseType sse_decode_warehouse_type(SseDeserializer deserializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_allocation_method(AllocationMethod self, SseSerializer serializer);
+
+@protected void sse_encode_alternative_link(AlternativeLink self, SseSerializer serializer);
+
+@protected void sse_encode_attachment(Attachment self, SseSerializer serializer);
+
+@protected void sse_encode_attachment_entity(AttachmentEntity self, SseSerializer serializer);
+
+@protected void sse_encode_bonded_eligibility(BondedEligibility self, SseSerializer serializer);
+
+@protected void sse_encode_bonded_reconciliation(BondedReconciliation self, SseSerializer serializer);
+
+@protected void sse_encode_bonded_reconciliation_line(BondedReconciliationLine self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_attachment(Attachment self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_category(Category self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_charge_billing(ChargeBilling self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_complete_delivery_note_request(CompleteDeliveryNoteRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_contract_price(ContractPrice self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_attachment_request(CreateAttachmentRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_category_request(CreateCategoryRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_customer_contact_request(CreateCustomerContactRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_customer_request(CreateCustomerRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_delivery_note_request(CreateDeliveryNoteRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_equivalence_group_request(CreateEquivalenceGroupRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_invoice_request(CreateInvoiceRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_landed_cost_request(CreateLandedCostRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_order_charge_request(CreateOrderChargeRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_order_item_request(CreateOrderItemRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_order_request(CreateOrderRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_payment_request(CreatePaymentRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_port_request(CreatePortRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_price_agreement_line_request(CreatePriceAgreementLineRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_price_agreement_request(CreatePriceAgreementRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_pricing_rule_request(CreatePricingRuleRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_requisition_template_request(CreateRequisitionTemplateRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_return_request(CreateReturnRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_ship_request(CreateShipRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_ship_visit_request(CreateShipVisitRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_stock_movement_request(CreateStockMovementRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_stock_request(CreateStockRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_supplier_invoice_request(CreateSupplierInvoiceRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_supplier_payment_request(CreateSupplierPaymentRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_supplier_request(CreateSupplierRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_supply_item_request(CreateSupplyItemRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_create_unit_request(CreateUnitRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_credit_check(CreditCheck self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_customer(Customer self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_delivery_note(DeliveryNote self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_delivery_type(DeliveryType self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_equivalence_group(EquivalenceGroup self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_impa_catalog_entry(ImpaCatalogEntry self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_impa_catalog_import_request(ImpaCatalogImportRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_instantiate_template_request(InstantiateTemplateRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_invoice(Invoice self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_invoice_status(InvoiceStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_order_export_filter(OrderExportFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_order_return(OrderReturn self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_order_status(OrderStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_order_with_items(OrderWithItems self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_payment(Payment self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pdf_render_request(PdfRenderRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_pharmacist_sign_off_request(PharmacistSignOffRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_port(Port self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_price_agreement(PriceAgreement self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_price_list_import_request(PriceListImportRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_price_list_match_kind(PriceListMatchKind self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_product_alternative(ProductAlternative self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_record_delivery_request(RecordDeliveryRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_requisition_import_request(RequisitionImportRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_requisition_template(RequisitionTemplate self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ship(Ship self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ship_visit(ShipVisit self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stock(Stock self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stock_with_movements(StockWithMovements self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_supplier(Supplier self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_supplier_invoice(SupplierInvoice self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_supply_item(SupplyItem self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_category_request(UpdateCategoryRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_company_profile_request(UpdateCompanyProfileRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_customer_request(UpdateCustomerRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_order_charge_request(UpdateOrderChargeRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_order_item_request(UpdateOrderItemRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_order_request(UpdateOrderRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_port_request(UpdatePortRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_price_agreement_request(UpdatePriceAgreementRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_pricing_rule_request(UpdatePricingRuleRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_requisition_template_request(UpdateRequisitionTemplateRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_ship_request(UpdateShipRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_ship_visit_request(UpdateShipVisitRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_stock_request(UpdateStockRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_supplier_request(UpdateSupplierRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_supply_item_request(UpdateSupplyItemRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_update_unit_request(UpdateUnitRequest self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_visit_status(VisitStatus self, SseSerializer serializer);
+
+@protected void sse_encode_calendar_data(CalendarData self, SseSerializer serializer);
+
+@protected void sse_encode_calendar_event(CalendarEvent self, SseSerializer serializer);
+
+@protected void sse_encode_calendar_event_type(CalendarEventType self, SseSerializer serializer);
+
+@protected void sse_encode_category(Category self, SseSerializer serializer);
+
+@protected void sse_encode_charge_billing(ChargeBilling self, SseSerializer serializer);
+
+@protected void sse_encode_charge_type(ChargeType self, SseSerializer serializer);
+
+@protected void sse_encode_company_profile(CompanyProfile self, SseSerializer serializer);
+
+@protected void sse_encode_complete_delivery_note_request(CompleteDeliveryNoteRequest self, SseSerializer serializer);
+
+@protected void sse_encode_compliance_issue(ComplianceIssue self, SseSerializer serializer);
+
+@protected void sse_encode_compliance_issue_kind(ComplianceIssueKind self, SseSerializer serializer);
+
+@protected void sse_encode_contract_price(ContractPrice self, SseSerializer serializer);
+
+@protected void sse_encode_create_attachment_request(CreateAttachmentRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_category_request(CreateCategoryRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_customer_contact_request(CreateCustomerContactRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_customer_request(CreateCustomerRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_delivery_note_request(CreateDeliveryNoteRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_equivalence_group_request(CreateEquivalenceGroupRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_invoice_request(CreateInvoiceRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_landed_cost_request(CreateLandedCostRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_order_charge_request(CreateOrderChargeRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_order_item_request(CreateOrderItemRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_order_request(CreateOrderRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_payment_request(CreatePaymentRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_port_request(CreatePortRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_price_agreement_line_request(CreatePriceAgreementLineRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_price_agreement_request(CreatePriceAgreementRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_pricing_rule_request(CreatePricingRuleRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_requisition_template_request(CreateRequisitionTemplateRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_return_request(CreateReturnRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_ship_request(CreateShipRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_ship_visit_request(CreateShipVisitRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_stock_movement_request(CreateStockMovementRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_stock_request(CreateStockRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_supplier_invoice_line_request(CreateSupplierInvoiceLineRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_supplier_invoice_request(CreateSupplierInvoiceRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_supplier_payment_request(CreateSupplierPaymentRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_supplier_request(CreateSupplierRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_supply_item_request(CreateSupplyItemRequest self, SseSerializer serializer);
+
+@protected void sse_encode_create_unit_request(CreateUnitRequest self, SseSerializer serializer);
+
+@protected void sse_encode_credit_check(CreditCheck self, SseSerializer serializer);
+
+@protected void sse_encode_credit_note(CreditNote self, SseSerializer serializer);
+
+@protected void sse_encode_currency_exposure(CurrencyExposure self, SseSerializer serializer);
+
+@protected void sse_encode_currency_rounding(CurrencyRounding self, SseSerializer serializer);
+
+@protected void sse_encode_customer(Customer self, SseSerializer serializer);
+
+@protected void sse_encode_customer_balance(CustomerBalance self, SseSerializer serializer);
+
+@protected void sse_encode_customer_contact(CustomerContact self, SseSerializer serializer);
+
+@protected void sse_encode_dangerous_goods_class_total(DangerousGoodsClassTotal self, SseSerializer serializer);
+
+@protected void sse_encode_dangerous_goods_line(DangerousGoodsLine self, SseSerializer serializer);
+
+@protected void sse_encode_dangerous_goods_summary(DangerousGoodsSummary self, SseSerializer serializer);
 
-@protected UpdateOrderItemRequest dco_decode_update_order_item_request(dynamic raw);
+@protected void sse_encode_delivery_line(DeliveryLine self, SseSerializer serializer);
 
-@protected UpdateOrderRequest dco_decode_update_order_request(dynamic raw);
+@protected void sse_encode_delivery_note(DeliveryNote self, SseSerializer serializer);
 
-@protected UpdatePortRequest dco_decode_update_port_request(dynamic raw);
+@protected void sse_encode_delivery_note_attachment(DeliveryNoteAttachment self, SseSerializer serializer);
 
-@protected UpdateShipRequest dco_decode_update_ship_request(dynamic raw);
+@protected void sse_encode_delivery_note_attachment_kind(DeliveryNoteAttachmentKind self, SseSerializer serializer);
 
-@protected UpdateShipVisitRequest dco_decode_update_ship_visit_request(dynamic raw);
+@protected void sse_encode_delivery_note_line(DeliveryNoteLine self, SseSerializer serializer);
 
-@protected UpdateStockRequest dco_decode_update_stock_request(dynamic raw);
+@protected void sse_encode_delivery_note_status(DeliveryNoteStatus self, SseSerializer serializer);
 
-@protected UpdateSupplierRequest dco_decode_update_supplier_request(dynamic raw);
+@protected void sse_encode_delivery_type(DeliveryType self, SseSerializer serializer);
 
-@protected UpdateSupplyItemRequest dco_decode_update_supply_item_request(dynamic raw);
+@protected void sse_encode_equivalence_group(EquivalenceGroup self, SseSerializer serializer);
 
-@protected VisitStatus dco_decode_visit_status(dynamic raw);
+@protected void sse_encode_export_result(ExportResult self, SseSerializer serializer);
 
-@protected String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
 
-@protected bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_fulfilment_status(FulfilmentStatus self, SseSerializer serializer);
 
-@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
 
-@protected CreateOrderItemRequest sse_decode_box_autoadd_create_order_item_request(SseDeserializer deserializer);
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
 
-@protected CreateOrderRequest sse_decode_box_autoadd_create_order_request(SseDeserializer deserializer);
+@protected void sse_encode_impa_catalog_entry(ImpaCatalogEntry self, SseSerializer serializer);
 
-@protected CreatePortRequest sse_decode_box_autoadd_create_port_request(SseDeserializer deserializer);
+@protected void sse_encode_impa_catalog_import_request(ImpaCatalogImportRequest self, SseSerializer serializer);
 
-@protected CreateShipRequest sse_decode_box_autoadd_create_ship_request(SseDeserializer deserializer);
+@protected void sse_encode_impa_catalog_import_result(ImpaCatalogImportResult self, SseSerializer serializer);
 
-@protected CreateShipVisitRequest sse_decode_box_autoadd_create_ship_visit_request(SseDeserializer deserializer);
+@protected void sse_encode_impa_code_issue(ImpaCodeIssue self, SseSerializer serializer);
 
-@protected CreateStockMovementRequest sse_decode_box_autoadd_create_stock_movement_request(SseDeserializer deserializer);
+@protected void sse_encode_impa_code_problem(ImpaCodeProblem self, SseSerializer serializer);
 
-@protected CreateStockRequest sse_decode_box_autoadd_create_stock_request(SseDeserializer deserializer);
+@protected void sse_encode_impa_column_mapping(ImpaColumnMapping self, SseSerializer serializer);
 
-@protected CreateSupplierRequest sse_decode_box_autoadd_create_supplier_request(SseDeserializer deserializer);
+@protected void sse_encode_impa_section(ImpaSection self, SseSerializer serializer);
 
-@protected CreateSupplyItemRequest sse_decode_box_autoadd_create_supply_item_request(SseDeserializer deserializer);
+@protected void sse_encode_instantiate_template_request(InstantiateTemplateRequest self, SseSerializer serializer);
 
-@protected DeliveryType sse_decode_box_autoadd_delivery_type(SseDeserializer deserializer);
+@protected void sse_encode_invoice(Invoice self, SseSerializer serializer);
 
-@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+@protected void sse_encode_invoice_status(InvoiceStatus self, SseSerializer serializer);
 
-@protected int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+@protected void sse_encode_item_delivery(ItemDelivery self, SseSerializer serializer);
 
-@protected OrderStatus sse_decode_box_autoadd_order_status(SseDeserializer deserializer);
+@protected void sse_encode_item_profit(ItemProfit self, SseSerializer serializer);
 
-@protected OrderWithItems sse_decode_box_autoadd_order_with_items(SseDeserializer deserializer);
+@protected void sse_encode_item_weight(ItemWeight self, SseSerializer serializer);
 
-@protected Port sse_decode_box_autoadd_port(SseDeserializer deserializer);
+@protected void sse_encode_landed_cost(LandedCost self, SseSerializer serializer);
 
-@protected Ship sse_decode_box_autoadd_ship(SseDeserializer deserializer);
+@protected void sse_encode_landed_cost_allocation(LandedCostAllocation self, SseSerializer serializer);
 
-@protected ShipVisit sse_decode_box_autoadd_ship_visit(SseDeserializer deserializer);
+@protected void sse_encode_landed_cost_type(LandedCostType self, SseSerializer serializer);
 
-@protected Stock sse_decode_box_autoadd_stock(SseDeserializer deserializer);
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
-@protected StockWithMovements sse_decode_box_autoadd_stock_with_movements(SseDeserializer deserializer);
+@protected void sse_encode_list_attachment(List<Attachment> self, SseSerializer serializer);
 
-@protected Supplier sse_decode_box_autoadd_supplier(SseDeserializer deserializer);
+@protected void sse_encode_list_bonded_reconciliation_line(List<BondedReconciliationLine> self, SseSerializer serializer);
 
-@protected SupplyItem sse_decode_box_autoadd_supply_item(SseDeserializer deserializer);
+@protected void sse_encode_list_calendar_event(List<CalendarEvent> self, SseSerializer serializer);
 
-@protected UpdateOrderItemRequest sse_decode_box_autoadd_update_order_item_request(SseDeserializer deserializer);
+@protected void sse_encode_list_category(List<Category> self, SseSerializer serializer);
 
-@protected UpdateOrderRequest sse_decode_box_autoadd_update_order_request(SseDeserializer deserializer);
+@protected void sse_encode_list_compliance_issue(List<ComplianceIssue> self, SseSerializer serializer);
 
-@protected UpdatePortRequest sse_decode_box_autoadd_update_port_request(SseDeserializer deserializer);
+@protected void sse_encode_list_create_customer_contact_request(List<CreateCustomerContactRequest> self, SseSerializer serializer);
 
-@protected UpdateShipRequest sse_decode_box_autoadd_update_ship_request(SseDeserializer deserializer);
+@protected void sse_encode_list_create_price_agreement_line_request(List<CreatePriceAgreementLineRequest> self, SseSerializer serializer);
 
-@protected UpdateShipVisitRequest sse_decode_box_autoadd_update_ship_visit_request(SseDeserializer deserializer);
+@protected void sse_encode_list_create_supplier_invoice_line_request(List<CreateSupplierInvoiceLineRequest> self, SseSerializer serializer);
 
-@protected UpdateStockRequest sse_decode_box_autoadd_update_stock_request(SseDeserializer deserializer);
+@protected void sse_encode_list_credit_note(List<CreditNote> self, SseSerializer serializer);
 
-@protected UpdateSupplierRequest sse_decode_box_autoadd_update_supplier_request(SseDeserializer deserializer);
+@protected void sse_encode_list_currency_exposure(List<CurrencyExposure> self, SseSerializer serializer);
 
-@protected UpdateSupplyItemRequest sse_decode_box_autoadd_update_supply_item_request(SseDeserializer deserializer);
+@protected void sse_encode_list_currency_rounding(List<CurrencyRounding> self, SseSerializer serializer);
 
-@protected VisitStatus sse_decode_box_autoadd_visit_status(SseDeserializer deserializer);
+@protected void sse_encode_list_customer(List<Customer> self, SseSerializer serializer);
 
-@protected CalendarData sse_decode_calendar_data(SseDeserializer deserializer);
+@protected void sse_encode_list_customer_balance(List<CustomerBalance> self, SseSerializer serializer);
 
-@protected CalendarEvent sse_decode_calendar_event(SseDeserializer deserializer);
+@protected void sse_encode_list_customer_contact(List<CustomerContact> self, SseSerializer serializer);
 
-@protected CalendarEventType sse_decode_calendar_event_type(SseDeserializer deserializer);
+@protected void sse_encode_list_dangerous_goods_class_total(List<DangerousGoodsClassTotal> self, SseSerializer serializer);
 
-@protected CreateOrderItemRequest sse_decode_create_order_item_request(SseDeserializer deserializer);
+@protected void sse_encode_list_dangerous_goods_line(List<DangerousGoodsLine> self, SseSerializer serializer);
 
-@protected CreateOrderRequest sse_decode_create_order_request(SseDeserializer deserializer);
+@protected void sse_encode_list_delivery_line(List<DeliveryLine> self, SseSerializer serializer);
 
-@protected CreatePortRequest sse_decode_create_port_request(SseDeserializer deserializer);
+@protected void sse_encode_list_delivery_note(List<DeliveryNote> self, SseSerializer serializer);
 
-@protected CreateShipRequest sse_decode_create_ship_request(SseDeserializer deserializer);
+@protected void sse_encode_list_delivery_note_attachment(List<DeliveryNoteAttachment> self, SseSerializer serializer);
 
-@protected CreateShipVisitRequest sse_decode_create_ship_visit_request(SseDeserializer deserializer);
+@protected void sse_encode_list_delivery_note_line(List<DeliveryNoteLine> self, SseSerializer serializer);
 
-@protected CreateStockMovementRequest sse_decode_create_stock_movement_request(SseDeserializer deserializer);
+@protected void sse_encode_list_equivalence_group(List<EquivalenceGroup> self, SseSerializer serializer);
 
-@protected CreateStockRequest sse_decode_create_stock_request(SseDeserializer deserializer);
+@protected void sse_encode_list_impa_catalog_entry(List<ImpaCatalogEntry> self, SseSerializer serializer);
 
-@protected CreateSupplierRequest sse_decode_create_supplier_request(SseDeserializer deserializer);
+@protected void sse_encode_list_impa_code_issue(List<ImpaCodeIssue> self, SseSerializer serializer);
 
-@protected CreateSupplyItemRequest sse_decode_create_supply_item_request(SseDeserializer deserializer);
+@protected void sse_encode_list_impa_section(List<ImpaSection> self, SseSerializer serializer);
 
-@protected DeliveryType sse_decode_delivery_type(SseDeserializer deserializer);
+@protected void sse_encode_list_invoice(List<Invoice> self, SseSerializer serializer);
 
-@protected double sse_decode_f_64(SseDeserializer deserializer);
+@protected void sse_encode_list_item_delivery(List<ItemDelivery> self, SseSerializer serializer);
 
-@protected int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_list_item_weight(List<ItemWeight> self, SseSerializer serializer);
 
-@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+@protected void sse_encode_list_landed_cost(List<LandedCost> self, SseSerializer serializer);
 
-@protected ItemProfit sse_decode_item_profit(SseDeserializer deserializer);
+@protected void sse_encode_list_landed_cost_allocation(List<LandedCostAllocation> self, SseSerializer serializer);
 
-@protected List<CalendarEvent> sse_decode_list_calendar_event(SseDeserializer deserializer);
+@protected void sse_encode_list_off_contract_line(List<OffContractLine> self, SseSerializer serializer);
 
-@protected List<Order> sse_decode_list_order(SseDeserializer deserializer);
+@protected void sse_encode_list_order(List<Order> self, SseSerializer serializer);
 
-@protected List<OrderItem> sse_decode_list_order_item(SseDeserializer deserializer);
+@protected void sse_encode_list_order_charge(List<OrderCharge> self, SseSerializer serializer);
 
-@protected List<OrderProfitInfo> sse_decode_list_order_profit_info(SseDeserializer deserializer);
+@protected void sse_encode_list_order_item(List<OrderItem> self, SseSerializer serializer);
 
-@protected List<Port> sse_decode_list_port(SseDeserializer deserializer);
+@protected void sse_encode_list_order_link(List<OrderLink> self, SseSerializer serializer);
 
-@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_list_order_profit_info(List<OrderProfitInfo> self, SseSerializer serializer);
 
-@protected List<Ship> sse_decode_list_ship(SseDeserializer deserializer);
+@protected void sse_encode_list_order_return(List<OrderReturn> self, SseSerializer serializer);
 
-@protected List<ShipVisit> sse_decode_list_ship_visit(SseDeserializer deserializer);
+@protected void sse_encode_list_order_return_line(List<OrderReturnLine> self, SseSerializer serializer);
 
-@protected List<Stock> sse_decode_list_stock(SseDeserializer deserializer);
+@protected void sse_encode_list_pack_size(List<PackSize> self, SseSerializer serializer);
 
-@protected List<StockMovement> sse_decode_list_stock_movement(SseDeserializer deserializer);
+@protected void sse_encode_list_payable_due(List<PayableDue> self, SseSerializer serializer);
 
-@protected List<Supplier> sse_decode_list_supplier(SseDeserializer deserializer);
+@protected void sse_encode_list_payment(List<Payment> self, SseSerializer serializer);
 
-@protected List<SupplyItem> sse_decode_list_supply_item(SseDeserializer deserializer);
+@protected void sse_encode_list_payment_allocation(List<PaymentAllocation> self, SseSerializer serializer);
 
-@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+@protected void sse_encode_list_payment_allocation_request(List<PaymentAllocationRequest> self, SseSerializer serializer);
 
-@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+@protected void sse_encode_list_port(List<Port> self, SseSerializer serializer);
 
-@protected DeliveryType? sse_decode_opt_box_autoadd_delivery_type(SseDeserializer deserializer);
+@protected void sse_encode_list_price_agreement(List<PriceAgreement> self, SseSerializer serializer);
 
-@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+@protected void sse_encode_list_price_agreement_line(List<PriceAgreementLine> self, SseSerializer serializer);
 
-@protected int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+@protected void sse_encode_list_price_change(List<PriceChange> self, SseSerializer serializer);
 
-@protected OrderStatus? sse_decode_opt_box_autoadd_order_status(SseDeserializer deserializer);
+@protected void sse_encode_list_price_list_change(List<PriceListChange> self, SseSerializer serializer);
 
-@protected OrderWithItems? sse_decode_opt_box_autoadd_order_with_items(SseDeserializer deserializer);
+@protected void sse_encode_list_price_rise_item(List<PriceRiseItem> self, SseSerializer serializer);
 
-@protected Port? sse_decode_opt_box_autoadd_port(SseDeserializer deserializer);
+@protected void sse_encode_list_pricing_rule(List<PricingRule> self, SseSerializer serializer);
 
-@protected Ship? sse_decode_opt_box_autoadd_ship(SseDeserializer deserializer);
+@protected void sse_encode_list_prim_i_32_loose(List<int> self, SseSerializer serializer);
 
-@protected ShipVisit? sse_decode_opt_box_autoadd_ship_visit(SseDeserializer deserializer);
+@protected void sse_encode_list_prim_i_32_strict(Int32List self, SseSerializer serializer);
 
-@protected Stock? sse_decode_opt_box_autoadd_stock(SseDeserializer deserializer);
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
 
-@protected StockWithMovements? sse_decode_opt_box_autoadd_stock_with_movements(SseDeserializer deserializer);
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
 
-@protected Supplier? sse_decode_opt_box_autoadd_supplier(SseDeserializer deserializer);
+@protected void sse_encode_list_product_alternative(List<ProductAlternative> self, SseSerializer serializer);
 
-@protected SupplyItem? sse_decode_opt_box_autoadd_supply_item(SseDeserializer deserializer);
+@protected void sse_encode_list_receivables_aging_row(List<ReceivablesAgingRow> self, SseSerializer serializer);
 
-@protected VisitStatus? sse_decode_opt_box_autoadd_visit_status(SseDeserializer deserializer);
+@protected void sse_encode_list_repriced_item(List<RepricedItem> self, SseSerializer serializer);
 
-@protected Order sse_decode_order(SseDeserializer deserializer);
+@protected void sse_encode_list_requisition_import_line(List<RequisitionImportLine> self, SseSerializer serializer);
 
-@protected OrderItem sse_decode_order_item(SseDeserializer deserializer);
+@protected void sse_encode_list_requisition_template(List<RequisitionTemplate> self, SseSerializer serializer);
 
-@protected OrderProfitInfo sse_decode_order_profit_info(SseDeserializer deserializer);
+@protected void sse_encode_list_requisition_template_line(List<RequisitionTemplateLine> self, SseSerializer serializer);
 
-@protected OrderStatus sse_decode_order_status(SseDeserializer deserializer);
+@protected void sse_encode_list_requisition_template_line_request(List<RequisitionTemplateLineRequest> self, SseSerializer serializer);
 
-@protected OrderTotals sse_decode_order_totals(SseDeserializer deserializer);
+@protected void sse_encode_list_return_line_request(List<ReturnLineRequest> self, SseSerializer serializer);
 
-@protected OrderWithItems sse_decode_order_with_items(SseDeserializer deserializer);
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
 
-@protected Port sse_decode_port(SseDeserializer deserializer);
+@protected void sse_encode_list_search_scope(List<SearchScope> self, SseSerializer serializer);
 
-@protected ProfitSummary sse_decode_profit_summary(SseDeserializer deserializer);
+@protected void sse_encode_list_ship(List<Ship> self, SseSerializer serializer);
 
-@protected Ship sse_decode_ship(SseDeserializer deserializer);
+@protected void sse_encode_list_ship_visit(List<ShipVisit> self, SseSerializer serializer);
 
-@protected ShipVisit sse_decode_ship_visit(SseDeserializer deserializer);
+@protected void sse_encode_list_stock(List<Stock> self, SseSerializer serializer);
 
-@protected Stock sse_decode_stock(SseDeserializer deserializer);
+@protected void sse_encode_list_stock_movement(List<StockMovement> self, SseSerializer serializer);
 
-@protected StockMovement sse_decode_stock_movement(SseDeserializer deserializer);
+@protected void sse_encode_list_supplier(List<Supplier> self, SseSerializer serializer);
 
-@protected StockMovementType sse_decode_stock_movement_type(SseDeserializer deserializer);
+@protected void sse_encode_list_supplier_invoice(List<SupplierInvoice> self, SseSerializer serializer);
 
-@protected StockSummary sse_decode_stock_summary(SseDeserializer deserializer);
+@protected void sse_encode_list_supplier_invoice_line(List<SupplierInvoiceLine> self, SseSerializer serializer);
 
-@protected StockWithMovements sse_decode_stock_with_movements(SseDeserializer deserializer);
+@protected void sse_encode_list_supplier_invoice_line_match(List<SupplierInvoiceLineMatch> self, SseSerializer serializer);
 
-@protected Supplier sse_decode_supplier(SseDeserializer deserializer);
+@protected void sse_encode_list_supplier_payment(List<SupplierPayment> self, SseSerializer serializer);
 
-@protected SupplyItem sse_decode_supply_item(SseDeserializer deserializer);
+@protected void sse_encode_list_supply_item(List<SupplyItem> self, SseSerializer serializer);
 
-@protected int sse_decode_u_8(SseDeserializer deserializer);
+@protected void sse_encode_list_unit_of_measure(List<UnitOfMeasure> self, SseSerializer serializer);
 
-@protected void sse_decode_unit(SseDeserializer deserializer);
+@protected void sse_encode_match_status(MatchStatus self, SseSerializer serializer);
 
-@protected UpdateOrderItemRequest sse_decode_update_order_item_request(SseDeserializer deserializer);
+@protected void sse_encode_off_contract_line(OffContractLine self, SseSerializer serializer);
 
-@protected UpdateOrderRequest sse_decode_update_order_request(SseDeserializer deserializer);
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
-@protected UpdatePortRequest sse_decode_update_port_request(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_attachment(Attachment? self, SseSerializer serializer);
 
-@protected UpdateShipRequest sse_decode_update_ship_request(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
-@protected UpdateShipVisitRequest sse_decode_update_ship_visit_request(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_category(Category? self, SseSerializer serializer);
 
-@protected UpdateStockRequest sse_decode_update_stock_request(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_charge_billing(ChargeBilling? self, SseSerializer serializer);
 
-@protected UpdateSupplierRequest sse_decode_update_supplier_request(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_contract_price(ContractPrice? self, SseSerializer serializer);
 
-@protected UpdateSupplyItemRequest sse_decode_update_supply_item_request(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_credit_check(CreditCheck? self, SseSerializer serializer);
 
-@protected VisitStatus sse_decode_visit_status(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_customer(Customer? self, SseSerializer serializer);
 
-@protected void sse_encode_String(String self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_delivery_note(DeliveryNote? self, SseSerializer serializer);
 
-@protected void sse_encode_bool(bool self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_delivery_type(DeliveryType? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_equivalence_group(EquivalenceGroup? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_order_item_request(CreateOrderItemRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_order_request(CreateOrderRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_port_request(CreatePortRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_impa_catalog_entry(ImpaCatalogEntry? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_ship_request(CreateShipRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_invoice(Invoice? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_ship_visit_request(CreateShipVisitRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_invoice_status(InvoiceStatus? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_stock_movement_request(CreateStockMovementRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_order_return(OrderReturn? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_stock_request(CreateStockRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_order_status(OrderStatus? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_supplier_request(CreateSupplierRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_order_with_items(OrderWithItems? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_create_supply_item_request(CreateSupplyItemRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_payment(Payment? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_delivery_type(DeliveryType self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_port(Port? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_price_agreement(PriceAgreement? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_price_list_match_kind(PriceListMatchKind? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_order_status(OrderStatus self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_product_alternative(ProductAlternative? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_order_with_items(OrderWithItems self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_requisition_template(RequisitionTemplate? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_port(Port self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_ship(Ship? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_ship(Ship self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_ship_visit(ShipVisit? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_ship_visit(ShipVisit self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_stock(Stock? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_stock(Stock self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_stock_with_movements(StockWithMovements? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_stock_with_movements(StockWithMovements self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_supplier(Supplier? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_supplier(Supplier self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_supplier_invoice(SupplierInvoice? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_supply_item(SupplyItem self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_supply_item(SupplyItem? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_order_item_request(UpdateOrderItemRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_box_autoadd_visit_status(VisitStatus? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_order_request(UpdateOrderRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_port_request(UpdatePortRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_list_prim_i_32_strict(Int32List? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_ship_request(UpdateShipRequest self, SseSerializer serializer);
+@protected void sse_encode_opt_list_requisition_template_line_request(List<RequisitionTemplateLineRequest>? self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_ship_visit_request(UpdateShipVisitRequest self, SseSerializer serializer);
+@protected void sse_encode_order(Order self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_stock_request(UpdateStockRequest self, SseSerializer serializer);
+@protected void sse_encode_order_charge(OrderCharge self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_supplier_request(UpdateSupplierRequest self, SseSerializer serializer);
+@protected void sse_encode_order_compliance(OrderCompliance self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_update_supply_item_request(UpdateSupplyItemRequest self, SseSerializer serializer);
+@protected void sse_encode_order_export_filter(OrderExportFilter self, SseSerializer serializer);
 
-@protected void sse_encode_box_autoadd_visit_status(VisitStatus self, SseSerializer serializer);
+@protected void sse_encode_order_item(OrderItem self, SseSerializer serializer);
 
-@protected void sse_encode_calendar_data(CalendarData self, SseSerializer serializer);
+@protected void sse_encode_order_link(OrderLink self, SseSerializer serializer);
 
-@protected void sse_encode_calendar_event(CalendarEvent self, SseSerializer serializer);
+@protected void sse_encode_order_link_type(OrderLinkType self, SseSerializer serializer);
 
-@protected void sse_encode_calendar_event_type(CalendarEventType self, SseSerializer serializer);
+@protected void sse_encode_order_profit_info(OrderProfitInfo self, SseSerializer serializer);
 
-@protected void sse_encode_create_order_item_request(CreateOrderItemRequest self, SseSerializer serializer);
+@protected void sse_encode_order_return(OrderReturn self, SseSerializer serializer);
 
-@protected void sse_encode_create_order_request(CreateOrderRequest self, SseSerializer serializer);
+@protected void sse_encode_order_return_line(OrderReturnLine self, SseSerializer serializer);
 
-@protected void sse_encode_create_port_request(CreatePortRequest self, SseSerializer serializer);
+@protected void sse_encode_order_status(OrderStatus self, SseSerializer serializer);
 
-@protected void sse_encode_create_ship_request(CreateShipRequest self, SseSerializer serializer);
+@protected void sse_encode_order_totals(OrderTotals self, SseSerializer serializer);
 
-@protected void sse_encode_create_ship_visit_request(CreateShipVisitRequest self, SseSerializer serializer);
+@protected void sse_encode_order_with_items(OrderWithItems self, SseSerializer serializer);
 
-@protected void sse_encode_create_stock_movement_request(CreateStockMovementRequest self, SseSerializer serializer);
+@protected void sse_encode_pack_size(PackSize self, SseSerializer serializer);
 
-@protected void sse_encode_create_stock_request(CreateStockRequest self, SseSerializer serializer);
+@protected void sse_encode_payable_due(PayableDue self, SseSerializer serializer);
 
-@protected void sse_encode_create_supplier_request(CreateSupplierRequest self, SseSerializer serializer);
+@protected void sse_encode_payment(Payment self, SseSerializer serializer);
 
-@protected void sse_encode_create_supply_item_request(CreateSupplyItemRequest self, SseSerializer serializer);
+@protected void sse_encode_payment_allocation(PaymentAllocation self, SseSerializer serializer);
 
-@protected void sse_encode_delivery_type(DeliveryType self, SseSerializer serializer);
+@protected void sse_encode_payment_allocation_request(PaymentAllocationRequest self, SseSerializer serializer);
 
-@protected void sse_encode_f_64(double self, SseSerializer serializer);
+@protected void sse_encode_payment_method(PaymentMethod self, SseSerializer serializer);
 
-@protected void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_pdf_document_kind(PdfDocumentKind self, SseSerializer serializer);
 
-@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+@protected void sse_encode_pdf_render_request(PdfRenderRequest self, SseSerializer serializer);
 
-@protected void sse_encode_item_profit(ItemProfit self, SseSerializer serializer);
+@protected void sse_encode_pdf_render_result(PdfRenderResult self, SseSerializer serializer);
 
-@protected void sse_encode_list_calendar_event(List<CalendarEvent> self, SseSerializer serializer);
+@protected void sse_encode_pharmacist_sign_off_request(PharmacistSignOffRequest self, SseSerializer serializer);
 
-@protected void sse_encode_list_order(List<Order> self, SseSerializer serializer);
+@protected void sse_encode_port(Port self, SseSerializer serializer);
 
-@protected void sse_encode_list_order_item(List<OrderItem> self, SseSerializer serializer);
+@protected void sse_encode_price_agreement(PriceAgreement self, SseSerializer serializer);
 
-@protected void sse_encode_list_order_profit_info(List<OrderProfitInfo> self, SseSerializer serializer);
+@protected void sse_encode_price_agreement_line(PriceAgreementLine self, SseSerializer serializer);
 
-@protected void sse_encode_list_port(List<Port> self, SseSerializer serializer);
+@protected void sse_encode_price_change(PriceChange self, SseSerializer serializer);
 
-@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+@protected void sse_encode_price_change_source(PriceChangeSource self, SseSerializer serializer);
 
-@protected void sse_encode_list_ship(List<Ship> self, SseSerializer serializer);
+@protected void sse_encode_price_list_change(PriceListChange self, SseSerializer serializer);
 
-@protected void sse_encode_list_ship_visit(List<ShipVisit> self, SseSerializer serializer);
+@protected void sse_encode_price_list_change_kind(PriceListChangeKind self, SseSerializer serializer);
 
-@protected void sse_encode_list_stock(List<Stock> self, SseSerializer serializer);
+@protected void sse_encode_price_list_column_mapping(PriceListColumnMapping self, SseSerializer serializer);
 
-@protected void sse_encode_list_stock_movement(List<StockMovement> self, SseSerializer serializer);
+@protected void sse_encode_price_list_import_request(PriceListImportRequest self, SseSerializer serializer);
 
-@protected void sse_encode_list_supplier(List<Supplier> self, SseSerializer serializer);
+@protected void sse_encode_price_list_import_result(PriceListImportResult self, SseSerializer serializer);
 
-@protected void sse_encode_list_supply_item(List<SupplyItem> self, SseSerializer serializer);
+@protected void sse_encode_price_list_match_kind(PriceListMatchKind self, SseSerializer serializer);
 
-@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected void sse_encode_price_list_preview(PriceListPreview self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+@protected void sse_encode_price_proposal(PriceProposal self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_delivery_type(DeliveryType? self, SseSerializer serializer);
+@protected void sse_encode_price_rise_item(PriceRiseItem self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+@protected void sse_encode_price_source(PriceSource self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+@protected void sse_encode_pricing_rule(PricingRule self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_order_status(OrderStatus? self, SseSerializer serializer);
+@protected void sse_encode_product_alternative(ProductAlternative self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_order_with_items(OrderWithItems? self, SseSerializer serializer);
+@protected void sse_encode_profit_summary(ProfitSummary self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_port(Port? self, SseSerializer serializer);
+@protected void sse_encode_receivables_aging_row(ReceivablesAgingRow self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_ship(Ship? self, SseSerializer serializer);
+@protected void sse_encode_record_delivery_request(RecordDeliveryRequest self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_ship_visit(ShipVisit? self, SseSerializer serializer);
+@protected void sse_encode_reprice_result(RepriceResult self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_stock(Stock? self, SseSerializer serializer);
+@protected void sse_encode_repriced_item(RepricedItem self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_stock_with_movements(StockWithMovements? self, SseSerializer serializer);
+@protected void sse_encode_requisition_column_mapping(RequisitionColumnMapping self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_supplier(Supplier? self, SseSerializer serializer);
+@protected void sse_encode_requisition_import_line(RequisitionImportLine self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_supply_item(SupplyItem? self, SseSerializer serializer);
+@protected void sse_encode_requisition_import_preview(RequisitionImportPreview self, SseSerializer serializer);
 
-@protected void sse_encode_opt_box_autoadd_visit_status(VisitStatus? self, SseSerializer serializer);
+@protected void sse_encode_requisition_import_request(RequisitionImportRequest self, SseSerializer serializer);
 
-@protected void sse_encode_order(Order self, SseSerializer serializer);
+@protected void sse_encode_requisition_match_kind(RequisitionMatchKind self, SseSerializer serializer);
 
-@protected void sse_encode_order_item(OrderItem self, SseSerializer serializer);
+@protected void sse_encode_requisition_template(RequisitionTemplate self, SseSerializer serializer);
 
-@protected void sse_encode_order_profit_info(OrderProfitInfo self, SseSerializer serializer);
+@protected void sse_encode_requisition_template_line(RequisitionTemplateLine self, SseSerializer serializer);
 
-@protected void sse_encode_order_status(OrderStatus self, SseSerializer serializer);
+@protected void sse_encode_requisition_template_line_request(RequisitionTemplateLineRequest self, SseSerializer serializer);
 
-@protected void sse_encode_order_totals(OrderTotals self, SseSerializer serializer);
+@protected void sse_encode_return_line_request(ReturnLineRequest self, SseSerializer serializer);
 
-@protected void sse_encode_order_with_items(OrderWithItems self, SseSerializer serializer);
+@protected void sse_encode_return_reason(ReturnReason self, SseSerializer serializer);
 
-@protected void sse_encode_port(Port self, SseSerializer serializer);
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
 
-@protected void sse_encode_profit_summary(ProfitSummary self, SseSerializer serializer);
+@protected void sse_encode_search_scope(SearchScope self, SseSerializer serializer);
 
 @protected void sse_encode_ship(Ship self, SseSerializer serializer);
 
@@ -633,18 +2233,46 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected void sse_encode_supplier(Supplier self, SseSerializer serializer);
 
+@protected void sse_encode_supplier_invoice(SupplierInvoice self, SseSerializer serializer);
+
+@protected void sse_encode_supplier_invoice_line(SupplierInvoiceLine self, SseSerializer serializer);
+
+@protected void sse_encode_supplier_invoice_line_match(SupplierInvoiceLineMatch self, SseSerializer serializer);
+
+@protected void sse_encode_supplier_invoice_match(SupplierInvoiceMatch self, SseSerializer serializer);
+
+@protected void sse_encode_supplier_payment(SupplierPayment self, SseSerializer serializer);
+
 @protected void sse_encode_supply_item(SupplyItem self, SseSerializer serializer);
 
 @protected void sse_encode_u_8(int self, SseSerializer serializer);
 
 @protected void sse_encode_unit(void self, SseSerializer serializer);
 
+@protected void sse_encode_unit_dimension(UnitDimension self, SseSerializer serializer);
+
+@protected void sse_encode_unit_of_measure(UnitOfMeasure self, SseSerializer serializer);
+
+@protected void sse_encode_update_category_request(UpdateCategoryRequest self, SseSerializer serializer);
+
+@protected void sse_encode_update_company_profile_request(UpdateCompanyProfileRequest self, SseSerializer serializer);
+
+@protected void sse_encode_update_customer_request(UpdateCustomerRequest self, SseSerializer serializer);
+
+@protected void sse_encode_update_order_charge_request(UpdateOrderChargeRequest self, SseSerializer serializer);
+
 @protected void sse_encode_update_order_item_request(UpdateOrderItemRequest self, SseSerializer serializer);
 
 @protected void sse_encode_update_order_request(UpdateOrderRequest self, SseSerializer serializer);
 
 @protected void sse_encode_update_port_request(UpdatePortRequest self, SseSerializer serializer);
 
+@protected void sse_encode_update_price_agreement_request(UpdatePriceAgreementRequest self, SseSerializer serializer);
+
+@protected void sse_encode_update_pricing_rule_request(UpdatePricingRuleRequest self, SseSerializer serializer);
+
+@protected void sse_encode_update_requisition_template_request(UpdateRequisitionTemplateRequest self, SseSerializer serializer);
+
 @protected void sse_encode_update_ship_request(UpdateShipRequest self, SseSerializer serializer);
 
 @protected void sse_encode_update_ship_visit_request(UpdateShipVisitRequest self, SseSerializer serializer);
@@ -655,7 +2283,11 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
 @protected void sse_encode_update_supply_item_request(UpdateSupplyItemRequest self, SseSerializer serializer);
 
+@protected void sse_encode_update_unit_request(UpdateUnitRequest self, SseSerializer serializer);
+
 @protected void sse_encode_visit_status(VisitStatus self, SseSerializer serializer);
+
+@protected void sse_encode_warehouse_type(WarehouseType self, SseSerializer serializer);
                 }
                 
 
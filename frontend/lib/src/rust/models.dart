@@ -6,1708 +6,4992 @@
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+
+            
+
+            
+
+            /// Basis used to spread a landed cost over order items
+enum AllocationMethod {
+                    /// buying_price * quantity
+value,
+/// Line weight in kg
+weight,
+quantity,
+                    ;
+                    
+                }
+
+/// Why a supply item counts as an alternative
+enum AlternativeLink {
+                    sameImpa,
+manual,
+                    ;
+                    
+                }
+
+/// Attachment metadata; the file is fetched with `get_attachment_data`
+class Attachment  {
+                final int id;
+final AttachmentEntity entityType;
+final int entityId;
+/// Slot the file fills on its record (e.g. SIGNATURE on a delivery note), None for free attachments
+final String? role;
+final String fileName;
+final String mimeType;
+/// SHA-256 of the content; identical files are stored once
+final String contentHash;
+final PlatformInt64 sizeBytes;
+/// Pixel size, for images
+final int? width;
+final int? height;
+final bool hasThumbnail;
+final String? caption;
+final String createdAt;
+
+                const Attachment({required this.id ,required this.entityType ,required this.entityId ,this.role ,required this.fileName ,required this.mimeType ,required this.contentHash ,required this.sizeBytes ,this.width ,this.height ,required this.hasThumbnail ,this.caption ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^entityType.hashCode^entityId.hashCode^role.hashCode^fileName.hashCode^mimeType.hashCode^contentHash.hashCode^sizeBytes.hashCode^width.hashCode^height.hashCode^hasThumbnail.hashCode^caption.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Attachment &&
+                runtimeType == other.runtimeType
+                && id == other.id&& entityType == other.entityType&& entityId == other.entityId&& role == other.role&& fileName == other.fileName&& mimeType == other.mimeType&& contentHash == other.contentHash&& sizeBytes == other.sizeBytes&& width == other.width&& height == other.height&& hasThumbnail == other.hasThumbnail&& caption == other.caption&& createdAt == other.createdAt;
+        
+            }
+
+/// Kind of record a file is attached to
+enum AttachmentEntity {
+                    supplyItem,
+order,
+deliveryNote,
+supplier,
+ship,
+                    ;
+                    
+                }
+
+/// Whether an order's ship may receive bonded goods
+class BondedEligibility  {
+                final int orderId;
+final String? shipName;
+final String? shipFlag;
+final String? portName;
+final String? portCountry;
+final bool isInternationalPort;
+final bool isEligible;
+/// Why the ship is not eligible
+final String? reason;
+
+                const BondedEligibility({required this.orderId ,this.shipName ,this.shipFlag ,this.portName ,this.portCountry ,required this.isInternationalPort ,required this.isEligible ,this.reason ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^shipName.hashCode^shipFlag.hashCode^portName.hashCode^portCountry.hashCode^isInternationalPort.hashCode^isEligible.hashCode^reason.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BondedEligibility &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& shipName == other.shipName&& shipFlag == other.shipFlag&& portName == other.portName&& portCountry == other.portCountry&& isInternationalPort == other.isInternationalPort&& isEligible == other.isEligible&& reason == other.reason;
+        
+            }
+
+/// Periodic bonded stock reconciliation for customs
+class BondedReconciliation  {
+                /// YYYY-MM-DD, inclusive
+final String periodStart;
+final String periodEnd;
+final List<BondedReconciliationLine> lines;
+/// Bonded movements in the period with their customs declarations
+final List<StockMovement> movements;
+
+                const BondedReconciliation({required this.periodStart ,required this.periodEnd ,required this.lines ,required this.movements ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => periodStart.hashCode^periodEnd.hashCode^lines.hashCode^movements.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BondedReconciliation &&
+                runtimeType == other.runtimeType
+                && periodStart == other.periodStart&& periodEnd == other.periodEnd&& lines == other.lines&& movements == other.movements;
+        
+            }
+
+/// Bonded stock of one item over a reconciliation period, in the stock unit
+class BondedReconciliationLine  {
+                final int stockId;
+final int supplyItemId;
+final String? supplyItemName;
+final String unit;
+final String? warehouseLocation;
+final double openingQuantity;
+final double receivedQuantity;
+final double returnedQuantity;
+final double issuedQuantity;
+/// Net change from count corrections
+final double adjustedQuantity;
+final double closingQuantity;
+/// Balance the movement log gives today
+final double expectedQuantity;
+/// Quantity on the stock record today
+final double recordedQuantity;
+/// Recorded minus expected; non-zero means stock changed outside the movement log
+final double difference;
+
+                const BondedReconciliationLine({required this.stockId ,required this.supplyItemId ,this.supplyItemName ,required this.unit ,this.warehouseLocation ,required this.openingQuantity ,required this.receivedQuantity ,required this.returnedQuantity ,required this.issuedQuantity ,required this.adjustedQuantity ,required this.closingQuantity ,required this.expectedQuantity ,required this.recordedQuantity ,required this.difference ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stockId.hashCode^supplyItemId.hashCode^supplyItemName.hashCode^unit.hashCode^warehouseLocation.hashCode^openingQuantity.hashCode^receivedQuantity.hashCode^returnedQuantity.hashCode^issuedQuantity.hashCode^adjustedQuantity.hashCode^closingQuantity.hashCode^expectedQuantity.hashCode^recordedQuantity.hashCode^difference.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BondedReconciliationLine &&
+                runtimeType == other.runtimeType
+                && stockId == other.stockId&& supplyItemId == other.supplyItemId&& supplyItemName == other.supplyItemName&& unit == other.unit&& warehouseLocation == other.warehouseLocation&& openingQuantity == other.openingQuantity&& receivedQuantity == other.receivedQuantity&& returnedQuantity == other.returnedQuantity&& issuedQuantity == other.issuedQuantity&& adjustedQuantity == other.adjustedQuantity&& closingQuantity == other.closingQuantity&& expectedQuantity == other.expectedQuantity&& recordedQuantity == other.recordedQuantity&& difference == other.difference;
+        
+            }
+
 /// Calendar data response with all events
-class CalendarData {
-  final List<CalendarEvent> events;
-  final List<Port> ports;
-
-  const CalendarData({
-    required this.events,
-    required this.ports,
-  });
-
-  @override
-  int get hashCode => events.hashCode ^ ports.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CalendarData &&
-          runtimeType == other.runtimeType &&
-          events == other.events &&
-          ports == other.ports;
-}
+class CalendarData  {
+                final List<CalendarEvent> events;
+final List<Port> ports;
+
+                const CalendarData({required this.events ,required this.ports ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => events.hashCode^ports.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CalendarData &&
+                runtimeType == other.runtimeType
+                && events == other.events&& ports == other.ports;
+        
+            }
 
 /// Calendar event for unified calendar view
-class CalendarEvent {
-  final String id;
-  final CalendarEventType eventType;
-  final String title;
-  final String? subtitle;
-  final String startDate;
-  final String endDate;
-  final String color;
-  final String status;
-  final int? relatedShipId;
-  final int? relatedPortId;
-  final int? relatedOrderId;
-  final String? metadata;
-
-  const CalendarEvent({
-    required this.id,
-    required this.eventType,
-    required this.title,
-    this.subtitle,
-    required this.startDate,
-    required this.endDate,
-    required this.color,
-    required this.status,
-    this.relatedShipId,
-    this.relatedPortId,
-    this.relatedOrderId,
-    this.metadata,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      eventType.hashCode ^
-      title.hashCode ^
-      subtitle.hashCode ^
-      startDate.hashCode ^
-      endDate.hashCode ^
-      color.hashCode ^
-      status.hashCode ^
-      relatedShipId.hashCode ^
-      relatedPortId.hashCode ^
-      relatedOrderId.hashCode ^
-      metadata.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CalendarEvent &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          eventType == other.eventType &&
-          title == other.title &&
-          subtitle == other.subtitle &&
-          startDate == other.startDate &&
-          endDate == other.endDate &&
-          color == other.color &&
-          status == other.status &&
-          relatedShipId == other.relatedShipId &&
-          relatedPortId == other.relatedPortId &&
-          relatedOrderId == other.relatedOrderId &&
-          metadata == other.metadata;
-}
+class CalendarEvent  {
+                final String id;
+final CalendarEventType eventType;
+final String title;
+final String? subtitle;
+final String startDate;
+final String endDate;
+final String color;
+final String status;
+final int? relatedShipId;
+final int? relatedPortId;
+final int? relatedOrderId;
+final String? metadata;
+
+                const CalendarEvent({required this.id ,required this.eventType ,required this.title ,this.subtitle ,required this.startDate ,required this.endDate ,required this.color ,required this.status ,this.relatedShipId ,this.relatedPortId ,this.relatedOrderId ,this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^eventType.hashCode^title.hashCode^subtitle.hashCode^startDate.hashCode^endDate.hashCode^color.hashCode^status.hashCode^relatedShipId.hashCode^relatedPortId.hashCode^relatedOrderId.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CalendarEvent &&
+                runtimeType == other.runtimeType
+                && id == other.id&& eventType == other.eventType&& title == other.title&& subtitle == other.subtitle&& startDate == other.startDate&& endDate == other.endDate&& color == other.color&& status == other.status&& relatedShipId == other.relatedShipId&& relatedPortId == other.relatedPortId&& relatedOrderId == other.relatedOrderId&& metadata == other.metadata;
+        
+            }
 
 /// Calendar event type
 enum CalendarEventType {
-  shipVisit,
-  orderDelivery,
-  warehouseDelivery,
-  shipDelivery,
-  ;
-}
-
-class CreateOrderItemRequest {
-  final int orderId;
-  final String productName;
-  final String? impaCode;
-  final String? description;
-  final double quantity;
-  final String unit;
-  final double buyingPrice;
-  final double sellingPrice;
-  final String currency;
-  final DeliveryType deliveryType;
-  final String? warehouseDeliveryDate;
-  final String? shipDeliveryDate;
-  final String? notes;
-
-  const CreateOrderItemRequest({
-    required this.orderId,
-    required this.productName,
-    this.impaCode,
-    this.description,
-    required this.quantity,
-    required this.unit,
-    required this.buyingPrice,
-    required this.sellingPrice,
-    required this.currency,
-    required this.deliveryType,
-    this.warehouseDeliveryDate,
-    this.shipDeliveryDate,
-    this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      orderId.hashCode ^
-      productName.hashCode ^
-      impaCode.hashCode ^
-      description.hashCode ^
-      quantity.hashCode ^
-      unit.hashCode ^
-      buyingPrice.hashCode ^
-      sellingPrice.hashCode ^
-      currency.hashCode ^
-      deliveryType.hashCode ^
-      warehouseDeliveryDate.hashCode ^
-      shipDeliveryDate.hashCode ^
-      notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateOrderItemRequest &&
-          runtimeType == other.runtimeType &&
-          orderId == other.orderId &&
-          productName == other.productName &&
-          impaCode == other.impaCode &&
-          description == other.description &&
-          quantity == other.quantity &&
-          unit == other.unit &&
-          buyingPrice == other.buyingPrice &&
-          sellingPrice == other.sellingPrice &&
-          currency == other.currency &&
-          deliveryType == other.deliveryType &&
-          warehouseDeliveryDate == other.warehouseDeliveryDate &&
-          shipDeliveryDate == other.shipDeliveryDate &&
-          notes == other.notes;
-}
-
-class CreateOrderRequest {
-  final int shipId;
-
-  /// Optional ship visit ID - links order to a specific port call
-  final int? shipVisitId;
-  final String? deliveryPort;
-  final String? notes;
-  final String currency;
-
-  const CreateOrderRequest({
-    required this.shipId,
-    this.shipVisitId,
-    this.deliveryPort,
-    this.notes,
-    required this.currency,
-  });
-
-  @override
-  int get hashCode =>
-      shipId.hashCode ^
-      shipVisitId.hashCode ^
-      deliveryPort.hashCode ^
-      notes.hashCode ^
-      currency.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateOrderRequest &&
-          runtimeType == other.runtimeType &&
-          shipId == other.shipId &&
-          shipVisitId == other.shipVisitId &&
-          deliveryPort == other.deliveryPort &&
-          notes == other.notes &&
-          currency == other.currency;
-}
-
-class CreatePortRequest {
-  final String name;
-  final String country;
-  final String? city;
-  final String timezone;
-  final double? latitude;
-  final double? longitude;
-  final String? notes;
-
-  const CreatePortRequest({
-    required this.name,
-    required this.country,
-    this.city,
-    required this.timezone,
-    this.latitude,
-    this.longitude,
-    this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      country.hashCode ^
-      city.hashCode ^
-      timezone.hashCode ^
-      latitude.hashCode ^
-      longitude.hashCode ^
-      notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreatePortRequest &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          country == other.country &&
-          city == other.city &&
-          timezone == other.timezone &&
-          latitude == other.latitude &&
-          longitude == other.longitude &&
-          notes == other.notes;
-}
-
-class CreateShipRequest {
-  final String name;
-  final String imoNumber;
-  final String flag;
-  final String? shipType;
-  final double? grossTonnage;
-  final String? owner;
-
-  const CreateShipRequest({
-    required this.name,
-    required this.imoNumber,
-    required this.flag,
-    this.shipType,
-    this.grossTonnage,
-    this.owner,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      imoNumber.hashCode ^
-      flag.hashCode ^
-      shipType.hashCode ^
-      grossTonnage.hashCode ^
-      owner.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateShipRequest &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          imoNumber == other.imoNumber &&
-          flag == other.flag &&
-          shipType == other.shipType &&
-          grossTonnage == other.grossTonnage &&
-          owner == other.owner;
-}
-
-class CreateShipVisitRequest {
-  final int shipId;
-  final int portId;
-  final String eta;
-  final String etd;
-  final String? agentInfo;
-  final String? notes;
-
-  const CreateShipVisitRequest({
-    required this.shipId,
-    required this.portId,
-    required this.eta,
-    required this.etd,
-    this.agentInfo,
-    this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      shipId.hashCode ^
-      portId.hashCode ^
-      eta.hashCode ^
-      etd.hashCode ^
-      agentInfo.hashCode ^
-      notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateShipVisitRequest &&
-          runtimeType == other.runtimeType &&
-          shipId == other.shipId &&
-          portId == other.portId &&
-          eta == other.eta &&
-          etd == other.etd &&
-          agentInfo == other.agentInfo &&
-          notes == other.notes;
-}
-
-class CreateStockMovementRequest {
-  final int stockId;
-  final StockMovementType movementType;
-  final double quantity;
-  final String? referenceType;
-  final int? referenceId;
-  final String? referenceInfo;
-  final String? notes;
-
-  const CreateStockMovementRequest({
-    required this.stockId,
-    required this.movementType,
-    required this.quantity,
-    this.referenceType,
-    this.referenceId,
-    this.referenceInfo,
-    this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      stockId.hashCode ^
-      movementType.hashCode ^
-      quantity.hashCode ^
-      referenceType.hashCode ^
-      referenceId.hashCode ^
-      referenceInfo.hashCode ^
-      notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateStockMovementRequest &&
-          runtimeType == other.runtimeType &&
-          stockId == other.stockId &&
-          movementType == other.movementType &&
-          quantity == other.quantity &&
-          referenceType == other.referenceType &&
-          referenceId == other.referenceId &&
-          referenceInfo == other.referenceInfo &&
-          notes == other.notes;
-}
-
-class CreateStockRequest {
-  final int supplyItemId;
-  final double quantity;
-  final String unit;
-  final String? warehouseLocation;
-  final double minimumQuantity;
-
-  const CreateStockRequest({
-    required this.supplyItemId,
-    required this.quantity,
-    required this.unit,
-    this.warehouseLocation,
-    required this.minimumQuantity,
-  });
-
-  @override
-  int get hashCode =>
-      supplyItemId.hashCode ^
-      quantity.hashCode ^
-      unit.hashCode ^
-      warehouseLocation.hashCode ^
-      minimumQuantity.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateStockRequest &&
-          runtimeType == other.runtimeType &&
-          supplyItemId == other.supplyItemId &&
-          quantity == other.quantity &&
-          unit == other.unit &&
-          warehouseLocation == other.warehouseLocation &&
-          minimumQuantity == other.minimumQuantity;
-}
-
-class CreateSupplierRequest {
-  final String name;
-  final String? contactPerson;
-  final String? email;
-  final String? phone;
-  final String? address;
-  final String? country;
-  final String category;
-
-  const CreateSupplierRequest({
-    required this.name,
-    this.contactPerson,
-    this.email,
-    this.phone,
-    this.address,
-    this.country,
-    required this.category,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      contactPerson.hashCode ^
-      email.hashCode ^
-      phone.hashCode ^
-      address.hashCode ^
-      country.hashCode ^
-      category.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateSupplierRequest &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          contactPerson == other.contactPerson &&
-          email == other.email &&
-          phone == other.phone &&
-          address == other.address &&
-          country == other.country &&
-          category == other.category;
-}
-
-class CreateSupplyItemRequest {
-  final int supplierId;
-  final String? impaCode;
-  final String name;
-  final String? description;
-  final String category;
-  final String unit;
-  final double unitPrice;
-  final String currency;
-  final int? minimumOrderQuantity;
-
-  const CreateSupplyItemRequest({
-    required this.supplierId,
-    this.impaCode,
-    required this.name,
-    this.description,
-    required this.category,
-    required this.unit,
-    required this.unitPrice,
-    required this.currency,
-    this.minimumOrderQuantity,
-  });
-
-  @override
-  int get hashCode =>
-      supplierId.hashCode ^
-      impaCode.hashCode ^
-      name.hashCode ^
-      description.hashCode ^
-      category.hashCode ^
-      unit.hashCode ^
-      unitPrice.hashCode ^
-      currency.hashCode ^
-      minimumOrderQuantity.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is CreateSupplyItemRequest &&
-          runtimeType == other.runtimeType &&
-          supplierId == other.supplierId &&
-          impaCode == other.impaCode &&
-          name == other.name &&
-          description == other.description &&
-          category == other.category &&
-          unit == other.unit &&
-          unitPrice == other.unitPrice &&
-          currency == other.currency &&
-          minimumOrderQuantity == other.minimumOrderQuantity;
-}
+                    shipVisit,
+orderDelivery,
+warehouseDelivery,
+shipDelivery,
+                    ;
+                    
+                }
+
+/// Node of the product category tree, e.g. Kumanya > Süt Ürünleri > Peynir
+class Category  {
+                final int id;
+final int? parentId;
+/// Unique code, e.g. "PROVISIONS" or "DAIRY"
+final String code;
+final String nameTr;
+final String? nameEn;
+/// 0 for top-level categories
+final int depth;
+/// Turkish names from the root, e.g. "Kumanya > Süt Ürünleri"
+final String path;
+final int sortOrder;
+final bool isActive;
+/// Supply items filed directly under this category
+final int itemCount;
+final String createdAt;
+final String updatedAt;
+
+                const Category({required this.id ,this.parentId ,required this.code ,required this.nameTr ,this.nameEn ,required this.depth ,required this.path ,required this.sortOrder ,required this.isActive ,required this.itemCount ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^parentId.hashCode^code.hashCode^nameTr.hashCode^nameEn.hashCode^depth.hashCode^path.hashCode^sortOrder.hashCode^isActive.hashCode^itemCount.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Category &&
+                runtimeType == other.runtimeType
+                && id == other.id&& parentId == other.parentId&& code == other.code&& nameTr == other.nameTr&& nameEn == other.nameEn&& depth == other.depth&& path == other.path&& sortOrder == other.sortOrder&& isActive == other.isActive&& itemCount == other.itemCount&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// How a charge is billed to the customer
+enum ChargeBilling {
+                    /// Billed at cost
+passThrough,
+/// Billed at cost plus `markup_percent`
+markup,
+/// Our cost only, not billed (e.g. agency commission we pay)
+absorbed,
+                    ;
+                    
+                }
+
+/// Non-item charge on an order
+enum ChargeType {
+                    launchBoat,
+truckDelivery,
+afterHours,
+portFee,
+agencyCommission,
+discount,
+other,
+                    ;
+                    
+                }
+
+/// Our own company, printed as the letterhead of every document
+class CompanyProfile  {
+                final String name;
+final String? address;
+final String? phone;
+final String? email;
+final String? website;
+final String? taxOffice;
+final String? taxNumber;
+final String? bankName;
+final String? iban;
+final String? swift;
+final String? updatedAt;
+
+                const CompanyProfile({required this.name ,this.address ,this.phone ,this.email ,this.website ,this.taxOffice ,this.taxNumber ,this.bankName ,this.iban ,this.swift ,this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^address.hashCode^phone.hashCode^email.hashCode^website.hashCode^taxOffice.hashCode^taxNumber.hashCode^bankName.hashCode^iban.hashCode^swift.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompanyProfile &&
+                runtimeType == other.runtimeType
+                && name == other.name&& address == other.address&& phone == other.phone&& email == other.email&& website == other.website&& taxOffice == other.taxOffice&& taxNumber == other.taxNumber&& bankName == other.bankName&& iban == other.iban&& swift == other.swift&& updatedAt == other.updatedAt;
+        
+            }
+
+class CompleteDeliveryNoteRequest  {
+                final String receiverName;
+final String receiverRank;
+/// Defaults to now
+final String? signedAt;
+
+                const CompleteDeliveryNoteRequest({required this.receiverName ,required this.receiverRank ,this.signedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => receiverName.hashCode^receiverRank.hashCode^signedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CompleteDeliveryNoteRequest &&
+                runtimeType == other.runtimeType
+                && receiverName == other.receiverName&& receiverRank == other.receiverRank&& signedAt == other.signedAt;
+        
+            }
+
+class ComplianceIssue  {
+                final int orderItemId;
+final String productName;
+final ComplianceIssueKind kind;
+final String detail;
+
+                const ComplianceIssue({required this.orderItemId ,required this.productName ,required this.kind ,required this.detail ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^productName.hashCode^kind.hashCode^detail.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ComplianceIssue &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& productName == other.productName&& kind == other.kind&& detail == other.detail;
+        
+            }
+
+enum ComplianceIssueKind {
+                    /// Dangerous good or SDS-required item without a safety data sheet on file
+missingSds,
+/// Prescription-only medicine not signed off by a pharmacist
+missingPharmacistSignOff,
+                    ;
+                    
+                }
+
+/// Selling price resolved from a customer's agreement
+class ContractPrice  {
+                final int agreementId;
+final String agreementName;
+final int lineId;
+final double sellingPrice;
+/// True when the price comes from an IMPA group markup/price rather than the item itself
+final bool isGroupPrice;
+
+                const ContractPrice({required this.agreementId ,required this.agreementName ,required this.lineId ,required this.sellingPrice ,required this.isGroupPrice ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => agreementId.hashCode^agreementName.hashCode^lineId.hashCode^sellingPrice.hashCode^isGroupPrice.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ContractPrice &&
+                runtimeType == other.runtimeType
+                && agreementId == other.agreementId&& agreementName == other.agreementName&& lineId == other.lineId&& sellingPrice == other.sellingPrice&& isGroupPrice == other.isGroupPrice;
+        
+            }
+
+class CreateAttachmentRequest  {
+                final AttachmentEntity entityType;
+final int entityId;
+final String fileName;
+final String mimeType;
+final Uint8List data;
+final String? caption;
+
+                const CreateAttachmentRequest({required this.entityType ,required this.entityId ,required this.fileName ,required this.mimeType ,required this.data ,this.caption ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => entityType.hashCode^entityId.hashCode^fileName.hashCode^mimeType.hashCode^data.hashCode^caption.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateAttachmentRequest &&
+                runtimeType == other.runtimeType
+                && entityType == other.entityType&& entityId == other.entityId&& fileName == other.fileName&& mimeType == other.mimeType&& data == other.data&& caption == other.caption;
+        
+            }
+
+class CreateCategoryRequest  {
+                final int? parentId;
+final String code;
+final String nameTr;
+final String? nameEn;
+final int? sortOrder;
+
+                const CreateCategoryRequest({this.parentId ,required this.code ,required this.nameTr ,this.nameEn ,this.sortOrder ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => parentId.hashCode^code.hashCode^nameTr.hashCode^nameEn.hashCode^sortOrder.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateCategoryRequest &&
+                runtimeType == other.runtimeType
+                && parentId == other.parentId&& code == other.code&& nameTr == other.nameTr&& nameEn == other.nameEn&& sortOrder == other.sortOrder;
+        
+            }
+
+class CreateCustomerContactRequest  {
+                final String name;
+final String? role;
+final String? email;
+final String? phone;
+final bool isPrimary;
+
+                const CreateCustomerContactRequest({required this.name ,this.role ,this.email ,this.phone ,required this.isPrimary ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^role.hashCode^email.hashCode^phone.hashCode^isPrimary.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateCustomerContactRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& role == other.role&& email == other.email&& phone == other.phone&& isPrimary == other.isPrimary;
+        
+            }
+
+class CreateCustomerRequest  {
+                final String legalName;
+final String? taxNumber;
+final String? taxOffice;
+final String? billingAddress;
+final String? country;
+/// Defaults to 30 days
+final int? paymentTermsDays;
+final double? creditLimit;
+/// Defaults to USD
+final String? defaultCurrency;
+final String? notes;
+final List<CreateCustomerContactRequest> contacts;
+
+                const CreateCustomerRequest({required this.legalName ,this.taxNumber ,this.taxOffice ,this.billingAddress ,this.country ,this.paymentTermsDays ,this.creditLimit ,this.defaultCurrency ,this.notes ,required this.contacts ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => legalName.hashCode^taxNumber.hashCode^taxOffice.hashCode^billingAddress.hashCode^country.hashCode^paymentTermsDays.hashCode^creditLimit.hashCode^defaultCurrency.hashCode^notes.hashCode^contacts.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateCustomerRequest &&
+                runtimeType == other.runtimeType
+                && legalName == other.legalName&& taxNumber == other.taxNumber&& taxOffice == other.taxOffice&& billingAddress == other.billingAddress&& country == other.country&& paymentTermsDays == other.paymentTermsDays&& creditLimit == other.creditLimit&& defaultCurrency == other.defaultCurrency&& notes == other.notes&& contacts == other.contacts;
+        
+            }
+
+class CreateDeliveryNoteRequest  {
+                final int orderId;
+/// Delivery events to put on the note; None = every delivery not yet on a note
+final Int32List? deliveryIds;
+final String? notes;
+
+                const CreateDeliveryNoteRequest({required this.orderId ,this.deliveryIds ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^deliveryIds.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateDeliveryNoteRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& deliveryIds == other.deliveryIds&& notes == other.notes;
+        
+            }
+
+class CreateEquivalenceGroupRequest  {
+                final String name;
+final String? notes;
+final Int32List supplyItemIds;
+
+                const CreateEquivalenceGroupRequest({required this.name ,this.notes ,required this.supplyItemIds ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^notes.hashCode^supplyItemIds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateEquivalenceGroupRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& notes == other.notes&& supplyItemIds == other.supplyItemIds;
+        
+            }
+
+class CreateInvoiceRequest  {
+                final int orderId;
+/// Defaults to today
+final String? issueDate;
+/// Defaults to issue date + the order's payment terms
+final String? dueDate;
+/// Defaults to the order's total revenue
+final double? amount;
+final String? notes;
+
+                const CreateInvoiceRequest({required this.orderId ,this.issueDate ,this.dueDate ,this.amount ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^issueDate.hashCode^dueDate.hashCode^amount.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateInvoiceRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& issueDate == other.issueDate&& dueDate == other.dueDate&& amount == other.amount&& notes == other.notes;
+        
+            }
+
+class CreateLandedCostRequest  {
+                final int orderId;
+final int? stockMovementId;
+final LandedCostType costType;
+final String? description;
+/// In the order's currency
+final double amount;
+final AllocationMethod allocationMethod;
+final String? reference;
+/// Line weights for allocation by weight; lines sold in KG default to their quantity
+final List<ItemWeight> weights;
+
+                const CreateLandedCostRequest({required this.orderId ,this.stockMovementId ,required this.costType ,this.description ,required this.amount ,required this.allocationMethod ,this.reference ,required this.weights ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^stockMovementId.hashCode^costType.hashCode^description.hashCode^amount.hashCode^allocationMethod.hashCode^reference.hashCode^weights.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateLandedCostRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& stockMovementId == other.stockMovementId&& costType == other.costType&& description == other.description&& amount == other.amount&& allocationMethod == other.allocationMethod&& reference == other.reference&& weights == other.weights;
+        
+            }
+
+class CreateOrderChargeRequest  {
+                final int orderId;
+final ChargeType chargeType;
+final String? description;
+/// Cost of the charge; for a discount, the discount given
+final double amount;
+/// Ignored for discounts
+final ChargeBilling billing;
+/// Required when billing is Markup
+final double? markupPercent;
+final String? notes;
+
+                const CreateOrderChargeRequest({required this.orderId ,required this.chargeType ,this.description ,required this.amount ,required this.billing ,this.markupPercent ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^chargeType.hashCode^description.hashCode^amount.hashCode^billing.hashCode^markupPercent.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateOrderChargeRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& chargeType == other.chargeType&& description == other.description&& amount == other.amount&& billing == other.billing&& markupPercent == other.markupPercent&& notes == other.notes;
+        
+            }
+
+class CreateOrderItemRequest  {
+                final int orderId;
+final String productName;
+final String? impaCode;
+final String? description;
+final double quantity;
+final String unit;
+final double buyingPrice;
+/// 0 = let the pricing engine propose the price (price agreement, then pricing rules)
+final double sellingPrice;
+final String currency;
+final DeliveryType deliveryType;
+final String? warehouseDeliveryDate;
+final String? shipDeliveryDate;
+final String? notes;
+
+                const CreateOrderItemRequest({required this.orderId ,required this.productName ,this.impaCode ,this.description ,required this.quantity ,required this.unit ,required this.buyingPrice ,required this.sellingPrice ,required this.currency ,required this.deliveryType ,this.warehouseDeliveryDate ,this.shipDeliveryDate ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^productName.hashCode^impaCode.hashCode^description.hashCode^quantity.hashCode^unit.hashCode^buyingPrice.hashCode^sellingPrice.hashCode^currency.hashCode^deliveryType.hashCode^warehouseDeliveryDate.hashCode^shipDeliveryDate.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateOrderItemRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& productName == other.productName&& impaCode == other.impaCode&& description == other.description&& quantity == other.quantity&& unit == other.unit&& buyingPrice == other.buyingPrice&& sellingPrice == other.sellingPrice&& currency == other.currency&& deliveryType == other.deliveryType&& warehouseDeliveryDate == other.warehouseDeliveryDate&& shipDeliveryDate == other.shipDeliveryDate&& notes == other.notes;
+        
+            }
+
+class CreateOrderRequest  {
+                final int shipId;
+/// Optional ship visit ID - links order to a specific port call
+final int? shipVisitId;
+final String? deliveryPort;
+final String? notes;
+/// Leave empty to use the customer's default currency
+final String currency;
+
+                const CreateOrderRequest({required this.shipId ,this.shipVisitId ,this.deliveryPort ,this.notes ,required this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => shipId.hashCode^shipVisitId.hashCode^deliveryPort.hashCode^notes.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateOrderRequest &&
+                runtimeType == other.runtimeType
+                && shipId == other.shipId&& shipVisitId == other.shipVisitId&& deliveryPort == other.deliveryPort&& notes == other.notes&& currency == other.currency;
+        
+            }
+
+class CreatePaymentRequest  {
+                final int customerId;
+final double amount;
+final String currency;
+final String paymentDate;
+final PaymentMethod method;
+final String? reference;
+final String? notes;
+/// Explicit allocations. If empty, the payment is applied to the
+/// customer's oldest open invoices in the same currency.
+final List<PaymentAllocationRequest> allocations;
+
+                const CreatePaymentRequest({required this.customerId ,required this.amount ,required this.currency ,required this.paymentDate ,required this.method ,this.reference ,this.notes ,required this.allocations ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => customerId.hashCode^amount.hashCode^currency.hashCode^paymentDate.hashCode^method.hashCode^reference.hashCode^notes.hashCode^allocations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreatePaymentRequest &&
+                runtimeType == other.runtimeType
+                && customerId == other.customerId&& amount == other.amount&& currency == other.currency&& paymentDate == other.paymentDate&& method == other.method&& reference == other.reference&& notes == other.notes&& allocations == other.allocations;
+        
+            }
+
+class CreatePortRequest  {
+                final String name;
+final String country;
+final String? city;
+final String timezone;
+final double? latitude;
+final double? longitude;
+final String? notes;
+final bool isInternational;
+
+                const CreatePortRequest({required this.name ,required this.country ,this.city ,required this.timezone ,this.latitude ,this.longitude ,this.notes ,required this.isInternational ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^country.hashCode^city.hashCode^timezone.hashCode^latitude.hashCode^longitude.hashCode^notes.hashCode^isInternational.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreatePortRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& country == other.country&& city == other.city&& timezone == other.timezone&& latitude == other.latitude&& longitude == other.longitude&& notes == other.notes&& isInternational == other.isInternational;
+        
+            }
+
+class CreatePriceAgreementLineRequest  {
+                final String? impaCode;
+final String? impaGroup;
+final String? description;
+final double? fixedPrice;
+final double? markupPercent;
+
+                const CreatePriceAgreementLineRequest({this.impaCode ,this.impaGroup ,this.description ,this.fixedPrice ,this.markupPercent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => impaCode.hashCode^impaGroup.hashCode^description.hashCode^fixedPrice.hashCode^markupPercent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreatePriceAgreementLineRequest &&
+                runtimeType == other.runtimeType
+                && impaCode == other.impaCode&& impaGroup == other.impaGroup&& description == other.description&& fixedPrice == other.fixedPrice&& markupPercent == other.markupPercent;
+        
+            }
+
+class CreatePriceAgreementRequest  {
+                final int customerId;
+final String name;
+final String currency;
+final String validFrom;
+final String validTo;
+final String? notes;
+final List<CreatePriceAgreementLineRequest> lines;
+
+                const CreatePriceAgreementRequest({required this.customerId ,required this.name ,required this.currency ,required this.validFrom ,required this.validTo ,this.notes ,required this.lines ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => customerId.hashCode^name.hashCode^currency.hashCode^validFrom.hashCode^validTo.hashCode^notes.hashCode^lines.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreatePriceAgreementRequest &&
+                runtimeType == other.runtimeType
+                && customerId == other.customerId&& name == other.name&& currency == other.currency&& validFrom == other.validFrom&& validTo == other.validTo&& notes == other.notes&& lines == other.lines;
+        
+            }
+
+class CreatePricingRuleRequest  {
+                final String name;
+final int? categoryId;
+final int? supplierId;
+final int? customerId;
+final double? markupPercent;
+final double? minMarginPercent;
+final String? notes;
+
+                const CreatePricingRuleRequest({required this.name ,this.categoryId ,this.supplierId ,this.customerId ,this.markupPercent ,this.minMarginPercent ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^categoryId.hashCode^supplierId.hashCode^customerId.hashCode^markupPercent.hashCode^minMarginPercent.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreatePricingRuleRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& categoryId == other.categoryId&& supplierId == other.supplierId&& customerId == other.customerId&& markupPercent == other.markupPercent&& minMarginPercent == other.minMarginPercent&& notes == other.notes;
+        
+            }
+
+class CreateRequisitionTemplateRequest  {
+                final String name;
+/// Exactly one of ship_id / ship_type
+final int? shipId;
+final String? shipType;
+final double? baseHeadcount;
+final String? notes;
+final List<RequisitionTemplateLineRequest> lines;
+
+                const CreateRequisitionTemplateRequest({required this.name ,this.shipId ,this.shipType ,this.baseHeadcount ,this.notes ,required this.lines ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^shipId.hashCode^shipType.hashCode^baseHeadcount.hashCode^notes.hashCode^lines.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateRequisitionTemplateRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& shipId == other.shipId&& shipType == other.shipType&& baseHeadcount == other.baseHeadcount&& notes == other.notes&& lines == other.lines;
+        
+            }
+
+class CreateReturnRequest  {
+                final int orderId;
+/// Defaults to today
+final String? returnDate;
+final String? notes;
+final List<ReturnLineRequest> lines;
+/// Issue a credit note against the order's invoice
+final bool issueCreditNote;
+/// Customs declaration for goods restocked into the bonded warehouse
+final String? customsDeclarationNumber;
+
+                const CreateReturnRequest({required this.orderId ,this.returnDate ,this.notes ,required this.lines ,required this.issueCreditNote ,this.customsDeclarationNumber ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^returnDate.hashCode^notes.hashCode^lines.hashCode^issueCreditNote.hashCode^customsDeclarationNumber.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateReturnRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& returnDate == other.returnDate&& notes == other.notes&& lines == other.lines&& issueCreditNote == other.issueCreditNote&& customsDeclarationNumber == other.customsDeclarationNumber;
+        
+            }
+
+class CreateShipRequest  {
+                final String name;
+final String imoNumber;
+final String flag;
+final String? shipType;
+final double? grossTonnage;
+final String? owner;
+final int? ownerId;
+
+                const CreateShipRequest({required this.name ,required this.imoNumber ,required this.flag ,this.shipType ,this.grossTonnage ,this.owner ,this.ownerId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^imoNumber.hashCode^flag.hashCode^shipType.hashCode^grossTonnage.hashCode^owner.hashCode^ownerId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateShipRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& imoNumber == other.imoNumber&& flag == other.flag&& shipType == other.shipType&& grossTonnage == other.grossTonnage&& owner == other.owner&& ownerId == other.ownerId;
+        
+            }
+
+class CreateShipVisitRequest  {
+                final int shipId;
+final int portId;
+final String eta;
+final String etd;
+final String? agentInfo;
+final String? notes;
+
+                const CreateShipVisitRequest({required this.shipId ,required this.portId ,required this.eta ,required this.etd ,this.agentInfo ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => shipId.hashCode^portId.hashCode^eta.hashCode^etd.hashCode^agentInfo.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateShipVisitRequest &&
+                runtimeType == other.runtimeType
+                && shipId == other.shipId&& portId == other.portId&& eta == other.eta&& etd == other.etd&& agentInfo == other.agentInfo&& notes == other.notes;
+        
+            }
+
+class CreateStockMovementRequest  {
+                final int stockId;
+final StockMovementType movementType;
+final double quantity;
+/// Unit of `quantity`; None = the stock unit. Converted to the stock unit.
+final String? unit;
+final String? referenceType;
+final int? referenceId;
+final String? referenceInfo;
+/// Order line a goods receipt fills; needs the order as reference
+final int? orderItemId;
+final String? notes;
+final String? customsDeclarationNumber;
+
+                const CreateStockMovementRequest({required this.stockId ,required this.movementType ,required this.quantity ,this.unit ,this.referenceType ,this.referenceId ,this.referenceInfo ,this.orderItemId ,this.notes ,this.customsDeclarationNumber ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stockId.hashCode^movementType.hashCode^quantity.hashCode^unit.hashCode^referenceType.hashCode^referenceId.hashCode^referenceInfo.hashCode^orderItemId.hashCode^notes.hashCode^customsDeclarationNumber.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateStockMovementRequest &&
+                runtimeType == other.runtimeType
+                && stockId == other.stockId&& movementType == other.movementType&& quantity == other.quantity&& unit == other.unit&& referenceType == other.referenceType&& referenceId == other.referenceId&& referenceInfo == other.referenceInfo&& orderItemId == other.orderItemId&& notes == other.notes&& customsDeclarationNumber == other.customsDeclarationNumber;
+        
+            }
+
+class CreateStockRequest  {
+                final int supplyItemId;
+final double quantity;
+final String unit;
+final String? warehouseLocation;
+/// Bonded stock starts empty; goods come in with a declared In movement
+final WarehouseType warehouseType;
+final double minimumQuantity;
+
+                const CreateStockRequest({required this.supplyItemId ,required this.quantity ,required this.unit ,this.warehouseLocation ,required this.warehouseType ,required this.minimumQuantity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplyItemId.hashCode^quantity.hashCode^unit.hashCode^warehouseLocation.hashCode^warehouseType.hashCode^minimumQuantity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateStockRequest &&
+                runtimeType == other.runtimeType
+                && supplyItemId == other.supplyItemId&& quantity == other.quantity&& unit == other.unit&& warehouseLocation == other.warehouseLocation&& warehouseType == other.warehouseType&& minimumQuantity == other.minimumQuantity;
+        
+            }
+
+class CreateSupplierInvoiceLineRequest  {
+                final int? orderItemId;
+final int? supplyItemId;
+final String description;
+final double quantity;
+final double unitPrice;
+
+                const CreateSupplierInvoiceLineRequest({this.orderItemId ,this.supplyItemId ,required this.description ,required this.quantity ,required this.unitPrice ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^supplyItemId.hashCode^description.hashCode^quantity.hashCode^unitPrice.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateSupplierInvoiceLineRequest &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& supplyItemId == other.supplyItemId&& description == other.description&& quantity == other.quantity&& unitPrice == other.unitPrice;
+        
+            }
+
+class CreateSupplierInvoiceRequest  {
+                final int supplierId;
+final String invoiceNumber;
+final int? orderId;
+final String currency;
+final String issueDate;
+final String dueDate;
+final String? notes;
+final List<CreateSupplierInvoiceLineRequest> lines;
+
+                const CreateSupplierInvoiceRequest({required this.supplierId ,required this.invoiceNumber ,this.orderId ,required this.currency ,required this.issueDate ,required this.dueDate ,this.notes ,required this.lines ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierId.hashCode^invoiceNumber.hashCode^orderId.hashCode^currency.hashCode^issueDate.hashCode^dueDate.hashCode^notes.hashCode^lines.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateSupplierInvoiceRequest &&
+                runtimeType == other.runtimeType
+                && supplierId == other.supplierId&& invoiceNumber == other.invoiceNumber&& orderId == other.orderId&& currency == other.currency&& issueDate == other.issueDate&& dueDate == other.dueDate&& notes == other.notes&& lines == other.lines;
+        
+            }
+
+class CreateSupplierPaymentRequest  {
+                final int supplierInvoiceId;
+/// Amount in invoice currency
+final double amount;
+final String paymentDate;
+final PaymentMethod method;
+final String? reference;
+final String? notes;
+
+                const CreateSupplierPaymentRequest({required this.supplierInvoiceId ,required this.amount ,required this.paymentDate ,required this.method ,this.reference ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierInvoiceId.hashCode^amount.hashCode^paymentDate.hashCode^method.hashCode^reference.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateSupplierPaymentRequest &&
+                runtimeType == other.runtimeType
+                && supplierInvoiceId == other.supplierInvoiceId&& amount == other.amount&& paymentDate == other.paymentDate&& method == other.method&& reference == other.reference&& notes == other.notes;
+        
+            }
+
+class CreateSupplierRequest  {
+                final String name;
+final String? contactPerson;
+final String? email;
+final String? phone;
+final String? address;
+final String? country;
+final int categoryId;
+final int? leadTimeDays;
+
+                const CreateSupplierRequest({required this.name ,this.contactPerson ,this.email ,this.phone ,this.address ,this.country ,required this.categoryId ,this.leadTimeDays ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^contactPerson.hashCode^email.hashCode^phone.hashCode^address.hashCode^country.hashCode^categoryId.hashCode^leadTimeDays.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateSupplierRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& contactPerson == other.contactPerson&& email == other.email&& phone == other.phone&& address == other.address&& country == other.country&& categoryId == other.categoryId&& leadTimeDays == other.leadTimeDays;
+        
+            }
+
+class CreateSupplyItemRequest  {
+                final int supplierId;
+final String? impaCode;
+final String? supplierSku;
+final String name;
+final String? description;
+/// Filled in from the IMPA section when not given
+final int? categoryId;
+final String unit;
+final double unitPrice;
+final String currency;
+final int? minimumOrderQuantity;
+final String? unNumber;
+final String? hazardClass;
+final bool sdsRequired;
+final bool prescriptionOnly;
+final bool isBonded;
+
+                const CreateSupplyItemRequest({required this.supplierId ,this.impaCode ,this.supplierSku ,required this.name ,this.description ,this.categoryId ,required this.unit ,required this.unitPrice ,required this.currency ,this.minimumOrderQuantity ,this.unNumber ,this.hazardClass ,required this.sdsRequired ,required this.prescriptionOnly ,required this.isBonded ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierId.hashCode^impaCode.hashCode^supplierSku.hashCode^name.hashCode^description.hashCode^categoryId.hashCode^unit.hashCode^unitPrice.hashCode^currency.hashCode^minimumOrderQuantity.hashCode^unNumber.hashCode^hazardClass.hashCode^sdsRequired.hashCode^prescriptionOnly.hashCode^isBonded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateSupplyItemRequest &&
+                runtimeType == other.runtimeType
+                && supplierId == other.supplierId&& impaCode == other.impaCode&& supplierSku == other.supplierSku&& name == other.name&& description == other.description&& categoryId == other.categoryId&& unit == other.unit&& unitPrice == other.unitPrice&& currency == other.currency&& minimumOrderQuantity == other.minimumOrderQuantity&& unNumber == other.unNumber&& hazardClass == other.hazardClass&& sdsRequired == other.sdsRequired&& prescriptionOnly == other.prescriptionOnly&& isBonded == other.isBonded;
+        
+            }
+
+class CreateUnitRequest  {
+                final String code;
+final String nameTr;
+final String? nameEn;
+final UnitDimension dimension;
+final double factor;
+final List<String> aliases;
+
+                const CreateUnitRequest({required this.code ,required this.nameTr ,this.nameEn ,required this.dimension ,required this.factor ,required this.aliases ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^nameTr.hashCode^nameEn.hashCode^dimension.hashCode^factor.hashCode^aliases.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreateUnitRequest &&
+                runtimeType == other.runtimeType
+                && code == other.code&& nameTr == other.nameTr&& nameEn == other.nameEn&& dimension == other.dimension&& factor == other.factor&& aliases == other.aliases;
+        
+            }
+
+/// Credit exposure of a customer, optionally including an order under review
+class CreditCheck  {
+                final int customerId;
+final String customerName;
+final double? creditLimit;
+/// Customer's default currency; the limit and the amounts below are in it
+final String currency;
+/// Unpaid balance of open invoices
+final double openReceivables;
+/// Revenue of active orders that are not invoiced yet
+final double uninvoicedOrders;
+/// Revenue of the order being checked (0 when it is in another currency)
+final double orderAmount;
+final double projectedExposure;
+final bool exceedsLimit;
+/// Exposure in other currencies, not counted against the limit
+final List<CurrencyExposure> otherCurrencies;
+final String? warning;
+
+                const CreditCheck({required this.customerId ,required this.customerName ,this.creditLimit ,required this.currency ,required this.openReceivables ,required this.uninvoicedOrders ,required this.orderAmount ,required this.projectedExposure ,required this.exceedsLimit ,required this.otherCurrencies ,this.warning ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => customerId.hashCode^customerName.hashCode^creditLimit.hashCode^currency.hashCode^openReceivables.hashCode^uninvoicedOrders.hashCode^orderAmount.hashCode^projectedExposure.hashCode^exceedsLimit.hashCode^otherCurrencies.hashCode^warning.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreditCheck &&
+                runtimeType == other.runtimeType
+                && customerId == other.customerId&& customerName == other.customerName&& creditLimit == other.creditLimit&& currency == other.currency&& openReceivables == other.openReceivables&& uninvoicedOrders == other.uninvoicedOrders&& orderAmount == other.orderAmount&& projectedExposure == other.projectedExposure&& exceedsLimit == other.exceedsLimit&& otherCurrencies == other.otherCurrencies&& warning == other.warning;
+        
+            }
+
+/// Credit note reducing the balance of a customer invoice
+class CreditNote  {
+                final int id;
+/// CN-2026-0001
+final String creditNoteNumber;
+final int invoiceId;
+final String? invoiceNumber;
+final int orderId;
+final int? returnId;
+final String customerName;
+final double amount;
+final String currency;
+final String issueDate;
+final String? notes;
+final String createdAt;
+
+                const CreditNote({required this.id ,required this.creditNoteNumber ,required this.invoiceId ,this.invoiceNumber ,required this.orderId ,this.returnId ,required this.customerName ,required this.amount ,required this.currency ,required this.issueDate ,this.notes ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^creditNoteNumber.hashCode^invoiceId.hashCode^invoiceNumber.hashCode^orderId.hashCode^returnId.hashCode^customerName.hashCode^amount.hashCode^currency.hashCode^issueDate.hashCode^notes.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CreditNote &&
+                runtimeType == other.runtimeType
+                && id == other.id&& creditNoteNumber == other.creditNoteNumber&& invoiceId == other.invoiceId&& invoiceNumber == other.invoiceNumber&& orderId == other.orderId&& returnId == other.returnId&& customerName == other.customerName&& amount == other.amount&& currency == other.currency&& issueDate == other.issueDate&& notes == other.notes&& createdAt == other.createdAt;
+        
+            }
+
+/// Credit exposure of a customer in one currency
+class CurrencyExposure  {
+                final String currency;
+final double openReceivables;
+/// Including the order under review when it is in this currency
+final double uninvoicedOrders;
+
+                const CurrencyExposure({required this.currency ,required this.openReceivables ,required this.uninvoicedOrders ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => currency.hashCode^openReceivables.hashCode^uninvoicedOrders.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CurrencyExposure &&
+                runtimeType == other.runtimeType
+                && currency == other.currency&& openReceivables == other.openReceivables&& uninvoicedOrders == other.uninvoicedOrders;
+        
+            }
+
+/// Selling prices in a currency are rounded up to a multiple of `increment`
+class CurrencyRounding  {
+                final String currency;
+final double increment;
+
+                const CurrencyRounding({required this.currency ,required this.increment ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => currency.hashCode^increment.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CurrencyRounding &&
+                runtimeType == other.runtimeType
+                && currency == other.currency&& increment == other.increment;
+        
+            }
+
+class Customer  {
+                final int id;
+final String legalName;
+final String? taxNumber;
+final String? taxOffice;
+final String? billingAddress;
+final String? country;
+/// Days from invoice date until payment is due
+final int paymentTermsDays;
+/// Maximum open exposure in the default currency (None = no limit)
+final double? creditLimit;
+final String defaultCurrency;
+final String? notes;
+final bool isActive;
+final List<CustomerContact> contacts;
+final String createdAt;
+final String updatedAt;
+
+                const Customer({required this.id ,required this.legalName ,this.taxNumber ,this.taxOffice ,this.billingAddress ,this.country ,required this.paymentTermsDays ,this.creditLimit ,required this.defaultCurrency ,this.notes ,required this.isActive ,required this.contacts ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^legalName.hashCode^taxNumber.hashCode^taxOffice.hashCode^billingAddress.hashCode^country.hashCode^paymentTermsDays.hashCode^creditLimit.hashCode^defaultCurrency.hashCode^notes.hashCode^isActive.hashCode^contacts.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Customer &&
+                runtimeType == other.runtimeType
+                && id == other.id&& legalName == other.legalName&& taxNumber == other.taxNumber&& taxOffice == other.taxOffice&& billingAddress == other.billingAddress&& country == other.country&& paymentTermsDays == other.paymentTermsDays&& creditLimit == other.creditLimit&& defaultCurrency == other.defaultCurrency&& notes == other.notes&& isActive == other.isActive&& contacts == other.contacts&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// Customer account balance in one currency
+class CustomerBalance  {
+                final int customerId;
+final String customerName;
+final String currency;
+final double totalInvoiced;
+final double totalPaid;
+/// Credit notes issued against the invoices
+final double totalCredited;
+final double openBalance;
+/// Unallocated payments (overpayment credit)
+final double credit;
+
+                const CustomerBalance({required this.customerId ,required this.customerName ,required this.currency ,required this.totalInvoiced ,required this.totalPaid ,required this.totalCredited ,required this.openBalance ,required this.credit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => customerId.hashCode^customerName.hashCode^currency.hashCode^totalInvoiced.hashCode^totalPaid.hashCode^totalCredited.hashCode^openBalance.hashCode^credit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CustomerBalance &&
+                runtimeType == other.runtimeType
+                && customerId == other.customerId&& customerName == other.customerName&& currency == other.currency&& totalInvoiced == other.totalInvoiced&& totalPaid == other.totalPaid&& totalCredited == other.totalCredited&& openBalance == other.openBalance&& credit == other.credit;
+        
+            }
+
+class CustomerContact  {
+                final int id;
+final int customerId;
+final String name;
+/// e.g. "Purchasing", "Superintendent", "Accounting"
+final String? role;
+final String? email;
+final String? phone;
+final bool isPrimary;
+
+                const CustomerContact({required this.id ,required this.customerId ,required this.name ,this.role ,this.email ,this.phone ,required this.isPrimary ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^customerId.hashCode^name.hashCode^role.hashCode^email.hashCode^phone.hashCode^isPrimary.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CustomerContact &&
+                runtimeType == other.runtimeType
+                && id == other.id&& customerId == other.customerId&& name == other.name&& role == other.role&& email == other.email&& phone == other.phone&& isPrimary == other.isPrimary;
+        
+            }
+
+class DangerousGoodsClassTotal  {
+                final String hazardClass;
+/// IMDG class name, e.g. "Yanıcı Sıvılar"
+final String description;
+final int lineCount;
+final List<String> unNumbers;
+
+                const DangerousGoodsClassTotal({required this.hazardClass ,required this.description ,required this.lineCount ,required this.unNumbers ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hazardClass.hashCode^description.hashCode^lineCount.hashCode^unNumbers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DangerousGoodsClassTotal &&
+                runtimeType == other.runtimeType
+                && hazardClass == other.hazardClass&& description == other.description&& lineCount == other.lineCount&& unNumbers == other.unNumbers;
+        
+            }
+
+/// A dangerous good handed over on a delivery note
+class DangerousGoodsLine  {
+                final int orderItemId;
+final String productName;
+final String? impaCode;
+final String unNumber;
+final String hazardClass;
+final double quantity;
+final String unit;
+final bool sdsOnFile;
+
+                const DangerousGoodsLine({required this.orderItemId ,required this.productName ,this.impaCode ,required this.unNumber ,required this.hazardClass ,required this.quantity ,required this.unit ,required this.sdsOnFile ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^productName.hashCode^impaCode.hashCode^unNumber.hashCode^hazardClass.hashCode^quantity.hashCode^unit.hashCode^sdsOnFile.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DangerousGoodsLine &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& productName == other.productName&& impaCode == other.impaCode&& unNumber == other.unNumber&& hazardClass == other.hazardClass&& quantity == other.quantity&& unit == other.unit&& sdsOnFile == other.sdsOnFile;
+        
+            }
+
+/// Dangerous goods declaration of one delivery
+class DangerousGoodsSummary  {
+                final int deliveryNoteId;
+final String noteNumber;
+final String? orderNumber;
+final String? shipName;
+final List<DangerousGoodsLine> lines;
+final List<DangerousGoodsClassTotal> classes;
+
+                const DangerousGoodsSummary({required this.deliveryNoteId ,required this.noteNumber ,this.orderNumber ,this.shipName ,required this.lines ,required this.classes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => deliveryNoteId.hashCode^noteNumber.hashCode^orderNumber.hashCode^shipName.hashCode^lines.hashCode^classes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DangerousGoodsSummary &&
+                runtimeType == other.runtimeType
+                && deliveryNoteId == other.deliveryNoteId&& noteNumber == other.noteNumber&& orderNumber == other.orderNumber&& shipName == other.shipName&& lines == other.lines&& classes == other.classes;
+        
+            }
+
+class DeliveryLine  {
+                final int orderItemId;
+final double quantity;
+
+                const DeliveryLine({required this.orderItemId ,required this.quantity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^quantity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeliveryLine &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& quantity == other.quantity;
+        
+            }
+
+class DeliveryNote  {
+                final int id;
+/// DN-2026-0001
+final String noteNumber;
+final int orderId;
+final String? orderNumber;
+final String? shipName;
+final int? shipVisitId;
+final DeliveryNoteStatus status;
+/// Chief steward / officer who received the goods
+final String? receiverName;
+final String? receiverRank;
+/// When the receiver signed
+final String? signedAt;
+final String? notes;
+final List<DeliveryNoteLine> lines;
+final List<DeliveryNoteAttachment> attachments;
+final String createdAt;
+
+                const DeliveryNote({required this.id ,required this.noteNumber ,required this.orderId ,this.orderNumber ,this.shipName ,this.shipVisitId ,required this.status ,this.receiverName ,this.receiverRank ,this.signedAt ,this.notes ,required this.lines ,required this.attachments ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^noteNumber.hashCode^orderId.hashCode^orderNumber.hashCode^shipName.hashCode^shipVisitId.hashCode^status.hashCode^receiverName.hashCode^receiverRank.hashCode^signedAt.hashCode^notes.hashCode^lines.hashCode^attachments.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeliveryNote &&
+                runtimeType == other.runtimeType
+                && id == other.id&& noteNumber == other.noteNumber&& orderId == other.orderId&& orderNumber == other.orderNumber&& shipName == other.shipName&& shipVisitId == other.shipVisitId&& status == other.status&& receiverName == other.receiverName&& receiverRank == other.receiverRank&& signedAt == other.signedAt&& notes == other.notes&& lines == other.lines&& attachments == other.attachments&& createdAt == other.createdAt;
+        
+            }
+
+/// Attachment metadata; the image itself is fetched with `get_delivery_note_attachment_data`
+class DeliveryNoteAttachment  {
+                final int id;
+final int deliveryNoteId;
+final DeliveryNoteAttachmentKind kind;
+final String fileName;
+final String mimeType;
+final PlatformInt64 sizeBytes;
+final String createdAt;
+
+                const DeliveryNoteAttachment({required this.id ,required this.deliveryNoteId ,required this.kind ,required this.fileName ,required this.mimeType ,required this.sizeBytes ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^deliveryNoteId.hashCode^kind.hashCode^fileName.hashCode^mimeType.hashCode^sizeBytes.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeliveryNoteAttachment &&
+                runtimeType == other.runtimeType
+                && id == other.id&& deliveryNoteId == other.deliveryNoteId&& kind == other.kind&& fileName == other.fileName&& mimeType == other.mimeType&& sizeBytes == other.sizeBytes&& createdAt == other.createdAt;
+        
+            }
+
+enum DeliveryNoteAttachmentKind {
+                    /// Receiver's captured signature
+signature,
+/// Photo of the ship stamp
+shipStamp,
+                    ;
+                    
+                }
+
+/// Delivered quantity of an order line on a delivery note
+class DeliveryNoteLine  {
+                final int orderItemId;
+final String productName;
+final String? impaCode;
+final String unit;
+final double quantity;
+
+                const DeliveryNoteLine({required this.orderItemId ,required this.productName ,this.impaCode ,required this.unit ,required this.quantity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^productName.hashCode^impaCode.hashCode^unit.hashCode^quantity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeliveryNoteLine &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& productName == other.productName&& impaCode == other.impaCode&& unit == other.unit&& quantity == other.quantity;
+        
+            }
+
+enum DeliveryNoteStatus {
+                    /// Generated, waiting for the receiver's signature
+draft,
+/// Signed on board; counts as proof of delivery
+completed,
+                    ;
+                    
+                }
 
 /// Delivery type - how the order will be delivered
 enum DeliveryType {
-  /// Supplier -> Warehouse -> Ship
-  viaWarehouse,
-
-  /// Supplier -> Ship (direct delivery)
-  directToShip,
-  ;
-}
+                    /// Supplier -> Warehouse -> Ship
+viaWarehouse,
+/// Supplier -> Ship (direct delivery)
+directToShip,
+                    ;
+                    
+                }
+
+/// Supply items linked by hand as interchangeable (items sharing an IMPA code
+/// are equivalent without a group)
+class EquivalenceGroup  {
+                final int id;
+final String name;
+final String? notes;
+final List<SupplyItem> members;
+final String createdAt;
+final String updatedAt;
+
+                const EquivalenceGroup({required this.id ,required this.name ,this.notes ,required this.members ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^notes.hashCode^members.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EquivalenceGroup &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& notes == other.notes&& members == other.members&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// Files written by an export
+class ExportResult  {
+                /// The output path, plus one file per extra table for CSV exports
+final List<String> files;
+/// Data rows written (all tables)
+final int rowCount;
+
+                const ExportResult({required this.files ,required this.rowCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => files.hashCode^rowCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ExportResult &&
+                runtimeType == other.runtimeType
+                && files == other.files&& rowCount == other.rowCount;
+        
+            }
+
+/// Delivery state of an order line
+enum FulfilmentStatus {
+                    pending,
+partiallyDelivered,
+delivered,
+/// Delivered part done, remainder moved to a follow-up order
+rolledOver,
+/// Closed short; the remainder will not be delivered
+closed,
+                    ;
+                    
+                }
+
+/// Reference entry of the IMPA Marine Stores Guide
+class ImpaCatalogEntry  {
+                /// Six digits, no spaces ("370101")
+final String code;
+final String description;
+final String? unit;
+/// First two digits of the code
+final String section;
+final String? sectionName;
+/// Supply item category used when auto-filling items of this section
+final int? categoryId;
+/// Category code, e.g. "PROVISIONS"
+final String? category;
+
+                const ImpaCatalogEntry({required this.code ,required this.description ,this.unit ,required this.section ,this.sectionName ,this.categoryId ,this.category ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^description.hashCode^unit.hashCode^section.hashCode^sectionName.hashCode^categoryId.hashCode^category.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImpaCatalogEntry &&
+                runtimeType == other.runtimeType
+                && code == other.code&& description == other.description&& unit == other.unit&& section == other.section&& sectionName == other.sectionName&& categoryId == other.categoryId&& category == other.category;
+        
+            }
+
+class ImpaCatalogImportRequest  {
+                /// CSV or XLSX file
+final String filePath;
+final ImpaColumnMapping mapping;
+/// 1-based row holding the column headers (0 = no header row)
+final int headerRow;
+/// Sheet to read (defaults to the first sheet)
+final String? sheetName;
+/// CSV delimiter (detected when None)
+final String? delimiter;
+/// Clear the catalog before loading
+final bool replaceExisting;
+
+                const ImpaCatalogImportRequest({required this.filePath ,required this.mapping ,required this.headerRow ,this.sheetName ,this.delimiter ,required this.replaceExisting ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => filePath.hashCode^mapping.hashCode^headerRow.hashCode^sheetName.hashCode^delimiter.hashCode^replaceExisting.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImpaCatalogImportRequest &&
+                runtimeType == other.runtimeType
+                && filePath == other.filePath&& mapping == other.mapping&& headerRow == other.headerRow&& sheetName == other.sheetName&& delimiter == other.delimiter&& replaceExisting == other.replaceExisting;
+        
+            }
+
+class ImpaCatalogImportResult  {
+                final int inserted;
+final int updated;
+/// Section header rows ("37 NAUTICAL EQUIPMENT") taken as section names
+final int sections;
+/// Rows skipped with the reason ("Row 12: ...")
+final List<String> errors;
+
+                const ImpaCatalogImportResult({required this.inserted ,required this.updated ,required this.sections ,required this.errors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => inserted.hashCode^updated.hashCode^sections.hashCode^errors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImpaCatalogImportResult &&
+                runtimeType == other.runtimeType
+                && inserted == other.inserted&& updated == other.updated&& sections == other.sections&& errors == other.errors;
+        
+            }
+
+/// Supply item whose IMPA code does not match the reference catalog
+class ImpaCodeIssue  {
+                final int supplyItemId;
+final String supplyItemName;
+final String? supplierName;
+final String impaCode;
+final ImpaCodeProblem problem;
+final String message;
+
+                const ImpaCodeIssue({required this.supplyItemId ,required this.supplyItemName ,this.supplierName ,required this.impaCode ,required this.problem ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplyItemId.hashCode^supplyItemName.hashCode^supplierName.hashCode^impaCode.hashCode^problem.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImpaCodeIssue &&
+                runtimeType == other.runtimeType
+                && supplyItemId == other.supplyItemId&& supplyItemName == other.supplyItemName&& supplierName == other.supplierName&& impaCode == other.impaCode&& problem == other.problem&& message == other.message;
+        
+            }
+
+enum ImpaCodeProblem {
+                    /// Not six digits or an unknown section
+invalid,
+/// Well-formed but missing from the reference catalog
+notInCatalog,
+                    ;
+                    
+                }
+
+/// Catalog file columns, each a header name or a column letter ("A")
+class ImpaColumnMapping  {
+                final String code;
+final String description;
+final String? unit;
+/// Section code or "37 Nautical Equipment"; defaults to the code's first two digits
+final String? section;
+
+                const ImpaColumnMapping({required this.code ,required this.description ,this.unit ,this.section ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^description.hashCode^unit.hashCode^section.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImpaColumnMapping &&
+                runtimeType == other.runtimeType
+                && code == other.code&& description == other.description&& unit == other.unit&& section == other.section;
+        
+            }
+
+class ImpaSection  {
+                final String code;
+final String? name;
+final int? categoryId;
+final String? category;
+final int entryCount;
+
+                const ImpaSection({required this.code ,this.name ,this.categoryId ,this.category ,required this.entryCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^name.hashCode^categoryId.hashCode^category.hashCode^entryCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImpaSection &&
+                runtimeType == other.runtimeType
+                && code == other.code&& name == other.name&& categoryId == other.categoryId&& category == other.category&& entryCount == other.entryCount;
+        
+            }
+
+/// Create an order from a template
+class InstantiateTemplateRequest  {
+                final int templateId;
+final int shipId;
+final int? shipVisitId;
+/// Persons on board this call; quantities scale by headcount / base_headcount
+final double? headcount;
+/// Explicit quantity factor (takes precedence over headcount)
+final double? scaleFactor;
+/// Leave empty to use the customer's default currency
+final String currency;
+
+                const InstantiateTemplateRequest({required this.templateId ,required this.shipId ,this.shipVisitId ,this.headcount ,this.scaleFactor ,required this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => templateId.hashCode^shipId.hashCode^shipVisitId.hashCode^headcount.hashCode^scaleFactor.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InstantiateTemplateRequest &&
+                runtimeType == other.runtimeType
+                && templateId == other.templateId&& shipId == other.shipId&& shipVisitId == other.shipVisitId&& headcount == other.headcount&& scaleFactor == other.scaleFactor&& currency == other.currency;
+        
+            }
+
+class Invoice  {
+                final int id;
+final String invoiceNumber;
+final int orderId;
+final String? orderNumber;
+/// Customer account the invoice is billed to
+final int? customerId;
+/// Ship owner / manager the invoice is billed to
+final String customerName;
+final double amount;
+/// Sum of payment allocations, in invoice currency
+final double paidAmount;
+/// Sum of credit notes issued against the invoice
+final double creditedAmount;
+/// amount - paid_amount - credited_amount
+final double balance;
+final String currency;
+final String issueDate;
+final String dueDate;
+final InvoiceStatus status;
+final String? notes;
+final String createdAt;
+final String updatedAt;
+
+                const Invoice({required this.id ,required this.invoiceNumber ,required this.orderId ,this.orderNumber ,this.customerId ,required this.customerName ,required this.amount ,required this.paidAmount ,required this.creditedAmount ,required this.balance ,required this.currency ,required this.issueDate ,required this.dueDate ,required this.status ,this.notes ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^invoiceNumber.hashCode^orderId.hashCode^orderNumber.hashCode^customerId.hashCode^customerName.hashCode^amount.hashCode^paidAmount.hashCode^creditedAmount.hashCode^balance.hashCode^currency.hashCode^issueDate.hashCode^dueDate.hashCode^status.hashCode^notes.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Invoice &&
+                runtimeType == other.runtimeType
+                && id == other.id&& invoiceNumber == other.invoiceNumber&& orderId == other.orderId&& orderNumber == other.orderNumber&& customerId == other.customerId&& customerName == other.customerName&& amount == other.amount&& paidAmount == other.paidAmount&& creditedAmount == other.creditedAmount&& balance == other.balance&& currency == other.currency&& issueDate == other.issueDate&& dueDate == other.dueDate&& status == other.status&& notes == other.notes&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// Invoice status - derived from how much of the invoice has been paid
+enum InvoiceStatus {
+                    open,
+partiallyPaid,
+paid,
+cancelled,
+                    ;
+                    
+                }
+
+/// Delivery event of an order line
+class ItemDelivery  {
+                final int id;
+final int orderItemId;
+final String? productName;
+final double quantity;
+final String deliveredAt;
+final int? shipVisitId;
+final String? notes;
+final String createdAt;
+
+                const ItemDelivery({required this.id ,required this.orderItemId ,this.productName ,required this.quantity ,required this.deliveredAt ,this.shipVisitId ,this.notes ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^orderItemId.hashCode^productName.hashCode^quantity.hashCode^deliveredAt.hashCode^shipVisitId.hashCode^notes.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ItemDelivery &&
+                runtimeType == other.runtimeType
+                && id == other.id&& orderItemId == other.orderItemId&& productName == other.productName&& quantity == other.quantity&& deliveredAt == other.deliveredAt&& shipVisitId == other.shipVisitId&& notes == other.notes&& createdAt == other.createdAt;
+        
+            }
 
 /// Profit calculation for a single item
-class ItemProfit {
-  /// Total cost: buying_price * quantity
-  final double totalCost;
-
-  /// Total revenue: selling_price * quantity
-  final double totalRevenue;
-
-  /// Gross profit: (selling_price - buying_price) * quantity
-  final double grossProfit;
-
-  /// Margin percentage: ((selling_price - buying_price) / selling_price) * 100
-  final double? marginPercent;
-
-  const ItemProfit({
-    required this.totalCost,
-    required this.totalRevenue,
-    required this.grossProfit,
-    this.marginPercent,
-  });
-
-  @override
-  int get hashCode =>
-      totalCost.hashCode ^
-      totalRevenue.hashCode ^
-      grossProfit.hashCode ^
-      marginPercent.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ItemProfit &&
-          runtimeType == other.runtimeType &&
-          totalCost == other.totalCost &&
-          totalRevenue == other.totalRevenue &&
-          grossProfit == other.grossProfit &&
-          marginPercent == other.marginPercent;
-}
-
-class Order {
-  final int id;
-  final String orderNumber;
-  final int shipId;
-  final String? shipName;
-
-  /// Optional link to a ship visit - when the order will be delivered
-  final int? shipVisitId;
-  final String? shipVisitInfo;
-  final OrderStatus status;
-  final String? deliveryPort;
-  final String? notes;
-  final String currency;
-  final String createdAt;
-  final String updatedAt;
-
-  const Order({
-    required this.id,
-    required this.orderNumber,
-    required this.shipId,
-    this.shipName,
-    this.shipVisitId,
-    this.shipVisitInfo,
-    required this.status,
-    this.deliveryPort,
-    this.notes,
-    required this.currency,
-    required this.createdAt,
-    required this.updatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      orderNumber.hashCode ^
-      shipId.hashCode ^
-      shipName.hashCode ^
-      shipVisitId.hashCode ^
-      shipVisitInfo.hashCode ^
-      status.hashCode ^
-      deliveryPort.hashCode ^
-      notes.hashCode ^
-      currency.hashCode ^
-      createdAt.hashCode ^
-      updatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Order &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          orderNumber == other.orderNumber &&
-          shipId == other.shipId &&
-          shipName == other.shipName &&
-          shipVisitId == other.shipVisitId &&
-          shipVisitInfo == other.shipVisitInfo &&
-          status == other.status &&
-          deliveryPort == other.deliveryPort &&
-          notes == other.notes &&
-          currency == other.currency &&
-          createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
-}
-
-class OrderItem {
-  final int id;
-  final int orderId;
-  final String productName;
-  final String? impaCode;
-  final String? description;
-  final double quantity;
-  final String unit;
-
-  /// Cost price - what we pay to supplier
-  final double buyingPrice;
-
-  /// Revenue price - what we charge the customer
-  final double sellingPrice;
-  final String currency;
-
-  /// Delivery type for this item
-  final DeliveryType deliveryType;
-
-  /// When supplier delivers to warehouse (if ViaWarehouse)
-  final String? warehouseDeliveryDate;
-
-  /// When delivered to ship
-  final String? shipDeliveryDate;
-  final String? notes;
-
-  const OrderItem({
-    required this.id,
-    required this.orderId,
-    required this.productName,
-    this.impaCode,
-    this.description,
-    required this.quantity,
-    required this.unit,
-    required this.buyingPrice,
-    required this.sellingPrice,
-    required this.currency,
-    required this.deliveryType,
-    this.warehouseDeliveryDate,
-    this.shipDeliveryDate,
-    this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      orderId.hashCode ^
-      productName.hashCode ^
-      impaCode.hashCode ^
-      description.hashCode ^
-      quantity.hashCode ^
-      unit.hashCode ^
-      buyingPrice.hashCode ^
-      sellingPrice.hashCode ^
-      currency.hashCode ^
-      deliveryType.hashCode ^
-      warehouseDeliveryDate.hashCode ^
-      shipDeliveryDate.hashCode ^
-      notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OrderItem &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          orderId == other.orderId &&
-          productName == other.productName &&
-          impaCode == other.impaCode &&
-          description == other.description &&
-          quantity == other.quantity &&
-          unit == other.unit &&
-          buyingPrice == other.buyingPrice &&
-          sellingPrice == other.sellingPrice &&
-          currency == other.currency &&
-          deliveryType == other.deliveryType &&
-          warehouseDeliveryDate == other.warehouseDeliveryDate &&
-          shipDeliveryDate == other.shipDeliveryDate &&
-          notes == other.notes;
-}
+class ItemProfit  {
+                /// Total cost: (buying_price + landed cost per unit) * quantity
+final double totalCost;
+/// Total revenue: selling_price * quantity
+final double totalRevenue;
+/// Gross profit: total_revenue - total_cost
+final double grossProfit;
+/// Margin percentage: ((selling_price - unit cost) / selling_price) * 100
+final double? marginPercent;
+/// Landed cost (freight, customs, handling) allocated to the line, included in `total_cost`
+final double landedCost;
+/// Minimum margin the pricing rules require for this item, in percent
+final double? minMarginPercent;
+/// True when the margin is below `min_margin_percent`
+final bool belowMarginFloor;
+
+                const ItemProfit({required this.totalCost ,required this.totalRevenue ,required this.grossProfit ,this.marginPercent ,required this.landedCost ,this.minMarginPercent ,required this.belowMarginFloor ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalCost.hashCode^totalRevenue.hashCode^grossProfit.hashCode^marginPercent.hashCode^landedCost.hashCode^minMarginPercent.hashCode^belowMarginFloor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ItemProfit &&
+                runtimeType == other.runtimeType
+                && totalCost == other.totalCost&& totalRevenue == other.totalRevenue&& grossProfit == other.grossProfit&& marginPercent == other.marginPercent&& landedCost == other.landedCost&& minMarginPercent == other.minMarginPercent&& belowMarginFloor == other.belowMarginFloor;
+        
+            }
+
+/// Weight of an order line, for allocation by weight
+class ItemWeight  {
+                final int orderItemId;
+final double weightKg;
+
+                const ItemWeight({required this.orderItemId ,required this.weightKg ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^weightKg.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ItemWeight &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& weightKg == other.weightKg;
+        
+            }
+
+/// Landed cost recorded against an order (purchase order) or one of its goods receipts
+class LandedCost  {
+                final int id;
+final int orderId;
+final String? orderNumber;
+/// Goods receipt (stock IN movement) the cost belongs to; None = whole order
+final int? stockMovementId;
+final LandedCostType costType;
+final String? description;
+final double amount;
+final String currency;
+final AllocationMethod allocationMethod;
+/// Freight bill / customs declaration number
+final String? reference;
+final List<LandedCostAllocation> allocations;
+final String createdAt;
+
+                const LandedCost({required this.id ,required this.orderId ,this.orderNumber ,this.stockMovementId ,required this.costType ,this.description ,required this.amount ,required this.currency ,required this.allocationMethod ,this.reference ,required this.allocations ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^orderId.hashCode^orderNumber.hashCode^stockMovementId.hashCode^costType.hashCode^description.hashCode^amount.hashCode^currency.hashCode^allocationMethod.hashCode^reference.hashCode^allocations.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LandedCost &&
+                runtimeType == other.runtimeType
+                && id == other.id&& orderId == other.orderId&& orderNumber == other.orderNumber&& stockMovementId == other.stockMovementId&& costType == other.costType&& description == other.description&& amount == other.amount&& currency == other.currency&& allocationMethod == other.allocationMethod&& reference == other.reference&& allocations == other.allocations&& createdAt == other.createdAt;
+        
+            }
+
+class LandedCostAllocation  {
+                final int id;
+final int landedCostId;
+final int orderItemId;
+final String? productName;
+/// Value, weight or quantity of the line
+final double basis;
+final double amount;
+/// amount / line quantity
+final double amountPerUnit;
+
+                const LandedCostAllocation({required this.id ,required this.landedCostId ,required this.orderItemId ,this.productName ,required this.basis ,required this.amount ,required this.amountPerUnit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^landedCostId.hashCode^orderItemId.hashCode^productName.hashCode^basis.hashCode^amount.hashCode^amountPerUnit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LandedCostAllocation &&
+                runtimeType == other.runtimeType
+                && id == other.id&& landedCostId == other.landedCostId&& orderItemId == other.orderItemId&& productName == other.productName&& basis == other.basis&& amount == other.amount&& amountPerUnit == other.amountPerUnit;
+        
+            }
+
+enum LandedCostType {
+                    freight,
+customs,
+handling,
+insurance,
+other,
+                    ;
+                    
+                }
+
+/// Result of matching one invoice line against the order and goods receipts
+enum MatchStatus {
+                    /// Ordered, received and billed quantities and prices agree
+matched,
+/// Billed price differs from the order's buying price
+priceVariance,
+/// Billed quantity differs from ordered or received quantity
+quantityVariance,
+/// No goods receipt (stock IN) recorded for the line yet
+notReceived,
+/// Line is not linked to a purchase order line
+unmatched,
+/// Order line is not in the invoicing supplier's catalog
+wrongSupplier,
+                    ;
+                    
+                }
+
+/// Order line whose selling price differs from the customer's contract
+class OffContractLine  {
+                final int orderId;
+final String orderNumber;
+final int orderItemId;
+final int customerId;
+final String customerName;
+final String productName;
+final String impaCode;
+final double quantity;
+final String currency;
+final double sellingPrice;
+final double contractPrice;
+/// (selling_price - contract_price) * quantity
+final double revenueDifference;
+final int agreementId;
+final String agreementName;
+
+                const OffContractLine({required this.orderId ,required this.orderNumber ,required this.orderItemId ,required this.customerId ,required this.customerName ,required this.productName ,required this.impaCode ,required this.quantity ,required this.currency ,required this.sellingPrice ,required this.contractPrice ,required this.revenueDifference ,required this.agreementId ,required this.agreementName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^orderNumber.hashCode^orderItemId.hashCode^customerId.hashCode^customerName.hashCode^productName.hashCode^impaCode.hashCode^quantity.hashCode^currency.hashCode^sellingPrice.hashCode^contractPrice.hashCode^revenueDifference.hashCode^agreementId.hashCode^agreementName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OffContractLine &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& orderNumber == other.orderNumber&& orderItemId == other.orderItemId&& customerId == other.customerId&& customerName == other.customerName&& productName == other.productName&& impaCode == other.impaCode&& quantity == other.quantity&& currency == other.currency&& sellingPrice == other.sellingPrice&& contractPrice == other.contractPrice&& revenueDifference == other.revenueDifference&& agreementId == other.agreementId&& agreementName == other.agreementName;
+        
+            }
+
+class Order  {
+                final int id;
+final String orderNumber;
+final int shipId;
+final String? shipName;
+/// Optional link to a ship visit - when the order will be delivered
+final int? shipVisitId;
+final String? shipVisitInfo;
+/// Customer the order is billed to (owner of the ship at order time)
+final int? customerId;
+final String? customerName;
+/// Payment terms inherited from the customer when the order was created
+final int? paymentTermsDays;
+final OrderStatus status;
+final String? deliveryPort;
+final String? notes;
+final String currency;
+/// Original order when this is a back-order follow-up
+final int? parentOrderId;
+/// Reason the order may be marked delivered without a signed delivery note
+final String? deliveryNoteWaiver;
+final String createdAt;
+final String updatedAt;
+
+                const Order({required this.id ,required this.orderNumber ,required this.shipId ,this.shipName ,this.shipVisitId ,this.shipVisitInfo ,this.customerId ,this.customerName ,this.paymentTermsDays ,required this.status ,this.deliveryPort ,this.notes ,required this.currency ,this.parentOrderId ,this.deliveryNoteWaiver ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^orderNumber.hashCode^shipId.hashCode^shipName.hashCode^shipVisitId.hashCode^shipVisitInfo.hashCode^customerId.hashCode^customerName.hashCode^paymentTermsDays.hashCode^status.hashCode^deliveryPort.hashCode^notes.hashCode^currency.hashCode^parentOrderId.hashCode^deliveryNoteWaiver.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Order &&
+                runtimeType == other.runtimeType
+                && id == other.id&& orderNumber == other.orderNumber&& shipId == other.shipId&& shipName == other.shipName&& shipVisitId == other.shipVisitId&& shipVisitInfo == other.shipVisitInfo&& customerId == other.customerId&& customerName == other.customerName&& paymentTermsDays == other.paymentTermsDays&& status == other.status&& deliveryPort == other.deliveryPort&& notes == other.notes&& currency == other.currency&& parentOrderId == other.parentOrderId&& deliveryNoteWaiver == other.deliveryNoteWaiver&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// Charge line of an order, in the order's currency.
+///
+/// A discount has no cost and a negative revenue.
+class OrderCharge  {
+                final int id;
+final int orderId;
+final ChargeType chargeType;
+final String? description;
+final ChargeBilling billing;
+final double? markupPercent;
+/// What the charge costs us
+final double costAmount;
+/// What the customer is billed (negative for discounts)
+final double revenueAmount;
+final String currency;
+final String? notes;
+final String createdAt;
+
+                const OrderCharge({required this.id ,required this.orderId ,required this.chargeType ,this.description ,required this.billing ,this.markupPercent ,required this.costAmount ,required this.revenueAmount ,required this.currency ,this.notes ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^orderId.hashCode^chargeType.hashCode^description.hashCode^billing.hashCode^markupPercent.hashCode^costAmount.hashCode^revenueAmount.hashCode^currency.hashCode^notes.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderCharge &&
+                runtimeType == other.runtimeType
+                && id == other.id&& orderId == other.orderId&& chargeType == other.chargeType&& description == other.description&& billing == other.billing&& markupPercent == other.markupPercent&& costAmount == other.costAmount&& revenueAmount == other.revenueAmount&& currency == other.currency&& notes == other.notes&& createdAt == other.createdAt;
+        
+            }
+
+/// Documents an order needs before it can be Prepared or sent on its way
+class OrderCompliance  {
+                final int orderId;
+final String orderNumber;
+/// Lines that are dangerous goods, need an SDS or are prescription-only
+final int regulatedLines;
+final List<ComplianceIssue> issues;
+final bool isCompliant;
+
+                const OrderCompliance({required this.orderId ,required this.orderNumber ,required this.regulatedLines ,required this.issues ,required this.isCompliant ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^orderNumber.hashCode^regulatedLines.hashCode^issues.hashCode^isCompliant.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderCompliance &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& orderNumber == other.orderNumber&& regulatedLines == other.regulatedLines&& issues == other.issues&& isCompliant == other.isCompliant;
+        
+            }
+
+/// Filters for exporting the order list (all optional)
+class OrderExportFilter  {
+                final OrderStatus? status;
+final int? shipId;
+final int? customerId;
+/// Orders created on or after this date (YYYY-MM-DD)
+final String? dateFrom;
+/// Orders created on or before this date (YYYY-MM-DD)
+final String? dateTo;
+
+                const OrderExportFilter({this.status ,this.shipId ,this.customerId ,this.dateFrom ,this.dateTo ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => status.hashCode^shipId.hashCode^customerId.hashCode^dateFrom.hashCode^dateTo.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderExportFilter &&
+                runtimeType == other.runtimeType
+                && status == other.status&& shipId == other.shipId&& customerId == other.customerId&& dateFrom == other.dateFrom&& dateTo == other.dateTo;
+        
+            }
+
+class OrderItem  {
+                final int id;
+final int orderId;
+final String productName;
+final String? impaCode;
+final String? description;
+final double quantity;
+final String unit;
+/// Cost price - what we pay to supplier
+final double buyingPrice;
+/// Revenue price - what we charge the customer
+final double sellingPrice;
+final String currency;
+/// Delivery type for this item
+final DeliveryType deliveryType;
+/// When supplier delivers to warehouse (if ViaWarehouse)
+final String? warehouseDeliveryDate;
+/// When delivered to ship
+final String? shipDeliveryDate;
+final String? notes;
+/// Sum of the recorded deliveries
+final double deliveredQuantity;
+/// Still to be delivered on this order (0 once the line is closed)
+final double backOrderedQuantity;
+/// Moved to a follow-up order (no longer part of `quantity`)
+final double rolledOverQuantity;
+/// Dropped when the line was closed short (no longer part of `quantity`)
+final double closedQuantity;
+final String? closedAt;
+final String? closeReason;
+final FulfilmentStatus fulfilment;
+/// Line of the original order this back-order line comes from
+final int? backorderOfItemId;
+/// Sent back by the ship after delivery (still part of `quantity`)
+final double returnedQuantity;
+
+                const OrderItem({required this.id ,required this.orderId ,required this.productName ,this.impaCode ,this.description ,required this.quantity ,required this.unit ,required this.buyingPrice ,required this.sellingPrice ,required this.currency ,required this.deliveryType ,this.warehouseDeliveryDate ,this.shipDeliveryDate ,this.notes ,required this.deliveredQuantity ,required this.backOrderedQuantity ,required this.rolledOverQuantity ,required this.closedQuantity ,this.closedAt ,this.closeReason ,required this.fulfilment ,this.backorderOfItemId ,required this.returnedQuantity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^orderId.hashCode^productName.hashCode^impaCode.hashCode^description.hashCode^quantity.hashCode^unit.hashCode^buyingPrice.hashCode^sellingPrice.hashCode^currency.hashCode^deliveryType.hashCode^warehouseDeliveryDate.hashCode^shipDeliveryDate.hashCode^notes.hashCode^deliveredQuantity.hashCode^backOrderedQuantity.hashCode^rolledOverQuantity.hashCode^closedQuantity.hashCode^closedAt.hashCode^closeReason.hashCode^fulfilment.hashCode^backorderOfItemId.hashCode^returnedQuantity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderItem &&
+                runtimeType == other.runtimeType
+                && id == other.id&& orderId == other.orderId&& productName == other.productName&& impaCode == other.impaCode&& description == other.description&& quantity == other.quantity&& unit == other.unit&& buyingPrice == other.buyingPrice&& sellingPrice == other.sellingPrice&& currency == other.currency&& deliveryType == other.deliveryType&& warehouseDeliveryDate == other.warehouseDeliveryDate&& shipDeliveryDate == other.shipDeliveryDate&& notes == other.notes&& deliveredQuantity == other.deliveredQuantity&& backOrderedQuantity == other.backOrderedQuantity&& rolledOverQuantity == other.rolledOverQuantity&& closedQuantity == other.closedQuantity&& closedAt == other.closedAt&& closeReason == other.closeReason&& fulfilment == other.fulfilment&& backorderOfItemId == other.backorderOfItemId&& returnedQuantity == other.returnedQuantity;
+        
+            }
+
+/// Audit record of a split or merge between two orders
+class OrderLink  {
+                final int id;
+final OrderLinkType linkType;
+final int sourceOrderId;
+final String? sourceOrderNumber;
+final int targetOrderId;
+final String? targetOrderNumber;
+/// Order items moved from source to target
+final Int32List orderItemIds;
+final String createdAt;
+
+                const OrderLink({required this.id ,required this.linkType ,required this.sourceOrderId ,this.sourceOrderNumber ,required this.targetOrderId ,this.targetOrderNumber ,required this.orderItemIds ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^linkType.hashCode^sourceOrderId.hashCode^sourceOrderNumber.hashCode^targetOrderId.hashCode^targetOrderNumber.hashCode^orderItemIds.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderLink &&
+                runtimeType == other.runtimeType
+                && id == other.id&& linkType == other.linkType&& sourceOrderId == other.sourceOrderId&& sourceOrderNumber == other.sourceOrderNumber&& targetOrderId == other.targetOrderId&& targetOrderNumber == other.targetOrderNumber&& orderItemIds == other.orderItemIds&& createdAt == other.createdAt;
+        
+            }
+
+/// How two orders are related after a split or a merge
+enum OrderLinkType {
+                    /// Items were moved out of the source into a new target order
+split,
+/// The source order was merged into the target and cancelled
+merge,
+                    ;
+                    
+                }
 
 /// Order profit information
-class OrderProfitInfo {
-  final int orderId;
-  final String orderNumber;
-  final String shipName;
-  final double totalRevenue;
-  final double totalCost;
-  final double profit;
-  final double marginPercent;
-  final String currency;
-
-  const OrderProfitInfo({
-    required this.orderId,
-    required this.orderNumber,
-    required this.shipName,
-    required this.totalRevenue,
-    required this.totalCost,
-    required this.profit,
-    required this.marginPercent,
-    required this.currency,
-  });
-
-  @override
-  int get hashCode =>
-      orderId.hashCode ^
-      orderNumber.hashCode ^
-      shipName.hashCode ^
-      totalRevenue.hashCode ^
-      totalCost.hashCode ^
-      profit.hashCode ^
-      marginPercent.hashCode ^
-      currency.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OrderProfitInfo &&
-          runtimeType == other.runtimeType &&
-          orderId == other.orderId &&
-          orderNumber == other.orderNumber &&
-          shipName == other.shipName &&
-          totalRevenue == other.totalRevenue &&
-          totalCost == other.totalCost &&
-          profit == other.profit &&
-          marginPercent == other.marginPercent &&
-          currency == other.currency;
-}
+class OrderProfitInfo  {
+                final int orderId;
+final String orderNumber;
+final String shipName;
+final double totalRevenue;
+final double totalCost;
+final double profit;
+final double marginPercent;
+final String currency;
+
+                const OrderProfitInfo({required this.orderId ,required this.orderNumber ,required this.shipName ,required this.totalRevenue ,required this.totalCost ,required this.profit ,required this.marginPercent ,required this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^orderNumber.hashCode^shipName.hashCode^totalRevenue.hashCode^totalCost.hashCode^profit.hashCode^marginPercent.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderProfitInfo &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& orderNumber == other.orderNumber&& shipName == other.shipName&& totalRevenue == other.totalRevenue&& totalCost == other.totalCost&& profit == other.profit&& marginPercent == other.marginPercent&& currency == other.currency;
+        
+            }
+
+/// Goods returned by a ship against a delivered order
+class OrderReturn  {
+                final int id;
+/// RET-2026-0001
+final String returnNumber;
+final int orderId;
+final String? orderNumber;
+final String returnDate;
+final String? notes;
+/// Revenue given back (sum of the line credit amounts)
+final double creditAmount;
+final String currency;
+final int? creditNoteId;
+final String? creditNoteNumber;
+final List<OrderReturnLine> lines;
+final String createdAt;
+
+                const OrderReturn({required this.id ,required this.returnNumber ,required this.orderId ,this.orderNumber ,required this.returnDate ,this.notes ,required this.creditAmount ,required this.currency ,this.creditNoteId ,this.creditNoteNumber ,required this.lines ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^returnNumber.hashCode^orderId.hashCode^orderNumber.hashCode^returnDate.hashCode^notes.hashCode^creditAmount.hashCode^currency.hashCode^creditNoteId.hashCode^creditNoteNumber.hashCode^lines.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderReturn &&
+                runtimeType == other.runtimeType
+                && id == other.id&& returnNumber == other.returnNumber&& orderId == other.orderId&& orderNumber == other.orderNumber&& returnDate == other.returnDate&& notes == other.notes&& creditAmount == other.creditAmount&& currency == other.currency&& creditNoteId == other.creditNoteId&& creditNoteNumber == other.creditNoteNumber&& lines == other.lines&& createdAt == other.createdAt;
+        
+            }
+
+class OrderReturnLine  {
+                final int id;
+final int orderItemId;
+final String? productName;
+final double quantity;
+final String? unit;
+final ReturnReason reason;
+/// Put back into warehouse stock with a Return movement
+final bool restocked;
+final int? stockMovementId;
+/// quantity * selling_price
+final double creditAmount;
+/// quantity * buying_price when restocked, 0 when written off
+final double costAmount;
+
+                const OrderReturnLine({required this.id ,required this.orderItemId ,this.productName ,required this.quantity ,this.unit ,required this.reason ,required this.restocked ,this.stockMovementId ,required this.creditAmount ,required this.costAmount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^orderItemId.hashCode^productName.hashCode^quantity.hashCode^unit.hashCode^reason.hashCode^restocked.hashCode^stockMovementId.hashCode^creditAmount.hashCode^costAmount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderReturnLine &&
+                runtimeType == other.runtimeType
+                && id == other.id&& orderItemId == other.orderItemId&& productName == other.productName&& quantity == other.quantity&& unit == other.unit&& reason == other.reason&& restocked == other.restocked&& stockMovementId == other.stockMovementId&& creditAmount == other.creditAmount&& costAmount == other.costAmount;
+        
+            }
 
 /// Order Status - Enforced state machine flow
 /// NEW -> QUOTED -> AGREED -> WAITING_GOODS -> PREPARED -> ON_WAY -> DELIVERED -> INVOICED
 enum OrderStatus {
-  new_,
-  quoted,
-  agreed,
-  waitingGoods,
-  prepared,
-  onWay,
-  delivered,
-  invoiced,
-  cancelled,
-  ;
-}
+                    new_,
+quoted,
+agreed,
+waitingGoods,
+prepared,
+onWay,
+delivered,
+invoiced,
+cancelled,
+                    ;
+                    
+                }
 
 /// Order totals calculated in Rust
-class OrderTotals {
-  final int itemCount;
-  final double totalCost;
-  final double totalRevenue;
-  final double grossProfit;
-  final double? marginPercent;
-  final String currency;
-
-  const OrderTotals({
-    required this.itemCount,
-    required this.totalCost,
-    required this.totalRevenue,
-    required this.grossProfit,
-    this.marginPercent,
-    required this.currency,
-  });
-
-  @override
-  int get hashCode =>
-      itemCount.hashCode ^
-      totalCost.hashCode ^
-      totalRevenue.hashCode ^
-      grossProfit.hashCode ^
-      marginPercent.hashCode ^
-      currency.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OrderTotals &&
-          runtimeType == other.runtimeType &&
-          itemCount == other.itemCount &&
-          totalCost == other.totalCost &&
-          totalRevenue == other.totalRevenue &&
-          grossProfit == other.grossProfit &&
-          marginPercent == other.marginPercent &&
-          currency == other.currency;
-}
-
-class OrderWithItems {
-  final Order order;
-  final List<OrderItem> items;
-  final OrderTotals totals;
-
-  const OrderWithItems({
-    required this.order,
-    required this.items,
-    required this.totals,
-  });
-
-  @override
-  int get hashCode => order.hashCode ^ items.hashCode ^ totals.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is OrderWithItems &&
-          runtimeType == other.runtimeType &&
-          order == other.order &&
-          items == other.items &&
-          totals == other.totals;
-}
-
-class Port {
-  final int id;
-  final String name;
-  final String country;
-  final String? city;
-  final String timezone;
-  final double? latitude;
-  final double? longitude;
-  final String? notes;
-  final bool isActive;
-  final String createdAt;
-  final String updatedAt;
-
-  const Port({
-    required this.id,
-    required this.name,
-    required this.country,
-    this.city,
-    required this.timezone,
-    this.latitude,
-    this.longitude,
-    this.notes,
-    required this.isActive,
-    required this.createdAt,
-    required this.updatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      name.hashCode ^
-      country.hashCode ^
-      city.hashCode ^
-      timezone.hashCode ^
-      latitude.hashCode ^
-      longitude.hashCode ^
-      notes.hashCode ^
-      isActive.hashCode ^
-      createdAt.hashCode ^
-      updatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Port &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          name == other.name &&
-          country == other.country &&
-          city == other.city &&
-          timezone == other.timezone &&
-          latitude == other.latitude &&
-          longitude == other.longitude &&
-          notes == other.notes &&
-          isActive == other.isActive &&
-          createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
-}
+class OrderTotals  {
+                final int itemCount;
+final int chargeCount;
+/// Items and charges
+final double totalCost;
+/// Items and charges (discounts reduce revenue)
+final double totalRevenue;
+/// Part of `total_cost` coming from order charges
+final double chargesCost;
+/// Part of `total_revenue` coming from order charges
+final double chargesRevenue;
+/// Part of `total_cost` coming from landed costs allocated to the items
+final double landedCost;
+/// Revenue given back for returned goods, already deducted from `total_revenue`
+final double returnsRevenue;
+/// Cost of returned goods put back into stock, already deducted from `total_cost`
+final double returnsCost;
+final double grossProfit;
+final double? marginPercent;
+final String currency;
+
+                const OrderTotals({required this.itemCount ,required this.chargeCount ,required this.totalCost ,required this.totalRevenue ,required this.chargesCost ,required this.chargesRevenue ,required this.landedCost ,required this.returnsRevenue ,required this.returnsCost ,required this.grossProfit ,this.marginPercent ,required this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => itemCount.hashCode^chargeCount.hashCode^totalCost.hashCode^totalRevenue.hashCode^chargesCost.hashCode^chargesRevenue.hashCode^landedCost.hashCode^returnsRevenue.hashCode^returnsCost.hashCode^grossProfit.hashCode^marginPercent.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderTotals &&
+                runtimeType == other.runtimeType
+                && itemCount == other.itemCount&& chargeCount == other.chargeCount&& totalCost == other.totalCost&& totalRevenue == other.totalRevenue&& chargesCost == other.chargesCost&& chargesRevenue == other.chargesRevenue&& landedCost == other.landedCost&& returnsRevenue == other.returnsRevenue&& returnsCost == other.returnsCost&& grossProfit == other.grossProfit&& marginPercent == other.marginPercent&& currency == other.currency;
+        
+            }
+
+class OrderWithItems  {
+                final Order order;
+final List<OrderItem> items;
+final OrderTotals totals;
+/// Credit position of the order's customer (warning set when over the limit)
+final CreditCheck? creditCheck;
+
+                const OrderWithItems({required this.order ,required this.items ,required this.totals ,this.creditCheck ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => order.hashCode^items.hashCode^totals.hashCode^creditCheck.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OrderWithItems &&
+                runtimeType == other.runtimeType
+                && order == other.order&& items == other.items&& totals == other.totals&& creditCheck == other.creditCheck;
+        
+            }
+
+/// Content of one pack of a supply item, e.g. 1 KOLI = 24 ADET
+class PackSize  {
+                final int id;
+final int supplyItemId;
+final String packUnit;
+final double quantity;
+final String contentUnit;
+
+                const PackSize({required this.id ,required this.supplyItemId ,required this.packUnit ,required this.quantity ,required this.contentUnit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^supplyItemId.hashCode^packUnit.hashCode^quantity.hashCode^contentUnit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PackSize &&
+                runtimeType == other.runtimeType
+                && id == other.id&& supplyItemId == other.supplyItemId&& packUnit == other.packUnit&& quantity == other.quantity&& contentUnit == other.contentUnit;
+        
+            }
+
+/// Open supplier invoice falling due within the requested window
+class PayableDue  {
+                final int supplierInvoiceId;
+final int supplierId;
+final String? supplierName;
+final String invoiceNumber;
+final String currency;
+final String dueDate;
+final double balance;
+/// Negative when overdue
+final PlatformInt64 daysUntilDue;
+
+                const PayableDue({required this.supplierInvoiceId ,required this.supplierId ,this.supplierName ,required this.invoiceNumber ,required this.currency ,required this.dueDate ,required this.balance ,required this.daysUntilDue ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierInvoiceId.hashCode^supplierId.hashCode^supplierName.hashCode^invoiceNumber.hashCode^currency.hashCode^dueDate.hashCode^balance.hashCode^daysUntilDue.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PayableDue &&
+                runtimeType == other.runtimeType
+                && supplierInvoiceId == other.supplierInvoiceId&& supplierId == other.supplierId&& supplierName == other.supplierName&& invoiceNumber == other.invoiceNumber&& currency == other.currency&& dueDate == other.dueDate&& balance == other.balance&& daysUntilDue == other.daysUntilDue;
+        
+            }
+
+class Payment  {
+                final int id;
+/// Customer account the payment came from
+final int? customerId;
+final String customerName;
+final double amount;
+final String currency;
+/// Part of the payment already applied to invoices, in payment currency
+final double allocatedAmount;
+/// Overpayment kept as credit on the customer account
+final double unallocatedAmount;
+final String paymentDate;
+final PaymentMethod method;
+final String? reference;
+final String? notes;
+final List<PaymentAllocation> allocations;
+final String createdAt;
+
+                const Payment({required this.id ,this.customerId ,required this.customerName ,required this.amount ,required this.currency ,required this.allocatedAmount ,required this.unallocatedAmount ,required this.paymentDate ,required this.method ,this.reference ,this.notes ,required this.allocations ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^customerId.hashCode^customerName.hashCode^amount.hashCode^currency.hashCode^allocatedAmount.hashCode^unallocatedAmount.hashCode^paymentDate.hashCode^method.hashCode^reference.hashCode^notes.hashCode^allocations.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Payment &&
+                runtimeType == other.runtimeType
+                && id == other.id&& customerId == other.customerId&& customerName == other.customerName&& amount == other.amount&& currency == other.currency&& allocatedAmount == other.allocatedAmount&& unallocatedAmount == other.unallocatedAmount&& paymentDate == other.paymentDate&& method == other.method&& reference == other.reference&& notes == other.notes&& allocations == other.allocations&& createdAt == other.createdAt;
+        
+            }
+
+class PaymentAllocation  {
+                final int id;
+final int paymentId;
+final int invoiceId;
+final String? invoiceNumber;
+/// Amount taken from the payment, in payment currency
+final double amount;
+/// Payment currency -> invoice currency
+final double exchangeRate;
+/// amount * exchange_rate, in invoice currency
+final double invoiceAmount;
+
+                const PaymentAllocation({required this.id ,required this.paymentId ,required this.invoiceId ,this.invoiceNumber ,required this.amount ,required this.exchangeRate ,required this.invoiceAmount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^paymentId.hashCode^invoiceId.hashCode^invoiceNumber.hashCode^amount.hashCode^exchangeRate.hashCode^invoiceAmount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaymentAllocation &&
+                runtimeType == other.runtimeType
+                && id == other.id&& paymentId == other.paymentId&& invoiceId == other.invoiceId&& invoiceNumber == other.invoiceNumber&& amount == other.amount&& exchangeRate == other.exchangeRate&& invoiceAmount == other.invoiceAmount;
+        
+            }
+
+class PaymentAllocationRequest  {
+                final int invoiceId;
+/// Amount in payment currency
+final double amount;
+/// Required when payment and invoice currencies differ
+final double? exchangeRate;
+
+                const PaymentAllocationRequest({required this.invoiceId ,required this.amount ,this.exchangeRate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => invoiceId.hashCode^amount.hashCode^exchangeRate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaymentAllocationRequest &&
+                runtimeType == other.runtimeType
+                && invoiceId == other.invoiceId&& amount == other.amount&& exchangeRate == other.exchangeRate;
+        
+            }
+
+/// How a payment was received
+enum PaymentMethod {
+                    bankTransfer,
+cash,
+creditCard,
+cheque,
+                    ;
+                    
+                }
+
+enum PdfDocumentKind {
+                    proforma,
+quotation,
+invoice,
+deliveryNote,
+                    ;
+                    
+                }
+
+class PdfRenderRequest  {
+                final int orderId;
+final PdfDocumentKind kind;
+/// Invoice or delivery note to print (defaults to the order's latest one)
+final int? documentId;
+/// VAT added on top of the net amount (0 = exempt)
+final double taxRatePercent;
+/// Where the PDF is written
+final String outputPath;
+
+                const PdfRenderRequest({required this.orderId ,required this.kind ,this.documentId ,required this.taxRatePercent ,required this.outputPath ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^kind.hashCode^documentId.hashCode^taxRatePercent.hashCode^outputPath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PdfRenderRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& kind == other.kind&& documentId == other.documentId&& taxRatePercent == other.taxRatePercent&& outputPath == other.outputPath;
+        
+            }
+
+class PdfRenderResult  {
+                final String filePath;
+final int pageCount;
+
+                const PdfRenderResult({required this.filePath ,required this.pageCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => filePath.hashCode^pageCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PdfRenderResult &&
+                runtimeType == other.runtimeType
+                && filePath == other.filePath&& pageCount == other.pageCount;
+        
+            }
+
+class PharmacistSignOffRequest  {
+                final String pharmacistName;
+/// Pharmacist's chamber registration number
+final String licenseNumber;
+final String? notes;
+
+                const PharmacistSignOffRequest({required this.pharmacistName ,required this.licenseNumber ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pharmacistName.hashCode^licenseNumber.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PharmacistSignOffRequest &&
+                runtimeType == other.runtimeType
+                && pharmacistName == other.pharmacistName&& licenseNumber == other.licenseNumber&& notes == other.notes;
+        
+            }
+
+class Port  {
+                final int id;
+final String name;
+final String country;
+final String? city;
+final String timezone;
+final double? latitude;
+final double? longitude;
+final String? notes;
+/// Open to international traffic under customs control (bonded goods can be delivered)
+final bool isInternational;
+final bool isActive;
+final String createdAt;
+final String updatedAt;
+
+                const Port({required this.id ,required this.name ,required this.country ,this.city ,required this.timezone ,this.latitude ,this.longitude ,this.notes ,required this.isInternational ,required this.isActive ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^country.hashCode^city.hashCode^timezone.hashCode^latitude.hashCode^longitude.hashCode^notes.hashCode^isInternational.hashCode^isActive.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Port &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& country == other.country&& city == other.city&& timezone == other.timezone&& latitude == other.latitude&& longitude == other.longitude&& notes == other.notes&& isInternational == other.isInternational&& isActive == other.isActive&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+class PriceAgreement  {
+                final int id;
+final int customerId;
+final String? customerName;
+final String name;
+final String currency;
+final String validFrom;
+final String validTo;
+final String? notes;
+final bool isActive;
+final List<PriceAgreementLine> lines;
+final String createdAt;
+final String updatedAt;
+
+                const PriceAgreement({required this.id ,required this.customerId ,this.customerName ,required this.name ,required this.currency ,required this.validFrom ,required this.validTo ,this.notes ,required this.isActive ,required this.lines ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^customerId.hashCode^customerName.hashCode^name.hashCode^currency.hashCode^validFrom.hashCode^validTo.hashCode^notes.hashCode^isActive.hashCode^lines.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceAgreement &&
+                runtimeType == other.runtimeType
+                && id == other.id&& customerId == other.customerId&& customerName == other.customerName&& name == other.name&& currency == other.currency&& validFrom == other.validFrom&& validTo == other.validTo&& notes == other.notes&& isActive == other.isActive&& lines == other.lines&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// Contract price for one IMPA item or a whole IMPA group.
+///
+/// Exactly one of `impa_code` / `impa_group` and one of `fixed_price` /
+/// `markup_percent` is set.
+class PriceAgreementLine  {
+                final int id;
+final int agreementId;
+/// Full IMPA code (item-level price)
+final String? impaCode;
+/// IMPA code prefix, e.g. "37" or "3701" (category-level price)
+final String? impaGroup;
+final String? description;
+/// Fixed selling price per unit
+final double? fixedPrice;
+/// Markup on the buying price, in percent
+final double? markupPercent;
+
+                const PriceAgreementLine({required this.id ,required this.agreementId ,this.impaCode ,this.impaGroup ,this.description ,this.fixedPrice ,this.markupPercent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^agreementId.hashCode^impaCode.hashCode^impaGroup.hashCode^description.hashCode^fixedPrice.hashCode^markupPercent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceAgreementLine &&
+                runtimeType == other.runtimeType
+                && id == other.id&& agreementId == other.agreementId&& impaCode == other.impaCode&& impaGroup == other.impaGroup&& description == other.description&& fixedPrice == other.fixedPrice&& markupPercent == other.markupPercent;
+        
+            }
+
+/// One supply item price change; the first entry of an item has no old price
+class PriceChange  {
+                final int id;
+final int supplyItemId;
+/// YYYY-MM-DD
+final String effectiveDate;
+final double? oldPrice;
+final String? oldCurrency;
+final double newPrice;
+final String currency;
+/// Percent change, when old and new price share a currency
+final double? changePercent;
+final PriceChangeSource source;
+/// Price list file name, RFQ number, ...
+final String? reference;
+final String createdAt;
+
+                const PriceChange({required this.id ,required this.supplyItemId ,required this.effectiveDate ,this.oldPrice ,this.oldCurrency ,required this.newPrice ,required this.currency ,this.changePercent ,required this.source ,this.reference ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^supplyItemId.hashCode^effectiveDate.hashCode^oldPrice.hashCode^oldCurrency.hashCode^newPrice.hashCode^currency.hashCode^changePercent.hashCode^source.hashCode^reference.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceChange &&
+                runtimeType == other.runtimeType
+                && id == other.id&& supplyItemId == other.supplyItemId&& effectiveDate == other.effectiveDate&& oldPrice == other.oldPrice&& oldCurrency == other.oldCurrency&& newPrice == other.newPrice&& currency == other.currency&& changePercent == other.changePercent&& source == other.source&& reference == other.reference&& createdAt == other.createdAt;
+        
+            }
+
+/// Where a supply item price change came from
+enum PriceChangeSource {
+                    /// Edited by hand on the supply item
+manual,
+/// Supplier price list import
+priceListImport,
+/// Awarded quotation (RFQ)
+rfqAward,
+                    ;
+                    
+                }
+
+/// One line of the price list diff
+class PriceListChange  {
+                /// Row number in the file (1-based), None for discontinued items
+final int? rowNumber;
+final PriceListChangeKind kind;
+final PriceListMatchKind? matchedBy;
+final int? supplyItemId;
+final String? supplierSku;
+final String? impaCode;
+final String name;
+final String? unit;
+final double? oldPrice;
+final String? oldCurrency;
+final double? newPrice;
+final String? currency;
+final double? changePercent;
+/// Why the row cannot be imported
+final String? error;
+
+                const PriceListChange({this.rowNumber ,required this.kind ,this.matchedBy ,this.supplyItemId ,this.supplierSku ,this.impaCode ,required this.name ,this.unit ,this.oldPrice ,this.oldCurrency ,this.newPrice ,this.currency ,this.changePercent ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => rowNumber.hashCode^kind.hashCode^matchedBy.hashCode^supplyItemId.hashCode^supplierSku.hashCode^impaCode.hashCode^name.hashCode^unit.hashCode^oldPrice.hashCode^oldCurrency.hashCode^newPrice.hashCode^currency.hashCode^changePercent.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceListChange &&
+                runtimeType == other.runtimeType
+                && rowNumber == other.rowNumber&& kind == other.kind&& matchedBy == other.matchedBy&& supplyItemId == other.supplyItemId&& supplierSku == other.supplierSku&& impaCode == other.impaCode&& name == other.name&& unit == other.unit&& oldPrice == other.oldPrice&& oldCurrency == other.oldCurrency&& newPrice == other.newPrice&& currency == other.currency&& changePercent == other.changePercent&& error == other.error;
+        
+            }
+
+/// What importing a price list row does to the catalog
+enum PriceListChangeKind {
+                    new_,
+priceUp,
+priceDown,
+/// Price in a different currency than before
+currencyChanged,
+unchanged,
+/// The supplier's item is not on the list
+discontinued,
+                    ;
+                    
+                }
+
+/// Which columns of a supplier price list hold which field (header text or column letter)
+class PriceListColumnMapping  {
+                final String? supplierSku;
+final String? impaCode;
+final String name;
+final String? unit;
+final String price;
+/// Defaults to the request currency when not mapped
+final String? currency;
+
+                const PriceListColumnMapping({this.supplierSku ,this.impaCode ,required this.name ,this.unit ,required this.price ,this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierSku.hashCode^impaCode.hashCode^name.hashCode^unit.hashCode^price.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceListColumnMapping &&
+                runtimeType == other.runtimeType
+                && supplierSku == other.supplierSku&& impaCode == other.impaCode&& name == other.name&& unit == other.unit&& price == other.price&& currency == other.currency;
+        
+            }
+
+class PriceListImportRequest  {
+                final int supplierId;
+/// Path of a .csv, .xlsx or .xls file
+final String filePath;
+final PriceListColumnMapping mapping;
+/// 1-based row of the column headers (0 = no header row)
+final int headerRow;
+final String? sheetName;
+final String? delimiter;
+/// Currency of rows without a currency column, e.g. "TRY"
+final String currency;
+/// Category of new items whose IMPA section has none
+final int? defaultCategoryId;
+/// Date the prices take effect (YYYY-MM-DD), defaults to today
+final String? effectiveDate;
+/// Mark the supplier's items missing from the list as unavailable
+final bool discontinueMissing;
+
+                const PriceListImportRequest({required this.supplierId ,required this.filePath ,required this.mapping ,required this.headerRow ,this.sheetName ,this.delimiter ,required this.currency ,this.defaultCategoryId ,this.effectiveDate ,required this.discontinueMissing ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierId.hashCode^filePath.hashCode^mapping.hashCode^headerRow.hashCode^sheetName.hashCode^delimiter.hashCode^currency.hashCode^defaultCategoryId.hashCode^effectiveDate.hashCode^discontinueMissing.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceListImportRequest &&
+                runtimeType == other.runtimeType
+                && supplierId == other.supplierId&& filePath == other.filePath&& mapping == other.mapping&& headerRow == other.headerRow&& sheetName == other.sheetName&& delimiter == other.delimiter&& currency == other.currency&& defaultCategoryId == other.defaultCategoryId&& effectiveDate == other.effectiveDate&& discontinueMissing == other.discontinueMissing;
+        
+            }
+
+class PriceListImportResult  {
+                final int created;
+final int updated;
+final int unchanged;
+final int discontinued;
+
+                const PriceListImportResult({required this.created ,required this.updated ,required this.unchanged ,required this.discontinued ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => created.hashCode^updated.hashCode^unchanged.hashCode^discontinued.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceListImportResult &&
+                runtimeType == other.runtimeType
+                && created == other.created&& updated == other.updated&& unchanged == other.unchanged&& discontinued == other.discontinued;
+        
+            }
+
+/// How a price list row was matched to one of the supplier's items
+enum PriceListMatchKind {
+                    supplierSku,
+impa,
+name,
+                    ;
+                    
+                }
+
+/// Diff of a price list against the supplier's catalog, before anything is written
+class PriceListPreview  {
+                final int supplierId;
+final String supplierName;
+final List<PriceListChange> lines;
+final int newItems;
+final int priceUp;
+final int priceDown;
+final int currencyChanged;
+final int unchanged;
+final int discontinued;
+final int errors;
+
+                const PriceListPreview({required this.supplierId ,required this.supplierName ,required this.lines ,required this.newItems ,required this.priceUp ,required this.priceDown ,required this.currencyChanged ,required this.unchanged ,required this.discontinued ,required this.errors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierId.hashCode^supplierName.hashCode^lines.hashCode^newItems.hashCode^priceUp.hashCode^priceDown.hashCode^currencyChanged.hashCode^unchanged.hashCode^discontinued.hashCode^errors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceListPreview &&
+                runtimeType == other.runtimeType
+                && supplierId == other.supplierId&& supplierName == other.supplierName&& lines == other.lines&& newItems == other.newItems&& priceUp == other.priceUp&& priceDown == other.priceDown&& currencyChanged == other.currencyChanged&& unchanged == other.unchanged&& discontinued == other.discontinued&& errors == other.errors;
+        
+            }
+
+/// Selling price proposed by the pricing engine, with the reasoning behind it
+class PriceProposal  {
+                final double buyingPrice;
+/// 0 when `source` is NoRule
+final double sellingPrice;
+final String currency;
+final PriceSource source;
+/// Rule whose markup was applied
+final int? ruleId;
+/// Agreement the contract price comes from
+final int? agreementId;
+final double? markupPercent;
+final double? minMarginPercent;
+/// Margin of the proposed price, in percent
+final double? marginPercent;
+/// True when the markup price was raised to the margin floor
+final bool floorApplied;
+/// True when a contract price is below the margin floor (contract prices are not raised)
+final bool belowMarginFloor;
+final double roundingIncrement;
+final String explanation;
+
+                const PriceProposal({required this.buyingPrice ,required this.sellingPrice ,required this.currency ,required this.source ,this.ruleId ,this.agreementId ,this.markupPercent ,this.minMarginPercent ,this.marginPercent ,required this.floorApplied ,required this.belowMarginFloor ,required this.roundingIncrement ,required this.explanation ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => buyingPrice.hashCode^sellingPrice.hashCode^currency.hashCode^source.hashCode^ruleId.hashCode^agreementId.hashCode^markupPercent.hashCode^minMarginPercent.hashCode^marginPercent.hashCode^floorApplied.hashCode^belowMarginFloor.hashCode^roundingIncrement.hashCode^explanation.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceProposal &&
+                runtimeType == other.runtimeType
+                && buyingPrice == other.buyingPrice&& sellingPrice == other.sellingPrice&& currency == other.currency&& source == other.source&& ruleId == other.ruleId&& agreementId == other.agreementId&& markupPercent == other.markupPercent&& minMarginPercent == other.minMarginPercent&& marginPercent == other.marginPercent&& floorApplied == other.floorApplied&& belowMarginFloor == other.belowMarginFloor&& roundingIncrement == other.roundingIncrement&& explanation == other.explanation;
+        
+            }
+
+/// Supply item whose price rose over a period
+class PriceRiseItem  {
+                final int supplyItemId;
+final String supplyItemName;
+final String? impaCode;
+final int supplierId;
+final String? supplierName;
+final String currency;
+/// Price in effect at the start of the period
+final double startPrice;
+/// Price in effect at the end of the period
+final double endPrice;
+final double changePercent;
+/// Price changes inside the period
+final int changeCount;
+final String lastChangeDate;
+
+                const PriceRiseItem({required this.supplyItemId ,required this.supplyItemName ,this.impaCode ,required this.supplierId ,this.supplierName ,required this.currency ,required this.startPrice ,required this.endPrice ,required this.changePercent ,required this.changeCount ,required this.lastChangeDate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplyItemId.hashCode^supplyItemName.hashCode^impaCode.hashCode^supplierId.hashCode^supplierName.hashCode^currency.hashCode^startPrice.hashCode^endPrice.hashCode^changePercent.hashCode^changeCount.hashCode^lastChangeDate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceRiseItem &&
+                runtimeType == other.runtimeType
+                && supplyItemId == other.supplyItemId&& supplyItemName == other.supplyItemName&& impaCode == other.impaCode&& supplierId == other.supplierId&& supplierName == other.supplierName&& currency == other.currency&& startPrice == other.startPrice&& endPrice == other.endPrice&& changePercent == other.changePercent&& changeCount == other.changeCount&& lastChangeDate == other.lastChangeDate;
+        
+            }
+
+/// Where a proposed selling price comes from
+enum PriceSource {
+                    /// Customer price agreement
+contract,
+customerRule,
+supplierRule,
+categoryRule,
+defaultRule,
+/// Only a margin floor matched; the price is the floor price
+marginFloor,
+/// No agreement or rule covers the item
+noRule,
+                    ;
+                    
+                }
+
+/// Markup / margin floor rule.
+///
+/// A rule without category, supplier and customer is the default rule. When
+/// several rules match an item the most specific one wins: customer before
+/// supplier before category.
+class PricingRule  {
+                final int id;
+final String name;
+/// Supply item category; the rule also covers its subcategories
+final int? categoryId;
+/// Category code, e.g. "PROVISIONS"
+final String? category;
+final int? supplierId;
+final String? supplierName;
+final int? customerId;
+final String? customerName;
+/// Markup on the buying price, in percent
+final double? markupPercent;
+/// Minimum margin on the selling price, in percent
+final double? minMarginPercent;
+final String? notes;
+final bool isActive;
+final String createdAt;
+final String updatedAt;
+
+                const PricingRule({required this.id ,required this.name ,this.categoryId ,this.category ,this.supplierId ,this.supplierName ,this.customerId ,this.customerName ,this.markupPercent ,this.minMarginPercent ,this.notes ,required this.isActive ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^categoryId.hashCode^category.hashCode^supplierId.hashCode^supplierName.hashCode^customerId.hashCode^customerName.hashCode^markupPercent.hashCode^minMarginPercent.hashCode^notes.hashCode^isActive.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PricingRule &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& categoryId == other.categoryId&& category == other.category&& supplierId == other.supplierId&& supplierName == other.supplierName&& customerId == other.customerId&& customerName == other.customerName&& markupPercent == other.markupPercent&& minMarginPercent == other.minMarginPercent&& notes == other.notes&& isActive == other.isActive&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// An equivalent supply item of another (or the same) supplier
+class ProductAlternative  {
+                final int supplyItemId;
+final String name;
+final String? impaCode;
+final String? supplierSku;
+final int supplierId;
+final String? supplierName;
+final String unit;
+final double unitPrice;
+final String currency;
+final bool isAvailable;
+/// Quantity in our warehouse
+final double stockQuantity;
+final int? leadTimeDays;
+final AlternativeLink linkedBy;
+
+                const ProductAlternative({required this.supplyItemId ,required this.name ,this.impaCode ,this.supplierSku ,required this.supplierId ,this.supplierName ,required this.unit ,required this.unitPrice ,required this.currency ,required this.isAvailable ,required this.stockQuantity ,this.leadTimeDays ,required this.linkedBy ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplyItemId.hashCode^name.hashCode^impaCode.hashCode^supplierSku.hashCode^supplierId.hashCode^supplierName.hashCode^unit.hashCode^unitPrice.hashCode^currency.hashCode^isAvailable.hashCode^stockQuantity.hashCode^leadTimeDays.hashCode^linkedBy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProductAlternative &&
+                runtimeType == other.runtimeType
+                && supplyItemId == other.supplyItemId&& name == other.name&& impaCode == other.impaCode&& supplierSku == other.supplierSku&& supplierId == other.supplierId&& supplierName == other.supplierName&& unit == other.unit&& unitPrice == other.unitPrice&& currency == other.currency&& isAvailable == other.isAvailable&& stockQuantity == other.stockQuantity&& leadTimeDays == other.leadTimeDays&& linkedBy == other.linkedBy;
+        
+            }
 
 /// Profit summary for dashboard
-class ProfitSummary {
-  final int totalOrders;
-  final double totalRevenue;
-  final double totalCost;
-  final double totalProfit;
-  final double? averageMargin;
-  final String currency;
-
-  const ProfitSummary({
-    required this.totalOrders,
-    required this.totalRevenue,
-    required this.totalCost,
-    required this.totalProfit,
-    this.averageMargin,
-    required this.currency,
-  });
-
-  @override
-  int get hashCode =>
-      totalOrders.hashCode ^
-      totalRevenue.hashCode ^
-      totalCost.hashCode ^
-      totalProfit.hashCode ^
-      averageMargin.hashCode ^
-      currency.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ProfitSummary &&
-          runtimeType == other.runtimeType &&
-          totalOrders == other.totalOrders &&
-          totalRevenue == other.totalRevenue &&
-          totalCost == other.totalCost &&
-          totalProfit == other.totalProfit &&
-          averageMargin == other.averageMargin &&
-          currency == other.currency;
-}
-
-class Ship {
-  final int id;
-  final String name;
-  final String imoNumber;
-  final String flag;
-  final String? shipType;
-  final double? grossTonnage;
-  final String? owner;
-  final String createdAt;
-  final String updatedAt;
-
-  const Ship({
-    required this.id,
-    required this.name,
-    required this.imoNumber,
-    required this.flag,
-    this.shipType,
-    this.grossTonnage,
-    this.owner,
-    required this.createdAt,
-    required this.updatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      name.hashCode ^
-      imoNumber.hashCode ^
-      flag.hashCode ^
-      shipType.hashCode ^
-      grossTonnage.hashCode ^
-      owner.hashCode ^
-      createdAt.hashCode ^
-      updatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Ship &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          name == other.name &&
-          imoNumber == other.imoNumber &&
-          flag == other.flag &&
-          shipType == other.shipType &&
-          grossTonnage == other.grossTonnage &&
-          owner == other.owner &&
-          createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
-}
-
-class ShipVisit {
-  final int id;
-  final int shipId;
-  final String? shipName;
-  final int portId;
-  final String? portName;
-  final String eta;
-  final String etd;
-  final String? ata;
-  final String? atd;
-  final VisitStatus status;
-  final String? agentInfo;
-  final String? notes;
-  final String createdAt;
-  final String updatedAt;
-
-  const ShipVisit({
-    required this.id,
-    required this.shipId,
-    this.shipName,
-    required this.portId,
-    this.portName,
-    required this.eta,
-    required this.etd,
-    this.ata,
-    this.atd,
-    required this.status,
-    this.agentInfo,
-    this.notes,
-    required this.createdAt,
-    required this.updatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      shipId.hashCode ^
-      shipName.hashCode ^
-      portId.hashCode ^
-      portName.hashCode ^
-      eta.hashCode ^
-      etd.hashCode ^
-      ata.hashCode ^
-      atd.hashCode ^
-      status.hashCode ^
-      agentInfo.hashCode ^
-      notes.hashCode ^
-      createdAt.hashCode ^
-      updatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ShipVisit &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          shipId == other.shipId &&
-          shipName == other.shipName &&
-          portId == other.portId &&
-          portName == other.portName &&
-          eta == other.eta &&
-          etd == other.etd &&
-          ata == other.ata &&
-          atd == other.atd &&
-          status == other.status &&
-          agentInfo == other.agentInfo &&
-          notes == other.notes &&
-          createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
-}
+class ProfitSummary  {
+                final int totalOrders;
+final double totalRevenue;
+final double totalCost;
+final double totalProfit;
+final double? averageMargin;
+final String currency;
+
+                const ProfitSummary({required this.totalOrders ,required this.totalRevenue ,required this.totalCost ,required this.totalProfit ,this.averageMargin ,required this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalOrders.hashCode^totalRevenue.hashCode^totalCost.hashCode^totalProfit.hashCode^averageMargin.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProfitSummary &&
+                runtimeType == other.runtimeType
+                && totalOrders == other.totalOrders&& totalRevenue == other.totalRevenue&& totalCost == other.totalCost&& totalProfit == other.totalProfit&& averageMargin == other.averageMargin&& currency == other.currency;
+        
+            }
+
+/// Receivables aging row - open balances per owner, bucketed by days past due
+class ReceivablesAgingRow  {
+                /// None for invoices and payments not linked to a customer account
+final int? customerId;
+final String customerName;
+final String currency;
+final double days030;
+final double days3160;
+final double days6190;
+final double daysOver90;
+final double total;
+/// Unallocated payments as of the report date
+final double credit;
+
+                const ReceivablesAgingRow({this.customerId ,required this.customerName ,required this.currency ,required this.days030 ,required this.days3160 ,required this.days6190 ,required this.daysOver90 ,required this.total ,required this.credit ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => customerId.hashCode^customerName.hashCode^currency.hashCode^days030.hashCode^days3160.hashCode^days6190.hashCode^daysOver90.hashCode^total.hashCode^credit.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReceivablesAgingRow &&
+                runtimeType == other.runtimeType
+                && customerId == other.customerId&& customerName == other.customerName&& currency == other.currency&& days030 == other.days030&& days3160 == other.days3160&& days6190 == other.days6190&& daysOver90 == other.daysOver90&& total == other.total&& credit == other.credit;
+        
+            }
+
+/// Goods handed over to the ship for some lines of an order
+class RecordDeliveryRequest  {
+                final int orderId;
+/// Defaults to now
+final String? deliveredAt;
+/// Defaults to the order's ship visit
+final int? shipVisitId;
+final String? notes;
+final List<DeliveryLine> lines;
+
+                const RecordDeliveryRequest({required this.orderId ,this.deliveredAt ,this.shipVisitId ,this.notes ,required this.lines ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^deliveredAt.hashCode^shipVisitId.hashCode^notes.hashCode^lines.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecordDeliveryRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& deliveredAt == other.deliveredAt&& shipVisitId == other.shipVisitId&& notes == other.notes&& lines == other.lines;
+        
+            }
+
+/// Outcome of repricing all lines of an order
+class RepriceResult  {
+                final int orderId;
+final int updatedCount;
+final int unchangedCount;
+/// Lines no agreement or rule covers (left as they are)
+final int skippedCount;
+final List<RepricedItem> items;
+
+                const RepriceResult({required this.orderId ,required this.updatedCount ,required this.unchangedCount ,required this.skippedCount ,required this.items ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^updatedCount.hashCode^unchangedCount.hashCode^skippedCount.hashCode^items.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RepriceResult &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& updatedCount == other.updatedCount&& unchangedCount == other.unchangedCount&& skippedCount == other.skippedCount&& items == other.items;
+        
+            }
+
+class RepricedItem  {
+                final int orderItemId;
+final String productName;
+final String? impaCode;
+final double oldSellingPrice;
+final double newSellingPrice;
+final PriceProposal proposal;
+
+                const RepricedItem({required this.orderItemId ,required this.productName ,this.impaCode ,required this.oldSellingPrice ,required this.newSellingPrice ,required this.proposal ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^productName.hashCode^impaCode.hashCode^oldSellingPrice.hashCode^newSellingPrice.hashCode^proposal.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RepricedItem &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& productName == other.productName&& impaCode == other.impaCode&& oldSellingPrice == other.oldSellingPrice&& newSellingPrice == other.newSellingPrice&& proposal == other.proposal;
+        
+            }
+
+/// Which columns of the file hold which field.
+///
+/// A column is given by its header text (case-insensitive) or by its letter
+/// ("A", "B", ... "AA"); letters are required when the file has no header row.
+class RequisitionColumnMapping  {
+                final String? impaCode;
+final String description;
+final String quantity;
+final String? unit;
+final String? notes;
+
+                const RequisitionColumnMapping({this.impaCode ,required this.description ,required this.quantity ,this.unit ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => impaCode.hashCode^description.hashCode^quantity.hashCode^unit.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequisitionColumnMapping &&
+                runtimeType == other.runtimeType
+                && impaCode == other.impaCode&& description == other.description&& quantity == other.quantity&& unit == other.unit&& notes == other.notes;
+        
+            }
+
+/// One line of a requisition file with its proposed supply item.
+/// Operators may change `supply_item_id`, `quantity` or `include` before committing.
+class RequisitionImportLine  {
+                /// Row number in the file (1-based)
+final int rowNumber;
+final String? impaCode;
+final String description;
+final double quantity;
+final String unit;
+final String? notes;
+final RequisitionMatchKind matchKind;
+final int? supplyItemId;
+final String? supplyItemName;
+/// 1.0 for IMPA matches, name similarity (0-1) for fuzzy matches
+final double matchScore;
+/// Whether the line becomes an order item on commit
+final bool include;
+/// Why the row could not be read (such lines are never imported)
+final String? error;
+
+                const RequisitionImportLine({required this.rowNumber ,this.impaCode ,required this.description ,required this.quantity ,required this.unit ,this.notes ,required this.matchKind ,this.supplyItemId ,this.supplyItemName ,required this.matchScore ,required this.include ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => rowNumber.hashCode^impaCode.hashCode^description.hashCode^quantity.hashCode^unit.hashCode^notes.hashCode^matchKind.hashCode^supplyItemId.hashCode^supplyItemName.hashCode^matchScore.hashCode^include.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequisitionImportLine &&
+                runtimeType == other.runtimeType
+                && rowNumber == other.rowNumber&& impaCode == other.impaCode&& description == other.description&& quantity == other.quantity&& unit == other.unit&& notes == other.notes&& matchKind == other.matchKind&& supplyItemId == other.supplyItemId&& supplyItemName == other.supplyItemName&& matchScore == other.matchScore&& include == other.include&& error == other.error;
+        
+            }
+
+/// Result of reading a requisition file, before anything is written
+class RequisitionImportPreview  {
+                final int orderId;
+final String orderNumber;
+final List<RequisitionImportLine> lines;
+final int impaMatched;
+final int fuzzyMatched;
+final int unmatched;
+final int errors;
+
+                const RequisitionImportPreview({required this.orderId ,required this.orderNumber ,required this.lines ,required this.impaMatched ,required this.fuzzyMatched ,required this.unmatched ,required this.errors ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^orderNumber.hashCode^lines.hashCode^impaMatched.hashCode^fuzzyMatched.hashCode^unmatched.hashCode^errors.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequisitionImportPreview &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& orderNumber == other.orderNumber&& lines == other.lines&& impaMatched == other.impaMatched&& fuzzyMatched == other.fuzzyMatched&& unmatched == other.unmatched&& errors == other.errors;
+        
+            }
+
+/// Requisition file to preview for an order
+class RequisitionImportRequest  {
+                final int orderId;
+/// Path of a .csv, .xlsx or .xls file
+final String filePath;
+final RequisitionColumnMapping mapping;
+/// 1-based row of the column headers (0 = no header row); data starts below it
+final int headerRow;
+/// Worksheet to read (default: the first sheet)
+final String? sheetName;
+/// CSV delimiter (default: detected from the first line, ';' or ',')
+final String? delimiter;
+
+                const RequisitionImportRequest({required this.orderId ,required this.filePath ,required this.mapping ,required this.headerRow ,this.sheetName ,this.delimiter ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderId.hashCode^filePath.hashCode^mapping.hashCode^headerRow.hashCode^sheetName.hashCode^delimiter.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequisitionImportRequest &&
+                runtimeType == other.runtimeType
+                && orderId == other.orderId&& filePath == other.filePath&& mapping == other.mapping&& headerRow == other.headerRow&& sheetName == other.sheetName&& delimiter == other.delimiter;
+        
+            }
+
+/// How a requisition line was matched to a supply item
+enum RequisitionMatchKind {
+                    /// Same IMPA code
+impa,
+/// Similar product name
+fuzzyName,
+unmatched,
+                    ;
+                    
+                }
+
+/// Saved provisions list for a ship or for every ship of a type
+class RequisitionTemplate  {
+                final int id;
+final String name;
+/// Set for a ship's own template
+final int? shipId;
+final String? shipName;
+/// Set for a template shared by a ship type (e.g. "Cruise")
+final String? shipType;
+/// Persons on board (crew + passengers) the quantities are meant for
+final double? baseHeadcount;
+final String? notes;
+final List<RequisitionTemplateLine> lines;
+final String createdAt;
+final String updatedAt;
+
+                const RequisitionTemplate({required this.id ,required this.name ,this.shipId ,this.shipName ,this.shipType ,this.baseHeadcount ,this.notes ,required this.lines ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^shipId.hashCode^shipName.hashCode^shipType.hashCode^baseHeadcount.hashCode^notes.hashCode^lines.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequisitionTemplate &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& shipId == other.shipId&& shipName == other.shipName&& shipType == other.shipType&& baseHeadcount == other.baseHeadcount&& notes == other.notes&& lines == other.lines&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+class RequisitionTemplateLine  {
+                final int id;
+final int templateId;
+final String productName;
+final String? impaCode;
+final String? description;
+final double quantity;
+final String unit;
+final String? notes;
+
+                const RequisitionTemplateLine({required this.id ,required this.templateId ,required this.productName ,this.impaCode ,this.description ,required this.quantity ,required this.unit ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^templateId.hashCode^productName.hashCode^impaCode.hashCode^description.hashCode^quantity.hashCode^unit.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequisitionTemplateLine &&
+                runtimeType == other.runtimeType
+                && id == other.id&& templateId == other.templateId&& productName == other.productName&& impaCode == other.impaCode&& description == other.description&& quantity == other.quantity&& unit == other.unit&& notes == other.notes;
+        
+            }
+
+class RequisitionTemplateLineRequest  {
+                final String productName;
+final String? impaCode;
+final String? description;
+final double quantity;
+final String unit;
+final String? notes;
+
+                const RequisitionTemplateLineRequest({required this.productName ,this.impaCode ,this.description ,required this.quantity ,required this.unit ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => productName.hashCode^impaCode.hashCode^description.hashCode^quantity.hashCode^unit.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequisitionTemplateLineRequest &&
+                runtimeType == other.runtimeType
+                && productName == other.productName&& impaCode == other.impaCode&& description == other.description&& quantity == other.quantity&& unit == other.unit&& notes == other.notes;
+        
+            }
+
+class ReturnLineRequest  {
+                final int orderItemId;
+final double quantity;
+final ReturnReason reason;
+/// Put the goods back into stock (needs a stock record for the item's IMPA code)
+final bool restock;
+
+                const ReturnLineRequest({required this.orderItemId ,required this.quantity ,required this.reason ,required this.restock ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => orderItemId.hashCode^quantity.hashCode^reason.hashCode^restock.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReturnLineRequest &&
+                runtimeType == other.runtimeType
+                && orderItemId == other.orderItemId&& quantity == other.quantity&& reason == other.reason&& restock == other.restock;
+        
+            }
+
+enum ReturnReason {
+                    damaged,
+wrongItem,
+surplus,
+other,
+                    ;
+                    
+                }
+
+/// One search result; `scope` tells which kind of record `entity_id` is
+class SearchHit  {
+                final SearchScope scope;
+final int entityId;
+final String title;
+final String? subtitle;
+/// Text around the match, matched words wrapped in <b></b>
+final String snippet;
+/// Higher is a better match
+final double score;
+
+                const SearchHit({required this.scope ,required this.entityId ,required this.title ,this.subtitle ,required this.snippet ,required this.score ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => scope.hashCode^entityId.hashCode^title.hashCode^subtitle.hashCode^snippet.hashCode^score.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && scope == other.scope&& entityId == other.entityId&& title == other.title&& subtitle == other.subtitle&& snippet == other.snippet&& score == other.score;
+        
+            }
+
+/// What a global search looks through
+enum SearchScope {
+                    supplyItems,
+suppliers,
+ships,
+orders,
+                    ;
+                    
+                }
+
+class Ship  {
+                final int id;
+final String name;
+final String imoNumber;
+final String flag;
+final String? shipType;
+final double? grossTonnage;
+/// Owner name as shown on documents (legal name of the linked customer)
+final String? owner;
+/// Customer account that owns / manages the ship
+final int? ownerId;
+final String createdAt;
+final String updatedAt;
+
+                const Ship({required this.id ,required this.name ,required this.imoNumber ,required this.flag ,this.shipType ,this.grossTonnage ,this.owner ,this.ownerId ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^imoNumber.hashCode^flag.hashCode^shipType.hashCode^grossTonnage.hashCode^owner.hashCode^ownerId.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Ship &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& imoNumber == other.imoNumber&& flag == other.flag&& shipType == other.shipType&& grossTonnage == other.grossTonnage&& owner == other.owner&& ownerId == other.ownerId&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+class ShipVisit  {
+                final int id;
+final int shipId;
+final String? shipName;
+final int portId;
+final String? portName;
+final String eta;
+final String etd;
+final String? ata;
+final String? atd;
+final VisitStatus status;
+final String? agentInfo;
+final String? notes;
+final String createdAt;
+final String updatedAt;
+
+                const ShipVisit({required this.id ,required this.shipId ,this.shipName ,required this.portId ,this.portName ,required this.eta ,required this.etd ,this.ata ,this.atd ,required this.status ,this.agentInfo ,this.notes ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^shipId.hashCode^shipName.hashCode^portId.hashCode^portName.hashCode^eta.hashCode^etd.hashCode^ata.hashCode^atd.hashCode^status.hashCode^agentInfo.hashCode^notes.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ShipVisit &&
+                runtimeType == other.runtimeType
+                && id == other.id&& shipId == other.shipId&& shipName == other.shipName&& portId == other.portId&& portName == other.portName&& eta == other.eta&& etd == other.etd&& ata == other.ata&& atd == other.atd&& status == other.status&& agentInfo == other.agentInfo&& notes == other.notes&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
 
 /// Current stock level for a product
-class Stock {
-  final int id;
-  final int supplyItemId;
-  final String? supplyItemName;
-  final double quantity;
-  final String unit;
-  final String? warehouseLocation;
-  final double minimumQuantity;
-  final String lastUpdated;
-
-  const Stock({
-    required this.id,
-    required this.supplyItemId,
-    this.supplyItemName,
-    required this.quantity,
-    required this.unit,
-    this.warehouseLocation,
-    required this.minimumQuantity,
-    required this.lastUpdated,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      supplyItemId.hashCode ^
-      supplyItemName.hashCode ^
-      quantity.hashCode ^
-      unit.hashCode ^
-      warehouseLocation.hashCode ^
-      minimumQuantity.hashCode ^
-      lastUpdated.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Stock &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          supplyItemId == other.supplyItemId &&
-          supplyItemName == other.supplyItemName &&
-          quantity == other.quantity &&
-          unit == other.unit &&
-          warehouseLocation == other.warehouseLocation &&
-          minimumQuantity == other.minimumQuantity &&
-          lastUpdated == other.lastUpdated;
-}
+class Stock  {
+                final int id;
+final int supplyItemId;
+final String? supplyItemName;
+final double quantity;
+final String unit;
+final String? warehouseLocation;
+final WarehouseType warehouseType;
+final double minimumQuantity;
+final String lastUpdated;
+
+                const Stock({required this.id ,required this.supplyItemId ,this.supplyItemName ,required this.quantity ,required this.unit ,this.warehouseLocation ,required this.warehouseType ,required this.minimumQuantity ,required this.lastUpdated ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^supplyItemId.hashCode^supplyItemName.hashCode^quantity.hashCode^unit.hashCode^warehouseLocation.hashCode^warehouseType.hashCode^minimumQuantity.hashCode^lastUpdated.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Stock &&
+                runtimeType == other.runtimeType
+                && id == other.id&& supplyItemId == other.supplyItemId&& supplyItemName == other.supplyItemName&& quantity == other.quantity&& unit == other.unit&& warehouseLocation == other.warehouseLocation&& warehouseType == other.warehouseType&& minimumQuantity == other.minimumQuantity&& lastUpdated == other.lastUpdated;
+        
+            }
 
 /// Stock movement record
-class StockMovement {
-  final int id;
-  final int stockId;
-  final String? supplyItemName;
-  final StockMovementType movementType;
-  final double quantity;
-  final String unit;
-  final String? referenceType;
-  final int? referenceId;
-  final String? referenceInfo;
-  final String? notes;
-  final String createdAt;
-
-  const StockMovement({
-    required this.id,
-    required this.stockId,
-    this.supplyItemName,
-    required this.movementType,
-    required this.quantity,
-    required this.unit,
-    this.referenceType,
-    this.referenceId,
-    this.referenceInfo,
-    this.notes,
-    required this.createdAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      stockId.hashCode ^
-      supplyItemName.hashCode ^
-      movementType.hashCode ^
-      quantity.hashCode ^
-      unit.hashCode ^
-      referenceType.hashCode ^
-      referenceId.hashCode ^
-      referenceInfo.hashCode ^
-      notes.hashCode ^
-      createdAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is StockMovement &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          stockId == other.stockId &&
-          supplyItemName == other.supplyItemName &&
-          movementType == other.movementType &&
-          quantity == other.quantity &&
-          unit == other.unit &&
-          referenceType == other.referenceType &&
-          referenceId == other.referenceId &&
-          referenceInfo == other.referenceInfo &&
-          notes == other.notes &&
-          createdAt == other.createdAt;
-}
+class StockMovement  {
+                final int id;
+final int stockId;
+final String? supplyItemName;
+final StockMovementType movementType;
+final double quantity;
+final String unit;
+final String? referenceType;
+final int? referenceId;
+final String? referenceInfo;
+/// Order line a goods receipt fills
+final int? orderItemId;
+final String? notes;
+/// Quantity and unit as entered, when they differ from the stock unit
+final double? enteredQuantity;
+final String? enteredUnit;
+/// Customs declaration the movement was made under (required in a bonded warehouse)
+final String? customsDeclarationNumber;
+final String createdAt;
+
+                const StockMovement({required this.id ,required this.stockId ,this.supplyItemName ,required this.movementType ,required this.quantity ,required this.unit ,this.referenceType ,this.referenceId ,this.referenceInfo ,this.orderItemId ,this.notes ,this.enteredQuantity ,this.enteredUnit ,this.customsDeclarationNumber ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^stockId.hashCode^supplyItemName.hashCode^movementType.hashCode^quantity.hashCode^unit.hashCode^referenceType.hashCode^referenceId.hashCode^referenceInfo.hashCode^orderItemId.hashCode^notes.hashCode^enteredQuantity.hashCode^enteredUnit.hashCode^customsDeclarationNumber.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StockMovement &&
+                runtimeType == other.runtimeType
+                && id == other.id&& stockId == other.stockId&& supplyItemName == other.supplyItemName&& movementType == other.movementType&& quantity == other.quantity&& unit == other.unit&& referenceType == other.referenceType&& referenceId == other.referenceId&& referenceInfo == other.referenceInfo&& orderItemId == other.orderItemId&& notes == other.notes&& enteredQuantity == other.enteredQuantity&& enteredUnit == other.enteredUnit&& customsDeclarationNumber == other.customsDeclarationNumber&& createdAt == other.createdAt;
+        
+            }
 
 /// Stock movement type - what kind of inventory change
 enum StockMovementType {
-  /// Goods received from supplier
-  in_,
-
-  /// Goods sent to ship
-  out,
-
-  /// Inventory adjustment (count correction)
-  adjustment,
-
-  /// Return from ship
-  return_,
-  ;
-}
+                    /// Goods received from supplier
+in_,
+/// Goods sent to ship
+out,
+/// Inventory adjustment (count correction)
+adjustment,
+/// Return from ship
+return_,
+                    ;
+                    
+                }
 
 /// Stock summary for dashboard
-class StockSummary {
-  final int totalItems;
-  final int lowStockCount;
-  final int outOfStockCount;
-  final double totalValue;
-  final String currency;
-
-  const StockSummary({
-    required this.totalItems,
-    required this.lowStockCount,
-    required this.outOfStockCount,
-    required this.totalValue,
-    required this.currency,
-  });
-
-  @override
-  int get hashCode =>
-      totalItems.hashCode ^
-      lowStockCount.hashCode ^
-      outOfStockCount.hashCode ^
-      totalValue.hashCode ^
-      currency.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is StockSummary &&
-          runtimeType == other.runtimeType &&
-          totalItems == other.totalItems &&
-          lowStockCount == other.lowStockCount &&
-          outOfStockCount == other.outOfStockCount &&
-          totalValue == other.totalValue &&
-          currency == other.currency;
-}
+class StockSummary  {
+                final int totalItems;
+final int lowStockCount;
+final int outOfStockCount;
+/// Catalog price plus allocated landed cost
+final double totalValue;
+/// Part of `total_value` coming from landed costs
+final double landedCostValue;
+final String currency;
+
+                const StockSummary({required this.totalItems ,required this.lowStockCount ,required this.outOfStockCount ,required this.totalValue ,required this.landedCostValue ,required this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => totalItems.hashCode^lowStockCount.hashCode^outOfStockCount.hashCode^totalValue.hashCode^landedCostValue.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StockSummary &&
+                runtimeType == other.runtimeType
+                && totalItems == other.totalItems&& lowStockCount == other.lowStockCount&& outOfStockCount == other.outOfStockCount&& totalValue == other.totalValue&& landedCostValue == other.landedCostValue&& currency == other.currency;
+        
+            }
 
 /// Stock with movement history
-class StockWithMovements {
-  final Stock stock;
-  final List<StockMovement> movements;
-
-  const StockWithMovements({
-    required this.stock,
-    required this.movements,
-  });
-
-  @override
-  int get hashCode => stock.hashCode ^ movements.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is StockWithMovements &&
-          runtimeType == other.runtimeType &&
-          stock == other.stock &&
-          movements == other.movements;
-}
-
-class Supplier {
-  final int id;
-  final String name;
-  final String? contactPerson;
-  final String? email;
-  final String? phone;
-  final String? address;
-  final String? country;
-  final String category;
-  final bool isActive;
-  final String createdAt;
-  final String updatedAt;
-
-  const Supplier({
-    required this.id,
-    required this.name,
-    this.contactPerson,
-    this.email,
-    this.phone,
-    this.address,
-    this.country,
-    required this.category,
-    required this.isActive,
-    required this.createdAt,
-    required this.updatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      name.hashCode ^
-      contactPerson.hashCode ^
-      email.hashCode ^
-      phone.hashCode ^
-      address.hashCode ^
-      country.hashCode ^
-      category.hashCode ^
-      isActive.hashCode ^
-      createdAt.hashCode ^
-      updatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is Supplier &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          name == other.name &&
-          contactPerson == other.contactPerson &&
-          email == other.email &&
-          phone == other.phone &&
-          address == other.address &&
-          country == other.country &&
-          category == other.category &&
-          isActive == other.isActive &&
-          createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
-}
-
-class SupplyItem {
-  final int id;
-  final int supplierId;
-  final String? supplierName;
-  final String? impaCode;
-  final String name;
-  final String? description;
-  final String category;
-  final String unit;
-  final double unitPrice;
-  final String currency;
-  final int? minimumOrderQuantity;
-  final bool isAvailable;
-  final String createdAt;
-  final String updatedAt;
-
-  const SupplyItem({
-    required this.id,
-    required this.supplierId,
-    this.supplierName,
-    this.impaCode,
-    required this.name,
-    this.description,
-    required this.category,
-    required this.unit,
-    required this.unitPrice,
-    required this.currency,
-    this.minimumOrderQuantity,
-    required this.isAvailable,
-    required this.createdAt,
-    required this.updatedAt,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      supplierId.hashCode ^
-      supplierName.hashCode ^
-      impaCode.hashCode ^
-      name.hashCode ^
-      description.hashCode ^
-      category.hashCode ^
-      unit.hashCode ^
-      unitPrice.hashCode ^
-      currency.hashCode ^
-      minimumOrderQuantity.hashCode ^
-      isAvailable.hashCode ^
-      createdAt.hashCode ^
-      updatedAt.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SupplyItem &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          supplierId == other.supplierId &&
-          supplierName == other.supplierName &&
-          impaCode == other.impaCode &&
-          name == other.name &&
-          description == other.description &&
-          category == other.category &&
-          unit == other.unit &&
-          unitPrice == other.unitPrice &&
-          currency == other.currency &&
-          minimumOrderQuantity == other.minimumOrderQuantity &&
-          isAvailable == other.isAvailable &&
-          createdAt == other.createdAt &&
-          updatedAt == other.updatedAt;
-}
-
-class UpdateOrderItemRequest {
-  final String? productName;
-  final String? impaCode;
-  final String? description;
-  final double? quantity;
-  final String? unit;
-  final double? buyingPrice;
-  final double? sellingPrice;
-  final DeliveryType? deliveryType;
-  final String? warehouseDeliveryDate;
-  final String? shipDeliveryDate;
-  final String? notes;
-
-  const UpdateOrderItemRequest({
-    this.productName,
-    this.impaCode,
-    this.description,
-    this.quantity,
-    this.unit,
-    this.buyingPrice,
-    this.sellingPrice,
-    this.deliveryType,
-    this.warehouseDeliveryDate,
-    this.shipDeliveryDate,
-    this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      productName.hashCode ^
-      impaCode.hashCode ^
-      description.hashCode ^
-      quantity.hashCode ^
-      unit.hashCode ^
-      buyingPrice.hashCode ^
-      sellingPrice.hashCode ^
-      deliveryType.hashCode ^
-      warehouseDeliveryDate.hashCode ^
-      shipDeliveryDate.hashCode ^
-      notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdateOrderItemRequest &&
-          runtimeType == other.runtimeType &&
-          productName == other.productName &&
-          impaCode == other.impaCode &&
-          description == other.description &&
-          quantity == other.quantity &&
-          unit == other.unit &&
-          buyingPrice == other.buyingPrice &&
-          sellingPrice == other.sellingPrice &&
-          deliveryType == other.deliveryType &&
-          warehouseDeliveryDate == other.warehouseDeliveryDate &&
-          shipDeliveryDate == other.shipDeliveryDate &&
-          notes == other.notes;
-}
-
-class UpdateOrderRequest {
-  final int? shipId;
-  final int? shipVisitId;
-  final String? deliveryPort;
-  final String? notes;
-  final String? currency;
-
-  const UpdateOrderRequest({
-    this.shipId,
-    this.shipVisitId,
-    this.deliveryPort,
-    this.notes,
-    this.currency,
-  });
-
-  @override
-  int get hashCode =>
-      shipId.hashCode ^
-      shipVisitId.hashCode ^
-      deliveryPort.hashCode ^
-      notes.hashCode ^
-      currency.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdateOrderRequest &&
-          runtimeType == other.runtimeType &&
-          shipId == other.shipId &&
-          shipVisitId == other.shipVisitId &&
-          deliveryPort == other.deliveryPort &&
-          notes == other.notes &&
-          currency == other.currency;
-}
-
-class UpdatePortRequest {
-  final String? name;
-  final String? country;
-  final String? city;
-  final String? timezone;
-  final double? latitude;
-  final double? longitude;
-  final String? notes;
-  final bool? isActive;
-
-  const UpdatePortRequest({
-    this.name,
-    this.country,
-    this.city,
-    this.timezone,
-    this.latitude,
-    this.longitude,
-    this.notes,
-    this.isActive,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      country.hashCode ^
-      city.hashCode ^
-      timezone.hashCode ^
-      latitude.hashCode ^
-      longitude.hashCode ^
-      notes.hashCode ^
-      isActive.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdatePortRequest &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          country == other.country &&
-          city == other.city &&
-          timezone == other.timezone &&
-          latitude == other.latitude &&
-          longitude == other.longitude &&
-          notes == other.notes &&
-          isActive == other.isActive;
-}
-
-class UpdateShipRequest {
-  final String? name;
-  final String? imoNumber;
-  final String? flag;
-  final String? shipType;
-  final double? grossTonnage;
-  final String? owner;
-
-  const UpdateShipRequest({
-    this.name,
-    this.imoNumber,
-    this.flag,
-    this.shipType,
-    this.grossTonnage,
-    this.owner,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      imoNumber.hashCode ^
-      flag.hashCode ^
-      shipType.hashCode ^
-      grossTonnage.hashCode ^
-      owner.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdateShipRequest &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          imoNumber == other.imoNumber &&
-          flag == other.flag &&
-          shipType == other.shipType &&
-          grossTonnage == other.grossTonnage &&
-          owner == other.owner;
-}
-
-class UpdateShipVisitRequest {
-  final int? portId;
-  final String? eta;
-  final String? etd;
-  final String? ata;
-  final String? atd;
-  final VisitStatus? status;
-  final String? agentInfo;
-  final String? notes;
-
-  const UpdateShipVisitRequest({
-    this.portId,
-    this.eta,
-    this.etd,
-    this.ata,
-    this.atd,
-    this.status,
-    this.agentInfo,
-    this.notes,
-  });
-
-  @override
-  int get hashCode =>
-      portId.hashCode ^
-      eta.hashCode ^
-      etd.hashCode ^
-      ata.hashCode ^
-      atd.hashCode ^
-      status.hashCode ^
-      agentInfo.hashCode ^
-      notes.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdateShipVisitRequest &&
-          runtimeType == other.runtimeType &&
-          portId == other.portId &&
-          eta == other.eta &&
-          etd == other.etd &&
-          ata == other.ata &&
-          atd == other.atd &&
-          status == other.status &&
-          agentInfo == other.agentInfo &&
-          notes == other.notes;
-}
-
-class UpdateStockRequest {
-  final double? quantity;
-  final String? warehouseLocation;
-  final double? minimumQuantity;
-
-  const UpdateStockRequest({
-    this.quantity,
-    this.warehouseLocation,
-    this.minimumQuantity,
-  });
-
-  @override
-  int get hashCode =>
-      quantity.hashCode ^ warehouseLocation.hashCode ^ minimumQuantity.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdateStockRequest &&
-          runtimeType == other.runtimeType &&
-          quantity == other.quantity &&
-          warehouseLocation == other.warehouseLocation &&
-          minimumQuantity == other.minimumQuantity;
-}
-
-class UpdateSupplierRequest {
-  final String? name;
-  final String? contactPerson;
-  final String? email;
-  final String? phone;
-  final String? address;
-  final String? country;
-  final String? category;
-
-  const UpdateSupplierRequest({
-    this.name,
-    this.contactPerson,
-    this.email,
-    this.phone,
-    this.address,
-    this.country,
-    this.category,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      contactPerson.hashCode ^
-      email.hashCode ^
-      phone.hashCode ^
-      address.hashCode ^
-      country.hashCode ^
-      category.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdateSupplierRequest &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          contactPerson == other.contactPerson &&
-          email == other.email &&
-          phone == other.phone &&
-          address == other.address &&
-          country == other.country &&
-          category == other.category;
-}
-
-class UpdateSupplyItemRequest {
-  final int? supplierId;
-  final String? impaCode;
-  final String? name;
-  final String? description;
-  final String? category;
-  final String? unit;
-  final double? unitPrice;
-  final String? currency;
-  final int? minimumOrderQuantity;
-  final bool? isAvailable;
-
-  const UpdateSupplyItemRequest({
-    this.supplierId,
-    this.impaCode,
-    this.name,
-    this.description,
-    this.category,
-    this.unit,
-    this.unitPrice,
-    this.currency,
-    this.minimumOrderQuantity,
-    this.isAvailable,
-  });
-
-  @override
-  int get hashCode =>
-      supplierId.hashCode ^
-      impaCode.hashCode ^
-      name.hashCode ^
-      description.hashCode ^
-      category.hashCode ^
-      unit.hashCode ^
-      unitPrice.hashCode ^
-      currency.hashCode ^
-      minimumOrderQuantity.hashCode ^
-      isAvailable.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is UpdateSupplyItemRequest &&
-          runtimeType == other.runtimeType &&
-          supplierId == other.supplierId &&
-          impaCode == other.impaCode &&
-          name == other.name &&
-          description == other.description &&
-          category == other.category &&
-          unit == other.unit &&
-          unitPrice == other.unitPrice &&
-          currency == other.currency &&
-          minimumOrderQuantity == other.minimumOrderQuantity &&
-          isAvailable == other.isAvailable;
-}
+class StockWithMovements  {
+                final Stock stock;
+final List<StockMovement> movements;
+
+                const StockWithMovements({required this.stock ,required this.movements ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => stock.hashCode^movements.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StockWithMovements &&
+                runtimeType == other.runtimeType
+                && stock == other.stock&& movements == other.movements;
+        
+            }
+
+class Supplier  {
+                final int id;
+final String name;
+final String? contactPerson;
+final String? email;
+final String? phone;
+final String? address;
+final String? country;
+/// Category code, e.g. "PROVISIONS"
+final String category;
+final int? categoryId;
+final String? categoryName;
+/// Usual days from order to delivery at our warehouse
+final int? leadTimeDays;
+final bool isActive;
+final String createdAt;
+final String updatedAt;
+
+                const Supplier({required this.id ,required this.name ,this.contactPerson ,this.email ,this.phone ,this.address ,this.country ,required this.category ,this.categoryId ,this.categoryName ,this.leadTimeDays ,required this.isActive ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^contactPerson.hashCode^email.hashCode^phone.hashCode^address.hashCode^country.hashCode^category.hashCode^categoryId.hashCode^categoryName.hashCode^leadTimeDays.hashCode^isActive.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Supplier &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& contactPerson == other.contactPerson&& email == other.email&& phone == other.phone&& address == other.address&& country == other.country&& category == other.category&& categoryId == other.categoryId&& categoryName == other.categoryName&& leadTimeDays == other.leadTimeDays&& isActive == other.isActive&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+class SupplierInvoice  {
+                final int id;
+final int supplierId;
+final String? supplierName;
+/// The supplier's own invoice number
+final String invoiceNumber;
+/// Order the goods were bought for (acts as the purchase order)
+final int? orderId;
+final String? orderNumber;
+/// Sum of line totals
+final double amount;
+final double paidAmount;
+final double balance;
+final String currency;
+final String issueDate;
+final String dueDate;
+final InvoiceStatus status;
+/// Set once the invoice passed matching and may be paid
+final String? approvedAt;
+final String? notes;
+final List<SupplierInvoiceLine> lines;
+final String createdAt;
+final String updatedAt;
+
+                const SupplierInvoice({required this.id ,required this.supplierId ,this.supplierName ,required this.invoiceNumber ,this.orderId ,this.orderNumber ,required this.amount ,required this.paidAmount ,required this.balance ,required this.currency ,required this.issueDate ,required this.dueDate ,required this.status ,this.approvedAt ,this.notes ,required this.lines ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^supplierId.hashCode^supplierName.hashCode^invoiceNumber.hashCode^orderId.hashCode^orderNumber.hashCode^amount.hashCode^paidAmount.hashCode^balance.hashCode^currency.hashCode^issueDate.hashCode^dueDate.hashCode^status.hashCode^approvedAt.hashCode^notes.hashCode^lines.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SupplierInvoice &&
+                runtimeType == other.runtimeType
+                && id == other.id&& supplierId == other.supplierId&& supplierName == other.supplierName&& invoiceNumber == other.invoiceNumber&& orderId == other.orderId&& orderNumber == other.orderNumber&& amount == other.amount&& paidAmount == other.paidAmount&& balance == other.balance&& currency == other.currency&& issueDate == other.issueDate&& dueDate == other.dueDate&& status == other.status&& approvedAt == other.approvedAt&& notes == other.notes&& lines == other.lines&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+class SupplierInvoiceLine  {
+                final int id;
+final int supplierInvoiceId;
+/// Purchase order line being billed
+final int? orderItemId;
+/// Catalog item (links the line to its stock receipts)
+final int? supplyItemId;
+final String description;
+final double quantity;
+final double unitPrice;
+final double lineTotal;
+
+                const SupplierInvoiceLine({required this.id ,required this.supplierInvoiceId ,this.orderItemId ,this.supplyItemId ,required this.description ,required this.quantity ,required this.unitPrice ,required this.lineTotal ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^supplierInvoiceId.hashCode^orderItemId.hashCode^supplyItemId.hashCode^description.hashCode^quantity.hashCode^unitPrice.hashCode^lineTotal.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SupplierInvoiceLine &&
+                runtimeType == other.runtimeType
+                && id == other.id&& supplierInvoiceId == other.supplierInvoiceId&& orderItemId == other.orderItemId&& supplyItemId == other.supplyItemId&& description == other.description&& quantity == other.quantity&& unitPrice == other.unitPrice&& lineTotal == other.lineTotal;
+        
+            }
+
+class SupplierInvoiceLineMatch  {
+                final int lineId;
+final String description;
+final int? orderItemId;
+final double? orderedQuantity;
+/// Estimated cost on the order item (OrderItem.buying_price)
+final double? orderedPrice;
+/// Sum of stock IN movements for the order line; None for direct-to-ship lines
+final double? receivedQuantity;
+final double invoicedQuantity;
+final double invoicedPrice;
+/// (invoiced_price - ordered_price) * invoiced_quantity
+final double priceVariance;
+final MatchStatus status;
+
+                const SupplierInvoiceLineMatch({required this.lineId ,required this.description ,this.orderItemId ,this.orderedQuantity ,this.orderedPrice ,this.receivedQuantity ,required this.invoicedQuantity ,required this.invoicedPrice ,required this.priceVariance ,required this.status ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => lineId.hashCode^description.hashCode^orderItemId.hashCode^orderedQuantity.hashCode^orderedPrice.hashCode^receivedQuantity.hashCode^invoicedQuantity.hashCode^invoicedPrice.hashCode^priceVariance.hashCode^status.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SupplierInvoiceLineMatch &&
+                runtimeType == other.runtimeType
+                && lineId == other.lineId&& description == other.description&& orderItemId == other.orderItemId&& orderedQuantity == other.orderedQuantity&& orderedPrice == other.orderedPrice&& receivedQuantity == other.receivedQuantity&& invoicedQuantity == other.invoicedQuantity&& invoicedPrice == other.invoicedPrice&& priceVariance == other.priceVariance&& status == other.status;
+        
+            }
+
+class SupplierInvoiceMatch  {
+                final int supplierInvoiceId;
+final List<SupplierInvoiceLineMatch> lines;
+final bool isFullyMatched;
+final double totalPriceVariance;
+
+                const SupplierInvoiceMatch({required this.supplierInvoiceId ,required this.lines ,required this.isFullyMatched ,required this.totalPriceVariance ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierInvoiceId.hashCode^lines.hashCode^isFullyMatched.hashCode^totalPriceVariance.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SupplierInvoiceMatch &&
+                runtimeType == other.runtimeType
+                && supplierInvoiceId == other.supplierInvoiceId&& lines == other.lines&& isFullyMatched == other.isFullyMatched&& totalPriceVariance == other.totalPriceVariance;
+        
+            }
+
+class SupplierPayment  {
+                final int id;
+final int supplierInvoiceId;
+final String? invoiceNumber;
+final int supplierId;
+final String? supplierName;
+final double amount;
+final String currency;
+final String paymentDate;
+final PaymentMethod method;
+final String? reference;
+final String? notes;
+final String createdAt;
+
+                const SupplierPayment({required this.id ,required this.supplierInvoiceId ,this.invoiceNumber ,required this.supplierId ,this.supplierName ,required this.amount ,required this.currency ,required this.paymentDate ,required this.method ,this.reference ,this.notes ,required this.createdAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^supplierInvoiceId.hashCode^invoiceNumber.hashCode^supplierId.hashCode^supplierName.hashCode^amount.hashCode^currency.hashCode^paymentDate.hashCode^method.hashCode^reference.hashCode^notes.hashCode^createdAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SupplierPayment &&
+                runtimeType == other.runtimeType
+                && id == other.id&& supplierInvoiceId == other.supplierInvoiceId&& invoiceNumber == other.invoiceNumber&& supplierId == other.supplierId&& supplierName == other.supplierName&& amount == other.amount&& currency == other.currency&& paymentDate == other.paymentDate&& method == other.method&& reference == other.reference&& notes == other.notes&& createdAt == other.createdAt;
+        
+            }
+
+class SupplyItem  {
+                final int id;
+final int supplierId;
+final String? supplierName;
+final String? impaCode;
+/// The supplier's own article number
+final String? supplierSku;
+final String name;
+final String? description;
+/// Category code, e.g. "DAIRY"
+final String category;
+final int? categoryId;
+final String? categoryName;
+final String unit;
+final double unitPrice;
+final String currency;
+final int? minimumOrderQuantity;
+final bool isAvailable;
+/// UN number of a dangerous good, e.g. "UN1263"
+final String? unNumber;
+/// IMDG hazard class, e.g. "3" or "2.2"
+final String? hazardClass;
+/// A safety data sheet must be on file before the item goes on board
+final bool sdsRequired;
+/// Medicine that needs a pharmacist's sign-off on every order
+final bool prescriptionOnly;
+/// Excise goods (alcohol, tobacco) that may be kept duty-suspended in a bonded warehouse
+final bool isBonded;
+/// The safety data sheet, fetched with `get_attachment_data`
+final int? sdsAttachmentId;
+final String createdAt;
+final String updatedAt;
+
+                const SupplyItem({required this.id ,required this.supplierId ,this.supplierName ,this.impaCode ,this.supplierSku ,required this.name ,this.description ,required this.category ,this.categoryId ,this.categoryName ,required this.unit ,required this.unitPrice ,required this.currency ,this.minimumOrderQuantity ,required this.isAvailable ,this.unNumber ,this.hazardClass ,required this.sdsRequired ,required this.prescriptionOnly ,required this.isBonded ,this.sdsAttachmentId ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^supplierId.hashCode^supplierName.hashCode^impaCode.hashCode^supplierSku.hashCode^name.hashCode^description.hashCode^category.hashCode^categoryId.hashCode^categoryName.hashCode^unit.hashCode^unitPrice.hashCode^currency.hashCode^minimumOrderQuantity.hashCode^isAvailable.hashCode^unNumber.hashCode^hazardClass.hashCode^sdsRequired.hashCode^prescriptionOnly.hashCode^isBonded.hashCode^sdsAttachmentId.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SupplyItem &&
+                runtimeType == other.runtimeType
+                && id == other.id&& supplierId == other.supplierId&& supplierName == other.supplierName&& impaCode == other.impaCode&& supplierSku == other.supplierSku&& name == other.name&& description == other.description&& category == other.category&& categoryId == other.categoryId&& categoryName == other.categoryName&& unit == other.unit&& unitPrice == other.unitPrice&& currency == other.currency&& minimumOrderQuantity == other.minimumOrderQuantity&& isAvailable == other.isAvailable&& unNumber == other.unNumber&& hazardClass == other.hazardClass&& sdsRequired == other.sdsRequired&& prescriptionOnly == other.prescriptionOnly&& isBonded == other.isBonded&& sdsAttachmentId == other.sdsAttachmentId&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// What a unit measures; units convert freely only within a dimension
+enum UnitDimension {
+                    mass,
+volume,
+length,
+count,
+/// Box, crate, roll... - converts only through an item's pack size
+pack,
+                    ;
+                    
+                }
+
+class UnitOfMeasure  {
+                final int id;
+/// e.g. "KG"
+final String code;
+final String nameTr;
+final String? nameEn;
+final UnitDimension dimension;
+/// Size in the dimension's base unit (KG, LT, M, ADET); 1 for the base unit and packs
+final double factor;
+/// Other spellings accepted for this unit, e.g. "PCS" for "ADET"
+final List<String> aliases;
+final bool isActive;
+
+                const UnitOfMeasure({required this.id ,required this.code ,required this.nameTr ,this.nameEn ,required this.dimension ,required this.factor ,required this.aliases ,required this.isActive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^code.hashCode^nameTr.hashCode^nameEn.hashCode^dimension.hashCode^factor.hashCode^aliases.hashCode^isActive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UnitOfMeasure &&
+                runtimeType == other.runtimeType
+                && id == other.id&& code == other.code&& nameTr == other.nameTr&& nameEn == other.nameEn&& dimension == other.dimension&& factor == other.factor&& aliases == other.aliases&& isActive == other.isActive;
+        
+            }
+
+class UpdateCategoryRequest  {
+                final String? code;
+final String? nameTr;
+final String? nameEn;
+final int? sortOrder;
+final bool? isActive;
+
+                const UpdateCategoryRequest({this.code ,this.nameTr ,this.nameEn ,this.sortOrder ,this.isActive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^nameTr.hashCode^nameEn.hashCode^sortOrder.hashCode^isActive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateCategoryRequest &&
+                runtimeType == other.runtimeType
+                && code == other.code&& nameTr == other.nameTr&& nameEn == other.nameEn&& sortOrder == other.sortOrder&& isActive == other.isActive;
+        
+            }
+
+class UpdateCompanyProfileRequest  {
+                final String name;
+final String? address;
+final String? phone;
+final String? email;
+final String? website;
+final String? taxOffice;
+final String? taxNumber;
+final String? bankName;
+final String? iban;
+final String? swift;
+
+                const UpdateCompanyProfileRequest({required this.name ,this.address ,this.phone ,this.email ,this.website ,this.taxOffice ,this.taxNumber ,this.bankName ,this.iban ,this.swift ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^address.hashCode^phone.hashCode^email.hashCode^website.hashCode^taxOffice.hashCode^taxNumber.hashCode^bankName.hashCode^iban.hashCode^swift.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateCompanyProfileRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& address == other.address&& phone == other.phone&& email == other.email&& website == other.website&& taxOffice == other.taxOffice&& taxNumber == other.taxNumber&& bankName == other.bankName&& iban == other.iban&& swift == other.swift;
+        
+            }
+
+class UpdateCustomerRequest  {
+                final String? legalName;
+final String? taxNumber;
+final String? taxOffice;
+final String? billingAddress;
+final String? country;
+final int? paymentTermsDays;
+final double? creditLimit;
+/// Remove the credit limit (`credit_limit` is ignored)
+final bool clearCreditLimit;
+final String? defaultCurrency;
+final String? notes;
+
+                const UpdateCustomerRequest({this.legalName ,this.taxNumber ,this.taxOffice ,this.billingAddress ,this.country ,this.paymentTermsDays ,this.creditLimit ,required this.clearCreditLimit ,this.defaultCurrency ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => legalName.hashCode^taxNumber.hashCode^taxOffice.hashCode^billingAddress.hashCode^country.hashCode^paymentTermsDays.hashCode^creditLimit.hashCode^clearCreditLimit.hashCode^defaultCurrency.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateCustomerRequest &&
+                runtimeType == other.runtimeType
+                && legalName == other.legalName&& taxNumber == other.taxNumber&& taxOffice == other.taxOffice&& billingAddress == other.billingAddress&& country == other.country&& paymentTermsDays == other.paymentTermsDays&& creditLimit == other.creditLimit&& clearCreditLimit == other.clearCreditLimit&& defaultCurrency == other.defaultCurrency&& notes == other.notes;
+        
+            }
+
+class UpdateOrderChargeRequest  {
+                final String? description;
+final double? amount;
+final ChargeBilling? billing;
+final double? markupPercent;
+final String? notes;
+
+                const UpdateOrderChargeRequest({this.description ,this.amount ,this.billing ,this.markupPercent ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => description.hashCode^amount.hashCode^billing.hashCode^markupPercent.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateOrderChargeRequest &&
+                runtimeType == other.runtimeType
+                && description == other.description&& amount == other.amount&& billing == other.billing&& markupPercent == other.markupPercent&& notes == other.notes;
+        
+            }
+
+class UpdateOrderItemRequest  {
+                final String? productName;
+final String? impaCode;
+final String? description;
+final double? quantity;
+final String? unit;
+final double? buyingPrice;
+final double? sellingPrice;
+final DeliveryType? deliveryType;
+final String? warehouseDeliveryDate;
+final String? shipDeliveryDate;
+final String? notes;
+
+                const UpdateOrderItemRequest({this.productName ,this.impaCode ,this.description ,this.quantity ,this.unit ,this.buyingPrice ,this.sellingPrice ,this.deliveryType ,this.warehouseDeliveryDate ,this.shipDeliveryDate ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => productName.hashCode^impaCode.hashCode^description.hashCode^quantity.hashCode^unit.hashCode^buyingPrice.hashCode^sellingPrice.hashCode^deliveryType.hashCode^warehouseDeliveryDate.hashCode^shipDeliveryDate.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateOrderItemRequest &&
+                runtimeType == other.runtimeType
+                && productName == other.productName&& impaCode == other.impaCode&& description == other.description&& quantity == other.quantity&& unit == other.unit&& buyingPrice == other.buyingPrice&& sellingPrice == other.sellingPrice&& deliveryType == other.deliveryType&& warehouseDeliveryDate == other.warehouseDeliveryDate&& shipDeliveryDate == other.shipDeliveryDate&& notes == other.notes;
+        
+            }
+
+class UpdateOrderRequest  {
+                final int? shipId;
+final int? shipVisitId;
+final String? deliveryPort;
+final String? notes;
+final String? currency;
+
+                const UpdateOrderRequest({this.shipId ,this.shipVisitId ,this.deliveryPort ,this.notes ,this.currency ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => shipId.hashCode^shipVisitId.hashCode^deliveryPort.hashCode^notes.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateOrderRequest &&
+                runtimeType == other.runtimeType
+                && shipId == other.shipId&& shipVisitId == other.shipVisitId&& deliveryPort == other.deliveryPort&& notes == other.notes&& currency == other.currency;
+        
+            }
+
+class UpdatePortRequest  {
+                final String? name;
+final String? country;
+final String? city;
+final String? timezone;
+final double? latitude;
+final double? longitude;
+final String? notes;
+final bool? isInternational;
+final bool? isActive;
+
+                const UpdatePortRequest({this.name ,this.country ,this.city ,this.timezone ,this.latitude ,this.longitude ,this.notes ,this.isInternational ,this.isActive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^country.hashCode^city.hashCode^timezone.hashCode^latitude.hashCode^longitude.hashCode^notes.hashCode^isInternational.hashCode^isActive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdatePortRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& country == other.country&& city == other.city&& timezone == other.timezone&& latitude == other.latitude&& longitude == other.longitude&& notes == other.notes&& isInternational == other.isInternational&& isActive == other.isActive;
+        
+            }
+
+class UpdatePriceAgreementRequest  {
+                final String? name;
+final String? validFrom;
+final String? validTo;
+final String? notes;
+final bool? isActive;
+
+                const UpdatePriceAgreementRequest({this.name ,this.validFrom ,this.validTo ,this.notes ,this.isActive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^validFrom.hashCode^validTo.hashCode^notes.hashCode^isActive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdatePriceAgreementRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& validFrom == other.validFrom&& validTo == other.validTo&& notes == other.notes&& isActive == other.isActive;
+        
+            }
+
+class UpdatePricingRuleRequest  {
+                final String? name;
+final double? markupPercent;
+final double? minMarginPercent;
+final String? notes;
+final bool? isActive;
+
+                const UpdatePricingRuleRequest({this.name ,this.markupPercent ,this.minMarginPercent ,this.notes ,this.isActive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^markupPercent.hashCode^minMarginPercent.hashCode^notes.hashCode^isActive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdatePricingRuleRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& markupPercent == other.markupPercent&& minMarginPercent == other.minMarginPercent&& notes == other.notes&& isActive == other.isActive;
+        
+            }
+
+class UpdateRequisitionTemplateRequest  {
+                final String? name;
+final double? baseHeadcount;
+final String? notes;
+/// Replaces all lines when set
+final List<RequisitionTemplateLineRequest>? lines;
+
+                const UpdateRequisitionTemplateRequest({this.name ,this.baseHeadcount ,this.notes ,this.lines ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^baseHeadcount.hashCode^notes.hashCode^lines.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateRequisitionTemplateRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& baseHeadcount == other.baseHeadcount&& notes == other.notes&& lines == other.lines;
+        
+            }
+
+class UpdateShipRequest  {
+                final String? name;
+final String? imoNumber;
+final String? flag;
+final String? shipType;
+final double? grossTonnage;
+final String? owner;
+final int? ownerId;
+
+                const UpdateShipRequest({this.name ,this.imoNumber ,this.flag ,this.shipType ,this.grossTonnage ,this.owner ,this.ownerId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^imoNumber.hashCode^flag.hashCode^shipType.hashCode^grossTonnage.hashCode^owner.hashCode^ownerId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateShipRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& imoNumber == other.imoNumber&& flag == other.flag&& shipType == other.shipType&& grossTonnage == other.grossTonnage&& owner == other.owner&& ownerId == other.ownerId;
+        
+            }
+
+class UpdateShipVisitRequest  {
+                final int? portId;
+final String? eta;
+final String? etd;
+final String? ata;
+final String? atd;
+final VisitStatus? status;
+final String? agentInfo;
+final String? notes;
+
+                const UpdateShipVisitRequest({this.portId ,this.eta ,this.etd ,this.ata ,this.atd ,this.status ,this.agentInfo ,this.notes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => portId.hashCode^eta.hashCode^etd.hashCode^ata.hashCode^atd.hashCode^status.hashCode^agentInfo.hashCode^notes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateShipVisitRequest &&
+                runtimeType == other.runtimeType
+                && portId == other.portId&& eta == other.eta&& etd == other.etd&& ata == other.ata&& atd == other.atd&& status == other.status&& agentInfo == other.agentInfo&& notes == other.notes;
+        
+            }
+
+class UpdateStockRequest  {
+                final double? quantity;
+final String? warehouseLocation;
+final double? minimumQuantity;
+
+                const UpdateStockRequest({this.quantity ,this.warehouseLocation ,this.minimumQuantity ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => quantity.hashCode^warehouseLocation.hashCode^minimumQuantity.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateStockRequest &&
+                runtimeType == other.runtimeType
+                && quantity == other.quantity&& warehouseLocation == other.warehouseLocation&& minimumQuantity == other.minimumQuantity;
+        
+            }
+
+class UpdateSupplierRequest  {
+                final String? name;
+final String? contactPerson;
+final String? email;
+final String? phone;
+final String? address;
+final String? country;
+final int? categoryId;
+final int? leadTimeDays;
+
+                const UpdateSupplierRequest({this.name ,this.contactPerson ,this.email ,this.phone ,this.address ,this.country ,this.categoryId ,this.leadTimeDays ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^contactPerson.hashCode^email.hashCode^phone.hashCode^address.hashCode^country.hashCode^categoryId.hashCode^leadTimeDays.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateSupplierRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& contactPerson == other.contactPerson&& email == other.email&& phone == other.phone&& address == other.address&& country == other.country&& categoryId == other.categoryId&& leadTimeDays == other.leadTimeDays;
+        
+            }
+
+class UpdateSupplyItemRequest  {
+                final int? supplierId;
+final String? impaCode;
+final String? supplierSku;
+final String? name;
+final String? description;
+final int? categoryId;
+final String? unit;
+final double? unitPrice;
+final String? currency;
+final int? minimumOrderQuantity;
+final bool? isAvailable;
+/// Date a price change takes effect (YYYY-MM-DD), defaults to today
+final String? priceEffectiveDate;
+/// Blank clears the UN number
+final String? unNumber;
+/// Blank clears the hazard class
+final String? hazardClass;
+final bool? sdsRequired;
+final bool? prescriptionOnly;
+final bool? isBonded;
+
+                const UpdateSupplyItemRequest({this.supplierId ,this.impaCode ,this.supplierSku ,this.name ,this.description ,this.categoryId ,this.unit ,this.unitPrice ,this.currency ,this.minimumOrderQuantity ,this.isAvailable ,this.priceEffectiveDate ,this.unNumber ,this.hazardClass ,this.sdsRequired ,this.prescriptionOnly ,this.isBonded ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => supplierId.hashCode^impaCode.hashCode^supplierSku.hashCode^name.hashCode^description.hashCode^categoryId.hashCode^unit.hashCode^unitPrice.hashCode^currency.hashCode^minimumOrderQuantity.hashCode^isAvailable.hashCode^priceEffectiveDate.hashCode^unNumber.hashCode^hazardClass.hashCode^sdsRequired.hashCode^prescriptionOnly.hashCode^isBonded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateSupplyItemRequest &&
+                runtimeType == other.runtimeType
+                && supplierId == other.supplierId&& impaCode == other.impaCode&& supplierSku == other.supplierSku&& name == other.name&& description == other.description&& categoryId == other.categoryId&& unit == other.unit&& unitPrice == other.unitPrice&& currency == other.currency&& minimumOrderQuantity == other.minimumOrderQuantity&& isAvailable == other.isAvailable&& priceEffectiveDate == other.priceEffectiveDate&& unNumber == other.unNumber&& hazardClass == other.hazardClass&& sdsRequired == other.sdsRequired&& prescriptionOnly == other.prescriptionOnly&& isBonded == other.isBonded;
+        
+            }
+
+class UpdateUnitRequest  {
+                final String? nameTr;
+final String? nameEn;
+final double? factor;
+/// Replaces the alias list when given
+final List<String>? aliases;
+final bool? isActive;
+
+                const UpdateUnitRequest({this.nameTr ,this.nameEn ,this.factor ,this.aliases ,this.isActive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => nameTr.hashCode^nameEn.hashCode^factor.hashCode^aliases.hashCode^isActive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpdateUnitRequest &&
+                runtimeType == other.runtimeType
+                && nameTr == other.nameTr&& nameEn == other.nameEn&& factor == other.factor&& aliases == other.aliases&& isActive == other.isActive;
+        
+            }
 
 /// Ship visit status
 enum VisitStatus {
-  planned,
-  arrived,
-  departed,
-  cancelled,
-  ;
-}
+                    planned,
+arrived,
+departed,
+cancelled,
+                    ;
+                    
+                }
+
+/// Kind of warehouse a stock record is kept in
+enum WarehouseType {
+                    /// Duty-paid goods
+general,
+/// Customs bonded warehouse: duty-suspended goods for foreign ships
+bonded,
+                    ;
+                    
+                }
+            
\ No newline at end of file